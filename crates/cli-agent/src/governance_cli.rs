@@ -1,11 +1,13 @@
 use governance_local::{CommunityId};
-use governance_sim::{SncPolicySnapshot};
-use orchestration::governance::validate_policy_change;
+use governance_sim::{CachedSimulationBackend, LinearPolicySimulator, SimulationHorizon, SncPolicySnapshot};
+use orchestration::governance::{validate_policy_change, PolicyChangeRequest, PolicyGateThresholds};
 
 fn run_policy_proposal() -> Result<(), String> {
-    // Backend implementations would wrap a permissioned ledger + Osireon node.
+    // The governance backend would wrap a permissioned ledger; swap the
+    // reference simulator for an Osireon node once one is reachable. The
+    // cache spares the node repeat runs while a proposal is being edited.
     let governance_backend = MyLedgerBackend::new();
-    let simulator_backend = MyOsireonBackend::new();
+    let simulator_backend = CachedSimulationBackend::new(LinearPolicySimulator::default());
 
     let proposal_id = "snc-policy-2026-02-fairness-upgrade";
 
@@ -20,13 +22,21 @@ fn run_policy_proposal() -> Result<(), String> {
         eco_weight: 0.4,
     };
 
-    validate_policy_change(
-        &governance_backend,
-        &simulator_backend,
-        proposal_id,
-        &affected,
-        &snapshot,
-    )?;
+    // Corridor-negotiated bounds, e.g. `{"min_trust_index": 0.5}`.
+    let thresholds: PolicyGateThresholds = match std::env::var("MORPHIX_POLICY_THRESHOLDS") {
+        Ok(path) => std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| serde_json::from_str(&text).map_err(|e| e.to_string()))
+            .map_err(|e| format!("policy thresholds {path}: {e}"))?,
+        Err(_) => PolicyGateThresholds::default(),
+    };
+
+    let request = PolicyChangeRequest {
+        thresholds,
+        horizon: Some(SimulationHorizon { years: 20.0, discount_rate: 0.03 }),
+        ..PolicyChangeRequest::new(proposal_id, &affected, &snapshot)
+    };
+    validate_policy_change(&governance_backend, &simulator_backend, &request)?;
 
     println!("Policy is FPIC‑aligned and passes simulation thresholds.");
     Ok(())
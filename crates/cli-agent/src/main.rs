@@ -1,16 +1,69 @@
-use core_contract::eco::{CorridorId, EcoImpactMetrics, NeuromorphArtifact};
-use core_contract::{DefaultSovereignNeuromorphContract, RoleTier};
-use eco_gbif::GbifEcoSource;
-use orchestration::NeuromorphOrchestrator;
+use core_contract::composite::CompositeContract;
+use core_contract::eco::{CorridorId, EcoImpactMetrics, NeuromorphArtifact, ProvenanceInfo};
+use core_contract::eco_source::{EcoDataSource, EcoSourceError, SyncEcoAdapter};
+use core_contract::eco_trend::{EcoDimension, EcoDriftAlert, EcoTrendConfig, EcoTrendObserver};
+use core_contract::fpic::{simulate_revocation, DelegationTree, FpicToken};
+use core_contract::health::{self, EcoSourceProbe};
+use core_contract::labels::DataLabels;
+use core_contract::{DefaultSovereignNeuromorphContract, DistilledKnowledge, RoleTier};
+use eco_gbif::{CorridorRegistry, EcoScoreTable, EcoSnapshot, GbifEcoSource, TableEcoSource};
+use governance_sim::{rank_policies, sensitivity_sweep, LinearPolicySimulator, SncPolicySnapshot};
+use morphix::backfill::{self, BackfillOptions, BackfillSource, ColumnMapping};
+use morphix::biorail::{BioRailConfig, GateCase, GateReport};
+use morphix::config::{Config, ConfigBundle};
+use morphix::ledger::{dp_metrics, DpSeed, Ledger, TreeOfLife};
+use morphix::reports::JsonFileEcoTrendStore;
+use morphix::telemetry::{self, Counter, LocalAggregator, TelemetryConfig, TimedEcoSource};
+use orchestration::{NeuromorphOrchestrator, OrchestrationError};
+
+/// Set from `--eco-table <path>`, which may appear anywhere on the command
+/// line.
+static ECO_TABLE: std::sync::OnceLock<String> = std::sync::OnceLock::new();
 
 fn main() {
-    let contract = DefaultSovereignNeuromorphContract::new(true, true, true);
-    let eco_source = GbifEcoSource;
-    let orchestrator = NeuromorphOrchestrator::new(contract, eco_source);
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(i) = args.iter().position(|a| a == "--eco-table") {
+        if i + 1 >= args.len() {
+            eprintln!("--eco-table needs a path");
+            std::process::exit(1);
+        }
+        let path = args.remove(i + 1);
+        args.remove(i);
+        let _ = ECO_TABLE.set(path);
+    }
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
 
-    let artifact = NeuromorphArtifact {
+    match args.as_slice() {
+        ["eco", "trend", corridor] => run_eco_trend(corridor),
+        ["eco", "export-snapshot", path, corridors @ ..] => run_eco_export_snapshot(path, corridors),
+        ["consent", "simulate-revoke", path] => run_simulate_revoke(path),
+        ["gate", "replay", path, flags @ ..] => run_gate_replay(path, flags),
+        ["health"] => run_health(),
+        ["policy", "sweep", param, from, to, steps] => run_policy_sweep(param, from, to, steps),
+        ["policy", "rank", "--file", path] => run_policy_rank(path),
+        ["ledger", "export", path] => run_ledger_export(path, None),
+        ["ledger", "export", path, "--dp-epsilon", epsilon, "--dp-seed-file", seed_file]
+        | ["ledger", "export", path, "--dp-seed-file", seed_file, "--dp-epsilon", epsilon] => {
+            run_ledger_export(path, Some((epsilon, seed_file)))
+        }
+        ["backfill", path] => run_backfill(path, false),
+        ["backfill", path, "--dry-run"] | ["backfill", "--dry-run", path] => run_backfill(path, true),
+        ["telemetry", "show"] => run_telemetry_show(),
+        ["telemetry", "purge"] => run_telemetry_purge(),
+        ["demo", "--async"] => run_demo_async(),
+        ["demo", "--trace"] => run_demo(true, None),
+        ["demo", "--artifact-file", path] => run_demo(false, Some(path)),
+        ["demo", "--trace", "--artifact-file", path] | ["demo", "--artifact-file", path, "--trace"] => {
+            run_demo(true, Some(path))
+        }
+        _ => run_demo(false, None),
+    }
+}
+
+fn demo_artifact(corridor: &str) -> NeuromorphArtifact {
+    NeuromorphArtifact {
         id: "artifact-001".to_string(),
-        corridor_id: CorridorId("protected-desert-phoenix".to_string()),
+        corridor_id: CorridorId(corridor.to_string()),
         eco_impact: EcoImpactMetrics {
             climate_score: 1.0,
             biodiversity_score: 1.0,
@@ -18,16 +71,229 @@ fn main() {
             corridor_score: 1.0,
         },
         summary: "Example neuromorph research turn for Phoenix corridor.".to_string(),
+        data_labels: DataLabels::default(),
+    }
+}
+
+/// The stub eco source: `TableEcoSource` over the `--eco-table` file when
+/// given, otherwise `GbifEcoSource`.
+enum StubEcoSource {
+    Gbif(GbifEcoSource),
+    Table(TableEcoSource),
+}
+
+impl EcoDataSource for StubEcoSource {
+    fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+        match self {
+            Self::Gbif(source) => source.calculate(artifact),
+            Self::Table(source) => source.calculate(artifact),
+        }
+    }
+
+    fn provenance_label(&self) -> &'static str {
+        match self {
+            Self::Gbif(source) => source.provenance_label(),
+            Self::Table(source) => source.provenance_label(),
+        }
+    }
+
+    fn provenance_detail(&self) -> ProvenanceInfo {
+        match self {
+            Self::Gbif(source) => source.provenance_detail(),
+            Self::Table(source) => source.provenance_detail(),
+        }
+    }
+}
+
+/// Corridors come from the registry named by `MORPHIX_CORRIDOR_REGISTRY`
+/// (TOML, or JSON by extension), or the bundled `corridors.toml`.
+fn eco_source() -> StubEcoSource {
+    let source = corridor_registry().and_then(|registry| match ECO_TABLE.get() {
+        Some(path) => EcoScoreTable::load(std::path::Path::new(path))
+            .map(|table| StubEcoSource::Table(TableEcoSource::new(registry, table))),
+        None => Ok(StubEcoSource::Gbif(GbifEcoSource::new(registry))),
+    });
+    match source {
+        Ok(source) => source,
+        Err(err) => {
+            eprintln!("Cannot load eco source: {err}");
+            std::process::exit(1);
+        }
+    }
+}
+
+fn corridor_registry() -> Result<CorridorRegistry, String> {
+    match std::env::var("MORPHIX_CORRIDOR_REGISTRY") {
+        Ok(path) => CorridorRegistry::load(std::path::Path::new(&path)),
+        Err(_) => CorridorRegistry::from_toml_str(include_str!("../corridors.toml")),
+    }
+}
+
+/// Telemetry stays off unless `MORPHIX_TELEMETRY_CONFIG` names a JSON
+/// `TelemetryConfig` that enables it.
+fn telemetry_config() -> TelemetryConfig {
+    let Ok(path) = std::env::var("MORPHIX_TELEMETRY_CONFIG") else {
+        return TelemetryConfig::default();
     };
+    match std::fs::read_to_string(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|text| serde_json::from_str(&text).map_err(|e| e.to_string()))
+    {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("Ignoring telemetry config {path}: {err}");
+            TelemetryConfig::default()
+        }
+    }
+}
+
+/// The demo contract: a single all-consenting member, unless
+/// `MORPHIX_CONTRACTS_CONFIG` names a JSON array of members, each
+/// `{"name", "explicit_consent", "sovereign_abort_control",
+/// "discipline_non_coercive"}`, that must all pass.
+fn demo_contract() -> CompositeContract {
+    let Ok(path) = std::env::var("MORPHIX_CONTRACTS_CONFIG") else {
+        return CompositeContract::new()
+            .with_member("default", DefaultSovereignNeuromorphContract::new(true, true, true));
+    };
+    let parsed = std::fs::read_to_string(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|text| serde_json::from_str::<Vec<serde_json::Value>>(&text).map_err(|e| e.to_string()))
+        .and_then(|members| {
+            members.iter().try_fold(CompositeContract::new(), |composite, member| {
+                let flag = |key: &str| {
+                    member[key]
+                        .as_bool()
+                        .ok_or_else(|| format!("member is missing boolean {key:?}"))
+                };
+                let name = member["name"].as_str().ok_or("member is missing \"name\"")?;
+                let contract = DefaultSovereignNeuromorphContract::new(
+                    flag("explicit_consent")?,
+                    flag("sovereign_abort_control")?,
+                    flag("discipline_non_coercive")?,
+                );
+                Ok(composite.with_member(name, contract))
+            })
+        });
+    match parsed {
+        Ok(composite) => composite,
+        Err(err) => {
+            eprintln!("Cannot read contracts config {path}: {err}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Process exit code for a refused distillation, so scripts can branch on
+/// the reason without parsing stderr.
+fn exit_code(err: &OrchestrationError) -> i32 {
+    match err {
+        OrchestrationError::MissingConsent => 10,
+        OrchestrationError::NoAbortControl => 11,
+        OrchestrationError::CoerciveDiscipline => 12,
+        OrchestrationError::DowngradeForbidden => 13,
+        OrchestrationError::ContractMemberRefused { predicate, .. } => exit_code(&(*predicate).into()),
+        OrchestrationError::PurposeProhibited { .. } => 14,
+        OrchestrationError::FpicExpired => 15,
+        OrchestrationError::FpicWithdrawn(_) => 16,
+        OrchestrationError::CareMisaligned => 17,
+        OrchestrationError::ChatMissingDualLinkage => 20,
+        OrchestrationError::ChatUncertaintyNotExposed => 21,
+        OrchestrationError::EcoSourceFailure(_) => 30,
+        OrchestrationError::SimulationFailure(_) => 31,
+        OrchestrationError::InvalidKnowledgeFactor(_) => 40,
+        OrchestrationError::InvalidArtifact(_) => 41,
+        OrchestrationError::QuotaExceeded { .. } => 50,
+        OrchestrationError::QuotaActorMissing => 51,
+    }
+}
 
-    let result = orchestrator.distill_neuromorph_content(
+/// Reads a `NeuromorphArtifact` JSON document from `path`.
+fn load_artifact(path: &str) -> NeuromorphArtifact {
+    let parsed = std::fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|text| serde_json::from_str(&text).map_err(|e| e.to_string()));
+    match parsed {
+        Ok(artifact) => artifact,
+        Err(err) => {
+            eprintln!("Cannot read artifact {path}: {err}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// With `trace`, also prints the decision trace as one JSON line on stdout.
+/// With `artifact_file`, distills that artifact instead of the built-in one.
+fn run_demo(trace: bool, artifact_file: Option<&str>) {
+    let telemetry = LocalAggregator::new(telemetry_config());
+    let contract = demo_contract();
+    let eco_source = TimedEcoSource {
+        inner: eco_source(),
+        telemetry: &telemetry,
+    };
+    let orchestrator = NeuromorphOrchestrator::new(contract, eco_source);
+
+    let artifact = match artifact_file {
+        Some(path) => load_artifact(path),
+        None => demo_artifact("protected-desert-phoenix"),
+    };
+    let result = orchestrator.distill_with_trace(
         RoleTier::Learner,
         artifact,
+        "education",
         /* has_biophysical_signal */ true,
         /* uses_discipline_signals */ true,
         /* dual_empirical_formal_present */ true,
         /* uncertainty_exposed */ true,
+        None,
     );
+    if let (true, Ok((_, decision))) = (trace, &result) {
+        match serde_json::to_string(decision) {
+            Ok(line) => println!("{line}"),
+            Err(err) => eprintln!("Cannot serialize decision trace: {err}"),
+        }
+    }
+    report_demo(result.map(|(dk, _)| dk), &telemetry);
+}
+
+/// Same demo through the async orchestrator path on a tokio runtime, with
+/// the stub eco source behind `SyncEcoAdapter`.
+fn run_demo_async() {
+    let runtime = match tokio::runtime::Builder::new_current_thread().build() {
+        Ok(runtime) => runtime,
+        Err(err) => {
+            eprintln!("Cannot start async runtime: {err}");
+            std::process::exit(1);
+        }
+    };
+    let telemetry = LocalAggregator::new(telemetry_config());
+    let contract = demo_contract();
+    let eco_source = SyncEcoAdapter(TimedEcoSource {
+        inner: eco_source(),
+        telemetry: &telemetry,
+    });
+    let orchestrator = NeuromorphOrchestrator::new(contract, eco_source);
+
+    let result = runtime.block_on(orchestrator.distill_neuromorph_content_async(
+        RoleTier::Learner,
+        demo_artifact("protected-desert-phoenix"),
+        "education",
+        true,
+        true,
+        true,
+        true,
+    ));
+    report_demo(result, &telemetry);
+}
+
+fn report_demo(result: Result<DistilledKnowledge, OrchestrationError>, telemetry: &LocalAggregator) {
+    match &result {
+        Ok(_) => telemetry.increment(Counter::Distilled),
+        Err(err) => telemetry.record_refusal(err),
+    }
+    if let Err(err) = telemetry.flush(std::time::SystemTime::now()) {
+        eprintln!("Cannot write telemetry aggregate: {err}");
+    }
 
     match result {
         Ok(dk) => {
@@ -39,6 +305,439 @@ fn main() {
                 dk.neurorights_compliant,
             );
         }
-        Err(err) => eprintln!("SNC refused: {err}"),
+        Err(err) => {
+            eprintln!("SNC refused: {err}");
+            std::process::exit(exit_code(&err));
+        }
+    }
+}
+
+/// Prints drift alerts as they are raised by the orchestrator.
+struct PrintingTrendObserver;
+
+impl EcoTrendObserver for PrintingTrendObserver {
+    fn on_drift_alert(&self, alert: &EcoDriftAlert) {
+        println!(
+            "ALERT corridor={} dimension={:?} slope={:.4}/day window={}",
+            alert.corridor.0, alert.dimension, alert.slope, alert.window
+        );
+    }
+
+    fn on_store_failed(&self, error: &str) {
+        eprintln!("Cannot save eco trend: {error}");
+    }
+}
+
+/// Records one sample for `corridor` in the tracker kept at
+/// `MORPHIX_ECO_TREND_STATE` (default `eco-trend.json`), so repeated runs
+/// build up the series, then prints it.
+fn run_eco_trend(corridor: &str) {
+    let path = std::env::var("MORPHIX_ECO_TREND_STATE").unwrap_or_else(|_| "eco-trend.json".to_string());
+    let contract = DefaultSovereignNeuromorphContract::new(true, true, true);
+    let orchestrator = match NeuromorphOrchestrator::new(contract, eco_source()).with_stored_eco_trend(
+        EcoTrendConfig::default(),
+        JsonFileEcoTrendStore::new(&path),
+        Box::new(PrintingTrendObserver),
+    ) {
+        Ok(orchestrator) => orchestrator,
+        Err(err) => {
+            eprintln!("Cannot load eco trend: {err}");
+            std::process::exit(1);
+        }
+    };
+
+    let refusal = orchestrator
+        .distill_neuromorph_content(
+            RoleTier::Researcher,
+            demo_artifact(corridor),
+            "research",
+            false,
+            false,
+            true,
+            true,
+        )
+        .err();
+    if let Some(err) = &refusal {
+        eprintln!("SNC refused: {err}");
+    }
+
+    let corridor_id = CorridorId(corridor.to_string());
+    if let Some(tracker) = orchestrator.eco_trend() {
+        println!("EcoImpact trend for corridor {corridor}:");
+        for sample in tracker.series(&corridor_id) {
+            let m = &sample.metrics;
+            println!(
+                "  {:?} climate={:.3} biodiversity={:.3} biosphere={:.3} corridor={:.3}",
+                sample.at, m.climate_score, m.biodiversity_score, m.biosphere_score, m.corridor_score
+            );
+        }
+        for dimension in EcoDimension::ALL {
+            if let Some(mean) = tracker.rolling_mean(&corridor_id, dimension) {
+                println!("  rolling mean {:?}: {:.3}", dimension, mean);
+            }
+        }
+    }
+
+    if let Some(err) = refusal {
+        std::process::exit(exit_code(&err));
+    }
+}
+
+/// Scores `corridors` (default: every corridor registered exactly) through
+/// the stub eco source and writes them to `path` for offline use; CSV for a
+/// `.csv` path, JSON otherwise.
+fn run_eco_export_snapshot(path: &str, corridors: &[&str]) {
+    let source = eco_source();
+    let corridors: Vec<CorridorId> = if corridors.is_empty() {
+        match corridor_registry() {
+            Ok(registry) => registry.corridor_ids(),
+            Err(err) => {
+                eprintln!("Cannot load eco source: {err}");
+                std::process::exit(1);
+            }
+        }
+    } else {
+        corridors.iter().map(|c| CorridorId(c.to_string())).collect()
+    };
+    let exported = EcoSnapshot::export(&source, &corridors, std::time::SystemTime::now())
+        .and_then(|snapshot| snapshot.write(std::path::Path::new(path)).map(|()| snapshot));
+    match exported {
+        Ok(snapshot) => println!(
+            "Exported {} corridor(s) from {} to {path} ({})",
+            snapshot.corridors.len(),
+            snapshot.source,
+            snapshot.export_date()
+        ),
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Reads `{"root": FpicToken, "tree": DelegationTree}` from `path` and prints
+/// the impact of revoking the root now.
+fn run_simulate_revoke(path: &str) {
+    let parsed = std::fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|text| serde_json::from_str::<serde_json::Value>(&text).map_err(|e| e.to_string()))
+        .and_then(|mut doc| {
+            let root: FpicToken =
+                serde_json::from_value(doc["root"].take()).map_err(|e| format!("root: {e}"))?;
+            let tree: DelegationTree =
+                serde_json::from_value(doc["tree"].take()).map_err(|e| format!("tree: {e}"))?;
+            Ok((root, tree))
+        });
+
+    let (root, tree) = match parsed {
+        Ok(v) => v,
+        Err(err) => {
+            eprintln!("Cannot read delegation tree {path}: {err}");
+            std::process::exit(1);
+        }
+    };
+
+    let impact = simulate_revocation(&root, &tree, std::time::SystemTime::now());
+    match serde_json::to_string_pretty(&impact) {
+        Ok(json) => println!("{json}"),
+        Err(err) => eprintln!("Cannot render revocation impact: {err}"),
+    }
+}
+
+/// Sweeps one `SncPolicySnapshot` field of the example proposal through the
+/// reference simulator and prints each step's outcome and the mean slopes.
+fn run_policy_sweep(param: &str, from: &str, to: &str, steps: &str) {
+    let parsed = (|| {
+        let param = param.parse()?;
+        let number = |arg: &str| arg.parse::<f32>().map_err(|e| format!("{arg:?}: {e}"));
+        let steps = steps.parse::<usize>().map_err(|e| format!("{steps:?}: {e}"))?;
+        let base = SncPolicySnapshot { min_knowledge_factor_open: 0.8, chat_issuance_slope: 1.0, eco_weight: 0.4 };
+        sensitivity_sweep(&LinearPolicySimulator::default(), &base, param, number(from)?..=number(to)?, steps)
+    })();
+    let sweep = match parsed {
+        Ok(sweep) => sweep,
+        Err(err) => {
+            eprintln!("Cannot sweep {param}: {err}");
+            std::process::exit(1);
+        }
+    };
+
+    println!("{:>25} {:>10} {:>10} {:>10}", sweep.param.name(), "risk", "justice", "trust");
+    for (value, o) in &sweep.points {
+        println!(
+            "{:>25.3} {:>10.3} {:>10.3} {:>10.3}",
+            value, o.expected_neurorights_risk, o.environmental_justice_score, o.trust_index
+        );
+    }
+    for (value, err) in &sweep.failures {
+        println!("{value:>25.3} failed: {err}");
+    }
+    if let Some(g) = &sweep.gradient {
+        println!(
+            "{:>25} {:>10.3} {:>10.3} {:>10.3}",
+            "d/dx", g.expected_neurorights_risk, g.environmental_justice_score, g.trust_index
+        );
+    }
+}
+
+/// Ranks a JSON array of `SncPolicySnapshot`s through the reference
+/// simulator, printing each candidate's outcome and, for dominated ones,
+/// which candidates dominate them and on which axes.
+fn run_policy_rank(path: &str) {
+    let parsed = std::fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|text| serde_json::from_str::<Vec<SncPolicySnapshot>>(&text).map_err(|e| e.to_string()))
+        .and_then(|candidates| rank_policies(&LinearPolicySimulator::default(), &candidates));
+    let ranking = match parsed {
+        Ok(ranking) => ranking,
+        Err(err) => {
+            eprintln!("Cannot rank candidates in {path}: {err}");
+            std::process::exit(1);
+        }
+    };
+
+    println!("{:>9} {:>10} {:>10} {:>10}", "candidate", "risk", "justice", "trust");
+    for c in &ranking.candidates {
+        let o = &c.outcome;
+        println!(
+            "{:>9} {:>10.3} {:>10.3} {:>10.3} {}",
+            c.index,
+            o.expected_neurorights_risk,
+            o.environmental_justice_score,
+            o.trust_index,
+            if c.is_pareto_optimal() { "pareto-optimal" } else { "dominated" }
+        );
+        for domination in &c.dominated_by {
+            println!("{:>9} {domination}", "");
+        }
+    }
+}
+
+/// Replays a saved `GateCase` under the case's own config, or under the JSON
+/// `BioRailConfig` given with `--config <path>`. A config whose hash differs
+/// from the one the case was recorded under is refused unless
+/// `--cross-config` is given.
+fn run_gate_replay(path: &str, flags: &[&str]) {
+    let (config_path, cross_config) = match flags {
+        [] => (None, false),
+        ["--cross-config"] => (None, true),
+        ["--config", config] => (Some(*config), false),
+        ["--config", config, "--cross-config"] | ["--cross-config", "--config", config] => (Some(*config), true),
+        _ => {
+            eprintln!("usage: gate replay <case.json> [--config <biorail.json>] [--cross-config]");
+            std::process::exit(1);
+        }
+    };
+    let replayed = GateCase::load_json(path)
+        .map_err(|e| format!("Cannot read gate case {path}: {e}"))
+        .and_then(|case| {
+            let config = match config_path {
+                Some(config_path) => load_biorail_config(config_path)?,
+                None => case.config.clone(),
+            };
+            case.replay_under(&config, cross_config).map(|report| (case, report))
+        });
+    let (case, report) = match replayed {
+        Ok(replayed) => replayed,
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
+    };
+
+    println!("recorded: {:?}", case.report.verdict);
+    println!("replayed: {:?}", report.verdict);
+    for violation in &report.violated_constraints {
+        println!("  {violation:?}");
+    }
+    // Load time and path of the config version are not part of the decision.
+    let recorded = GateReport { config_version: report.config_version.clone(), ..case.report };
+    if report != recorded {
+        println!("replayed report differs from the recorded one");
+    }
+}
+
+/// Reads a JSON `BioRailConfig` and stamps it with the bundle's version.
+fn load_biorail_config(path: &str) -> Result<BioRailConfig, String> {
+    let bundle = ConfigBundle::read(std::path::Path::new(path)).map_err(|e| format!("Cannot read config {path}: {e}"))?;
+    let mut config: BioRailConfig =
+        serde_json::from_slice(&bundle.bytes).map_err(|e| format!("Cannot read config {path}: {e}"))?;
+    config.config_version = Some(bundle.version(std::time::SystemTime::now())?);
+    Ok(config)
+}
+
+/// Probes the backends this agent is wired to and exits non-zero when the
+/// node is not ready.
+fn run_health() {
+    let eco_source = eco_source();
+    let eco_probe = EcoSourceProbe {
+        source: &eco_source,
+        sample: demo_artifact("protected-desert-phoenix"),
+        slow_after: std::time::Duration::from_millis(500),
+    };
+
+    let report = health::report(&[&eco_probe]);
+    for component in &report.components {
+        println!("{:<40} {:?} {}", component.component, component.status, component.detail);
+    }
+    println!("overall: {:?}", report.overall);
+
+    if !report.is_ready() {
+        std::process::exit(1);
+    }
+}
+
+/// Prints the locally stored daily aggregates.
+fn run_telemetry_show() {
+    let config = telemetry_config();
+    println!(
+        "telemetry {} ({})",
+        if config.enabled { "enabled" } else { "disabled" },
+        config.dir.display()
+    );
+    let aggregates = match telemetry::load_aggregates(&config.dir) {
+        Ok(a) => a,
+        Err(err) => {
+            eprintln!("Cannot read telemetry: {err}");
+            std::process::exit(1);
+        }
+    };
+    for aggregate in &aggregates {
+        let integrity = if aggregate.verify() { "ok" } else { "HASH MISMATCH" };
+        println!("{} [{}] {}", aggregate.day, integrity, aggregate.content_hash);
+        for (counter, count) in &aggregate.counters {
+            println!("  {:<32} {}", format!("{counter:?}"), count);
+        }
+        for (histogram, counts) in &aggregate.histograms {
+            println!(
+                "  {:<32} n={} sum_ms={} buckets={:?}",
+                format!("{histogram:?}"),
+                counts.total,
+                counts.sum_ms,
+                counts.counts
+            );
+        }
+    }
+}
+
+/// Deletes every stored daily aggregate.
+fn run_telemetry_purge() {
+    let config = telemetry_config();
+    match telemetry::purge(&config.dir) {
+        Ok(removed) => println!("Purged {removed} telemetry aggregate(s) from {}", config.dir.display()),
+        Err(err) => {
+            eprintln!("Cannot purge telemetry: {err}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Imports a legacy CSV/JSON deed export into a fresh ledger using the
+/// default column mapping and prints what was converted, skipped and
+/// reordered.
+fn run_backfill(path: &str, dry_run: bool) {
+    let source = match BackfillSource::read(std::path::Path::new(path), ColumnMapping::default()) {
+        Ok(source) => source,
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
+    };
+    let runtime = match tokio::runtime::Builder::new_current_thread().build() {
+        Ok(runtime) => runtime,
+        Err(err) => {
+            eprintln!("Cannot start runtime: {err}");
+            std::process::exit(1);
+        }
+    };
+
+    let config = Config::default();
+    let ledger = Ledger::new(config.clone());
+    let tree = TreeOfLife::new(ledger.clone(), config);
+    let opts = BackfillOptions {
+        dry_run,
+        guard_baseline: true,
+        ..BackfillOptions::default()
+    };
+
+    let report = match runtime.block_on(backfill::run(source, &ledger, &tree, opts)) {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("Backfill failed: {err}");
+            std::process::exit(1);
+        }
+    };
+
+    println!(
+        "{} {} deed(s), skipped {}, reordered {}{}",
+        if report.dry_run { "Would append" } else { "Appended" },
+        report.converted.len(),
+        report.skipped.len(),
+        report.repairs.len(),
+        if report.dry_run { " (dry run)" } else { "" }
+    );
+    for skipped in &report.skipped {
+        println!("  skipped row {}: {}", skipped.row, skipped.reason);
+    }
+    for repair in &report.repairs {
+        println!(
+            "  moved row {} (t={}) ahead of t={}",
+            repair.row, repair.timestamp, repair.preceding_timestamp
+        );
+    }
+    if report.dry_run {
+        return;
+    }
+
+    let metrics = runtime.block_on(ledger.compute_metrics());
+    println!(
+        "ledger: {} events, {} good, {} harm, {} CHURCH, moral score {}",
+        metrics.total_events, metrics.good_deeds, metrics.harm_flags, metrics.balance.church_tokens, report.moral_score
+    );
+    if let Some(baseline) = &report.guard_baseline {
+        println!(
+            "guard baseline: harm ratio {:.2}, ethics flags/deed {:.2}, predicates {:?}",
+            baseline.harm_ratio, baseline.ethics_flags_per_deed, baseline.micro_society.predicates
+        );
+    }
+}
+
+/// Imports a legacy deed export into a fresh ledger and prints its metrics as
+/// JSON. With `dp` = (epsilon, seed file) the metrics are noised by
+/// `dp_metrics` under a freshly drawn `DpSeed`, which is written to the seed
+/// file (never overwritten) for a later audit of the published commitment.
+fn run_ledger_export(path: &str, dp: Option<(&str, &str)>) {
+    let exported = (|| {
+        let source = BackfillSource::read(std::path::Path::new(path), ColumnMapping::default())?;
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .map_err(|e| format!("Cannot start runtime: {e}"))?;
+        let config = Config::default();
+        let ledger = Ledger::new(config.clone());
+        let tree = TreeOfLife::new(ledger.clone(), config);
+        runtime.block_on(backfill::run(source, &ledger, &tree, BackfillOptions::default()))?;
+        let metrics = runtime.block_on(ledger.compute_metrics());
+
+        let Some((epsilon, seed_file)) = dp else {
+            return serde_json::to_string_pretty(&metrics).map_err(|e| e.to_string());
+        };
+        let epsilon = epsilon.parse::<f64>().map_err(|e| format!("--dp-epsilon {epsilon:?}: {e}"))?;
+        let seed = DpSeed::generate()?;
+        let noised = dp_metrics(&metrics, epsilon, &seed)?;
+        let seed_json = serde_json::to_vec(&seed).map_err(|e| e.to_string())?;
+        std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(seed_file)
+            .and_then(|mut file| std::io::Write::write_all(&mut file, &seed_json))
+            .map_err(|e| format!("Cannot write seed file {seed_file}: {e}"))?;
+        serde_json::to_string_pretty(&noised).map_err(|e| e.to_string())
+    })();
+    match exported {
+        Ok(json) => println!("{json}"),
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
     }
 }
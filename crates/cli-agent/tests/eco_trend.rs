@@ -0,0 +1,49 @@
+//! `eco trend` keeps its tracker between runs, so a corridor's series grows
+//! one sample per invocation and drift is seen across them.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn scratch_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("cli-agent-eco-trend-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Runs `eco trend` against a table scoring every dimension at `score`.
+fn eco_trend(dir: &Path, score: f32) -> String {
+    let table = dir.join("eco-table.toml");
+    std::fs::write(
+        &table,
+        format!(
+            "[default]\nclimate_score = 0.9\nbiodiversity_score = {score}\nbiosphere_score = 0.9\ncorridor_score = 0.9\n"
+        ),
+    )
+    .unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_cli-agent"))
+        .args(["eco", "trend", "protected-desert-phoenix", "--eco-table"])
+        .arg(&table)
+        .env("MORPHIX_ECO_TREND_STATE", dir.join("state/eco-trend.json"))
+        .env_remove("MORPHIX_CORRIDOR_REGISTRY")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn samples_accumulate_across_runs_and_drift_alerts_once() {
+    let dir = scratch_dir();
+    let runs: Vec<String> = (0..10).map(|i| eco_trend(&dir, 0.9 - 0.05 * i as f32)).collect();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let samples = |out: &str| out.lines().filter(|l| l.contains(" climate=")).count();
+    assert_eq!(samples(&runs[0]), 1);
+    assert_eq!(samples(&runs[9]), 10);
+
+    let alerts: Vec<&str> = runs.iter().flat_map(|out| out.lines()).filter(|l| l.starts_with("ALERT")).collect();
+    assert_eq!(alerts.len(), 1, "{alerts:?}");
+    assert!(alerts[0].starts_with("ALERT corridor=protected-desert-phoenix dimension=Biodiversity"));
+    assert!(runs[7].contains("ALERT"));
+}
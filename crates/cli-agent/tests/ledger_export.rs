@@ -0,0 +1,53 @@
+//! `ledger export` prints exact metrics, or noised ones whose seed commitment
+//! verifies against the seed file it writes.
+
+use std::process::Command;
+
+use morphix::ledger::{DpMetrics, DpSeed};
+
+const DEEDS: &str = "legacy_id,timestamp,actor_id,deed_type,target_ids,tags,life_harm,ethics_flags
+L-001,1600000000,alice,ecological_sustainability,,,false,
+L-002,1600003600,bob,homelessness_relief,,,false,
+L-003,1600007200,alice,ecological_sustainability,,,true,RoH_breach
+";
+
+fn ledger_export(args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_cli-agent"))
+        .args(["ledger", "export"])
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn dp_export_commits_to_the_written_seed() {
+    let dir = std::env::temp_dir().join(format!("cli-agent-ledger-export-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    let deeds = dir.join("deeds.csv");
+    std::fs::write(&deeds, DEEDS).unwrap();
+    let deeds = deeds.to_str().unwrap();
+    let seed_file = dir.join("dp-seed.json");
+    let seed_file = seed_file.to_str().unwrap();
+
+    let exact = ledger_export(&[deeds]);
+    assert!(exact.status.success(), "{}", String::from_utf8_lossy(&exact.stderr));
+    let exact: serde_json::Value = serde_json::from_slice(&exact.stdout).unwrap();
+    assert_eq!(exact["total_events"], 3);
+    assert_eq!(exact["harm_flags"], 1);
+
+    let noised = ledger_export(&[deeds, "--dp-epsilon", "0.5", "--dp-seed-file", seed_file]);
+    assert!(noised.status.success(), "{}", String::from_utf8_lossy(&noised.stderr));
+    let noised: DpMetrics = serde_json::from_slice(&noised.stdout).unwrap();
+    let seed: DpSeed = serde_json::from_slice(&std::fs::read(seed_file).unwrap()).unwrap();
+    assert_eq!(noised.report.epsilon, 0.5);
+    assert!(noised.report.verify_seed(&seed));
+
+    // The seed file is never overwritten, and a bad epsilon is refused.
+    let again = ledger_export(&[deeds, "--dp-epsilon", "0.5", "--dp-seed-file", seed_file]);
+    assert!(!again.status.success());
+    std::fs::remove_file(seed_file).unwrap();
+    let zero = ledger_export(&[deeds, "--dp-epsilon", "0", "--dp-seed-file", seed_file]);
+    assert!(String::from_utf8_lossy(&zero.stderr).contains("epsilon must be positive and finite"));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -1,3 +1,10 @@
+use alloc::string::String;
+
+/// OCAP / CARE aligned community identifier.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CommunityId(pub String);
+
 #[derive(Clone, Debug)]
 pub struct CareAttestation {
     pub collective_benefit: bool,
@@ -0,0 +1,150 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{SncPredicate, SovereignNeuromorphContract};
+
+type Member = (String, Box<dyn SovereignNeuromorphContract + Send + Sync>);
+
+/// AND of several independently owned contracts (institutional, community,
+/// individual consent): each predicate holds only if it holds for every
+/// member. A composite with no members satisfies nothing.
+#[derive(Default)]
+pub struct CompositeContract {
+    members: Vec<Member>,
+}
+
+impl CompositeContract {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `contract` under `name`, which refusals report when it fails.
+    pub fn with_member(
+        mut self,
+        name: impl Into<String>,
+        contract: impl SovereignNeuromorphContract + Send + Sync + 'static,
+    ) -> Self {
+        self.members.push((name.into(), Box::new(contract)));
+        self
+    }
+
+    /// Member names in the order they were added.
+    pub fn member_names(&self) -> impl Iterator<Item = &str> {
+        self.members.iter().map(|(name, _)| name.as_str())
+    }
+
+    fn all(&self, predicate: SncPredicate) -> bool {
+        !self.members.is_empty()
+            && self
+                .members
+                .iter()
+                .all(|(_, contract)| predicate.holds(contract.as_ref()))
+    }
+}
+
+impl SovereignNeuromorphContract for CompositeContract {
+    fn has_explicit_consent(&self) -> bool {
+        self.all(SncPredicate::ExplicitConsent)
+    }
+
+    fn has_sovereign_abort_control(&self) -> bool {
+        self.all(SncPredicate::SovereignAbortControl)
+    }
+
+    fn is_discipline_personalized_and_non_coercive(&self) -> bool {
+        self.all(SncPredicate::NonCoerciveDiscipline)
+    }
+
+    fn forbids_downgrade_or_rollback(&self) -> bool {
+        self.all(SncPredicate::ForbidsDowngradeOrRollback)
+    }
+
+    /// The first member, in insertion order, that fails `predicate`.
+    fn failing_member(&self, predicate: SncPredicate) -> Option<&str> {
+        self.members
+            .iter()
+            .find(|(_, contract)| !predicate.holds(contract.as_ref()))
+            .map(|(name, _)| name.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DefaultSovereignNeuromorphContract;
+
+    /// A member that tolerates rollbacks.
+    struct PermitsRollback;
+
+    impl SovereignNeuromorphContract for PermitsRollback {
+        fn has_explicit_consent(&self) -> bool {
+            true
+        }
+
+        fn has_sovereign_abort_control(&self) -> bool {
+            true
+        }
+
+        fn is_discipline_personalized_and_non_coercive(&self) -> bool {
+            true
+        }
+
+        fn forbids_downgrade_or_rollback(&self) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn one_permissive_member_fails_the_composite() {
+        let composite = CompositeContract::new()
+            .with_member(
+                "institutional",
+                DefaultSovereignNeuromorphContract::new(true, true, true),
+            )
+            .with_member("community", PermitsRollback);
+
+        assert!(composite.has_explicit_consent());
+        assert!(!composite.forbids_downgrade_or_rollback());
+        assert_eq!(
+            composite.failing_member(SncPredicate::ForbidsDowngradeOrRollback),
+            Some("community")
+        );
+        assert_eq!(
+            composite.failing_member(SncPredicate::ExplicitConsent),
+            None
+        );
+    }
+
+    #[test]
+    fn first_failing_member_is_reported() {
+        let composite = CompositeContract::new()
+            .with_member(
+                "institutional",
+                DefaultSovereignNeuromorphContract::new(true, true, true),
+            )
+            .with_member(
+                "individual",
+                DefaultSovereignNeuromorphContract::new(false, true, true),
+            )
+            .with_member(
+                "community",
+                DefaultSovereignNeuromorphContract::new(false, true, true),
+            );
+        assert!(!composite.has_explicit_consent());
+        assert_eq!(
+            composite.failing_member(SncPredicate::ExplicitConsent),
+            Some("individual")
+        );
+        assert_eq!(
+            composite.member_names().collect::<Vec<_>>(),
+            ["institutional", "individual", "community"]
+        );
+    }
+
+    #[test]
+    fn empty_composite_satisfies_nothing() {
+        let empty = CompositeContract::new();
+        assert!(SncPredicate::ALL.iter().all(|p| !p.holds(&empty)));
+    }
+}
@@ -0,0 +1,42 @@
+use std::time::SystemTime;
+
+/// Identity of a loaded configuration bundle, embedded in decision records
+/// so any decision can be traced back to the exact thresholds in force.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConfigVersion {
+    /// Hex digest of the canonicalized bundle; independent of key order.
+    pub hash: String,
+    pub loaded_at: SystemTime,
+    pub source_path: Option<String>,
+}
+
+impl ConfigVersion {
+    pub fn new(hash: impl Into<String>, loaded_at: SystemTime, source_path: Option<String>) -> Self {
+        Self {
+            hash: hash.into(),
+            loaded_at,
+            source_path,
+        }
+    }
+
+    /// Two versions describe the same configuration when their hashes match,
+    /// regardless of when or from where they were loaded.
+    pub fn same_config(&self, other: &ConfigVersion) -> bool {
+        self.hash == other.hash
+    }
+
+    /// Replay guard: a decision recorded under `self` may only be replayed
+    /// under a different config when the caller opts in with `cross_config`
+    /// (the `--cross-config` flag).
+    pub fn ensure_comparable(&self, replay: &ConfigVersion, cross_config: bool) -> Result<(), String> {
+        if self.same_config(replay) || cross_config {
+            Ok(())
+        } else {
+            Err(format!(
+                "Config mismatch: recorded under {} but replaying under {}; pass --cross-config to compare anyway.",
+                self.hash, replay.hash
+            ))
+        }
+    }
+}
@@ -1,9 +1,25 @@
-#[derive(Clone, Debug)]
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::labels::DataLabels;
+
+/// Default cap on `NeuromorphArtifact::summary`, in characters.
+pub const DEFAULT_MAX_SUMMARY_LEN: usize = 16_384;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct CorridorId(pub String);
 
 /// Structured EcoImpact metrics used by SNC and CHAT scoring.
 /// Values are normalized to [0,1], where 1.0 means best / least harm.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EcoImpactMetrics {
     /// Energy / carbon efficiency (1.0 = minimal emissions per useful work).
     pub climate_score: f32,
@@ -24,12 +40,432 @@ impl EcoImpactMetrics {
     }
 }
 
+/// One `EcoImpactMetrics` value with the reason it came out that way.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MetricExplanation {
+    pub value: f32,
+    pub explanation: String,
+    /// Datasets the value was derived from, e.g. "gbif:dataset/…".
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sources: Vec<String>,
+}
+
+/// Where a set of eco metrics came from: the source's label and, when the
+/// source knows them, the dataset version and when the data was fetched.
+/// Displays as e.g. "gbif (dataset v3, fetched 2025-11-02)".
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProvenanceInfo {
+    pub label: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dataset_version: Option<String>,
+    /// Unix seconds at which the underlying data was fetched upstream, not
+    /// when it was last served.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub fetched_at: Option<u64>,
+}
+
+impl ProvenanceInfo {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            dataset_version: None,
+            fetched_at: None,
+        }
+    }
+
+    pub fn with_dataset_version(mut self, version: impl Into<String>) -> Self {
+        self.dataset_version = Some(version.into());
+        self
+    }
+
+    pub fn with_fetched_at(mut self, unix_secs: u64) -> Self {
+        self.fetched_at = Some(unix_secs);
+        self
+    }
+}
+
+impl From<&str> for ProvenanceInfo {
+    fn from(label: &str) -> Self {
+        Self::new(label)
+    }
+}
+
+impl From<String> for ProvenanceInfo {
+    fn from(label: String) -> Self {
+        Self::new(label)
+    }
+}
+
+impl fmt::Display for ProvenanceInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label)?;
+        match (&self.dataset_version, self.fetched_at) {
+            (None, None) => Ok(()),
+            (Some(version), None) => write!(f, " (dataset {version})"),
+            (None, Some(at)) => write!(f, " (fetched {})", utc_date(at)),
+            (Some(version), Some(at)) => write!(f, " (dataset {version}, fetched {})", utc_date(at)),
+        }
+    }
+}
+
+/// `YYYY-MM-DD` for the UTC day containing `unix_secs`.
+fn utc_date(unix_secs: u64) -> String {
+    let days = (unix_secs / 86_400) as i64;
+    // Civil-from-days (Howard Hinnant), valid for the proleptic Gregorian calendar.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// `EcoImpactMetrics` with a `MetricExplanation` per metric, for showing
+/// corridor stewards why a score is what it is.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EcoImpactReport {
+    pub climate: MetricExplanation,
+    pub biodiversity: MetricExplanation,
+    pub biosphere: MetricExplanation,
+    pub corridor: MetricExplanation,
+    /// Provenance of the source that produced the report.
+    pub provenance: String,
+    /// See `ProvenanceInfo::dataset_version`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dataset_version: Option<String>,
+    /// See `ProvenanceInfo::fetched_at`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub fetched_at: Option<u64>,
+}
+
+impl EcoImpactReport {
+    /// A report for a source that gives no reasons: each metric is
+    /// explained only by where it came from.
+    pub fn generic(metrics: &EcoImpactMetrics, provenance: impl Into<ProvenanceInfo>) -> Self {
+        let provenance = provenance.into();
+        let explain = |value| MetricExplanation {
+            value,
+            explanation: format!("reported by {} without an explanation", provenance.label),
+            sources: Vec::new(),
+        };
+        Self {
+            climate: explain(metrics.climate_score),
+            biodiversity: explain(metrics.biodiversity_score),
+            biosphere: explain(metrics.biosphere_score),
+            corridor: explain(metrics.corridor_score),
+            provenance: provenance.label,
+            dataset_version: provenance.dataset_version,
+            fetched_at: provenance.fetched_at,
+        }
+    }
+
+    pub fn provenance_info(&self) -> ProvenanceInfo {
+        ProvenanceInfo {
+            label: self.provenance.clone(),
+            dataset_version: self.dataset_version.clone(),
+            fetched_at: self.fetched_at,
+        }
+    }
+
+    /// Replace the provenance label, dataset version and fetch time.
+    pub fn with_provenance(mut self, provenance: ProvenanceInfo) -> Self {
+        self.provenance = provenance.label;
+        self.dataset_version = provenance.dataset_version;
+        self.fetched_at = provenance.fetched_at;
+        self
+    }
+
+    pub fn metrics(&self) -> EcoImpactMetrics {
+        EcoImpactMetrics {
+            climate_score: self.climate.value,
+            biodiversity_score: self.biodiversity.value,
+            biosphere_score: self.biosphere.value,
+            corridor_score: self.corridor.value,
+        }
+    }
+
+    /// Each metric under its `EcoImpactMetrics` field name.
+    pub fn entries(&self) -> [(&'static str, &MetricExplanation); 4] {
+        [
+            ("climate_score", &self.climate),
+            ("biodiversity_score", &self.biodiversity),
+            ("biosphere_score", &self.biosphere),
+            ("corridor_score", &self.corridor),
+        ]
+    }
+}
+
+impl fmt::Display for EcoImpactReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "EcoImpact report ({})", self.provenance_info())?;
+        for (name, metric) in self.entries() {
+            write!(f, "\n  {name:<18} {:.3}  {}", metric.value, metric.explanation)?;
+            if !metric.sources.is_empty() {
+                write!(f, "\n  {:<18}        sources: {}", "", metric.sources.join(", "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Core SNC artifact; every contribution must declare corridor + EcoImpact.[file:69]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        into = "crate::schema::ArtifactDocument",
+        try_from = "crate::schema::ArtifactDocument"
+    )
+)]
 pub struct NeuromorphArtifact {
     pub id: String,
     pub corridor_id: CorridorId,
     pub eco_impact: EcoImpactMetrics,
     /// Plaintext or structured representation of the content.
     pub summary: String,
+    /// Licensing and data-sovereignty labels; immutable once attached.
+    pub data_labels: DataLabels,
+}
+
+/// One reason `NeuromorphArtifact::validate` rejected an artifact.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ArtifactViolation {
+    EmptyId,
+    EmptyCorridor,
+    /// Corridor ids are slugs; whitespace and control characters are
+    /// rejected.
+    MalformedCorridor(String),
+    EmptySummary,
+    SummaryTooLong { len: usize, max: usize },
+    /// `metric` names the `EcoImpactMetrics` field; NaN is out of range.
+    EcoMetricOutOfRange { metric: &'static str, value: f32 },
+}
+
+impl fmt::Display for ArtifactViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyId => f.write_str("artifact id is empty"),
+            Self::EmptyCorridor => f.write_str("corridor id is empty"),
+            Self::MalformedCorridor(id) => write!(f, "corridor id {id:?} is malformed"),
+            Self::EmptySummary => f.write_str("summary is empty"),
+            Self::SummaryTooLong { len, max } => {
+                write!(f, "summary is {len} characters, over the cap of {max}")
+            }
+            Self::EcoMetricOutOfRange { metric, value } => {
+                write!(f, "{metric} = {value} is outside [0, 1]")
+            }
+        }
+    }
+}
+
+impl NeuromorphArtifact {
+    /// Checks the artifact before it reaches any scoring: non-empty id,
+    /// corridor and summary, a summary of at most `max_summary_len`
+    /// characters, and every eco metric within [0, 1]. Reports all
+    /// violations, not just the first.
+    pub fn validate(&self, max_summary_len: usize) -> Result<(), Vec<ArtifactViolation>> {
+        let mut violations = Vec::new();
+        if self.id.is_empty() {
+            violations.push(ArtifactViolation::EmptyId);
+        }
+        let corridor = &self.corridor_id.0;
+        if corridor.is_empty() {
+            violations.push(ArtifactViolation::EmptyCorridor);
+        } else if corridor.chars().any(|c| c.is_whitespace() || c.is_control()) {
+            violations.push(ArtifactViolation::MalformedCorridor(corridor.clone()));
+        }
+        if self.summary.is_empty() {
+            violations.push(ArtifactViolation::EmptySummary);
+        } else {
+            let len = self.summary.chars().count();
+            if len > max_summary_len {
+                violations.push(ArtifactViolation::SummaryTooLong {
+                    len,
+                    max: max_summary_len,
+                });
+            }
+        }
+        let eco = &self.eco_impact;
+        for (metric, value) in [
+            ("climate_score", eco.climate_score),
+            ("biodiversity_score", eco.biodiversity_score),
+            ("biosphere_score", eco.biosphere_score),
+            ("corridor_score", eco.corridor_score),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                violations.push(ArtifactViolation::EcoMetricOutOfRange { metric, value });
+            }
+        }
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    fn artifact() -> NeuromorphArtifact {
+        NeuromorphArtifact {
+            id: "a-1".into(),
+            corridor_id: CorridorId("phoenix-canal".into()),
+            eco_impact: EcoImpactMetrics {
+                climate_score: 0.9,
+                biodiversity_score: 1.0,
+                biosphere_score: 0.0,
+                corridor_score: 0.5,
+            },
+            summary: "Canal shade survey".into(),
+            data_labels: DataLabels::default(),
+        }
+    }
+
+    fn violations(artifact: &NeuromorphArtifact) -> Vec<ArtifactViolation> {
+        artifact.validate(DEFAULT_MAX_SUMMARY_LEN).unwrap_err()
+    }
+
+    #[test]
+    fn well_formed_artifact_passes() {
+        assert_eq!(artifact().validate(DEFAULT_MAX_SUMMARY_LEN), Ok(()));
+    }
+
+    #[test]
+    fn empty_id_is_rejected() {
+        let mut a = artifact();
+        a.id.clear();
+        assert_eq!(violations(&a), [ArtifactViolation::EmptyId]);
+    }
+
+    #[test]
+    fn empty_and_malformed_corridors_are_rejected() {
+        let mut a = artifact();
+        a.corridor_id.0.clear();
+        assert_eq!(violations(&a), [ArtifactViolation::EmptyCorridor]);
+
+        a.corridor_id.0 = "phoenix canal".into();
+        assert_eq!(
+            violations(&a),
+            [ArtifactViolation::MalformedCorridor("phoenix canal".into())]
+        );
+        a.corridor_id.0 = "phoenix-canal\n".into();
+        assert!(matches!(violations(&a)[..], [ArtifactViolation::MalformedCorridor(_)]));
+    }
+
+    #[test]
+    fn empty_summary_is_rejected() {
+        let mut a = artifact();
+        a.summary.clear();
+        assert_eq!(violations(&a), [ArtifactViolation::EmptySummary]);
+    }
+
+    #[test]
+    fn summary_cap_counts_characters() {
+        let mut a = artifact();
+        a.summary = "é".repeat(8);
+        assert_eq!(a.validate(8), Ok(()));
+        assert_eq!(
+            a.validate(7).unwrap_err(),
+            [ArtifactViolation::SummaryTooLong { len: 8, max: 7 }]
+        );
+    }
+
+    #[test]
+    fn eco_metrics_outside_unit_interval_or_nan_are_rejected() {
+        let mut a = artifact();
+        a.eco_impact.climate_score = -0.1;
+        assert_eq!(
+            violations(&a),
+            [ArtifactViolation::EcoMetricOutOfRange { metric: "climate_score", value: -0.1 }]
+        );
+
+        let mut a = artifact();
+        a.eco_impact.corridor_score = 1.5;
+        assert_eq!(
+            violations(&a),
+            [ArtifactViolation::EcoMetricOutOfRange { metric: "corridor_score", value: 1.5 }]
+        );
+
+        let mut a = artifact();
+        a.eco_impact.biosphere_score = f32::NAN;
+        assert!(matches!(
+            violations(&a)[..],
+            [ArtifactViolation::EcoMetricOutOfRange { metric: "biosphere_score", value }] if value.is_nan()
+        ));
+    }
+
+    #[test]
+    fn report_round_trips_metrics_and_renders_a_block() {
+        let metrics = artifact().eco_impact;
+        let mut report = EcoImpactReport::generic(&metrics, "fixed");
+        assert_eq!(report.metrics(), metrics);
+        report.biodiversity.explanation = "no red-listed taxa recorded".into();
+        report.biodiversity.sources = vec!["gbif:dataset/a".into(), "gbif:taxon/5".into()];
+        assert_eq!(
+            report.to_string(),
+            "EcoImpact report (fixed)\n\
+             \x20 climate_score      0.900  reported by fixed without an explanation\n\
+             \x20 biodiversity_score 1.000  no red-listed taxa recorded\n\
+             \x20                           sources: gbif:dataset/a, gbif:taxon/5\n\
+             \x20 biosphere_score    0.000  reported by fixed without an explanation\n\
+             \x20 corridor_score     0.500  reported by fixed without an explanation"
+        );
+    }
+
+    #[test]
+    fn provenance_info_renders_version_and_fetch_date() {
+        assert_eq!(ProvenanceInfo::new("gbif").to_string(), "gbif");
+        let info = ProvenanceInfo::new("gbif")
+            .with_dataset_version("v3")
+            .with_fetched_at(1_762_041_600);
+        assert_eq!(info.to_string(), "gbif (dataset v3, fetched 2025-11-02)");
+        assert_eq!(
+            ProvenanceInfo::new("stac").with_fetched_at(0).to_string(),
+            "stac (fetched 1970-01-01)"
+        );
+
+        let report = EcoImpactReport::generic(&artifact().eco_impact, info.clone());
+        assert_eq!(report.provenance_info(), info);
+        assert!(report
+            .to_string()
+            .starts_with("EcoImpact report (gbif (dataset v3, fetched 2025-11-02))\n"));
+        assert_eq!(report.climate.explanation, "reported by gbif without an explanation");
+    }
+
+    #[test]
+    fn every_violation_is_reported_together() {
+        let mut a = artifact();
+        a.id.clear();
+        a.corridor_id.0 = "phoenix\tcanal".into();
+        a.summary = "x".repeat(20);
+        a.eco_impact.biodiversity_score = f32::INFINITY;
+        a.eco_impact.climate_score = -1.0;
+        let found = a.validate(10).unwrap_err();
+        assert_eq!(
+            found,
+            vec![
+                ArtifactViolation::EmptyId,
+                ArtifactViolation::MalformedCorridor("phoenix\tcanal".into()),
+                ArtifactViolation::SummaryTooLong { len: 20, max: 10 },
+                ArtifactViolation::EcoMetricOutOfRange { metric: "climate_score", value: -1.0 },
+                ArtifactViolation::EcoMetricOutOfRange {
+                    metric: "biodiversity_score",
+                    value: f32::INFINITY
+                },
+            ]
+        );
+        assert_eq!(found[0].to_string(), "artifact id is empty");
+    }
 }
@@ -1,5 +1,7 @@
 use std::fmt;
 
+use crate::eco::MetricExplanation;
+
 /// Minimal ecological context passed into all impact scorers.
 /// This stays abstract but is shaped for STAC-like EO plus
 /// biodiversity attributes.[web:148]
@@ -60,7 +62,7 @@ pub trait AuditableScorer: ExplainableScorer {
 
 /// Sealed pattern to keep external crates from implementing the
 /// marker traits directly; they implement concrete structs instead.
-mod sealed {
+pub(crate) mod sealed {
     pub trait Sealed {}
 }
 use sealed::Sealed;
@@ -84,6 +86,18 @@ pub trait EcoImpactAdapter: Send + Sync {
 
     /// Compute an impact score for the given context.
     fn compute_impact(&self, ctx: &EcoContext) -> ImpactScore;
+
+    /// `compute_impact` as a `MetricExplanation`. The default cites only
+    /// `ctx.dataset_id`; adapters that know their dataset references
+    /// override it.
+    fn explain_impact(&self, ctx: &EcoContext) -> MetricExplanation {
+        let score = self.compute_impact(ctx);
+        MetricExplanation {
+            value: score.value,
+            explanation: score.explanation,
+            sources: vec![ctx.dataset_id.clone()],
+        }
+    }
 }
 
 /// Main trait-object type used by AI-chat and orchestration code.
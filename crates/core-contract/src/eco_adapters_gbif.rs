@@ -1,3 +1,4 @@
+use crate::eco::MetricExplanation;
 use crate::eco_adapter::{EcoContext, EcoImpactAdapter, ImpactScore};
 use crate::eco_adapter::sealed::Sealed;
 
@@ -22,31 +23,76 @@ impl EcoImpactAdapter for GbifRiskAdapter {
     }
 
     fn compute_impact(&self, ctx: &EcoContext) -> ImpactScore {
+        let explained = self.explain_impact(ctx);
+        ImpactScore::clamped(explained.value, explained.explanation)
+    }
+
+    /// Biodiversity sensitivity, citing the GBIF dataset and, when given,
+    /// the taxon it was assessed for.
+    fn explain_impact(&self, ctx: &EcoContext) -> MetricExplanation {
         // In a real implementation, you would:
         // 1. Call a GBIF client with ctx.taxon_or_feature and region_hint.
         // 2. Aggregate occurrences / red-list categories.
         // 3. Map to a normalized risk score in [0,1].[file:71]
+        let mut sources = vec![format!("gbif:dataset/{}", ctx.dataset_id)];
+        if let Some(taxon) = &ctx.taxon_or_feature {
+            sources.push(format!("gbif:taxon/{taxon}"));
+        }
 
         // Placeholder: high score if we have a taxon + region, else neutral.
-        let has_specific_target =
-            ctx.taxon_or_feature.is_some() && ctx.region_hint.is_some();
-
-        if has_specific_target {
-            ImpactScore::clamped(
+        let (value, explanation) = match (&ctx.taxon_or_feature, &ctx.region_hint) {
+            (Some(taxon), Some(region)) => (
                 0.9,
                 format!(
-                    "High biodiversity sensitivity inferred for dataset={} taxon={:?} region={:?} (stub).",
-                    ctx.dataset_id, ctx.taxon_or_feature, ctx.region_hint
+                    "biodiversity: high sensitivity assumed for taxon {taxon} in region {region} \
+                     (stub; would flag {} or more occurrences)",
+                    self.high_risk_threshold
                 ),
-            )
-        } else {
-            ImpactScore::clamped(
+            ),
+            (None, _) => (
                 0.5,
-                format!(
-                    "Neutral biodiversity impact for dataset={} (insufficient GBIF context, stub).",
-                    ctx.dataset_id
-                ),
-            )
+                "biodiversity: neutral, no taxon to look up occurrences for (stub)".to_string(),
+            ),
+            (Some(taxon), None) => (
+                0.5,
+                format!("biodiversity: neutral, taxon {taxon} given without a region (stub)"),
+            ),
+        };
+        MetricExplanation {
+            value,
+            explanation,
+            sources,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctx(taxon: Option<&str>, region: Option<&str>) -> EcoContext {
+        EcoContext {
+            dataset_id: "d-1".into(),
+            region_hint: region.map(Into::into),
+            taxon_or_feature: taxon.map(Into::into),
+            raw_metadata: None,
+        }
+    }
+
+    #[test]
+    fn explanation_cites_dataset_and_taxon() {
+        let adapter = GbifRiskAdapter::new(25);
+        let high = adapter.explain_impact(&ctx(Some("2435099"), Some("9q5c")));
+        assert_eq!(high.value, 0.9);
+        assert_eq!(high.sources, ["gbif:dataset/d-1", "gbif:taxon/2435099"]);
+        assert!(high.explanation.contains("25 or more occurrences"));
+
+        let neutral = adapter.explain_impact(&ctx(Some("2435099"), None));
+        assert_eq!(neutral.value, 0.5);
+        assert!(neutral.explanation.contains("without a region"));
+        assert_eq!(
+            adapter.compute_impact(&ctx(None, None)).explanation,
+            adapter.explain_impact(&ctx(None, None)).explanation
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::eco::MetricExplanation;
 use crate::eco_adapter::{EcoContext, EcoImpactAdapter, ImpactScore};
 use crate::eco_adapter::sealed::Sealed;
 
@@ -23,20 +24,56 @@ impl EcoImpactAdapter for StacEcoAdapter {
     }
 
     fn compute_impact(&self, ctx: &EcoContext) -> ImpactScore {
+        let explained = self.explain_impact(ctx);
+        ImpactScore::clamped(explained.value, explained.explanation)
+    }
+
+    /// Biosphere (land-surface) impact, citing the STAC collection it
+    /// would query.
+    fn explain_impact(&self, ctx: &EcoContext) -> MetricExplanation {
         // In a real implementation:
         // - Use stac_client or a custom async client to query items
         //   intersecting ctx.region_hint for ctx.dataset_id.[web:148]
         // - Derive impact metrics from bands, time series, etc.
         //
         // For now we just emit a low-risk placeholder with explanation.
-
-        ImpactScore::clamped(
-            0.3,
-            format!(
-                "Low-to-moderate eco impact inferred from STAC dataset={} at {} (stub).",
-                ctx.dataset_id,
-                self.stac_api_url
+        let collection = format!(
+            "{}/collections/{}",
+            self.stac_api_url.trim_end_matches('/'),
+            ctx.dataset_id
+        );
+        let area = match &ctx.region_hint {
+            Some(region) => format!("region {region}"),
+            None => "the whole collection extent".to_string(),
+        };
+        MetricExplanation {
+            value: 0.3,
+            explanation: format!(
+                "biosphere: low-to-moderate land-surface impact assumed over {area} (stub)"
             ),
-        )
+            sources: vec![collection],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explanation_cites_the_collection() {
+        let adapter = StacEcoAdapter::new("https://stac.example/api/");
+        let explained = adapter.explain_impact(&EcoContext {
+            dataset_id: "sentinel-2-l2a".into(),
+            region_hint: Some("9q5c".into()),
+            taxon_or_feature: None,
+            raw_metadata: None,
+        });
+        assert_eq!(
+            explained.sources,
+            ["https://stac.example/api/collections/sentinel-2-l2a"]
+        );
+        assert!(explained.explanation.contains("region 9q5c"));
+        assert_eq!(explained.value, 0.3);
     }
 }
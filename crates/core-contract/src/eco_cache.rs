@@ -0,0 +1,463 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::eco::{CorridorId, EcoImpactMetrics, NeuromorphArtifact, ProvenanceInfo};
+use crate::eco_source::{EcoDataSource, EcoSourceError};
+
+/// Suffix appended to the provenance of results served from the cache.
+pub const CACHED_SUFFIX: &str = " (cached)";
+
+/// What a cached result is keyed on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CacheKeying {
+    /// One entry per corridor, shared by every artifact in it.
+    Corridor,
+    /// One entry per (corridor, artifact id).
+    CorridorAndArtifact,
+}
+
+/// A metrics result with the provenance it was served under.
+type Served = Result<(EcoImpactMetrics, ProvenanceInfo), EcoSourceError>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct CacheKey {
+    corridor: CorridorId,
+    artifact_id: Option<String>,
+}
+
+struct CacheEntry {
+    metrics: EcoImpactMetrics,
+    provenance: ProvenanceInfo,
+    stored_at: SystemTime,
+    /// Insertion order; the lowest is evicted first.
+    seq: u64,
+}
+
+#[derive(Default)]
+struct CacheState {
+    entries: HashMap<CacheKey, CacheEntry>,
+    next_seq: u64,
+}
+
+/// Caches an inner source's successful results for `ttl`, holding at most
+/// `max_entries`. An entry expires once `ttl` has elapsed since it was
+/// stored. When full, expired entries are dropped first, then the oldest
+/// insertion. Failures are never cached.
+///
+/// Hits keep the provenance of the lookup that filled the entry, its
+/// `fetched_at` included, with `CACHED_SUFFIX` on the label. A result whose
+/// source gave no `fetched_at` is stamped with the time of that lookup.
+pub struct CachedEcoSource<E: EcoDataSource> {
+    inner: E,
+    ttl: Duration,
+    max_entries: usize,
+    keying: CacheKeying,
+    clock: Box<dyn Fn() -> SystemTime + Send + Sync>,
+    state: Mutex<CacheState>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl<E: EcoDataSource> CachedEcoSource<E> {
+    /// Corridor-keyed cache on the system clock.
+    pub fn new(inner: E, ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            inner,
+            ttl,
+            max_entries,
+            keying: CacheKeying::Corridor,
+            clock: Box::new(SystemTime::now),
+            state: Mutex::new(CacheState::default()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub fn with_keying(mut self, keying: CacheKeying) -> Self {
+        self.keying = keying;
+        self
+    }
+
+    /// Replace the system clock, e.g. with a fixed or stepped one in tests.
+    pub fn with_clock(mut self, clock: impl Fn() -> SystemTime + Send + Sync + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// Number of stored entries, expired or not.
+    pub fn len(&self) -> usize {
+        self.state.lock().map(|s| s.entries.len()).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn key(&self, artifact: &NeuromorphArtifact) -> CacheKey {
+        CacheKey {
+            corridor: artifact.corridor_id.clone(),
+            artifact_id: match self.keying {
+                CacheKeying::Corridor => None,
+                CacheKeying::CorridorAndArtifact => Some(artifact.id.clone()),
+            },
+        }
+    }
+
+    fn is_fresh(&self, entry: &CacheEntry, now: SystemTime) -> bool {
+        match now.duration_since(entry.stored_at) {
+            Ok(age) => age < self.ttl,
+            // Stored "in the future" (clock stepped back): treat as fresh.
+            Err(_) => true,
+        }
+    }
+
+    fn lookup(
+        &self,
+        key: &CacheKey,
+        now: SystemTime,
+    ) -> Option<(EcoImpactMetrics, ProvenanceInfo)> {
+        let state = self.state.lock().ok()?;
+        let entry = state.entries.get(key)?;
+        if !self.is_fresh(entry, now) {
+            return None;
+        }
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        Some((entry.metrics.clone(), cached(entry.provenance.clone())))
+    }
+
+    fn store(&self, key: CacheKey, metrics: &EcoImpactMetrics, provenance: &ProvenanceInfo, now: SystemTime) {
+        if self.max_entries == 0 {
+            return;
+        }
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        if !state.entries.contains_key(&key) && state.entries.len() >= self.max_entries {
+            state.entries.retain(|_, entry| self.is_fresh(entry, now));
+            if state.entries.len() >= self.max_entries {
+                let oldest = state
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.seq)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    state.entries.remove(&oldest);
+                }
+            }
+        }
+        let seq = state.next_seq;
+        state.next_seq += 1;
+        state.entries.insert(
+            key,
+            CacheEntry {
+                metrics: metrics.clone(),
+                provenance: provenance.clone(),
+                stored_at: now,
+                seq,
+            },
+        );
+    }
+}
+
+/// `provenance` as served from the cache.
+fn cached(mut provenance: ProvenanceInfo) -> ProvenanceInfo {
+    provenance.label.push_str(CACHED_SUFFIX);
+    provenance
+}
+
+/// `provenance`, with `now` as its fetch time unless the source gave one.
+fn stamped(mut provenance: ProvenanceInfo, now: SystemTime) -> ProvenanceInfo {
+    if provenance.fetched_at.is_none() {
+        provenance.fetched_at = now.duration_since(UNIX_EPOCH).ok().map(|d| d.as_secs());
+    }
+    provenance
+}
+
+impl<E: EcoDataSource> EcoDataSource for CachedEcoSource<E> {
+    fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+        self.calculate_with_provenance(artifact)
+            .map(|(metrics, _)| metrics)
+    }
+
+    fn calculate_batch(
+        &self,
+        artifacts: &[&NeuromorphArtifact],
+    ) -> Vec<Result<EcoImpactMetrics, EcoSourceError>> {
+        self.calculate_batch_with_provenance(artifacts)
+            .into_iter()
+            .map(|r| r.map(|(metrics, _)| metrics))
+            .collect()
+    }
+
+    fn provenance_label(&self) -> &'static str {
+        self.inner.provenance_label()
+    }
+
+    fn provenance_detail(&self) -> ProvenanceInfo {
+        self.inner.provenance_detail()
+    }
+
+    fn calculate_with_provenance(
+        &self,
+        artifact: &NeuromorphArtifact,
+    ) -> Result<(EcoImpactMetrics, ProvenanceInfo), EcoSourceError> {
+        let now = (self.clock)();
+        let key = self.key(artifact);
+        if let Some(hit) = self.lookup(&key, now) {
+            return Ok(hit);
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let (metrics, provenance) = self.inner.calculate_with_provenance(artifact)?;
+        let provenance = stamped(provenance, now);
+        self.store(key, &metrics, &provenance, now);
+        Ok((metrics, provenance))
+    }
+
+    /// Serves hits from the cache and sends one artifact per missing key
+    /// through the inner batch path; later artifacts sharing that key count
+    /// as hits.
+    fn calculate_batch_with_provenance(
+        &self,
+        artifacts: &[&NeuromorphArtifact],
+    ) -> Vec<Result<(EcoImpactMetrics, ProvenanceInfo), EcoSourceError>> {
+        let now = (self.clock)();
+        let mut results: Vec<Option<Served>> = artifacts.iter().map(|_| None).collect();
+        let mut missing: Vec<(CacheKey, Vec<usize>)> = Vec::new();
+        for (i, artifact) in artifacts.iter().enumerate() {
+            let key = self.key(artifact);
+            if let Some(hit) = self.lookup(&key, now) {
+                results[i] = Some(Ok(hit));
+            } else if let Some((_, indices)) = missing.iter_mut().find(|(k, _)| *k == key) {
+                indices.push(i);
+            } else {
+                missing.push((key, vec![i]));
+            }
+        }
+
+        let lookups: Vec<&NeuromorphArtifact> = missing
+            .iter()
+            .map(|(_, indices)| artifacts[indices[0]])
+            .collect();
+        let fetched = self.inner.calculate_batch_with_provenance(&lookups);
+        for ((key, indices), result) in missing.into_iter().zip(fetched) {
+            let result = result.map(|(metrics, provenance)| (metrics, stamped(provenance, now)));
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            if let Ok((metrics, provenance)) = &result {
+                self.store(key, metrics, provenance, now);
+            }
+            for (n, &i) in indices.iter().enumerate() {
+                results[i] = Some(match (&result, n) {
+                    (Ok((metrics, provenance)), 0) => Ok((metrics.clone(), provenance.clone())),
+                    (Ok((metrics, provenance)), _) => {
+                        self.hits.fetch_add(1, Ordering::Relaxed);
+                        Ok((metrics.clone(), cached(provenance.clone())))
+                    }
+                    (Err(e), _) => Err(e.clone()),
+                });
+            }
+        }
+
+        results
+            .into_iter()
+            .map(|r| r.unwrap_or_else(|| Err(EcoSourceError::Internal("eco batch returned too few results".into()))))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU32;
+    use std::sync::Arc;
+    use std::time::UNIX_EPOCH;
+
+    /// Derives metrics from the corridor name and counts lookups.
+    #[derive(Default)]
+    struct CountingSource {
+        calls: AtomicU32,
+    }
+
+    impl EcoDataSource for CountingSource {
+        fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let score = if artifact.corridor_id.0.starts_with("protected") {
+                0.9
+            } else {
+                0.4
+            };
+            Ok(EcoImpactMetrics {
+                climate_score: score,
+                biodiversity_score: score,
+                biosphere_score: score,
+                corridor_score: score,
+            })
+        }
+
+        fn provenance_label(&self) -> &'static str {
+            "counting"
+        }
+    }
+
+    /// `CountingSource` whose provenance carries a dataset version and the
+    /// time it was last refreshed upstream.
+    struct DatedSource(CountingSource);
+
+    impl EcoDataSource for DatedSource {
+        fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+            self.0.calculate(artifact)
+        }
+
+        fn provenance_label(&self) -> &'static str {
+            "dated"
+        }
+
+        fn provenance_detail(&self) -> ProvenanceInfo {
+            ProvenanceInfo::new("dated")
+                .with_dataset_version("v7")
+                .with_fetched_at(5)
+        }
+    }
+
+    fn artifact(id: &str, corridor: &str) -> NeuromorphArtifact {
+        NeuromorphArtifact {
+            id: id.into(),
+            corridor_id: CorridorId(corridor.into()),
+            eco_impact: EcoImpactMetrics {
+                climate_score: 0.0,
+                biodiversity_score: 0.0,
+                biosphere_score: 0.0,
+                corridor_score: 0.0,
+            },
+            summary: String::new(),
+            data_labels: Default::default(),
+        }
+    }
+
+    /// Clock the test advances by hand, in seconds since the epoch.
+    fn stepped_clock() -> (Arc<AtomicU64>, impl Fn() -> SystemTime + Send + Sync) {
+        let secs = Arc::new(AtomicU64::new(0));
+        let handle = secs.clone();
+        (secs, move || {
+            UNIX_EPOCH + Duration::from_secs(handle.load(Ordering::SeqCst))
+        })
+    }
+
+    #[test]
+    fn same_corridor_within_ttl_calls_inner_once() {
+        let (secs, clock) = stepped_clock();
+        let cache = CachedEcoSource::new(CountingSource::default(), Duration::from_secs(60), 16)
+            .with_clock(clock);
+
+        let (first, provenance) = cache
+            .calculate_with_provenance(&artifact("a-1", "protected-desert"))
+            .unwrap();
+        assert_eq!(provenance.label, "counting");
+        secs.store(59, Ordering::SeqCst);
+        let (second, provenance) = cache
+            .calculate_with_provenance(&artifact("a-2", "protected-desert"))
+            .unwrap();
+        assert_eq!(provenance.label, "counting (cached)");
+        assert_eq!(second.scalar(), first.scalar());
+        assert_eq!(cache.inner().calls.load(Ordering::SeqCst), 1);
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+
+        // At exactly `ttl` the entry has expired.
+        secs.store(60, Ordering::SeqCst);
+        cache
+            .calculate(&artifact("a-3", "protected-desert"))
+            .unwrap();
+        assert_eq!(cache.inner().calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn full_cache_evicts_expired_then_oldest() {
+        let (secs, clock) = stepped_clock();
+        let cache = CachedEcoSource::new(CountingSource::default(), Duration::from_secs(100), 2)
+            .with_clock(clock);
+        cache.calculate(&artifact("a", "c1")).unwrap();
+        secs.store(10, Ordering::SeqCst);
+        cache.calculate(&artifact("b", "c2")).unwrap();
+        secs.store(20, Ordering::SeqCst);
+        cache.calculate(&artifact("c", "c3")).unwrap(); // evicts c1, the oldest
+        assert_eq!(cache.len(), 2);
+
+        cache.calculate(&artifact("d", "c2")).unwrap();
+        cache.calculate(&artifact("e", "c3")).unwrap();
+        assert_eq!(cache.hits(), 2);
+        cache.calculate(&artifact("f", "c1")).unwrap();
+        assert_eq!(cache.misses(), 4);
+    }
+
+    #[test]
+    fn artifact_keying_and_batches() {
+        let cache = CachedEcoSource::new(CountingSource::default(), Duration::from_secs(60), 16)
+            .with_keying(CacheKeying::CorridorAndArtifact)
+            .with_clock(|| UNIX_EPOCH);
+        cache.calculate(&artifact("a-1", "c1")).unwrap();
+        cache.calculate(&artifact("a-2", "c1")).unwrap();
+        assert_eq!(cache.inner().calls.load(Ordering::SeqCst), 2);
+
+        let corridor = CachedEcoSource::new(CountingSource::default(), Duration::from_secs(60), 16)
+            .with_clock(|| UNIX_EPOCH);
+        let (a, b, c) = (
+            artifact("a", "c1"),
+            artifact("b", "c1"),
+            artifact("c", "c2"),
+        );
+        let results = corridor.calculate_batch_with_provenance(&[&a, &b, &c]);
+        assert_eq!(corridor.inner().calls.load(Ordering::SeqCst), 2);
+        assert_eq!(results[0].as_ref().unwrap().1.label, "counting");
+        assert_eq!(results[1].as_ref().unwrap().1.label, "counting (cached)");
+        assert_eq!(results[2].as_ref().unwrap().1.label, "counting");
+        assert_eq!((corridor.hits(), corridor.misses()), (1, 2));
+    }
+
+    #[test]
+    fn hits_keep_the_original_fetch_time() {
+        let (secs, clock) = stepped_clock();
+        let cache = CachedEcoSource::new(DatedSource(CountingSource::default()), Duration::from_secs(60), 16)
+            .with_clock(clock);
+        secs.store(10, Ordering::SeqCst);
+        let (_, fetched) = cache
+            .calculate_with_provenance(&artifact("a-1", "protected-desert"))
+            .unwrap();
+        secs.store(40, Ordering::SeqCst);
+        let (_, served) = cache
+            .calculate_with_provenance(&artifact("a-2", "protected-desert"))
+            .unwrap();
+        assert_eq!(served.label, "dated (cached)");
+        assert_eq!(served.dataset_version.as_deref(), Some("v7"));
+        assert_eq!((fetched.fetched_at, served.fetched_at), (Some(5), Some(5)));
+
+        // Without a fetch time from the source, the miss that filled the
+        // entry is stamped and later hits report it rather than their own.
+        let (secs, clock) = stepped_clock();
+        let undated = CachedEcoSource::new(CountingSource::default(), Duration::from_secs(60), 16)
+            .with_clock(clock);
+        secs.store(10, Ordering::SeqCst);
+        let (_, fetched) = undated
+            .calculate_with_provenance(&artifact("a-1", "protected-desert"))
+            .unwrap();
+        secs.store(40, Ordering::SeqCst);
+        let (_, served) = undated
+            .calculate_with_provenance(&artifact("a-2", "protected-desert"))
+            .unwrap();
+        assert_eq!((fetched.fetched_at, served.fetched_at), (Some(10), Some(10)));
+        let results = undated.calculate_batch_with_provenance(&[&artifact("a-3", "protected-desert")]);
+        assert_eq!(results[0].as_ref().unwrap().1.fetched_at, Some(10));
+    }
+}
@@ -10,6 +10,8 @@ pub enum NoCoerciveChannels {}
 pub enum DynamicConsent {}
 
 mod sealed {
+    // Marker only: sealing the corridor / neurorights types, never used as a bound.
+    #[allow(dead_code)]
     pub trait Sealed {}
 }
 use sealed::Sealed;
@@ -45,3 +47,9 @@ impl<const ID: u32> CoreEcoEngine<ID> {
         )
     }
 }
+
+impl<const ID: u32> Default for CoreEcoEngine<ID> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
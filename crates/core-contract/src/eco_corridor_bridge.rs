@@ -14,6 +14,12 @@ impl<const ID: u32> CorridorBoundScoreEngine<ID> {
     }
 }
 
+impl<const ID: u32> Default for CorridorBoundScoreEngine<ID> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<const ID: u32> EcoImpactAdapter for CorridorBoundScoreEngine<ID> {
     fn name(&self) -> &'static str {
         CoreEcoEngine::<ID>::ENGINE_NAME
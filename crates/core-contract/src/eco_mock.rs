@@ -0,0 +1,280 @@
+//! Scriptable `EcoDataSource` for tests.
+//!
+//! Each corridor answers from its own script of responses; the last
+//! response repeats once the script runs out, and unscripted corridors
+//! answer with the artifact's declared `eco_impact` (or are refused, with
+//! `with_unknown_corridors_refused`). Failures can also be scheduled on the
+//! Nth call regardless of corridor. Every call is recorded, and lookups can
+//! be slowed down by a fixed latency: the sync trait sleeps, the async one
+//! waits without blocking its executor.
+//!
+//! ```
+//! use core_contract::eco::{CorridorId, EcoImpactMetrics, NeuromorphArtifact};
+//! use core_contract::eco_mock::{MockEcoCall, MockEcoSource};
+//! use core_contract::eco_source::{EcoDataSource, EcoSourceError};
+//!
+//! let scores = |s| EcoImpactMetrics {
+//!     climate_score: s,
+//!     biodiversity_score: s,
+//!     biosphere_score: s,
+//!     corridor_score: s,
+//! };
+//! let artifact = |corridor: &str| NeuromorphArtifact {
+//!     id: "a-1".into(),
+//!     corridor_id: CorridorId(corridor.into()),
+//!     eco_impact: scores(0.5),
+//!     summary: String::new(),
+//!     data_labels: Default::default(),
+//! };
+//! let eco = MockEcoSource::new()
+//!     .with_label("gbif")
+//!     .with_metrics("phoenix-canal", scores(0.9))
+//!     .with_failure_on_call(2, EcoSourceError::UpstreamTimeout);
+//!
+//! assert_eq!(eco.calculate(&artifact("phoenix-canal")), Ok(scores(0.9)));
+//! assert_eq!(
+//!     eco.calculate(&artifact("phoenix-canal")),
+//!     Err(EcoSourceError::UpstreamTimeout)
+//! );
+//! // Unscripted corridors echo the declared scores.
+//! assert_eq!(eco.calculate(&artifact("elsewhere")), Ok(scores(0.5)));
+//! assert_eq!(eco.call_count(), 3);
+//! assert_eq!(
+//!     eco.calls()[2],
+//!     MockEcoCall {
+//!         artifact_id: "a-1".into(),
+//!         corridor_id: CorridorId("elsewhere".into()),
+//!     }
+//! );
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+
+use crate::eco::{CorridorId, EcoImpactMetrics, NeuromorphArtifact, ProvenanceInfo};
+use crate::eco_source::{AsyncEcoDataSource, EcoDataSource, EcoSourceError};
+
+/// One lookup received by a `MockEcoSource`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MockEcoCall {
+    pub artifact_id: String,
+    pub corridor_id: CorridorId,
+}
+
+type Response = Result<EcoImpactMetrics, EcoSourceError>;
+
+#[derive(Default)]
+struct MockState {
+    scripts: HashMap<CorridorId, VecDeque<Response>>,
+    failures: HashMap<usize, EcoSourceError>,
+    calls: Vec<MockEcoCall>,
+}
+
+pub struct MockEcoSource {
+    label: &'static str,
+    detail: Option<ProvenanceInfo>,
+    refuse_unknown: bool,
+    latency: Duration,
+    state: Mutex<MockState>,
+}
+
+impl Default for MockEcoSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockEcoSource {
+    /// Labelled "mock", echoing every artifact's declared scores.
+    pub fn new() -> Self {
+        Self {
+            label: "mock",
+            detail: None,
+            refuse_unknown: false,
+            latency: Duration::ZERO,
+            state: Mutex::new(MockState::default()),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn state_mut(&mut self) -> &mut MockState {
+        self.state.get_mut().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn with_label(mut self, label: &'static str) -> Self {
+        self.label = label;
+        self
+    }
+
+    /// Reported by `provenance_detail` instead of the bare label.
+    pub fn with_provenance_detail(mut self, detail: ProvenanceInfo) -> Self {
+        self.detail = Some(detail);
+        self
+    }
+
+    /// Append `responses` to the corridor's script, answered one per call.
+    pub fn with_responses(
+        mut self,
+        corridor: impl Into<String>,
+        responses: impl IntoIterator<Item = Response>,
+    ) -> Self {
+        self.state_mut()
+            .scripts
+            .entry(CorridorId(corridor.into()))
+            .or_default()
+            .extend(responses);
+        self
+    }
+
+    /// Append `metrics` to the corridor's script.
+    pub fn with_metrics(self, corridor: impl Into<String>, metrics: EcoImpactMetrics) -> Self {
+        self.with_responses(corridor, [Ok(metrics)])
+    }
+
+    /// Append an error to the corridor's script.
+    pub fn with_error(self, corridor: impl Into<String>, error: EcoSourceError) -> Self {
+        self.with_responses(corridor, [Err(error)])
+    }
+
+    /// Fail the `n`th call (counting from 1, across all corridors) with
+    /// `error`, whatever the corridor's script says.
+    pub fn with_failure_on_call(mut self, n: usize, error: EcoSourceError) -> Self {
+        self.state_mut().failures.insert(n, error);
+        self
+    }
+
+    /// Answer unscripted corridors with `EcoSourceError::UnknownCorridor`
+    /// rather than the artifact's declared scores.
+    pub fn with_unknown_corridors_refused(mut self) -> Self {
+        self.refuse_unknown = true;
+        self
+    }
+
+    /// Delay every answer by `latency`.
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    /// Every lookup received so far, in order.
+    pub fn calls(&self) -> Vec<MockEcoCall> {
+        self.lock().calls.clone()
+    }
+
+    pub fn call_count(&self) -> usize {
+        self.lock().calls.len()
+    }
+
+    /// Record the call and pick its response.
+    fn respond(&self, artifact: &NeuromorphArtifact) -> Response {
+        let mut state = self.lock();
+        state.calls.push(MockEcoCall {
+            artifact_id: artifact.id.clone(),
+            corridor_id: artifact.corridor_id.clone(),
+        });
+        let n = state.calls.len();
+        if let Some(error) = state.failures.remove(&n) {
+            return Err(error);
+        }
+        match state.scripts.get_mut(&artifact.corridor_id) {
+            Some(script) if script.len() > 1 => script
+                .pop_front()
+                .unwrap_or_else(|| Ok(artifact.eco_impact.clone())),
+            Some(script) if !script.is_empty() => script[0].clone(),
+            _ if self.refuse_unknown => Err(EcoSourceError::UnknownCorridor(
+                artifact.corridor_id.clone(),
+            )),
+            _ => Ok(artifact.eco_impact.clone()),
+        }
+    }
+}
+
+impl EcoDataSource for MockEcoSource {
+    fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+        if !self.latency.is_zero() {
+            std::thread::sleep(self.latency);
+        }
+        self.respond(artifact)
+    }
+
+    fn provenance_label(&self) -> &'static str {
+        self.label
+    }
+
+    fn provenance_detail(&self) -> ProvenanceInfo {
+        self.detail
+            .clone()
+            .unwrap_or_else(|| ProvenanceInfo::new(self.label))
+    }
+}
+
+impl AsyncEcoDataSource for MockEcoSource {
+    async fn calculate(
+        &self,
+        artifact: &NeuromorphArtifact,
+    ) -> Result<EcoImpactMetrics, EcoSourceError> {
+        if !self.latency.is_zero() {
+            Delay::new(self.latency).await;
+        }
+        self.respond(artifact)
+    }
+
+    fn provenance_label(&self) -> &'static str {
+        self.label
+    }
+
+    fn provenance_detail(&self) -> ProvenanceInfo {
+        EcoDataSource::provenance_detail(self)
+    }
+}
+
+/// Runtime-agnostic sleep: a helper thread wakes the task at the deadline.
+struct Delay {
+    deadline: Instant,
+    waker: Option<Arc<Mutex<Waker>>>,
+}
+
+impl Delay {
+    fn new(after: Duration) -> Self {
+        Self {
+            deadline: Instant::now() + after,
+            waker: None,
+        }
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+        match &self.waker {
+            Some(waker) => {
+                if let Ok(mut waker) = waker.lock() {
+                    waker.clone_from(cx.waker());
+                }
+            }
+            None => {
+                let waker = Arc::new(Mutex::new(cx.waker().clone()));
+                let (shared, deadline) = (waker.clone(), self.deadline);
+                std::thread::spawn(move || {
+                    std::thread::sleep(deadline.saturating_duration_since(Instant::now()));
+                    if let Ok(waker) = shared.lock() {
+                        waker.wake_by_ref();
+                    }
+                });
+                self.waker = Some(waker);
+            }
+        }
+        Poll::Pending
+    }
+}
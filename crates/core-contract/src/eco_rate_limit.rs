@@ -0,0 +1,435 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::eco::{EcoImpactMetrics, EcoImpactReport, NeuromorphArtifact, ProvenanceInfo};
+use crate::eco_source::{EcoDataSource, EcoSourceError};
+
+/// Budget and retry policy of a `RateLimitedEcoSource`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RateLimitConfig {
+    /// Sustained request rate; the bucket refills at this many tokens per
+    /// second.
+    pub requests_per_second: f64,
+    /// Bucket size: requests allowed back to back after an idle period.
+    pub burst: u32,
+    /// Retries after a transient error, each taking a token of its own.
+    pub max_retries: u32,
+    /// Backoff before the first retry; doubled for each further retry.
+    pub initial_backoff: Duration,
+    /// Cap on a single backoff, before jitter.
+    pub max_backoff: Duration,
+    /// Longest a call may spend waiting for tokens and backoffs in total.
+    pub deadline: Duration,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            requests_per_second: 10.0,
+            burst: 10,
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            deadline: Duration::from_secs(2),
+        }
+    }
+}
+
+/// Counters of a `RateLimitedEcoSource`, since construction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RateLimitStats {
+    /// Requests that had to wait for a token.
+    pub delayed: u64,
+    /// Total time spent waiting for tokens and backoffs.
+    pub delayed_for: Duration,
+    /// Calls refused for want of a token before the deadline.
+    pub dropped: u64,
+    /// Retries after transient errors.
+    pub retries: u64,
+}
+
+/// Default transient-error test: upstream timeouts, HTTP 429/503, and
+/// `Internal` messages mentioning either, case-insensitively.
+pub fn is_transient(error: &EcoSourceError) -> bool {
+    match error {
+        EcoSourceError::UpstreamTimeout => true,
+        EcoSourceError::UpstreamRejected { status } => matches!(status, 429 | 503),
+        EcoSourceError::Internal(message) => {
+            let message = message.to_ascii_lowercase();
+            ["429", "503", "timeout", "timed out", "rate limit", "temporarily unavailable"]
+                .iter()
+                .any(|marker| message.contains(marker))
+        }
+        EcoSourceError::UnknownCorridor(_) | EcoSourceError::StaleData { .. } => false,
+    }
+}
+
+struct Bucket {
+    /// May go negative: a negative balance is tokens already promised to
+    /// waiting requests.
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+/// Token-bucket rate limit with retries in front of an external eco source.
+/// Every attempt takes a token; a request waits for one when the bucket is
+/// empty. Transient errors (`is_transient` unless replaced) are retried
+/// with exponential backoff and jitter. A call that would wait past
+/// `RateLimitConfig::deadline` for a token fails as a throttled upstream
+/// would, with `UpstreamRejected { status: 429 }`; one with no time left to
+/// retry returns its last error.
+pub struct RateLimitedEcoSource<E: EcoDataSource> {
+    inner: E,
+    config: RateLimitConfig,
+    bucket: Mutex<Bucket>,
+    clock: Box<dyn Fn() -> Instant + Send + Sync>,
+    sleep: Box<dyn Fn(Duration) + Send + Sync>,
+    transient: fn(&EcoSourceError) -> bool,
+    jitter_state: AtomicU64,
+    delayed: AtomicU64,
+    delayed_nanos: AtomicU64,
+    dropped: AtomicU64,
+    retries: AtomicU64,
+}
+
+impl<E: EcoDataSource> RateLimitedEcoSource<E> {
+    /// Starts with a full bucket, on the system clock.
+    pub fn new(inner: E, config: RateLimitConfig) -> Self {
+        let now = Instant::now();
+        Self {
+            inner,
+            bucket: Mutex::new(Bucket {
+                tokens: f64::from(config.burst),
+                refilled_at: now,
+            }),
+            config,
+            clock: Box::new(Instant::now),
+            sleep: Box::new(std::thread::sleep),
+            transient: is_transient,
+            jitter_state: AtomicU64::new(0x853c_49e6_748f_ea9b),
+            delayed: AtomicU64::new(0),
+            delayed_nanos: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+            retries: AtomicU64::new(0),
+        }
+    }
+
+    /// Replace the clock and the sleep together, e.g. with a mock clock
+    /// whose sleep advances it. The bucket's refill time is reset to the
+    /// new clock's now.
+    pub fn with_clock(
+        mut self,
+        clock: impl Fn() -> Instant + Send + Sync + 'static,
+        sleep: impl Fn(Duration) + Send + Sync + 'static,
+    ) -> Self {
+        if let Ok(bucket) = self.bucket.get_mut() {
+            bucket.refilled_at = clock();
+        }
+        self.clock = Box::new(clock);
+        self.sleep = Box::new(sleep);
+        self
+    }
+
+    /// Replace `is_transient`.
+    pub fn with_transient(mut self, transient: fn(&EcoSourceError) -> bool) -> Self {
+        self.transient = transient;
+        self
+    }
+
+    /// Seed of the backoff jitter, for reproducible delays.
+    pub fn with_jitter_seed(self, seed: u64) -> Self {
+        self.jitter_state.store(seed, Ordering::Relaxed);
+        self
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+
+    pub fn config(&self) -> &RateLimitConfig {
+        &self.config
+    }
+
+    pub fn stats(&self) -> RateLimitStats {
+        RateLimitStats {
+            delayed: self.delayed.load(Ordering::Relaxed),
+            delayed_for: Duration::from_nanos(self.delayed_nanos.load(Ordering::Relaxed)),
+            dropped: self.dropped.load(Ordering::Relaxed),
+            retries: self.retries.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Uniform in [0, 1), from a SplitMix64 stream.
+    fn jitter(&self) -> f64 {
+        let mut z = self
+            .jitter_state
+            .fetch_add(0x9e37_79b9_7f4a_7c15, Ordering::Relaxed)
+            .wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn wait(&self, duration: Duration) {
+        if duration.is_zero() {
+            return;
+        }
+        let nanos = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        self.delayed_nanos.fetch_add(nanos, Ordering::Relaxed);
+        (self.sleep)(duration);
+    }
+
+    fn rate_limited(&self) -> EcoSourceError {
+        self.dropped.fetch_add(1, Ordering::Relaxed);
+        EcoSourceError::UpstreamRejected { status: 429 }
+    }
+
+    /// Reserve a token, waiting for it when needed. Refused, without
+    /// reserving, when the wait would end past `deadline`.
+    fn acquire(&self, deadline: Instant) -> Result<(), EcoSourceError> {
+        let wait = {
+            let Ok(mut bucket) = self.bucket.lock() else {
+                return Err(EcoSourceError::Internal("rate limiter state poisoned".into()));
+            };
+            let now = (self.clock)();
+            let elapsed = now.saturating_duration_since(bucket.refilled_at);
+            bucket.tokens = (bucket.tokens + elapsed.as_secs_f64() * self.config.requests_per_second)
+                .min(f64::from(self.config.burst));
+            bucket.refilled_at = now;
+
+            let shortfall = 1.0 - bucket.tokens;
+            let wait = if shortfall > 0.0 {
+                Duration::from_secs_f64(shortfall / self.config.requests_per_second)
+            } else {
+                Duration::ZERO
+            };
+            if now + wait > deadline {
+                drop(bucket);
+                return Err(self.rate_limited());
+            }
+            bucket.tokens -= 1.0;
+            wait
+        };
+        if !wait.is_zero() {
+            self.delayed.fetch_add(1, Ordering::Relaxed);
+            self.wait(wait);
+        }
+        Ok(())
+    }
+
+    /// Run `attempt` under the budget and retry policy.
+    fn call<T>(&self, attempt: impl Fn() -> Result<T, EcoSourceError>) -> Result<T, EcoSourceError> {
+        let deadline = (self.clock)() + self.config.deadline;
+        let mut backoff = self.config.initial_backoff;
+        let mut retry = 0;
+        loop {
+            self.acquire(deadline)?;
+            match attempt() {
+                Ok(value) => return Ok(value),
+                Err(e) if retry < self.config.max_retries && (self.transient)(&e) => {
+                    // Equal jitter: half the backoff, plus up to the other half.
+                    let capped = backoff.min(self.config.max_backoff);
+                    let delay = capped / 2 + capped.mul_f64(self.jitter() / 2.0);
+                    if (self.clock)() + delay > deadline {
+                        return Err(e);
+                    }
+                    self.retries.fetch_add(1, Ordering::Relaxed);
+                    self.wait(delay);
+                    backoff = backoff.saturating_mul(2);
+                    retry += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+impl<E: EcoDataSource> EcoDataSource for RateLimitedEcoSource<E> {
+    fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+        self.call(|| self.inner.calculate(artifact))
+    }
+
+    fn provenance_label(&self) -> &'static str {
+        self.inner.provenance_label()
+    }
+
+    fn provenance_detail(&self) -> ProvenanceInfo {
+        self.inner.provenance_detail()
+    }
+
+    fn calculate_with_provenance(
+        &self,
+        artifact: &NeuromorphArtifact,
+    ) -> Result<(EcoImpactMetrics, ProvenanceInfo), EcoSourceError> {
+        self.call(|| self.inner.calculate_with_provenance(artifact))
+    }
+
+    fn calculate_with_report(
+        &self,
+        artifact: &NeuromorphArtifact,
+    ) -> Result<EcoImpactReport, EcoSourceError> {
+        self.call(|| self.inner.calculate_with_report(artifact))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eco::CorridorId;
+    use std::sync::atomic::AtomicU32;
+    use std::sync::Arc;
+
+    /// Fails with a 429 `failures` times, then succeeds.
+    struct Throttled {
+        failures: u32,
+        calls: AtomicU32,
+    }
+
+    impl Throttled {
+        fn new(failures: u32) -> Self {
+            Self {
+                failures,
+                calls: AtomicU32::new(0),
+            }
+        }
+    }
+
+    impl EcoDataSource for Throttled {
+        fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            if call <= self.failures {
+                Err(EcoSourceError::UpstreamRejected { status: 429 })
+            } else {
+                Ok(artifact.eco_impact.clone())
+            }
+        }
+
+        fn provenance_label(&self) -> &'static str {
+            "throttled"
+        }
+    }
+
+    fn artifact() -> NeuromorphArtifact {
+        NeuromorphArtifact {
+            id: "a-1".into(),
+            corridor_id: CorridorId("corridor".into()),
+            eco_impact: EcoImpactMetrics {
+                climate_score: 0.5,
+                biodiversity_score: 0.5,
+                biosphere_score: 0.5,
+                corridor_score: 0.5,
+            },
+            summary: String::new(),
+            data_labels: Default::default(),
+        }
+    }
+
+    /// Clock that only moves when slept on; returns the elapsed time handle.
+    fn limited<E: EcoDataSource>(inner: E, config: RateLimitConfig) -> (RateLimitedEcoSource<E>, Arc<Mutex<Duration>>) {
+        let start = Instant::now();
+        let elapsed = Arc::new(Mutex::new(Duration::ZERO));
+        let (now, slept) = (elapsed.clone(), elapsed.clone());
+        let source = RateLimitedEcoSource::new(inner, config).with_clock(
+            move || start + *now.lock().unwrap(),
+            move |d| *slept.lock().unwrap() += d,
+        );
+        (source, elapsed)
+    }
+
+    fn config(requests_per_second: f64, burst: u32) -> RateLimitConfig {
+        RateLimitConfig {
+            requests_per_second,
+            burst,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn bucket_delays_past_the_burst_and_drops_past_the_deadline() {
+        let (source, elapsed) = limited(Throttled::new(0), config(2.0, 2));
+        for _ in 0..2 {
+            source.calculate(&artifact()).unwrap();
+        }
+        assert_eq!(*elapsed.lock().unwrap(), Duration::ZERO);
+
+        // Third request waits half a second for the next token.
+        source.calculate(&artifact()).unwrap();
+        assert_eq!(*elapsed.lock().unwrap(), Duration::from_millis(500));
+        assert_eq!(source.stats().delayed, 1);
+
+        // Four more fit in the 2 s deadline; the fifth would not.
+        for _ in 0..4 {
+            source.calculate(&artifact()).unwrap();
+        }
+        let (tight, _) = limited(
+            Throttled::new(0),
+            RateLimitConfig {
+                deadline: Duration::from_millis(400),
+                ..config(2.0, 1)
+            },
+        );
+        tight.calculate(&artifact()).unwrap();
+        let err = tight.calculate(&artifact()).unwrap_err();
+        assert_eq!(err, EcoSourceError::UpstreamRejected { status: 429 });
+        assert_eq!(tight.stats().dropped, 1);
+        assert_eq!(tight.inner().calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn transient_errors_back_off_exponentially_with_jitter() {
+        let (source, elapsed) = limited(Throttled::new(3), config(100.0, 10));
+        let (_, provenance) = source.calculate_with_provenance(&artifact()).unwrap();
+        assert_eq!(provenance.label, "throttled");
+        let stats = source.stats();
+        assert_eq!(stats.retries, 3);
+        // Backoffs of 100, 200 and 400 ms, each jittered into [half, full).
+        let waited = *elapsed.lock().unwrap();
+        assert!(waited >= Duration::from_millis(350) && waited < Duration::from_millis(700), "{waited:?}");
+        assert_eq!(stats.delayed_for, waited);
+
+        // Out of retries: the last error comes back.
+        let (source, _) = limited(Throttled::new(10), config(100.0, 10));
+        assert_eq!(
+            source.calculate(&artifact()).unwrap_err(),
+            EcoSourceError::UpstreamRejected { status: 429 }
+        );
+        assert_eq!(source.inner().calls.load(Ordering::SeqCst), 4);
+
+        // Permanent errors are not retried.
+        let (source, _) = limited(Throttled::new(1), config(100.0, 10));
+        let source = source.with_transient(|_| false);
+        assert!(source.calculate(&artifact()).is_err());
+        assert_eq!(source.stats().retries, 0);
+    }
+
+    #[test]
+    fn backoff_is_capped_and_bounded_by_the_deadline() {
+        let (source, elapsed) = limited(
+            Throttled::new(10),
+            RateLimitConfig {
+                max_retries: 10,
+                max_backoff: Duration::from_millis(300),
+                deadline: Duration::from_secs(1),
+                ..config(100.0, 10)
+            },
+        );
+        let err = source.calculate(&artifact()).unwrap_err();
+        assert_eq!(err, EcoSourceError::UpstreamRejected { status: 429 });
+        assert!(*elapsed.lock().unwrap() <= Duration::from_secs(1));
+        assert_eq!(source.stats().dropped, 0);
+        // 100 ms then capped 300 ms backoffs: at least three retries fit.
+        assert!(source.stats().retries >= 3);
+    }
+
+    #[test]
+    fn default_transient_test() {
+        assert!(is_transient(&EcoSourceError::UpstreamTimeout));
+        assert!(is_transient(&EcoSourceError::UpstreamRejected { status: 503 }));
+        assert!(!is_transient(&EcoSourceError::UpstreamRejected { status: 403 }));
+        assert!(is_transient(&EcoSourceError::Internal("upstream Timed Out".into())));
+        assert!(!is_transient(&EcoSourceError::Internal("404 corridor not found".into())));
+        assert!(!is_transient(&EcoSourceError::UnknownCorridor(CorridorId("c".into()))));
+    }
+}
@@ -9,6 +9,12 @@ pub struct EcoImpactRegistry {
     adapters: HashMap<String, EcoImpactAdapterBox>,
 }
 
+impl Default for EcoImpactRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl EcoImpactRegistry {
     pub fn new() -> Self {
         Self {
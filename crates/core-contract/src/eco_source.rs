@@ -1,11 +1,701 @@
-use crate::eco::{EcoImpactMetrics, NeuromorphArtifact};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::Future;
+use core::mem::discriminant;
+use core::time::Duration;
+
+use crate::eco::{CorridorId, EcoImpactMetrics, EcoImpactReport, NeuromorphArtifact, ProvenanceInfo};
+
+/// Why an eco source could not score an artifact. The variant, not the
+/// message, decides what the orchestrator does: stale data is scored in a
+/// degraded mode, everything else refuses.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EcoSourceError {
+    /// The source has no data for this corridor.
+    UnknownCorridor(CorridorId),
+    /// The upstream service did not answer in time.
+    UpstreamTimeout,
+    /// The upstream service refused the request, e.g. HTTP 429 or 503.
+    UpstreamRejected { status: u16 },
+    /// The source only has metrics older than its freshness threshold.
+    /// They are carried along so callers can choose to use them anyway.
+    StaleData { age: Duration, metrics: EcoImpactMetrics },
+    /// Anything else, e.g. a malformed upstream response or bad config.
+    Internal(String),
+}
+
+impl fmt::Display for EcoSourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCorridor(corridor) => write!(f, "unknown corridor {:?}", corridor.0),
+            Self::UpstreamTimeout => f.write_str("upstream timeout"),
+            Self::UpstreamRejected { status } => write!(f, "upstream rejected the request (HTTP {status})"),
+            Self::StaleData { age, .. } => write!(f, "eco data is stale ({} s old)", age.as_secs()),
+            Self::Internal(message) => f.write_str(message),
+        }
+    }
+}
+
+/// Folds the failures of several sources into one error: the last of them
+/// when they all share a variant other than `Internal`, so e.g. a corridor
+/// every source lacks stays `UnknownCorridor`; otherwise `Internal` listing
+/// each as "{label}: {error}" after `prefix`.
+fn combine(prefix: &str, mut failures: Vec<(String, EcoSourceError)>) -> EcoSourceError {
+    let agree = failures
+        .windows(2)
+        .all(|pair| discriminant(&pair[0].1) == discriminant(&pair[1].1));
+    match failures.pop() {
+        Some((_, last)) if agree && !matches!(last, EcoSourceError::Internal(_)) => last,
+        last => {
+            failures.extend(last);
+            let listed: Vec<String> = failures
+                .iter()
+                .map(|(label, error)| format!("{label}: {error}"))
+                .collect();
+            EcoSourceError::Internal(format!("{prefix}: {}", listed.join("; ")))
+        }
+    }
+}
 
 /// Pluggable provider interface for EcoImpact metrics.[file:71][file:69]
 pub trait EcoDataSource {
     /// Compute refined EcoImpact for a given artifact.
     /// Implementations may call external APIs, local models, or simulators.
-    fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, String>;
+    fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError>;
+
+    /// Compute refined EcoImpact for several artifacts at once, returning one
+    /// result per artifact in input order. The default calls `calculate` for
+    /// each; sources backed by remote lookups can override it to share work
+    /// (e.g. one query per corridor).
+    fn calculate_batch(
+        &self,
+        artifacts: &[&NeuromorphArtifact],
+    ) -> Vec<Result<EcoImpactMetrics, EcoSourceError>> {
+        artifacts.iter().map(|a| self.calculate(a)).collect()
+    }
 
     /// Optional human-readable provenance label (e.g., "GBIF+Copernicus v1").
     fn provenance_label(&self) -> &'static str;
+
+    /// `provenance_label` with the dataset version and fetch time of the
+    /// data the source currently serves, e.g. "gbif (dataset v3, fetched
+    /// 2025-11-02)". The default knows neither.
+    fn provenance_detail(&self) -> ProvenanceInfo {
+        ProvenanceInfo::new(self.provenance_label())
+    }
+
+    /// `calculate` plus the provenance of this particular result. Sources
+    /// whose provenance varies per call (e.g. `FallbackEcoSource`) override
+    /// it; the default pairs `calculate` with `provenance_detail`.
+    fn calculate_with_provenance(
+        &self,
+        artifact: &NeuromorphArtifact,
+    ) -> Result<(EcoImpactMetrics, ProvenanceInfo), EcoSourceError> {
+        self.calculate(artifact)
+            .map(|metrics| (metrics, self.provenance_detail()))
+    }
+
+    /// `calculate` with a reason for every metric. The default wraps
+    /// `calculate_with_provenance` in `EcoImpactReport::generic`; sources
+    /// that know why a score came out as it did override it.
+    fn calculate_with_report(
+        &self,
+        artifact: &NeuromorphArtifact,
+    ) -> Result<EcoImpactReport, EcoSourceError> {
+        self.calculate_with_provenance(artifact)
+            .map(|(metrics, provenance)| EcoImpactReport::generic(&metrics, provenance))
+    }
+
+    /// Batch counterpart of `calculate_with_provenance`; the default pairs
+    /// `calculate_batch` with `provenance_detail`.
+    fn calculate_batch_with_provenance(
+        &self,
+        artifacts: &[&NeuromorphArtifact],
+    ) -> Vec<Result<(EcoImpactMetrics, ProvenanceInfo), EcoSourceError>> {
+        let results = self.calculate_batch(artifacts);
+        let provenance = self.provenance_detail();
+        results
+            .into_iter()
+            .map(|r| r.map(|metrics| (metrics, provenance.clone())))
+            .collect()
+    }
+}
+
+/// Separator between attempted sources in a `FallbackEcoSource` label.
+pub const FALLBACK_SEPARATOR: &str = "→";
+
+/// Tries an ordered chain of eco sources, each up to `1 + retries` times,
+/// and returns the first success. The provenance of a result lists every
+/// source that was tried, ending with the one that produced it, e.g.
+/// "gbif→stac", with that source's dataset version and fetch time. When
+/// every attempt fails the error is the first `StaleData` if any, otherwise
+/// the failures combined: their shared variant, or `Internal` listing each.
+#[derive(Default)]
+pub struct FallbackEcoSource {
+    sources: Vec<(Box<dyn EcoDataSource + Send + Sync>, u32)>,
+}
+
+impl FallbackEcoSource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append `source` to the chain, retried up to `retries` times after its
+    /// first failure before moving on.
+    pub fn with_source(mut self, source: impl EcoDataSource + Send + Sync + 'static, retries: u32) -> Self {
+        self.sources.push((Box::new(source), retries));
+        self
+    }
+}
+
+impl EcoDataSource for FallbackEcoSource {
+    fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+        self.calculate_with_provenance(artifact).map(|(metrics, _)| metrics)
+    }
+
+    /// Always per artifact, so each result carries its own provenance.
+    fn calculate_batch_with_provenance(
+        &self,
+        artifacts: &[&NeuromorphArtifact],
+    ) -> Vec<Result<(EcoImpactMetrics, ProvenanceInfo), EcoSourceError>> {
+        artifacts
+            .iter()
+            .map(|a| self.calculate_with_provenance(a))
+            .collect()
+    }
+
+    /// The primary source's label; per-result provenance comes from
+    /// `calculate_with_provenance`.
+    fn provenance_label(&self) -> &'static str {
+        self.sources
+            .first()
+            .map_or("fallback-empty", |(source, _)| source.provenance_label())
+    }
+
+    fn calculate_with_provenance(
+        &self,
+        artifact: &NeuromorphArtifact,
+    ) -> Result<(EcoImpactMetrics, ProvenanceInfo), EcoSourceError> {
+        let mut tried: Vec<&str> = Vec::new();
+        let mut failures: Vec<(String, EcoSourceError)> = Vec::new();
+        for (source, retries) in &self.sources {
+            let label = source.provenance_label();
+            tried.push(label);
+            let attempts = retries.saturating_add(1);
+            for attempt in 1..=attempts {
+                match source.calculate_with_provenance(artifact) {
+                    Ok((metrics, mut provenance)) => {
+                        if let Some((_, earlier)) = tried.split_last().filter(|(_, e)| !e.is_empty()) {
+                            let earlier = earlier.join(FALLBACK_SEPARATOR);
+                            provenance.label = format!("{earlier}{FALLBACK_SEPARATOR}{}", provenance.label);
+                        }
+                        return Ok((metrics, provenance));
+                    }
+                    Err(e) => failures.push((format!("{label} (attempt {attempt}/{attempts})"), e)),
+                }
+            }
+        }
+        if failures.is_empty() {
+            return Err(EcoSourceError::Internal("No eco sources configured.".into()));
+        }
+        // Stale data beats no data; the earliest source's is preferred.
+        if let Some(stale) = failures
+            .iter()
+            .position(|(_, e)| matches!(e, EcoSourceError::StaleData { .. }))
+        {
+            return Err(failures.swap_remove(stale).1);
+        }
+        Err(combine("All eco sources failed", failures))
+    }
+}
+
+/// Separator between the two legs in a `CompositeEcoSource` provenance.
+pub const COMPOSITE_SEPARATOR: &str = "+";
+
+/// What `CompositeEcoSource` does when one leg fails.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LegFailure {
+    /// Refuse the artifact.
+    #[default]
+    FailClosed,
+    /// Score from the surviving leg alone. The failed leg is marked
+    /// "[failed]" in the provenance, and its metrics are explained as taken
+    /// from the other leg.
+    Degrade,
+}
+
+/// Merges a biodiversity source (e.g. GBIF) and a climate source (e.g.
+/// STAC/Copernicus): biodiversity and biosphere come from the first,
+/// climate from the second, and corridor is the lower of the two. The
+/// provenance names both, e.g. "gbif+stac"; dataset versions are listed per
+/// leg ("gbif:v3+stac:2024-10") and the fetch time is the older leg's.
+pub struct CompositeEcoSource<B, C> {
+    biodiversity: B,
+    climate: C,
+    on_leg_failure: LegFailure,
+}
+
+impl<B: EcoDataSource, C: EcoDataSource> CompositeEcoSource<B, C> {
+    /// Fails closed when either leg fails.
+    pub fn new(biodiversity: B, climate: C) -> Self {
+        Self {
+            biodiversity,
+            climate,
+            on_leg_failure: LegFailure::FailClosed,
+        }
+    }
+
+    pub fn with_leg_failure(mut self, on_leg_failure: LegFailure) -> Self {
+        self.on_leg_failure = on_leg_failure;
+        self
+    }
+}
+
+/// Explains each metric of the surviving leg's `report` that the `failed`
+/// leg would have supplied (`own` false, in climate, biodiversity,
+/// biosphere, corridor order) as borrowed.
+fn degraded(mut report: EcoImpactReport, failed: &str, error: &str, own: [bool; 4]) -> EcoImpactReport {
+    let from = report.provenance.clone();
+    let metrics = [
+        &mut report.climate,
+        &mut report.biodiversity,
+        &mut report.biosphere,
+        &mut report.corridor,
+    ];
+    for (metric, own) in metrics.into_iter().zip(own) {
+        if !own {
+            metric.explanation = format!("{failed} failed ({error}); taken from {from}: {}", metric.explanation);
+        }
+    }
+    report
+}
+
+impl<B: EcoDataSource, C: EcoDataSource> EcoDataSource for CompositeEcoSource<B, C> {
+    fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+        self.calculate_with_report(artifact).map(|report| report.metrics())
+    }
+
+    /// Per-result provenance comes from `calculate_with_provenance`.
+    fn provenance_label(&self) -> &'static str {
+        "composite-eco-source"
+    }
+
+    fn calculate_with_provenance(
+        &self,
+        artifact: &NeuromorphArtifact,
+    ) -> Result<(EcoImpactMetrics, ProvenanceInfo), EcoSourceError> {
+        self.calculate_with_report(artifact)
+            .map(|report| (report.metrics(), report.provenance_info()))
+    }
+
+    /// Always per artifact, so each result carries its own provenance.
+    fn calculate_batch_with_provenance(
+        &self,
+        artifacts: &[&NeuromorphArtifact],
+    ) -> Vec<Result<(EcoImpactMetrics, ProvenanceInfo), EcoSourceError>> {
+        artifacts
+            .iter()
+            .map(|a| self.calculate_with_provenance(a))
+            .collect()
+    }
+
+    fn calculate_with_report(
+        &self,
+        artifact: &NeuromorphArtifact,
+    ) -> Result<EcoImpactReport, EcoSourceError> {
+        let bio_label = self.biodiversity.provenance_label();
+        let climate_label = self.climate.provenance_label();
+        let degrade = self.on_leg_failure == LegFailure::Degrade;
+        // A stale leg still contributes; the merged result is then stale as
+        // a whole, as old as its oldest leg.
+        let mut stale: Option<Duration> = None;
+        let mut leg = |result: Result<EcoImpactReport, EcoSourceError>, provenance: ProvenanceInfo| {
+            match result {
+                Err(EcoSourceError::StaleData { age, metrics }) => {
+                    stale = Some(stale.map_or(age, |oldest| oldest.max(age)));
+                    Ok(EcoImpactReport::generic(&metrics, provenance))
+                }
+                other => other,
+            }
+        };
+        let merged = match (
+            leg(self.biodiversity.calculate_with_report(artifact), self.biodiversity.provenance_detail()),
+            leg(self.climate.calculate_with_report(artifact), self.climate.provenance_detail()),
+        ) {
+            (Ok(bio), Ok(climate)) => {
+                let corridor = if climate.corridor.value < bio.corridor.value {
+                    &climate
+                } else {
+                    &bio
+                };
+                let mut merged_corridor = corridor.corridor.clone();
+                merged_corridor.explanation = format!(
+                    "lower of {} {:.3} and {} {:.3}: {}",
+                    bio.provenance,
+                    bio.corridor.value,
+                    climate.provenance,
+                    climate.corridor.value,
+                    corridor.corridor.explanation
+                );
+                let versions: Vec<String> = [&bio, &climate]
+                    .iter()
+                    .filter_map(|leg| Some(format!("{}:{}", leg.provenance, leg.dataset_version.as_ref()?)))
+                    .collect();
+                EcoImpactReport {
+                    provenance: format!("{}{COMPOSITE_SEPARATOR}{}", bio.provenance, climate.provenance),
+                    dataset_version: (!versions.is_empty()).then(|| versions.join(COMPOSITE_SEPARATOR)),
+                    // As fresh as the older leg.
+                    fetched_at: bio.fetched_at.into_iter().chain(climate.fetched_at).min(),
+                    climate: climate.climate,
+                    corridor: merged_corridor,
+                    biodiversity: bio.biodiversity,
+                    biosphere: bio.biosphere,
+                }
+            }
+            (Ok(bio), Err(e)) if degrade => {
+                let provenance = format!("{}{COMPOSITE_SEPARATOR}{climate_label}[failed]", bio.provenance);
+                let mut report = degraded(bio, climate_label, &e.to_string(), [false, true, true, true]);
+                report.provenance = provenance;
+                report
+            }
+            (Err(e), Ok(climate)) if degrade => {
+                let provenance = format!("{bio_label}[failed]{COMPOSITE_SEPARATOR}{}", climate.provenance);
+                let mut report = degraded(climate, bio_label, &e.to_string(), [true, false, false, true]);
+                report.provenance = provenance;
+                report
+            }
+            (Ok(_), Err(e)) => return Err(leg_failed("Climate", climate_label, e)),
+            (Err(e), Ok(_)) => return Err(leg_failed("Biodiversity", bio_label, e)),
+            (Err(bio), Err(climate)) => {
+                return Err(combine(
+                    "Both eco sources failed",
+                    vec![(bio_label.into(), bio), (climate_label.into(), climate)],
+                ))
+            }
+        };
+        match stale {
+            Some(age) => Err(EcoSourceError::StaleData {
+                age,
+                metrics: merged.metrics(),
+            }),
+            None => Ok(merged),
+        }
+    }
+}
+
+/// A single failed leg's error, kept as is unless it is only a message.
+fn leg_failed(kind: &str, label: &str, error: EcoSourceError) -> EcoSourceError {
+    match error {
+        EcoSourceError::Internal(message) => {
+            EcoSourceError::Internal(format!("{kind} source {label} failed: {message}"))
+        }
+        other => other,
+    }
+}
+
+/// Async counterpart of `EcoDataSource` for providers behind HTTP APIs
+/// (GBIF, STAC, Planetary Computer). Implementations write
+/// `async fn calculate`; the returned future must be `Send` so distillation
+/// can run on a multi-threaded runtime.
+pub trait AsyncEcoDataSource {
+    /// Compute refined EcoImpact for a given artifact.
+    fn calculate(
+        &self,
+        artifact: &NeuromorphArtifact,
+    ) -> impl Future<Output = Result<EcoImpactMetrics, EcoSourceError>> + Send;
+
+    /// Optional human-readable provenance label (e.g., "GBIF+Copernicus v1").
+    fn provenance_label(&self) -> &'static str;
+
+    /// See `EcoDataSource::provenance_detail`.
+    fn provenance_detail(&self) -> ProvenanceInfo {
+        ProvenanceInfo::new(self.provenance_label())
+    }
+}
+
+/// Exposes any synchronous `EcoDataSource` through `AsyncEcoDataSource`;
+/// `calculate` runs the sync lookup to completion on first poll.
+pub struct SyncEcoAdapter<E>(pub E);
+
+impl<E: EcoDataSource + Sync> AsyncEcoDataSource for SyncEcoAdapter<E> {
+    async fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+        self.0.calculate(artifact)
+    }
+
+    fn provenance_label(&self) -> &'static str {
+        self.0.provenance_label()
+    }
+
+    fn provenance_detail(&self) -> ProvenanceInfo {
+        self.0.provenance_detail()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eco::CorridorId;
+    use core::sync::atomic::{AtomicU32, Ordering};
+
+    /// Fails `failures` times, then succeeds.
+    struct FlakySource {
+        label: &'static str,
+        failures: u32,
+        calls: AtomicU32,
+    }
+
+    impl FlakySource {
+        fn new(label: &'static str, failures: u32) -> Self {
+            Self {
+                label,
+                failures,
+                calls: AtomicU32::new(0),
+            }
+        }
+    }
+
+    impl EcoDataSource for FlakySource {
+        fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            if call <= self.failures {
+                Err(EcoSourceError::Internal(format!("rate limited (call {call})")))
+            } else {
+                Ok(artifact.eco_impact.clone())
+            }
+        }
+
+        fn provenance_label(&self) -> &'static str {
+            self.label
+        }
+    }
+
+    fn artifact() -> NeuromorphArtifact {
+        NeuromorphArtifact {
+            id: "artifact-1".into(),
+            corridor_id: CorridorId("corridor".into()),
+            eco_impact: EcoImpactMetrics {
+                climate_score: 0.5,
+                biodiversity_score: 0.5,
+                biosphere_score: 0.5,
+                corridor_score: 0.5,
+            },
+            summary: String::new(),
+            data_labels: Default::default(),
+        }
+    }
+
+    #[test]
+    fn retries_recover_a_flaky_primary() {
+        let chain = FallbackEcoSource::new().with_source(FlakySource::new("gbif", 2), 2);
+        let (metrics, provenance) = chain.calculate_with_provenance(&artifact()).unwrap();
+        assert_eq!(metrics.climate_score, 0.5);
+        assert_eq!(provenance.label, "gbif");
+    }
+
+    #[test]
+    fn fallback_names_every_source_tried() {
+        let chain = FallbackEcoSource::new()
+            .with_source(FlakySource::new("gbif", u32::MAX), 1)
+            .with_source(FlakySource::new("stac", 1), 1);
+        let (_, provenance) = chain.calculate_with_provenance(&artifact()).unwrap();
+        assert_eq!(provenance.label, "gbif→stac");
+        assert_eq!(chain.provenance_label(), "gbif");
+
+        let results = chain.calculate_batch_with_provenance(&[&artifact()]);
+        assert_eq!(results[0].as_ref().unwrap().1.label, "gbif→stac");
+    }
+
+    /// Always returns `metrics`.
+    struct FixedSource(&'static str, EcoImpactMetrics);
+
+    impl EcoDataSource for FixedSource {
+        fn calculate(&self, _: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+            Ok(self.1.clone())
+        }
+
+        fn provenance_label(&self) -> &'static str {
+            self.0
+        }
+    }
+
+    fn gbif() -> FixedSource {
+        FixedSource("gbif", EcoImpactMetrics {
+            climate_score: 0.1,
+            biodiversity_score: 0.9,
+            biosphere_score: 0.8,
+            corridor_score: 0.7,
+        })
+    }
+
+    fn stac() -> FixedSource {
+        FixedSource("stac", EcoImpactMetrics {
+            climate_score: 0.6,
+            biodiversity_score: 0.1,
+            biosphere_score: 0.1,
+            corridor_score: 0.4,
+        })
+    }
+
+    #[test]
+    fn composite_merges_legs_and_names_both() {
+        let composite = CompositeEcoSource::new(gbif(), stac());
+        let (metrics, provenance) = composite.calculate_with_provenance(&artifact()).unwrap();
+        assert_eq!(
+            metrics,
+            EcoImpactMetrics {
+                climate_score: 0.6,
+                biodiversity_score: 0.9,
+                biosphere_score: 0.8,
+                corridor_score: 0.4,
+            }
+        );
+        assert_eq!(provenance.label, "gbif+stac");
+
+        let report = composite.calculate_with_report(&artifact()).unwrap();
+        assert!(report.corridor.explanation.starts_with("lower of gbif 0.700 and stac 0.400: "));
+
+        // Fail-closed is the default.
+        let failing = CompositeEcoSource::new(gbif(), FlakySource::new("stac", 1));
+        assert_eq!(
+            failing.calculate(&artifact()).unwrap_err().to_string(),
+            "Climate source stac failed: rate limited (call 1)"
+        );
+    }
+
+    /// `FixedSource` with a dataset version and fetch time.
+    struct VersionedSource(FixedSource, &'static str, u64);
+
+    impl EcoDataSource for VersionedSource {
+        fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+            self.0.calculate(artifact)
+        }
+
+        fn provenance_label(&self) -> &'static str {
+            self.0.provenance_label()
+        }
+
+        fn provenance_detail(&self) -> ProvenanceInfo {
+            ProvenanceInfo::new(self.0.0).with_dataset_version(self.1).with_fetched_at(self.2)
+        }
+    }
+
+    #[test]
+    fn composite_names_leg_versions_and_the_oldest_fetch() {
+        let composite =
+            CompositeEcoSource::new(VersionedSource(gbif(), "v3", 200), VersionedSource(stac(), "v1", 100));
+        let (_, provenance) = composite.calculate_with_provenance(&artifact()).unwrap();
+        assert_eq!(provenance.label, "gbif+stac");
+        assert_eq!(provenance.dataset_version.as_deref(), Some("gbif:v3+stac:v1"));
+        assert_eq!(provenance.fetched_at, Some(100));
+
+        // Legs without a version are left out.
+        let composite = CompositeEcoSource::new(VersionedSource(gbif(), "v3", 200), stac());
+        let (_, provenance) = composite.calculate_with_provenance(&artifact()).unwrap();
+        assert_eq!(provenance.dataset_version.as_deref(), Some("gbif:v3"));
+        assert_eq!(provenance.fetched_at, Some(200));
+    }
+
+    #[test]
+    fn composite_degrades_to_the_surviving_leg() {
+        let composite =
+            CompositeEcoSource::new(gbif(), FlakySource::new("stac", 1)).with_leg_failure(LegFailure::Degrade);
+        let report = composite.calculate_with_report(&artifact()).unwrap();
+        assert_eq!(report.provenance, "gbif+stac[failed]");
+        assert_eq!(report.metrics(), gbif().1);
+        assert_eq!(
+            report.climate.explanation,
+            "stac failed (rate limited (call 1)); taken from gbif: reported by gbif without an explanation"
+        );
+        assert!(!report.biodiversity.explanation.contains("failed"));
+
+        let composite =
+            CompositeEcoSource::new(FlakySource::new("gbif", 1), stac()).with_leg_failure(LegFailure::Degrade);
+        let (metrics, provenance) = composite.calculate_with_provenance(&artifact()).unwrap();
+        assert_eq!(provenance.label, "gbif[failed]+stac");
+        assert_eq!(metrics, stac().1);
+
+        let both = CompositeEcoSource::new(FlakySource::new("gbif", 1), FlakySource::new("stac", 1))
+            .with_leg_failure(LegFailure::Degrade);
+        assert_eq!(
+            both.calculate(&artifact()).unwrap_err().to_string(),
+            "Both eco sources failed: gbif: rate limited (call 1); stac: rate limited (call 1)"
+        );
+    }
+
+    #[test]
+    fn exhausted_chain_lists_every_failure() {
+        let chain = FallbackEcoSource::new()
+            .with_source(FlakySource::new("gbif", 5), 1)
+            .with_source(FlakySource::new("stac", 5), 0);
+        let err = chain.calculate(&artifact()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "All eco sources failed: gbif (attempt 1/2): rate limited (call 1); \
+             gbif (attempt 2/2): rate limited (call 2); stac (attempt 1/1): rate limited (call 1)"
+        );
+        assert!(FallbackEcoSource::new().calculate(&artifact()).is_err());
+    }
+
+    /// Always fails with `error`.
+    struct BrokenSource(&'static str, EcoSourceError);
+
+    impl EcoDataSource for BrokenSource {
+        fn calculate(&self, _: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+            Err(self.1.clone())
+        }
+
+        fn provenance_label(&self) -> &'static str {
+            self.0
+        }
+    }
+
+    fn stale(label: &'static str, days: u64) -> BrokenSource {
+        BrokenSource(label, EcoSourceError::StaleData {
+            age: Duration::from_secs(days * 86_400),
+            metrics: stac().1,
+        })
+    }
+
+    #[test]
+    fn typed_errors_survive_fallback_and_composite() {
+        let unknown = EcoSourceError::UnknownCorridor(CorridorId("corridor".into()));
+        let chain = FallbackEcoSource::new()
+            .with_source(BrokenSource("gbif", unknown.clone()), 1)
+            .with_source(BrokenSource("stac", unknown.clone()), 0);
+        assert_eq!(chain.calculate(&artifact()).unwrap_err(), unknown);
+        assert_eq!(unknown.to_string(), "unknown corridor \"corridor\"");
+
+        // Mixed failures collapse to Internal, unless one source had stale data.
+        let mixed = FallbackEcoSource::new()
+            .with_source(BrokenSource("gbif", EcoSourceError::UpstreamTimeout), 0)
+            .with_source(BrokenSource("stac", EcoSourceError::UpstreamRejected { status: 503 }), 0);
+        assert_eq!(
+            mixed.calculate(&artifact()).unwrap_err().to_string(),
+            "All eco sources failed: gbif (attempt 1/1): upstream timeout; \
+             stac (attempt 1/1): upstream rejected the request (HTTP 503)"
+        );
+        let with_stale = mixed.with_source(stale("cache", 3), 0);
+        assert!(matches!(
+            with_stale.calculate(&artifact()),
+            Err(EcoSourceError::StaleData { .. })
+        ));
+
+        let closed = CompositeEcoSource::new(gbif(), BrokenSource("stac", EcoSourceError::UpstreamTimeout));
+        assert_eq!(closed.calculate(&artifact()).unwrap_err(), EcoSourceError::UpstreamTimeout);
+    }
+
+    #[test]
+    fn composite_with_a_stale_leg_is_stale() {
+        let composite = CompositeEcoSource::new(gbif(), stale("stac", 40));
+        let Err(EcoSourceError::StaleData { age, metrics }) = composite.calculate(&artifact()) else {
+            panic!("expected stale data");
+        };
+        assert_eq!(age, Duration::from_secs(40 * 86_400));
+        assert_eq!(metrics.climate_score, 0.6);
+        assert_eq!(metrics.biodiversity_score, 0.9);
+    }
 }
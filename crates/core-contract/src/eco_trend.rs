@@ -0,0 +1,373 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::{Duration, SystemTime};
+
+use crate::eco::{CorridorId, EcoImpactMetrics};
+
+/// One EcoImpact dimension tracked over time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EcoDimension {
+    Climate,
+    Biodiversity,
+    Biosphere,
+    Corridor,
+}
+
+impl EcoDimension {
+    pub const ALL: [EcoDimension; 4] = [
+        EcoDimension::Climate,
+        EcoDimension::Biodiversity,
+        EcoDimension::Biosphere,
+        EcoDimension::Corridor,
+    ];
+
+    pub fn value_of(&self, metrics: &EcoImpactMetrics) -> f32 {
+        match self {
+            EcoDimension::Climate => metrics.climate_score,
+            EcoDimension::Biodiversity => metrics.biodiversity_score,
+            EcoDimension::Biosphere => metrics.biosphere_score,
+            EcoDimension::Corridor => metrics.corridor_score,
+        }
+    }
+}
+
+/// A refined metric set recorded for a corridor at a point in time.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EcoTrendSample {
+    pub at: SystemTime,
+    pub metrics: EcoImpactMetrics,
+}
+
+/// Raised when a dimension declines faster than the configured rate.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EcoDriftAlert {
+    pub corridor: CorridorId,
+    pub dimension: EcoDimension,
+    /// Least-squares slope over the window, in score units per day.
+    pub slope: f32,
+    /// Number of samples the slope was computed over.
+    pub window: usize,
+}
+
+/// Receives drift alerts raised while the orchestrator refines EcoImpact.
+pub trait EcoTrendObserver: Send + Sync {
+    fn on_drift_alert(&self, alert: &EcoDriftAlert);
+
+    /// Called when the tracker could not be saved to its store. The sample
+    /// is kept in memory either way.
+    fn on_store_failed(&self, _error: &str) {}
+}
+
+/// Keeps a tracker between runs, so a series can grow across processes.
+pub trait EcoTrendStore: Send + Sync {
+    /// The saved tracker, or `None` when nothing has been saved yet.
+    fn load(&self) -> Result<Option<EcoTrendTracker>, String>;
+
+    fn save(&self, tracker: &EcoTrendTracker) -> Result<(), String>;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EcoTrendConfig {
+    /// Ring-buffer capacity per corridor.
+    pub capacity: usize,
+    /// Number of most recent samples used for rolling means and slopes.
+    pub window: usize,
+    /// Alert when a slope falls below `-max_decline_per_day`.
+    pub max_decline_per_day: f32,
+}
+
+impl Default for EcoTrendConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 256,
+            window: 8,
+            max_decline_per_day: 0.01,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct CorridorTrend {
+    samples: VecDeque<EcoTrendSample>,
+    /// Dimensions currently past the drift threshold; an alert is only raised
+    /// when a dimension enters this set, not on every sample while inside it.
+    drifting: HashSet<EcoDimension>,
+}
+
+/// Per-corridor time series of refined EcoImpact metrics with drift detection.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EcoTrendTracker {
+    cfg: EcoTrendConfig,
+    corridors: HashMap<CorridorId, CorridorTrend>,
+}
+
+impl EcoTrendTracker {
+    pub fn new(cfg: EcoTrendConfig) -> Self {
+        Self {
+            cfg,
+            corridors: HashMap::new(),
+        }
+    }
+
+    /// Same samples under `cfg`; corridors holding more than the new
+    /// capacity keep their most recent samples.
+    pub fn with_config(mut self, cfg: EcoTrendConfig) -> Self {
+        let capacity = cfg.capacity.max(1);
+        for trend in self.corridors.values_mut() {
+            while trend.samples.len() > capacity {
+                trend.samples.pop_front();
+            }
+        }
+        self.cfg = cfg;
+        self
+    }
+
+    /// Copy holding only the samples whose time satisfies `keep`, e.g. those
+    /// inside a reporting period.
+    pub fn samples_where(&self, keep: impl Fn(SystemTime) -> bool) -> Self {
+        let corridors = self
+            .corridors
+            .iter()
+            .map(|(corridor, trend)| {
+                let samples = trend
+                    .samples
+                    .iter()
+                    .filter(|s| keep(s.at))
+                    .cloned()
+                    .collect();
+                let trend = CorridorTrend {
+                    samples,
+                    drifting: trend.drifting.clone(),
+                };
+                (corridor.clone(), trend)
+            })
+            .collect();
+        Self {
+            cfg: self.cfg.clone(),
+            corridors,
+        }
+    }
+
+    /// Append a refined metric set and return any newly raised drift alerts.
+    pub fn record(
+        &mut self,
+        corridor: &CorridorId,
+        metrics: EcoImpactMetrics,
+        at: SystemTime,
+    ) -> Vec<EcoDriftAlert> {
+        let capacity = self.cfg.capacity.max(1);
+        let window = self.cfg.window.max(2);
+        let threshold = -self.cfg.max_decline_per_day.abs();
+
+        let trend = self.corridors.entry(corridor.clone()).or_default();
+        if trend.samples.len() == capacity {
+            trend.samples.pop_front();
+        }
+        trend.samples.push_back(EcoTrendSample { at, metrics });
+
+        let mut alerts = Vec::new();
+        if trend.samples.len() < window {
+            return alerts;
+        }
+
+        for dimension in EcoDimension::ALL {
+            let slope = match window_slope(&trend.samples, window, dimension) {
+                Some(s) => s,
+                None => continue,
+            };
+            if slope < threshold {
+                if trend.drifting.insert(dimension) {
+                    alerts.push(EcoDriftAlert {
+                        corridor: corridor.clone(),
+                        dimension,
+                        slope,
+                        window,
+                    });
+                }
+            } else {
+                trend.drifting.remove(&dimension);
+            }
+        }
+        alerts
+    }
+
+    /// Recorded samples for a corridor, oldest first.
+    pub fn series(&self, corridor: &CorridorId) -> Vec<&EcoTrendSample> {
+        self.corridors
+            .get(corridor)
+            .map(|t| t.samples.iter().collect())
+            .unwrap_or_default()
+    }
+
+    /// Rolling mean of a dimension over the configured window.
+    pub fn rolling_mean(&self, corridor: &CorridorId, dimension: EcoDimension) -> Option<f32> {
+        let trend = self.corridors.get(corridor)?;
+        let n = trend.samples.len().min(self.cfg.window.max(1));
+        if n == 0 {
+            return None;
+        }
+        let sum: f32 = trend
+            .samples
+            .iter()
+            .rev()
+            .take(n)
+            .map(|s| dimension.value_of(&s.metrics))
+            .sum();
+        Some(sum / n as f32)
+    }
+
+    /// Least-squares slope of a dimension over the configured window, per day.
+    pub fn slope(&self, corridor: &CorridorId, dimension: EcoDimension) -> Option<f32> {
+        let trend = self.corridors.get(corridor)?;
+        window_slope(&trend.samples, self.cfg.window.max(2), dimension)
+    }
+
+    pub fn corridors(&self) -> Vec<CorridorId> {
+        self.corridors.keys().cloned().collect()
+    }
+}
+
+fn window_slope(
+    samples: &VecDeque<EcoTrendSample>,
+    window: usize,
+    dimension: EcoDimension,
+) -> Option<f32> {
+    let n = samples.len().min(window);
+    if n < 2 {
+        return None;
+    }
+    let recent: Vec<&EcoTrendSample> = samples.iter().skip(samples.len() - n).collect();
+    let origin = recent[0].at;
+    let day = Duration::from_secs(86_400).as_secs_f64();
+
+    let points: Vec<(f64, f64)> = recent
+        .iter()
+        .map(|s| {
+            let x = s
+                .at
+                .duration_since(origin)
+                .map(|d| d.as_secs_f64() / day)
+                .unwrap_or(0.0);
+            (x, dimension.value_of(&s.metrics) as f64)
+        })
+        .collect();
+
+    let mean_x = points.iter().map(|p| p.0).sum::<f64>() / n as f64;
+    let mean_y = points.iter().map(|p| p.1).sum::<f64>() / n as f64;
+    let var_x: f64 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+    if var_x <= f64::EPSILON {
+        return None;
+    }
+    let cov: f64 = points
+        .iter()
+        .map(|p| (p.0 - mean_x) * (p.1 - mean_y))
+        .sum();
+    Some((cov / var_x) as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::UNIX_EPOCH;
+
+    fn metrics(biodiversity: f32) -> EcoImpactMetrics {
+        EcoImpactMetrics {
+            climate_score: 0.8,
+            biodiversity_score: biodiversity,
+            biosphere_score: 0.8,
+            corridor_score: 0.8,
+        }
+    }
+
+    fn day(n: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(86_400 * n)
+    }
+
+    #[test]
+    fn declining_biodiversity_alerts_once_per_crossing() {
+        let mut tracker = EcoTrendTracker::new(EcoTrendConfig {
+            capacity: 64,
+            window: 4,
+            max_decline_per_day: 0.01,
+        });
+        let corridor = CorridorId("protected-desert-phoenix".into());
+
+        let mut alerts = Vec::new();
+        for i in 0..12 {
+            let score = 0.9 - 0.05 * i as f32;
+            alerts.extend(tracker.record(&corridor, metrics(score), day(i)));
+        }
+
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].dimension, EcoDimension::Biodiversity);
+        assert!(alerts[0].slope < -0.01);
+
+        // Recover, then decline again: a second crossing raises a second alert.
+        for i in 12..20 {
+            alerts.extend(tracker.record(&corridor, metrics(0.5), day(i)));
+        }
+        for i in 20..26 {
+            let score = 0.5 - 0.05 * (i - 19) as f32;
+            alerts.extend(tracker.record(&corridor, metrics(score), day(i)));
+        }
+        assert_eq!(alerts.len(), 2);
+    }
+
+    #[test]
+    fn flat_series_never_alerts() {
+        let mut tracker = EcoTrendTracker::new(EcoTrendConfig::default());
+        let corridor = CorridorId("urban-phoenix-core".into());
+
+        for i in 0..50 {
+            assert!(tracker.record(&corridor, metrics(0.7), day(i)).is_empty());
+        }
+        assert_eq!(tracker.series(&corridor).len(), 50);
+        let slope = tracker.slope(&corridor, EcoDimension::Biodiversity).unwrap();
+        assert!(slope.abs() < 1e-6);
+    }
+
+    #[test]
+    fn narrowing_keeps_recent_samples() {
+        let mut tracker = EcoTrendTracker::new(EcoTrendConfig::default());
+        let corridor = CorridorId("urban-phoenix-core".into());
+        for i in 0..10 {
+            tracker.record(&corridor, metrics(0.1 * i as f32), day(i));
+        }
+
+        let period = tracker.samples_where(|at| at >= day(3) && at < day(6));
+        let at: Vec<_> = period.series(&corridor).iter().map(|s| s.at).collect();
+        assert_eq!(at, [day(3), day(4), day(5)]);
+
+        let narrowed = tracker.with_config(EcoTrendConfig {
+            capacity: 2,
+            ..EcoTrendConfig::default()
+        });
+        let at: Vec<_> = narrowed.series(&corridor).iter().map(|s| s.at).collect();
+        assert_eq!(at, [day(8), day(9)]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn tracker_round_trips_with_drift_state() {
+        let mut tracker = EcoTrendTracker::new(EcoTrendConfig {
+            capacity: 64,
+            window: 4,
+            max_decline_per_day: 0.01,
+        });
+        let corridor = CorridorId("protected-desert-phoenix".into());
+        for i in 0..6 {
+            tracker.record(&corridor, metrics(0.9 - 0.05 * i as f32), day(i));
+        }
+
+        let json = serde_json::to_string(&tracker).unwrap();
+        let mut back: EcoTrendTracker = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.series(&corridor), tracker.series(&corridor));
+        // Still drifting after the reload, so the next decline raises nothing.
+        assert!(back.record(&corridor, metrics(0.55), day(6)).is_empty());
+    }
+}
@@ -1,9 +1,11 @@
 #![forbid(unsafe_code)]
 
+use std::collections::{BTreeMap, HashMap};
 use std::time::{Duration, SystemTime};
 
 /// Minimal FPIC decision state.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FpicStatus {
     Granted,
     Denied,
@@ -14,6 +16,7 @@ pub enum FpicStatus {
 /// `granted_at` is when FPIC was recorded; `max_age` encodes
 /// “sufficiently in advance” + freshness windows.[file:69]
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConsentLifetime {
     pub granted_at: SystemTime,
     pub max_age: Duration,
@@ -30,6 +33,7 @@ impl ConsentLifetime {
 
 /// Runtime FPIC token with an always-available veto hook.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FpicToken {
     status: FpicStatus,
     lifetime: ConsentLifetime,
@@ -48,6 +52,11 @@ impl FpicToken {
         self.status = FpicStatus::Revoked;
     }
 
+    /// False once `now` is past `granted_at + max_age`, whatever the status.
+    pub fn is_fresh(&self, now: SystemTime) -> bool {
+        self.lifetime.is_fresh(now)
+    }
+
     pub fn status(&self, now: SystemTime) -> FpicStatus {
         if !self.lifetime.is_fresh(now) {
             return FpicStatus::Revoked;
@@ -55,3 +64,162 @@ impl FpicToken {
         self.status
     }
 }
+
+/// One delegated sub-token in a consent delegation chain.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DelegationLink {
+    pub token_id: String,
+    /// Token this one was delegated from; the root is `DelegationTree::root_id`.
+    pub parent_id: String,
+    pub delegate_did: String,
+    pub scope: String,
+    pub token: FpicToken,
+}
+
+/// Delegation chain records rooted at a single community token.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DelegationTree {
+    pub root_id: String,
+    pub links: Vec<DelegationLink>,
+}
+
+/// Sub-tokens sharing a delegate and scope that a revocation would invalidate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RevocationGroup {
+    pub delegate_did: String,
+    pub scope: String,
+    pub token_ids: Vec<String>,
+    /// Sum of active sessions reported for these tokens.
+    pub active_sessions: u64,
+}
+
+/// Blast radius of revoking a root token at a given instant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RevocationImpact {
+    pub root_id: String,
+    /// The root was already revoked or stale, so nothing changes.
+    pub root_already_invalid: bool,
+    /// Valid sub-tokens that the revocation would invalidate, grouped.
+    pub newly_revoked: Vec<RevocationGroup>,
+    /// Sub-tokens already invalid at `at` (expired, vetoed, or under an invalid parent).
+    pub already_invalid: Vec<String>,
+    pub total_active_sessions: u64,
+}
+
+/// Simulate revoking `root` at `at` over its delegation tree.
+pub fn simulate_revocation(root: &FpicToken, tree: &DelegationTree, at: SystemTime) -> RevocationImpact {
+    simulate_revocation_with_sessions(root, tree, at, &|_| 0)
+}
+
+/// Like `simulate_revocation`, estimating in-flight work via `active_sessions`,
+/// which maps a token id to its number of active sessions.
+pub fn simulate_revocation_with_sessions(
+    root: &FpicToken,
+    tree: &DelegationTree,
+    at: SystemTime,
+    active_sessions: &dyn Fn(&str) -> u64,
+) -> RevocationImpact {
+    let root_valid = root.status(at) == FpicStatus::Granted;
+
+    let mut children: HashMap<&str, Vec<&DelegationLink>> = HashMap::new();
+    for link in &tree.links {
+        children.entry(link.parent_id.as_str()).or_default().push(link);
+    }
+
+    let mut groups: BTreeMap<(String, String), RevocationGroup> = BTreeMap::new();
+    let mut already_invalid = Vec::new();
+    let mut total_active_sessions = 0;
+
+    // Depth-first walk carrying whether every ancestor is still valid.
+    let mut stack: Vec<(&str, bool)> = vec![(tree.root_id.as_str(), root_valid)];
+    while let Some((parent, parent_valid)) = stack.pop() {
+        for link in children.get(parent).into_iter().flatten() {
+            let valid = parent_valid && link.token.status(at) == FpicStatus::Granted;
+            if valid {
+                let sessions = active_sessions(&link.token_id);
+                total_active_sessions += sessions;
+                let group = groups
+                    .entry((link.delegate_did.clone(), link.scope.clone()))
+                    .or_insert_with(|| RevocationGroup {
+                        delegate_did: link.delegate_did.clone(),
+                        scope: link.scope.clone(),
+                        token_ids: Vec::new(),
+                        active_sessions: 0,
+                    });
+                group.token_ids.push(link.token_id.clone());
+                group.active_sessions += sessions;
+            } else {
+                already_invalid.push(link.token_id.clone());
+            }
+            stack.push((link.token_id.as_str(), valid));
+        }
+    }
+
+    let mut newly_revoked: Vec<RevocationGroup> = groups.into_values().collect();
+    for group in &mut newly_revoked {
+        group.token_ids.sort();
+    }
+    already_invalid.sort();
+
+    RevocationImpact {
+        root_id: tree.root_id.clone(),
+        root_already_invalid: !root_valid,
+        newly_revoked,
+        already_invalid,
+        total_active_sessions,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::UNIX_EPOCH;
+
+    fn link(id: &str, parent: &str, did: &str, scope: &str, max_age_secs: u64) -> DelegationLink {
+        DelegationLink {
+            token_id: id.into(),
+            parent_id: parent.into(),
+            delegate_did: did.into(),
+            scope: scope.into(),
+            token: FpicToken::new(UNIX_EPOCH, Duration::from_secs(max_age_secs)),
+        }
+    }
+
+    #[test]
+    fn three_level_tree_separates_expired_from_newly_revoked() {
+        let at = UNIX_EPOCH + Duration::from_secs(100);
+        let root = FpicToken::new(UNIX_EPOCH, Duration::from_secs(1_000));
+        let tree = DelegationTree {
+            root_id: "root".into(),
+            links: vec![
+                link("a", "root", "did:a", "water", 1_000),
+                link("b", "root", "did:b", "air", 50), // expired before `at`
+                link("a1", "a", "did:a", "water", 1_000),
+                link("a2", "a", "did:c", "soil", 10), // expired leaf
+                link("b1", "b", "did:b", "air", 1_000), // valid itself, parent expired
+                link("a1x", "a1", "did:a", "water", 1_000),
+            ],
+        };
+
+        let impact = simulate_revocation_with_sessions(&root, &tree, at, &|id| {
+            if id == "a1" { 3 } else { 1 }
+        });
+
+        assert!(!impact.root_already_invalid);
+        assert_eq!(impact.already_invalid, vec!["a2", "b", "b1"]);
+        assert_eq!(
+            impact.newly_revoked,
+            vec![RevocationGroup {
+                delegate_did: "did:a".into(),
+                scope: "water".into(),
+                token_ids: vec!["a".into(), "a1".into(), "a1x".into()],
+                active_sessions: 5,
+            }]
+        );
+        assert_eq!(impact.total_active_sessions, 5);
+    }
+}
@@ -0,0 +1,203 @@
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::eco::NeuromorphArtifact;
+use crate::eco_source::{EcoDataSource, EcoSourceError};
+
+/// Readiness of one component; ordered so the worst status is the maximum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HealthStatus {
+    Healthy,
+    /// Working, but slower, staler, or narrower than configured.
+    Degraded,
+    /// Cannot do its job right now.
+    Unhealthy,
+}
+
+/// Result of probing one pluggable backend.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComponentHealth {
+    pub component: String,
+    pub status: HealthStatus,
+    pub detail: String,
+    pub checked_at: SystemTime,
+}
+
+impl ComponentHealth {
+    pub fn new(component: impl Into<String>, status: HealthStatus, detail: impl Into<String>) -> Self {
+        Self {
+            component: component.into(),
+            status,
+            detail: detail.into(),
+            checked_at: SystemTime::now(),
+        }
+    }
+}
+
+/// Implemented by eco sources, governance backends, ledger stores, and
+/// simulators so a node can answer "can I do my job right now".
+///
+/// Probes must be cheap and side-effect free from the caller's point of view.
+pub trait HealthCheckable {
+    fn health(&self) -> ComponentHealth;
+}
+
+/// Node-wide readiness; `overall` is the worst component status.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HealthReport {
+    pub overall: HealthStatus,
+    pub components: Vec<ComponentHealth>,
+}
+
+impl HealthReport {
+    /// Whether the node should report ready (e.g. HTTP 200 vs 503).
+    /// Degraded components still serve requests.
+    pub fn is_ready(&self) -> bool {
+        self.overall != HealthStatus::Unhealthy
+    }
+}
+
+/// Probe every component and aggregate by worst-of. An empty set is healthy.
+pub fn report(components: &[&dyn HealthCheckable]) -> HealthReport {
+    let components: Vec<ComponentHealth> = components.iter().map(|c| c.health()).collect();
+    let overall = components
+        .iter()
+        .map(|c| c.status)
+        .max()
+        .unwrap_or(HealthStatus::Healthy);
+    HealthReport { overall, components }
+}
+
+/// Readiness probe for an `EcoDataSource`: scores a reference artifact and
+/// checks the call succeeds, returns normalized metrics, and is fast enough.
+pub struct EcoSourceProbe<'a, E: EcoDataSource> {
+    pub source: &'a E,
+    pub sample: NeuromorphArtifact,
+    /// Calls slower than this report Degraded.
+    pub slow_after: Duration,
+}
+
+impl<E: EcoDataSource> HealthCheckable for EcoSourceProbe<'_, E> {
+    fn health(&self) -> ComponentHealth {
+        let component = format!("eco_source:{}", self.source.provenance_label());
+        let started = Instant::now();
+        let result = self.source.calculate(&self.sample);
+        let elapsed = started.elapsed();
+
+        match result {
+            // Stale data is still served, in a degraded mode.
+            Err(e @ EcoSourceError::StaleData { .. }) => {
+                ComponentHealth::new(component, HealthStatus::Degraded, format!("calculate failed: {e}"))
+            }
+            Err(e) => ComponentHealth::new(component, HealthStatus::Unhealthy, format!("calculate failed: {e}")),
+            Ok(m) => {
+                let scores = [m.climate_score, m.biodiversity_score, m.biosphere_score, m.corridor_score];
+                if !scores.iter().all(|v| v.is_finite() && (0.0..=1.0).contains(v)) {
+                    ComponentHealth::new(component, HealthStatus::Degraded, "metrics outside [0,1]")
+                } else if elapsed > self.slow_after {
+                    ComponentHealth::new(
+                        component,
+                        HealthStatus::Degraded,
+                        format!("slow response: {} ms", elapsed.as_millis()),
+                    )
+                } else {
+                    ComponentHealth::new(component, HealthStatus::Healthy, format!("ok in {} ms", elapsed.as_millis()))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eco::{CorridorId, EcoImpactMetrics};
+    use crate::labels::DataLabels;
+
+    struct Fixed(HealthStatus);
+
+    impl HealthCheckable for Fixed {
+        fn health(&self) -> ComponentHealth {
+            ComponentHealth::new("fixed", self.0, "")
+        }
+    }
+
+    #[test]
+    fn overall_status_is_worst_of_components() {
+        use HealthStatus::*;
+
+        assert_eq!(report(&[]).overall, Healthy);
+        assert_eq!(report(&[&Fixed(Healthy), &Fixed(Healthy)]).overall, Healthy);
+
+        let degraded = report(&[&Fixed(Healthy), &Fixed(Degraded)]);
+        assert_eq!(degraded.overall, Degraded);
+        assert!(degraded.is_ready());
+
+        let unhealthy = report(&[&Fixed(Unhealthy), &Fixed(Degraded), &Fixed(Healthy)]);
+        assert_eq!(unhealthy.overall, Unhealthy);
+        assert!(!unhealthy.is_ready());
+        assert_eq!(unhealthy.components.len(), 3);
+    }
+
+    struct ScriptedEco(Result<f32, EcoSourceError>, Duration);
+
+    impl EcoDataSource for ScriptedEco {
+        fn calculate(&self, _: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+            std::thread::sleep(self.1);
+            self.0.clone().map(|v| EcoImpactMetrics {
+                climate_score: v,
+                biodiversity_score: v,
+                biosphere_score: v,
+                corridor_score: v,
+            })
+        }
+
+        fn provenance_label(&self) -> &'static str {
+            "scripted"
+        }
+    }
+
+    fn probe(source: &ScriptedEco, slow_after: Duration) -> HealthStatus {
+        EcoSourceProbe {
+            source,
+            sample: NeuromorphArtifact {
+                id: "probe".into(),
+                corridor_id: CorridorId("probe".into()),
+                eco_impact: EcoImpactMetrics {
+                    climate_score: 1.0,
+                    biodiversity_score: 1.0,
+                    biosphere_score: 1.0,
+                    corridor_score: 1.0,
+                },
+                summary: String::new(),
+                data_labels: DataLabels::default(),
+            },
+            slow_after,
+        }
+        .health()
+        .status
+    }
+
+    #[test]
+    fn eco_source_probe_paths() {
+        let minute = Duration::from_secs(60);
+        let slow = Duration::from_millis(5);
+        assert_eq!(probe(&ScriptedEco(Ok(0.8), Duration::ZERO), minute), HealthStatus::Healthy);
+        assert_eq!(probe(&ScriptedEco(Ok(f32::NAN), Duration::ZERO), minute), HealthStatus::Degraded);
+        assert_eq!(probe(&ScriptedEco(Ok(0.8), slow), Duration::from_millis(1)), HealthStatus::Degraded);
+        let timeout = EcoSourceError::UpstreamTimeout;
+        assert_eq!(probe(&ScriptedEco(Err(timeout), Duration::ZERO), minute), HealthStatus::Unhealthy);
+        let stale = EcoSourceError::StaleData {
+            age: Duration::from_secs(86_400),
+            metrics: EcoImpactMetrics {
+                climate_score: 0.8,
+                biodiversity_score: 0.8,
+                biosphere_score: 0.8,
+                corridor_score: 0.8,
+            },
+        };
+        assert_eq!(probe(&ScriptedEco(Err(stale), Duration::ZERO), minute), HealthStatus::Degraded);
+    }
+}
@@ -0,0 +1,53 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::care::CommunityId;
+
+/// Licensing and data-sovereignty labels attached to an artifact
+/// (TK/BC-style notices, CARE usage restrictions).
+///
+/// Labels are carried unchanged from the artifact into every distillation
+/// result and redacted view; nothing downstream may edit or drop them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DataLabels {
+    /// Notices to display with the content, e.g. "TK Attribution".
+    pub notices: Vec<String>,
+    /// When non-empty, the only purposes the content may be used for.
+    pub allowed_purposes: Vec<String>,
+    /// Purposes the content must never be used for; overrides `allowed_purposes`.
+    pub prohibited_purposes: Vec<String>,
+    /// Community that stewards the content, if any.
+    pub steward_community: Option<CommunityId>,
+}
+
+impl DataLabels {
+    /// Whether `purpose` may be served under these labels: it must not be
+    /// prohibited and, if an allow-list is present, must appear on it.
+    pub fn permits(&self, purpose: &str) -> bool {
+        if self.prohibited_purposes.iter().any(|p| p == purpose) {
+            return false;
+        }
+        self.allowed_purposes.is_empty() || self.allowed_purposes.iter().any(|p| p == purpose)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn prohibition_overrides_allow_list() {
+        let labels = DataLabels {
+            notices: vec!["TK Non-Commercial".into()],
+            allowed_purposes: vec!["education".into(), "commercial".into()],
+            prohibited_purposes: vec!["commercial".into()],
+            steward_community: Some(CommunityId("indigenous-phoenix-water-shed".into())),
+        };
+        assert!(labels.permits("education"));
+        assert!(!labels.permits("commercial"));
+        assert!(!labels.permits("research"));
+        assert!(DataLabels::default().permits("research"));
+    }
+}
@@ -0,0 +1,224 @@
+//! Core SNC contract types shared by every Morphix crate.
+//!
+//! Feature flags:
+//! - `std` (default): modules that need clocks, hash maps, or boxed adapters
+//!   (FPIC tokens, sovereign runtime, eco adapters/registry, eco caching,
+//!   eco rate limiting, eco trends, health probes).
+//! - `serde`: serialization derives on the contract types.
+//! - `crypto`: SHA-256 hex stamps; without it stamps use FNV-1a.
+//! - `test-util`: `eco_mock::MockEcoSource`, a scriptable eco source for
+//!   tests.
+//!
+//! With `--no-default-features` only the pure EcoImpact, CARE, and contract
+//! types remain, suitable for edge devices.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+pub mod care;
+pub mod composite;
+pub mod eco;
+pub mod eco_source;
+pub mod labels;
+pub mod roh;
+#[cfg(feature = "serde")]
+mod schema;
+
+#[cfg(feature = "std")]
+pub mod config_version;
+#[cfg(feature = "std")]
+pub mod eco_adapter;
+#[cfg(feature = "std")]
+pub mod eco_adapters_gbif;
+#[cfg(feature = "std")]
+pub mod eco_adapters_stac;
+#[cfg(feature = "std")]
+pub mod eco_cache;
+#[cfg(feature = "std")]
+pub mod eco_core_engine;
+#[cfg(feature = "std")]
+pub mod eco_corridor_bridge;
+#[cfg(feature = "test-util")]
+pub mod eco_mock;
+#[cfg(feature = "std")]
+pub mod eco_rate_limit;
+#[cfg(feature = "std")]
+pub mod eco_registry;
+#[cfg(feature = "std")]
+pub mod eco_trend;
+#[cfg(feature = "std")]
+pub mod fpic;
+#[cfg(feature = "std")]
+pub mod health;
+#[cfg(feature = "std")]
+pub mod sovereign_runtime;
+
+/// Role of the person requesting distilled knowledge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoleTier {
+    Learner,
+    Teacher,
+    Mentor,
+    Researcher,
+}
+
+/// Access class assigned to distilled knowledge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AccessClass {
+    Open,
+    KnowledgeGated,
+    HighAutonomy,
+}
+
+/// Sovereign Neuromorph Contract: predicates every distillation must satisfy.
+pub trait SovereignNeuromorphContract {
+    fn has_explicit_consent(&self) -> bool;
+    fn has_sovereign_abort_control(&self) -> bool;
+    fn is_discipline_personalized_and_non_coercive(&self) -> bool;
+    fn forbids_downgrade_or_rollback(&self) -> bool;
+
+    /// Name of the member contract that fails `predicate`, for contracts
+    /// composed of several; `None` for a single contract.
+    fn failing_member(&self, _predicate: SncPredicate) -> Option<&str> {
+        None
+    }
+}
+
+/// One predicate of `SovereignNeuromorphContract`, in check order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SncPredicate {
+    ExplicitConsent,
+    SovereignAbortControl,
+    NonCoerciveDiscipline,
+    ForbidsDowngradeOrRollback,
+}
+
+impl SncPredicate {
+    pub const ALL: [SncPredicate; 4] = [
+        SncPredicate::ExplicitConsent,
+        SncPredicate::SovereignAbortControl,
+        SncPredicate::NonCoerciveDiscipline,
+        SncPredicate::ForbidsDowngradeOrRollback,
+    ];
+
+    pub fn holds<C: SovereignNeuromorphContract + ?Sized>(self, contract: &C) -> bool {
+        match self {
+            Self::ExplicitConsent => contract.has_explicit_consent(),
+            Self::SovereignAbortControl => contract.has_sovereign_abort_control(),
+            Self::NonCoerciveDiscipline => contract.is_discipline_personalized_and_non_coercive(),
+            Self::ForbidsDowngradeOrRollback => contract.forbids_downgrade_or_rollback(),
+        }
+    }
+}
+
+/// Default SNC: downgrades and rollbacks are structurally forbidden.
+#[derive(Clone, Debug)]
+pub struct DefaultSovereignNeuromorphContract {
+    explicit_consent: bool,
+    sovereign_abort_control: bool,
+    discipline_non_coercive: bool,
+}
+
+impl DefaultSovereignNeuromorphContract {
+    pub fn new(
+        explicit_consent: bool,
+        sovereign_abort_control: bool,
+        discipline_non_coercive: bool,
+    ) -> Self {
+        Self {
+            explicit_consent,
+            sovereign_abort_control,
+            discipline_non_coercive,
+        }
+    }
+}
+
+impl SovereignNeuromorphContract for DefaultSovereignNeuromorphContract {
+    fn has_explicit_consent(&self) -> bool {
+        self.explicit_consent
+    }
+
+    fn has_sovereign_abort_control(&self) -> bool {
+        self.sovereign_abort_control
+    }
+
+    fn is_discipline_personalized_and_non_coercive(&self) -> bool {
+        self.discipline_non_coercive
+    }
+
+    fn forbids_downgrade_or_rollback(&self) -> bool {
+        true
+    }
+}
+
+/// Version of the JSON documents for `NeuromorphArtifact` and
+/// `DistilledKnowledge`, written as their `schema_version` field.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Result of a successful distillation.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        into = "schema::DistilledKnowledgeDocument",
+        try_from = "schema::DistilledKnowledgeDocument"
+    )
+)]
+pub struct DistilledKnowledge {
+    pub role: RoleTier,
+    /// Knowledge-factor F_K in [0,1].
+    pub knowledge_factor: f32,
+    pub access_class: AccessClass,
+    /// Hex stamp binding role, F_K, access class, and eco provenance,
+    /// including its dataset version and fetch time when known.
+    pub hex_stamp: String,
+    pub neurorights_compliant: bool,
+    pub has_biophysical_signal: bool,
+    pub uses_discipline_signals: bool,
+    /// Provenance label of the EcoDataSource that refined EcoImpact.
+    pub eco_provenance: String,
+    /// Version of the eco dataset behind `eco_provenance`, when the source
+    /// reports one.
+    pub eco_dataset_version: Option<String>,
+    /// When that eco data was fetched upstream, in seconds since the Unix
+    /// epoch.
+    pub eco_fetched_at: Option<u64>,
+    /// Data-sovereignty labels copied verbatim from the source artifact.
+    pub data_labels: labels::DataLabels,
+    /// Configuration bundle in force when this result was produced.
+    #[cfg(feature = "std")]
+    pub config_version: Option<config_version::ConfigVersion>,
+}
+
+/// Hex-encoded digest used for `DistilledKnowledge::hex_stamp`.
+#[cfg(feature = "crypto")]
+pub fn hex_stamp(bytes: &[u8]) -> String {
+    use core::fmt::Write;
+    use sha2::{Digest, Sha256};
+
+    let digest = Sha256::digest(bytes);
+    let mut out = String::with_capacity(2 + digest.len() * 2);
+    out.push_str("0x");
+    for b in digest.iter() {
+        let _ = write!(out, "{:02x}", b);
+    }
+    out
+}
+
+/// Hex-encoded digest used for `DistilledKnowledge::hex_stamp`.
+#[cfg(not(feature = "crypto"))]
+pub fn hex_stamp(bytes: &[u8]) -> String {
+    // FNV-1a 64: dependency-free, not collision resistant.
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for b in bytes {
+        hash ^= *b as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    alloc::format!("0x{:016x}", hash)
+}
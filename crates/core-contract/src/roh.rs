@@ -0,0 +1,217 @@
+//! Risk-of-Harm: one scalar type and one ceiling convention for every crate.
+//!
+//! `RoH` is generic over its float so each caller keeps the precision it
+//! already computes in: the guard works in `f32`, the biorail gate in
+//! `f64`, and no value is rounded by the move. The ceiling depends on the
+//! capability tier (0.30 in `ControlledHuman`, none elsewhere), so a
+//! comparison against a bare 0.30 is a bug in every other tier.
+
+/// Capability tiers mirrored from NewRowPrint.PolicyEngine / CapabilityState lattice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CapabilityState {
+    ModelOnly,
+    LabBench,
+    ControlledHuman,
+    GeneralUse,
+}
+
+/// Float types a `RoH` can hold.
+pub trait RoHFloat: Copy + PartialOrd + sealed::Sealed {
+    const ZERO: Self;
+    const ONE: Self;
+    /// The RoH model's ceiling for `ControlledHuman`, 0.30.
+    const CONTROLLED_HUMAN_CEILING: Self;
+}
+
+impl RoHFloat for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const CONTROLLED_HUMAN_CEILING: Self = 0.30;
+}
+
+impl RoHFloat for f64 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const CONTROLLED_HUMAN_CEILING: Self = 0.30;
+}
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// Risk-of-Harm score scalar, governed by .rohmodel.aln (monotone,
+/// RoH_after >= RoH_before, RoH <= 0.30 in CapControlledHuman).
+///
+/// Always in [0, 1] or NaN: every constructor clamps, deserialization
+/// included, so no caller has to remember to.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "RawRoH<T>", bound(deserialize = "T: RoHFloat + serde::Deserialize<'de>")))]
+pub struct RoH<T = f64> {
+    value: T,
+}
+
+/// The wire form of `RoH`, clamped on the way in.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawRoH<T> {
+    value: T,
+}
+
+#[cfg(feature = "serde")]
+impl<T: RoHFloat> From<RawRoH<T>> for RoH<T> {
+    fn from(raw: RawRoH<T>) -> Self {
+        Self::new(raw.value)
+    }
+}
+
+impl<T: RoHFloat> RoH<T> {
+    /// `value` clamped into [0, 1]. NaN stays NaN, so it still fails every
+    /// `within` check.
+    pub fn new(value: T) -> Self {
+        let value = if value < T::ZERO {
+            T::ZERO
+        } else if value > T::ONE {
+            T::ONE
+        } else {
+            value
+        };
+        Self { value }
+    }
+
+    /// Same as `new`; kept for callers written before `new` clamped.
+    pub fn clamped(value: T) -> Self {
+        Self::new(value)
+    }
+
+    pub const fn value(self) -> T {
+        self.value
+    }
+
+    /// Ceiling in force for `state`: 0.30 in `ControlledHuman`, 1.0 (no
+    /// ceiling) in every other tier.
+    pub const fn ceiling_for(state: CapabilityState) -> T {
+        match state {
+            CapabilityState::ControlledHuman => T::CONTROLLED_HUMAN_CEILING,
+            _ => T::ONE,
+        }
+    }
+
+    /// `value ≤ limit`; false for NaN.
+    pub fn within(self, limit: T) -> bool {
+        self.value <= limit
+    }
+
+    /// Over the ceiling for `state`; true for NaN.
+    pub fn exceeds_ceiling(self, state: CapabilityState) -> bool {
+        !self.within(Self::ceiling_for(state))
+    }
+
+    /// RoH never rises across a transition: `self ≤ before`; false for NaN.
+    pub fn is_monotone_after(self, before: Self) -> bool {
+        self.value <= before.value
+    }
+}
+
+impl<T: RoHFloat> From<T> for RoH<T> {
+    /// Clamps, as `new` does.
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<RoH<f32>> for RoH<f64> {
+    /// Exact: every `f32` is an `f64`.
+    fn from(roh: RoH<f32>) -> Self {
+        Self { value: f64::from(roh.value) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cmp::Ordering;
+
+    const STATES: [CapabilityState; 4] = [
+        CapabilityState::ModelOnly,
+        CapabilityState::LabBench,
+        CapabilityState::ControlledHuman,
+        CapabilityState::GeneralUse,
+    ];
+
+    #[test]
+    fn ceilings_match_the_constants_they_replace() {
+        // morphix_guard::default_roh_ceiling and the watchdog used 0.30_f32,
+        // microsociety's gate a bare 0.3_f64.
+        for state in STATES {
+            let expected = if state == CapabilityState::ControlledHuman { 0.30_f32 } else { 1.0 };
+            assert_eq!(RoH::<f32>::ceiling_for(state).to_bits(), expected.to_bits());
+        }
+        assert_eq!(RoH::<f64>::ceiling_for(CapabilityState::ControlledHuman).to_bits(), 0.3_f64.to_bits());
+    }
+
+    #[test]
+    fn comparisons_agree_with_bare_floats() {
+        let just_over = f32::from_bits(0.3_f32.to_bits() + 1);
+        let samples = [0.0_f32, 0.1, 0.29, 0.3, just_over, 0.31, 0.9, 1.0, f32::NAN];
+        for state in STATES {
+            let ceiling = RoH::<f32>::ceiling_for(state);
+            for value in samples {
+                let roh = RoH::new(value);
+                assert_eq!(roh.within(ceiling), value <= ceiling, "{value} in {state:?}");
+                let over = matches!(value.partial_cmp(&ceiling), Some(Ordering::Greater) | None);
+                assert_eq!(roh.exceeds_ceiling(state), over, "{value} in {state:?}");
+                for before in samples {
+                    assert_eq!(roh.is_monotone_after(RoH::new(before)), value <= before);
+                }
+            }
+        }
+        // Capability-aware: 0.5 is over the ceiling only in ControlledHuman.
+        assert!(RoH::new(0.5_f64).exceeds_ceiling(CapabilityState::ControlledHuman));
+        assert!(!RoH::new(0.5_f64).exceeds_ceiling(CapabilityState::LabBench));
+    }
+
+    #[test]
+    fn clamping_matches_f32_clamp() {
+        for value in [-1.0_f32, -0.0, 0.0, 0.2, 1.0, 1.5, f32::INFINITY] {
+            assert_eq!(RoH::clamped(value).value().to_bits(), value.clamp(0.0, 1.0).to_bits(), "{value}");
+        }
+        assert!(RoH::clamped(f32::NAN).value().is_nan());
+        assert!(RoH::clamped(f64::NAN).exceeds_ceiling(CapabilityState::GeneralUse));
+    }
+
+    #[test]
+    fn widening_is_exact() {
+        for value in [0.1_f32, 0.3, f32::from_bits(0.3_f32.to_bits() + 1), 1.0 / 3.0] {
+            let wide: RoH<f64> = RoH::new(value).into();
+            assert_eq!(wide.value() as f32, value);
+            assert_eq!(wide.value(), value as f64);
+        }
+        assert_eq!(RoH::from(0.25_f64), RoH::new(0.25));
+    }
+
+    #[test]
+    fn every_constructor_clamps() {
+        for value in [-0.5_f64, 0.0, 0.3, 1.0, 2.0] {
+            let expected = value.clamp(0.0, 1.0);
+            assert_eq!(RoH::new(value).value(), expected);
+            assert_eq!(RoH::from(value).value(), expected);
+        }
+        assert!(RoH::from(f32::NAN).value().is_nan());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_as_the_guard_struct_did() {
+        let json = serde_json::to_string(&RoH::new(0.25_f32)).unwrap();
+        assert_eq!(json, r#"{"value":0.25}"#);
+        let back: RoH<f32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, RoH::new(0.25));
+        assert_eq!(serde_json::to_string(&CapabilityState::ControlledHuman).unwrap(), r#""ControlledHuman""#);
+        let over: RoH<f64> = serde_json::from_str(r#"{"value":1.5}"#).unwrap();
+        assert_eq!(over.value(), 1.0);
+    }
+}
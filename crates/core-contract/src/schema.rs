@@ -0,0 +1,210 @@
+//! Versioned JSON documents for `NeuromorphArtifact` and
+//! `DistilledKnowledge`.
+//!
+//! Both serialize through the structs below, which add `schema_version`
+//! alongside the fields; deserializing any other version is an error.
+
+use alloc::format;
+use alloc::string::String;
+
+use serde::{Deserialize, Serialize};
+
+use crate::eco::{CorridorId, EcoImpactMetrics, NeuromorphArtifact};
+use crate::labels::DataLabels;
+use crate::{AccessClass, DistilledKnowledge, RoleTier, SCHEMA_VERSION};
+
+fn check_version(found: u32) -> Result<(), String> {
+    if found == SCHEMA_VERSION {
+        Ok(())
+    } else {
+        Err(format!(
+            "unsupported schema_version {found}, expected {SCHEMA_VERSION}"
+        ))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ArtifactDocument {
+    schema_version: u32,
+    id: String,
+    corridor_id: CorridorId,
+    eco_impact: EcoImpactMetrics,
+    summary: String,
+    #[serde(default)]
+    data_labels: DataLabels,
+}
+
+impl From<NeuromorphArtifact> for ArtifactDocument {
+    fn from(a: NeuromorphArtifact) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            id: a.id,
+            corridor_id: a.corridor_id,
+            eco_impact: a.eco_impact,
+            summary: a.summary,
+            data_labels: a.data_labels,
+        }
+    }
+}
+
+impl TryFrom<ArtifactDocument> for NeuromorphArtifact {
+    type Error = String;
+
+    fn try_from(d: ArtifactDocument) -> Result<Self, String> {
+        check_version(d.schema_version)?;
+        Ok(Self {
+            id: d.id,
+            corridor_id: d.corridor_id,
+            eco_impact: d.eco_impact,
+            summary: d.summary,
+            data_labels: d.data_labels,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct DistilledKnowledgeDocument {
+    schema_version: u32,
+    role: RoleTier,
+    knowledge_factor: f32,
+    access_class: AccessClass,
+    hex_stamp: String,
+    neurorights_compliant: bool,
+    has_biophysical_signal: bool,
+    uses_discipline_signals: bool,
+    eco_provenance: String,
+    #[serde(default)]
+    eco_dataset_version: Option<String>,
+    #[serde(default)]
+    eco_fetched_at: Option<u64>,
+    #[serde(default)]
+    data_labels: DataLabels,
+    #[cfg(feature = "std")]
+    #[serde(default)]
+    config_version: Option<crate::config_version::ConfigVersion>,
+}
+
+impl From<DistilledKnowledge> for DistilledKnowledgeDocument {
+    fn from(dk: DistilledKnowledge) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            role: dk.role,
+            knowledge_factor: dk.knowledge_factor,
+            access_class: dk.access_class,
+            hex_stamp: dk.hex_stamp,
+            neurorights_compliant: dk.neurorights_compliant,
+            has_biophysical_signal: dk.has_biophysical_signal,
+            uses_discipline_signals: dk.uses_discipline_signals,
+            eco_provenance: dk.eco_provenance,
+            eco_dataset_version: dk.eco_dataset_version,
+            eco_fetched_at: dk.eco_fetched_at,
+            data_labels: dk.data_labels,
+            #[cfg(feature = "std")]
+            config_version: dk.config_version,
+        }
+    }
+}
+
+impl TryFrom<DistilledKnowledgeDocument> for DistilledKnowledge {
+    type Error = String;
+
+    fn try_from(d: DistilledKnowledgeDocument) -> Result<Self, String> {
+        check_version(d.schema_version)?;
+        Ok(Self {
+            role: d.role,
+            knowledge_factor: d.knowledge_factor,
+            access_class: d.access_class,
+            hex_stamp: d.hex_stamp,
+            neurorights_compliant: d.neurorights_compliant,
+            has_biophysical_signal: d.has_biophysical_signal,
+            uses_discipline_signals: d.uses_discipline_signals,
+            eco_provenance: d.eco_provenance,
+            eco_dataset_version: d.eco_dataset_version,
+            eco_fetched_at: d.eco_fetched_at,
+            data_labels: d.data_labels,
+            #[cfg(feature = "std")]
+            config_version: d.config_version,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn artifact() -> NeuromorphArtifact {
+        NeuromorphArtifact {
+            id: "a-1".into(),
+            corridor_id: CorridorId("phoenix-canal".into()),
+            eco_impact: EcoImpactMetrics {
+                climate_score: 0.9,
+                biodiversity_score: 0.8,
+                biosphere_score: 1.0,
+                corridor_score: 0.5,
+            },
+            summary: "Canal shade survey".into(),
+            data_labels: DataLabels {
+                prohibited_purposes: alloc::vec!["commercial".into()],
+                ..DataLabels::default()
+            },
+        }
+    }
+
+    #[test]
+    fn artifact_round_trips_with_schema_version() {
+        let json = serde_json::to_value(artifact()).unwrap();
+        assert_eq!(json["schema_version"], SCHEMA_VERSION);
+        assert_eq!(json["corridor_id"], "phoenix-canal");
+        assert_eq!(
+            json["eco_impact"]["climate_score"].as_f64(),
+            Some(0.9f32 as f64)
+        );
+        let back: NeuromorphArtifact = serde_json::from_value(json).unwrap();
+        assert_eq!(back, artifact());
+    }
+
+    #[test]
+    fn distilled_knowledge_round_trips_with_schema_version() {
+        let dk = DistilledKnowledge {
+            role: RoleTier::Researcher,
+            knowledge_factor: 0.8,
+            access_class: AccessClass::Open,
+            hex_stamp: "0xabc".into(),
+            neurorights_compliant: true,
+            has_biophysical_signal: false,
+            uses_discipline_signals: false,
+            eco_provenance: "gbif".into(),
+            eco_dataset_version: Some("corridors-2026.10".into()),
+            eco_fetched_at: Some(1_792_022_400),
+            data_labels: artifact().data_labels,
+            #[cfg(feature = "std")]
+            config_version: None,
+        };
+        let json = serde_json::to_string(&dk).unwrap();
+        assert!(json.starts_with(r#"{"schema_version":1,"role":"Researcher","#));
+        let back: DistilledKnowledge = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, dk);
+
+        // Documents written before the eco dataset fields still load.
+        let mut older = serde_json::to_value(&dk).unwrap();
+        let fields = older.as_object_mut().unwrap();
+        fields.remove("eco_dataset_version");
+        fields.remove("eco_fetched_at");
+        let back: DistilledKnowledge = serde_json::from_value(older).unwrap();
+        assert_eq!((back.eco_dataset_version, back.eco_fetched_at), (None, None));
+    }
+
+    #[test]
+    fn other_versions_and_unknown_fields_are_rejected() {
+        let mut json = serde_json::to_value(artifact()).unwrap();
+        json["schema_version"] = 2.into();
+        let err = serde_json::from_value::<NeuromorphArtifact>(json.clone()).unwrap_err();
+        assert!(err.to_string().contains("unsupported schema_version 2"));
+
+        json["schema_version"] = SCHEMA_VERSION.into();
+        json["summry"] = "typo".into();
+        assert!(serde_json::from_value::<NeuromorphArtifact>(json).is_err());
+    }
+}
@@ -1,31 +1,242 @@
-use core_contract::eco::{EcoImpactMetrics, NeuromorphArtifact};
-use core_contract::eco_source::EcoDataSource;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use core_contract::eco::{
+    EcoImpactMetrics, EcoImpactReport, MetricExplanation, NeuromorphArtifact, ProvenanceInfo,
+};
+use core_contract::eco_source::{EcoDataSource, EcoSourceError};
+
+mod registry;
+mod snapshot;
+mod table;
+
+pub use registry::{
+    CorridorExtent, CorridorMetadata, CorridorRegistry, LandUseClass, ProtectionStatus,
+};
+pub use snapshot::{EcoSnapshot, OfflineSnapshotEcoSource};
+pub use table::{EcoScoreTable, TableEcoSource};
 
 /// Stub implementation: in production, call GBIF / planetary APIs.[file:71][file:69]
-pub struct GbifEcoSource;
+/// Scores come from the artifact corridor's `CorridorMetadata`; corridors
+/// missing from the registry are refused. Provenance carries the registry
+/// version as the dataset version and the lookup time as the fetch time.
+pub struct GbifEcoSource {
+    registry: CorridorRegistry,
+    clock: Box<dyn Fn() -> SystemTime + Send + Sync>,
+}
+
+impl GbifEcoSource {
+    pub fn new(registry: CorridorRegistry) -> Self {
+        Self {
+            registry,
+            clock: Box::new(SystemTime::now),
+        }
+    }
+
+    /// Replace the system clock, e.g. with a fixed one in tests.
+    pub fn with_clock(mut self, clock: impl Fn() -> SystemTime + Send + Sync + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    pub fn registry(&self) -> &CorridorRegistry {
+        &self.registry
+    }
+}
 
 impl EcoDataSource for GbifEcoSource {
-    fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, String> {
-        // Placeholder logic: derive scores from corridor_id prefix, then clamp.
-        let corridor = artifact.corridor_id.0.as_str();
-
-        let (climate, biodiversity, biosphere, corridor_score) = if corridor.starts_with("urban") {
-            (0.7_f32, 0.5_f32, 0.6_f32, 0.8_f32)
-        } else if corridor.starts_with("protected") {
-            (0.9_f32, 0.9_f32, 0.95_f32, 0.9_f32)
-        } else {
-            (0.8_f32, 0.7_f32, 0.7_f32, 0.7_f32)
+    fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+        self.calculate_with_report(artifact)
+            .map(|report| report.metrics())
+    }
+
+    fn provenance_label(&self) -> &'static str {
+        "stub-gbif-eco-source-v2"
+    }
+
+    fn provenance_detail(&self) -> ProvenanceInfo {
+        let mut info = ProvenanceInfo::new(self.provenance_label());
+        info.dataset_version = self.registry.version().map(str::to_string);
+        info.fetched_at = (self.clock)()
+            .duration_since(UNIX_EPOCH)
+            .ok()
+            .map(|d| d.as_secs());
+        info
+    }
+
+    fn calculate_with_report(
+        &self,
+        artifact: &NeuromorphArtifact,
+    ) -> Result<EcoImpactReport, EcoSourceError> {
+        let corridor = &artifact.corridor_id;
+        let metadata = self
+            .registry
+            .lookup(corridor)
+            .ok_or_else(|| EcoSourceError::UnknownCorridor(corridor.clone()))?;
+
+        // Placeholder logic: land use sets the baseline, protection lifts
+        // it, and dense settlement erodes connectivity.
+        let (climate, biodiversity, biosphere) = match metadata.land_use_class {
+            LandUseClass::Urban => (0.7_f32, 0.5_f32, 0.6_f32),
+            LandUseClass::Agricultural => (0.75, 0.6, 0.65),
+            LandUseClass::Grassland => (0.8, 0.7, 0.75),
+            LandUseClass::Desert => (0.8, 0.75, 0.8),
+            LandUseClass::Forest => (0.85, 0.8, 0.85),
+            LandUseClass::Wetland => (0.85, 0.85, 0.85),
+        };
+        let (bonus, corridor_score) = match metadata.protection_status {
+            ProtectionStatus::Unprotected => (0.0_f32, 0.7_f32),
+            ProtectionStatus::Managed => (0.05, 0.8),
+            ProtectionStatus::Protected => (0.1, 0.9),
         };
+        let crowding = 0.1 * (metadata.population_density / 1000.0).min(1.0);
 
-        Ok(EcoImpactMetrics {
-            climate_score: climate.clamp(0.0, 1.0),
-            biodiversity_score: biodiversity.clamp(0.0, 1.0),
-            biosphere_score: biosphere.clamp(0.0, 1.0),
-            corridor_score: corridor_score.clamp(0.0, 1.0),
-        })
+        let land_use = format!("{:?}", metadata.land_use_class).to_lowercase();
+        let protection = format!("{:?}", metadata.protection_status).to_lowercase();
+        let sources = vec![format!("corridor-registry:{}", corridor.0)];
+        let explain = |value: f32, explanation: String| MetricExplanation {
+            value: value.clamp(0.0, 1.0),
+            explanation,
+            sources: sources.clone(),
+        };
+        let protection_bonus = |base: f32, bonus: f32| {
+            if bonus > 0.0 {
+                format!("{base} for {land_use} land, +{bonus} for {protection} status")
+            } else {
+                format!("{base} for {land_use} land, no bonus as {protection}")
+            }
+        };
+
+        Ok(EcoImpactReport {
+            climate: explain(
+                climate + bonus / 2.0,
+                protection_bonus(climate, bonus / 2.0),
+            ),
+            biodiversity: explain(biodiversity + bonus, protection_bonus(biodiversity, bonus)),
+            biosphere: explain(biosphere + bonus, protection_bonus(biosphere, bonus)),
+            corridor: explain(
+                corridor_score - crowding,
+                format!(
+                    "{corridor_score} for {protection} status, -{crowding:.3} for {} people/km²",
+                    metadata.population_density
+                ),
+            ),
+            provenance: String::new(),
+            dataset_version: None,
+            fetched_at: None,
+        }
+        .with_provenance(self.provenance_detail()))
     }
+}
 
-    fn provenance_label(&self) -> &'static str {
-        "stub-gbif-eco-source-v1"
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core_contract::eco::CorridorId;
+    use core_contract::labels::DataLabels;
+
+    fn source() -> GbifEcoSource {
+        GbifEcoSource::new(
+            CorridorRegistry::from_toml_str(include_str!("../tests/fixtures/corridors.toml"))
+                .unwrap(),
+        )
+    }
+
+    fn artifact(corridor: &str) -> NeuromorphArtifact {
+        NeuromorphArtifact {
+            id: "a".to_string(),
+            corridor_id: CorridorId(corridor.to_string()),
+            eco_impact: EcoImpactMetrics {
+                climate_score: 1.0,
+                biodiversity_score: 1.0,
+                biosphere_score: 1.0,
+                corridor_score: 1.0,
+            },
+            summary: String::new(),
+            data_labels: DataLabels::default(),
+        }
+    }
+
+    #[test]
+    fn scores_follow_land_use_and_protection() {
+        let source = source();
+        let score = |corridor| source.calculate(&artifact(corridor)).unwrap();
+
+        let desert = score("protected-desert-phoenix");
+        let downtown = score("urban-downtown");
+        let farm = score("farm-south");
+        let forest = score("forest-mogollon-rim");
+        let grassland = score("grassland-sonoita");
+        let park = score("urban-riparian-park");
+
+        assert!((desert.biodiversity_score - 0.85).abs() < 1e-6);
+        assert!((desert.corridor_score - 0.8988).abs() < 1e-4);
+        // 1200 people/km² saturates the crowding penalty.
+        assert!((downtown.corridor_score - 0.6).abs() < 1e-6);
+        assert!(downtown.biodiversity_score < farm.biodiversity_score);
+        assert!(farm.biodiversity_score < grassland.biodiversity_score);
+        assert!(grassland.biodiversity_score < forest.biodiversity_score);
+        // A managed wetland inside the urban namespace is not scored as urban.
+        assert!(park.biodiversity_score > downtown.biodiversity_score);
+    }
+
+    #[test]
+    fn report_explains_each_metric() {
+        let report = source()
+            .calculate_with_report(&artifact("urban-downtown"))
+            .unwrap();
+        assert_eq!(
+            report.biodiversity.explanation,
+            "0.5 for urban land, no bonus as unprotected"
+        );
+        assert_eq!(
+            report.corridor.explanation,
+            "0.7 for unprotected status, -0.100 for 1200 people/km²"
+        );
+        assert_eq!(
+            report.corridor.sources,
+            ["corridor-registry:urban-downtown"]
+        );
+        assert_eq!(
+            source().calculate(&artifact("urban-downtown")).unwrap(),
+            report.metrics()
+        );
+
+        let phoenix = source()
+            .calculate_with_report(&artifact("protected-desert-phoenix"))
+            .unwrap();
+        assert_eq!(
+            phoenix.biosphere.explanation,
+            "0.8 for desert land, +0.1 for protected status"
+        );
+    }
+
+    #[test]
+    fn provenance_names_registry_version_and_lookup_time() {
+        let registry = source().registry().clone().with_version("2026.10");
+        let source = GbifEcoSource::new(registry)
+            .with_clock(|| UNIX_EPOCH + std::time::Duration::from_secs(1_762_041_600));
+        let report = source
+            .calculate_with_report(&artifact("urban-downtown"))
+            .unwrap();
+        assert_eq!(
+            report.provenance_info().to_string(),
+            "stub-gbif-eco-source-v2 (dataset 2026.10, fetched 2025-11-02)"
+        );
+        let (_, provenance) = source
+            .calculate_with_provenance(&artifact("urban-downtown"))
+            .unwrap();
+        assert_eq!(provenance, report.provenance_info());
+    }
+
+    #[test]
+    fn unknown_corridors_are_refused() {
+        let err = source().calculate(&artifact("suburban-edge")).unwrap_err();
+        assert_eq!(
+            err,
+            EcoSourceError::UnknownCorridor(CorridorId("suburban-edge".into()))
+        );
+        assert!(GbifEcoSource::new(CorridorRegistry::new())
+            .calculate(&artifact("protected-desert-phoenix"))
+            .is_err());
     }
 }
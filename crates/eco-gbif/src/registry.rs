@@ -0,0 +1,287 @@
+//! Corridor metadata loaded from a TOML or JSON registry file.
+//!
+//! ```toml
+//! # Optional; reported as the dataset version of eco scores.
+//! version = "2026.10"
+//!
+//! [corridors.protected-desert-phoenix]
+//! extent = { bbox = [-112.35, 33.25, -111.6, 33.9] }
+//! land_use_class = "desert"
+//! protection_status = "protected"
+//! population_density = 12.0
+//!
+//! # Any corridor id starting with "urban-".
+//! [namespaces.urban]
+//! extent = { wkt = "POLYGON((-112.2 33.3, -111.9 33.3, -111.9 33.6, -112.2 33.3))" }
+//! land_use_class = "urban"
+//! protection_status = "unprotected"
+//! population_density = 1200.0
+//! ```
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use core_contract::eco::CorridorId;
+use serde::{Deserialize, Serialize};
+
+/// Geographic footprint of a corridor.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CorridorExtent {
+    /// `[min_lon, min_lat, max_lon, max_lat]` in WGS 84 degrees.
+    Bbox([f64; 4]),
+    Wkt(String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LandUseClass {
+    Urban,
+    Agricultural,
+    Grassland,
+    Desert,
+    Forest,
+    Wetland,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProtectionStatus {
+    Unprotected,
+    /// Managed for conservation without formal protection.
+    Managed,
+    Protected,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CorridorMetadata {
+    pub extent: CorridorExtent,
+    pub land_use_class: LandUseClass,
+    pub protection_status: ProtectionStatus,
+    /// People per km².
+    pub population_density: f32,
+}
+
+#[derive(Deserialize)]
+struct RegistryFile {
+    #[serde(default)]
+    version: Option<String>,
+    #[serde(default)]
+    corridors: HashMap<String, CorridorMetadata>,
+    #[serde(default)]
+    namespaces: HashMap<String, CorridorMetadata>,
+}
+
+/// Metadata by exact corridor id, with namespace entries as a fallback.
+/// A namespace `ns` covers every id of the form `ns-...`; the longest
+/// matching namespace wins.
+#[derive(Clone, Debug, Default)]
+pub struct CorridorRegistry {
+    version: Option<String>,
+    corridors: HashMap<String, CorridorMetadata>,
+    namespaces: HashMap<String, CorridorMetadata>,
+}
+
+impl CorridorRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    pub fn with_corridor(mut self, id: impl Into<String>, metadata: CorridorMetadata) -> Self {
+        self.corridors.insert(id.into(), metadata);
+        self
+    }
+
+    pub fn with_namespace(mut self, prefix: impl Into<String>, metadata: CorridorMetadata) -> Self {
+        self.namespaces.insert(prefix.into(), metadata);
+        self
+    }
+
+    /// Reads a registry file; `.json` files are parsed as JSON, anything
+    /// else as TOML.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read corridor registry {}: {e}", path.display()))?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => Self::from_json_str(&text),
+            _ => Self::from_toml_str(&text),
+        }
+    }
+
+    pub fn from_toml_str(text: &str) -> Result<Self, String> {
+        let file: RegistryFile = toml::from_str(text).map_err(|e| format!("Invalid TOML: {e}"))?;
+        Self::from_file(file)
+    }
+
+    pub fn from_json_str(text: &str) -> Result<Self, String> {
+        let file: RegistryFile =
+            serde_json::from_str(text).map_err(|e| format!("Invalid JSON: {e}"))?;
+        Self::from_file(file)
+    }
+
+    fn from_file(file: RegistryFile) -> Result<Self, String> {
+        let entries = file.corridors.iter().chain(&file.namespaces);
+        for (id, metadata) in entries {
+            validate(id, metadata)?;
+        }
+        if file.namespaces.contains_key("") {
+            return Err("empty corridor namespace".into());
+        }
+        Ok(Self {
+            version: file.version,
+            corridors: file.corridors,
+            namespaces: file.namespaces,
+        })
+    }
+
+    /// Version of the registry file, if it declares one.
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// Metadata registered for exactly this id.
+    pub fn get(&self, id: &CorridorId) -> Option<&CorridorMetadata> {
+        self.corridors.get(&id.0)
+    }
+
+    /// `get`, falling back to the longest namespace covering the id.
+    pub fn lookup(&self, id: &CorridorId) -> Option<&CorridorMetadata> {
+        self.get(id).or_else(|| {
+            self.namespaces
+                .iter()
+                .filter(|(prefix, _)| {
+                    id.0.strip_prefix(prefix.as_str())
+                        .is_some_and(|rest| rest.starts_with('-'))
+                })
+                .max_by_key(|(prefix, _)| prefix.len())
+                .map(|(_, metadata)| metadata)
+        })
+    }
+
+    /// Ids registered exactly, sorted; namespaces are not included.
+    pub fn corridor_ids(&self) -> Vec<CorridorId> {
+        let mut ids: Vec<_> = self.corridors.keys().cloned().map(CorridorId).collect();
+        ids.sort_by(|a, b| a.0.cmp(&b.0));
+        ids
+    }
+
+    pub fn len(&self) -> usize {
+        self.corridors.len() + self.namespaces.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+fn validate(id: &str, metadata: &CorridorMetadata) -> Result<(), String> {
+    if !metadata.population_density.is_finite() || metadata.population_density < 0.0 {
+        return Err(format!(
+            "{id}: invalid population density {}",
+            metadata.population_density
+        ));
+    }
+    match &metadata.extent {
+        CorridorExtent::Bbox([min_lon, min_lat, max_lon, max_lat]) => {
+            if !(min_lon <= max_lon && min_lat <= max_lat) {
+                return Err(format!("{id}: bbox minimum exceeds maximum"));
+            }
+        }
+        CorridorExtent::Wkt(wkt) => {
+            if wkt.trim().is_empty() {
+                return Err(format!("{id}: empty WKT extent"));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../tests/fixtures/corridors.toml");
+
+    fn id(s: &str) -> CorridorId {
+        CorridorId(s.to_string())
+    }
+
+    #[test]
+    fn fixture_resolves_exact_ids_before_namespaces() {
+        let registry = CorridorRegistry::from_toml_str(FIXTURE).unwrap();
+        assert_eq!(registry.len(), 7);
+        assert_eq!(
+            registry.corridor_ids()[0],
+            CorridorId("forest-mogollon-rim".to_string())
+        );
+        assert_eq!(registry.corridor_ids().len(), 4);
+
+        let phoenix = registry.lookup(&id("protected-desert-phoenix")).unwrap();
+        assert_eq!(phoenix.land_use_class, LandUseClass::Desert);
+        assert!(matches!(phoenix.extent, CorridorExtent::Bbox(_)));
+
+        // Exact entry shadows the "urban" namespace.
+        let park = registry.lookup(&id("urban-riparian-park")).unwrap();
+        assert_eq!(park.land_use_class, LandUseClass::Wetland);
+        assert_eq!(
+            registry
+                .lookup(&id("urban-downtown"))
+                .unwrap()
+                .land_use_class,
+            LandUseClass::Urban
+        );
+        // Longest namespace wins.
+        assert_eq!(
+            registry
+                .lookup(&id("farm-irrigated-north"))
+                .unwrap()
+                .protection_status,
+            ProtectionStatus::Managed
+        );
+        assert_eq!(
+            registry
+                .lookup(&id("farm-south"))
+                .unwrap()
+                .protection_status,
+            ProtectionStatus::Unprotected
+        );
+
+        assert!(registry.get(&id("urban-downtown")).is_none());
+        // Namespaces match whole segments only.
+        assert!(registry.lookup(&id("urbanist-hub")).is_none());
+        assert!(registry.lookup(&id("urban")).is_none());
+    }
+
+    #[test]
+    fn json_registry_and_invalid_entries() {
+        let registry = CorridorRegistry::from_json_str(
+            r#"{"corridors": {"wetland-delta": {
+                "extent": {"wkt": "POINT(-114.7 32.1)"},
+                "land_use_class": "wetland",
+                "protection_status": "protected",
+                "population_density": 3.5
+            }}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            registry
+                .lookup(&id("wetland-delta"))
+                .unwrap()
+                .population_density,
+            3.5
+        );
+
+        let inverted = FIXTURE.replace("-112.35, 33.25, -111.6", "-111.5, 33.25, -111.6");
+        assert!(CorridorRegistry::from_toml_str(&inverted)
+            .unwrap_err()
+            .contains("bbox"));
+        let negative = FIXTURE.replace("population_density = 12.0", "population_density = -1.0");
+        assert!(CorridorRegistry::from_toml_str(&negative).is_err());
+        assert!(CorridorRegistry::from_toml_str("corridors = 3").is_err());
+    }
+}
@@ -0,0 +1,472 @@
+//! Offline eco scores: a snapshot exported while online, served in the
+//! field without network access.
+//!
+//! JSON snapshots look like
+//!
+//! ```json
+//! {"exported_at": 1760486400, "source": "stub-gbif-eco-source-v2",
+//!  "corridors": {"protected-desert-phoenix": {"climate_score": 0.85, ...}}}
+//! ```
+//!
+//! and CSV snapshots carry the same fields, with the export metadata in a
+//! leading comment:
+//!
+//! ```text
+//! # exported_at=1760486400 source=stub-gbif-eco-source-v2
+//! corridor_id,climate_score,biodiversity_score,biosphere_score,corridor_score
+//! protected-desert-phoenix,0.85,0.85,0.9,0.8988
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use core_contract::eco::{
+    CorridorId, EcoImpactMetrics, EcoImpactReport, MetricExplanation, NeuromorphArtifact,
+    ProvenanceInfo,
+};
+use core_contract::eco_source::{EcoDataSource, EcoSourceError};
+use serde::{Deserialize, Serialize};
+
+const CSV_HEADER: &str =
+    "corridor_id,climate_score,biodiversity_score,biosphere_score,corridor_score";
+
+/// Metrics per corridor id as exported from an online eco source.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EcoSnapshot {
+    /// Unix seconds.
+    pub exported_at: u64,
+    /// Provenance label of the source the metrics came from.
+    pub source: String,
+    pub corridors: BTreeMap<String, EcoImpactMetrics>,
+}
+
+impl EcoSnapshot {
+    /// Score every corridor through `source`, failing if any corridor
+    /// fails. Corridor ids may not contain commas or whitespace, so that
+    /// the snapshot can be written as CSV.
+    pub fn export<E: EcoDataSource + ?Sized>(
+        source: &E,
+        corridors: &[CorridorId],
+        exported_at: SystemTime,
+    ) -> Result<Self, String> {
+        let mut scored = BTreeMap::new();
+        let mut failures = Vec::new();
+        for corridor in corridors {
+            if corridor.0.is_empty()
+                || corridor
+                    .0
+                    .chars()
+                    .any(|c| c == ',' || c.is_whitespace() || c.is_control())
+            {
+                failures.push(format!("{:?}: not a valid corridor id", corridor.0));
+                continue;
+            }
+            match source.calculate(&probe(corridor)) {
+                Ok(metrics) => {
+                    scored.insert(corridor.0.clone(), metrics);
+                }
+                Err(e) => failures.push(format!("{}: {e}", corridor.0)),
+            }
+        }
+        if !failures.is_empty() {
+            return Err(format!("Cannot export snapshot: {}", failures.join("; ")));
+        }
+        Ok(Self {
+            exported_at: exported_at
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            source: source.provenance_label().to_string(),
+            corridors: scored,
+        })
+    }
+
+    /// `YYYY-MM-DD` of `exported_at`, UTC.
+    pub fn export_date(&self) -> String {
+        utc_date(UNIX_EPOCH + Duration::from_secs(self.exported_at))
+    }
+
+    /// Reads a snapshot; `.csv` files are parsed as CSV, anything else as
+    /// JSON.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read eco snapshot {}: {e}", path.display()))?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("csv") => Self::from_csv_str(&text),
+            _ => Self::from_json_str(&text),
+        }
+    }
+
+    /// Writes the snapshot in the format `load` expects for `path`.
+    pub fn write(&self, path: &Path) -> Result<(), String> {
+        let text = match path.extension().and_then(|e| e.to_str()) {
+            Some("csv") => self.to_csv(),
+            _ => self.to_json()?,
+        };
+        std::fs::write(path, text)
+            .map_err(|e| format!("Cannot write eco snapshot {}: {e}", path.display()))
+    }
+
+    pub fn from_json_str(text: &str) -> Result<Self, String> {
+        let snapshot: Self =
+            serde_json::from_str(text).map_err(|e| format!("Invalid JSON: {e}"))?;
+        snapshot.validate()?;
+        Ok(snapshot)
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("Cannot render snapshot: {e}"))
+    }
+
+    pub fn from_csv_str(text: &str) -> Result<Self, String> {
+        let mut lines = text.lines().enumerate();
+        let meta = lines
+            .next()
+            .and_then(|(_, line)| line.strip_prefix('#'))
+            .ok_or("line 1: expected `# exported_at=… source=…`")?;
+        let (mut exported_at, mut source) = (None, None);
+        for field in meta.split_whitespace() {
+            match field.split_once('=') {
+                Some(("exported_at", v)) => {
+                    exported_at = Some(
+                        v.parse::<u64>()
+                            .map_err(|e| format!("line 1: exported_at {v:?}: {e}"))?,
+                    )
+                }
+                Some(("source", v)) => source = Some(v.to_string()),
+                _ => return Err(format!("line 1: unexpected field {field:?}")),
+            }
+        }
+        match lines.next() {
+            Some((_, header)) if header.trim() == CSV_HEADER => {}
+            _ => return Err(format!("line 2: expected header `{CSV_HEADER}`")),
+        }
+
+        let mut corridors = BTreeMap::new();
+        for (i, line) in lines {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let row = i + 1;
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let [id, climate, biodiversity, biosphere, corridor] = fields[..] else {
+                return Err(format!(
+                    "line {row}: expected 5 fields, found {}",
+                    fields.len()
+                ));
+            };
+            let number = |name: &str, v: &str| {
+                v.parse::<f32>()
+                    .map_err(|e| format!("line {row}: {name} {v:?}: {e}"))
+            };
+            let metrics = EcoImpactMetrics {
+                climate_score: number("climate_score", climate)?,
+                biodiversity_score: number("biodiversity_score", biodiversity)?,
+                biosphere_score: number("biosphere_score", biosphere)?,
+                corridor_score: number("corridor_score", corridor)?,
+            };
+            if corridors.insert(id.to_string(), metrics).is_some() {
+                return Err(format!("line {row}: duplicate corridor {id}"));
+            }
+        }
+
+        let snapshot = Self {
+            exported_at: exported_at.ok_or("line 1: missing exported_at")?,
+            source: source.ok_or("line 1: missing source")?,
+            corridors,
+        };
+        snapshot.validate()?;
+        Ok(snapshot)
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut out = format!(
+            "# exported_at={} source={}\n{CSV_HEADER}\n",
+            self.exported_at, self.source
+        );
+        for (id, m) in &self.corridors {
+            out.push_str(&format!(
+                "{id},{},{},{},{}\n",
+                m.climate_score, m.biodiversity_score, m.biosphere_score, m.corridor_score
+            ));
+        }
+        out
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.source.is_empty() || self.source.chars().any(char::is_whitespace) {
+            return Err(format!("snapshot source {:?} is not a label", self.source));
+        }
+        for (id, m) in &self.corridors {
+            for (field, value) in [
+                ("climate_score", m.climate_score),
+                ("biodiversity_score", m.biodiversity_score),
+                ("biosphere_score", m.biosphere_score),
+                ("corridor_score", m.corridor_score),
+            ] {
+                if !(0.0..=1.0).contains(&value) {
+                    return Err(format!("{id}: {field} = {value} is outside [0, 1]"));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Placeholder artifact for scoring a corridor during export.
+fn probe(corridor: &CorridorId) -> NeuromorphArtifact {
+    NeuromorphArtifact {
+        id: format!("snapshot-probe-{}", corridor.0),
+        corridor_id: corridor.clone(),
+        eco_impact: EcoImpactMetrics {
+            climate_score: 1.0,
+            biodiversity_score: 1.0,
+            biosphere_score: 1.0,
+            corridor_score: 1.0,
+        },
+        summary: "eco snapshot export".to_string(),
+        data_labels: Default::default(),
+    }
+}
+
+/// Serves an `EcoSnapshot` by exact corridor id. Provenance reads
+/// "offline-snapshot:{source}@{export date}", fetched at the export time.
+/// Once the snapshot is older
+/// than `staleness` (30 days unless set) every lookup fails with
+/// `EcoSourceError::StaleData`, carrying the snapshot's metrics.
+pub struct OfflineSnapshotEcoSource {
+    snapshot: EcoSnapshot,
+    staleness: Duration,
+    clock: Box<dyn Fn() -> SystemTime + Send + Sync>,
+}
+
+impl OfflineSnapshotEcoSource {
+    pub fn new(snapshot: EcoSnapshot) -> Self {
+        Self {
+            snapshot,
+            staleness: Duration::from_secs(30 * 86_400),
+            clock: Box::new(SystemTime::now),
+        }
+    }
+
+    pub fn with_staleness(mut self, staleness: Duration) -> Self {
+        self.staleness = staleness;
+        self
+    }
+
+    /// Replace the system clock, e.g. with a fixed one in tests.
+    pub fn with_clock(mut self, clock: impl Fn() -> SystemTime + Send + Sync + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    pub fn snapshot(&self) -> &EcoSnapshot {
+        &self.snapshot
+    }
+
+    /// Time since export, if past the staleness threshold.
+    pub fn stale_by(&self) -> Option<Duration> {
+        let exported = UNIX_EPOCH + Duration::from_secs(self.snapshot.exported_at);
+        (self.clock)()
+            .duration_since(exported)
+            .ok()
+            .filter(|age| *age > self.staleness)
+    }
+}
+
+impl EcoDataSource for OfflineSnapshotEcoSource {
+    fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+        let corridor = &artifact.corridor_id;
+        let metrics = self
+            .snapshot
+            .corridors
+            .get(&corridor.0)
+            .cloned()
+            .ok_or_else(|| EcoSourceError::UnknownCorridor(corridor.clone()))?;
+        match self.stale_by() {
+            Some(age) => Err(EcoSourceError::StaleData { age, metrics }),
+            None => Ok(metrics),
+        }
+    }
+
+    /// Provenance with the export date comes from `provenance_detail`.
+    fn provenance_label(&self) -> &'static str {
+        "offline-snapshot"
+    }
+
+    fn provenance_detail(&self) -> ProvenanceInfo {
+        ProvenanceInfo::new(format!(
+            "offline-snapshot:{}@{}",
+            self.snapshot.source,
+            self.snapshot.export_date()
+        ))
+        .with_fetched_at(self.snapshot.exported_at)
+    }
+
+    fn calculate_with_report(
+        &self,
+        artifact: &NeuromorphArtifact,
+    ) -> Result<EcoImpactReport, EcoSourceError> {
+        let metrics = self.calculate(artifact)?;
+        let date = self.snapshot.export_date();
+        let explanation = format!("from the {} snapshot exported {date}", self.snapshot.source);
+        let explain = |value| MetricExplanation {
+            value,
+            explanation: explanation.clone(),
+            sources: vec![format!("snapshot:{}@{date}", self.snapshot.source)],
+        };
+        Ok(EcoImpactReport {
+            climate: explain(metrics.climate_score),
+            biodiversity: explain(metrics.biodiversity_score),
+            biosphere: explain(metrics.biosphere_score),
+            corridor: explain(metrics.corridor_score),
+            provenance: String::new(),
+            dataset_version: None,
+            fetched_at: None,
+        }
+        .with_provenance(self.provenance_detail()))
+    }
+}
+
+/// `YYYY-MM-DD` for the UTC day containing `at`.
+fn utc_date(at: SystemTime) -> String {
+    let days = at
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() / 86_400)
+        .unwrap_or(0) as i64;
+    // Civil-from-days (Howard Hinnant), valid for the proleptic Gregorian calendar.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CorridorRegistry, GbifEcoSource};
+
+    /// 2026-10-15T00:00:00Z.
+    const EXPORTED_AT: u64 = 1_792_022_400;
+
+    fn exported() -> EcoSnapshot {
+        let gbif = GbifEcoSource::new(
+            CorridorRegistry::from_toml_str(include_str!("../tests/fixtures/corridors.toml"))
+                .unwrap(),
+        );
+        let corridors: Vec<CorridorId> =
+            ["protected-desert-phoenix", "urban-downtown", "farm-south"]
+                .into_iter()
+                .map(|id| CorridorId(id.to_string()))
+                .collect();
+        EcoSnapshot::export(
+            &gbif,
+            &corridors,
+            UNIX_EPOCH + Duration::from_secs(EXPORTED_AT),
+        )
+        .unwrap()
+    }
+
+    fn artifact(corridor: &str) -> NeuromorphArtifact {
+        probe(&CorridorId(corridor.to_string()))
+    }
+
+    #[test]
+    fn snapshot_round_trips_through_json_and_csv() {
+        let snapshot = exported();
+        assert_eq!(snapshot.export_date(), "2026-10-15");
+        assert_eq!(snapshot.source, "stub-gbif-eco-source-v2");
+        assert_eq!(snapshot.corridors.len(), 3);
+
+        let json = snapshot.to_json().unwrap();
+        assert_eq!(EcoSnapshot::from_json_str(&json).unwrap(), snapshot);
+        let csv = snapshot.to_csv();
+        assert!(csv.starts_with("# exported_at=1792022400 source=stub-gbif-eco-source-v2\n"));
+        assert_eq!(EcoSnapshot::from_csv_str(&csv).unwrap(), snapshot);
+
+        let dir = std::env::temp_dir().join(format!("eco-snapshot-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in ["snapshot.json", "snapshot.csv"] {
+            let path = dir.join(name);
+            snapshot.write(&path).unwrap();
+            assert_eq!(EcoSnapshot::load(&path).unwrap(), snapshot);
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn export_and_load_reject_bad_input() {
+        let gbif = GbifEcoSource::new(CorridorRegistry::new());
+        let err = EcoSnapshot::export(
+            &gbif,
+            &[CorridorId("a,b".into()), CorridorId("nowhere".into())],
+            UNIX_EPOCH,
+        )
+        .unwrap_err();
+        assert!(err.contains("\"a,b\": not a valid corridor id"), "{err}");
+        assert!(err.contains("nowhere: unknown corridor"), "{err}");
+
+        let csv = exported().to_csv().replace("0.85,", "1.5,");
+        assert!(EcoSnapshot::from_csv_str(&csv)
+            .unwrap_err()
+            .contains("outside [0, 1]"));
+        assert_eq!(
+            EcoSnapshot::from_csv_str(&format!(
+                "# exported_at=1 source=s\n{CSV_HEADER}\nx,0.1,0.1\n"
+            ))
+            .unwrap_err(),
+            "line 3: expected 5 fields, found 3"
+        );
+        assert!(EcoSnapshot::from_csv_str(CSV_HEADER).is_err());
+    }
+
+    #[test]
+    fn offline_source_serves_by_corridor_and_flags_stale_data() {
+        let day = |n: u64| UNIX_EPOCH + Duration::from_secs(EXPORTED_AT + n * 86_400);
+        let source = OfflineSnapshotEcoSource::new(exported()).with_clock(move || day(10));
+        let (metrics, provenance) = source
+            .calculate_with_provenance(&artifact("urban-downtown"))
+            .unwrap();
+        assert_eq!(metrics, exported().corridors["urban-downtown"]);
+        assert_eq!(
+            provenance.label,
+            "offline-snapshot:stub-gbif-eco-source-v2@2026-10-15"
+        );
+        // Fetched when exported, however long ago that was.
+        assert_eq!(provenance.fetched_at, Some(EXPORTED_AT));
+        assert!(source.stale_by().is_none());
+        let report = source
+            .calculate_with_report(&artifact("urban-downtown"))
+            .unwrap();
+        assert_eq!(report.provenance_info(), provenance);
+        assert_eq!(
+            report.climate.explanation,
+            "from the stub-gbif-eco-source-v2 snapshot exported 2026-10-15"
+        );
+
+        let stale = OfflineSnapshotEcoSource::new(exported())
+            .with_staleness(Duration::from_secs(7 * 86_400))
+            .with_clock(move || day(10));
+        assert_eq!(
+            stale.calculate_with_report(&artifact("urban-downtown")),
+            Err(EcoSourceError::StaleData {
+                age: Duration::from_secs(10 * 86_400),
+                metrics: metrics.clone(),
+            })
+        );
+
+        assert_eq!(
+            source.calculate(&artifact("forest-mogollon-rim")),
+            Err(EcoSourceError::UnknownCorridor(CorridorId(
+                "forest-mogollon-rim".into()
+            )))
+        );
+    }
+}
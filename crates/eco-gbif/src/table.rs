@@ -0,0 +1,233 @@
+//! Eco scores read from a per-deployment table instead of code.
+//!
+//! ```toml
+//! # Used for any land-use class without its own row.
+//! [default]
+//! climate_score = 0.8
+//! biodiversity_score = 0.7
+//! biosphere_score = 0.7
+//! corridor_score = 0.7
+//!
+//! [classes.urban]
+//! climate_score = 0.7
+//! biodiversity_score = 0.5
+//! biosphere_score = 0.6
+//! corridor_score = 0.8
+//! ```
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use core_contract::eco::{EcoImpactMetrics, NeuromorphArtifact};
+use core_contract::eco_source::{EcoDataSource, EcoSourceError};
+use serde::Deserialize;
+
+use crate::{CorridorRegistry, LandUseClass};
+
+/// `EcoImpactMetrics` by land-use class. Every value is checked to lie in
+/// [0, 1] on load.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct EcoScoreTable {
+    /// Fallback row for classes missing from `classes`. Without it such
+    /// classes are refused.
+    #[serde(default)]
+    pub default: Option<EcoImpactMetrics>,
+    #[serde(default)]
+    pub classes: HashMap<LandUseClass, EcoImpactMetrics>,
+}
+
+impl EcoScoreTable {
+    /// Reads a table file; `.json` files are parsed as JSON, anything else
+    /// as TOML.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read eco table {}: {e}", path.display()))?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => Self::from_json_str(&text),
+            _ => Self::from_toml_str(&text),
+        }
+    }
+
+    pub fn from_toml_str(text: &str) -> Result<Self, String> {
+        let table: Self = toml::from_str(text).map_err(|e| format!("Invalid TOML: {e}"))?;
+        table.validate()?;
+        Ok(table)
+    }
+
+    pub fn from_json_str(text: &str) -> Result<Self, String> {
+        let table: Self = serde_json::from_str(text).map_err(|e| format!("Invalid JSON: {e}"))?;
+        table.validate()?;
+        Ok(table)
+    }
+
+    /// Fails on the first value outside [0, 1], naming its row and field.
+    pub fn validate(&self) -> Result<(), String> {
+        let mut classes: Vec<_> = self.classes.iter().collect();
+        classes.sort_by_key(|(class, _)| format!("{class:?}"));
+        let rows = self
+            .default
+            .iter()
+            .map(|m| ("default".to_string(), m))
+            .chain(classes.into_iter().map(|(class, m)| (row_name(*class), m)));
+        for (row, metrics) in rows {
+            for (field, value) in [
+                ("climate_score", metrics.climate_score),
+                ("biodiversity_score", metrics.biodiversity_score),
+                ("biosphere_score", metrics.biosphere_score),
+                ("corridor_score", metrics.corridor_score),
+            ] {
+                if !(0.0..=1.0).contains(&value) {
+                    return Err(format!(
+                        "eco table row {row}: {field} = {value} is outside [0, 1]"
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The row for `class`, or the default row.
+    pub fn row(&self, class: LandUseClass) -> Option<&EcoImpactMetrics> {
+        self.classes.get(&class).or(self.default.as_ref())
+    }
+}
+
+/// The class as written in table files.
+fn row_name(class: LandUseClass) -> String {
+    serde_json::to_value(class)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_else(|| format!("{class:?}"))
+}
+
+/// `EcoDataSource` that scores an artifact with the `EcoScoreTable` row for
+/// its corridor's land-use class. Corridors missing from the registry, and
+/// classes with neither a row nor a default, are refused.
+pub struct TableEcoSource {
+    registry: CorridorRegistry,
+    table: EcoScoreTable,
+}
+
+impl TableEcoSource {
+    pub fn new(registry: CorridorRegistry, table: EcoScoreTable) -> Self {
+        Self { registry, table }
+    }
+
+    pub fn table(&self) -> &EcoScoreTable {
+        &self.table
+    }
+}
+
+impl EcoDataSource for TableEcoSource {
+    fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+        let corridor = &artifact.corridor_id;
+        let metadata = self
+            .registry
+            .lookup(corridor)
+            .ok_or_else(|| EcoSourceError::UnknownCorridor(corridor.clone()))?;
+        self.table
+            .row(metadata.land_use_class)
+            .cloned()
+            .ok_or_else(|| {
+                EcoSourceError::Internal(format!(
+                    "eco table has no row for {} and no default",
+                    row_name(metadata.land_use_class)
+                ))
+            })
+    }
+
+    fn provenance_label(&self) -> &'static str {
+        "table-eco-source-v1"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core_contract::eco::CorridorId;
+    use core_contract::labels::DataLabels;
+
+    const TABLE: &str = r#"
+        [default]
+        climate_score = 0.8
+        biodiversity_score = 0.7
+        biosphere_score = 0.7
+        corridor_score = 0.7
+
+        [classes.urban]
+        climate_score = 0.7
+        biodiversity_score = 0.5
+        biosphere_score = 0.6
+        corridor_score = 0.8
+
+        [classes.desert]
+        climate_score = 0.9
+        biodiversity_score = 0.9
+        biosphere_score = 0.95
+        corridor_score = 0.9
+    "#;
+
+    fn artifact(corridor: &str) -> NeuromorphArtifact {
+        NeuromorphArtifact {
+            id: "a".to_string(),
+            corridor_id: CorridorId(corridor.to_string()),
+            eco_impact: EcoImpactMetrics {
+                climate_score: 1.0,
+                biodiversity_score: 1.0,
+                biosphere_score: 1.0,
+                corridor_score: 1.0,
+            },
+            summary: String::new(),
+            data_labels: DataLabels::default(),
+        }
+    }
+
+    fn registry() -> CorridorRegistry {
+        CorridorRegistry::from_toml_str(include_str!("../tests/fixtures/corridors.toml")).unwrap()
+    }
+
+    #[test]
+    fn rows_by_class_fall_back_to_default() {
+        let source = TableEcoSource::new(registry(), EcoScoreTable::from_toml_str(TABLE).unwrap());
+        let downtown = source.calculate(&artifact("urban-downtown")).unwrap();
+        assert_eq!(downtown.biodiversity_score, 0.5);
+        let phoenix = source
+            .calculate(&artifact("protected-desert-phoenix"))
+            .unwrap();
+        assert_eq!(phoenix.biosphere_score, 0.95);
+        // Forest has no row.
+        let rim = source.calculate(&artifact("forest-mogollon-rim")).unwrap();
+        assert_eq!(rim, source.table().default.clone().unwrap());
+
+        assert!(source.calculate(&artifact("suburb-edge")).is_err());
+
+        let no_default = EcoScoreTable {
+            default: None,
+            ..source.table().clone()
+        };
+        let err = TableEcoSource::new(registry(), no_default)
+            .calculate(&artifact("forest-mogollon-rim"))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "eco table has no row for forest and no default"
+        );
+    }
+
+    #[test]
+    fn out_of_range_value_names_its_row() {
+        let bad = TABLE.replace("biodiversity_score = 0.5", "biodiversity_score = 1.3");
+        assert_eq!(
+            EcoScoreTable::from_toml_str(&bad).unwrap_err(),
+            "eco table row urban: biodiversity_score = 1.3 is outside [0, 1]"
+        );
+
+        let json = r#"{"default": {"climate_score": 0.5, "biodiversity_score": 0.5,
+            "biosphere_score": -0.1, "corridor_score": 0.5}}"#;
+        assert_eq!(
+            EcoScoreTable::from_json_str(json).unwrap_err(),
+            "eco table row default: biosphere_score = -0.1 is outside [0, 1]"
+        );
+        assert!(EcoScoreTable::from_toml_str("[classes.tundra]\nclimate_score = 0.5").is_err());
+    }
+}
@@ -0,0 +1,55 @@
+//! Push notification of FPIC status changes.
+//!
+//! A subscriber receives every event published after it subscribed, in
+//! publication order; events published before `subscribe()` are never
+//! replayed. A subscriber that falls more than the stream's capacity behind
+//! gets `RecvError::Lagged` and should re-read the statuses it cares about.
+
+use std::time::SystemTime;
+
+use tokio::sync::broadcast;
+
+use crate::{CommunityId, FpicStatus};
+
+/// Events buffered per subscriber before it starts lagging.
+pub const DEFAULT_EVENT_CAPACITY: usize = 256;
+
+/// A recorded FPIC status change.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GovernanceEvent {
+    pub proposal_id: String,
+    pub community_id: CommunityId,
+    pub new_status: FpicStatus,
+    pub at: SystemTime,
+}
+
+/// Broadcast channel of `GovernanceEvent`s; clones publish to the same
+/// subscribers.
+#[derive(Clone, Debug)]
+pub struct GovernanceEventStream {
+    sender: broadcast::Sender<GovernanceEvent>,
+}
+
+impl GovernanceEventStream {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    /// Receive events published from now on.
+    pub fn subscribe(&self) -> broadcast::Receiver<GovernanceEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Send `event` to current subscribers and return how many there were.
+    pub fn publish(&self, event: GovernanceEvent) -> usize {
+        self.sender.send(event).unwrap_or(0)
+    }
+}
+
+impl Default for GovernanceEventStream {
+    fn default() -> Self {
+        Self::new(DEFAULT_EVENT_CAPACITY)
+    }
+}
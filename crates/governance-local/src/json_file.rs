@@ -0,0 +1,590 @@
+//! Reference `CommunityGovernanceBackend` backed by an append-only JSONL
+//! file. Each line is a `CommunityVoteResult`, a `{"proposal": ..}`
+//! registration, or a `{"proposal_state": {"proposal_id": .., "state": ..}}`
+//! lifecycle move.
+//!
+//! The file is replayed into an in-memory index when opened; the latest
+//! line for a (proposal, community) pair is its current status. Appends are
+//! serialized behind a mutex and written as one complete line each, so
+//! concurrent `record_fpic_result` calls from one process never interleave.
+//! A final line cut short by a crash was never acknowledged to its caller;
+//! opening the file truncates it away.
+//!
+//! Each recorded vote is also published as a `GovernanceEvent`; replaying
+//! the file on open publishes nothing.
+
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use serde_json::{json, Value};
+
+use crate::{
+    CommunityGovernanceBackend, CommunityId, CommunityVoteResult, FpicStatus, GovernanceEvent,
+    GovernanceEventStream, GovernanceProposal, ProposalState, ProposalTransition,
+};
+
+struct State {
+    file: File,
+    index: HashMap<(String, CommunityId), FpicStatus>,
+    /// Registered proposals in registration order, with their states.
+    proposals: Vec<(GovernanceProposal, ProposalState)>,
+}
+
+impl State {
+    fn proposal_mut(
+        &mut self,
+        proposal_id: &str,
+    ) -> Option<&mut (GovernanceProposal, ProposalState)> {
+        self.proposals.iter_mut().find(|(p, _)| p.id == proposal_id)
+    }
+
+    fn replay(&mut self, line: &str) -> Result<(), String> {
+        let mut value: Value = serde_json::from_str(line).map_err(|e| e.to_string())?;
+        if let Some(proposal) = value.get_mut("proposal") {
+            let proposal: GovernanceProposal =
+                serde_json::from_value(proposal.take()).map_err(|e| e.to_string())?;
+            self.proposals.push((proposal, ProposalState::Draft));
+        } else if let Some(moved) = value.get_mut("proposal_state") {
+            let proposal_id = moved["proposal_id"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string();
+            let state: ProposalState =
+                serde_json::from_value(moved["state"].take()).map_err(|e| e.to_string())?;
+            let entry = self
+                .proposal_mut(&proposal_id)
+                .ok_or_else(|| format!("state for unregistered proposal {proposal_id:?}"))?;
+            entry.1 = state;
+        } else {
+            let result: CommunityVoteResult =
+                serde_json::from_value(value).map_err(|e| e.to_string())?;
+            self.index.insert(
+                (result.proposal_id, result.community_id),
+                result.fpic_status,
+            );
+        }
+        Ok(())
+    }
+}
+
+pub struct JsonFileGovernanceBackend {
+    path: PathBuf,
+    state: Mutex<State>,
+    events: GovernanceEventStream,
+}
+
+impl JsonFileGovernanceBackend {
+    /// Opens (or creates) the log at `path` and replays it. Fails on a
+    /// complete line that is not a valid `CommunityVoteResult`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref().to_path_buf();
+        let io_err = |e: std::io::Error| format!("{}: {e}", path.display());
+        let file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(&path)
+            .map_err(io_err)?;
+
+        let mut state = State {
+            file: file.try_clone().map_err(io_err)?,
+            index: HashMap::new(),
+            proposals: Vec::new(),
+        };
+        let mut complete_len = 0u64;
+        let mut torn = false;
+        let mut reader = BufReader::new(&file);
+        let mut line = String::new();
+        let mut number = 0;
+        loop {
+            line.clear();
+            let read = reader.read_line(&mut line).map_err(io_err)?;
+            if read == 0 {
+                break;
+            }
+            if !line.ends_with('\n') {
+                torn = true;
+                break;
+            }
+            number += 1;
+            complete_len += read as u64;
+            if line.trim().is_empty() {
+                continue;
+            }
+            state
+                .replay(&line)
+                .map_err(|e| format!("{}:{number}: {e}", path.display()))?;
+        }
+        if torn {
+            file.set_len(complete_len).map_err(io_err)?;
+        }
+
+        Ok(Self {
+            path,
+            state: Mutex::new(state),
+            events: GovernanceEventStream::default(),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Events for votes recorded from now on; see `GovernanceEventStream`.
+    pub fn subscribe(&self) -> tokio::sync::broadcast::Receiver<GovernanceEvent> {
+        self.events.subscribe()
+    }
+
+    pub fn events(&self) -> &GovernanceEventStream {
+        &self.events
+    }
+
+    /// Register `proposal` as a `Draft`. Fails if its id is already
+    /// registered.
+    pub fn record_proposal(&self, proposal: GovernanceProposal) -> Result<(), String> {
+        let line = json!({ "proposal": &proposal });
+        let mut state = self.state.lock().map_err(|e| e.to_string())?;
+        if state.proposal_mut(&proposal.id).is_some() {
+            return Err(format!("proposal {:?} is already registered", proposal.id));
+        }
+        self.append(&mut state, &line)?;
+        state.proposals.push((proposal, ProposalState::Draft));
+        Ok(())
+    }
+
+    /// Write `line` plus a newline and sync it; called with the lock held.
+    fn append(&self, state: &mut State, line: &impl serde::Serialize) -> Result<(), String> {
+        let mut line = serde_json::to_string(line).map_err(|e| e.to_string())?;
+        line.push('\n');
+        state
+            .file
+            .write_all(line.as_bytes())
+            .and_then(|()| state.file.sync_data())
+            .map_err(|e| format!("{}: {e}", self.path.display()))
+    }
+}
+
+impl CommunityGovernanceBackend for JsonFileGovernanceBackend {
+    /// `Pending` for pairs with no recorded vote.
+    fn get_fpic_status(
+        &self,
+        proposal_id: &str,
+        community: &CommunityId,
+    ) -> Result<FpicStatus, String> {
+        let state = self.state.lock().map_err(|e| e.to_string())?;
+        Ok(state
+            .index
+            .get(&(proposal_id.to_string(), community.clone()))
+            .cloned()
+            .unwrap_or(FpicStatus::Pending))
+    }
+
+    /// Appends and syncs one line, updates the index, then publishes the
+    /// change. The lock is held throughout so events follow file order.
+    fn record_fpic_result(&self, result: CommunityVoteResult) -> Result<(), String> {
+        let mut state = self.state.lock().map_err(|e| e.to_string())?;
+        self.append(&mut state, &result)?;
+        let event = GovernanceEvent {
+            proposal_id: result.proposal_id.clone(),
+            community_id: result.community_id.clone(),
+            new_status: result.fpic_status.clone(),
+            at: SystemTime::now(),
+        };
+        state.index.insert(
+            (result.proposal_id, result.community_id),
+            result.fpic_status,
+        );
+        self.events.publish(event);
+        Ok(())
+    }
+
+    fn get_proposal_state(&self, proposal_id: &str) -> Result<ProposalState, String> {
+        let mut state = self.state.lock().map_err(|e| e.to_string())?;
+        state
+            .proposal_mut(proposal_id)
+            .map(|(_, s)| *s)
+            .ok_or_else(|| format!("unknown proposal {proposal_id:?}"))
+    }
+
+    fn advance_proposal(
+        &self,
+        proposal_id: &str,
+        transition: ProposalTransition,
+    ) -> Result<ProposalState, String> {
+        let mut state = self.state.lock().map_err(|e| e.to_string())?;
+        let (_, current) = state
+            .proposal_mut(proposal_id)
+            .ok_or_else(|| format!("unknown proposal {proposal_id:?}"))?;
+        let next = current
+            .after(transition)
+            .ok_or_else(|| format!("cannot apply {transition:?} to a {current:?} proposal"))?;
+        let line = json!({ "proposal_state": { "proposal_id": proposal_id, "state": next } });
+        self.append(&mut state, &line)?;
+        if let Some((_, current)) = state.proposal_mut(proposal_id) {
+            *current = next;
+        }
+        Ok(next)
+    }
+
+    /// Registered proposals naming `corridor` exactly, in registration order.
+    fn list_proposals_for_corridor(
+        &self,
+        corridor: &str,
+    ) -> Result<Vec<GovernanceProposal>, String> {
+        let state = self.state.lock().map_err(|e| e.to_string())?;
+        Ok(state
+            .proposals
+            .iter()
+            .filter(|(p, _)| p.affected_corridors.iter().any(|c| c == corridor))
+            .map(|(p, _)| p.clone())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    fn scratch_file(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "morphix-governance-{name}-{}.jsonl",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn vote(proposal: &str, community: &str, status: FpicStatus) -> CommunityVoteResult {
+        CommunityVoteResult {
+            proposal_id: proposal.into(),
+            community_id: CommunityId(community.into()),
+            fpic_status: status,
+            signatures: Vec::new(),
+        }
+    }
+
+    fn proposal(id: &str, corridors: &[&str]) -> GovernanceProposal {
+        GovernanceProposal {
+            id: id.into(),
+            title: format!("Proposal {id}"),
+            description: String::new(),
+            affected_corridors: corridors.iter().map(|c| c.to_string()).collect(),
+            created_at: UNIX_EPOCH,
+            policy_diff: None,
+        }
+    }
+
+    fn ids(proposals: Vec<GovernanceProposal>) -> Vec<String> {
+        proposals.into_iter().map(|p| p.id).collect()
+    }
+
+    fn status(backend: &JsonFileGovernanceBackend, proposal: &str, community: &str) -> FpicStatus {
+        backend
+            .get_fpic_status(proposal, &CommunityId(community.into()))
+            .unwrap()
+    }
+
+    #[test]
+    fn statuses_survive_reopen() {
+        let path = scratch_file("reopen");
+        let granted_at = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        {
+            let backend = JsonFileGovernanceBackend::open(&path).unwrap();
+            backend
+                .record_fpic_result(vote("p-1", "water-shed", FpicStatus::Pending))
+                .unwrap();
+            backend
+                .record_fpic_result(vote(
+                    "p-1",
+                    "water-shed",
+                    FpicStatus::Granted {
+                        timestamp: granted_at,
+                        signed_by: vec!["did:example:delegate".into()],
+                        valid_until: None,
+                    },
+                ))
+                .unwrap();
+            backend
+                .record_fpic_result(vote(
+                    "p-1",
+                    "south-phoenix-air",
+                    FpicStatus::Withheld {
+                        timestamp: granted_at,
+                        reason: "heat season".into(),
+                    },
+                ))
+                .unwrap();
+            backend
+                .record_fpic_result(vote("p-2", "water-shed", FpicStatus::Pending))
+                .unwrap();
+        }
+
+        let reopened = JsonFileGovernanceBackend::open(&path).unwrap();
+        match status(&reopened, "p-1", "water-shed") {
+            FpicStatus::Granted {
+                timestamp,
+                signed_by,
+                ..
+            } => {
+                assert_eq!(timestamp, granted_at);
+                assert_eq!(signed_by, ["did:example:delegate"]);
+            }
+            other => panic!("expected Granted, got {other:?}"),
+        }
+        assert!(matches!(
+            status(&reopened, "p-1", "south-phoenix-air"),
+            FpicStatus::Withheld { reason, .. } if reason == "heat season"
+        ));
+        assert!(matches!(
+            status(&reopened, "p-2", "water-shed"),
+            FpicStatus::Pending
+        ));
+        assert!(matches!(
+            status(&reopened, "p-3", "water-shed"),
+            FpicStatus::Pending
+        ));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn concurrent_records_stay_line_delimited() {
+        let path = scratch_file("concurrent");
+        let backend = Arc::new(JsonFileGovernanceBackend::open(&path).unwrap());
+        let threads: Vec<_> = (0..8)
+            .map(|t| {
+                let backend = Arc::clone(&backend);
+                std::thread::spawn(move || {
+                    for i in 0..25 {
+                        let reason = format!("thread {t} vote {i}");
+                        backend
+                            .record_fpic_result(vote(
+                                &format!("p-{i}"),
+                                &format!("community-{t}"),
+                                FpicStatus::Withheld {
+                                    timestamp: UNIX_EPOCH,
+                                    reason,
+                                },
+                            ))
+                            .unwrap();
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(text.lines().count(), 200);
+        let reopened = JsonFileGovernanceBackend::open(&path).unwrap();
+        assert!(matches!(
+            status(&reopened, "p-24", "community-7"),
+            FpicStatus::Withheld { reason, .. } if reason == "thread 7 vote 24"
+        ));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn torn_final_line_is_truncated_on_open() {
+        let path = scratch_file("torn");
+        {
+            let backend = JsonFileGovernanceBackend::open(&path).unwrap();
+            backend
+                .record_fpic_result(vote("p-1", "water-shed", FpicStatus::Pending))
+                .unwrap();
+        }
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(br#"{"proposal_id":"p-1","commu"#).unwrap();
+        drop(file);
+
+        let backend = JsonFileGovernanceBackend::open(&path).unwrap();
+        backend
+            .record_fpic_result(vote(
+                "p-2",
+                "water-shed",
+                FpicStatus::Withheld {
+                    timestamp: UNIX_EPOCH,
+                    reason: "no quorum".into(),
+                },
+            ))
+            .unwrap();
+        drop(backend);
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(text.lines().count(), 2);
+        let reopened = JsonFileGovernanceBackend::open(&path).unwrap();
+        assert!(matches!(
+            status(&reopened, "p-1", "water-shed"),
+            FpicStatus::Pending
+        ));
+        assert!(matches!(
+            status(&reopened, "p-2", "water-shed"),
+            FpicStatus::Withheld { reason, .. } if reason == "no quorum"
+        ));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn proposals_are_listed_by_exact_corridor() {
+        let path = scratch_file("corridors");
+        {
+            let backend = JsonFileGovernanceBackend::open(&path).unwrap();
+            backend
+                .record_proposal(proposal("p-1", &["phoenix-canal", "south-phoenix-air"]))
+                .unwrap();
+            backend
+                .record_proposal(proposal("p-2", &["phoenix-canal"]))
+                .unwrap();
+            backend
+                .record_proposal(proposal("p-3", &["phoenix-canal-east"]))
+                .unwrap();
+            backend
+                .record_fpic_result(vote("p-1", "water-shed", FpicStatus::Pending))
+                .unwrap();
+            assert!(backend.record_proposal(proposal("p-2", &[])).is_err());
+        }
+
+        let reopened = JsonFileGovernanceBackend::open(&path).unwrap();
+        let listed = |corridor| ids(reopened.list_proposals_for_corridor(corridor).unwrap());
+        assert_eq!(listed("phoenix-canal"), ["p-1", "p-2"]);
+        assert_eq!(listed("south-phoenix-air"), ["p-1"]);
+        assert_eq!(listed("phoenix-canal-east"), ["p-3"]);
+        assert!(listed("phoenix").is_empty());
+        assert!(matches!(
+            status(&reopened, "p-1", "water-shed"),
+            FpicStatus::Pending
+        ));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn proposal_states_survive_reopen() {
+        let path = scratch_file("states");
+        {
+            let backend = JsonFileGovernanceBackend::open(&path).unwrap();
+            backend
+                .record_proposal(proposal("p-1", &["phoenix-canal"]))
+                .unwrap();
+            backend
+                .advance_proposal("p-1", ProposalTransition::OpenForFpic)
+                .unwrap();
+            assert!(backend
+                .advance_proposal("p-1", ProposalTransition::OpenForFpic)
+                .is_err());
+            assert!(backend
+                .advance_proposal("p-9", ProposalTransition::Withdraw)
+                .is_err());
+        }
+
+        let reopened = JsonFileGovernanceBackend::open(&path).unwrap();
+        assert_eq!(
+            reopened.get_proposal_state("p-1"),
+            Ok(ProposalState::OpenForFpic)
+        );
+        assert_eq!(
+            reopened.advance_proposal("p-1", ProposalTransition::Withdraw),
+            Ok(ProposalState::Withdrawn)
+        );
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn proposal_policy_diff_survives_reopen() {
+        use governance_sim::SncPolicySnapshot;
+
+        let path = scratch_file("policy-diff");
+        let current = SncPolicySnapshot {
+            min_knowledge_factor_open: 0.75,
+            chat_issuance_slope: 1.0,
+            eco_weight: 0.3,
+        };
+        let proposed = SncPolicySnapshot {
+            eco_weight: 0.4,
+            ..current.clone()
+        };
+        let diff = current.diff(&proposed);
+        {
+            let backend = JsonFileGovernanceBackend::open(&path).unwrap();
+            backend
+                .record_proposal(GovernanceProposal {
+                    policy_diff: Some(diff.clone()),
+                    ..proposal("p-1", &["phoenix-canal"])
+                })
+                .unwrap();
+        }
+        // A proposal recorded before diffs existed.
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| {
+                use std::io::Write;
+                writeln!(
+                    file,
+                    r#"{{"proposal":{{"id":"p-0","title":"","description":"","affected_corridors":["phoenix-canal"],"created_at":{{"secs_since_epoch":0,"nanos_since_epoch":0}}}}}}"#
+                )
+            })
+            .unwrap();
+
+        let reopened = JsonFileGovernanceBackend::open(&path).unwrap();
+        let proposals = reopened
+            .list_proposals_for_corridor("phoenix-canal")
+            .unwrap();
+        let diffs: Vec<_> = proposals.iter().map(|p| p.policy_diff.clone()).collect();
+        assert_eq!(diffs, [Some(diff), None]);
+        assert_eq!(
+            proposals[0].policy_diff.as_ref().unwrap().summary(),
+            "change eco_weight from 0.3 to 0.4 (tightening)"
+        );
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn subscribers_see_later_votes_in_order() {
+        use tokio::sync::broadcast::error::TryRecvError;
+
+        let path = scratch_file("events");
+        let backend = JsonFileGovernanceBackend::open(&path).unwrap();
+        let mut early = backend.subscribe();
+        for proposal in ["p-1", "p-2", "p-3"] {
+            backend
+                .record_fpic_result(vote(proposal, "water-shed", FpicStatus::Pending))
+                .unwrap();
+        }
+        let mut late = backend.subscribe();
+        backend
+            .record_fpic_result(vote(
+                "p-1",
+                "water-shed",
+                FpicStatus::Withheld {
+                    timestamp: UNIX_EPOCH,
+                    reason: "heat season".into(),
+                },
+            ))
+            .unwrap();
+
+        let received: Vec<_> = std::iter::from_fn(|| early.try_recv().ok())
+            .map(|event| event.proposal_id)
+            .collect();
+        assert_eq!(received, ["p-1", "p-2", "p-3", "p-1"]);
+
+        // Late subscribers only see what was published after subscribing.
+        let event = late.try_recv().unwrap();
+        assert!(matches!(event.new_status, FpicStatus::Withheld { .. }));
+        assert_eq!(event.community_id, CommunityId("water-shed".into()));
+        assert!(matches!(late.try_recv(), Err(TryRecvError::Empty)));
+
+        // Replaying the log on open publishes nothing.
+        let reopened = JsonFileGovernanceBackend::open(&path).unwrap();
+        assert!(matches!(
+            reopened.subscribe().try_recv(),
+            Err(TryRecvError::Empty)
+        ));
+        let _ = std::fs::remove_file(&path);
+    }
+}
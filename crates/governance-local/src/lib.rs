@@ -1,16 +1,56 @@
-use std::time::SystemTime;
+use std::collections::HashSet;
+use std::fmt;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
-/// OCAP / CARE aligned community identifier.
-#[derive(Clone, Debug)]
-pub struct CommunityId(pub String);
+use core_contract::health::{ComponentHealth, HealthCheckable, HealthStatus};
+use governance_sim::PolicyDiff;
+
+pub use core_contract::care::CommunityId;
+
+mod lifecycle;
+pub use lifecycle::{
+    LifecycleError, ProposalLifecycle, ProposalOutcome, ProposalState, ProposalTransition,
+};
+
+mod token_sync;
+pub use token_sync::sync_token_from_governance;
+
+#[cfg(feature = "signatures")]
+mod signatures;
+#[cfg(feature = "ed25519")]
+pub use signatures::Ed25519Verifier;
+#[cfg(feature = "signatures")]
+pub use signatures::{
+    payload_hash, verify_vote_signatures, vote_signing_payload, DelegateFailure,
+    SignatureCheckedBackend, SignatureError, SignatureFailure, SignatureVerifier,
+};
+
+#[cfg(feature = "events")]
+mod events;
+#[cfg(feature = "events")]
+pub use events::{GovernanceEvent, GovernanceEventStream, DEFAULT_EVENT_CAPACITY};
+
+#[cfg(feature = "test-util")]
+mod mock;
+#[cfg(feature = "test-util")]
+pub use mock::{MockCall, MockGovernanceBackend};
+
+#[cfg(feature = "json-file")]
+mod json_file;
+#[cfg(feature = "json-file")]
+pub use json_file::JsonFileGovernanceBackend;
 
 /// FPIC status for a given proposal and community.[web:145][web:144]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FpicStatus {
     Pending,
     Granted {
         timestamp: SystemTime,
         signed_by: Vec<String>, // community delegates, DID strings
+        /// The grant stops counting at this instant; `None` never expires.
+        #[cfg_attr(feature = "serde", serde(default))]
+        valid_until: Option<SystemTime>,
     },
     Withheld {
         timestamp: SystemTime,
@@ -18,8 +58,96 @@ pub enum FpicStatus {
     },
 }
 
+impl FpicStatus {
+    /// `valid_until` of a grant that has expired at `now`.
+    pub fn expired_at(&self, now: SystemTime) -> Option<SystemTime> {
+        match self {
+            Self::Granted {
+                valid_until: Some(until),
+                ..
+            } if now >= *until => Some(*until),
+            _ => None,
+        }
+    }
+}
+
+/// `YYYY-MM-DD` for the UTC day containing `at`, for consent messages.
+pub fn utc_date(at: SystemTime) -> String {
+    let days = at
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() / 86_400)
+        .unwrap_or(0) as i64;
+    // Civil-from-days (Howard Hinnant), valid for the proleptic Gregorian calendar.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// How many recognized delegates must sign a `Granted` status for a
+/// community's consent to count.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QuorumPolicy {
+    pub required: usize,
+    /// Delegate DIDs whose signatures count; empty means any delegate counts.
+    pub recognized_delegates: Vec<String>,
+}
+
+/// Why a status does not meet its community's `QuorumPolicy`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum QuorumError {
+    /// Only `Granted` statuses can meet a quorum.
+    NotGranted,
+    /// `signed` distinct recognized delegates signed; `required` are needed.
+    BelowQuorum { signed: usize, required: usize },
+}
+
+impl fmt::Display for QuorumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotGranted => f.write_str("FPIC is not granted"),
+            Self::BelowQuorum { signed, required } => write!(
+                f,
+                "{signed} of {required} required recognized delegates signed"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for QuorumError {}
+
+/// Check that `status` is `Granted` and signed by at least
+/// `policy.required` distinct recognized delegates. A DID listed twice in
+/// `signed_by` counts once.
+pub fn verify_quorum(status: &FpicStatus, policy: &QuorumPolicy) -> Result<(), QuorumError> {
+    let FpicStatus::Granted { signed_by, .. } = status else {
+        return Err(QuorumError::NotGranted);
+    };
+    let signed = signed_by
+        .iter()
+        .filter(|did| {
+            policy.recognized_delegates.is_empty() || policy.recognized_delegates.contains(did)
+        })
+        .collect::<HashSet<_>>()
+        .len();
+    if signed < policy.required {
+        return Err(QuorumError::BelowQuorum {
+            signed,
+            required: policy.required,
+        });
+    }
+    Ok(())
+}
+
 /// A governance proposal affecting SNC/CHAT rules or deployments.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GovernanceProposal {
     pub id: String,
     pub title: String,
@@ -27,14 +155,32 @@ pub struct GovernanceProposal {
     /// Corridors, territories, or data scopes impacted.
     pub affected_corridors: Vec<String>,
     pub created_at: SystemTime,
+    /// The SNC rule change put to FPIC, so the record shows exactly what
+    /// communities approved.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub policy_diff: Option<PolicyDiff>,
+}
+
+/// A delegate's signature over a vote's signing payload.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DelegateSignature {
+    pub did: String,
+    pub signature: Vec<u8>,
+    /// Hex SHA-256 of the payload the delegate signed.
+    pub payload_hash: String,
 }
 
 /// Result of a community vote, suitable for recording on a permissioned ledger.[web:145][web:143]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommunityVoteResult {
     pub proposal_id: String,
     pub community_id: CommunityId,
     pub fpic_status: FpicStatus,
+    /// Delegate signatures; not part of the signed payload.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub signatures: Vec<DelegateSignature>,
 }
 
 /// Minimal trait an FPIC / IDS layer must implement.
@@ -52,4 +198,211 @@ pub trait CommunityGovernanceBackend {
         &self,
         result: CommunityVoteResult,
     ) -> Result<(), String>;
+
+    /// Whether the community granted consent for the proposal and that
+    /// grant has since expired, so it must be asked again.
+    fn needs_reconsent(
+        &self,
+        proposal_id: &str,
+        community: &CommunityId,
+        now: SystemTime,
+    ) -> Result<bool, String> {
+        Ok(self
+            .get_fpic_status(proposal_id, community)?
+            .expired_at(now)
+            .is_some())
+    }
+
+    /// Current lifecycle state of a proposal. Backends that do not track
+    /// lifecycles return `Err`.
+    fn get_proposal_state(&self, proposal_id: &str) -> Result<ProposalState, String> {
+        Err(format!(
+            "backend does not track the lifecycle of proposal {proposal_id:?}"
+        ))
+    }
+
+    /// Apply `transition` to a proposal and return its new state. Backends
+    /// that do not track lifecycles return `Err`.
+    fn advance_proposal(
+        &self,
+        proposal_id: &str,
+        _transition: ProposalTransition,
+    ) -> Result<ProposalState, String> {
+        Err(format!(
+            "backend does not track the lifecycle of proposal {proposal_id:?}"
+        ))
+    }
+
+    /// Proposals whose `affected_corridors` include `corridor`. Backends
+    /// that do not store proposals return `Err`.
+    fn list_proposals_for_corridor(
+        &self,
+        corridor: &str,
+    ) -> Result<Vec<GovernanceProposal>, String> {
+        Err(format!(
+            "backend does not store proposals; cannot list those for corridor {corridor:?}"
+        ))
+    }
+}
+
+/// Proposal id used by `GovernanceProbe`; backends should answer it with
+/// `Pending` (or any status) without side effects.
+pub const HEALTH_PROBE_PROPOSAL_ID: &str = "__morphix_healthz__";
+
+/// Round-trip readiness probe for a governance backend.
+pub struct GovernanceProbe<'a, B: CommunityGovernanceBackend> {
+    pub backend: &'a B,
+    pub community: CommunityId,
+    /// Round trips slower than this report Degraded.
+    pub slow_after: Duration,
+}
+
+impl<B: CommunityGovernanceBackend> HealthCheckable for GovernanceProbe<'_, B> {
+    fn health(&self) -> ComponentHealth {
+        let component = format!("governance:{}", self.community.0);
+        let started = Instant::now();
+        let result = self.backend.get_fpic_status(HEALTH_PROBE_PROPOSAL_ID, &self.community);
+        let elapsed = started.elapsed();
+
+        match result {
+            Err(e) => ComponentHealth::new(component, HealthStatus::Unhealthy, format!("round trip failed: {e}")),
+            Ok(_) if elapsed > self.slow_after => ComponentHealth::new(
+                component,
+                HealthStatus::Degraded,
+                format!("slow round trip: {} ms", elapsed.as_millis()),
+            ),
+            Ok(_) => ComponentHealth::new(
+                component,
+                HealthStatus::Healthy,
+                format!("round trip in {} ms", elapsed.as_millis()),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct ScriptedBackend(Result<(), String>, Duration);
+
+    impl CommunityGovernanceBackend for ScriptedBackend {
+        fn get_fpic_status(&self, _: &str, _: &CommunityId) -> Result<FpicStatus, String> {
+            std::thread::sleep(self.1);
+            self.0.clone().map(|_| FpicStatus::Pending)
+        }
+
+        fn record_fpic_result(&self, _: CommunityVoteResult) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    fn probe(backend: &ScriptedBackend, slow_after: Duration) -> HealthStatus {
+        GovernanceProbe {
+            backend,
+            community: CommunityId("frontline-south-phoenix-air".into()),
+            slow_after,
+        }
+        .health()
+        .status
+    }
+
+    fn granted(signed_by: &[&str]) -> FpicStatus {
+        FpicStatus::Granted {
+            timestamp: SystemTime::UNIX_EPOCH,
+            signed_by: signed_by.iter().map(|did| did.to_string()).collect(),
+            valid_until: None,
+        }
+    }
+
+    struct FixedBackend(FpicStatus);
+
+    impl CommunityGovernanceBackend for FixedBackend {
+        fn get_fpic_status(&self, _: &str, _: &CommunityId) -> Result<FpicStatus, String> {
+            Ok(self.0.clone())
+        }
+
+        fn record_fpic_result(&self, _: CommunityVoteResult) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn lifecycle_methods_default_to_unsupported() {
+        let backend = FixedBackend(FpicStatus::Pending);
+        assert!(backend.get_proposal_state("p-1").is_err());
+        assert!(backend
+            .advance_proposal("p-1", ProposalTransition::OpenForFpic)
+            .is_err());
+    }
+
+    #[test]
+    fn grant_expires_at_valid_until() {
+        let until = UNIX_EPOCH + Duration::from_secs(1_767_225_600);
+        let backend = FixedBackend(FpicStatus::Granted {
+            timestamp: UNIX_EPOCH,
+            signed_by: vec![],
+            valid_until: Some(until),
+        });
+        let community = CommunityId("indigenous-phoenix-water-shed".into());
+        let needs = |now| backend.needs_reconsent("p-1", &community, now).unwrap();
+
+        assert!(!needs(until - Duration::from_secs(1)));
+        assert!(needs(until));
+        assert_eq!(backend.0.expired_at(until), Some(until));
+        assert_eq!(utc_date(until), "2026-01-01");
+
+        for status in [granted(&[]), FpicStatus::Pending] {
+            let open_ended = FixedBackend(status);
+            assert!(!open_ended.needs_reconsent("p-1", &community, until).unwrap());
+        }
+    }
+
+    fn council() -> QuorumPolicy {
+        QuorumPolicy {
+            required: 2,
+            recognized_delegates: vec!["did:a".into(), "did:b".into(), "did:c".into()],
+        }
+    }
+
+    #[test]
+    fn quorum_is_met_exactly_at_the_threshold() {
+        assert_eq!(verify_quorum(&granted(&["did:a", "did:c"]), &council()), Ok(()));
+        assert_eq!(
+            verify_quorum(&granted(&["did:a", "did:x"]), &council()),
+            Err(QuorumError::BelowQuorum { signed: 1, required: 2 })
+        );
+    }
+
+    #[test]
+    fn duplicate_signatures_count_once() {
+        assert_eq!(
+            verify_quorum(&granted(&["did:a", "did:a"]), &council()),
+            Err(QuorumError::BelowQuorum { signed: 1, required: 2 })
+        );
+    }
+
+    #[test]
+    fn empty_recognized_list_accepts_any_delegate() {
+        let open = QuorumPolicy { required: 2, recognized_delegates: vec![] };
+        assert_eq!(verify_quorum(&granted(&["did:x", "did:y"]), &open), Ok(()));
+        assert_eq!(
+            verify_quorum(&granted(&["did:x"]), &open),
+            Err(QuorumError::BelowQuorum { signed: 1, required: 2 })
+        );
+        assert_eq!(verify_quorum(&FpicStatus::Pending, &open), Err(QuorumError::NotGranted));
+    }
+
+    #[test]
+    fn governance_probe_paths() {
+        let minute = Duration::from_secs(60);
+        let ok = ScriptedBackend(Ok(()), Duration::ZERO);
+        assert_eq!(probe(&ok, minute), HealthStatus::Healthy);
+
+        let slow = ScriptedBackend(Ok(()), Duration::from_millis(5));
+        assert_eq!(probe(&slow, Duration::from_millis(1)), HealthStatus::Degraded);
+
+        let down = ScriptedBackend(Err("connection refused".into()), Duration::ZERO);
+        assert_eq!(probe(&down, minute), HealthStatus::Unhealthy);
+    }
 }
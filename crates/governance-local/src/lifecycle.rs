@@ -0,0 +1,232 @@
+use std::fmt;
+
+use crate::{CommunityVoteResult, GovernanceProposal};
+
+/// How a closed proposal was decided.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProposalOutcome {
+    Enacted,
+    Rejected,
+}
+
+/// Where a proposal is in its lifecycle. `Closed` and `Withdrawn` are final.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProposalState {
+    Draft,
+    OpenForFpic,
+    Closed { outcome: ProposalOutcome },
+    Withdrawn,
+}
+
+/// A requested move between `ProposalState`s.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProposalTransition {
+    /// Draft -> OpenForFpic.
+    OpenForFpic,
+    /// OpenForFpic -> Closed.
+    Close(ProposalOutcome),
+    /// Draft or OpenForFpic -> Withdrawn.
+    Withdraw,
+}
+
+impl ProposalState {
+    /// The state `transition` leads to, or `None` if it is not allowed
+    /// from here.
+    pub fn after(self, transition: ProposalTransition) -> Option<ProposalState> {
+        match (self, transition) {
+            (Self::Draft, ProposalTransition::OpenForFpic) => Some(Self::OpenForFpic),
+            (Self::OpenForFpic, ProposalTransition::Close(outcome)) => {
+                Some(Self::Closed { outcome })
+            }
+            (Self::Draft | Self::OpenForFpic, ProposalTransition::Withdraw) => {
+                Some(Self::Withdrawn)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Why a lifecycle operation was refused.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LifecycleError {
+    IllegalTransition {
+        from: ProposalState,
+        transition: ProposalTransition,
+    },
+    /// FPIC results are only accepted while the proposal is `OpenForFpic`.
+    NotOpenForFpic { state: ProposalState },
+    /// The vote names a different proposal.
+    ProposalMismatch { expected: String, found: String },
+}
+
+impl fmt::Display for LifecycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IllegalTransition { from, transition } => {
+                write!(f, "cannot apply {transition:?} to a {from:?} proposal")
+            }
+            Self::NotOpenForFpic { state } => {
+                write!(f, "cannot record FPIC results against a {state:?} proposal")
+            }
+            Self::ProposalMismatch { expected, found } => {
+                write!(f, "vote is for proposal {found:?}, not {expected:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LifecycleError {}
+
+/// A proposal together with its current state.
+#[derive(Clone, Debug)]
+pub struct ProposalLifecycle {
+    proposal: GovernanceProposal,
+    state: ProposalState,
+}
+
+impl ProposalLifecycle {
+    /// Start `proposal` as a `Draft`.
+    pub fn new(proposal: GovernanceProposal) -> Self {
+        Self {
+            proposal,
+            state: ProposalState::Draft,
+        }
+    }
+
+    pub fn proposal(&self) -> &GovernanceProposal {
+        &self.proposal
+    }
+
+    pub fn state(&self) -> ProposalState {
+        self.state
+    }
+
+    /// Move to the state `transition` leads to, leaving the state unchanged
+    /// if the move is illegal.
+    pub fn advance(
+        &mut self,
+        transition: ProposalTransition,
+    ) -> Result<ProposalState, LifecycleError> {
+        let next = self
+            .state
+            .after(transition)
+            .ok_or(LifecycleError::IllegalTransition {
+                from: self.state,
+                transition,
+            })?;
+        self.state = next;
+        Ok(next)
+    }
+
+    pub fn open_for_fpic(&mut self) -> Result<ProposalState, LifecycleError> {
+        self.advance(ProposalTransition::OpenForFpic)
+    }
+
+    pub fn close(&mut self, outcome: ProposalOutcome) -> Result<ProposalState, LifecycleError> {
+        self.advance(ProposalTransition::Close(outcome))
+    }
+
+    pub fn withdraw(&mut self) -> Result<ProposalState, LifecycleError> {
+        self.advance(ProposalTransition::Withdraw)
+    }
+
+    /// Check that `vote` may be recorded: it must name this proposal, and
+    /// the proposal must be `OpenForFpic`.
+    pub fn admit_vote(&self, vote: &CommunityVoteResult) -> Result<(), LifecycleError> {
+        if vote.proposal_id != self.proposal.id {
+            return Err(LifecycleError::ProposalMismatch {
+                expected: self.proposal.id.clone(),
+                found: vote.proposal_id.clone(),
+            });
+        }
+        if self.state != ProposalState::OpenForFpic {
+            return Err(LifecycleError::NotOpenForFpic { state: self.state });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CommunityId, FpicStatus};
+    use std::time::SystemTime;
+
+    fn lifecycle() -> ProposalLifecycle {
+        ProposalLifecycle::new(GovernanceProposal {
+            id: "snc-policy-2026-02".into(),
+            title: "Fairness upgrade".into(),
+            description: String::new(),
+            affected_corridors: vec!["phoenix-canal".into()],
+            created_at: SystemTime::UNIX_EPOCH,
+            policy_diff: None,
+        })
+    }
+
+    fn vote() -> CommunityVoteResult {
+        CommunityVoteResult {
+            proposal_id: "snc-policy-2026-02".into(),
+            community_id: CommunityId("indigenous-phoenix-water-shed".into()),
+            fpic_status: FpicStatus::Pending,
+            signatures: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn draft_opens_takes_votes_and_closes() {
+        let mut proposal = lifecycle();
+        assert_eq!(proposal.state(), ProposalState::Draft);
+        assert_eq!(proposal.open_for_fpic(), Ok(ProposalState::OpenForFpic));
+        assert_eq!(proposal.admit_vote(&vote()), Ok(()));
+
+        let enacted = ProposalState::Closed {
+            outcome: ProposalOutcome::Enacted,
+        };
+        assert_eq!(proposal.close(ProposalOutcome::Enacted), Ok(enacted));
+        assert_eq!(
+            proposal.admit_vote(&vote()),
+            Err(LifecycleError::NotOpenForFpic { state: enacted })
+        );
+        assert_eq!(
+            proposal.open_for_fpic(),
+            Err(LifecycleError::IllegalTransition {
+                from: enacted,
+                transition: ProposalTransition::OpenForFpic,
+            })
+        );
+    }
+
+    #[test]
+    fn votes_against_draft_or_withdrawn_proposals_are_refused() {
+        let mut proposal = lifecycle();
+        assert_eq!(
+            proposal.admit_vote(&vote()),
+            Err(LifecycleError::NotOpenForFpic {
+                state: ProposalState::Draft
+            })
+        );
+
+        proposal.open_for_fpic().unwrap();
+        proposal.withdraw().unwrap();
+        assert_eq!(
+            proposal.admit_vote(&vote()),
+            Err(LifecycleError::NotOpenForFpic {
+                state: ProposalState::Withdrawn
+            })
+        );
+    }
+
+    #[test]
+    fn drafts_cannot_close_and_state_is_kept_on_refusal() {
+        let mut proposal = lifecycle();
+        let err = proposal.close(ProposalOutcome::Rejected).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "cannot apply Close(Rejected) to a Draft proposal"
+        );
+        assert_eq!(proposal.state(), ProposalState::Draft);
+    }
+}
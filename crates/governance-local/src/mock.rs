@@ -0,0 +1,323 @@
+//! Scriptable `CommunityGovernanceBackend` for tests.
+//!
+//! Each (proposal, community) pair answers from its own script of
+//! responses; the last response repeats once the script runs out, and
+//! unscripted pairs answer the default status (`Pending` unless set).
+//! Every call is recorded, and every status change, whether recorded
+//! through the trait or injected with `set_status`, is published on the
+//! mock's `GovernanceEventStream`.
+//!
+//! The orchestration governance tests drive `validate_policy_change` and
+//! friends with it:
+//!
+//! ```
+//! use std::time::SystemTime;
+//! use governance_local::{
+//!     CommunityGovernanceBackend, CommunityId, FpicStatus, MockCall, MockGovernanceBackend,
+//! };
+//!
+//! let water = CommunityId("water-shed".into());
+//! let granted = FpicStatus::Granted {
+//!     timestamp: SystemTime::UNIX_EPOCH,
+//!     signed_by: vec!["did:example:elder".into()],
+//!     valid_until: None,
+//! };
+//! let governance = MockGovernanceBackend::new()
+//!     .with_statuses("p-1", water.clone(), [FpicStatus::Pending, granted])
+//!     .with_error("p-2", water.clone(), "ledger node offline");
+//!
+//! // The first validation sees Pending, every later one sees Granted.
+//! assert_eq!(governance.get_fpic_status("p-1", &water), Ok(FpicStatus::Pending));
+//! assert!(matches!(
+//!     governance.get_fpic_status("p-1", &water),
+//!     Ok(FpicStatus::Granted { .. })
+//! ));
+//! assert!(matches!(
+//!     governance.get_fpic_status("p-1", &water),
+//!     Ok(FpicStatus::Granted { .. })
+//! ));
+//! assert_eq!(
+//!     governance.get_fpic_status("p-2", &water),
+//!     Err("ledger node offline".to_string())
+//! );
+//! assert_eq!(governance.calls().len(), 4);
+//! assert_eq!(
+//!     governance.calls()[3],
+//!     MockCall::GetFpicStatus {
+//!         proposal_id: "p-2".into(),
+//!         community: water,
+//!     }
+//! );
+//! ```
+//!
+//! Watcher code subscribes before changing a status:
+//!
+//! ```
+//! use std::time::SystemTime;
+//! use governance_local::{CommunityId, FpicStatus, MockGovernanceBackend};
+//!
+//! let governance = MockGovernanceBackend::new();
+//! let mut events = governance.subscribe();
+//! governance.set_status(
+//!     "p-1",
+//!     CommunityId("water-shed".into()),
+//!     FpicStatus::Withheld {
+//!         timestamp: SystemTime::UNIX_EPOCH,
+//!         reason: "consent withdrawn".into(),
+//!     },
+//! );
+//! let event = events.try_recv().unwrap();
+//! assert!(matches!(event.new_status, FpicStatus::Withheld { .. }));
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use crate::{
+    CommunityGovernanceBackend, CommunityId, CommunityVoteResult, FpicStatus, GovernanceEvent,
+    GovernanceEventStream, GovernanceProposal, ProposalState, ProposalTransition,
+};
+
+/// One call received by a `MockGovernanceBackend`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MockCall {
+    GetFpicStatus {
+        proposal_id: String,
+        community: CommunityId,
+    },
+    RecordFpicResult(CommunityVoteResult),
+    GetProposalState(String),
+    AdvanceProposal {
+        proposal_id: String,
+        transition: ProposalTransition,
+    },
+    ListProposalsForCorridor(String),
+}
+
+type Response = Result<FpicStatus, String>;
+
+struct MockState {
+    scripts: HashMap<(String, CommunityId), VecDeque<Response>>,
+    default_status: FpicStatus,
+    record_errors: VecDeque<String>,
+    proposals: Vec<(GovernanceProposal, ProposalState)>,
+    calls: Vec<MockCall>,
+}
+
+pub struct MockGovernanceBackend {
+    state: Mutex<MockState>,
+    events: GovernanceEventStream,
+}
+
+impl Default for MockGovernanceBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockGovernanceBackend {
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(MockState {
+                scripts: HashMap::new(),
+                default_status: FpicStatus::Pending,
+                record_errors: VecDeque::new(),
+                proposals: Vec::new(),
+                calls: Vec::new(),
+            }),
+            events: GovernanceEventStream::default(),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn state_mut(&mut self) -> &mut MockState {
+        self.state.get_mut().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn script(
+        mut self,
+        proposal_id: &str,
+        community: CommunityId,
+        responses: impl IntoIterator<Item = Response>,
+    ) -> Self {
+        self.state_mut()
+            .scripts
+            .entry((proposal_id.to_string(), community))
+            .or_default()
+            .extend(responses);
+        self
+    }
+
+    /// Status for pairs without a script.
+    pub fn with_default_status(mut self, status: FpicStatus) -> Self {
+        self.state_mut().default_status = status;
+        self
+    }
+
+    /// Append `statuses` to the pair's script, answered one per call.
+    pub fn with_statuses(
+        self,
+        proposal_id: &str,
+        community: CommunityId,
+        statuses: impl IntoIterator<Item = FpicStatus>,
+    ) -> Self {
+        self.script(proposal_id, community, statuses.into_iter().map(Ok))
+    }
+
+    /// Append an error to the pair's script.
+    pub fn with_error(
+        self,
+        proposal_id: &str,
+        community: CommunityId,
+        error: impl Into<String>,
+    ) -> Self {
+        self.script(proposal_id, community, [Err(error.into())])
+    }
+
+    /// Fail the next `record_fpic_result` call with `error`; queued
+    /// failures are used in order.
+    pub fn with_record_error(mut self, error: impl Into<String>) -> Self {
+        self.state_mut().record_errors.push_back(error.into());
+        self
+    }
+
+    /// Register `proposal` in `state` for the lifecycle and corridor calls.
+    pub fn with_proposal(mut self, proposal: GovernanceProposal, state: ProposalState) -> Self {
+        self.state_mut().proposals.push((proposal, state));
+        self
+    }
+
+    /// Replace the pair's script with `status`, as if the community decided
+    /// out of band, and publish the change.
+    pub fn set_status(&self, proposal_id: &str, community: CommunityId, status: FpicStatus) {
+        let mut state = self.lock();
+        self.apply(&mut state, proposal_id, community, status);
+    }
+
+    fn apply(
+        &self,
+        state: &mut MockState,
+        proposal_id: &str,
+        community: CommunityId,
+        status: FpicStatus,
+    ) {
+        state.scripts.insert(
+            (proposal_id.to_string(), community.clone()),
+            VecDeque::from([Ok(status.clone())]),
+        );
+        self.events.publish(GovernanceEvent {
+            proposal_id: proposal_id.to_string(),
+            community_id: community,
+            new_status: status,
+            at: SystemTime::now(),
+        });
+    }
+
+    /// Every call received so far, in order.
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.lock().calls.clone()
+    }
+
+    /// Status changes from now on; see `GovernanceEventStream`.
+    pub fn subscribe(&self) -> tokio::sync::broadcast::Receiver<GovernanceEvent> {
+        self.events.subscribe()
+    }
+
+    pub fn events(&self) -> &GovernanceEventStream {
+        &self.events
+    }
+}
+
+impl CommunityGovernanceBackend for MockGovernanceBackend {
+    fn get_fpic_status(
+        &self,
+        proposal_id: &str,
+        community: &CommunityId,
+    ) -> Result<FpicStatus, String> {
+        let mut state = self.lock();
+        state.calls.push(MockCall::GetFpicStatus {
+            proposal_id: proposal_id.to_string(),
+            community: community.clone(),
+        });
+        let key = (proposal_id.to_string(), community.clone());
+        match state.scripts.get_mut(&key) {
+            Some(script) if script.len() > 1 => {
+                script.pop_front().unwrap_or(Ok(FpicStatus::Pending))
+            }
+            Some(script) if !script.is_empty() => script[0].clone(),
+            _ => Ok(state.default_status.clone()),
+        }
+    }
+
+    /// Fails with the next queued record error, if any; otherwise the vote
+    /// becomes the pair's status and is published.
+    fn record_fpic_result(&self, result: CommunityVoteResult) -> Result<(), String> {
+        let mut state = self.lock();
+        state.calls.push(MockCall::RecordFpicResult(result.clone()));
+        if let Some(error) = state.record_errors.pop_front() {
+            return Err(error);
+        }
+        self.apply(
+            &mut state,
+            &result.proposal_id,
+            result.community_id,
+            result.fpic_status,
+        );
+        Ok(())
+    }
+
+    fn get_proposal_state(&self, proposal_id: &str) -> Result<ProposalState, String> {
+        let mut state = self.lock();
+        state
+            .calls
+            .push(MockCall::GetProposalState(proposal_id.to_string()));
+        state
+            .proposals
+            .iter()
+            .find(|(p, _)| p.id == proposal_id)
+            .map(|(_, s)| *s)
+            .ok_or_else(|| format!("unknown proposal {proposal_id:?}"))
+    }
+
+    fn advance_proposal(
+        &self,
+        proposal_id: &str,
+        transition: ProposalTransition,
+    ) -> Result<ProposalState, String> {
+        let mut state = self.lock();
+        state.calls.push(MockCall::AdvanceProposal {
+            proposal_id: proposal_id.to_string(),
+            transition,
+        });
+        let (_, current) = state
+            .proposals
+            .iter_mut()
+            .find(|(p, _)| p.id == proposal_id)
+            .ok_or_else(|| format!("unknown proposal {proposal_id:?}"))?;
+        let next = current
+            .after(transition)
+            .ok_or_else(|| format!("cannot apply {transition:?} to a {current:?} proposal"))?;
+        *current = next;
+        Ok(next)
+    }
+
+    fn list_proposals_for_corridor(
+        &self,
+        corridor: &str,
+    ) -> Result<Vec<GovernanceProposal>, String> {
+        let mut state = self.lock();
+        state
+            .calls
+            .push(MockCall::ListProposalsForCorridor(corridor.to_string()));
+        Ok(state
+            .proposals
+            .iter()
+            .filter(|(p, _)| p.affected_corridors.iter().any(|c| c == corridor))
+            .map(|(p, _)| p.clone())
+            .collect())
+    }
+}
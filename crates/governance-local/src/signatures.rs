@@ -0,0 +1,347 @@
+//! Delegate signatures over FPIC votes.
+//!
+//! A delegate signs the canonical JSON (`morphix_canonical`) of the vote
+//! with its `signatures` left out, and records the hex SHA-256 of those
+//! bytes as `payload_hash`. `SignatureCheckedBackend` refuses to record a
+//! vote unless every DID in a `Granted` status's `signed_by` has a valid
+//! signature and every attached signature verifies.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use sha2::{Digest, Sha256};
+
+use crate::{
+    CommunityGovernanceBackend, CommunityId, CommunityVoteResult, FpicStatus, GovernanceProposal,
+    ProposalState, ProposalTransition,
+};
+
+/// Why one delegate's signature was not accepted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignatureFailure {
+    /// Listed in `signed_by` without an attached signature.
+    Missing,
+    /// Signed a different payload than this vote.
+    PayloadHashMismatch,
+    /// The verifier has no key for the DID.
+    UnknownDelegate,
+    /// The signature does not verify against the DID's key.
+    Invalid,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DelegateFailure {
+    pub did: String,
+    pub failure: SignatureFailure,
+}
+
+/// Why a vote's signatures were rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignatureError {
+    /// The vote could not be canonicalized for signing.
+    Payload(String),
+    /// Delegates whose signatures failed, in `signed_by` then attachment order.
+    Delegates(Vec<DelegateFailure>),
+}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Payload(e) => write!(f, "vote payload: {e}"),
+            Self::Delegates(failures) => {
+                f.write_str("delegate signatures rejected:")?;
+                for (i, failure) in failures.iter().enumerate() {
+                    let sep = if i == 0 { " " } else { ", " };
+                    write!(f, "{sep}{} ({:?})", failure.did, failure.failure)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for SignatureError {}
+
+/// Checks one delegate's signature over raw payload bytes.
+pub trait SignatureVerifier: Send + Sync {
+    fn verify(&self, did: &str, payload: &[u8], signature: &[u8]) -> Result<(), SignatureFailure>;
+}
+
+/// Bytes a delegate signs for `vote`: its canonical JSON without
+/// `signatures`.
+pub fn vote_signing_payload(vote: &CommunityVoteResult) -> Result<Vec<u8>, SignatureError> {
+    let mut value =
+        serde_json::to_value(vote).map_err(|e| SignatureError::Payload(e.to_string()))?;
+    if let Some(object) = value.as_object_mut() {
+        object.remove("signatures");
+    }
+    morphix_canonical::canonical_json(&value).map_err(|e| SignatureError::Payload(e.to_string()))
+}
+
+/// Lowercase hex SHA-256 of `payload`, as stored in `payload_hash`.
+pub fn payload_hash(payload: &[u8]) -> String {
+    Sha256::digest(payload)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Check every attached signature, and that every `signed_by` DID of a
+/// `Granted` status has one.
+pub fn verify_vote_signatures(
+    vote: &CommunityVoteResult,
+    verifier: &dyn SignatureVerifier,
+) -> Result<(), SignatureError> {
+    let payload = vote_signing_payload(vote)?;
+    let hash = payload_hash(&payload);
+
+    let mut failures = Vec::new();
+    if let FpicStatus::Granted { signed_by, .. } = &vote.fpic_status {
+        let attached: HashSet<&str> = vote.signatures.iter().map(|s| s.did.as_str()).collect();
+        let mut seen = HashSet::new();
+        for did in signed_by {
+            if seen.insert(did.as_str()) && !attached.contains(did.as_str()) {
+                failures.push(DelegateFailure {
+                    did: did.clone(),
+                    failure: SignatureFailure::Missing,
+                });
+            }
+        }
+    }
+    for signature in &vote.signatures {
+        let checked = if signature.payload_hash != hash {
+            Err(SignatureFailure::PayloadHashMismatch)
+        } else {
+            verifier.verify(&signature.did, &payload, &signature.signature)
+        };
+        if let Err(failure) = checked {
+            failures.push(DelegateFailure {
+                did: signature.did.clone(),
+                failure,
+            });
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(SignatureError::Delegates(failures))
+    }
+}
+
+/// Wraps a backend so `record_fpic_result` only accepts votes whose
+/// delegate signatures verify.
+pub struct SignatureCheckedBackend<B, V> {
+    inner: B,
+    verifier: V,
+}
+
+impl<B, V> SignatureCheckedBackend<B, V> {
+    pub fn new(inner: B, verifier: V) -> Self {
+        Self { inner, verifier }
+    }
+
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+}
+
+impl<B, V> CommunityGovernanceBackend for SignatureCheckedBackend<B, V>
+where
+    B: CommunityGovernanceBackend,
+    V: SignatureVerifier,
+{
+    fn get_fpic_status(
+        &self,
+        proposal_id: &str,
+        community: &CommunityId,
+    ) -> Result<FpicStatus, String> {
+        self.inner.get_fpic_status(proposal_id, community)
+    }
+
+    fn record_fpic_result(&self, result: CommunityVoteResult) -> Result<(), String> {
+        verify_vote_signatures(&result, &self.verifier).map_err(|e| e.to_string())?;
+        self.inner.record_fpic_result(result)
+    }
+
+    fn get_proposal_state(&self, proposal_id: &str) -> Result<ProposalState, String> {
+        self.inner.get_proposal_state(proposal_id)
+    }
+
+    fn advance_proposal(
+        &self,
+        proposal_id: &str,
+        transition: ProposalTransition,
+    ) -> Result<ProposalState, String> {
+        self.inner.advance_proposal(proposal_id, transition)
+    }
+
+    fn list_proposals_for_corridor(
+        &self,
+        corridor: &str,
+    ) -> Result<Vec<GovernanceProposal>, String> {
+        self.inner.list_proposals_for_corridor(corridor)
+    }
+}
+
+/// Ed25519 delegate keys registered by DID.
+#[cfg(feature = "ed25519")]
+#[derive(Clone, Debug, Default)]
+pub struct Ed25519Verifier {
+    keys: std::collections::HashMap<String, ed25519_dalek::VerifyingKey>,
+}
+
+#[cfg(feature = "ed25519")]
+impl Ed25519Verifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `did`'s 32-byte public key. Fails on bytes that are not a
+    /// valid curve point.
+    pub fn with_key(
+        mut self,
+        did: impl Into<String>,
+        public_key: [u8; 32],
+    ) -> Result<Self, String> {
+        let key =
+            ed25519_dalek::VerifyingKey::from_bytes(&public_key).map_err(|e| e.to_string())?;
+        self.keys.insert(did.into(), key);
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "ed25519")]
+impl SignatureVerifier for Ed25519Verifier {
+    fn verify(&self, did: &str, payload: &[u8], signature: &[u8]) -> Result<(), SignatureFailure> {
+        let key = self
+            .keys
+            .get(did)
+            .ok_or(SignatureFailure::UnknownDelegate)?;
+        let signature = ed25519_dalek::Signature::from_slice(signature)
+            .map_err(|_| SignatureFailure::Invalid)?;
+        key.verify_strict(payload, &signature)
+            .map_err(|_| SignatureFailure::Invalid)
+    }
+}
+
+#[cfg(all(test, feature = "ed25519"))]
+mod tests {
+    use super::*;
+    use crate::DelegateSignature;
+    use ed25519_dalek::{Signer, SigningKey};
+    use std::time::UNIX_EPOCH;
+
+    // RFC 8032, section 7.1, TEST 1.
+    const SECRET: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+    const PUBLIC: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+    const EMPTY_MESSAGE_SIGNATURE: &str = "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b";
+
+    fn unhex<const N: usize>(hex: &str) -> [u8; N] {
+        let mut out = [0; N];
+        for (i, byte) in out.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        out
+    }
+
+    fn verifier() -> Ed25519Verifier {
+        Ed25519Verifier::new()
+            .with_key("did:example:elder", unhex(PUBLIC))
+            .unwrap()
+    }
+
+    fn granted_vote(signed_by: &[&str]) -> CommunityVoteResult {
+        CommunityVoteResult {
+            proposal_id: "snc-policy-2026-02".into(),
+            community_id: CommunityId("indigenous-phoenix-water-shed".into()),
+            fpic_status: FpicStatus::Granted {
+                timestamp: UNIX_EPOCH,
+                signed_by: signed_by.iter().map(|did| did.to_string()).collect(),
+                valid_until: None,
+            },
+            signatures: Vec::new(),
+        }
+    }
+
+    fn sign(vote: &mut CommunityVoteResult, did: &str) {
+        let payload = vote_signing_payload(vote).unwrap();
+        let key = SigningKey::from_bytes(&unhex(SECRET));
+        vote.signatures.push(DelegateSignature {
+            did: did.into(),
+            signature: key.sign(&payload).to_bytes().to_vec(),
+            payload_hash: payload_hash(&payload),
+        });
+    }
+
+    #[test]
+    fn rfc_8032_vector_verifies() {
+        let signature: [u8; 64] = unhex(EMPTY_MESSAGE_SIGNATURE);
+        assert_eq!(
+            verifier().verify("did:example:elder", b"", &signature),
+            Ok(())
+        );
+        assert_eq!(
+            verifier().verify("did:example:elder", b"x", &signature),
+            Err(SignatureFailure::Invalid)
+        );
+        assert_eq!(
+            verifier().verify("did:example:stranger", b"", &signature),
+            Err(SignatureFailure::UnknownDelegate)
+        );
+    }
+
+    #[test]
+    fn signed_vote_is_recorded_and_tampering_is_rejected() {
+        struct Accepting;
+
+        impl CommunityGovernanceBackend for Accepting {
+            fn get_fpic_status(&self, _: &str, _: &CommunityId) -> Result<FpicStatus, String> {
+                Ok(FpicStatus::Pending)
+            }
+
+            fn record_fpic_result(&self, _: CommunityVoteResult) -> Result<(), String> {
+                Ok(())
+            }
+        }
+
+        let backend = SignatureCheckedBackend::new(Accepting, verifier());
+        let mut vote = granted_vote(&["did:example:elder"]);
+        sign(&mut vote, "did:example:elder");
+        assert_eq!(backend.record_fpic_result(vote.clone()), Ok(()));
+
+        vote.proposal_id = "snc-policy-2026-03".into();
+        let err = backend.record_fpic_result(vote).unwrap_err();
+        assert_eq!(
+            err,
+            "delegate signatures rejected: did:example:elder (PayloadHashMismatch)"
+        );
+    }
+
+    #[test]
+    fn failures_list_every_delegate() {
+        let mut vote = granted_vote(&["did:example:elder", "did:example:youth"]);
+        sign(&mut vote, "did:example:elder");
+        // A valid signature under a DID the verifier does not know.
+        sign(&mut vote, "did:example:stranger");
+        vote.signatures[0].signature[0] ^= 1;
+
+        assert_eq!(
+            verify_vote_signatures(&vote, &verifier()),
+            Err(SignatureError::Delegates(vec![
+                DelegateFailure {
+                    did: "did:example:youth".into(),
+                    failure: SignatureFailure::Missing,
+                },
+                DelegateFailure {
+                    did: "did:example:elder".into(),
+                    failure: SignatureFailure::Invalid,
+                },
+                DelegateFailure {
+                    did: "did:example:stranger".into(),
+                    failure: SignatureFailure::UnknownDelegate,
+                },
+            ]))
+        );
+    }
+}
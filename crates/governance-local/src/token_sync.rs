@@ -0,0 +1,155 @@
+//! Bridge between community-level FPIC decisions (`FpicStatus` here) and
+//! the runtime `core_contract::fpic` token state.
+//!
+//! | governance              | runtime   |
+//! |-------------------------|-----------|
+//! | `Pending`               | `Denied`  |
+//! | `Granted { .. }`        | `Granted` |
+//! | `Withheld { .. }`       | `Revoked` |
+//!
+//! Pending maps to `Denied` because consent not yet given is consent not
+//! given. Going to the runtime drops timestamps, delegates, expiry and the
+//! withholding reason; coming back, only `Denied` is recoverable.
+
+use core_contract::fpic::{FpicStatus as RuntimeFpicStatus, FpicToken};
+
+use crate::{CommunityGovernanceBackend, CommunityId, FpicStatus};
+
+impl From<&FpicStatus> for RuntimeFpicStatus {
+    fn from(status: &FpicStatus) -> Self {
+        match status {
+            FpicStatus::Pending => Self::Denied,
+            FpicStatus::Granted { .. } => Self::Granted,
+            FpicStatus::Withheld { .. } => Self::Revoked,
+        }
+    }
+}
+
+impl From<FpicStatus> for RuntimeFpicStatus {
+    fn from(status: FpicStatus) -> Self {
+        Self::from(&status)
+    }
+}
+
+/// Only `Denied` converts (to `Pending`); `Granted` and `Revoked` would
+/// need the timestamp, delegates or reason the runtime status lacks.
+impl TryFrom<RuntimeFpicStatus> for FpicStatus {
+    type Error = String;
+
+    fn try_from(status: RuntimeFpicStatus) -> Result<Self, String> {
+        match status {
+            RuntimeFpicStatus::Denied => Ok(Self::Pending),
+            RuntimeFpicStatus::Granted => {
+                Err("runtime Granted carries no timestamp or delegate signatures".into())
+            }
+            RuntimeFpicStatus::Revoked => {
+                Err("runtime Revoked carries no timestamp or withholding reason".into())
+            }
+        }
+    }
+}
+
+/// Veto `token` if `community` has withheld FPIC for `proposal_id`.
+/// Returns whether governance reported Withheld; other statuses leave the
+/// token untouched.
+pub fn sync_token_from_governance<B: CommunityGovernanceBackend + ?Sized>(
+    backend: &B,
+    proposal_id: &str,
+    community: &CommunityId,
+    token: &mut FpicToken,
+) -> Result<bool, String> {
+    match backend.get_fpic_status(proposal_id, community)? {
+        FpicStatus::Withheld { .. } => {
+            token.veto();
+            Ok(true)
+        }
+        FpicStatus::Pending | FpicStatus::Granted { .. } => Ok(false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CommunityVoteResult;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    fn granted() -> FpicStatus {
+        FpicStatus::Granted {
+            timestamp: UNIX_EPOCH,
+            signed_by: vec!["did:a".into()],
+            valid_until: Some(UNIX_EPOCH + Duration::from_secs(60)),
+        }
+    }
+
+    fn withheld() -> FpicStatus {
+        FpicStatus::Withheld {
+            timestamp: UNIX_EPOCH,
+            reason: "heat season".into(),
+        }
+    }
+
+    struct FixedBackend(FpicStatus);
+
+    impl CommunityGovernanceBackend for FixedBackend {
+        fn get_fpic_status(&self, _: &str, _: &CommunityId) -> Result<FpicStatus, String> {
+            Ok(self.0.clone())
+        }
+
+        fn record_fpic_result(&self, _: CommunityVoteResult) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn governance_statuses_map_onto_runtime_statuses() {
+        assert_eq!(
+            RuntimeFpicStatus::from(FpicStatus::Pending),
+            RuntimeFpicStatus::Denied
+        );
+        // Lossy: delegates and expiry are dropped.
+        assert_eq!(
+            RuntimeFpicStatus::from(granted()),
+            RuntimeFpicStatus::Granted
+        );
+        // Lossy: timestamp and reason are dropped.
+        assert_eq!(
+            RuntimeFpicStatus::from(&withheld()),
+            RuntimeFpicStatus::Revoked
+        );
+    }
+
+    #[test]
+    fn only_denied_converts_back() {
+        assert!(matches!(
+            FpicStatus::try_from(RuntimeFpicStatus::Denied),
+            Ok(FpicStatus::Pending)
+        ));
+        assert!(FpicStatus::try_from(RuntimeFpicStatus::Granted).is_err());
+        assert!(FpicStatus::try_from(RuntimeFpicStatus::Revoked).is_err());
+
+        let round_trip = FpicStatus::try_from(RuntimeFpicStatus::from(FpicStatus::Pending));
+        assert!(matches!(round_trip, Ok(FpicStatus::Pending)));
+    }
+
+    #[test]
+    fn withheld_vetoes_the_runtime_token() {
+        let community = CommunityId("indigenous-phoenix-water-shed".into());
+        let now = SystemTime::now();
+        for (status, vetoed) in [
+            (FpicStatus::Pending, false),
+            (granted(), false),
+            (withheld(), true),
+        ] {
+            let mut token = FpicToken::new(now, Duration::from_secs(3_600));
+            let synced =
+                sync_token_from_governance(&FixedBackend(status), "p-1", &community, &mut token);
+            assert_eq!(synced, Ok(vetoed));
+            let expected = if vetoed {
+                RuntimeFpicStatus::Revoked
+            } else {
+                RuntimeFpicStatus::Granted
+            };
+            assert_eq!(token.status(now), expected);
+        }
+    }
+}
@@ -0,0 +1,233 @@
+//! Memoizing wrapper for slow (usually remote) simulators.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::{PolicySimulationBackend, SimulationHorizon, SimulationOutcome, SncPolicySnapshot};
+
+/// Cache key of a snapshot: the IEEE-754 bits of each field in declaration
+/// order, so equal keys mean bit-identical inputs regardless of how the
+/// values were parsed or printed. `-0.0` is folded into `0.0` and every NaN
+/// into one canonical NaN.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SnapshotKey([u32; 3]);
+
+impl SnapshotKey {
+    pub fn of(snapshot: &SncPolicySnapshot) -> Self {
+        let bits = |v: f32| {
+            if v == 0.0 {
+                0
+            } else if v.is_nan() {
+                f32::NAN.to_bits()
+            } else {
+                v.to_bits()
+            }
+        };
+        Self([
+            bits(snapshot.min_knowledge_factor_open),
+            bits(snapshot.chat_issuance_slope),
+            bits(snapshot.eco_weight),
+        ])
+    }
+}
+
+/// Wraps a `PolicySimulationBackend`, reusing an outcome for the same
+/// `SnapshotKey` until it is `ttl` old. At `capacity` the oldest entry is
+/// evicted. Errors are never cached.
+pub struct CachedSimulationBackend<S> {
+    inner: S,
+    ttl: Duration,
+    capacity: usize,
+    entries: Mutex<HashMap<SnapshotKey, (Instant, SimulationOutcome)>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl<S: PolicySimulationBackend> CachedSimulationBackend<S> {
+    /// Five-minute TTL, 256 entries.
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            ttl: Duration::from_secs(300),
+            capacity: 256,
+            entries: Mutex::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// A capacity of zero disables caching.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Evaluations answered from the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Evaluations passed to the inner backend, forced refreshes included.
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(
+        &self,
+    ) -> std::sync::MutexGuard<'_, HashMap<SnapshotKey, (Instant, SimulationOutcome)>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// `evaluate_policy`, skipping the lookup when `force_refresh` is set;
+    /// a successful refresh replaces the cached outcome.
+    pub fn evaluate_policy_cached(
+        &self,
+        policy: &SncPolicySnapshot,
+        force_refresh: bool,
+    ) -> Result<SimulationOutcome, String> {
+        let key = SnapshotKey::of(policy);
+        if !force_refresh {
+            if let Some((at, outcome)) = self.lock().get(&key) {
+                if at.elapsed() < self.ttl {
+                    self.hits.fetch_add(1, Ordering::Relaxed);
+                    return Ok(outcome.clone());
+                }
+            }
+        }
+
+        // The lock is not held across the (slow) inner evaluation.
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let outcome = self.inner.evaluate_policy(policy)?;
+        if self.capacity > 0 {
+            let mut entries = self.lock();
+            if entries.len() >= self.capacity && !entries.contains_key(&key) {
+                entries.retain(|_, (at, _)| at.elapsed() < self.ttl);
+                if entries.len() >= self.capacity {
+                    if let Some(oldest) = entries
+                        .iter()
+                        .min_by_key(|(_, (at, _))| *at)
+                        .map(|(k, _)| *k)
+                    {
+                        entries.remove(&oldest);
+                    }
+                }
+            }
+            entries.insert(key, (Instant::now(), outcome.clone()));
+        }
+        Ok(outcome)
+    }
+}
+
+impl<S: PolicySimulationBackend> PolicySimulationBackend for CachedSimulationBackend<S> {
+    fn evaluate_policy(&self, policy: &SncPolicySnapshot) -> Result<SimulationOutcome, String> {
+        self.evaluate_policy_cached(policy, false)
+    }
+
+    /// Not cached: keys carry no horizon.
+    fn evaluate_policy_over(
+        &self,
+        policy: &SncPolicySnapshot,
+        horizon: SimulationHorizon,
+    ) -> Result<SimulationOutcome, String> {
+        self.inner.evaluate_policy_over(policy, horizon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    #[derive(Default)]
+    struct Counting {
+        calls: AtomicUsize,
+    }
+
+    impl PolicySimulationBackend for Counting {
+        fn evaluate_policy(&self, p: &SncPolicySnapshot) -> Result<SimulationOutcome, String> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            if p.eco_weight < 0.0 {
+                return Err(format!("negative eco weight (call {call})"));
+            }
+            Ok(SimulationOutcome {
+                expected_neurorights_risk: 0.1,
+                environmental_justice_score: p.eco_weight,
+                trust_index: call as f32,
+            })
+        }
+    }
+
+    fn snapshot(eco_weight: f32) -> SncPolicySnapshot {
+        SncPolicySnapshot {
+            min_knowledge_factor_open: 0.75,
+            chat_issuance_slope: 1.0,
+            eco_weight,
+        }
+    }
+
+    #[test]
+    fn identical_snapshots_hit_and_one_ulp_misses() {
+        let cache = CachedSimulationBackend::new(Counting::default());
+        let first = cache.evaluate_policy(&snapshot(0.4)).unwrap();
+        // Parsed from a differently formatted literal: same bits.
+        let same = snapshot("0.400".parse().unwrap());
+        assert_eq!(
+            cache.evaluate_policy(&same).unwrap().trust_index,
+            first.trust_index
+        );
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+
+        let next_ulp = snapshot(f32::from_bits(0.4f32.to_bits() + 1));
+        assert_eq!(cache.evaluate_policy(&next_ulp).unwrap().trust_index, 2.0);
+        assert_eq!((cache.hits(), cache.misses()), (1, 2));
+
+        assert_eq!(
+            SnapshotKey::of(&snapshot(-0.0)),
+            SnapshotKey::of(&snapshot(0.0))
+        );
+    }
+
+    #[test]
+    fn force_refresh_ttl_capacity_and_errors() {
+        let cache = CachedSimulationBackend::new(Counting::default()).with_capacity(2);
+        cache.evaluate_policy(&snapshot(0.1)).unwrap();
+        let refreshed = cache.evaluate_policy_cached(&snapshot(0.1), true).unwrap();
+        assert_eq!(refreshed.trust_index, 2.0);
+        assert_eq!(
+            cache.evaluate_policy(&snapshot(0.1)).unwrap().trust_index,
+            2.0
+        );
+
+        // 0.1 is the oldest entry and is evicted by the third snapshot.
+        cache.evaluate_policy(&snapshot(0.2)).unwrap();
+        cache.evaluate_policy(&snapshot(0.3)).unwrap();
+        assert_eq!(
+            cache.evaluate_policy(&snapshot(0.1)).unwrap().trust_index,
+            5.0
+        );
+
+        assert!(cache.evaluate_policy(&snapshot(-1.0)).is_err());
+        assert!(cache.evaluate_policy(&snapshot(-1.0)).is_err());
+        assert_eq!(cache.inner().calls.load(Ordering::SeqCst), 7);
+
+        let expired = CachedSimulationBackend::new(Counting::default()).with_ttl(Duration::ZERO);
+        expired.evaluate_policy(&snapshot(0.1)).unwrap();
+        expired.evaluate_policy(&snapshot(0.1)).unwrap();
+        assert_eq!((expired.hits(), expired.misses()), (0, 2));
+    }
+}
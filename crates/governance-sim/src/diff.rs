@@ -0,0 +1,195 @@
+//! Field-by-field comparison of two `SncPolicySnapshot`s.
+
+use std::fmt;
+
+use crate::{SnapshotParam, SncPolicySnapshot};
+
+/// Whether a change makes the policy stricter or more permissive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PolicyDirection {
+    Tightening,
+    Loosening,
+}
+
+impl SnapshotParam {
+    /// Direction of raising this field: a higher F_K floor for Open or a
+    /// heavier eco weight tightens; steeper CHAT issuance loosens.
+    pub fn raising(self) -> PolicyDirection {
+        match self {
+            Self::MinKnowledgeFactorOpen | Self::EcoWeight => PolicyDirection::Tightening,
+            Self::ChatIssuanceSlope => PolicyDirection::Loosening,
+        }
+    }
+}
+
+/// One field that differs between two snapshots.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldChange {
+    pub param: SnapshotParam,
+    pub from: f32,
+    pub to: f32,
+    pub direction: PolicyDirection,
+}
+
+impl FieldChange {
+    pub fn delta(&self) -> f32 {
+        self.to - self.from
+    }
+}
+
+impl fmt::Display for FieldChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction = match self.direction {
+            PolicyDirection::Tightening => "tightening",
+            PolicyDirection::Loosening => "loosening",
+        };
+        write!(
+            f,
+            "change {} from {} to {} ({direction})",
+            self.param, self.from, self.to
+        )
+    }
+}
+
+/// Changes from one snapshot to another, in `SnapshotParam::ALL` order.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PolicyDiff {
+    pub changes: Vec<FieldChange>,
+}
+
+impl PolicyDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// True if no change loosens the policy.
+    pub fn only_tightens(&self) -> bool {
+        self.changes
+            .iter()
+            .all(|c| c.direction == PolicyDirection::Tightening)
+    }
+
+    /// The changes in `direction`.
+    pub fn in_direction(&self, direction: PolicyDirection) -> impl Iterator<Item = &FieldChange> {
+        self.changes
+            .iter()
+            .filter(move |c| c.direction == direction)
+    }
+
+    /// "no change", or each change joined by "; ".
+    pub fn summary(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for PolicyDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return f.write_str("no change");
+        }
+        for (i, change) in self.changes.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+impl SncPolicySnapshot {
+    /// What changes going from `self` to `other`. Fields are compared
+    /// exactly.
+    pub fn diff(&self, other: &SncPolicySnapshot) -> PolicyDiff {
+        let changes = SnapshotParam::ALL
+            .into_iter()
+            .filter_map(|param| {
+                let (from, to) = (param.get(self), param.get(other));
+                (from != to).then(|| FieldChange {
+                    param,
+                    from,
+                    to,
+                    direction: if to > from {
+                        param.raising()
+                    } else {
+                        match param.raising() {
+                            PolicyDirection::Tightening => PolicyDirection::Loosening,
+                            PolicyDirection::Loosening => PolicyDirection::Tightening,
+                        }
+                    },
+                })
+            })
+            .collect();
+        PolicyDiff { changes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn current() -> SncPolicySnapshot {
+        SncPolicySnapshot {
+            min_knowledge_factor_open: 0.75,
+            chat_issuance_slope: 1.0,
+            eco_weight: 0.3,
+        }
+    }
+
+    #[test]
+    fn identical_snapshots_have_no_change() {
+        let diff = current().diff(&current());
+        assert!(diff.is_empty());
+        assert!(diff.only_tightens());
+        assert_eq!(diff.summary(), "no change");
+    }
+
+    #[test]
+    fn mixed_diff_classifies_each_field() {
+        let proposed = SncPolicySnapshot {
+            min_knowledge_factor_open: 0.7,
+            chat_issuance_slope: 0.5,
+            eco_weight: 0.4,
+        };
+        let diff = current().diff(&proposed);
+        let directions: Vec<_> = diff
+            .changes
+            .iter()
+            .map(|c| (c.param, c.direction))
+            .collect();
+        assert_eq!(
+            directions,
+            [
+                (
+                    SnapshotParam::MinKnowledgeFactorOpen,
+                    PolicyDirection::Loosening
+                ),
+                (
+                    SnapshotParam::ChatIssuanceSlope,
+                    PolicyDirection::Tightening
+                ),
+                (SnapshotParam::EcoWeight, PolicyDirection::Tightening),
+            ]
+        );
+        assert!(!diff.only_tightens());
+        assert_eq!(diff.in_direction(PolicyDirection::Loosening).count(), 1);
+        assert!((diff.changes[2].delta() - 0.1).abs() < 1e-6);
+        assert_eq!(
+            diff.summary(),
+            "change min_knowledge_factor_open from 0.75 to 0.7 (loosening); \
+             change chat_issuance_slope from 1 to 0.5 (tightening); \
+             change eco_weight from 0.3 to 0.4 (tightening)"
+        );
+
+        // Reversing the diff flips every direction.
+        let back = proposed.diff(&current());
+        assert!(back
+            .changes
+            .iter()
+            .zip(&diff.changes)
+            .all(|(b, d)| b.direction != d.direction && b.from == d.to));
+    }
+}
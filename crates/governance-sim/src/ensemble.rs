@@ -0,0 +1,215 @@
+//! Monte-Carlo ensembles of simulator runs.
+//!
+//! A single `SimulationOutcome` is a point estimate; a `SimulationEnsemble`
+//! summarizes many runs of the same policy so callers can bound the tail,
+//! not just the mean.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::{PolicySimulationBackend, SimulationOutcome, SncPolicySnapshot};
+
+/// Percentiles computed by `PolicySimulationBackend::evaluate_policy_ensemble`.
+pub const DEFAULT_PERCENTILES: [f32; 3] = [5.0, 50.0, 95.0];
+
+/// Summary of one outcome field across an ensemble.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutcomeStats {
+    pub mean: f32,
+    /// Population standard deviation.
+    pub stddev: f32,
+    /// `(percentile, value)` pairs in request order, percentiles in
+    /// [0, 100], values linearly interpolated between the closest runs.
+    pub percentiles: Vec<(f32, f32)>,
+}
+
+impl OutcomeStats {
+    /// Fails on an empty sample, a non-finite value, or a percentile outside
+    /// [0, 100].
+    pub fn from_samples(samples: &[f32], percentiles: &[f32]) -> Result<Self, String> {
+        if samples.is_empty() {
+            return Err("no simulation runs to summarize".into());
+        }
+        if let Some(v) = samples.iter().find(|v| !v.is_finite()) {
+            return Err(format!("non-finite simulation value {v}"));
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f32::total_cmp);
+
+        let n = sorted.len() as f64;
+        let mean = sorted.iter().map(|&v| f64::from(v)).sum::<f64>() / n;
+        let variance = sorted
+            .iter()
+            .map(|&v| (f64::from(v) - mean).powi(2))
+            .sum::<f64>()
+            / n;
+
+        let percentiles = percentiles
+            .iter()
+            .map(|&p| {
+                if !(0.0..=100.0).contains(&p) {
+                    return Err(format!("percentile {p} outside [0, 100]"));
+                }
+                let rank = f64::from(p) / 100.0 * (sorted.len() - 1) as f64;
+                let (lo, hi) = (rank.floor() as usize, rank.ceil() as usize);
+                let fraction = rank - lo as f64;
+                let value = f64::from(sorted[lo])
+                    + (f64::from(sorted[hi]) - f64::from(sorted[lo])) * fraction;
+                Ok((p, value as f32))
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Self {
+            mean: mean as f32,
+            stddev: variance.sqrt() as f32,
+            percentiles,
+        })
+    }
+
+    /// The value at `percentile`, if it was computed.
+    pub fn percentile(&self, percentile: f32) -> Option<f32> {
+        self.percentiles
+            .iter()
+            .find(|(p, _)| *p == percentile)
+            .map(|(_, v)| *v)
+    }
+}
+
+/// Distribution of the three `SimulationOutcome` fields over `runs` runs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimulationEnsemble {
+    pub runs: usize,
+    pub expected_neurorights_risk: OutcomeStats,
+    pub environmental_justice_score: OutcomeStats,
+    pub trust_index: OutcomeStats,
+}
+
+impl SimulationEnsemble {
+    /// Summarize `outcomes` with the given percentiles for every field.
+    pub fn from_outcomes(
+        outcomes: &[SimulationOutcome],
+        percentiles: &[f32],
+    ) -> Result<Self, String> {
+        let field = |get: fn(&SimulationOutcome) -> f32| {
+            let samples: Vec<f32> = outcomes.iter().map(get).collect();
+            OutcomeStats::from_samples(&samples, percentiles)
+        };
+        Ok(Self {
+            runs: outcomes.len(),
+            expected_neurorights_risk: field(|o| o.expected_neurorights_risk)?,
+            environmental_justice_score: field(|o| o.environmental_justice_score)?,
+            trust_index: field(|o| o.trust_index)?,
+        })
+    }
+}
+
+/// Reference simulator drawing each field uniformly from `center ± spread`,
+/// clamped to [0, 1], from a seeded SplitMix64 stream. The policy is
+/// ignored; two simulators with the same seed produce the same runs.
+#[derive(Debug)]
+pub struct SeededSimulator {
+    center: SimulationOutcome,
+    spread: f32,
+    state: AtomicU64,
+}
+
+impl SeededSimulator {
+    pub fn new(seed: u64, center: SimulationOutcome, spread: f32) -> Self {
+        Self {
+            center,
+            spread,
+            state: AtomicU64::new(seed),
+        }
+    }
+
+    /// Uniform in [0, 1).
+    fn next_unit(&self) -> f32 {
+        const GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+        let mut z = self
+            .state
+            .fetch_add(GAMMA, Ordering::Relaxed)
+            .wrapping_add(GAMMA);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        (z >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    fn jitter(&self, center: f32) -> f32 {
+        (center + (self.next_unit() * 2.0 - 1.0) * self.spread).clamp(0.0, 1.0)
+    }
+}
+
+impl PolicySimulationBackend for SeededSimulator {
+    fn evaluate_policy(&self, _: &SncPolicySnapshot) -> Result<SimulationOutcome, String> {
+        Ok(SimulationOutcome {
+            expected_neurorights_risk: self.jitter(self.center.expected_neurorights_risk),
+            environmental_justice_score: self.jitter(self.center.environmental_justice_score),
+            trust_index: self.jitter(self.center.trust_index),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> SncPolicySnapshot {
+        SncPolicySnapshot {
+            min_knowledge_factor_open: 0.75,
+            chat_issuance_slope: 1.0,
+            eco_weight: 1.0,
+        }
+    }
+
+    fn simulator(seed: u64) -> SeededSimulator {
+        SeededSimulator::new(
+            seed,
+            SimulationOutcome {
+                expected_neurorights_risk: 0.25,
+                environmental_justice_score: 0.8,
+                trust_index: 0.5,
+            },
+            0.1,
+        )
+    }
+
+    #[test]
+    fn stats_interpolate_percentiles() {
+        let stats = OutcomeStats::from_samples(&[4.0, 1.0, 3.0, 2.0], &[0.0, 50.0, 100.0]).unwrap();
+        assert_eq!(stats.mean, 2.5);
+        assert!((stats.stddev - 1.25f32.sqrt()).abs() < 1e-6);
+        assert_eq!(stats.percentile(0.0), Some(1.0));
+        assert_eq!(stats.percentile(50.0), Some(2.5));
+        assert_eq!(stats.percentile(100.0), Some(4.0));
+        assert_eq!(stats.percentile(95.0), None);
+
+        assert!(OutcomeStats::from_samples(&[], &[50.0]).is_err());
+        assert!(OutcomeStats::from_samples(&[1.0], &[101.0]).is_err());
+        assert!(OutcomeStats::from_samples(&[f32::NAN], &[50.0]).is_err());
+    }
+
+    #[test]
+    fn seeded_ensembles_are_reproducible_and_bounded() {
+        let first = simulator(7)
+            .evaluate_policy_ensemble(&policy(), 200)
+            .unwrap();
+        let second = simulator(7)
+            .evaluate_policy_ensemble(&policy(), 200)
+            .unwrap();
+        assert_eq!(first, second);
+        assert_ne!(
+            first,
+            simulator(8)
+                .evaluate_policy_ensemble(&policy(), 200)
+                .unwrap()
+        );
+
+        let risk = &first.expected_neurorights_risk;
+        assert_eq!(first.runs, 200);
+        assert!((risk.mean - 0.25).abs() < 0.02);
+        assert!(risk.stddev > 0.0);
+        let p95 = risk.percentile(95.0).unwrap();
+        assert!(p95 > 0.3 && p95 <= 0.35);
+        assert!(simulator(7).evaluate_policy_ensemble(&policy(), 0).is_err());
+    }
+}
@@ -1,7 +1,29 @@
+use std::future::Future;
+use std::time::{Duration, Instant};
+
 use core_contract::eco::EcoImpactMetrics;
+use core_contract::health::{ComponentHealth, HealthCheckable, HealthStatus};
+
+mod cache;
+mod diff;
+mod ensemble;
+mod linear;
+mod pareto;
+mod sensitivity;
+
+pub use cache::{CachedSimulationBackend, SnapshotKey};
+pub use diff::{FieldChange, PolicyDiff, PolicyDirection};
+pub use ensemble::{OutcomeStats, SeededSimulator, SimulationEnsemble, DEFAULT_PERCENTILES};
+pub use linear::{LinearCoefficients, LinearPolicySimulator};
+pub use pareto::{
+    rank_policies, rank_policies_with_epsilon, Domination, OutcomeAxis, PolicyRanking, RankedPolicy,
+    PARETO_EPSILON,
+};
+pub use sensitivity::{sensitivity_sweep, OutcomeGradient, SensitivitySweep, SnapshotParam};
 
 /// Snapshot of an SNC rule configuration relevant for system‑level analysis.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SncPolicySnapshot {
     pub min_knowledge_factor_open: f32,
     pub chat_issuance_slope: f32,
@@ -16,15 +38,195 @@ pub struct SimulationOutcome {
     pub trust_index: f32,                 // 0 = opaque, 1 = transparent
 }
 
+/// How far ahead an outcome applies, and how steeply later years are
+/// discounted.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimulationHorizon {
+    pub years: f32,
+    /// Annual rate; 0.03 discounts each year by 3%.
+    pub discount_rate: f32,
+}
+
+impl SimulationHorizon {
+    /// `(1 + discount_rate)^-years`; fails unless `years` is finite and
+    /// non-negative and `discount_rate` finite and above -1.
+    pub fn discount_factor(&self) -> Result<f32, String> {
+        if !(self.years.is_finite() && self.years >= 0.0) {
+            return Err(format!("invalid horizon of {} years", self.years));
+        }
+        if !(self.discount_rate.is_finite() && self.discount_rate > -1.0) {
+            return Err(format!("invalid discount rate {}", self.discount_rate));
+        }
+        Ok((1.0 + self.discount_rate).powf(-self.years))
+    }
+}
+
 /// Trait for a policy simulator backend (Osireon, AEON, etc.).[web:136][web:137]
 pub trait PolicySimulationBackend {
     fn evaluate_policy(
         &self,
         policy: &SncPolicySnapshot,
     ) -> Result<SimulationOutcome, String>;
+
+    /// `evaluate_policy` for outcomes over `horizon`. Backends without a
+    /// notion of time keep this default, which fails rather than passing
+    /// off a point-in-time outcome as a long-run one.
+    fn evaluate_policy_over(
+        &self,
+        _policy: &SncPolicySnapshot,
+        horizon: SimulationHorizon,
+    ) -> Result<SimulationOutcome, String> {
+        Err(format!(
+            "simulator does not support a {}-year horizon",
+            horizon.years
+        ))
+    }
+
+    /// Run `evaluate_policy` `n_runs` times and summarize the outcomes with
+    /// `DEFAULT_PERCENTILES`. Fails if `n_runs` is zero or any run fails.
+    fn evaluate_policy_ensemble(
+        &self,
+        policy: &SncPolicySnapshot,
+        n_runs: usize,
+    ) -> Result<SimulationEnsemble, String> {
+        self.evaluate_policy_ensemble_with_percentiles(policy, n_runs, &DEFAULT_PERCENTILES)
+    }
+
+    /// `evaluate_policy_ensemble` with caller-chosen percentiles in [0, 100].
+    fn evaluate_policy_ensemble_with_percentiles(
+        &self,
+        policy: &SncPolicySnapshot,
+        n_runs: usize,
+        percentiles: &[f32],
+    ) -> Result<SimulationEnsemble, String> {
+        let outcomes = (0..n_runs)
+            .map(|_| self.evaluate_policy(policy))
+            .collect::<Result<Vec<_>, _>>()?;
+        SimulationEnsemble::from_outcomes(&outcomes, percentiles)
+    }
+}
+
+/// Async counterpart of `PolicySimulationBackend` for simulators behind a
+/// network (Osireon nodes, remote AEON services). Implementations write
+/// `async fn evaluate_policy`; the returned future must be `Send`.
+pub trait AsyncPolicySimulationBackend {
+    fn evaluate_policy(
+        &self,
+        policy: &SncPolicySnapshot,
+    ) -> impl Future<Output = Result<SimulationOutcome, String>> + Send;
+
+    /// `evaluate_policy` for outcomes over `horizon`; fails by default, as
+    /// `PolicySimulationBackend::evaluate_policy_over` does.
+    fn evaluate_policy_over(
+        &self,
+        _policy: &SncPolicySnapshot,
+        horizon: SimulationHorizon,
+    ) -> impl Future<Output = Result<SimulationOutcome, String>> + Send {
+        async move {
+            Err(format!(
+                "simulator does not support a {}-year horizon",
+                horizon.years
+            ))
+        }
+    }
+}
+
+/// Exposes any synchronous `PolicySimulationBackend` through
+/// `AsyncPolicySimulationBackend`; each call runs the sync simulation to
+/// completion on first poll.
+pub struct SyncSimulationAdapter<S>(pub S);
+
+impl<S: PolicySimulationBackend + Sync> AsyncPolicySimulationBackend for SyncSimulationAdapter<S> {
+    async fn evaluate_policy(&self, policy: &SncPolicySnapshot) -> Result<SimulationOutcome, String> {
+        self.0.evaluate_policy(policy)
+    }
+
+    async fn evaluate_policy_over(
+        &self,
+        policy: &SncPolicySnapshot,
+        horizon: SimulationHorizon,
+    ) -> Result<SimulationOutcome, String> {
+        self.0.evaluate_policy_over(policy, horizon)
+    }
 }
 
 /// Optional helper: combine EcoImpact into a simple global indicator.
 pub fn eco_to_global_indicator(eco: &EcoImpactMetrics) -> f32 {
     eco.scalar()
 }
+
+/// Readiness probe for a simulator: evaluates a reference policy and checks
+/// the indicators come back finite and in [0,1] within `slow_after`.
+pub struct SimulatorProbe<'a, S: PolicySimulationBackend> {
+    pub name: &'static str,
+    pub backend: &'a S,
+    pub reference_policy: SncPolicySnapshot,
+    pub slow_after: Duration,
+}
+
+impl<S: PolicySimulationBackend> HealthCheckable for SimulatorProbe<'_, S> {
+    fn health(&self) -> ComponentHealth {
+        let component = format!("simulator:{}", self.name);
+        let started = Instant::now();
+        let result = self.backend.evaluate_policy(&self.reference_policy);
+        let elapsed = started.elapsed();
+
+        match result {
+            Err(e) => ComponentHealth::new(component, HealthStatus::Unhealthy, format!("evaluation failed: {e}")),
+            Ok(o) => {
+                let indicators = [o.expected_neurorights_risk, o.environmental_justice_score, o.trust_index];
+                if !indicators.iter().all(|v| v.is_finite() && (0.0..=1.0).contains(v)) {
+                    ComponentHealth::new(component, HealthStatus::Degraded, "indicators outside [0,1]")
+                } else if elapsed > self.slow_after {
+                    ComponentHealth::new(
+                        component,
+                        HealthStatus::Degraded,
+                        format!("slow evaluation: {} ms", elapsed.as_millis()),
+                    )
+                } else {
+                    ComponentHealth::new(component, HealthStatus::Healthy, format!("ok in {} ms", elapsed.as_millis()))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct ScriptedSim(Result<f32, String>);
+
+    impl PolicySimulationBackend for ScriptedSim {
+        fn evaluate_policy(&self, _: &SncPolicySnapshot) -> Result<SimulationOutcome, String> {
+            self.0.clone().map(|v| SimulationOutcome {
+                expected_neurorights_risk: v,
+                environmental_justice_score: v,
+                trust_index: v,
+            })
+        }
+    }
+
+    fn probe(sim: &ScriptedSim) -> HealthStatus {
+        SimulatorProbe {
+            name: "scripted",
+            backend: sim,
+            reference_policy: SncPolicySnapshot {
+                min_knowledge_factor_open: 0.75,
+                chat_issuance_slope: 1.0,
+                eco_weight: 1.0,
+            },
+            slow_after: Duration::from_secs(60),
+        }
+        .health()
+        .status
+    }
+
+    #[test]
+    fn simulator_probe_paths() {
+        assert_eq!(probe(&ScriptedSim(Ok(0.5))), HealthStatus::Healthy);
+        assert_eq!(probe(&ScriptedSim(Ok(1.5))), HealthStatus::Degraded);
+        assert_eq!(probe(&ScriptedSim(Err("model not loaded".into()))), HealthStatus::Unhealthy);
+    }
+}
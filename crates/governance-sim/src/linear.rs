@@ -0,0 +1,202 @@
+//! Reference simulator: each outcome is an affine function of the policy
+//! snapshot, clamped to [0, 1].
+//!
+//! ```text
+//! neurorights risk  = risk_intercept
+//!                   + risk_per_issuance_slope  * chat_issuance_slope
+//!                   + risk_per_knowledge_floor * min_knowledge_factor_open
+//! justice           = justice_intercept
+//!                   + justice_per_eco_weight   * eco_weight
+//! trust             = trust_intercept
+//!                   + trust_per_knowledge_floor * min_knowledge_factor_open
+//!                   + trust_per_issuance_slope  * chat_issuance_slope
+//! ```
+//!
+//! Over a `SimulationHorizon`, justice and trust are multiplied by its
+//! discount factor; risk is not discounted.
+//!
+//! The defaults encode the direction of each effect, not a calibrated
+//! model: steeper CHAT issuance raises neurorights risk and erodes trust, a
+//! higher F_K floor for Open lowers risk and raises trust, and a heavier eco
+//! weight raises environmental justice.
+
+use crate::{PolicySimulationBackend, SimulationHorizon, SimulationOutcome, SncPolicySnapshot};
+
+/// Coefficients of `LinearPolicySimulator`; see the module docs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinearCoefficients {
+    pub risk_intercept: f32,
+    pub risk_per_issuance_slope: f32,
+    pub risk_per_knowledge_floor: f32,
+    pub justice_intercept: f32,
+    pub justice_per_eco_weight: f32,
+    pub trust_intercept: f32,
+    pub trust_per_knowledge_floor: f32,
+    pub trust_per_issuance_slope: f32,
+}
+
+impl Default for LinearCoefficients {
+    fn default() -> Self {
+        Self {
+            risk_intercept: 0.1,
+            risk_per_issuance_slope: 0.2,
+            risk_per_knowledge_floor: -0.15,
+            justice_intercept: 0.45,
+            justice_per_eco_weight: 0.5,
+            trust_intercept: 0.3,
+            trust_per_knowledge_floor: 0.5,
+            trust_per_issuance_slope: -0.1,
+        }
+    }
+}
+
+/// Deterministic `PolicySimulationBackend` over `LinearCoefficients`.
+/// Fails on a snapshot with a non-finite field.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LinearPolicySimulator {
+    pub coefficients: LinearCoefficients,
+}
+
+impl LinearPolicySimulator {
+    pub fn new(coefficients: LinearCoefficients) -> Self {
+        Self { coefficients }
+    }
+}
+
+impl PolicySimulationBackend for LinearPolicySimulator {
+    fn evaluate_policy(&self, policy: &SncPolicySnapshot) -> Result<SimulationOutcome, String> {
+        let SncPolicySnapshot {
+            min_knowledge_factor_open: floor,
+            chat_issuance_slope: slope,
+            eco_weight,
+        } = *policy;
+        if ![floor, slope, eco_weight].iter().all(|v| v.is_finite()) {
+            return Err(format!("non-finite policy snapshot {policy:?}"));
+        }
+        let c = &self.coefficients;
+        let unit = |v: f32| v.clamp(0.0, 1.0);
+        Ok(SimulationOutcome {
+            expected_neurorights_risk: unit(
+                c.risk_intercept
+                    + c.risk_per_issuance_slope * slope
+                    + c.risk_per_knowledge_floor * floor,
+            ),
+            environmental_justice_score: unit(
+                c.justice_intercept + c.justice_per_eco_weight * eco_weight,
+            ),
+            trust_index: unit(
+                c.trust_intercept
+                    + c.trust_per_knowledge_floor * floor
+                    + c.trust_per_issuance_slope * slope,
+            ),
+        })
+    }
+
+    fn evaluate_policy_over(
+        &self,
+        policy: &SncPolicySnapshot,
+        horizon: SimulationHorizon,
+    ) -> Result<SimulationOutcome, String> {
+        let discount = horizon.discount_factor()?;
+        let outcome = self.evaluate_policy(policy)?;
+        Ok(SimulationOutcome {
+            environmental_justice_score: outcome.environmental_justice_score * discount,
+            trust_index: outcome.trust_index * discount,
+            ..outcome
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(floor: f32, slope: f32, eco_weight: f32) -> [f32; 3] {
+        let o = LinearPolicySimulator::default()
+            .evaluate_policy(&SncPolicySnapshot {
+                min_knowledge_factor_open: floor,
+                chat_issuance_slope: slope,
+                eco_weight,
+            })
+            .unwrap();
+        [
+            o.expected_neurorights_risk,
+            o.environmental_justice_score,
+            o.trust_index,
+        ]
+    }
+
+    fn assert_outcome(actual: [f32; 3], expected: [f32; 3]) {
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-6, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn default_coefficients_are_pinned() {
+        // The CLI's example proposal.
+        assert_outcome(outcome(0.8, 1.0, 0.4), [0.18, 0.65, 0.6]);
+        assert_outcome(outcome(0.75, 1.0, 1.0), [0.1875, 0.95, 0.575]);
+        assert_outcome(outcome(0.0, 0.0, 0.0), [0.1, 0.45, 0.3]);
+        // Steep issuance with no F_K floor: risk over the default 0.3 bound.
+        assert_outcome(outcome(0.0, 2.0, 0.4), [0.5, 0.65, 0.1]);
+        // Outputs clamp to [0, 1].
+        assert_outcome(outcome(1.0, 10.0, 2.0), [1.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn longer_horizons_discount_justice_and_trust() {
+        let simulator = LinearPolicySimulator::default();
+        let policy = SncPolicySnapshot {
+            min_knowledge_factor_open: 0.8,
+            chat_issuance_slope: 1.0,
+            eco_weight: 0.4,
+        };
+        let over = |years| {
+            simulator
+                .evaluate_policy_over(
+                    &policy,
+                    SimulationHorizon {
+                        years,
+                        discount_rate: 0.03,
+                    },
+                )
+                .unwrap()
+        };
+
+        let now = over(0.0);
+        assert_outcome(
+            [
+                now.expected_neurorights_risk,
+                now.environmental_justice_score,
+                now.trust_index,
+            ],
+            [0.18, 0.65, 0.6],
+        );
+        let (one, twenty) = (over(1.0), over(20.0));
+        assert!((one.environmental_justice_score - 0.65 / 1.03).abs() < 1e-6);
+        assert!(twenty.environmental_justice_score < one.environmental_justice_score);
+        assert!((twenty.environmental_justice_score - 0.65 * 1.03f32.powi(-20)).abs() < 1e-5);
+        assert!(twenty.trust_index < one.trust_index);
+        assert_eq!(
+            twenty.expected_neurorights_risk,
+            now.expected_neurorights_risk
+        );
+
+        let invalid = SimulationHorizon {
+            years: -1.0,
+            discount_rate: 0.03,
+        };
+        assert!(simulator.evaluate_policy_over(&policy, invalid).is_err());
+    }
+
+    #[test]
+    fn non_finite_snapshot_is_an_error() {
+        let err = LinearPolicySimulator::default().evaluate_policy(&SncPolicySnapshot {
+            min_knowledge_factor_open: f32::NAN,
+            chat_issuance_slope: 1.0,
+            eco_weight: 1.0,
+        });
+        assert!(err.is_err());
+    }
+}
@@ -0,0 +1,258 @@
+//! Pareto comparison of candidate policies.
+//!
+//! Candidates are compared on three axes: low neurorights risk, high
+//! environmental justice and high trust. One candidate dominates another
+//! when it is no worse on every axis and better on at least one, where
+//! differences within the epsilon count as ties.
+
+use std::fmt;
+
+use crate::{PolicySimulationBackend, SimulationOutcome, SncPolicySnapshot};
+
+/// Differences at or below this are ties in `rank_policies`.
+pub const PARETO_EPSILON: f32 = 1e-3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OutcomeAxis {
+    /// Lower is better.
+    NeurorightsRisk,
+    EnvironmentalJustice,
+    TrustIndex,
+}
+
+impl OutcomeAxis {
+    pub const ALL: [OutcomeAxis; 3] = [
+        OutcomeAxis::NeurorightsRisk,
+        OutcomeAxis::EnvironmentalJustice,
+        OutcomeAxis::TrustIndex,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::NeurorightsRisk => "neurorights risk",
+            Self::EnvironmentalJustice => "environmental justice",
+            Self::TrustIndex => "trust index",
+        }
+    }
+
+    /// The outcome on this axis, oriented so that higher is better.
+    fn merit(self, outcome: &SimulationOutcome) -> f32 {
+        match self {
+            Self::NeurorightsRisk => -outcome.expected_neurorights_risk,
+            Self::EnvironmentalJustice => outcome.environmental_justice_score,
+            Self::TrustIndex => outcome.trust_index,
+        }
+    }
+}
+
+/// Candidate `by` dominates this one, strictly better on `axes`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Domination {
+    pub by: usize,
+    pub axes: Vec<OutcomeAxis>,
+}
+
+impl fmt::Display for Domination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let axes: Vec<&str> = self.axes.iter().map(|a| a.name()).collect();
+        write!(
+            f,
+            "dominated by candidate {} (better {}, no worse elsewhere)",
+            self.by,
+            axes.join(", ")
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RankedPolicy {
+    /// Position in the candidate slice.
+    pub index: usize,
+    pub outcome: SimulationOutcome,
+    /// Every candidate that dominates this one; empty on the Pareto front.
+    pub dominated_by: Vec<Domination>,
+}
+
+impl RankedPolicy {
+    pub fn is_pareto_optimal(&self) -> bool {
+        self.dominated_by.is_empty()
+    }
+}
+
+/// Result of `rank_policies`, in candidate order.
+#[derive(Clone, Debug)]
+pub struct PolicyRanking {
+    pub candidates: Vec<RankedPolicy>,
+}
+
+impl PolicyRanking {
+    /// Indices of the Pareto-optimal candidates.
+    pub fn front(&self) -> Vec<usize> {
+        self.candidates
+            .iter()
+            .filter(|c| c.is_pareto_optimal())
+            .map(|c| c.index)
+            .collect()
+    }
+}
+
+/// `rank_policies_with_epsilon` with `PARETO_EPSILON`.
+pub fn rank_policies<B>(
+    backend: &B,
+    candidates: &[SncPolicySnapshot],
+) -> Result<PolicyRanking, String>
+where
+    B: PolicySimulationBackend + ?Sized,
+{
+    rank_policies_with_epsilon(backend, candidates, PARETO_EPSILON)
+}
+
+/// Evaluate every candidate and mark the dominated ones. Fails on the
+/// first candidate the simulator cannot evaluate.
+pub fn rank_policies_with_epsilon<B>(
+    backend: &B,
+    candidates: &[SncPolicySnapshot],
+    epsilon: f32,
+) -> Result<PolicyRanking, String>
+where
+    B: PolicySimulationBackend + ?Sized,
+{
+    let outcomes = candidates
+        .iter()
+        .enumerate()
+        .map(|(i, c)| {
+            backend
+                .evaluate_policy(c)
+                .map_err(|e| format!("candidate {i}: {e}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let candidates = outcomes
+        .iter()
+        .enumerate()
+        .map(|(i, outcome)| RankedPolicy {
+            index: i,
+            outcome: outcome.clone(),
+            dominated_by: outcomes
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .filter_map(|(j, other)| {
+                    dominating_axes(other, outcome, epsilon).map(|axes| Domination { by: j, axes })
+                })
+                .collect(),
+        })
+        .collect();
+    Ok(PolicyRanking { candidates })
+}
+
+/// The axes on which `a` beats `b`, if `a` dominates `b`.
+fn dominating_axes(
+    a: &SimulationOutcome,
+    b: &SimulationOutcome,
+    epsilon: f32,
+) -> Option<Vec<OutcomeAxis>> {
+    let mut better = Vec::new();
+    for axis in OutcomeAxis::ALL {
+        let difference = axis.merit(a) - axis.merit(b);
+        if difference < -epsilon {
+            return None;
+        }
+        if difference > epsilon {
+            better.push(axis);
+        }
+    }
+    (!better.is_empty()).then_some(better)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reads the outcome straight off the snapshot: risk from
+    /// `chat_issuance_slope`, justice from `eco_weight`, trust from
+    /// `min_knowledge_factor_open`.
+    struct Echo;
+
+    impl PolicySimulationBackend for Echo {
+        fn evaluate_policy(&self, p: &SncPolicySnapshot) -> Result<SimulationOutcome, String> {
+            if p.eco_weight < 0.0 {
+                return Err("negative eco weight".into());
+            }
+            Ok(SimulationOutcome {
+                expected_neurorights_risk: p.chat_issuance_slope,
+                environmental_justice_score: p.eco_weight,
+                trust_index: p.min_knowledge_factor_open,
+            })
+        }
+    }
+
+    fn candidate(risk: f32, justice: f32, trust: f32) -> SncPolicySnapshot {
+        SncPolicySnapshot {
+            min_knowledge_factor_open: trust,
+            chat_issuance_slope: risk,
+            eco_weight: justice,
+        }
+    }
+
+    #[test]
+    fn dominated_candidates_name_their_dominator_and_axes() {
+        let ranking = rank_policies(
+            &Echo,
+            &[
+                candidate(0.2, 0.7, 0.6),
+                // Worse than 0 on risk and justice, tied on trust.
+                candidate(0.3, 0.6, 0.6),
+                // Trades justice for trust: not comparable with 0.
+                candidate(0.2, 0.6, 0.8),
+            ],
+        )
+        .unwrap();
+        assert_eq!(ranking.front(), [0, 2]);
+        assert!(ranking.candidates[0].is_pareto_optimal());
+        assert_eq!(
+            ranking.candidates[1].dominated_by,
+            [
+                Domination {
+                    by: 0,
+                    axes: vec![
+                        OutcomeAxis::NeurorightsRisk,
+                        OutcomeAxis::EnvironmentalJustice
+                    ],
+                },
+                Domination {
+                    by: 2,
+                    axes: vec![OutcomeAxis::NeurorightsRisk, OutcomeAxis::TrustIndex],
+                },
+            ]
+        );
+        assert_eq!(
+            ranking.candidates[1].dominated_by[0].to_string(),
+            "dominated by candidate 0 (better neurorights risk, environmental justice, no worse elsewhere)"
+        );
+    }
+
+    #[test]
+    fn ties_within_epsilon_do_not_dominate() {
+        let ranking = rank_policies(
+            &Echo,
+            &[candidate(0.2, 0.7, 0.6), candidate(0.2005, 0.6995, 0.6)],
+        )
+        .unwrap();
+        assert_eq!(ranking.front(), [0, 1]);
+
+        let strict = rank_policies_with_epsilon(
+            &Echo,
+            &[candidate(0.2, 0.7, 0.6), candidate(0.2005, 0.7, 0.6)],
+            0.0,
+        )
+        .unwrap();
+        assert_eq!(strict.front(), [0]);
+
+        let err = rank_policies(
+            &Echo,
+            &[candidate(0.2, 0.7, 0.6), candidate(0.2, -1.0, 0.6)],
+        );
+        assert_eq!(err.unwrap_err(), "candidate 1: negative eco weight");
+    }
+}
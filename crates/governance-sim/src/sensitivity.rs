@@ -0,0 +1,248 @@
+//! One-parameter sensitivity sweeps over `SncPolicySnapshot`.
+
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::{PolicySimulationBackend, SimulationOutcome, SncPolicySnapshot};
+
+/// A sweepable `SncPolicySnapshot` field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SnapshotParam {
+    MinKnowledgeFactorOpen,
+    ChatIssuanceSlope,
+    EcoWeight,
+}
+
+impl SnapshotParam {
+    pub const ALL: [SnapshotParam; 3] = [
+        SnapshotParam::MinKnowledgeFactorOpen,
+        SnapshotParam::ChatIssuanceSlope,
+        SnapshotParam::EcoWeight,
+    ];
+
+    /// The snapshot field name, as accepted by `from_str`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::MinKnowledgeFactorOpen => "min_knowledge_factor_open",
+            Self::ChatIssuanceSlope => "chat_issuance_slope",
+            Self::EcoWeight => "eco_weight",
+        }
+    }
+
+    pub fn get(self, snapshot: &SncPolicySnapshot) -> f32 {
+        match self {
+            Self::MinKnowledgeFactorOpen => snapshot.min_knowledge_factor_open,
+            Self::ChatIssuanceSlope => snapshot.chat_issuance_slope,
+            Self::EcoWeight => snapshot.eco_weight,
+        }
+    }
+
+    pub fn set(self, snapshot: &mut SncPolicySnapshot, value: f32) {
+        match self {
+            Self::MinKnowledgeFactorOpen => snapshot.min_knowledge_factor_open = value,
+            Self::ChatIssuanceSlope => snapshot.chat_issuance_slope = value,
+            Self::EcoWeight => snapshot.eco_weight = value,
+        }
+    }
+}
+
+impl fmt::Display for SnapshotParam {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for SnapshotParam {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        Self::ALL
+            .into_iter()
+            .find(|p| p.name() == s)
+            .ok_or_else(|| format!("unknown snapshot parameter {s:?}"))
+    }
+}
+
+/// Mean finite-difference slope of each outcome per unit of the swept
+/// parameter, over consecutive successful steps.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutcomeGradient {
+    pub expected_neurorights_risk: f32,
+    pub environmental_justice_score: f32,
+    pub trust_index: f32,
+}
+
+impl OutcomeGradient {
+    /// The outcome field with the steepest mean slope, by magnitude.
+    pub fn steepest(&self) -> (&'static str, f32) {
+        [
+            ("expected_neurorights_risk", self.expected_neurorights_risk),
+            (
+                "environmental_justice_score",
+                self.environmental_justice_score,
+            ),
+            ("trust_index", self.trust_index),
+        ]
+        .into_iter()
+        .fold(("expected_neurorights_risk", 0.0), |best, field| {
+            if field.1.abs() > best.1.abs() {
+                field
+            } else {
+                best
+            }
+        })
+    }
+}
+
+/// Result of `sensitivity_sweep`.
+#[derive(Clone, Debug)]
+pub struct SensitivitySweep {
+    pub param: SnapshotParam,
+    /// Successful steps in sweep order.
+    pub points: Vec<(f32, SimulationOutcome)>,
+    /// Steps the simulator failed, with its error.
+    pub failures: Vec<(f32, String)>,
+    /// `None` with fewer than two successful steps.
+    pub gradient: Option<OutcomeGradient>,
+}
+
+/// Evaluate `base` with `param` set to `steps` evenly spaced values across
+/// `range`, ends included (one step evaluates the start). A failed step is
+/// recorded in `failures` and the sweep continues. Fails only on zero steps
+/// or a non-finite range.
+pub fn sensitivity_sweep<B>(
+    backend: &B,
+    base: &SncPolicySnapshot,
+    param: SnapshotParam,
+    range: RangeInclusive<f32>,
+    steps: usize,
+) -> Result<SensitivitySweep, String>
+where
+    B: PolicySimulationBackend + ?Sized,
+{
+    let (start, end) = range.into_inner();
+    if steps == 0 {
+        return Err("a sweep needs at least one step".into());
+    }
+    if !start.is_finite() || !end.is_finite() {
+        return Err(format!("non-finite sweep range {start}..={end}"));
+    }
+
+    let mut points = Vec::new();
+    let mut failures = Vec::new();
+    for i in 0..steps {
+        let value = if steps == 1 {
+            start
+        } else {
+            start + (end - start) * i as f32 / (steps - 1) as f32
+        };
+        let mut snapshot = base.clone();
+        param.set(&mut snapshot, value);
+        match backend.evaluate_policy(&snapshot) {
+            Ok(outcome) => points.push((value, outcome)),
+            Err(e) => failures.push((value, e)),
+        }
+    }
+
+    let gradient = (points.len() >= 2).then(|| {
+        let pairs = points.windows(2).filter(|w| w[1].0 != w[0].0);
+        let n = pairs.clone().count().max(1) as f32;
+        let mean_slope = |get: fn(&SimulationOutcome) -> f32| {
+            pairs
+                .clone()
+                .map(|w| (get(&w[1].1) - get(&w[0].1)) / (w[1].0 - w[0].0))
+                .sum::<f32>()
+                / n
+        };
+        OutcomeGradient {
+            expected_neurorights_risk: mean_slope(|o| o.expected_neurorights_risk),
+            environmental_justice_score: mean_slope(|o| o.environmental_justice_score),
+            trust_index: mean_slope(|o| o.trust_index),
+        }
+    });
+
+    Ok(SensitivitySweep {
+        param,
+        points,
+        failures,
+        gradient,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LinearPolicySimulator;
+
+    fn base() -> SncPolicySnapshot {
+        SncPolicySnapshot {
+            min_knowledge_factor_open: 0.8,
+            chat_issuance_slope: 1.0,
+            eco_weight: 0.4,
+        }
+    }
+
+    #[test]
+    fn linear_sweep_recovers_the_coefficients() {
+        let sweep = sensitivity_sweep(
+            &LinearPolicySimulator::default(),
+            &base(),
+            SnapshotParam::ChatIssuanceSlope,
+            0.5..=2.5,
+            5,
+        )
+        .unwrap();
+        let values: Vec<f32> = sweep.points.iter().map(|(v, _)| *v).collect();
+        assert_eq!(values, [0.5, 1.0, 1.5, 2.0, 2.5]);
+        assert!(sweep.failures.is_empty());
+
+        let gradient = sweep.gradient.unwrap();
+        assert!((gradient.expected_neurorights_risk - 0.2).abs() < 1e-5);
+        assert!(gradient.environmental_justice_score.abs() < 1e-6);
+        assert!((gradient.trust_index + 0.1).abs() < 1e-5);
+        assert_eq!(gradient.steepest().0, "expected_neurorights_risk");
+    }
+
+    #[test]
+    fn failed_steps_do_not_abort_the_sweep() {
+        /// Refuses eco weights above 0.5.
+        struct Picky;
+
+        impl PolicySimulationBackend for Picky {
+            fn evaluate_policy(&self, p: &SncPolicySnapshot) -> Result<SimulationOutcome, String> {
+                if p.eco_weight > 0.5 {
+                    return Err("out of calibrated range".into());
+                }
+                LinearPolicySimulator::default().evaluate_policy(p)
+            }
+        }
+
+        let sweep =
+            sensitivity_sweep(&Picky, &base(), SnapshotParam::EcoWeight, 0.0..=1.0, 3).unwrap();
+        assert_eq!(sweep.points.len(), 2);
+        assert_eq!(
+            sweep.failures,
+            [(1.0, "out of calibrated range".to_string())]
+        );
+        let gradient = sweep.gradient.unwrap();
+        assert!((gradient.environmental_justice_score - 0.5).abs() < 1e-5);
+
+        let single =
+            sensitivity_sweep(&Picky, &base(), SnapshotParam::EcoWeight, 0.2..=1.0, 1).unwrap();
+        assert_eq!(single.points.len(), 1);
+        assert!(single.gradient.is_none());
+        assert!(
+            sensitivity_sweep(&Picky, &base(), SnapshotParam::EcoWeight, 0.0..=1.0, 0).is_err()
+        );
+    }
+
+    #[test]
+    fn params_parse_by_field_name() {
+        for param in SnapshotParam::ALL {
+            assert_eq!(param.name().parse(), Ok(param));
+        }
+        assert!("eco".parse::<SnapshotParam>().is_err());
+    }
+}
@@ -11,19 +11,29 @@
 //! defined in configuration.[file:3][file:4]
 
 use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "config-files")]
+use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
-use crate::state::{SiteId, SiteState, TerritoryScale, WorldSnapshot};
-use crate::deeds::{ProposedDeed, DeedEffect};
-use crate::ethics::EthicsDecision;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-/// Fixed Neuromorph‑GOD / Tree‑of‑Life limits.[file:2][file:4]
-pub const ROH_MAX: f64 = 0.3;
+use core_contract::roh::{CapabilityState, RoH};
+
+use crate::state::{SiteId, TerritoryScale};
+
+/// Fixed Neuromorph‑GOD / Tree‑of‑Life limits. `ROH_MAX` is the shared
+/// `ControlledHuman` ceiling and the scale `project` normalizes RoH by in
+/// every tier; the ceiling the world sweep enforces follows
+/// `BioRailConfig::capability_state`.[file:2][file:4]
+pub const ROH_MAX: f64 = RoH::<f64>::ceiling_for(CapabilityState::ControlledHuman);
 pub const DECAY_MAX: f64 = 1.0;
 
 /// Per‑zone corridor for the scalar rail b.[file:3]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BioRailZone {
     pub id: ZoneTag,
     /// Lower and upper bounds for b in this zone (inclusive).
@@ -33,6 +43,7 @@ pub struct BioRailZone {
 
 /// Simple tag for substrate / anatomical context.[file:4]
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ZoneTag {
     NeuralBand,
     VascularConduit,
@@ -42,20 +53,197 @@ pub enum ZoneTag {
 }
 
 /// Territorial bioload ceilings for the Terrasafe guard.[file:4]
+///
+/// The three fields are the original three-scale form, kept so existing
+/// configs load unchanged. Deployments with intermediate scales add a
+/// `scales` table; `BioRailGate` turns either into `ScaledCeilings` via
+/// `scaled()`.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TerrasafeCeilings {
     pub body_max: f64,
     pub room_max: f64,
     pub grid_max: f64,
+    /// Ceilings for every scale in an ordered hierarchy; when set they
+    /// replace the three fields above.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub scales: Option<ScaleCeilingTable>,
+}
+
+/// `[terrasafe.scales]`: the scales a deployment uses, smallest first, and
+/// a ceiling for each.[file:4]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScaleCeilingTable {
+    pub hierarchy: Vec<TerritoryScale>,
+    pub ceilings: HashMap<TerritoryScale, f64>,
 }
 
 /// Configuration bundle for the guard.[file:4]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BioRailConfig {
     pub zones: Vec<BioRailZone>,
     pub terrasafe: TerrasafeCeilings,
     /// POWER ≤ k·CHURCH multiplier.
     pub power_church_k: f64,
+    /// Capability tier the sites run in; selects the RoH ceiling. Configs
+    /// without it load as `ControlledHuman`.
+    #[cfg_attr(feature = "serde", serde(default = "controlled_human"))]
+    pub capability_state: CapabilityState,
+}
+
+#[cfg(feature = "serde")]
+fn controlled_human() -> CapabilityState {
+    CapabilityState::ControlledHuman
+}
+
+/// One reason a `BioRailConfig` is unusable, naming the zone or field.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BioRailConfigViolation {
+    /// The zone tag has more than one corridor.
+    DuplicateZone(ZoneTag),
+    /// `b_min > b_max`, or either bound is NaN.
+    InvertedCorridor { zone: ZoneTag, b_min: f64, b_max: f64 },
+    /// A Terrasafe ceiling is zero, negative or NaN.
+    NonPositiveCeiling { field: &'static str, value: f64 },
+    /// A smaller territory has a higher ceiling than a larger one.
+    CeilingOrder { lower: &'static str, lower_max: f64, upper: &'static str, upper_max: f64 },
+    /// `terrasafe.scales` does not give exactly one ceiling per hierarchy scale.
+    ScaleTable(CeilingConfigError),
+    /// `power_church_k` is negative or NaN.
+    NegativePowerChurchK(f64),
+}
+
+impl std::fmt::Display for BioRailConfigViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DuplicateZone(zone) => write!(f, "zone {zone:?} is configured more than once"),
+            Self::InvertedCorridor { zone, b_min, b_max } => write!(f, "zone {zone:?} has b_min {b_min} above b_max {b_max}"),
+            Self::NonPositiveCeiling { field, value } => write!(f, "terrasafe.{field} = {value} is not positive"),
+            Self::CeilingOrder { lower, lower_max, upper, upper_max } => {
+                write!(f, "terrasafe.{lower} = {lower_max} is above terrasafe.{upper} = {upper_max}")
+            }
+            Self::ScaleTable(e) => write!(f, "terrasafe.scales: {e}"),
+            Self::NegativePowerChurchK(k) => write!(f, "power_church_k = {k} is negative"),
+        }
+    }
+}
+
+/// Why a `BioRailConfig` could not be loaded.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BioRailConfigError {
+    /// The file could not be read.
+    Read(String),
+    /// The text is not valid TOML for a `BioRailConfig`.
+    Parse(String),
+    /// Every violation found, zones first, then ceilings, then k.
+    Invalid(Vec<BioRailConfigViolation>),
+}
+
+impl std::fmt::Display for BioRailConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read(e) | Self::Parse(e) => f.write_str(e),
+            Self::Invalid(violations) => {
+                f.write_str("invalid biorail config:")?;
+                for (i, violation) in violations.iter().enumerate() {
+                    write!(f, "{} {violation}", if i == 0 { "" } else { ";" })?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for BioRailConfigError {}
+
+impl BioRailConfig {
+    /// Reads and validates a TOML config; see
+    /// `tests/fixtures/biorail_config.toml` for the layout.
+    #[cfg(feature = "config-files")]
+    pub fn from_toml_path(path: &Path) -> Result<Self, BioRailConfigError> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| BioRailConfigError::Read(format!("Cannot read biorail config {}: {e}", path.display())))?;
+        Self::from_toml_str(&text)
+    }
+
+    #[cfg(feature = "config-files")]
+    pub fn from_toml_str(text: &str) -> Result<Self, BioRailConfigError> {
+        let cfg: Self = toml::from_str(text).map_err(|e| BioRailConfigError::Parse(format!("Invalid TOML: {e}")))?;
+        cfg.validate()?;
+        Ok(cfg)
+    }
+
+    /// Checks each zone tag appears at most once with `b_min ≤ b_max`,
+    /// ceilings are positive with body ≤ room ≤ grid, a `scales` table
+    /// builds a `ScaledCeilings` whose ceilings are positive and
+    /// non-decreasing up the hierarchy, and `power_church_k ≥ 0`,
+    /// reporting every violation.
+    pub fn validate(&self) -> Result<(), BioRailConfigError> {
+        let mut violations = Vec::new();
+        let mut seen = HashSet::new();
+        for zone in &self.zones {
+            if !seen.insert(&zone.id) {
+                violations.push(BioRailConfigViolation::DuplicateZone(zone.id.clone()));
+            }
+            if !matches!(zone.b_min.partial_cmp(&zone.b_max), Some(Ordering::Less | Ordering::Equal)) {
+                violations.push(BioRailConfigViolation::InvertedCorridor { zone: zone.id.clone(), b_min: zone.b_min, b_max: zone.b_max });
+            }
+        }
+
+        let t = &self.terrasafe;
+        check_ceilings(&[("body_max", t.body_max), ("room_max", t.room_max), ("grid_max", t.grid_max)], &mut violations);
+        if let Some(table) = &t.scales {
+            match ScaledCeilings::new(table.hierarchy.clone(), table.ceilings.clone()) {
+                Ok(scaled) => {
+                    let ceilings: Vec<_> = scaled
+                        .hierarchy()
+                        .iter()
+                        .map(|scale| (scale_field(scale), scaled.ceiling(scale).unwrap_or(f64::NAN)))
+                        .collect();
+                    check_ceilings(&ceilings, &mut violations);
+                }
+                Err(e) => violations.push(BioRailConfigViolation::ScaleTable(e)),
+            }
+        }
+
+        if !matches!(self.power_church_k.partial_cmp(&0.0), Some(Ordering::Greater | Ordering::Equal)) {
+            violations.push(BioRailConfigViolation::NegativePowerChurchK(self.power_church_k));
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(BioRailConfigError::Invalid(violations))
+        }
+    }
+}
+
+/// Ceilings must be positive and, smallest territory first, non-decreasing.
+fn check_ceilings(ceilings: &[(&'static str, f64)], violations: &mut Vec<BioRailConfigViolation>) {
+    for &(field, value) in ceilings {
+        if !matches!(value.partial_cmp(&0.0), Some(Ordering::Greater)) {
+            violations.push(BioRailConfigViolation::NonPositiveCeiling { field, value });
+        }
+    }
+    for pair in ceilings.windows(2) {
+        let ((lower, lower_max), (upper, upper_max)) = (pair[0], pair[1]);
+        if lower_max > upper_max {
+            violations.push(BioRailConfigViolation::CeilingOrder { lower, lower_max, upper, upper_max });
+        }
+    }
+}
+
+/// Name of a `terrasafe.scales` ceiling in violations.
+fn scale_field(scale: &TerritoryScale) -> &'static str {
+    match scale {
+        TerritoryScale::Body => "scales.Body",
+        TerritoryScale::Room => "scales.Room",
+        TerritoryScale::Building => "scales.Building",
+        TerritoryScale::District => "scales.District",
+        TerritoryScale::Grid => "scales.Grid",
+    }
 }
 
 /// Fast view of the 5D identity used for projection.[file:4]
@@ -72,6 +260,9 @@ pub struct Identity5D {
     pub context_territorial_load: f64,
     pub context_pollution: f64,
     pub sovereignty_trust: f64,
+    /// `false` withdraws consent to actuation. Unlike `sovereignty_trust` it
+    /// is not folded into b: the terrasafe gate blocks every change outright
+    /// (`GateConstraint::ConsentWithdrawn`), and `clamped` passes it through.[file:4]
     pub sovereignty_consent: bool,
 }
 
@@ -79,7 +270,7 @@ impl Identity5D {
     /// Clamp all inputs into [0,1] where applicable.[file:4]
     pub fn clamped(self) -> Self {
         fn c(x: f64) -> f64 {
-            if x.is_nan() { 0.0 } else { x.max(0.0).min(1.0) }
+            if x.is_nan() { 0.0 } else { x.clamp(0.0, 1.0) }
         }
         Self {
             biostate_fatigue: c(self.biostate_fatigue),
@@ -97,3 +288,1231 @@ impl Identity5D {
         }
     }
 }
+
+/// A rejected `Identity5DBuilder` input, naming the field.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Identity5DFieldError {
+    NotANumber { field: &'static str },
+    Negative { field: &'static str, value: f64 },
+}
+
+impl std::fmt::Display for Identity5DFieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotANumber { field } => write!(f, "{field} is NaN"),
+            Self::Negative { field, value } => write!(f, "{field} = {value} is negative"),
+        }
+    }
+}
+
+impl std::error::Error for Identity5DFieldError {}
+
+/// Output of `Identity5DBuilder::build`.
+#[derive(Clone, Debug)]
+pub struct BuiltIdentity {
+    /// Every field clamped into its raw range; `roh_slice` and `decay` stay
+    /// raw, so `project` normalizes them exactly once.
+    pub identity: Identity5D,
+    /// Fields never set, holding their default, in declaration order.
+    pub defaulted: Vec<&'static str>,
+}
+
+/// Field-by-field construction of an `Identity5D` with units spelled out.[file:4]
+///
+/// Setters reject NaN and negative values. An unset field takes its
+/// riskiest value (risk components 1, lifeforce level 0, RoH `ROH_MAX`,
+/// decay `DECAY_MAX`, trust 0, consent withdrawn), so a forgotten input can
+/// only tighten the rail; `BuiltIdentity::defaulted` lists them.
+#[derive(Clone, Debug, Default)]
+pub struct Identity5DBuilder {
+    biostate_fatigue: Option<f64>,
+    biostate_inflammation: Option<f64>,
+    neurostate_fear: Option<f64>,
+    neurostate_stimulation: Option<f64>,
+    lifeforce_level: Option<f64>,
+    lifeforce_drain: Option<f64>,
+    roh_slice: Option<f64>,
+    decay: Option<f64>,
+    context_territorial_load: Option<f64>,
+    context_pollution: Option<f64>,
+    sovereignty_trust: Option<f64>,
+    sovereignty_consent: Option<bool>,
+}
+
+fn checked(field: &'static str, value: f64) -> Result<Option<f64>, Identity5DFieldError> {
+    if value.is_nan() {
+        Err(Identity5DFieldError::NotANumber { field })
+    } else if value < 0.0 {
+        Err(Identity5DFieldError::Negative { field, value })
+    } else {
+        Ok(Some(value))
+    }
+}
+
+impl Identity5DBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fatigue fraction in [0,1].
+    pub fn biostate_fatigue(mut self, fraction: f64) -> Result<Self, Identity5DFieldError> {
+        self.biostate_fatigue = checked("biostate_fatigue", fraction)?;
+        Ok(self)
+    }
+
+    /// Inflammation fraction in [0,1].
+    pub fn biostate_inflammation(mut self, fraction: f64) -> Result<Self, Identity5DFieldError> {
+        self.biostate_inflammation = checked("biostate_inflammation", fraction)?;
+        Ok(self)
+    }
+
+    /// Fear fraction in [0,1].
+    pub fn neurostate_fear(mut self, fraction: f64) -> Result<Self, Identity5DFieldError> {
+        self.neurostate_fear = checked("neurostate_fear", fraction)?;
+        Ok(self)
+    }
+
+    /// Stimulation fraction in [0,1].
+    pub fn neurostate_stimulation(mut self, fraction: f64) -> Result<Self, Identity5DFieldError> {
+        self.neurostate_stimulation = checked("neurostate_stimulation", fraction)?;
+        Ok(self)
+    }
+
+    /// Lifeforce level in [0,1]; higher is healthier.
+    pub fn lifeforce_level(mut self, fraction: f64) -> Result<Self, Identity5DFieldError> {
+        self.lifeforce_level = checked("lifeforce_level", fraction)?;
+        Ok(self)
+    }
+
+    /// Lifeforce drain fraction in [0,1].
+    pub fn lifeforce_drain(mut self, fraction: f64) -> Result<Self, Identity5DFieldError> {
+        self.lifeforce_drain = checked("lifeforce_drain", fraction)?;
+        Ok(self)
+    }
+
+    /// Raw RoH on the envelope scale, where `ROH_MAX` (0.3) is the ceiling.
+    /// Do not pre-divide by `ROH_MAX`; use `roh_normalized` for a value
+    /// already on the [0,1] rail scale.
+    pub fn roh_raw(mut self, roh: f64) -> Result<Self, Identity5DFieldError> {
+        self.roh_slice = checked("roh_slice", roh)?;
+        Ok(self)
+    }
+
+    /// RoH as a fraction of `ROH_MAX`; stored raw as `fraction · ROH_MAX`.
+    pub fn roh_normalized(mut self, fraction: f64) -> Result<Self, Identity5DFieldError> {
+        self.roh_slice = checked("roh_slice", fraction)?.map(|f| f * ROH_MAX);
+        Ok(self)
+    }
+
+    /// Raw decay on the envelope scale, where `DECAY_MAX` is the ceiling.
+    pub fn decay(mut self, decay: f64) -> Result<Self, Identity5DFieldError> {
+        self.decay = checked("decay", decay)?;
+        Ok(self)
+    }
+
+    /// Territorial load fraction in [0,1].
+    pub fn context_territorial_load(mut self, fraction: f64) -> Result<Self, Identity5DFieldError> {
+        self.context_territorial_load = checked("context_territorial_load", fraction)?;
+        Ok(self)
+    }
+
+    /// Pollution fraction in [0,1].
+    pub fn context_pollution(mut self, fraction: f64) -> Result<Self, Identity5DFieldError> {
+        self.context_pollution = checked("context_pollution", fraction)?;
+        Ok(self)
+    }
+
+    /// Sovereignty trust in [0,1]; higher lowers b.
+    pub fn sovereignty_trust(mut self, fraction: f64) -> Result<Self, Identity5DFieldError> {
+        self.sovereignty_trust = checked("sovereignty_trust", fraction)?;
+        Ok(self)
+    }
+
+    pub fn sovereignty_consent(mut self, given: bool) -> Self {
+        self.sovereignty_consent = Some(given);
+        self
+    }
+
+    pub fn build(self) -> BuiltIdentity {
+        let mut defaulted = Vec::new();
+        let mut take = |field: &'static str, value: Option<f64>, default: f64, max: f64| {
+            value.unwrap_or_else(|| {
+                defaulted.push(field);
+                default
+            }).min(max)
+        };
+        let identity = Identity5D {
+            biostate_fatigue: take("biostate_fatigue", self.biostate_fatigue, 1.0, 1.0),
+            biostate_inflammation: take("biostate_inflammation", self.biostate_inflammation, 1.0, 1.0),
+            neurostate_fear: take("neurostate_fear", self.neurostate_fear, 1.0, 1.0),
+            neurostate_stimulation: take("neurostate_stimulation", self.neurostate_stimulation, 1.0, 1.0),
+            lifeforce_level: take("lifeforce_level", self.lifeforce_level, 0.0, 1.0),
+            lifeforce_drain: take("lifeforce_drain", self.lifeforce_drain, 1.0, 1.0),
+            roh_slice: take("roh_slice", self.roh_slice, ROH_MAX, ROH_MAX),
+            decay: take("decay", self.decay, DECAY_MAX, DECAY_MAX),
+            context_territorial_load: take("context_territorial_load", self.context_territorial_load, 1.0, 1.0),
+            context_pollution: take("context_pollution", self.context_pollution, 1.0, 1.0),
+            sovereignty_trust: take("sovereignty_trust", self.sovereignty_trust, 0.0, 1.0),
+            sovereignty_consent: false,
+        };
+        let identity = match self.sovereignty_consent {
+            Some(given) => Identity5D { sovereignty_consent: given, ..identity },
+            None => {
+                defaulted.push("sovereignty_consent");
+                identity
+            }
+        };
+        BuiltIdentity { identity, defaulted }
+    }
+}
+
+/// Position on the scalar rail, always in [0,1]; NaN maps to 0.[file:4]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct RailScalar(f64);
+
+impl RailScalar {
+    pub fn new_clamped(x: f64) -> Self {
+        RailScalar(if x.is_nan() { 0.0 } else { x.clamp(0.0, 1.0) })
+    }
+
+    pub fn value(self) -> f64 {
+        self.0
+    }
+}
+
+/// Weights of the monotone projection b = clamp(Σ risk weight · risk −
+/// sovereign_relief · trust).[file:4][file:3]
+///
+/// Paired sub-components (fatigue and inflammation, fear and stimulation,
+/// territorial load and pollution, 1 − lifeforce level and drain) are
+/// averaged into one risk each, so with the default weights this is the
+/// projection microsociety's `compute_biosignature` uses; both are checked
+/// against microsociety/tests/fixtures/projection_vectors.csv.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProjectionWeights {
+    pub biostate: f64,
+    pub neurostate: f64,
+    pub context: f64,
+    /// Applied to RoH normalized by `ROH_MAX`.[file:2]
+    pub roh: f64,
+    pub decay: f64,
+    pub lifeforce_risk: f64,
+    /// Subtracted per unit of sovereignty trust.[file:4]
+    pub sovereign_relief: f64,
+}
+
+impl ProjectionWeights {
+    pub const DEFAULT: ProjectionWeights = ProjectionWeights {
+        biostate: 0.18,
+        neurostate: 0.18,
+        context: 0.18,
+        roh: 0.18,
+        decay: 0.18,
+        lifeforce_risk: 0.10,
+        sovereign_relief: 0.4,
+    };
+}
+
+impl Default for ProjectionWeights {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Identity5D {
+    /// Projects the identity onto the rail.[file:4]
+    ///
+    /// With non-negative weights, raising any risk component never lowers b
+    /// and raising trust never raises it. Withdrawn consent saturates b at
+    /// 1.0: no trust or calm reading can make actuation look safe without it.
+    pub fn project(&self, weights: &ProjectionWeights) -> RailScalar {
+        if !self.sovereignty_consent {
+            return RailScalar::new_clamped(1.0);
+        }
+        let id = self.clone().clamped();
+        let w = weights;
+        let biostate = 0.5 * (id.biostate_fatigue + id.biostate_inflammation);
+        let neurostate = 0.5 * (id.neurostate_fear + id.neurostate_stimulation);
+        let context = 0.5 * (id.context_territorial_load + id.context_pollution);
+        let lifeforce_risk = 0.5 * ((1.0 - id.lifeforce_level) + id.lifeforce_drain);
+
+        // Same summation order as microsociety, so shared vectors agree bit for bit.
+        let risk_sum =
+            w.biostate * biostate +
+            w.neurostate * neurostate +
+            w.context * context +
+            w.roh * id.roh_slice +
+            w.decay * id.decay +
+            w.lifeforce_risk * lifeforce_risk;
+        RailScalar::new_clamped(risk_sum - w.sovereign_relief * id.sovereignty_trust)
+    }
+}
+
+impl TerrasafeCeilings {
+    /// The ceilings keyed by `TerritoryScale`: the `scales` table when set,
+    /// otherwise the three fields with hierarchy body < room < grid. A
+    /// table `ScaledCeilings::new` rejects yields no ceilings at all, so
+    /// every load is refused as `UnconfiguredScale`; `validate` names the
+    /// problem.
+    pub fn scaled(&self) -> ScaledCeilings {
+        if let Some(table) = &self.scales {
+            return ScaledCeilings::new(table.hierarchy.clone(), table.ceilings.clone())
+                .unwrap_or_else(|_| ScaledCeilings { hierarchy: Vec::new(), ceilings: HashMap::new() });
+        }
+        ScaledCeilings {
+            hierarchy: vec![TerritoryScale::Body, TerritoryScale::Room, TerritoryScale::Grid],
+            ceilings: HashMap::from([
+                (TerritoryScale::Body, self.body_max),
+                (TerritoryScale::Room, self.room_max),
+                (TerritoryScale::Grid, self.grid_max),
+            ]),
+        }
+    }
+}
+
+/// Bioload ceilings for every territory scale a deployment uses, from the
+/// smallest scale to the largest.[file:4]
+///
+/// Built through `new`, which rejects a hierarchy scale without a ceiling,
+/// so a misconfigured scale fails when the config is loaded rather than
+/// passing every check.
+#[derive(Clone, Debug)]
+pub struct ScaledCeilings {
+    hierarchy: Vec<TerritoryScale>,
+    ceilings: HashMap<TerritoryScale, f64>,
+}
+
+/// Why a `ScaledCeilings` configuration is unusable.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CeilingConfigError {
+    /// A scale in the hierarchy has no ceiling.
+    MissingCeiling(TerritoryScale),
+    /// A ceiling is given for a scale outside the hierarchy.
+    UnorderedScale(TerritoryScale),
+    /// A scale appears twice in the hierarchy.
+    DuplicateScale(TerritoryScale),
+}
+
+impl std::fmt::Display for CeilingConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingCeiling(scale) => write!(f, "no bioload ceiling for territory scale {scale:?}"),
+            Self::UnorderedScale(scale) => write!(f, "territory scale {scale:?} has a ceiling but is not in the hierarchy"),
+            Self::DuplicateScale(scale) => write!(f, "territory scale {scale:?} appears twice in the hierarchy"),
+        }
+    }
+}
+
+impl std::error::Error for CeilingConfigError {}
+
+impl ScaledCeilings {
+    /// `hierarchy` lists the scales smallest first; every one needs a
+    /// ceiling and every ceiling needs a place in it.
+    pub fn new(hierarchy: Vec<TerritoryScale>, ceilings: HashMap<TerritoryScale, f64>) -> Result<Self, CeilingConfigError> {
+        let mut seen = HashSet::new();
+        for scale in &hierarchy {
+            if !seen.insert(scale) {
+                return Err(CeilingConfigError::DuplicateScale(scale.clone()));
+            }
+            if !ceilings.contains_key(scale) {
+                return Err(CeilingConfigError::MissingCeiling(scale.clone()));
+            }
+        }
+        if let Some(extra) = ceilings.keys().find(|scale| !seen.contains(scale)) {
+            return Err(CeilingConfigError::UnorderedScale(extra.clone()));
+        }
+        Ok(Self { hierarchy, ceilings })
+    }
+
+    /// Configured scales, smallest first.
+    pub fn hierarchy(&self) -> &[TerritoryScale] {
+        &self.hierarchy
+    }
+
+    pub fn ceiling(&self, scale: &TerritoryScale) -> Option<f64> {
+        self.ceilings.get(scale).copied()
+    }
+}
+
+/// Current territorial loads checked against `TerrasafeCeilings`; the
+/// three-scale form of `ScaledLoad`.[file:4]
+#[derive(Clone, Debug)]
+pub struct TerritoryLoad {
+    pub body: f64,
+    pub room: f64,
+    pub grid: f64,
+}
+
+/// Current bioload at each territory scale a site reports.[file:4]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScaledLoad(pub HashMap<TerritoryScale, f64>);
+
+impl From<&TerritoryLoad> for ScaledLoad {
+    fn from(load: &TerritoryLoad) -> Self {
+        ScaledLoad(HashMap::from([
+            (TerritoryScale::Body, load.body),
+            (TerritoryScale::Room, load.room),
+            (TerritoryScale::Grid, load.grid),
+        ]))
+    }
+}
+
+/// Why `BioRailGate::check` refused.[file:4]
+#[derive(Clone, Debug, PartialEq)]
+pub enum RailRefusal {
+    /// `sovereignty_consent` is false.
+    ConsentWithdrawn,
+    /// No corridor is configured for the zone.
+    UnknownZone(ZoneTag),
+    /// A load is reported at a scale with no configured ceiling.
+    UnconfiguredScale(TerritoryScale),
+    /// A territory scale is over its Terrasafe ceiling.
+    BioloadCeiling { scale: TerritoryScale, load: f64, max: f64 },
+    /// The projected b is outside the zone corridor.
+    CorridorExit { b: RailScalar, b_min: f64, b_max: f64 },
+}
+
+/// Scalar gate: projected b within the zone corridor and every territory
+/// scale within its Terrasafe ceiling.[file:4][file:3]
+#[derive(Clone, Debug)]
+pub struct BioRailGate {
+    pub config: BioRailConfig,
+    pub weights: ProjectionWeights,
+    ceilings: ScaledCeilings,
+}
+
+impl BioRailGate {
+    /// Gate with the ceilings of `config.terrasafe`.
+    pub fn new(config: BioRailConfig, weights: ProjectionWeights) -> Self {
+        let ceilings = config.terrasafe.scaled();
+        Self { config, weights, ceilings }
+    }
+
+    /// Replaces the three-scale ceilings with `ceilings`.
+    pub fn with_ceilings(mut self, ceilings: ScaledCeilings) -> Self {
+        self.ceilings = ceilings;
+        self
+    }
+
+    pub fn ceilings(&self) -> &ScaledCeilings {
+        &self.ceilings
+    }
+
+    /// Corridor of `zone`; the first matching entry wins.
+    pub fn zone(&self, zone: &ZoneTag) -> Option<&BioRailZone> {
+        self.config.zones.iter().find(|z| &z.id == zone)
+    }
+
+    /// `check_scaled` with the three-scale load.
+    pub fn check(&self, zone: &ZoneTag, identity: &Identity5D, load: &TerritoryLoad) -> Result<RailScalar, RailRefusal> {
+        self.check_scaled(zone, identity, &ScaledLoad::from(load))
+    }
+
+    /// Projected b if the identity may be actuated in `zone`, else the
+    /// first refusal in order: consent, zone, a load at an unconfigured
+    /// scale, ceilings smallest scale first, corridor. Scales with no
+    /// reported load are not checked.
+    pub fn check_scaled(&self, zone: &ZoneTag, identity: &Identity5D, load: &ScaledLoad) -> Result<RailScalar, RailRefusal> {
+        if !identity.sovereignty_consent {
+            return Err(RailRefusal::ConsentWithdrawn);
+        }
+        let corridor = self.zone(zone).ok_or_else(|| RailRefusal::UnknownZone(zone.clone()))?;
+
+        if let Some(scale) = load.0.keys().find(|scale| self.ceilings.ceiling(scale).is_none()) {
+            return Err(RailRefusal::UnconfiguredScale(scale.clone()));
+        }
+        for scale in self.ceilings.hierarchy() {
+            let (Some(&load), Some(max)) = (load.0.get(scale), self.ceilings.ceiling(scale)) else { continue };
+            // NaN loads or ceilings count as over.
+            if matches!(load.partial_cmp(&max), Some(Ordering::Greater) | None) {
+                return Err(RailRefusal::BioloadCeiling { scale: scale.clone(), load, max });
+            }
+        }
+
+        let b = identity.project(&self.weights);
+        if b.value() < corridor.b_min || b.value() > corridor.b_max {
+            return Err(RailRefusal::CorridorExit { b, b_min: corridor.b_min, b_max: corridor.b_max });
+        }
+        Ok(b)
+    }
+}
+
+/// What the world invariants read from one site, cloned out of the
+/// snapshot so the sweep runs without the lock held.[file:4]
+#[derive(Clone, Debug)]
+pub struct SiteInvariantView {
+    pub site_id: SiteId,
+    pub zone: ZoneTag,
+    pub roh: f64,
+    pub power: f64,
+    pub church: f64,
+    pub load: ScaledLoad,
+}
+
+/// World state the invariant checker can sweep.
+pub trait WorldInvariantSource {
+    /// One view per site, in a stable order.
+    fn invariant_views(&self) -> Vec<SiteInvariantView>;
+}
+
+/// A site-level invariant that does not hold.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SiteInvariant {
+    /// RoH over the configured tier's ceiling (NaN counts as over).
+    RohCeiling { roh: f64 },
+    /// A load at a scale with no configured ceiling.
+    UnconfiguredScale(TerritoryScale),
+    /// A territory scale over its ceiling (NaN counts as over).
+    BioloadCeiling { scale: TerritoryScale, load: f64, max: f64 },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SiteViolation {
+    pub site_id: SiteId,
+    pub invariant: SiteInvariant,
+}
+
+/// Summed POWER and CHURCH over every site in one zone corridor.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CorridorBalance {
+    pub zone: ZoneTag,
+    pub sites: usize,
+    pub power: f64,
+    pub church: f64,
+}
+
+impl CorridorBalance {
+    /// POWER ≤ k·CHURCH over the corridor; NaN totals fail.[file:4]
+    pub fn holds(&self, k: f64) -> bool {
+        matches!(self.power.partial_cmp(&(k * self.church)), Some(Ordering::Less | Ordering::Equal))
+    }
+}
+
+/// A world-level invariant that does not hold.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WorldViolation {
+    /// Grid-scale load summed over all sites is over the grid ceiling.
+    GridCeiling { load: f64, max: f64 },
+    /// A corridor's summed POWER is over k times its summed CHURCH.
+    PowerChurch { zone: ZoneTag, power: f64, church: f64, k: f64 },
+}
+
+/// Result of one `WorldInvariantChecker::check` sweep.
+#[derive(Clone, Debug, Default)]
+pub struct WorldInvariantReport {
+    pub sites_checked: usize,
+    /// In snapshot order; a site may appear once per broken invariant.
+    pub site_violations: Vec<SiteViolation>,
+    /// Grid-scale load summed over all sites.
+    pub grid_load: f64,
+    /// One entry per zone with at least one site, in first-seen order.
+    pub corridors: Vec<CorridorBalance>,
+    pub world_violations: Vec<WorldViolation>,
+}
+
+impl WorldInvariantReport {
+    pub fn is_clean(&self) -> bool {
+        self.site_violations.is_empty() && self.world_violations.is_empty()
+    }
+}
+
+/// Periodic sweep of every site in a shared world snapshot against the
+/// global invariants: RoH ≤ the tier's ceiling per site, per-site and aggregate grid
+/// load within the Terrasafe ceilings, and POWER ≤ k·CHURCH per
+/// corridor.[file:2][file:4]
+///
+/// Only ever takes the read lock, and only long enough to clone the site
+/// views.
+#[derive(Clone, Debug)]
+pub struct WorldInvariantChecker {
+    ceilings: Arc<ScaledCeilings>,
+    roh_ceiling: f64,
+    power_church_k: f64,
+    chunk_size: usize,
+}
+
+impl WorldInvariantChecker {
+    /// Checker with the ceilings, RoH ceiling and k of `config`; sites are
+    /// evaluated in chunks of 32.
+    pub fn new(config: &BioRailConfig) -> Self {
+        Self {
+            ceilings: Arc::new(config.terrasafe.scaled()),
+            roh_ceiling: RoH::<f64>::ceiling_for(config.capability_state),
+            power_church_k: config.power_church_k,
+            chunk_size: 32,
+        }
+    }
+
+    pub fn with_ceilings(mut self, ceilings: ScaledCeilings) -> Self {
+        self.ceilings = Arc::new(ceilings);
+        self
+    }
+
+    /// Sites per blocking task; clamped to at least 1.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Sweep `snapshot`. Site checks run on the blocking pool in parallel
+    /// chunks; aggregates are summed in snapshot order so the report does
+    /// not depend on scheduling.
+    pub async fn check<W: WorldInvariantSource>(&self, snapshot: &Arc<RwLock<W>>) -> WorldInvariantReport {
+        let views = snapshot.read().await.invariant_views();
+
+        let mut tasks = Vec::with_capacity(views.len().div_ceil(self.chunk_size));
+        for chunk in views.chunks(self.chunk_size) {
+            let chunk = chunk.to_vec();
+            let (ceilings, roh_ceiling) = (Arc::clone(&self.ceilings), self.roh_ceiling);
+            tasks.push(tokio::task::spawn_blocking(move || {
+                chunk.iter().flat_map(|site| site_violations(site, &ceilings, roh_ceiling)).collect::<Vec<_>>()
+            }));
+        }
+        let mut report = WorldInvariantReport { sites_checked: views.len(), ..Default::default() };
+        for task in tasks {
+            let violations = task.await.unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()));
+            report.site_violations.extend(violations);
+        }
+
+        for site in &views {
+            report.grid_load += site.load.0.get(&TerritoryScale::Grid).copied().unwrap_or(0.0);
+            match report.corridors.iter_mut().find(|c| c.zone == site.zone) {
+                Some(corridor) => {
+                    corridor.sites += 1;
+                    corridor.power += site.power;
+                    corridor.church += site.church;
+                }
+                None => report.corridors.push(CorridorBalance { zone: site.zone.clone(), sites: 1, power: site.power, church: site.church }),
+            }
+        }
+        if let Some(max) = self.ceilings.ceiling(&TerritoryScale::Grid) {
+            if matches!(report.grid_load.partial_cmp(&max), Some(Ordering::Greater) | None) {
+                report.world_violations.push(WorldViolation::GridCeiling { load: report.grid_load, max });
+            }
+        }
+        for corridor in &report.corridors {
+            if !corridor.holds(self.power_church_k) {
+                report.world_violations.push(WorldViolation::PowerChurch {
+                    zone: corridor.zone.clone(),
+                    power: corridor.power,
+                    church: corridor.church,
+                    k: self.power_church_k,
+                });
+            }
+        }
+        report
+    }
+}
+
+/// Site-level invariants of one view: RoH, then unconfigured scales, then
+/// ceilings smallest scale first.
+fn site_violations(site: &SiteInvariantView, ceilings: &ScaledCeilings, roh_ceiling: f64) -> Vec<SiteViolation> {
+    let mut out = Vec::new();
+    let mut push = |invariant| out.push(SiteViolation { site_id: site.site_id, invariant });
+    if matches!(site.roh.partial_cmp(&roh_ceiling), Some(Ordering::Greater) | None) {
+        push(SiteInvariant::RohCeiling { roh: site.roh });
+    }
+    for scale in site.load.0.keys().filter(|scale| ceilings.ceiling(scale).is_none()) {
+        push(SiteInvariant::UnconfiguredScale(scale.clone()));
+    }
+    for scale in ceilings.hierarchy() {
+        let (Some(&load), Some(max)) = (site.load.0.get(scale), ceilings.ceiling(scale)) else { continue };
+        if matches!(load.partial_cmp(&max), Some(Ordering::Greater) | None) {
+            push(SiteInvariant::BioloadCeiling { scale: scale.clone(), load, max });
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VECTORS: &str = include_str!("../../../microsociety/tests/fixtures/projection_vectors.csv");
+
+    fn identity(risk: f64, trust: f64) -> Identity5D {
+        Identity5D {
+            biostate_fatigue: risk,
+            biostate_inflammation: risk,
+            neurostate_fear: risk,
+            neurostate_stimulation: risk,
+            lifeforce_level: 1.0 - risk,
+            lifeforce_drain: risk,
+            roh_slice: risk * ROH_MAX,
+            decay: risk,
+            context_territorial_load: risk,
+            context_pollution: risk,
+            sovereignty_trust: trust,
+            sovereignty_consent: true,
+        }
+    }
+
+    fn gate() -> BioRailGate {
+        BioRailGate::new(
+            BioRailConfig {
+                zones: vec![
+                    BioRailZone { id: ZoneTag::NeuralBand, b_min: 0.0, b_max: 0.3 },
+                    BioRailZone { id: ZoneTag::JetsonLineSite, b_min: 0.0, b_max: 0.6 },
+                ],
+                terrasafe: TerrasafeCeilings { body_max: 0.8, room_max: 0.8, grid_max: 0.8, scales: None },
+                power_church_k: 1.0,
+                capability_state: CapabilityState::ControlledHuman,
+            },
+            ProjectionWeights::DEFAULT,
+        )
+    }
+
+    fn calm_load() -> TerritoryLoad {
+        TerritoryLoad { body: 0.3, room: 0.3, grid: 0.3 }
+    }
+
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> f64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 >> 11) as f64 / (1u64 << 53) as f64
+        }
+    }
+
+    fn random_identity(rng: &mut Rng) -> Identity5D {
+        Identity5D {
+            biostate_fatigue: rng.next(),
+            biostate_inflammation: rng.next(),
+            neurostate_fear: rng.next(),
+            neurostate_stimulation: rng.next(),
+            lifeforce_level: rng.next(),
+            lifeforce_drain: rng.next(),
+            roh_slice: rng.next() * ROH_MAX,
+            decay: rng.next(),
+            context_territorial_load: rng.next(),
+            context_pollution: rng.next(),
+            sovereignty_trust: rng.next(),
+            sovereignty_consent: true,
+        }
+    }
+
+    #[test]
+    fn test_projection_matches_shared_vectors() {
+        let mut rows = 0;
+        for line in VECTORS.lines().filter(|l| !l.starts_with('#')).skip(1) {
+            let f: Vec<f64> = line.split(',').map(|x| x.parse().unwrap()).collect();
+            let [bio, fear, ctx, roh, decay, lifeforce, trust, expected] = f[..] else { panic!("{line}") };
+            let id = Identity5D {
+                biostate_fatigue: bio,
+                biostate_inflammation: bio,
+                neurostate_fear: fear,
+                neurostate_stimulation: fear,
+                lifeforce_level: lifeforce,
+                lifeforce_drain: 1.0 - lifeforce.clamp(0.0, 1.0),
+                roh_slice: roh,
+                decay,
+                context_territorial_load: ctx,
+                context_pollution: ctx,
+                sovereignty_trust: trust,
+                sovereignty_consent: true,
+            };
+            let b = id.project(&ProjectionWeights::DEFAULT).value();
+            assert!((b - expected).abs() < 1e-12, "{line}: got {b}");
+            rows += 1;
+        }
+        assert!(rows >= 10);
+    }
+
+    #[test]
+    fn test_withdrawn_consent_saturates_the_rail() {
+        let calm = Identity5D { sovereignty_consent: false, ..identity(0.0, 1.0) };
+        assert_eq!(calm.project(&ProjectionWeights::DEFAULT).value(), 1.0);
+        assert_eq!(gate().check(&ZoneTag::JetsonLineSite, &calm, &calm_load()), Err(RailRefusal::ConsentWithdrawn));
+    }
+
+    #[test]
+    fn test_nan_components_project_as_zero_risk() {
+        let id = Identity5D { neurostate_fear: f64::NAN, ..identity(0.0, 0.0) };
+        assert_eq!(id.project(&ProjectionWeights::DEFAULT), identity(0.0, 0.0).project(&ProjectionWeights::DEFAULT));
+    }
+
+    #[test]
+    fn test_gate_checks_zone_corridor_and_ceilings() {
+        let gate = gate();
+        let moderate = identity(0.5, 0.3);
+        let b = moderate.project(&gate.weights);
+        assert!(b.value() > 0.3 && b.value() < 0.6, "{b:?}");
+
+        assert_eq!(gate.check(&ZoneTag::JetsonLineSite, &moderate, &calm_load()), Ok(b));
+        assert_eq!(
+            gate.check(&ZoneTag::NeuralBand, &moderate, &calm_load()),
+            Err(RailRefusal::CorridorExit { b, b_min: 0.0, b_max: 0.3 })
+        );
+        assert_eq!(
+            gate.check(&ZoneTag::HydrogelPatch, &moderate, &calm_load()),
+            Err(RailRefusal::UnknownZone(ZoneTag::HydrogelPatch))
+        );
+        let crowded = TerritoryLoad { room: 0.9, ..calm_load() };
+        assert_eq!(
+            gate.check(&ZoneTag::JetsonLineSite, &moderate, &crowded),
+            Err(RailRefusal::BioloadCeiling { scale: TerritoryScale::Room, load: 0.9, max: 0.8 })
+        );
+        let unknown = TerritoryLoad { grid: f64::NAN, ..calm_load() };
+        assert!(matches!(
+            gate.check(&ZoneTag::JetsonLineSite, &moderate, &unknown),
+            Err(RailRefusal::BioloadCeiling { scale: TerritoryScale::Grid, .. })
+        ));
+    }
+
+    #[test]
+    fn test_projection_is_monotone_in_every_component() {
+        let w = ProjectionWeights::DEFAULT;
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..2000 {
+            let base = random_identity(&mut rng);
+            let b = base.project(&w).value();
+            let bump = rng.next();
+            let riskier = [
+                Identity5D { biostate_fatigue: base.biostate_fatigue + bump, ..base.clone() },
+                Identity5D { biostate_inflammation: base.biostate_inflammation + bump, ..base.clone() },
+                Identity5D { neurostate_fear: base.neurostate_fear + bump, ..base.clone() },
+                Identity5D { neurostate_stimulation: base.neurostate_stimulation + bump, ..base.clone() },
+                Identity5D { lifeforce_level: base.lifeforce_level - bump, ..base.clone() },
+                Identity5D { lifeforce_drain: base.lifeforce_drain + bump, ..base.clone() },
+                Identity5D { roh_slice: base.roh_slice + bump * ROH_MAX, ..base.clone() },
+                Identity5D { decay: base.decay + bump, ..base.clone() },
+                Identity5D { context_territorial_load: base.context_territorial_load + bump, ..base.clone() },
+                Identity5D { context_pollution: base.context_pollution + bump, ..base.clone() },
+                Identity5D { sovereignty_trust: base.sovereignty_trust - bump, ..base.clone() },
+                Identity5D { sovereignty_consent: false, ..base.clone() },
+            ];
+            for (i, id) in riskier.iter().enumerate() {
+                assert!(id.project(&w).value() >= b, "component {i} lowered b: {base:?}");
+            }
+        }
+    }
+
+    fn district_ceilings() -> ScaledCeilings {
+        ScaledCeilings::new(
+            vec![TerritoryScale::Body, TerritoryScale::Room, TerritoryScale::Building, TerritoryScale::District, TerritoryScale::Grid],
+            HashMap::from([
+                (TerritoryScale::Body, 0.8),
+                (TerritoryScale::Room, 0.8),
+                (TerritoryScale::Building, 0.7),
+                (TerritoryScale::District, 0.6),
+                (TerritoryScale::Grid, 0.8),
+            ]),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_intermediate_scales_are_enforced() {
+        let gate = gate().with_ceilings(district_ceilings());
+        let calm = identity(0.1, 0.6);
+        let mut load = ScaledLoad::from(&calm_load());
+        load.0.insert(TerritoryScale::Building, 0.5);
+        assert!(gate.check_scaled(&ZoneTag::JetsonLineSite, &calm, &load).is_ok());
+
+        load.0.insert(TerritoryScale::District, 0.65);
+        load.0.insert(TerritoryScale::Building, 0.75);
+        // Smallest scale first: the building refusal wins over the district one.
+        assert_eq!(
+            gate.check_scaled(&ZoneTag::JetsonLineSite, &calm, &load),
+            Err(RailRefusal::BioloadCeiling { scale: TerritoryScale::Building, load: 0.75, max: 0.7 })
+        );
+    }
+
+    #[test]
+    fn test_load_at_an_unconfigured_scale_fails_closed() {
+        let mut load = ScaledLoad::from(&calm_load());
+        load.0.insert(TerritoryScale::District, 0.1);
+        assert_eq!(
+            gate().check_scaled(&ZoneTag::JetsonLineSite, &identity(0.1, 0.6), &load),
+            Err(RailRefusal::UnconfiguredScale(TerritoryScale::District))
+        );
+    }
+
+    #[test]
+    fn test_missing_ceiling_is_a_config_error() {
+        let hierarchy = vec![TerritoryScale::Body, TerritoryScale::Building];
+        assert_eq!(
+            ScaledCeilings::new(hierarchy.clone(), HashMap::from([(TerritoryScale::Body, 0.8)])).unwrap_err(),
+            CeilingConfigError::MissingCeiling(TerritoryScale::Building)
+        );
+        let extra = HashMap::from([(TerritoryScale::Body, 0.8), (TerritoryScale::Building, 0.7), (TerritoryScale::Grid, 0.8)]);
+        assert_eq!(
+            ScaledCeilings::new(hierarchy, extra).unwrap_err(),
+            CeilingConfigError::UnorderedScale(TerritoryScale::Grid)
+        );
+        let twice = vec![TerritoryScale::Body, TerritoryScale::Body];
+        assert_eq!(
+            ScaledCeilings::new(twice, HashMap::from([(TerritoryScale::Body, 0.8)])).unwrap_err(),
+            CeilingConfigError::DuplicateScale(TerritoryScale::Body)
+        );
+    }
+
+    #[test]
+    fn test_three_field_config_keeps_working() {
+        let legacy = gate();
+        assert_eq!(legacy.ceilings().hierarchy(), [TerritoryScale::Body, TerritoryScale::Room, TerritoryScale::Grid]);
+        assert_eq!(legacy.ceilings().ceiling(&TerritoryScale::Grid), Some(0.8));
+        let scaled = ScaledCeilings::new(legacy.ceilings().hierarchy().to_vec(), legacy.config.terrasafe.scaled().ceilings);
+        assert!(scaled.is_ok());
+
+        let moderate = identity(0.5, 0.3);
+        let crowded = TerritoryLoad { body: 0.85, ..calm_load() };
+        assert_eq!(
+            legacy.check(&ZoneTag::JetsonLineSite, &moderate, &crowded),
+            legacy.check_scaled(&ZoneTag::JetsonLineSite, &moderate, &ScaledLoad::from(&crowded))
+        );
+    }
+
+    struct SyntheticWorld(Vec<SiteInvariantView>);
+
+    impl WorldInvariantSource for SyntheticWorld {
+        fn invariant_views(&self) -> Vec<SiteInvariantView> {
+            self.0.clone()
+        }
+    }
+
+    /// 100 calm sites split across two corridors, grid load 0.005 each.
+    fn synthetic_world() -> SyntheticWorld {
+        SyntheticWorld(
+            (0..100)
+                .map(|i| SiteInvariantView {
+                    site_id: SiteId(i),
+                    zone: if i % 2 == 0 { ZoneTag::JetsonLineSite } else { ZoneTag::XrField },
+                    roh: 0.1,
+                    power: 0.2,
+                    church: 0.4,
+                    load: ScaledLoad(HashMap::from([
+                        (TerritoryScale::Body, 0.3),
+                        (TerritoryScale::Room, 0.3),
+                        (TerritoryScale::Grid, 0.005),
+                    ])),
+                })
+                .collect(),
+        )
+    }
+
+    fn checker() -> WorldInvariantChecker {
+        WorldInvariantChecker::new(&gate().config).with_chunk_size(7)
+    }
+
+    #[tokio::test]
+    async fn test_clean_world_has_no_violations() {
+        let world = Arc::new(RwLock::new(synthetic_world()));
+        let report = checker().check(&world).await;
+        assert!(report.is_clean(), "{report:?}");
+        assert_eq!(report.sites_checked, 100);
+        assert!((report.grid_load - 0.5).abs() < 1e-9);
+        assert_eq!(report.corridors.len(), 2);
+        assert!(report.corridors.iter().all(|c| c.sites == 50));
+    }
+
+    #[tokio::test]
+    async fn test_seeded_violations_are_reported_per_site_and_world() {
+        let mut world = synthetic_world();
+        world.0[17].roh = 0.35;
+        world.0[42].load.0.insert(TerritoryScale::Room, 0.9);
+        world.0[73].roh = f64::NAN;
+        world.0[88].load.0.insert(TerritoryScale::District, 0.1);
+        // Odd sites are XrField: push that corridor's power past k·church.
+        for site in world.0.iter_mut().filter(|s| s.zone == ZoneTag::XrField) {
+            site.power = 0.5;
+        }
+        world.0[99].load.0.insert(TerritoryScale::Grid, 0.5);
+
+        let report = checker().check(&Arc::new(RwLock::new(world))).await;
+        let sites: Vec<_> = report.site_violations.iter().map(|v| v.site_id).collect();
+        assert_eq!(sites, [SiteId(17), SiteId(42), SiteId(73), SiteId(88)]);
+        assert_eq!(report.site_violations[0].invariant, SiteInvariant::RohCeiling { roh: 0.35 });
+        assert_eq!(
+            report.site_violations[1].invariant,
+            SiteInvariant::BioloadCeiling { scale: TerritoryScale::Room, load: 0.9, max: 0.8 }
+        );
+        assert!(matches!(report.site_violations[2].invariant, SiteInvariant::RohCeiling { roh } if roh.is_nan()));
+        assert_eq!(report.site_violations[3].invariant, SiteInvariant::UnconfiguredScale(TerritoryScale::District));
+
+        assert!(matches!(report.world_violations[0], WorldViolation::GridCeiling { load, max: 0.8 } if load > 0.8));
+        assert!(matches!(&report.world_violations[1], WorldViolation::PowerChurch { zone: ZoneTag::XrField, .. }));
+        assert_eq!(report.world_violations.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_roh_ceiling_follows_the_capability_tier() {
+        let mut world = synthetic_world();
+        world.0[17].roh = 0.35;
+        world.0[73].roh = f64::NAN;
+        let world = Arc::new(RwLock::new(world));
+
+        let mut bench = gate().config;
+        bench.capability_state = CapabilityState::LabBench;
+        let report = WorldInvariantChecker::new(&bench).check(&world).await;
+        let sites: Vec<_> = report.site_violations.iter().map(|v| v.site_id).collect();
+        assert_eq!(sites, [SiteId(73)], "only NaN is over LabBench's ceiling");
+        assert_eq!(checker().check(&world).await.site_violations.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_check_only_takes_the_read_lock() {
+        let world = Arc::new(RwLock::new(synthetic_world()));
+        // A concurrent reader would block a writer forever; the sweep must
+        // still finish while it is held.
+        let held = world.read().await;
+        let report = tokio::time::timeout(std::time::Duration::from_secs(5), checker().check(&world))
+            .await
+            .expect("check waited on the write lock");
+        assert!(report.is_clean());
+        drop(held);
+        assert!(world.try_write().is_ok(), "check left the lock held");
+    }
+
+    #[tokio::test]
+    async fn test_report_does_not_depend_on_chunking() {
+        let mut world = synthetic_world();
+        world.0[3].roh = 0.4;
+        world.0[64].load.0.insert(TerritoryScale::Body, 0.95);
+        let world = Arc::new(RwLock::new(world));
+        let one = checker().with_chunk_size(1).check(&world).await;
+        let all = checker().with_chunk_size(1000).check(&world).await;
+        assert_eq!(one.site_violations, all.site_violations);
+        assert_eq!(one.corridors, all.corridors);
+        assert_eq!(one.grid_load.to_bits(), all.grid_load.to_bits());
+    }
+
+    #[cfg(feature = "config-files")]
+    const CONFIG_FIXTURE: &str = include_str!("../tests/fixtures/biorail_config.toml");
+
+    fn invalid(cfg: &BioRailConfig) -> Vec<BioRailConfigViolation> {
+        match cfg.validate() {
+            Err(BioRailConfigError::Invalid(violations)) => violations,
+            other => panic!("expected violations, got {other:?}"),
+        }
+    }
+
+    #[cfg(feature = "config-files")]
+    #[test]
+    fn test_example_config_loads() {
+        let cfg = BioRailConfig::from_toml_str(CONFIG_FIXTURE).unwrap();
+        assert_eq!(cfg.zones.len(), 3);
+        assert_eq!(cfg.zones[1].id, ZoneTag::HydrogelPatch);
+        assert_eq!((cfg.terrasafe.body_max, cfg.terrasafe.grid_max), (0.6, 0.8));
+        assert_eq!(cfg.power_church_k, 1.0);
+        assert_eq!(cfg.capability_state, CapabilityState::ControlledHuman);
+        let untiered = CONFIG_FIXTURE.replace("capability_state = \"ControlledHuman\"", "");
+        assert_eq!(BioRailConfig::from_toml_str(&untiered).unwrap().capability_state, CapabilityState::ControlledHuman);
+
+        let path = std::env::temp_dir().join(format!("biorail_config_{}.toml", std::process::id()));
+        std::fs::write(&path, CONFIG_FIXTURE).unwrap();
+        let loaded = BioRailConfig::from_toml_path(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap().zones.len(), 3);
+    }
+
+    #[cfg(feature = "config-files")]
+    #[test]
+    fn test_loader_reports_read_parse_and_validation_errors() {
+        let missing = BioRailConfig::from_toml_path(Path::new("/nonexistent/biorail.toml"));
+        assert!(matches!(missing, Err(BioRailConfigError::Read(e)) if e.contains("/nonexistent/biorail.toml")));
+        let unknown_zone = CONFIG_FIXTURE.replace("\"NeuralBand\"", "\"Cortex\"");
+        assert!(matches!(BioRailConfig::from_toml_str(&unknown_zone), Err(BioRailConfigError::Parse(_))));
+
+        let inverted = CONFIG_FIXTURE.replace("b_max = 0.45", "b_max = 0.01");
+        let err = BioRailConfig::from_toml_str(&inverted).unwrap_err();
+        assert_eq!(err.to_string(), "invalid biorail config: zone HydrogelPatch has b_min 0.05 above b_max 0.01");
+    }
+
+    #[test]
+    fn test_duplicate_zone_is_named() {
+        let mut cfg = gate().config;
+        cfg.zones.push(BioRailZone { id: ZoneTag::NeuralBand, b_min: 0.0, b_max: 0.2 });
+        assert_eq!(invalid(&cfg), [BioRailConfigViolation::DuplicateZone(ZoneTag::NeuralBand)]);
+    }
+
+    #[test]
+    fn test_inverted_or_nan_corridor_is_named() {
+        let mut cfg = gate().config;
+        cfg.zones[0].b_min = 0.5;
+        cfg.zones[1].b_max = f64::NAN;
+        let violations = invalid(&cfg);
+        assert_eq!(violations[0], BioRailConfigViolation::InvertedCorridor { zone: ZoneTag::NeuralBand, b_min: 0.5, b_max: 0.3 });
+        assert!(matches!(violations[1], BioRailConfigViolation::InvertedCorridor { zone: ZoneTag::JetsonLineSite, .. }));
+        assert_eq!(violations.len(), 2);
+    }
+
+    #[test]
+    fn test_non_positive_ceiling_is_named() {
+        let mut cfg = gate().config;
+        cfg.terrasafe.body_max = 0.0;
+        cfg.terrasafe.room_max = f64::NAN;
+        let violations = invalid(&cfg);
+        assert_eq!(violations[0], BioRailConfigViolation::NonPositiveCeiling { field: "body_max", value: 0.0 });
+        assert!(matches!(violations[1], BioRailConfigViolation::NonPositiveCeiling { field: "room_max", value } if value.is_nan()));
+        assert_eq!(violations.len(), 2);
+    }
+
+    #[test]
+    fn test_ceiling_order_is_enforced() {
+        let mut cfg = gate().config;
+        cfg.terrasafe = TerrasafeCeilings { body_max: 0.9, room_max: 0.7, grid_max: 0.6, scales: None };
+        assert_eq!(
+            invalid(&cfg),
+            [
+                BioRailConfigViolation::CeilingOrder { lower: "body_max", lower_max: 0.9, upper: "room_max", upper_max: 0.7 },
+                BioRailConfigViolation::CeilingOrder { lower: "room_max", lower_max: 0.7, upper: "grid_max", upper_max: 0.6 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_negative_power_church_k_is_rejected() {
+        let mut cfg = gate().config;
+        cfg.power_church_k = -0.5;
+        assert_eq!(invalid(&cfg), [BioRailConfigViolation::NegativePowerChurchK(-0.5)]);
+        cfg.power_church_k = 0.0;
+        assert!(cfg.validate().is_ok());
+        assert_eq!(
+            BioRailConfigError::Invalid(vec![BioRailConfigViolation::NegativePowerChurchK(-0.5)]).to_string(),
+            "invalid biorail config: power_church_k = -0.5 is negative"
+        );
+    }
+
+    #[cfg(feature = "config-files")]
+    const SCALES: &str = "
+[terrasafe.scales]
+hierarchy = [\"Body\", \"Room\", \"Building\", \"District\", \"Grid\"]
+
+[terrasafe.scales.ceilings]
+Body = 0.6
+Room = 0.7
+Building = 0.72
+District = 0.75
+Grid = 0.8
+";
+
+    #[cfg(feature = "config-files")]
+    #[test]
+    fn test_scale_table_loads_and_drives_the_gate() {
+        let cfg = BioRailConfig::from_toml_str(&format!("{CONFIG_FIXTURE}{SCALES}")).unwrap();
+        let gate = BioRailGate::new(cfg, ProjectionWeights::default());
+        assert_eq!(gate.ceilings().hierarchy().len(), 5);
+        assert_eq!(gate.ceilings().ceiling(&TerritoryScale::District), Some(0.75));
+
+        let mut load = ScaledLoad::from(&TerritoryLoad { body: 0.3, room: 0.3, grid: 0.3 });
+        load.0.insert(TerritoryScale::Building, 0.73);
+        assert_eq!(
+            gate.check_scaled(&ZoneTag::JetsonLineSite, &identity(0.1, 0.6), &load),
+            Err(RailRefusal::BioloadCeiling { scale: TerritoryScale::Building, load: 0.73, max: 0.72 })
+        );
+    }
+
+    #[cfg(feature = "config-files")]
+    #[test]
+    fn test_intermediate_scale_without_ceiling_fails_to_load() {
+        let missing = format!("{CONFIG_FIXTURE}{SCALES}").replace("Building = 0.72\n", "");
+        let err = BioRailConfig::from_toml_str(&missing).unwrap_err();
+        assert_eq!(
+            err,
+            BioRailConfigError::Invalid(vec![BioRailConfigViolation::ScaleTable(CeilingConfigError::MissingCeiling(TerritoryScale::Building))])
+        );
+        assert_eq!(err.to_string(), "invalid biorail config: terrasafe.scales: no bioload ceiling for territory scale Building");
+    }
+
+    #[cfg(feature = "config-files")]
+    #[test]
+    fn test_scale_table_values_are_checked_like_the_fields() {
+        let bad = format!("{CONFIG_FIXTURE}{SCALES}").replace("Building = 0.72", "Building = 0.0").replace("District = 0.75", "District = -0.1");
+        assert_eq!(
+            invalid(&toml::from_str(&bad).unwrap()),
+            [
+                BioRailConfigViolation::NonPositiveCeiling { field: "scales.Building", value: 0.0 },
+                BioRailConfigViolation::NonPositiveCeiling { field: "scales.District", value: -0.1 },
+                BioRailConfigViolation::CeilingOrder { lower: "scales.Room", lower_max: 0.7, upper: "scales.Building", upper_max: 0.0 },
+                BioRailConfigViolation::CeilingOrder { lower: "scales.Building", lower_max: 0.0, upper: "scales.District", upper_max: -0.1 },
+            ]
+        );
+
+        let nan = format!("{CONFIG_FIXTURE}{SCALES}").replace("Grid = 0.8", "Grid = nan");
+        let violations = invalid(&toml::from_str(&nan).unwrap());
+        assert!(matches!(violations[..], [BioRailConfigViolation::NonPositiveCeiling { field: "scales.Grid", value }] if value.is_nan()));
+
+        let descending = format!("{CONFIG_FIXTURE}{SCALES}").replace("District = 0.75", "District = 0.65");
+        let err = BioRailConfig::from_toml_str(&descending).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid biorail config: terrasafe.scales.Building = 0.72 is above terrasafe.scales.District = 0.65"
+        );
+        // A gate built from an unvalidated bad table refuses every load.
+        let gate = BioRailGate::new(toml::from_str(&format!("{CONFIG_FIXTURE}{SCALES}").replace("Building = 0.72\n", "")).unwrap(), ProjectionWeights::default());
+        assert!(gate.ceilings().hierarchy().is_empty());
+    }
+
+    fn calm_builder() -> Result<Identity5DBuilder, Identity5DFieldError> {
+        Identity5DBuilder::new()
+            .biostate_fatigue(0.2)?
+            .biostate_inflammation(0.2)?
+            .neurostate_fear(0.2)?
+            .neurostate_stimulation(0.2)?
+            .lifeforce_level(0.8)?
+            .lifeforce_drain(0.2)?
+            .decay(0.2)?
+            .context_territorial_load(0.2)?
+            .context_pollution(0.2)?
+            .sovereignty_trust(0.6)
+            .map(|b| b.sovereignty_consent(true))
+    }
+
+    #[test]
+    fn test_roh_is_normalized_exactly_once() {
+        let raw = calm_builder().unwrap().roh_raw(0.15).unwrap().build();
+        let normalized = calm_builder().unwrap().roh_normalized(0.5).unwrap().build();
+        assert_eq!(raw.identity.roh_slice, 0.15);
+        assert_eq!(normalized.identity.roh_slice.to_bits(), (0.5 * ROH_MAX).to_bits());
+        assert_eq!(raw.identity.project(&ProjectionWeights::DEFAULT), normalized.identity.project(&ProjectionWeights::DEFAULT));
+        assert!(raw.defaulted.is_empty());
+
+        // The trap: projecting an already-clamped identity divides RoH by
+        // ROH_MAX a second time.
+        let twice = raw.identity.clone().clamped();
+        assert_eq!(twice.roh_slice, 0.5);
+        assert_eq!(twice.clone().clamped().roh_slice, 1.0);
+        assert!(twice.project(&ProjectionWeights::DEFAULT) > raw.identity.project(&ProjectionWeights::DEFAULT));
+    }
+
+    #[test]
+    fn test_setters_reject_nan_and_negative() {
+        assert_eq!(
+            Identity5DBuilder::new().neurostate_fear(f64::NAN).unwrap_err(),
+            Identity5DFieldError::NotANumber { field: "neurostate_fear" }
+        );
+        assert_eq!(
+            Identity5DBuilder::new().roh_normalized(-0.1).unwrap_err(),
+            Identity5DFieldError::Negative { field: "roh_slice", value: -0.1 }
+        );
+        assert_eq!(Identity5DBuilder::new().decay(-1.0).unwrap_err().to_string(), "decay = -1 is negative");
+    }
+
+    #[test]
+    fn test_defaulted_fields_are_listed_and_riskiest() {
+        let built = Identity5DBuilder::new().neurostate_fear(0.1).unwrap().build();
+        assert_eq!(built.defaulted.len(), 11);
+        assert!(!built.defaulted.contains(&"neurostate_fear"));
+        assert_eq!(built.defaulted.last(), Some(&"sovereignty_consent"));
+        assert!(!built.identity.sovereignty_consent);
+        assert_eq!(built.identity.roh_slice, ROH_MAX);
+
+        let calm = calm_builder().unwrap().build();
+        assert_eq!(calm.defaulted, ["roh_slice"]);
+        let set = calm_builder().unwrap().roh_raw(0.0).unwrap().build();
+        assert!(calm.identity.project(&ProjectionWeights::DEFAULT) >= set.identity.project(&ProjectionWeights::DEFAULT));
+    }
+
+    #[test]
+    fn test_build_clamps_into_raw_ranges() {
+        let built = calm_builder().unwrap().roh_raw(0.9).unwrap().neurostate_fear(4.0).unwrap().build();
+        assert_eq!(built.identity.roh_slice, ROH_MAX);
+        assert_eq!(built.identity.neurostate_fear, 1.0);
+        let unclamped = Identity5D { roh_slice: 0.9, neurostate_fear: 4.0, ..built.identity.clone() };
+        assert_eq!(built.identity.project(&ProjectionWeights::DEFAULT), unclamped.project(&ProjectionWeights::DEFAULT));
+    }
+}
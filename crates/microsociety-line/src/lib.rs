@@ -0,0 +1,6 @@
+//! Jetson-Line side of the MicroSociety spine: the BioRail gate over the
+//! full 5D identity, territorial bioload ceilings and the world-wide
+//! invariant sweep.
+
+pub mod biorail;
+pub mod state;
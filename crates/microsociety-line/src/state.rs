@@ -0,0 +1,22 @@
+//! Site identity and territorial scales shared by the gate and the world
+//! invariant sweep.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Stable identifier of one Jetson-Line site.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SiteId(pub u32);
+
+/// Territory scales a bioload ceiling can apply to, from the smallest to
+/// the largest; a deployment's `ScaledCeilings` hierarchy uses a subset.[file:4]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TerritoryScale {
+    Body,
+    Room,
+    Building,
+    District,
+    Grid,
+}
@@ -0,0 +1,262 @@
+//! Canonical JSON for hashing.
+//!
+//! Every hash Morphix persists (deed `self_hash`, config versions, telemetry
+//! aggregates) is taken over `canonical_json`, so the same logical content
+//! always yields the same bytes regardless of `HashMap` iteration order.
+//!
+//! The encoding follows RFC 8785 (JCS) with two deliberate differences:
+//! - strings and object keys are normalized to Unicode NFC first;
+//! - integers are written exactly rather than through an f64, so u64
+//!   timestamps and counters above 2^53 keep every digit.
+//!
+//! Otherwise: object keys are sorted by UTF-16 code units, there is no
+//! insignificant whitespace, floats use the shortest round-trip digits in
+//! ECMAScript `Number.prototype.toString` form, and only `"`, `\` and
+//! control characters are escaped. Non-finite floats have already become
+//! `null` by the time serde_json hands us a value.
+
+use std::fmt;
+
+use serde::Serialize;
+use serde_json::{Map, Number, Value};
+use unicode_normalization::UnicodeNormalization;
+
+/// Why a value could not be canonicalized.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CanonError {
+    /// The value's `Serialize` impl failed (e.g. a map with non-string keys).
+    Serialize(String),
+    /// Two object keys are identical once normalized to NFC.
+    DuplicateKey(String),
+}
+
+impl fmt::Display for CanonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Serialize(e) => write!(f, "Canonical serialization failed: {e}"),
+            Self::DuplicateKey(k) => write!(f, "Duplicate object key after NFC normalization: {k}"),
+        }
+    }
+}
+
+impl std::error::Error for CanonError {}
+
+/// Serializes `value` to canonical JSON bytes.
+pub fn canonical_json<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, CanonError> {
+    let value = serde_json::to_value(value).map_err(|e| CanonError::Serialize(e.to_string()))?;
+    let mut out = String::new();
+    write_value(&value, &mut out)?;
+    Ok(out.into_bytes())
+}
+
+fn write_value(value: &Value, out: &mut String) -> Result<(), CanonError> {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Number(n) => write_number(n, out),
+        Value::String(s) => write_string(s, out),
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_value(item, out)?;
+            }
+            out.push(']');
+        }
+        Value::Object(map) => write_object(map, out)?,
+    }
+    Ok(())
+}
+
+fn write_object(map: &Map<String, Value>, out: &mut String) -> Result<(), CanonError> {
+    let mut entries: Vec<(Vec<u16>, String, &Value)> = map
+        .iter()
+        .map(|(k, v)| {
+            let key: String = k.nfc().collect();
+            (key.encode_utf16().collect(), key, v)
+        })
+        .collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    if let Some(pair) = entries.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+        return Err(CanonError::DuplicateKey(pair[0].1.clone()));
+    }
+
+    out.push('{');
+    for (i, (_, key, value)) in entries.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_string(key, out);
+        out.push(':');
+        write_value(value, out)?;
+    }
+    out.push('}');
+    Ok(())
+}
+
+fn write_string(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.nfc() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\u{08}' => out.push_str("\\b"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\u{0c}' => out.push_str("\\f"),
+            '\r' => out.push_str("\\r"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn write_number(n: &Number, out: &mut String) {
+    if let Some(i) = n.as_i64() {
+        out.push_str(&i.to_string());
+    } else if let Some(u) = n.as_u64() {
+        out.push_str(&u.to_string());
+    } else if let Some(f) = n.as_f64() {
+        write_float(f, out);
+    }
+}
+
+/// ECMAScript `Number.prototype.toString` for a finite f64.
+fn write_float(f: f64, out: &mut String) {
+    if f == 0.0 {
+        out.push('0');
+        return;
+    }
+    if f < 0.0 {
+        out.push('-');
+    }
+    // `{:e}` yields the shortest round-trip digits, e.g. "1.2345e-7".
+    let sci = format!("{:e}", f.abs());
+    let (mantissa, exp) = sci
+        .split_once('e')
+        .expect("LowerExp always has an exponent");
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    let k = digits.len() as i32;
+    // The value is 0.<digits> * 10^n.
+    let n = exp.parse::<i32>().expect("LowerExp exponent is an integer") + 1;
+
+    if k <= n && n <= 21 {
+        out.push_str(&digits);
+        out.extend(std::iter::repeat_n('0', (n - k) as usize));
+    } else if 0 < n && n <= 21 {
+        out.push_str(&digits[..n as usize]);
+        out.push('.');
+        out.push_str(&digits[n as usize..]);
+    } else if -6 < n && n <= 0 {
+        out.push_str("0.");
+        out.extend(std::iter::repeat_n('0', (-n) as usize));
+        out.push_str(&digits);
+    } else {
+        out.push_str(&digits[..1]);
+        if k > 1 {
+            out.push('.');
+            out.push_str(&digits[1..]);
+        }
+        out.push('e');
+        out.push(if n - 1 < 0 { '-' } else { '+' });
+        out.push_str(&(n - 1).abs().to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+    use sha2::{Digest, Sha256};
+    use std::collections::HashMap;
+
+    #[derive(Serialize)]
+    struct Evidence {
+        actor: String,
+        context: HashMap<String, Value>,
+    }
+
+    fn text(value: &impl Serialize) -> String {
+        String::from_utf8(canonical_json(value).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn hashmap_insertion_order_does_not_change_the_digest() {
+        let keys = [
+            "river",
+            "aquifer",
+            "canopy",
+            "soil",
+            "pollinator",
+            "corridor",
+        ];
+        let digest = |order: &[&str]| {
+            let context = order
+                .iter()
+                .map(|k| (k.to_string(), serde_json::json!({ "k": k, "v": [1, 2.5] })))
+                .collect();
+            let evidence = Evidence {
+                actor: "steward-1".into(),
+                context,
+            };
+            Sha256::digest(canonical_json(&evidence).unwrap()).to_vec()
+        };
+
+        let expected = digest(&keys);
+        let mut reversed = keys;
+        reversed.reverse();
+        for _ in 0..32 {
+            assert_eq!(digest(&keys), expected);
+            assert_eq!(digest(&reversed), expected);
+        }
+    }
+
+    #[test]
+    fn objects_are_sorted_by_utf16_code_units_without_whitespace() {
+        let value = serde_json::json!({ "b": 1, "a": { "z": null, "y": true }, "\u{e9}": [], "\u{1f600}": "", "\u{ff61}": 0 });
+        // U+1F600 is a surrogate pair (0xD83D...) and sorts before U+FF61.
+        assert_eq!(
+            text(&value),
+            "{\"a\":{\"y\":true,\"z\":null},\"b\":1,\"\u{e9}\":[],\"\u{1f600}\":\"\",\"\u{ff61}\":0}"
+        );
+    }
+
+    #[test]
+    fn numbers_use_ecmascript_formatting() {
+        let cases = [
+            (serde_json::json!(1.0), "1"),
+            (serde_json::json!(-0.0), "0"),
+            (serde_json::json!(0.5), "0.5"),
+            (serde_json::json!(123.456), "123.456"),
+            (serde_json::json!(0.000001), "0.000001"),
+            (serde_json::json!(0.0000001), "1e-7"),
+            (serde_json::json!(1e21), "1e+21"),
+            (serde_json::json!(1e20), "100000000000000000000"),
+            (serde_json::json!(-2.5e-10), "-2.5e-10"),
+            (serde_json::json!(u64::MAX), "18446744073709551615"),
+            (serde_json::json!(-42), "-42"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(text(&value), expected, "{value}");
+        }
+    }
+
+    #[test]
+    fn strings_are_nfc_normalized_and_minimally_escaped() {
+        // "e" + combining acute composes to U+00E9.
+        assert_eq!(text(&"caf\u{65}\u{301}"), "\"caf\u{e9}\"");
+        assert_eq!(
+            text(&"a\"b\\c\n\u{1}/\u{7f}"),
+            "\"a\\\"b\\\\c\\n\\u0001/\u{7f}\""
+        );
+
+        let duplicate = serde_json::json!({ "caf\u{e9}": 1, "caf\u{65}\u{301}": 2 });
+        assert_eq!(
+            canonical_json(&duplicate),
+            Err(CanonError::DuplicateKey("caf\u{e9}".into()))
+        );
+    }
+}
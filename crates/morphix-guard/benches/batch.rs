@@ -0,0 +1,61 @@
+//! Serial vs batch evaluation over a synthetic archive.
+//!
+//! Run with `cargo bench -p morphix-guard --features parallel`; without the
+//! feature `evaluate_many` is serial too, and both lines should match.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use morphix_guard::*;
+
+const EPOCHS: u64 = 100_000;
+
+fn snapshot(epoch: u64) -> MorphixGuardInput {
+    let x = (epoch % 101) as f32 / 100.0;
+    MorphixGuardInput {
+        capability_state: CapabilityState::ControlledHuman,
+        roh: RoH::new(x * 0.4),
+        envelope: BiophysicalEnvelopeSnapshot {
+            eeg_alpha_frac: Some(0.5),
+            eeg_gamma_frac: Some(0.5),
+            eda_tonic_frac: Some(0.5),
+            bpm_frac: Some(x),
+            cognitive_load_warn_frac: Some(0.1),
+            sleep_arousal_warn_frac: Some(0.1),
+            inflammation_warn_frac: Some(x * 0.5),
+        },
+        tree_of_life: TreeOfLifeView {
+            blood: 0.5, oxygen: 0.5, wave: 0.5, h2o: 0.5, time: 0.5,
+            decay: x, lifeforce: 1.0 - x,
+            brain: 0.5, smart: 0.5, evolve: 0.5, power: x, tech: 0.5,
+            fear: x, pain: 0.2, nano: 0.5,
+        },
+        micro_society: MicroSocietyView { predicates: Vec::new() },
+        evolve_index: None,
+        epoch_index: Some(epoch),
+    }
+}
+
+fn bench_batch(c: &mut Criterion) {
+    let cfg = MorphixGuardConfig::default();
+    let inputs: Vec<_> = (0..EPOCHS).map(snapshot).collect();
+
+    let mut group = c.benchmark_group("archive_100k");
+    group.sample_size(10);
+    group.bench_function("serial", |b| {
+        b.iter(|| {
+            black_box(&inputs)
+                .iter()
+                .map(|i| MorphixGuard::evaluate(i, &cfg))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("evaluate_many", |b| {
+        b.iter(|| MorphixGuard::evaluate_many(black_box(&inputs), &cfg))
+    });
+    group.bench_function("evaluate_stream", |b| {
+        b.iter(|| MorphixGuard::evaluate_stream((0..EPOCHS).map(snapshot), &cfg).count())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_batch);
+criterion_main!(benches);
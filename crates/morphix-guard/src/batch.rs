@@ -0,0 +1,147 @@
+//! Batch evaluation for reprocessing archived epochs, e.g. after a
+//! threshold change.
+//!
+//! With the `parallel` feature, batches are spread over rayon's global
+//! pool; without it they run on the calling thread. Either way, output
+//! order matches input order, so archives can be re-labelled in place.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{MorphixGuard, MorphixGuardConfig, MorphixGuardInput, MorphixGuardView};
+
+/// Inputs evaluated per batch by `GuardViewStream` unless overridden.
+pub const DEFAULT_STREAM_CHUNK: usize = 4096;
+
+impl MorphixGuard {
+    /// `evaluate` over every input, views in input order.
+    pub fn evaluate_many(
+        inputs: &[MorphixGuardInput],
+        cfg: &MorphixGuardConfig,
+    ) -> Vec<MorphixGuardView> {
+        #[cfg(feature = "parallel")]
+        let views = inputs.par_iter().map(|i| Self::evaluate(i, cfg)).collect();
+        #[cfg(not(feature = "parallel"))]
+        let views = inputs.iter().map(|i| Self::evaluate(i, cfg)).collect();
+        views
+    }
+
+    /// Lazily evaluates `inputs`, holding at most one chunk of inputs and
+    /// views in memory. Each chunk goes through `evaluate_many`.
+    pub fn evaluate_stream<I>(inputs: I, cfg: &MorphixGuardConfig) -> GuardViewStream<'_, I::IntoIter>
+    where
+        I: IntoIterator<Item = MorphixGuardInput>,
+    {
+        GuardViewStream {
+            inputs: inputs.into_iter(),
+            cfg,
+            chunk: DEFAULT_STREAM_CHUNK,
+            ready: Vec::new().into_iter(),
+        }
+    }
+}
+
+/// Iterator returned by `MorphixGuard::evaluate_stream`.
+pub struct GuardViewStream<'a, I> {
+    inputs: I,
+    cfg: &'a MorphixGuardConfig,
+    chunk: usize,
+    ready: std::vec::IntoIter<MorphixGuardView>,
+}
+
+impl<I> GuardViewStream<'_, I> {
+    /// Inputs per batch; larger chunks parallelize better and use more
+    /// memory. Values below 1 are treated as 1.
+    pub fn with_chunk_size(mut self, chunk: usize) -> Self {
+        self.chunk = chunk.max(1);
+        self
+    }
+}
+
+impl<I: Iterator<Item = MorphixGuardInput>> Iterator for GuardViewStream<'_, I> {
+    type Item = MorphixGuardView;
+
+    fn next(&mut self) -> Option<MorphixGuardView> {
+        if let Some(view) = self.ready.next() {
+            return Some(view);
+        }
+        let batch: Vec<_> = self.inputs.by_ref().take(self.chunk).collect();
+        if batch.is_empty() {
+            return None;
+        }
+        self.ready = MorphixGuard::evaluate_many(&batch, self.cfg).into_iter();
+        self.ready.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        BiophysicalEnvelopeSnapshot, CapabilityState, MicroSocietyView, MorphixLabel, RoH, TreeOfLifeView,
+    };
+
+    fn input(epoch: u64) -> MorphixGuardInput {
+        let x = (epoch % 97) as f32 / 96.0;
+        MorphixGuardInput {
+            capability_state: CapabilityState::ControlledHuman,
+            roh: RoH::new(x * 0.4),
+            envelope: BiophysicalEnvelopeSnapshot {
+                eeg_alpha_frac: Some(0.5),
+                eeg_gamma_frac: Some(0.5),
+                eda_tonic_frac: Some(0.5),
+                bpm_frac: Some(0.5),
+                cognitive_load_warn_frac: Some(0.1),
+                sleep_arousal_warn_frac: Some(0.1),
+                inflammation_warn_frac: Some(0.1),
+            },
+            tree_of_life: TreeOfLifeView {
+                blood: 0.5, oxygen: 0.5, wave: 0.5, h2o: 0.5, time: 0.5,
+                decay: x, lifeforce: 1.0 - x,
+                brain: 0.5, smart: 0.5, evolve: 0.5, power: x, tech: 0.5,
+                fear: x, pain: 0.2, nano: 0.5,
+            },
+            micro_society: MicroSocietyView { predicates: Vec::new() },
+            evolve_index: None,
+            epoch_index: Some(epoch),
+        }
+    }
+
+    fn summary(view: &MorphixGuardView) -> (Option<u64>, Vec<MorphixLabel>) {
+        (view.epoch_index, view.diagnostics.iter().map(|d| d.label.clone()).collect())
+    }
+
+    #[test]
+    fn test_evaluate_many_matches_serial_evaluation_in_order() {
+        let cfg = MorphixGuardConfig::default();
+        let inputs: Vec<_> = (0..1_000).map(input).collect();
+        let serial: Vec<_> = inputs.iter().map(|i| summary(&MorphixGuard::evaluate(i, &cfg))).collect();
+        let batch: Vec<_> = MorphixGuard::evaluate_many(&inputs, &cfg).iter().map(summary).collect();
+        assert_eq!(batch, serial);
+        assert!(MorphixGuard::evaluate_many(&[], &cfg).is_empty());
+    }
+
+    #[test]
+    fn test_stream_crosses_chunk_boundaries_lazily() {
+        let cfg = MorphixGuardConfig::default();
+        let expected: Vec<_> = MorphixGuard::evaluate_many(&(0..250).map(input).collect::<Vec<_>>(), &cfg)
+            .iter()
+            .map(summary)
+            .collect();
+
+        let pulled = std::cell::Cell::new(0);
+        let source = (0..250).map(|e| {
+            pulled.set(pulled.get() + 1);
+            input(e)
+        });
+        let mut stream = MorphixGuard::evaluate_stream(source, &cfg).with_chunk_size(64);
+        assert_eq!(stream.next().map(|v| v.epoch_index), Some(Some(0)));
+        assert_eq!(pulled.get(), 64);
+        let rest: Vec<_> = stream.map(|v| summary(&v)).collect();
+        assert_eq!(rest, expected[1..]);
+        assert_eq!(pulled.get(), 250);
+
+        let zero = MorphixGuard::evaluate_stream((0..3).map(input), &cfg).with_chunk_size(0);
+        assert_eq!(zero.count(), 3);
+    }
+}
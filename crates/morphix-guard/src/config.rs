@@ -0,0 +1,376 @@
+//! Site-specific `MorphixGuardConfig` thresholds and their sanity checks.
+//!
+//! ```toml
+//! decay_boundary_thresh = 0.70
+//! lifeforce_fair_floor = 0.50
+//! power_unfair_thresh = 0.70
+//! fear_overload_thresh = 0.60
+//! pain_overload_thresh = 0.60
+//! # Optional; 0.02 per epoch unless set.
+//! trend_slope_thresh = 0.02
+//!
+//! # Optional per-tier overrides; unset fields keep the values above.
+//! [overrides.ControlledHuman]
+//! fear_overload_thresh = 0.50
+//!
+//! # The RoH ceiling has no base value; see `default_roh_ceiling`.
+//! [overrides.LabBench]
+//! roh_ceiling = 0.60
+//! ```
+
+use std::fmt;
+#[cfg(feature = "config-files")]
+use std::path::Path;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{CapabilityState, MorphixGuardConfig, RoH};
+
+/// Threshold overrides for one `CapabilityState`; `None` keeps the base
+/// value.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct PartialThresholds {
+    pub decay_boundary_thresh: Option<f32>,
+    pub lifeforce_fair_floor: Option<f32>,
+    pub power_unfair_thresh: Option<f32>,
+    pub fear_overload_thresh: Option<f32>,
+    pub pain_overload_thresh: Option<f32>,
+    /// RoH below which the 5D recovery-window labels apply.
+    pub roh_ceiling: Option<f32>,
+}
+
+/// Where a label's thresholds came from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ThresholdSource {
+    #[default]
+    Base,
+    /// `MorphixGuardConfig::overrides` entry for this state.
+    Override(CapabilityState),
+}
+
+/// The labelling thresholds in effect for one capability state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Thresholds {
+    pub decay_boundary_thresh: f32,
+    pub lifeforce_fair_floor: f32,
+    pub power_unfair_thresh: f32,
+    pub fear_overload_thresh: f32,
+    pub pain_overload_thresh: f32,
+    pub roh_ceiling: f32,
+    pub source: ThresholdSource,
+}
+
+/// RoH ceiling when no override sets one: the RoH model's 0.30 for
+/// `ControlledHuman`, and no ceiling (1.0) for the other tiers. [file:17]
+pub const fn default_roh_ceiling(state: CapabilityState) -> f32 {
+    RoH::ceiling_for(state)
+}
+
+impl Thresholds {
+    fn named(&self) -> [(&'static str, f32); 6] {
+        [
+            ("decay_boundary_thresh", self.decay_boundary_thresh),
+            ("lifeforce_fair_floor", self.lifeforce_fair_floor),
+            ("power_unfair_thresh", self.power_unfair_thresh),
+            ("fear_overload_thresh", self.fear_overload_thresh),
+            ("pain_overload_thresh", self.pain_overload_thresh),
+            ("roh_ceiling", self.roh_ceiling),
+        ]
+    }
+}
+
+/// One problem with a `MorphixGuardConfig`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigViolation {
+    NotANumber { field: &'static str },
+    OutOfRange { field: &'static str, value: f32 },
+    /// `lifeforce_fair_floor` above `decay_boundary_thresh`, which leaves
+    /// `D1Fair` unreachable.
+    FairFloorAboveDecayBoundary {
+        lifeforce_fair_floor: f32,
+        decay_boundary_thresh: f32,
+    },
+    /// A violation in the thresholds resolved for one override.
+    InOverride {
+        state: CapabilityState,
+        violation: Box<ConfigViolation>,
+    },
+}
+
+impl fmt::Display for ConfigViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotANumber { field } => write!(f, "{field} is NaN"),
+            Self::OutOfRange { field, value } => write!(f, "{field} = {value} is outside [0, 1]"),
+            Self::FairFloorAboveDecayBoundary {
+                lifeforce_fair_floor,
+                decay_boundary_thresh,
+            } => write!(
+                f,
+                "lifeforce_fair_floor {lifeforce_fair_floor} is above decay_boundary_thresh \
+                 {decay_boundary_thresh}, so D1Fair is unreachable"
+            ),
+            Self::InOverride { state, violation } => write!(f, "{state:?} override: {violation}"),
+        }
+    }
+}
+
+/// Why a guard config could not be used.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GuardConfigError {
+    /// The file could not be read.
+    Read(String),
+    /// The text is not valid TOML / JSON for a `MorphixGuardConfig`.
+    Parse(String),
+    /// Every violation found, in field order.
+    Invalid(Vec<ConfigViolation>),
+}
+
+impl fmt::Display for GuardConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read(e) | Self::Parse(e) => f.write_str(e),
+            Self::Invalid(violations) => {
+                f.write_str("invalid guard config:")?;
+                for (i, violation) in violations.iter().enumerate() {
+                    let sep = if i == 0 { " " } else { "; " };
+                    write!(f, "{sep}{violation}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for GuardConfigError {}
+
+impl MorphixGuardConfig {
+    /// Replace some thresholds for inputs in `state`.
+    pub fn with_override(mut self, state: CapabilityState, thresholds: PartialThresholds) -> Self {
+        self.overrides.insert(state, thresholds);
+        self
+    }
+
+    fn base_thresholds(&self, state: CapabilityState) -> Thresholds {
+        Thresholds {
+            decay_boundary_thresh: self.decay_boundary_thresh,
+            lifeforce_fair_floor: self.lifeforce_fair_floor,
+            power_unfair_thresh: self.power_unfair_thresh,
+            fear_overload_thresh: self.fear_overload_thresh,
+            pain_overload_thresh: self.pain_overload_thresh,
+            roh_ceiling: default_roh_ceiling(state),
+            source: ThresholdSource::Base,
+        }
+    }
+
+    /// Base thresholds with any override for `state` applied.
+    pub fn thresholds_for(&self, state: CapabilityState) -> Thresholds {
+        let base = self.base_thresholds(state);
+        let Some(o) = self.overrides.get(&state) else {
+            return base;
+        };
+        Thresholds {
+            decay_boundary_thresh: o.decay_boundary_thresh.unwrap_or(base.decay_boundary_thresh),
+            lifeforce_fair_floor: o.lifeforce_fair_floor.unwrap_or(base.lifeforce_fair_floor),
+            power_unfair_thresh: o.power_unfair_thresh.unwrap_or(base.power_unfair_thresh),
+            fear_overload_thresh: o.fear_overload_thresh.unwrap_or(base.fear_overload_thresh),
+            pain_overload_thresh: o.pain_overload_thresh.unwrap_or(base.pain_overload_thresh),
+            roh_ceiling: o.roh_ceiling.unwrap_or(base.roh_ceiling),
+            source: ThresholdSource::Override(state),
+        }
+    }
+
+    /// Reads a config file; `.json` files are parsed as JSON, anything else
+    /// as TOML.
+    #[cfg(feature = "config-files")]
+    pub fn load(path: &Path) -> Result<Self, GuardConfigError> {
+        let text = std::fs::read_to_string(path).map_err(|e| {
+            GuardConfigError::Read(format!("Cannot read guard config {}: {e}", path.display()))
+        })?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => Self::from_json_str(&text),
+            _ => Self::from_toml_str(&text),
+        }
+    }
+
+    #[cfg(feature = "config-files")]
+    pub fn from_toml_str(text: &str) -> Result<Self, GuardConfigError> {
+        let cfg: Self =
+            toml::from_str(text).map_err(|e| GuardConfigError::Parse(format!("Invalid TOML: {e}")))?;
+        cfg.validate()?;
+        Ok(cfg)
+    }
+
+    #[cfg(feature = "config-files")]
+    pub fn from_json_str(text: &str) -> Result<Self, GuardConfigError> {
+        let cfg: Self = serde_json::from_str(text)
+            .map_err(|e| GuardConfigError::Parse(format!("Invalid JSON: {e}")))?;
+        cfg.validate()?;
+        Ok(cfg)
+    }
+
+    /// Checks every threshold is a number in [0, 1] and that `D1Fair` stays
+    /// reachable, for the base values and every override, reporting all
+    /// violations at once.
+    pub fn validate(&self) -> Result<(), GuardConfigError> {
+        let trend = ("trend_slope_thresh", self.trend_slope_thresh);
+        let base = self.base_thresholds(CapabilityState::ControlledHuman);
+        let mut violations = check(&base, Some(trend));
+        let mut states: Vec<CapabilityState> = self.overrides.keys().copied().collect();
+        states.sort();
+        for state in states {
+            violations.extend(check(&self.thresholds_for(state), None).into_iter().map(|violation| {
+                ConfigViolation::InOverride {
+                    state,
+                    violation: Box::new(violation),
+                }
+            }));
+        }
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(GuardConfigError::Invalid(violations))
+        }
+    }
+}
+
+fn check(thresholds: &Thresholds, extra: Option<(&'static str, f32)>) -> Vec<ConfigViolation> {
+    let mut violations = Vec::new();
+    for (field, value) in thresholds.named().into_iter().chain(extra) {
+        if value.is_nan() {
+            violations.push(ConfigViolation::NotANumber { field });
+        } else if !(0.0..=1.0).contains(&value) {
+            violations.push(ConfigViolation::OutOfRange { field, value });
+        }
+    }
+    if thresholds.lifeforce_fair_floor > thresholds.decay_boundary_thresh {
+        violations.push(ConfigViolation::FairFloorAboveDecayBoundary {
+            lifeforce_fair_floor: thresholds.lifeforce_fair_floor,
+            decay_boundary_thresh: thresholds.decay_boundary_thresh,
+        });
+    }
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config_is_valid() {
+        assert_eq!(MorphixGuardConfig::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_every_violation_is_listed() {
+        let cfg = MorphixGuardConfig {
+            decay_boundary_thresh: 0.4,
+            power_unfair_thresh: f32::NAN,
+            fear_overload_thresh: 1.5,
+            ..MorphixGuardConfig::default()
+        };
+        let err = cfg.validate().unwrap_err();
+        assert_eq!(
+            err,
+            GuardConfigError::Invalid(vec![
+                ConfigViolation::NotANumber { field: "power_unfair_thresh" },
+                ConfigViolation::OutOfRange { field: "fear_overload_thresh", value: 1.5 },
+                ConfigViolation::FairFloorAboveDecayBoundary {
+                    lifeforce_fair_floor: 0.5,
+                    decay_boundary_thresh: 0.4,
+                },
+            ])
+        );
+        assert_eq!(
+            err.to_string(),
+            "invalid guard config: power_unfair_thresh is NaN; fear_overload_thresh = 1.5 is outside [0, 1]; \
+             lifeforce_fair_floor 0.5 is above decay_boundary_thresh 0.4, so D1Fair is unreachable"
+        );
+    }
+
+    #[test]
+    fn test_override_violations_name_their_state() {
+        let cfg = MorphixGuardConfig::default().with_override(
+            CapabilityState::ControlledHuman,
+            PartialThresholds {
+                fear_overload_thresh: Some(1.2),
+                lifeforce_fair_floor: Some(0.8),
+                ..PartialThresholds::default()
+            },
+        );
+        let err = cfg.validate().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid guard config: ControlledHuman override: fear_overload_thresh = 1.2 is outside [0, 1]; \
+             ControlledHuman override: lifeforce_fair_floor 0.8 is above decay_boundary_thresh 0.7, \
+             so D1Fair is unreachable"
+        );
+    }
+
+    #[cfg(feature = "config-files")]
+    const FIXTURE: &str = include_str!("../tests/fixtures/morphix_guard.toml");
+
+    #[cfg(feature = "config-files")]
+    #[test]
+    fn test_fixture_loads_in_both_formats() {
+        let cfg = MorphixGuardConfig::from_toml_str(FIXTURE).unwrap();
+        assert_eq!(cfg.decay_boundary_thresh, 0.65);
+        assert_eq!(cfg.trend_slope_thresh, 0.05);
+
+        let json = serde_json::to_string(&cfg).unwrap();
+        let back = MorphixGuardConfig::from_json_str(&json).unwrap();
+        assert_eq!(back.pain_overload_thresh, cfg.pain_overload_thresh);
+
+        let with_override = format!("{FIXTURE}\n[overrides.ControlledHuman]\nfear_overload_thresh = 0.5\n");
+        let cfg = MorphixGuardConfig::from_toml_str(&with_override).unwrap();
+        let human = cfg.thresholds_for(CapabilityState::ControlledHuman);
+        assert_eq!((human.fear_overload_thresh, human.pain_overload_thresh), (0.5, 0.6));
+        let json = serde_json::to_string(&cfg).unwrap();
+        assert_eq!(MorphixGuardConfig::from_json_str(&json).unwrap().overrides, cfg.overrides);
+        let typo = with_override.replace("fear_overload_thresh = 0.5", "fear_overlod_thresh = 0.5");
+        assert!(matches!(MorphixGuardConfig::from_toml_str(&typo), Err(GuardConfigError::Parse(_))));
+
+        // The trend threshold may be left out.
+        let without_trend = FIXTURE.replace("trend_slope_thresh = 0.05", "");
+        let cfg = MorphixGuardConfig::from_toml_str(&without_trend).unwrap();
+        assert_eq!(cfg.trend_slope_thresh, 0.02);
+    }
+
+    #[cfg(feature = "config-files")]
+    #[test]
+    fn test_malformed_fixtures_are_refused() {
+        let out_of_range = FIXTURE
+            .replace("power_unfair_thresh = 0.75", "power_unfair_thresh = -0.1")
+            .replace("lifeforce_fair_floor = 0.55", "lifeforce_fair_floor = 0.9");
+        match MorphixGuardConfig::from_toml_str(&out_of_range).unwrap_err() {
+            GuardConfigError::Invalid(violations) => assert_eq!(violations.len(), 2),
+            other => panic!("expected violations, got {other:?}"),
+        }
+
+        let nan = FIXTURE.replace("fear_overload_thresh = 0.6", "fear_overload_thresh = nan");
+        assert_eq!(
+            MorphixGuardConfig::from_toml_str(&nan).unwrap_err(),
+            GuardConfigError::Invalid(vec![ConfigViolation::NotANumber {
+                field: "fear_overload_thresh"
+            }])
+        );
+
+        let missing = FIXTURE.replace("pain_overload_thresh = 0.6", "");
+        assert!(matches!(
+            MorphixGuardConfig::from_toml_str(&missing),
+            Err(GuardConfigError::Parse(e)) if e.contains("pain_overload_thresh")
+        ));
+        assert!(matches!(
+            MorphixGuardConfig::from_json_str("{\"decay_boundary_thresh\": \"high\"}"),
+            Err(GuardConfigError::Parse(_))
+        ));
+        assert!(matches!(
+            MorphixGuardConfig::load(Path::new("no/such/guard.toml")),
+            Err(GuardConfigError::Read(_))
+        ));
+    }
+}
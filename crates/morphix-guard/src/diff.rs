@@ -0,0 +1,223 @@
+//! Epoch-to-epoch differences between two `MorphixGuardView`s, for reviewing
+//! .donutloop.aln logs without comparing JSON records by eye.
+
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{CapabilityState, MorphixGuardView, MorphixLabel};
+
+/// A capability-state transition between two views.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CapabilityChange {
+    pub from: CapabilityState,
+    pub to: CapabilityState,
+}
+
+/// A label present in both views whose severity changed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SeverityDelta {
+    pub label: MorphixLabel,
+    pub before: f32,
+    pub after: f32,
+}
+
+/// What changed from one view to the next; see `MorphixGuardView::diff`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GuardViewDiff {
+    /// Labels only in the newer view, in its order.
+    pub added: Vec<MorphixLabel>,
+    /// Labels only in the older view, in its order.
+    pub removed: Vec<MorphixLabel>,
+    pub roh_before: f32,
+    pub roh_after: f32,
+    pub capability: Option<CapabilityChange>,
+    /// Labels in both views, in the newer view's order.
+    pub severity: Vec<SeverityDelta>,
+}
+
+impl GuardViewDiff {
+    pub fn roh_delta(&self) -> f32 {
+        self.roh_after - self.roh_before
+    }
+
+    /// Whether the two views carry the same labels, severities, RoH and
+    /// capability state. Provenance and epoch indices are not compared.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.roh_before == self.roh_after
+            && self.capability.is_none()
+            && self.severity.is_empty()
+    }
+}
+
+impl MorphixGuardView {
+    /// Changes from `self` to `other`, typically epoch N to N+1.
+    pub fn diff(&self, other: &MorphixGuardView) -> GuardViewDiff {
+        let find = |view: &MorphixGuardView, label: &MorphixLabel| {
+            view.diagnostics
+                .iter()
+                .find(|d| d.label == *label)
+                .map(|d| d.severity)
+        };
+        let mut added = Vec::new();
+        let mut severity = Vec::new();
+        for d in &other.diagnostics {
+            match find(self, &d.label) {
+                None => added.push(d.label.clone()),
+                Some(before) if before != d.severity => severity.push(SeverityDelta {
+                    label: d.label.clone(),
+                    before,
+                    after: d.severity,
+                }),
+                Some(_) => {}
+            }
+        }
+        let removed = self
+            .diagnostics
+            .iter()
+            .filter(|d| find(other, &d.label).is_none())
+            .map(|d| d.label.clone())
+            .collect();
+        let capability =
+            (self.capability_state != other.capability_state).then_some(CapabilityChange {
+                from: self.capability_state,
+                to: other.capability_state,
+            });
+        GuardViewDiff {
+            added,
+            removed,
+            roh_before: self.roh_value,
+            roh_after: other.roh_value,
+            capability,
+            severity,
+        }
+    }
+}
+
+impl fmt::Display for GuardViewDiff {
+    /// e.g. "+D3OverloadRisk, -D1Fair, RoH 0.22→0.27"; "no change" when empty.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return f.write_str("no change");
+        }
+        let mut parts = Vec::new();
+        parts.extend(self.added.iter().map(|l| format!("+{}", name(l))));
+        parts.extend(self.removed.iter().map(|l| format!("-{}", name(l))));
+        if let Some(change) = self.capability {
+            parts.push(format!("{:?}→{:?}", change.from, change.to));
+        }
+        if self.roh_before != self.roh_after {
+            parts.push(format!("RoH {:.2}→{:.2}", self.roh_before, self.roh_after));
+        }
+        parts.extend(
+            self.severity
+                .iter()
+                .map(|s| format!("{} severity {:.2}→{:.2}", name(&s.label), s.before, s.after)),
+        );
+        f.write_str(&parts.join(", "))
+    }
+}
+
+fn name(label: &MorphixLabel) -> String {
+    match label {
+        MorphixLabel::Custom(name) => name.to_string(),
+        other => format!("{other:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GuardDimension, LabelProvenance, MorphixDiagnostic};
+
+    fn view(state: CapabilityState, roh: f32, labels: &[(MorphixLabel, f32)]) -> MorphixGuardView {
+        MorphixGuardView {
+            capability_state: state,
+            roh_value: roh,
+            evolve_index: None,
+            epoch_index: None,
+            diagnostics: labels
+                .iter()
+                .map(|(label, severity)| MorphixDiagnostic {
+                    label: label.clone(),
+                    provenance: LabelProvenance::fixed(GuardDimension::D1, "test", &[], &[]),
+                    severity: *severity,
+                    margin: 0.0,
+                })
+                .collect(),
+            config_version: None,
+        }
+    }
+
+    #[test]
+    fn test_identical_views_have_an_empty_diff() {
+        use MorphixLabel::*;
+        let a = view(
+            CapabilityState::LabBench,
+            0.22,
+            &[(D1Fair, 0.0), (D3OverloadRisk, 0.4)],
+        );
+        let diff = a.diff(&a.clone());
+        assert!(diff.is_empty());
+        assert_eq!(diff.roh_delta(), 0.0);
+        assert_eq!(diff.to_string(), "no change");
+    }
+
+    #[test]
+    fn test_diff_across_a_capability_transition() {
+        use CapabilityState::*;
+        use MorphixLabel::*;
+        let before = view(LabBench, 0.22, &[(D1Fair, 0.0), (D5BoundarySkimming, 0.2)]);
+        let after = view(
+            ControlledHuman,
+            0.27,
+            &[(D3OverloadRisk, 0.5), (D5BoundarySkimming, 0.35)],
+        );
+        let diff = before.diff(&after);
+        assert_eq!(diff.added, [D3OverloadRisk]);
+        assert_eq!(diff.removed, [D1Fair]);
+        assert_eq!(
+            diff.capability,
+            Some(CapabilityChange {
+                from: LabBench,
+                to: ControlledHuman
+            })
+        );
+        assert!((diff.roh_delta() - 0.05).abs() < 1e-6);
+        assert_eq!(diff.severity.len(), 1);
+        assert_eq!(diff.severity[0].label, D5BoundarySkimming);
+        assert_eq!(
+            diff.to_string(),
+            "+D3OverloadRisk, -D1Fair, LabBench→ControlledHuman, RoH 0.22→0.27, \
+             D5BoundarySkimming severity 0.20→0.35"
+        );
+
+        // Reversed, the diff mirrors.
+        let back = after.diff(&before);
+        assert_eq!(
+            (back.added, back.removed),
+            (vec![D1Fair], vec![D3OverloadRisk])
+        );
+        assert_eq!(back.capability.unwrap().to, LabBench);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_diff_serializes_to_json() {
+        use MorphixLabel::*;
+        let before = view(CapabilityState::GeneralUse, 0.22, &[(D1Fair, 0.0)]);
+        let after = view(CapabilityState::GeneralUse, 0.27, &[(D3OverloadRisk, 0.5)]);
+        let diff = before.diff(&after);
+        let json = serde_json::to_string(&diff).unwrap();
+        assert!(json.starts_with(r#"{"added":["D3OverloadRisk"],"removed":["D1Fair"]"#));
+        assert!(json.contains(r#""capability":null"#));
+        let back: GuardViewDiff = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, diff);
+    }
+}
@@ -0,0 +1,234 @@
+//! A single per-epoch fairness number for dashboards.
+//!
+//! Each risk diagnostic removes a share of what is left:
+//!
+//! ```text
+//! index = Π over risk diagnostics of (1 − weight · (floor + (1 − floor) · severity))
+//! ```
+//!
+//! Every factor lies in [0, 1], so the index stays in [0, 1] and adding a
+//! risk label can never raise it. Fair and calm labels contribute nothing;
+//! a view with no risk labels scores 1.0.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{unit, MorphixGuardView, MorphixLabel};
+
+/// Per-label weights for `MorphixGuardView::fairness_index`. Values are
+/// read as fractions and clamped to [0, 1]; fields missing from a config
+/// file keep their defaults.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct FairnessIndexWeights {
+    /// Default 0.5.
+    pub d1_unfair_drain_risk: f32,
+    /// Default 0.5.
+    pub d3_unfair_drain_risk: f32,
+    /// Default 0.6.
+    pub d3_overload_risk: f32,
+    /// Default 0.3: skimming is a warning rather than harm.
+    pub d5_boundary_skimming: f32,
+    /// Default 0.8: a confirmed drain weighs most.
+    pub d5_unfair_drain_confirmed: f32,
+    /// Default 0.6.
+    pub d5_overloaded_recovery_window: f32,
+    /// Default 0.25: missing envelope data means fairness cannot be vouched
+    /// for, even though `DataQuality` is not itself a risk.
+    pub data_quality: f32,
+    /// Any `MorphixLabel::Custom`. Default 0.5.
+    pub custom: f32,
+    /// Share of a label's weight charged at zero severity, so a present
+    /// label always costs something. Default 0.5.
+    pub severity_floor: f32,
+}
+
+impl Default for FairnessIndexWeights {
+    fn default() -> Self {
+        Self {
+            d1_unfair_drain_risk: 0.5,
+            d3_unfair_drain_risk: 0.5,
+            d3_overload_risk: 0.6,
+            d5_boundary_skimming: 0.3,
+            d5_unfair_drain_confirmed: 0.8,
+            d5_overloaded_recovery_window: 0.6,
+            data_quality: 0.25,
+            custom: 0.5,
+            severity_floor: 0.5,
+        }
+    }
+}
+
+impl FairnessIndexWeights {
+    /// The weight for `label`; 0 for the fair and calm labels.
+    pub fn weight(&self, label: &MorphixLabel) -> f32 {
+        let w = match label {
+            MorphixLabel::D1Fair | MorphixLabel::D3Fair | MorphixLabel::D5CalmStable => 0.0,
+            MorphixLabel::D1UnfairDrainRisk => self.d1_unfair_drain_risk,
+            MorphixLabel::D3UnfairDrainRisk => self.d3_unfair_drain_risk,
+            MorphixLabel::D3OverloadRisk => self.d3_overload_risk,
+            MorphixLabel::D5BoundarySkimming => self.d5_boundary_skimming,
+            MorphixLabel::D5UnfairDrainConfirmed => self.d5_unfair_drain_confirmed,
+            MorphixLabel::D5OverloadedRecoveryWindow => self.d5_overloaded_recovery_window,
+            MorphixLabel::DataQuality => self.data_quality,
+            MorphixLabel::Custom(_) => self.custom,
+        };
+        unit(w)
+    }
+}
+
+impl MorphixGuardView {
+    /// Fairness in [0, 1], 1.0 meaning fully fair and calm; see the module
+    /// docs for the formula. Adding a risk diagnostic, or raising the
+    /// severity of one, never increases the result.
+    pub fn fairness_index(&self, weights: &FairnessIndexWeights) -> f32 {
+        let floor = unit(weights.severity_floor);
+        self.diagnostics
+            .iter()
+            .map(|d| {
+                let charge = floor + (1.0 - floor) * unit(d.severity);
+                1.0 - weights.weight(&d.label) * charge
+            })
+            .product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CapabilityState, GuardDimension, LabelProvenance, MorphixDiagnostic};
+    use proptest::prelude::*;
+
+    fn diagnostic(label: MorphixLabel, severity: f32) -> MorphixDiagnostic {
+        MorphixDiagnostic {
+            label,
+            provenance: LabelProvenance::fixed(GuardDimension::D1, "test", &[], &[]),
+            severity,
+            margin: 0.0,
+        }
+    }
+
+    fn view(diagnostics: Vec<MorphixDiagnostic>) -> MorphixGuardView {
+        MorphixGuardView {
+            capability_state: CapabilityState::LabBench,
+            roh_value: 0.1,
+            evolve_index: None,
+            epoch_index: None,
+            diagnostics,
+            config_version: None,
+        }
+    }
+
+    const LABELS: [MorphixLabel; 10] = [
+        MorphixLabel::D1Fair,
+        MorphixLabel::D1UnfairDrainRisk,
+        MorphixLabel::D3Fair,
+        MorphixLabel::D3UnfairDrainRisk,
+        MorphixLabel::D3OverloadRisk,
+        MorphixLabel::D5CalmStable,
+        MorphixLabel::D5BoundarySkimming,
+        MorphixLabel::D5UnfairDrainConfirmed,
+        MorphixLabel::D5OverloadedRecoveryWindow,
+        MorphixLabel::DataQuality,
+    ];
+
+    fn label() -> impl Strategy<Value = MorphixLabel> {
+        prop_oneof![
+            (0..LABELS.len()).prop_map(|i| LABELS[i].clone()),
+            Just(MorphixLabel::Custom("SiteRule".into())),
+        ]
+    }
+
+    fn weights() -> impl Strategy<Value = FairnessIndexWeights> {
+        prop::array::uniform9(0.0f32..=1.0).prop_map(|w| FairnessIndexWeights {
+            d1_unfair_drain_risk: w[0],
+            d3_unfair_drain_risk: w[1],
+            d3_overload_risk: w[2],
+            d5_boundary_skimming: w[3],
+            d5_unfair_drain_confirmed: w[4],
+            d5_overloaded_recovery_window: w[5],
+            data_quality: w[6],
+            custom: w[7],
+            severity_floor: w[8],
+        })
+    }
+
+    #[test]
+    fn test_fair_view_scores_one_and_risks_lower_it() {
+        let w = FairnessIndexWeights::default();
+        let fair = view(vec![
+            diagnostic(MorphixLabel::D1Fair, 0.0),
+            diagnostic(MorphixLabel::D3Fair, 0.0),
+            diagnostic(MorphixLabel::D5CalmStable, 0.0),
+        ]);
+        assert_eq!(fair.fairness_index(&w), 1.0);
+        assert_eq!(view(Vec::new()).fairness_index(&w), 1.0);
+
+        // 1 − 0.5 · (0.5 + 0.5 · 0.4) = 0.65
+        let drain = view(vec![diagnostic(MorphixLabel::D1UnfairDrainRisk, 0.4)]);
+        assert!((drain.fairness_index(&w) - 0.65).abs() < 1e-6);
+
+        let confirmed = view(vec![
+            diagnostic(MorphixLabel::D1UnfairDrainRisk, 0.4),
+            diagnostic(MorphixLabel::D5UnfairDrainConfirmed, 1.0),
+        ]);
+        assert!((confirmed.fairness_index(&w) - 0.65 * 0.2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_out_of_range_weights_and_severities_are_clamped() {
+        let w = FairnessIndexWeights {
+            d3_overload_risk: 4.0,
+            severity_floor: f32::NAN,
+            ..FairnessIndexWeights::default()
+        };
+        let overloaded = view(vec![diagnostic(MorphixLabel::D3OverloadRisk, 2.0)]);
+        assert_eq!(overloaded.fairness_index(&w), 0.0);
+        let nan = view(vec![diagnostic(MorphixLabel::D3OverloadRisk, f32::NAN)]);
+        assert_eq!(nan.fairness_index(&w), 1.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_weights_load_from_partial_json() {
+        let w: FairnessIndexWeights =
+            serde_json::from_str(r#"{"d5_boundary_skimming":0.1,"custom":0.9}"#).unwrap();
+        assert_eq!(w.d5_boundary_skimming, 0.1);
+        assert_eq!(w.custom, 0.9);
+        assert_eq!(w.d3_overload_risk, 0.6);
+        assert!(serde_json::from_str::<FairnessIndexWeights>(r#"{"d1_fair":0.2}"#).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_adding_a_label_never_raises_the_index(
+            present in prop::collection::vec((label(), 0.0f32..=1.0), 0..8),
+            extra in (label(), 0.0f32..=1.0),
+            w in weights(),
+        ) {
+            let mut diagnostics: Vec<_> =
+                present.into_iter().map(|(l, s)| diagnostic(l, s)).collect();
+            let before = view(diagnostics.clone()).fairness_index(&w);
+            diagnostics.push(diagnostic(extra.0, extra.1));
+            let after = view(diagnostics).fairness_index(&w);
+            prop_assert!((0.0..=1.0).contains(&before));
+            prop_assert!((0.0..=1.0).contains(&after));
+            prop_assert!(after <= before, "{} > {}", after, before);
+        }
+
+        #[test]
+        fn test_raising_severity_never_raises_the_index(
+            present in prop::collection::vec((label(), 0.0f32..=1.0), 1..8),
+            bump in 0.0f32..=1.0,
+            w in weights(),
+        ) {
+            let diagnostics: Vec<_> =
+                present.into_iter().map(|(l, s)| diagnostic(l, s)).collect();
+            let before = view(diagnostics.clone()).fairness_index(&w);
+            let mut worse = diagnostics;
+            worse[0].severity = (worse[0].severity + bump).min(1.0);
+            prop_assert!(view(worse).fairness_index(&w) <= before);
+        }
+    }
+}
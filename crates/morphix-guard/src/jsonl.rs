@@ -0,0 +1,308 @@
+//! The canonical `.evolve.jsonl` line for a `MorphixGuardView`.
+//!
+//! One epoch record per line, following the `MORPHIX-GUARD-INTEGRATION`
+//! section of `morphix_guard.aln`:
+//!
+//! ```json
+//! {"v":1,"capability_state":"LabBench","roh_value":0.1,"evolve_index":null,
+//!  "epoch_index":7,"morphix_guard":{"diagnostics":[...],"config_version":null}}
+//! ```
+//!
+//! `v` is bumped whenever a field changes meaning or is removed; new fields
+//! may be added without a bump, and readers ignore fields they do not know.
+//! Everything here is string in, string out: writing and reading files
+//! stays with the sovereignty logging layer.
+
+use std::fmt;
+
+use core_contract::config_version::ConfigVersion;
+use serde::{Deserialize, Serialize};
+
+use crate::{CapabilityState, MorphixDiagnostic, MorphixGuardView};
+
+/// Schema version written by `to_evolve_line`, and the newest one
+/// `from_evolve_line` accepts.
+pub const EVOLVE_LINE_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct LineOut<'a> {
+    v: u32,
+    capability_state: CapabilityState,
+    roh_value: f32,
+    evolve_index: Option<u64>,
+    epoch_index: Option<u64>,
+    morphix_guard: GuardOut<'a>,
+}
+
+#[derive(Serialize)]
+struct GuardOut<'a> {
+    diagnostics: &'a [MorphixDiagnostic],
+    config_version: &'a Option<ConfigVersion>,
+}
+
+#[derive(Deserialize)]
+struct LineIn {
+    capability_state: CapabilityState,
+    roh_value: f32,
+    #[serde(default)]
+    evolve_index: Option<u64>,
+    #[serde(default)]
+    epoch_index: Option<u64>,
+    morphix_guard: GuardIn,
+}
+
+#[derive(Deserialize)]
+struct GuardIn {
+    diagnostics: Vec<MorphixDiagnostic>,
+    #[serde(default)]
+    config_version: Option<ConfigVersion>,
+}
+
+/// Read before the rest of the line, so a newer schema is reported as
+/// such rather than as whatever field it happens to break first.
+#[derive(Deserialize)]
+struct Version {
+    v: Option<u32>,
+}
+
+/// Why a line is not a guard epoch record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// Not JSON, or JSON missing or mistyping a required field.
+    Json(String),
+    /// No `v` field.
+    MissingVersion,
+    /// Written by a newer schema than this reader understands.
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(e) => write!(f, "invalid evolve line: {e}"),
+            Self::MissingVersion => f.write_str("evolve line has no \"v\" field"),
+            Self::UnsupportedVersion(v) => write!(
+                f,
+                "evolve line version {v} is newer than {EVOLVE_LINE_VERSION}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// `view` as one `.evolve.jsonl` line, without the trailing newline.
+pub fn to_evolve_line(view: &MorphixGuardView) -> String {
+    let line = LineOut {
+        v: EVOLVE_LINE_VERSION,
+        capability_state: view.capability_state,
+        roh_value: view.roh_value,
+        evolve_index: view.evolve_index,
+        epoch_index: view.epoch_index,
+        morphix_guard: GuardOut {
+            diagnostics: &view.diagnostics,
+            config_version: &view.config_version,
+        },
+    };
+    serde_json::to_string(&line).expect("guard views serialize to JSON")
+}
+
+/// Parses one line written by `to_evolve_line` at this or an older schema
+/// version. Unknown fields are ignored.
+pub fn from_evolve_line(line: &str) -> Result<MorphixGuardView, ParseError> {
+    let json = |e: serde_json::Error| ParseError::Json(e.to_string());
+    match serde_json::from_str::<Version>(line).map_err(json)?.v {
+        None => return Err(ParseError::MissingVersion),
+        Some(v) if v > EVOLVE_LINE_VERSION => return Err(ParseError::UnsupportedVersion(v)),
+        Some(_) => {}
+    }
+    let line: LineIn = serde_json::from_str(line).map_err(json)?;
+    Ok(MorphixGuardView {
+        capability_state: line.capability_state,
+        roh_value: line.roh_value,
+        evolve_index: line.evolve_index,
+        epoch_index: line.epoch_index,
+        diagnostics: line.morphix_guard.diagnostics,
+        config_version: line.morphix_guard.config_version,
+    })
+}
+
+/// A line `EvolveReader` could not parse. Lines are numbered from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedLine {
+    pub line: usize,
+    pub error: ParseError,
+}
+
+/// Yields the views in a sequence of lines, skipping blank lines and
+/// recording malformed ones in `skipped` instead of stopping.
+///
+/// ```
+/// use morphix_guard::jsonl::EvolveReader;
+///
+/// let mut reader = EvolveReader::from_text("not json\n\n");
+/// assert_eq!(reader.by_ref().count(), 0);
+/// assert_eq!(reader.skipped()[0].line, 1);
+/// ```
+pub struct EvolveReader<I> {
+    lines: I,
+    line: usize,
+    skipped: Vec<SkippedLine>,
+}
+
+impl<'a> EvolveReader<std::str::Lines<'a>> {
+    /// Reads the lines of `text`.
+    pub fn from_text(text: &'a str) -> Self {
+        Self::new(text.lines())
+    }
+}
+
+impl<I> EvolveReader<I> {
+    /// Reads `lines`, e.g. `BufRead::lines` mapped through `Result::ok`.
+    pub fn new<L>(lines: L) -> Self
+    where
+        L: IntoIterator<IntoIter = I>,
+    {
+        Self {
+            lines: lines.into_iter(),
+            line: 0,
+            skipped: Vec::new(),
+        }
+    }
+
+    /// Malformed lines seen so far, in order.
+    pub fn skipped(&self) -> &[SkippedLine] {
+        &self.skipped
+    }
+}
+
+impl<I, S> Iterator for EvolveReader<I>
+where
+    I: Iterator<Item = S>,
+    S: AsRef<str>,
+{
+    type Item = MorphixGuardView;
+
+    fn next(&mut self) -> Option<MorphixGuardView> {
+        for text in self.lines.by_ref() {
+            self.line += 1;
+            let text = text.as_ref().trim();
+            if text.is_empty() {
+                continue;
+            }
+            match from_evolve_line(text) {
+                Ok(view) => return Some(view),
+                Err(error) => self.skipped.push(SkippedLine {
+                    line: self.line,
+                    error,
+                }),
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        BiophysicalEnvelopeSnapshot, MicroSocietyView, MorphixGuard, MorphixGuardConfig,
+        MorphixGuardInput, MorphixLabel, RoH, TreeOfLifeView,
+    };
+
+    fn view(epoch: u64, decay: f32) -> MorphixGuardView {
+        let input = MorphixGuardInput {
+            capability_state: CapabilityState::ControlledHuman,
+            roh: RoH::new(0.12),
+            envelope: BiophysicalEnvelopeSnapshot {
+                eeg_alpha_frac: Some(0.5),
+                eeg_gamma_frac: Some(0.5),
+                eda_tonic_frac: Some(0.5),
+                bpm_frac: None,
+                cognitive_load_warn_frac: Some(0.1),
+                sleep_arousal_warn_frac: Some(0.1),
+                inflammation_warn_frac: Some(0.1),
+            },
+            tree_of_life: TreeOfLifeView {
+                blood: 0.5, oxygen: 0.5, wave: 0.5, h2o: 0.5, time: 0.5,
+                decay, lifeforce: 0.8,
+                brain: 0.5, smart: 0.5, evolve: 0.5, power: 0.3, tech: 0.5,
+                fear: 0.2, pain: 0.2, nano: 0.5,
+            },
+            micro_society: MicroSocietyView { predicates: Vec::new() },
+            evolve_index: Some(3),
+            epoch_index: Some(epoch),
+        };
+        MorphixGuard::evaluate(&input, &MorphixGuardConfig::default())
+    }
+
+    #[test]
+    fn test_lines_round_trip() {
+        let original = view(7, 0.75);
+        let line = to_evolve_line(&original);
+        assert!(line.starts_with(r#"{"v":1,"capability_state":"ControlledHuman","roh_value":0.12"#));
+        assert!(!line.contains('\n'));
+
+        let back = from_evolve_line(&line).unwrap();
+        assert_eq!(back.epoch_index, Some(7));
+        assert_eq!(back.evolve_index, Some(3));
+        let labels: Vec<_> = back.diagnostics.iter().map(|d| d.label.clone()).collect();
+        assert!(labels.contains(&MorphixLabel::D1UnfairDrainRisk));
+        assert!(labels.contains(&MorphixLabel::DataQuality));
+        assert_eq!(to_evolve_line(&back), line);
+    }
+
+    #[test]
+    fn test_unknown_fields_are_ignored_and_newer_versions_refused() {
+        let line = to_evolve_line(&view(1, 0.2));
+        let extended = line
+            .replacen('{', r#"{"operator_note":"shift B","#, 1)
+            .replacen(
+                r#""morphix_guard":{"#,
+                r#""morphix_guard":{"fairness_index":0.9,"#,
+                1,
+            );
+        let back = from_evolve_line(&extended).unwrap();
+        assert_eq!(to_evolve_line(&back), line);
+
+        let v2 = line.replacen(r#""v":1"#, r#""v":2"#, 1);
+        assert_eq!(
+            from_evolve_line(&v2).unwrap_err(),
+            ParseError::UnsupportedVersion(2)
+        );
+        let unversioned = line.replacen(r#""v":1,"#, "", 1);
+        assert_eq!(
+            from_evolve_line(&unversioned).unwrap_err(),
+            ParseError::MissingVersion
+        );
+    }
+
+    #[test]
+    fn test_reader_skips_and_reports_malformed_lines() {
+        let text = [
+            to_evolve_line(&view(1, 0.2)),
+            String::new(),
+            "{\"v\":1,\"capability_state\":\"LabBench\"".to_string(),
+            to_evolve_line(&view(2, 0.75)),
+            r#"{"v":9}"#.to_string(),
+            "   ".to_string(),
+            to_evolve_line(&view(3, 0.2)),
+        ]
+        .join("\n");
+        let mut reader = EvolveReader::from_text(&text);
+        let epochs: Vec<_> = reader.by_ref().map(|v| v.epoch_index).collect();
+        assert_eq!(epochs, [Some(1), Some(2), Some(3)]);
+
+        let skipped = reader.skipped();
+        assert_eq!(
+            skipped.iter().map(|s| s.line).collect::<Vec<_>>(),
+            [3, 5]
+        );
+        assert!(matches!(skipped[0].error, ParseError::Json(_)));
+        assert_eq!(skipped[1].error, ParseError::UnsupportedVersion(9));
+        assert!(skipped[0].error.to_string().starts_with("invalid evolve line"));
+
+        let owned = EvolveReader::new(text.lines().map(String::from));
+        assert_eq!(owned.count(), 3);
+    }
+}
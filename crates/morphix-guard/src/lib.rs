@@ -0,0 +1,1026 @@
+//! Morphix Guard: fairness–safety labelling observer
+//!
+//! Role:
+//! - Pure, non-actuating diagnostics over CapabilityState, RoH,
+//!   BiophysicalEnvelopeSnapshot, TreeOfLifeView, and MicroSociety predicates.
+//! - Computes 1D–5D fairness/safety labels with explicit provenance.
+//! - Emits only structured diagnostics for logging into .evolve.jsonl and
+//!   .donutloop.aln via the existing sovereignty logging layer.
+//! - Never mutates CapabilityState, consent, envelopes, ALN shards, or devices.
+//!
+//! This module is intended for integration as a Pattern I, read-only observer
+//! (Tree-of-Life / Neuroprint! style) in the NewRow-Print! / OrganicCPU stack. [file:14][file:10]
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use core_contract::config_version::ConfigVersion;
+
+mod batch;
+pub use batch::{GuardViewStream, DEFAULT_STREAM_CHUNK};
+
+mod config;
+pub use config::{
+    default_roh_ceiling, ConfigViolation, GuardConfigError, PartialThresholds, ThresholdSource, Thresholds,
+};
+
+mod diff;
+pub use diff::{CapabilityChange, GuardViewDiff, SeverityDelta};
+
+mod index;
+pub use index::FairnessIndexWeights;
+
+#[cfg(feature = "jsonl")]
+pub mod jsonl;
+
+mod quality;
+pub use quality::{Determinacy, EnvelopeAxes, EnvelopeAxis};
+
+mod report;
+pub use report::{CapabilityBreakdown, LabelStats, MorphixGuardReport};
+
+mod ruleset;
+pub use ruleset::{GuardRule, MorphixGuardRuleset};
+
+mod session;
+pub use session::{MorphixGuardSession, DEFAULT_HYSTERESIS};
+
+mod window;
+pub use window::{FieldTrend, MorphixGuardTrendView, MorphixTrendDiagnostic, MorphixTrendLabel};
+
+/// Capability tiers; shared with the other RoH consumers through core-contract. [file:17]
+pub use core_contract::roh::CapabilityState;
+
+/// MicroSociety predicates: CALM_STABLE, UNFAIR_DRAIN, etc., as computed by
+/// upstream NATURE / metabolic-doctrine layers from TREE and envelope histories. [file:10]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MicroSocietyPredicate {
+    CalmStable,
+    Overloaded,
+    UnfairDrain,
+    Recovery,
+    BoundarySkimming,
+    /// Upstream predicate without a dedicated variant; static names borrow,
+    /// deserialized names own.
+    Other(Cow<'static, str>),
+}
+
+/// Risk-of-Harm score scalar, already governed by .rohmodel.aln
+/// (monotone, RoH_after >= RoH_before, RoH <= 0.30 in CapControlledHuman). [file:17]
+///
+/// The shared `core_contract::roh::RoH` at the guard's `f32` precision.
+pub type RoH = core_contract::roh::RoH<f32>;
+
+/// BiophysicalEnvelopeSnapshot: flattened, read-only snapshot derived from
+/// BiophysicalEnvelopeSpec axes at a single epoch. [file:14][file:17]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BiophysicalEnvelopeSnapshot {
+    // Normalized envelope fractions 0.0–1.0 for key axes, None when the axis
+    // is unavailable (never stand in 0.0 for a missing reading).
+    // These are projections of the ALN EnvelopeAxis states; no device access. [file:17]
+    pub eeg_alpha_frac: Option<f32>,
+    pub eeg_gamma_frac: Option<f32>,
+    pub eda_tonic_frac: Option<f32>,
+    pub bpm_frac: Option<f32>,
+    pub cognitive_load_warn_frac: Option<f32>,
+    pub sleep_arousal_warn_frac: Option<f32>,
+    pub inflammation_warn_frac: Option<f32>,
+}
+
+/// TreeOfLifeView: the 14–15 TREE asset scalars defined in Tree-of-Life.rs. [file:14]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TreeOfLifeView {
+    pub blood: f32,
+    pub oxygen: f32,
+    pub wave: f32,
+    pub h2o: f32,
+    pub time: f32,
+    pub decay: f32,
+    pub lifeforce: f32,
+    pub brain: f32,
+    pub smart: f32,
+    pub evolve: f32,
+    pub power: f32,
+    pub tech: f32,
+    pub fear: f32,
+    pub pain: f32,
+    pub nano: f32,
+}
+
+/// Minimal MicroSociety view: predicates already computed upstream from
+/// multi-subject / multi-role histories (e.g., CALM_STABLE, UNFAIR_DRAIN). [file:10]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MicroSocietyView {
+    pub predicates: Vec<MicroSocietyPredicate>,
+}
+
+/// Input snapshot for MorphixGuard: single, per-epoch diagnostic view. [file:14][file:10]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MorphixGuardInput {
+    pub capability_state: CapabilityState,
+    pub roh: RoH,
+    pub envelope: BiophysicalEnvelopeSnapshot,
+    pub tree_of_life: TreeOfLifeView,
+    pub micro_society: MicroSocietyView,
+
+    // Optional provenance indices to line up with .evolve.jsonl / .donutloop.aln. [file:14][file:17]
+    pub evolve_index: Option<u64>,
+    pub epoch_index: Option<u64>,
+}
+
+/// 1D–5D fairness–safety label primitives.
+/// These are purely diagnostic categories; they carry no policy semantics. [file:10]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GuardDimension {
+    D1,
+    D2,
+    D3,
+    D4,
+    D5,
+}
+
+/// Core label enumeration, structured for explicit provenance. [file:10]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MorphixLabel {
+    /// 1D scalar: overall fair / within norms.
+    D1Fair,
+    /// 1D scalar: overall unfair / draining.
+    D1UnfairDrainRisk,
+
+    /// 3D composite views over DECAY, LIFEFORCE, and POWER. [file:10]
+    D3Fair, // e.g., balanced, low DECAY, adequate LIFEFORCE, non-exploitative POWER.
+    D3UnfairDrainRisk,
+    D3OverloadRisk,
+
+    /// 5D views DECAY, LIFEFORCE, POWER, FEAR, PAIN / NATURE tokens. [file:10]
+    D5BoundarySkimming,
+    D5CalmStable,
+    D5UnfairDrainConfirmed,
+    D5OverloadedRecoveryWindow,
+
+    /// Envelope axes missing or degraded this epoch; labels depending on
+    /// them are marked indeterminate.
+    DataQuality,
+
+    /// Site-specific label from a registered `GuardRule`.
+    Custom(Cow<'static, str>),
+}
+
+impl MorphixLabel {
+    /// Whether the label flags a risk; the fair and calm labels and
+    /// `DataQuality` do not. Custom labels count as risks.
+    pub const fn is_risk(&self) -> bool {
+        !matches!(self, Self::D1Fair | Self::D3Fair | Self::D5CalmStable | Self::DataQuality)
+    }
+}
+
+/// Provenance descriptor linking a label back to its source fields and shards. [file:14][file:10]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LabelProvenance {
+    /// Dimensions used (1D–5D).
+    pub dimension: GuardDimension,
+    /// Human-readable explanation surface.
+    pub explanation: Cow<'static, str>,
+    /// Source assets / signals that contributed to the label.
+    pub sources: Cow<'static, [Cow<'static, str>]>,
+    /// Shard references (ALN / spec names) that define the semantics. [file:14][file:17]
+    pub shard_refs: Cow<'static, [Cow<'static, str>]>,
+    /// The comparisons that decided the label, stored inline so the hot path
+    /// stays allocation-free; `Display` renders them after the explanation.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub evidence: [Option<Evidence>; MAX_EVIDENCE],
+    /// Whether the evidence thresholds came from the base config or a
+    /// capability-state override.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub thresholds: ThresholdSource,
+    /// `Indeterminate` when envelope axes the label depends on were missing.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub determinacy: Determinacy,
+}
+
+/// Most comparisons behind a single label.
+pub const MAX_EVIDENCE: usize = 3;
+
+/// One input value and the threshold it was compared against.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Evidence {
+    /// Input field, e.g. "lifeforce".
+    pub field: Cow<'static, str>,
+    pub value: f32,
+    /// Threshold name, e.g. "floor".
+    pub bound: Cow<'static, str>,
+    pub threshold: f32,
+}
+
+impl Evidence {
+    pub const fn new(field: &'static str, value: f32, bound: &'static str, threshold: f32) -> Self {
+        Self {
+            field: Cow::Borrowed(field),
+            value,
+            bound: Cow::Borrowed(bound),
+            threshold,
+        }
+    }
+}
+
+impl fmt::Display for Evidence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={:.2} vs {}={:.2}", self.field, self.value, self.bound, self.threshold)
+    }
+}
+
+impl LabelProvenance {
+    /// Provenance built from interned strings; constructing it never allocates.
+    pub const fn fixed(
+        dimension: GuardDimension,
+        explanation: &'static str,
+        sources: Names,
+        shard_refs: Names,
+    ) -> Self {
+        Self {
+            dimension,
+            explanation: Cow::Borrowed(explanation),
+            sources: Cow::Borrowed(sources),
+            shard_refs: Cow::Borrowed(shard_refs),
+            evidence: [None, None, None],
+            thresholds: ThresholdSource::Base,
+            determinacy: Determinacy::Determinate,
+        }
+    }
+
+    pub fn with_evidence(mut self, evidence: [Option<Evidence>; MAX_EVIDENCE]) -> Self {
+        self.evidence = evidence;
+        self
+    }
+
+    /// The recorded comparisons, in the order the rule checks them.
+    pub fn evidence(&self) -> impl Iterator<Item = &Evidence> {
+        self.evidence.iter().flatten()
+    }
+}
+
+impl fmt::Display for LabelProvenance {
+    /// The explanation followed by its numeric evidence, e.g.
+    /// "… (lifeforce=0.05 vs floor=0.50)".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.explanation)?;
+        for (i, evidence) in self.evidence().enumerate() {
+            let sep = if i == 0 { " (" } else { ", " };
+            write!(f, "{sep}{evidence}")?;
+        }
+        if self.evidence().next().is_some() {
+            f.write_str(")")?;
+        }
+        if let ThresholdSource::Override(state) = self.thresholds {
+            write!(f, " [{state:?} override]")?;
+        }
+        if let Determinacy::Indeterminate { missing } = self.determinacy {
+            write!(f, " [indeterminate: missing {missing}]")?;
+        }
+        Ok(())
+    }
+}
+
+/// Interned source and shard-ref names used by the built-in labels.
+const fn s(name: &'static str) -> Cow<'static, str> {
+    Cow::Borrowed(name)
+}
+
+const SRC_LIFEFORCE: Cow<'static, str> = s("TreeOfLifeView.lifeforce");
+const SRC_DECAY: Cow<'static, str> = s("TreeOfLifeView.decay");
+const SRC_POWER: Cow<'static, str> = s("TreeOfLifeView.power");
+const SRC_FEAR: Cow<'static, str> = s("TreeOfLifeView.fear");
+const SRC_PAIN: Cow<'static, str> = s("TreeOfLifeView.pain");
+const SRC_ROH: Cow<'static, str> = s("RoH.value");
+
+const SHARD_LIFEFORCE: Cow<'static, str> = s("Tree-of-Life.md/TREE-LIFEFORCE");
+const SHARD_DECAY: Cow<'static, str> = s("Tree-of-Life.md/TREE-DECAY");
+const SHARD_POWER: Cow<'static, str> = s("Tree-of-Life.md/TREE-POWER");
+const SHARD_FEAR: Cow<'static, str> = s("Tree-of-Life.md/TREE-FEAR");
+const SHARD_PAIN: Cow<'static, str> = s("Tree-of-Life.md/TREE-PAIN");
+const SHARD_ROH: Cow<'static, str> = s(".rohmodel.aln");
+
+type Names = &'static [Cow<'static, str>];
+
+const D1_SOURCES: Names = &[SRC_LIFEFORCE, SRC_DECAY, SRC_ROH];
+const D1_SHARDS: Names = &[SHARD_LIFEFORCE, SHARD_DECAY, SHARD_ROH];
+const D3_ENERGY_SOURCES: Names = &[SRC_DECAY, SRC_LIFEFORCE, SRC_POWER];
+const D3_FAIR_SHARDS: Names = &[SHARD_DECAY, SHARD_LIFEFORCE, SHARD_POWER];
+const D3_DRAIN_SHARDS: Names = &[
+    SHARD_DECAY,
+    SHARD_LIFEFORCE,
+    SHARD_POWER,
+    s("MetabolicDoctrine.* (UNFAIR_DRAIN)"),
+];
+const D3_OVERLOAD_SOURCES: Names = &[SRC_DECAY, SRC_ROH];
+const D3_OVERLOAD_SHARDS: Names = &[SHARD_ROH, s("BiophysicalEnvelopeSpec/*-overload")];
+const D5_CALM_SOURCES: Names = &[s("MicroSociety.CALM_STABLE"), SRC_DECAY, SRC_FEAR, SRC_PAIN];
+const D5_CALM_SHARDS: Names = &[
+    s("MetabolicDoctrine.NATURE/CALM_STABLE"),
+    SHARD_DECAY,
+    SHARD_FEAR,
+    SHARD_PAIN,
+];
+const D5_BOUNDARY_SOURCES: Names = &[s("MicroSociety.BOUNDARY_SKIMMING"), SRC_DECAY, SRC_ROH];
+const D5_BOUNDARY_SHARDS: Names = &[
+    s("MetabolicDoctrine.NATURE/BOUNDARY_SKIMMING"),
+    SHARD_ROH,
+    s("BiophysicalEnvelopeSpec/*-warn"),
+];
+const D5_DRAIN_SOURCES: Names = &[s("MicroSociety.UNFAIR_DRAIN"), SRC_LIFEFORCE, SRC_POWER];
+const D5_DRAIN_SHARDS: Names = &[s("MetabolicDoctrine.UnfairDrain"), SHARD_LIFEFORCE, SHARD_POWER];
+const D5_OVERLOAD_SOURCES: Names = &[s("MicroSociety.OVERLOADED"), SRC_FEAR, SRC_PAIN, SRC_ROH];
+const D5_OVERLOAD_SHARDS: Names = &[s("MetabolicDoctrine.Overloaded"), SHARD_FEAR, SHARD_PAIN, SHARD_ROH];
+const DATA_QUALITY_SOURCES: Names = &[s("BiophysicalEnvelopeSnapshot")];
+const DATA_QUALITY_SHARDS: Names = &[s("BiophysicalEnvelopeSpec")];
+
+/// A single diagnostic label + provenance bundle. Purely advisory. [file:10]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MorphixDiagnostic {
+    pub label: MorphixLabel,
+    pub provenance: LabelProvenance,
+    /// How deep into risk the inputs are, in [0, 1]; 0 for the fair and
+    /// calm labels. Each comparison is scored by its distance past the
+    /// threshold as a fraction of the room on that side of it:
+    /// - `D1UnfairDrainRisk`: the larger of lifeforce below floor and decay
+    ///   above boundary.
+    /// - `D3UnfairDrainRisk`, `D5UnfairDrainConfirmed`: the mean of power
+    ///   above its unfair threshold and lifeforce below floor.
+    /// - `D3OverloadRisk`: RoH above the decay boundary.
+    /// - `D5BoundarySkimming`: decay above boundary.
+    /// - `D5OverloadedRecoveryWindow`: the larger of fear and pain above
+    ///   their overload thresholds.
+    /// - `DataQuality`: the share of envelope axes missing.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub severity: f32,
+    /// How far the label is from flipping, in [0, 1]: the smallest distance
+    /// between a required comparison and its threshold, where either-or
+    /// comparisons (lifeforce or decay, fear or pain) count by the larger.
+    /// Near 0 means the next epoch may well be labelled differently; always
+    /// 0 for indeterminate labels and `DataQuality`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub margin: f32,
+}
+
+/// Aggregate diagnostics for one epoch / snapshot, ready for logging. [file:14]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MorphixGuardView {
+    /// Echo of input provenance to align with .evolve.jsonl / .donutloop.aln. [file:14][file:17]
+    pub capability_state: CapabilityState,
+    pub roh_value: f32,
+    pub evolve_index: Option<u64>,
+    pub epoch_index: Option<u64>,
+
+    /// Primary fairness–safety diagnostics.
+    pub diagnostics: Vec<MorphixDiagnostic>,
+
+    /// Version of the config bundle the thresholds were loaded from, if known.
+    pub config_version: Option<ConfigVersion>,
+}
+
+/// Configuration: thresholds for advisory labels only.
+/// Loaded and owned by the sovereignty core; MorphixGuard only reads it. [file:14][file:10]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MorphixGuardConfig {
+    /// DECAY threshold beyond which we consider boundary skimming. [file:10]
+    pub decay_boundary_thresh: f32,
+    /// LIFEFORCE floor for fair / calm-stable states. [file:10]
+    pub lifeforce_fair_floor: f32,
+    /// POWER fraction above which we consider potential unfair drain. [file:10]
+    pub power_unfair_thresh: f32,
+    /// FEAR / PAIN thresholds for overload risk. [file:10]
+    pub fear_overload_thresh: f32,
+    pub pain_overload_thresh: f32,
+    /// Per-tier replacements for the five thresholds above and the RoH
+    /// ceiling; fields left unset fall back to the base values (for the
+    /// ceiling, `default_roh_ceiling`).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub overrides: HashMap<CapabilityState, PartialThresholds>,
+    /// Per-epoch slope beyond which `evaluate_window` reports a trend.
+    #[cfg_attr(feature = "serde", serde(default = "default_trend_slope_thresh"))]
+    pub trend_slope_thresh: f32,
+
+    /// Set by the loader; echoed into every MorphixGuardView.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub config_version: Option<ConfigVersion>,
+}
+
+impl Default for MorphixGuardConfig {
+    /// Conservative defaults; concrete values should be documented in
+    /// morphix_guard.aln and aligned with existing envelope / RoH shards. [file:14][file:17]
+    fn default() -> Self {
+        Self {
+            decay_boundary_thresh: 0.70,
+            lifeforce_fair_floor: 0.50,
+            power_unfair_thresh: 0.70,
+            fear_overload_thresh: 0.60,
+            pain_overload_thresh: 0.60,
+            overrides: HashMap::new(),
+            trend_slope_thresh: default_trend_slope_thresh(),
+            config_version: None,
+        }
+    }
+}
+
+fn default_trend_slope_thresh() -> f32 {
+    0.02
+}
+
+/// Distance of `value` above `threshold` as a fraction of the room above it,
+/// in [0, 1] (0 when not above, and for NaN).
+fn above(value: f32, threshold: f32) -> f32 {
+    unit((value - threshold) / (1.0 - threshold).max(f32::EPSILON))
+}
+
+/// Distance of `value` below `threshold` as a fraction of the room below it.
+fn below(value: f32, threshold: f32) -> f32 {
+    unit((threshold - value) / threshold.max(f32::EPSILON))
+}
+
+fn unit(x: f32) -> f32 {
+    if x.is_nan() {
+        0.0
+    } else {
+        x.clamp(0.0, 1.0)
+    }
+}
+
+/// Upper bound on diagnostics per evaluation: one 1D, at most one 3D, and
+/// up to four 5D labels, plus `DataQuality`.
+pub const MAX_DIAGNOSTICS: usize = 7;
+
+/// MorphixGuard: namespace struct with pure, associated functions only.
+/// No internal state, no actuation, no IO, no kernel calls. [file:14][file:10]
+pub struct MorphixGuard;
+
+impl MorphixGuard {
+    /// Core entry: compute pure fairness–safety diagnostics for a single snapshot. [file:10]
+    ///
+    /// This function:
+    /// - Reads governed inputs only (CapabilityState, RoH, envelopes, TreeOfLife, MicroSociety).
+    /// - Computes 1D–5D labels based on simple, documented rules.
+    /// - Returns a MorphixGuardView suitable for serialization into .evolve.jsonl /
+    ///   .donutloop.aln by an external logging layer. [file:14][file:17]
+    ///
+    /// It MUST NOT:
+    /// - Write files, open sockets, call driver APIs.
+    /// - Mutate CapabilityState, consent, envelopes, or ALN shards.
+    /// - Trigger CapabilityTransitionRequest, ReversalConditions, or PolicyStack paths. [file:10]
+    pub fn evaluate(input: &MorphixGuardInput, cfg: &MorphixGuardConfig) -> MorphixGuardView {
+        let mut view = MorphixGuardView {
+            capability_state: input.capability_state,
+            roh_value: 0.0,
+            evolve_index: None,
+            epoch_index: None,
+            diagnostics: Vec::with_capacity(MAX_DIAGNOSTICS),
+            config_version: None,
+        };
+        Self::evaluate_into(input, cfg, &mut view);
+        view
+    }
+
+    /// Same diagnostics as `evaluate`, written into a caller-owned view.
+    ///
+    /// The diagnostics buffer is cleared and refilled in place, provenance is
+    /// interned, and the config version is only cloned when it changes, so a
+    /// view reused across epochs causes no heap allocation.
+    pub fn evaluate_into(
+        input: &MorphixGuardInput,
+        cfg: &MorphixGuardConfig,
+        view: &mut MorphixGuardView,
+    ) {
+        let diagnostics = &mut view.diagnostics;
+        diagnostics.clear();
+        diagnostics.reserve(MAX_DIAGNOSTICS);
+
+        let t = &input.tree_of_life;
+        // Already in [0, 1]: every RoH constructor clamps.
+        let roh = input.roh.value();
+
+        // Thresholds for this capability tier; a HashMap lookup, no allocation.
+        let th = cfg.thresholds_for(input.capability_state);
+        let floor = Evidence::new("lifeforce", t.lifeforce, "floor", th.lifeforce_fair_floor);
+        let boundary = Evidence::new("decay", t.decay, "boundary", th.decay_boundary_thresh);
+        let unfair = Evidence::new("power", t.power, "unfair", th.power_unfair_thresh);
+        let lifeforce_above = above(t.lifeforce, th.lifeforce_fair_floor);
+        let lifeforce_below = below(t.lifeforce, th.lifeforce_fair_floor);
+        let decay_above = above(t.decay, th.decay_boundary_thresh);
+        let decay_below = below(t.decay, th.decay_boundary_thresh);
+        let power_above = above(t.power, th.power_unfair_thresh);
+        let power_below = below(t.power, th.power_unfair_thresh);
+
+        // 1D fairness view: simple scalar check using LIFEFORCE vs DECAY & RoH. [file:10]
+        if t.lifeforce >= th.lifeforce_fair_floor && t.decay < th.decay_boundary_thresh {
+            diagnostics.push(MorphixDiagnostic {
+                label: MorphixLabel::D1Fair,
+                provenance: LabelProvenance::fixed(
+                    GuardDimension::D1,
+                    "Overall fair: lifeforce above floor and decay below boundary threshold.",
+                    D1_SOURCES,
+                    D1_SHARDS,
+                )
+                .with_evidence([Some(floor.clone()), Some(boundary.clone()), None]),
+                severity: 0.0,
+                margin: lifeforce_above.min(decay_below),
+            });
+        } else {
+            let depth = lifeforce_below.max(decay_above);
+            diagnostics.push(MorphixDiagnostic {
+                label: MorphixLabel::D1UnfairDrainRisk,
+                provenance: LabelProvenance::fixed(
+                    GuardDimension::D1,
+                    "Elevated unfair-drain risk: lifeforce depleted or decay near boundary.",
+                    D1_SOURCES,
+                    D1_SHARDS,
+                )
+                .with_evidence([Some(floor.clone()), Some(boundary.clone()), None]),
+                severity: depth,
+                margin: depth,
+            });
+        }
+
+        // 3D fairness view over DECAY, LIFEFORCE, POWER. [file:10]
+        if t.decay < th.decay_boundary_thresh
+            && t.lifeforce >= th.lifeforce_fair_floor
+            && t.power < th.power_unfair_thresh
+        {
+            diagnostics.push(MorphixDiagnostic {
+                label: MorphixLabel::D3Fair,
+                provenance: LabelProvenance::fixed(
+                    GuardDimension::D3,
+                    "3D fair energy budget: decay low, lifeforce adequate, power below unfair threshold.",
+                    D3_ENERGY_SOURCES,
+                    D3_FAIR_SHARDS,
+                )
+                .with_evidence([Some(boundary.clone()), Some(floor.clone()), Some(unfair.clone())]),
+                severity: 0.0,
+                margin: decay_below.min(lifeforce_above).min(power_below),
+            });
+        } else if t.power >= th.power_unfair_thresh && t.lifeforce < th.lifeforce_fair_floor {
+            diagnostics.push(MorphixDiagnostic {
+                label: MorphixLabel::D3UnfairDrainRisk,
+                provenance: LabelProvenance::fixed(
+                    GuardDimension::D3,
+                    "3D unfair-drain risk: power high while lifeforce is depleted under elevated decay.",
+                    D3_ENERGY_SOURCES,
+                    D3_DRAIN_SHARDS,
+                )
+                .with_evidence([Some(unfair.clone()), Some(floor.clone()), None]),
+                severity: (power_above + lifeforce_below) / 2.0,
+                margin: power_above.min(lifeforce_below),
+            });
+        } else if roh >= th.decay_boundary_thresh {
+            let depth = above(roh, th.decay_boundary_thresh);
+            diagnostics.push(MorphixDiagnostic {
+                label: MorphixLabel::D3OverloadRisk,
+                provenance: LabelProvenance::fixed(
+                    GuardDimension::D3,
+                    "3D overload risk: RoH and decay near boundary; consider cooldown in analysis.",
+                    D3_OVERLOAD_SOURCES,
+                    D3_OVERLOAD_SHARDS,
+                )
+                .with_evidence([
+                    Some(Evidence::new("roh", roh, "boundary", th.decay_boundary_thresh)),
+                    None,
+                    None,
+                ]),
+                severity: depth,
+                margin: depth,
+            });
+        }
+
+        // 5D view including FEAR and PAIN plus NATURE predicates. [file:10]
+        let has_calm = input
+            .micro_society
+            .predicates
+            .contains(&MicroSocietyPredicate::CalmStable);
+        let has_unfair = input
+            .micro_society
+            .predicates
+            .contains(&MicroSocietyPredicate::UnfairDrain);
+        let has_overload = input
+            .micro_society
+            .predicates
+            .contains(&MicroSocietyPredicate::Overloaded);
+        let has_boundary = input
+            .micro_society
+            .predicates
+            .contains(&MicroSocietyPredicate::BoundarySkimming);
+        let fear = Evidence::new("fear", t.fear, "overload", th.fear_overload_thresh);
+        // The 5D windows require RoH below the ceiling for this capability tier.
+        let ceiling = Evidence::new("roh", roh, "ceiling", th.roh_ceiling);
+        let roh_below = below(roh, th.roh_ceiling);
+
+        // Calm-stable.
+        if has_calm && t.decay < th.decay_boundary_thresh && t.fear < th.fear_overload_thresh {
+            diagnostics.push(MorphixDiagnostic {
+                label: MorphixLabel::D5CalmStable,
+                provenance: LabelProvenance::fixed(
+                    GuardDimension::D5,
+                    "5D calm-stable micro-society: CALM_STABLE predicate, low decay, low fear/pain.",
+                    D5_CALM_SOURCES,
+                    D5_CALM_SHARDS,
+                )
+                .with_evidence([Some(boundary.clone()), Some(fear.clone()), None]),
+                severity: 0.0,
+                margin: decay_below.min(below(t.fear, th.fear_overload_thresh)),
+            });
+        }
+
+        // Boundary-skimming: high DECAY but not yet overloaded, with BOUNDARY_SKIMMING. [file:10]
+        if has_boundary && t.decay >= th.decay_boundary_thresh && roh < th.roh_ceiling {
+            diagnostics.push(MorphixDiagnostic {
+                label: MorphixLabel::D5BoundarySkimming,
+                provenance: LabelProvenance::fixed(
+                    GuardDimension::D5,
+                    "5D boundary skimming: boundary-skimming predicate and decay near RoH ceiling.",
+                    D5_BOUNDARY_SOURCES,
+                    D5_BOUNDARY_SHARDS,
+                )
+                .with_evidence([Some(boundary), Some(ceiling.clone()), None]),
+                severity: decay_above,
+                margin: decay_above.min(roh_below),
+            });
+        }
+
+        // Unfair drain confirmed: UNFAIR_DRAIN + lifeforce low + power high. [file:10]
+        if has_unfair && t.lifeforce < th.lifeforce_fair_floor && t.power >= th.power_unfair_thresh {
+            diagnostics.push(MorphixDiagnostic {
+                label: MorphixLabel::D5UnfairDrainConfirmed,
+                provenance: LabelProvenance::fixed(
+                    GuardDimension::D5,
+                    "5D unfair drain confirmed: UNFAIR_DRAIN predicate, low lifeforce, high power.",
+                    D5_DRAIN_SOURCES,
+                    D5_DRAIN_SHARDS,
+                )
+                .with_evidence([Some(floor), Some(unfair), None]),
+                severity: (power_above + lifeforce_below) / 2.0,
+                margin: power_above.min(lifeforce_below),
+            });
+        }
+
+        // Overloaded recovery window: OVERLOADED + high fear/pain but RoH not yet at ceiling. [file:10]
+        if has_overload
+            && (t.fear >= th.fear_overload_thresh || t.pain >= th.pain_overload_thresh)
+            && roh < th.roh_ceiling
+        {
+            let depth = above(t.fear, th.fear_overload_thresh).max(above(t.pain, th.pain_overload_thresh));
+            diagnostics.push(MorphixDiagnostic {
+                label: MorphixLabel::D5OverloadedRecoveryWindow,
+                provenance: LabelProvenance::fixed(
+                    GuardDimension::D5,
+                    "5D overloaded recovery window: OVERLOADED predicate with high fear/pain under RoH ceiling.",
+                    D5_OVERLOAD_SOURCES,
+                    D5_OVERLOAD_SHARDS,
+                )
+                .with_evidence([
+                    Some(fear),
+                    Some(Evidence::new("pain", t.pain, "overload", th.pain_overload_thresh)),
+                    Some(ceiling),
+                ]),
+                severity: depth,
+                margin: depth.min(roh_below),
+            });
+        }
+
+        for diagnostic in diagnostics.iter_mut() {
+            diagnostic.provenance.thresholds = th.source;
+        }
+
+        // Data quality: labels relying on missing envelope axes assert nothing.
+        let missing = input.envelope.missing();
+        if !missing.is_empty() {
+            for diagnostic in diagnostics.iter_mut() {
+                let gaps = diagnostic.label.envelope_axes().intersection(missing);
+                if !gaps.is_empty() {
+                    diagnostic.provenance.determinacy = Determinacy::Indeterminate { missing: gaps };
+                    diagnostic.margin = 0.0;
+                }
+            }
+            let mut provenance = LabelProvenance::fixed(
+                GuardDimension::D1,
+                "Envelope data incomplete; labels depending on the missing axes are indeterminate.",
+                DATA_QUALITY_SOURCES,
+                DATA_QUALITY_SHARDS,
+            );
+            provenance.determinacy = Determinacy::Indeterminate { missing };
+            diagnostics.push(MorphixDiagnostic {
+                label: MorphixLabel::DataQuality,
+                provenance,
+                severity: missing.len() as f32 / EnvelopeAxis::ALL.len() as f32,
+                margin: 0.0,
+            });
+        }
+
+        view.capability_state = input.capability_state;
+        view.roh_value = roh;
+        view.evolve_index = input.evolve_index;
+        view.epoch_index = input.epoch_index;
+        if view.config_version != cfg.config_version {
+            view.config_version = cfg.config_version.clone();
+        }
+    }
+}
+
+// Unit tests for the allocation-free guard path.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+    use std::time::SystemTime;
+
+    struct CountingAlloc;
+
+    thread_local! {
+        static ALLOCATED: Cell<usize> = const { Cell::new(0) };
+    }
+
+    unsafe impl GlobalAlloc for CountingAlloc {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let _ = ALLOCATED.try_with(|n| n.set(n.get() + layout.size()));
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAlloc = CountingAlloc;
+
+    fn input(lifeforce: f32, decay: f32, power: f32, fear: f32, predicates: Vec<MicroSocietyPredicate>) -> MorphixGuardInput {
+        MorphixGuardInput {
+            capability_state: CapabilityState::ControlledHuman,
+            roh: RoH::new(0.2),
+            envelope: BiophysicalEnvelopeSnapshot {
+                eeg_alpha_frac: Some(0.5),
+                eeg_gamma_frac: Some(0.5),
+                eda_tonic_frac: Some(0.5),
+                bpm_frac: Some(0.5),
+                cognitive_load_warn_frac: Some(0.1),
+                sleep_arousal_warn_frac: Some(0.1),
+                inflammation_warn_frac: Some(0.1),
+            },
+            tree_of_life: TreeOfLifeView {
+                blood: 0.5, oxygen: 0.5, wave: 0.5, h2o: 0.5, time: 0.5,
+                decay, lifeforce,
+                brain: 0.5, smart: 0.5, evolve: 0.5,
+                power,
+                tech: 0.5,
+                fear,
+                pain: fear,
+                nano: 0.5,
+            },
+            micro_society: MicroSocietyView { predicates },
+            evolve_index: Some(1),
+            epoch_index: Some(2),
+        }
+    }
+
+    fn corpus() -> Vec<MorphixGuardInput> {
+        use MicroSocietyPredicate::*;
+        vec![
+            input(0.8, 0.2, 0.3, 0.1, vec![CalmStable]),
+            input(0.3, 0.8, 0.9, 0.7, vec![UnfairDrain, Overloaded, BoundarySkimming]),
+            input(0.6, 0.8, 0.3, 0.7, vec![BoundarySkimming, Overloaded]),
+            input(0.3, 0.2, 0.9, 0.2, vec![UnfairDrain]),
+        ]
+    }
+
+    #[test]
+    fn test_evaluate_into_reuses_buffer_without_allocating() {
+        let cfg = MorphixGuardConfig {
+            config_version: Some(ConfigVersion::new("abc123", SystemTime::now(), None)),
+            ..MorphixGuardConfig::default()
+        };
+        let inputs = corpus();
+
+        let mut view = MorphixGuard::evaluate(&inputs[1], &cfg);
+        let before = ALLOCATED.with(Cell::get);
+        for input in &inputs {
+            MorphixGuard::evaluate_into(input, &cfg, &mut view);
+        }
+        assert_eq!(ALLOCATED.with(Cell::get) - before, 0);
+        assert_eq!(view.config_version, cfg.config_version);
+    }
+
+    #[test]
+    fn test_evaluate_into_matches_evaluate() {
+        let cfg = MorphixGuardConfig::default();
+        let mut view = MorphixGuard::evaluate(&corpus()[0], &cfg);
+        for input in &corpus() {
+            let fresh = MorphixGuard::evaluate(input, &cfg);
+            MorphixGuard::evaluate_into(input, &cfg, &mut view);
+            let labels: Vec<_> = view.diagnostics.iter().map(|d| d.label.clone()).collect();
+            let fresh_labels: Vec<_> = fresh.diagnostics.iter().map(|d| d.label.clone()).collect();
+            assert_eq!(labels, fresh_labels);
+            assert!(view.diagnostics.len() <= MAX_DIAGNOSTICS);
+        }
+
+        let calm = MorphixGuard::evaluate(&corpus()[0], &cfg);
+        let d1 = &calm.diagnostics[0].provenance;
+        assert_eq!(d1.sources.as_ref(), ["TreeOfLifeView.lifeforce", "TreeOfLifeView.decay", "RoH.value"]);
+        assert_eq!(d1.shard_refs[2], ".rohmodel.aln");
+    }
+
+    fn diagnostic(view: &MorphixGuardView, label: MorphixLabel) -> &MorphixDiagnostic {
+        view.diagnostics.iter().find(|d| d.label == label).unwrap()
+    }
+
+    #[test]
+    fn test_deeper_violation_scores_higher_severity() {
+        use MicroSocietyPredicate::*;
+        let cfg = MorphixGuardConfig::default();
+        let severities = |label: MorphixLabel, inputs: &[MorphixGuardInput]| -> Vec<f32> {
+            inputs
+                .iter()
+                .map(|i| diagnostic(&MorphixGuard::evaluate(i, &cfg), label.clone()).severity)
+                .collect()
+        };
+        let increasing = |v: &[f32]| v.windows(2).all(|w| w[0] < w[1]);
+
+        let lifeforce: Vec<_> = [0.49, 0.3, 0.05].map(|l| input(l, 0.2, 0.3, 0.1, vec![])).into();
+        assert!(increasing(&severities(MorphixLabel::D1UnfairDrainRisk, &lifeforce)));
+
+        let power: Vec<_> = [0.7, 0.8, 1.0].map(|p| input(0.3, 0.2, p, 0.2, vec![UnfairDrain])).into();
+        assert!(increasing(&severities(MorphixLabel::D3UnfairDrainRisk, &power)));
+        assert!(increasing(&severities(MorphixLabel::D5UnfairDrainConfirmed, &power)));
+
+        let decay: Vec<_> = [0.7, 0.85, 0.95].map(|d| input(0.6, d, 0.3, 0.2, vec![BoundarySkimming])).into();
+        assert!(increasing(&severities(MorphixLabel::D5BoundarySkimming, &decay)));
+
+        let fear: Vec<_> = [0.65, 0.8, 0.99].map(|f| input(0.6, 0.2, 0.3, f, vec![Overloaded])).into();
+        assert!(increasing(&severities(MorphixLabel::D5OverloadedRecoveryWindow, &fear)));
+    }
+
+    #[test]
+    fn test_scores_stay_in_unit_range_and_fair_labels_have_no_severity() {
+        let cfg = MorphixGuardConfig::default();
+        let mut extremes = corpus();
+        extremes.push(input(0.0, 1.0, 1.0, 1.0, vec![MicroSocietyPredicate::UnfairDrain]));
+        extremes.push(input(f32::NAN, 2.0, -1.0, 0.5, vec![MicroSocietyPredicate::Overloaded]));
+        for input in &extremes {
+            for d in MorphixGuard::evaluate(input, &cfg).diagnostics {
+                assert!((0.0..=1.0).contains(&d.severity), "{:?} severity {}", d.label, d.severity);
+                assert!((0.0..=1.0).contains(&d.margin), "{:?} margin {}", d.label, d.margin);
+            }
+        }
+
+        let calm = MorphixGuard::evaluate(&corpus()[0], &cfg);
+        assert!(calm.diagnostics.iter().all(|d| d.severity == 0.0));
+        // lifeforce 0.8 sits 60% of the way from floor to 1.0; decay 0.2 is
+        // 71% below boundary, so the tighter lifeforce side sets the margin.
+        assert!((diagnostic(&calm, MorphixLabel::D1Fair).margin - 0.6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_fear_only_override_applies_to_its_capability_state() {
+        let cfg = MorphixGuardConfig::default().with_override(
+            CapabilityState::ControlledHuman,
+            PartialThresholds {
+                fear_overload_thresh: Some(0.5),
+                ..PartialThresholds::default()
+            },
+        );
+        let human = input(0.8, 0.2, 0.3, 0.55, vec![MicroSocietyPredicate::Overloaded]);
+        let view = MorphixGuard::evaluate(&human, &cfg);
+        let overload = diagnostic(&view, MorphixLabel::D5OverloadedRecoveryWindow);
+        assert_eq!(
+            overload.provenance.thresholds,
+            ThresholdSource::Override(CapabilityState::ControlledHuman)
+        );
+        // Pain keeps the base threshold.
+        let evidence: Vec<_> = overload.provenance.evidence().map(|e| e.threshold).collect();
+        assert_eq!(evidence, [0.5, 0.6, 0.3]);
+        assert!(overload.provenance.to_string().ends_with("[ControlledHuman override]"));
+        assert!(view.diagnostics.iter().all(|d| d.provenance.thresholds != ThresholdSource::Base));
+
+        let model_only = MorphixGuardInput {
+            capability_state: CapabilityState::ModelOnly,
+            ..human
+        };
+        let view = MorphixGuard::evaluate(&model_only, &cfg);
+        assert!(view.diagnostics.iter().all(|d| d.label != MorphixLabel::D5OverloadedRecoveryWindow));
+        assert!(view.diagnostics.iter().all(|d| d.provenance.thresholds == ThresholdSource::Base));
+        assert_eq!(cfg.thresholds_for(CapabilityState::ModelOnly).fear_overload_thresh, 0.6);
+    }
+
+    #[test]
+    fn test_roh_ceiling_follows_capability_state() {
+        let cfg = MorphixGuardConfig::default();
+        let skimming = |state| MorphixGuardInput {
+            capability_state: state,
+            roh: RoH::new(0.5),
+            ..input(0.6, 0.8, 0.3, 0.2, vec![MicroSocietyPredicate::BoundarySkimming])
+        };
+
+        let lab = MorphixGuard::evaluate(&skimming(CapabilityState::LabBench), &cfg);
+        let d5 = diagnostic(&lab, MorphixLabel::D5BoundarySkimming);
+        assert!(d5.provenance.to_string().contains("roh=0.50 vs ceiling=1.00"));
+        // decay 0.8 is a third of the way past its boundary; RoH is half-way under the ceiling.
+        assert!((d5.margin - 1.0 / 3.0).abs() < 1e-6);
+
+        // 0.5 is past the RoH model's ceiling for ControlledHuman.
+        let human = MorphixGuard::evaluate(&skimming(CapabilityState::ControlledHuman), &cfg);
+        assert!(human.diagnostics.iter().all(|d| d.label != MorphixLabel::D5BoundarySkimming));
+
+        let capped = cfg.with_override(
+            CapabilityState::LabBench,
+            PartialThresholds {
+                roh_ceiling: Some(0.4),
+                ..PartialThresholds::default()
+            },
+        );
+        let lab = MorphixGuard::evaluate(&skimming(CapabilityState::LabBench), &capped);
+        assert!(lab.diagnostics.iter().all(|d| d.label != MorphixLabel::D5BoundarySkimming));
+    }
+
+    fn determinacy(view: &MorphixGuardView, label: MorphixLabel) -> Determinacy {
+        diagnostic(view, label).provenance.determinacy
+    }
+
+    #[test]
+    fn test_fully_present_envelope_is_determinate() {
+        let cfg = MorphixGuardConfig::default();
+        for input in corpus() {
+            let view = MorphixGuard::evaluate(&input, &cfg);
+            assert!(view.diagnostics.iter().all(|d| d.label != MorphixLabel::DataQuality));
+            assert!(view
+                .diagnostics
+                .iter()
+                .all(|d| d.provenance.determinacy == Determinacy::Determinate));
+        }
+    }
+
+    #[test]
+    fn test_partially_missing_envelope_degrades_dependent_labels() {
+        let cfg = MorphixGuardConfig::default();
+        let mut calm = corpus().remove(0);
+        calm.envelope.bpm_frac = None;
+        calm.envelope.eda_tonic_frac = Some(f32::NAN);
+        let view = MorphixGuard::evaluate(&calm, &cfg);
+        let missing: EnvelopeAxes = [EnvelopeAxis::EdaTonic, EnvelopeAxis::Bpm].into_iter().collect();
+        for fair in [MorphixLabel::D1Fair, MorphixLabel::D3Fair, MorphixLabel::D5CalmStable] {
+            assert_eq!(determinacy(&view, fair.clone()), Determinacy::Indeterminate { missing });
+            assert_eq!(diagnostic(&view, fair).margin, 0.0);
+        }
+        let quality = diagnostic(&view, MorphixLabel::DataQuality);
+        let rendered = quality.provenance.to_string();
+        assert!(rendered.ends_with("[indeterminate: missing eda_tonic_frac, bpm_frac]"));
+        assert!((quality.severity - 2.0 / 7.0).abs() < 1e-6);
+
+        // Boundary skimming reads only the warn axes, which are all present.
+        let mut skimming = corpus().remove(2);
+        skimming.envelope.bpm_frac = None;
+        let view = MorphixGuard::evaluate(&skimming, &cfg);
+        assert_eq!(determinacy(&view, MorphixLabel::D5BoundarySkimming), Determinacy::Determinate);
+        assert_eq!(determinacy(&view, MorphixLabel::D1UnfairDrainRisk), Determinacy::Determinate);
+    }
+
+    #[test]
+    fn test_all_missing_envelope_asserts_no_fair_label() {
+        let cfg = MorphixGuardConfig::default();
+        let mut calm = corpus().remove(0);
+        calm.envelope = BiophysicalEnvelopeSnapshot::default();
+        let view = MorphixGuard::evaluate(&calm, &cfg);
+        assert_eq!(view.diagnostics.len(), 4);
+        assert!(view.diagnostics.len() <= MAX_DIAGNOSTICS);
+        for d in &view.diagnostics {
+            assert_eq!(d.provenance.determinacy, Determinacy::Indeterminate { missing: EnvelopeAxes::ALL });
+        }
+        assert_eq!(diagnostic(&view, MorphixLabel::DataQuality).severity, 1.0);
+        assert!(!MorphixLabel::DataQuality.is_risk());
+    }
+
+    #[test]
+    fn test_explanation_renders_numeric_evidence() {
+        let cfg = MorphixGuardConfig::default();
+        let view = MorphixGuard::evaluate(&input(0.05, 0.2, 0.3, 0.1, vec![]), &cfg);
+        let d1 = diagnostic(&view, MorphixLabel::D1UnfairDrainRisk);
+        assert_eq!(
+            d1.provenance.to_string(),
+            "Elevated unfair-drain risk: lifeforce depleted or decay near boundary. \
+             (lifeforce=0.05 vs floor=0.50, decay=0.20 vs boundary=0.70)"
+        );
+        assert!((d1.severity - 0.9).abs() < 1e-6);
+        assert_eq!(d1.provenance.evidence().count(), 2);
+    }
+}
@@ -0,0 +1,186 @@
+//! Envelope data quality.
+//!
+//! Envelope axes can be unavailable (sensor offline) or degraded (NaN).
+//! Rather than letting a stand-in value bias labels towards Fair, the guard
+//! marks every label that depends on a missing axis as indeterminate and
+//! reports the gaps in a `DataQuality` diagnostic.
+
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{BiophysicalEnvelopeSnapshot, MorphixLabel};
+
+/// One `BiophysicalEnvelopeSnapshot` axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EnvelopeAxis {
+    EegAlpha,
+    EegGamma,
+    EdaTonic,
+    Bpm,
+    CognitiveLoadWarn,
+    SleepArousalWarn,
+    InflammationWarn,
+}
+
+impl EnvelopeAxis {
+    pub const ALL: [Self; 7] = [
+        Self::EegAlpha,
+        Self::EegGamma,
+        Self::EdaTonic,
+        Self::Bpm,
+        Self::CognitiveLoadWarn,
+        Self::SleepArousalWarn,
+        Self::InflammationWarn,
+    ];
+
+    /// The snapshot field name, e.g. "bpm_frac".
+    pub const fn field(self) -> &'static str {
+        match self {
+            Self::EegAlpha => "eeg_alpha_frac",
+            Self::EegGamma => "eeg_gamma_frac",
+            Self::EdaTonic => "eda_tonic_frac",
+            Self::Bpm => "bpm_frac",
+            Self::CognitiveLoadWarn => "cognitive_load_warn_frac",
+            Self::SleepArousalWarn => "sleep_arousal_warn_frac",
+            Self::InflammationWarn => "inflammation_warn_frac",
+        }
+    }
+
+    const fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// A set of envelope axes, stored as a bitmask so diagnostics carrying it
+/// stay allocation-free. Serializes as a list of axes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(from = "Vec<EnvelopeAxis>", into = "Vec<EnvelopeAxis>")
+)]
+pub struct EnvelopeAxes(u8);
+
+impl EnvelopeAxes {
+    pub const NONE: Self = Self(0);
+    pub const ALL: Self = Self(0b111_1111);
+    /// The per-epoch envelope fractions behind the `*-overload` specs.
+    pub const OVERLOAD: Self = Self(0b000_1111);
+    /// The `*-warn` fractions.
+    pub const WARN: Self = Self(0b111_0000);
+
+    pub const fn contains(self, axis: EnvelopeAxis) -> bool {
+        self.0 & axis.bit() != 0
+    }
+
+    pub fn insert(&mut self, axis: EnvelopeAxis) {
+        self.0 |= axis.bit();
+    }
+
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Members in `EnvelopeAxis::ALL` order.
+    pub fn iter(self) -> impl Iterator<Item = EnvelopeAxis> {
+        EnvelopeAxis::ALL
+            .into_iter()
+            .filter(move |a| self.contains(*a))
+    }
+}
+
+impl From<Vec<EnvelopeAxis>> for EnvelopeAxes {
+    fn from(axes: Vec<EnvelopeAxis>) -> Self {
+        axes.into_iter().collect()
+    }
+}
+
+impl From<EnvelopeAxes> for Vec<EnvelopeAxis> {
+    fn from(axes: EnvelopeAxes) -> Self {
+        axes.iter().collect()
+    }
+}
+
+impl FromIterator<EnvelopeAxis> for EnvelopeAxes {
+    fn from_iter<I: IntoIterator<Item = EnvelopeAxis>>(iter: I) -> Self {
+        let mut axes = Self::NONE;
+        for axis in iter {
+            axes.insert(axis);
+        }
+        axes
+    }
+}
+
+impl fmt::Display for EnvelopeAxes {
+    /// Comma-separated field names.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, axis) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(axis.field())?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether a label could be fully checked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Determinacy {
+    #[default]
+    Determinate,
+    /// Envelope axes the label depends on were missing; the label is kept
+    /// for context but asserts nothing.
+    Indeterminate { missing: EnvelopeAxes },
+}
+
+impl BiophysicalEnvelopeSnapshot {
+    pub fn get(&self, axis: EnvelopeAxis) -> Option<f32> {
+        match axis {
+            EnvelopeAxis::EegAlpha => self.eeg_alpha_frac,
+            EnvelopeAxis::EegGamma => self.eeg_gamma_frac,
+            EnvelopeAxis::EdaTonic => self.eda_tonic_frac,
+            EnvelopeAxis::Bpm => self.bpm_frac,
+            EnvelopeAxis::CognitiveLoadWarn => self.cognitive_load_warn_frac,
+            EnvelopeAxis::SleepArousalWarn => self.sleep_arousal_warn_frac,
+            EnvelopeAxis::InflammationWarn => self.inflammation_warn_frac,
+        }
+    }
+
+    /// Axes that are absent or NaN.
+    pub fn missing(&self) -> EnvelopeAxes {
+        EnvelopeAxis::ALL
+            .into_iter()
+            .filter(|a| self.get(*a).is_none_or(f32::is_nan))
+            .collect()
+    }
+}
+
+impl MorphixLabel {
+    /// Envelope axes a label relies on:
+    /// - the fair and calm labels (`D1Fair`, `D3Fair`, `D5CalmStable`)
+    ///   vouch for the whole snapshot, so every axis;
+    /// - `D3OverloadRisk`: the overload fractions (`EnvelopeAxes::OVERLOAD`);
+    /// - `D5BoundarySkimming`: the warn fractions (`EnvelopeAxes::WARN`);
+    /// - every other label reads TREE and RoH values only.
+    pub const fn envelope_axes(&self) -> EnvelopeAxes {
+        match self {
+            Self::D1Fair | Self::D3Fair | Self::D5CalmStable => EnvelopeAxes::ALL,
+            Self::D3OverloadRisk => EnvelopeAxes::OVERLOAD,
+            Self::D5BoundarySkimming => EnvelopeAxes::WARN,
+            _ => EnvelopeAxes::NONE,
+        }
+    }
+}
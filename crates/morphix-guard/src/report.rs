@@ -0,0 +1,272 @@
+//! Per-run summary of the `MorphixGuardView`s a logging layer accumulated.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{CapabilityState, MorphixGuardView, MorphixLabel};
+
+/// How often one label fired and over which epochs.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LabelStats {
+    pub count: u64,
+    pub first_epoch: u64,
+    pub last_epoch: u64,
+}
+
+/// The epochs evaluated under one `CapabilityState`.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CapabilityBreakdown {
+    pub epochs: u64,
+    pub d1_fair_fraction: f32,
+    pub max_roh: f32,
+    pub label_counts: BTreeMap<MorphixLabel, u64>,
+}
+
+/// Label statistics over a run. An empty run yields `Default::default()`.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MorphixGuardReport {
+    pub epochs: u64,
+    /// Whether `first_epoch` / `last_epoch` are `epoch_index` values; when
+    /// any view lacks one, they are positions in the slice instead.
+    pub indexed: bool,
+    pub labels: BTreeMap<MorphixLabel, LabelStats>,
+    /// Share of epochs labelled `D1Fair`; 0 for an empty run.
+    pub d1_fair_fraction: f32,
+    pub max_roh: f32,
+    pub by_capability: BTreeMap<CapabilityState, CapabilityBreakdown>,
+}
+
+impl MorphixGuardReport {
+    /// Summarizes `views`, oldest first.
+    pub fn from_views(views: &[MorphixGuardView]) -> Self {
+        let indexed = !views.is_empty() && views.iter().all(|v| v.epoch_index.is_some());
+        let mut report = Self {
+            epochs: views.len() as u64,
+            indexed,
+            ..Self::default()
+        };
+        for (position, view) in views.iter().enumerate() {
+            let epoch = match view.epoch_index {
+                Some(index) if indexed => index,
+                _ => position as u64,
+            };
+            report.max_roh = report.max_roh.max(view.roh_value);
+
+            let capability = report
+                .by_capability
+                .entry(view.capability_state)
+                .or_default();
+            capability.epochs += 1;
+            capability.max_roh = capability.max_roh.max(view.roh_value);
+
+            for diagnostic in &view.diagnostics {
+                *capability
+                    .label_counts
+                    .entry(diagnostic.label.clone())
+                    .or_default() += 1;
+                let stats = report
+                    .labels
+                    .entry(diagnostic.label.clone())
+                    .or_insert(LabelStats {
+                        count: 0,
+                        first_epoch: epoch,
+                        last_epoch: epoch,
+                    });
+                stats.count += 1;
+                stats.first_epoch = stats.first_epoch.min(epoch);
+                stats.last_epoch = stats.last_epoch.max(epoch);
+            }
+        }
+        // D1Fair fires at most once per view, so its count is its epochs.
+        let fair = report
+            .labels
+            .get(&MorphixLabel::D1Fair)
+            .map_or(0, |s| s.count);
+        report.d1_fair_fraction = fraction(fair, report.epochs);
+        for capability in report.by_capability.values_mut() {
+            let fair = capability
+                .label_counts
+                .get(&MorphixLabel::D1Fair)
+                .copied()
+                .unwrap_or(0);
+            capability.d1_fair_fraction = fraction(fair, capability.epochs);
+        }
+        report
+    }
+}
+
+fn fraction(part: u64, whole: u64) -> f32 {
+    if whole == 0 {
+        0.0
+    } else {
+        part as f32 / whole as f32
+    }
+}
+
+impl fmt::Display for MorphixGuardReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "MorphixGuard report: {} epochs, D1Fair {:.1}%, max RoH {:.2}",
+            self.epochs,
+            self.d1_fair_fraction * 100.0,
+            self.max_roh
+        )?;
+        if self.epochs == 0 {
+            return Ok(());
+        }
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{:<28} {:>7} {:>9} {:>9}",
+            "label", "count", "first", "last"
+        )?;
+        for (label, stats) in &self.labels {
+            writeln!(
+                f,
+                "{:<28} {:>7} {:>9} {:>9}",
+                format!("{label:?}"),
+                stats.count,
+                stats.first_epoch,
+                stats.last_epoch
+            )?;
+        }
+        if !self.indexed {
+            writeln!(
+                f,
+                "(first / last are positions: some views have no epoch_index)"
+            )?;
+        }
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{:<28} {:>7} {:>9} {:>9}",
+            "capability", "epochs", "D1Fair", "max RoH"
+        )?;
+        for (state, capability) in &self.by_capability {
+            writeln!(
+                f,
+                "{:<28} {:>7} {:>8.1}% {:>9.2}",
+                format!("{state:?}"),
+                capability.epochs,
+                capability.d1_fair_fraction * 100.0,
+                capability.max_roh
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LabelProvenance, MorphixDiagnostic};
+
+    fn view(
+        state: CapabilityState,
+        epoch: Option<u64>,
+        roh: f32,
+        labels: &[MorphixLabel],
+    ) -> MorphixGuardView {
+        MorphixGuardView {
+            capability_state: state,
+            roh_value: roh,
+            evolve_index: None,
+            epoch_index: epoch,
+            diagnostics: labels
+                .iter()
+                .map(|label| MorphixDiagnostic {
+                    label: label.clone(),
+                    provenance: LabelProvenance::fixed(crate::GuardDimension::D1, "test", &[], &[]),
+                    severity: 0.0,
+                    margin: 0.0,
+                })
+                .collect(),
+            config_version: None,
+        }
+    }
+
+    #[test]
+    fn test_report_over_mixed_capability_states() {
+        use CapabilityState::*;
+        use MorphixLabel::*;
+        let views = [
+            view(LabBench, Some(10), 0.1, &[D1Fair, D3Fair]),
+            view(
+                ControlledHuman,
+                Some(11),
+                0.25,
+                &[D1UnfairDrainRisk, D5BoundarySkimming],
+            ),
+            view(ControlledHuman, Some(12), 0.2, &[D1Fair]),
+            view(LabBench, Some(14), 0.05, &[D1UnfairDrainRisk]),
+        ];
+        let report = MorphixGuardReport::from_views(&views);
+        assert_eq!(report.epochs, 4);
+        assert!(report.indexed);
+        assert_eq!(report.d1_fair_fraction, 0.5);
+        assert_eq!(report.max_roh, 0.25);
+        assert_eq!(
+            report.labels[&D1UnfairDrainRisk],
+            LabelStats {
+                count: 2,
+                first_epoch: 11,
+                last_epoch: 14
+            }
+        );
+        assert_eq!(report.labels[&D3Fair].count, 1);
+
+        let human = &report.by_capability[&ControlledHuman];
+        assert_eq!(
+            (human.epochs, human.d1_fair_fraction, human.max_roh),
+            (2, 0.5, 0.25)
+        );
+        assert_eq!(human.label_counts[&D5BoundarySkimming], 1);
+        assert_eq!(report.by_capability[&LabBench].max_roh, 0.1);
+
+        let table = report.to_string();
+        assert!(table.starts_with("MorphixGuard report: 4 epochs, D1Fair 50.0%, max RoH 0.25"));
+        assert!(table.contains("D1UnfairDrainRisk                  2        11        14"));
+        assert!(table.contains("ControlledHuman                    2     50.0%      0.25"));
+
+        // Any missing index falls back to positions for every label.
+        let mut unindexed = views.to_vec();
+        unindexed[3].epoch_index = None;
+        let report = MorphixGuardReport::from_views(&unindexed);
+        assert!(!report.indexed);
+        assert_eq!(report.labels[&D1UnfairDrainRisk].last_epoch, 3);
+        assert!(report.to_string().contains("are positions"));
+    }
+
+    #[test]
+    fn test_empty_run_gives_an_empty_report() {
+        let report = MorphixGuardReport::from_views(&[]);
+        assert_eq!(report, MorphixGuardReport::default());
+        assert_eq!(
+            report.to_string(),
+            "MorphixGuard report: 0 epochs, D1Fair 0.0%, max RoH 0.00\n"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_report_round_trips_through_json() {
+        let views = [view(
+            CapabilityState::GeneralUse,
+            None,
+            0.3,
+            &[MorphixLabel::D3OverloadRisk],
+        )];
+        let report = MorphixGuardReport::from_views(&views);
+        let json = serde_json::to_string(&report).unwrap();
+        assert!(json.contains("\"D3OverloadRisk\":{\"count\":1"));
+        let back: MorphixGuardReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, report);
+    }
+}
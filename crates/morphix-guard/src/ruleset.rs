@@ -0,0 +1,251 @@
+//! Site-specific diagnostic rules on top of the built-in labels.
+//!
+//! A `GuardRule` sees the input and config by shared reference only, so a
+//! custom rule can no more mutate state than the built-in rules can.
+//! `MorphixGuardRuleset` runs the built-in rules first, then custom rules in
+//! registration order; a label produced twice is reported once, with the
+//! provenance of both merged.
+//!
+//! ```
+//! use std::borrow::Cow;
+//! use morphix_guard::{
+//!     Evidence, GuardDimension, GuardRule, LabelProvenance, MorphixDiagnostic,
+//!     MorphixGuardConfig, MorphixGuardInput, MorphixGuardRuleset, MorphixLabel,
+//! };
+//!
+//! /// Flags nano-scale activity running ahead of the tech budget.
+//! struct NanoOutpacingTech;
+//!
+//! impl GuardRule for NanoOutpacingTech {
+//!     fn evaluate(&self, input: &MorphixGuardInput, _: &MorphixGuardConfig) -> Option<MorphixDiagnostic> {
+//!         let t = &input.tree_of_life;
+//!         (t.nano > t.tech).then(|| MorphixDiagnostic {
+//!             label: MorphixLabel::Custom(Cow::Borrowed("NanoOutpacingTech")),
+//!             provenance: LabelProvenance::fixed(
+//!                 GuardDimension::D3,
+//!                 "Nano activity above the tech budget.",
+//!                 &[Cow::Borrowed("TreeOfLifeView.nano"), Cow::Borrowed("TreeOfLifeView.tech")],
+//!                 &[Cow::Borrowed("Tree-of-Life.md/TREE-NANO")],
+//!             )
+//!             .with_evidence([Some(Evidence::new("nano", t.nano, "tech", t.tech)), None, None]),
+//!             severity: (t.nano - t.tech).clamp(0.0, 1.0),
+//!             margin: (t.nano - t.tech).clamp(0.0, 1.0),
+//!         })
+//!     }
+//! }
+//!
+//! let ruleset = MorphixGuardRuleset::new().with_rule(NanoOutpacingTech);
+//! # let _ = ruleset;
+//! ```
+
+use std::borrow::Cow;
+
+use crate::{
+    Determinacy, MorphixDiagnostic, MorphixGuard, MorphixGuardConfig, MorphixGuardInput,
+    MorphixGuardView,
+};
+
+/// A read-only diagnostic rule.
+pub trait GuardRule: Send + Sync {
+    /// The rule's diagnostic for `input`, if it fires.
+    fn evaluate(&self, input: &MorphixGuardInput, cfg: &MorphixGuardConfig) -> Option<MorphixDiagnostic>;
+}
+
+/// The built-in rules plus registered custom rules.
+#[derive(Default)]
+pub struct MorphixGuardRuleset {
+    rules: Vec<Box<dyn GuardRule>>,
+}
+
+impl MorphixGuardRuleset {
+    /// Built-in rules only.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `rule` after the built-ins and every rule registered before it.
+    pub fn with_rule(mut self, rule: impl GuardRule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// `MorphixGuard::evaluate` followed by each custom rule. A custom
+    /// diagnostic whose label is already present is merged into it: sources,
+    /// shard refs and evidence are combined, explanations joined, and the
+    /// higher severity and lower margin kept.
+    pub fn evaluate(&self, input: &MorphixGuardInput, cfg: &MorphixGuardConfig) -> MorphixGuardView {
+        let mut view = MorphixGuard::evaluate(input, cfg);
+        for rule in &self.rules {
+            let Some(diagnostic) = rule.evaluate(input, cfg) else {
+                continue;
+            };
+            match view.diagnostics.iter_mut().find(|d| d.label == diagnostic.label) {
+                Some(existing) => merge(existing, diagnostic),
+                None => view.diagnostics.push(diagnostic),
+            }
+        }
+        view
+    }
+}
+
+fn merge(into: &mut MorphixDiagnostic, from: MorphixDiagnostic) {
+    let (a, b) = (&mut into.provenance, from.provenance);
+    if !a.explanation.split("; ").any(|part| part == b.explanation) {
+        a.explanation = Cow::Owned(format!("{}; {}", a.explanation, b.explanation));
+    }
+    for source in b.sources.iter() {
+        if !a.sources.contains(source) {
+            a.sources.to_mut().push(source.clone());
+        }
+    }
+    for shard in b.shard_refs.iter() {
+        if !a.shard_refs.contains(shard) {
+            a.shard_refs.to_mut().push(shard.clone());
+        }
+    }
+    for evidence in b.evidence.into_iter().flatten() {
+        if a.evidence().any(|e| *e == evidence) {
+            continue;
+        }
+        if let Some(slot) = a.evidence.iter_mut().find(|slot| slot.is_none()) {
+            *slot = Some(evidence);
+        }
+    }
+    if let Determinacy::Indeterminate { missing } = b.determinacy {
+        a.determinacy = match a.determinacy {
+            Determinacy::Determinate => Determinacy::Indeterminate { missing },
+            Determinacy::Indeterminate { missing: ours } => Determinacy::Indeterminate {
+                missing: ours.iter().chain(missing.iter()).collect(),
+            },
+        };
+    }
+    into.severity = into.severity.max(from.severity);
+    into.margin = into.margin.min(from.margin);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        BiophysicalEnvelopeSnapshot, CapabilityState, Evidence, GuardDimension, LabelProvenance,
+        MicroSocietyView, MorphixLabel, RoH, TreeOfLifeView,
+    };
+
+    fn input(nano: f32, tech: f32) -> MorphixGuardInput {
+        MorphixGuardInput {
+            capability_state: CapabilityState::LabBench,
+            roh: RoH::new(0.1),
+            envelope: BiophysicalEnvelopeSnapshot {
+                eeg_alpha_frac: Some(0.5),
+                eeg_gamma_frac: Some(0.5),
+                eda_tonic_frac: Some(0.5),
+                bpm_frac: Some(0.5),
+                cognitive_load_warn_frac: Some(0.1),
+                sleep_arousal_warn_frac: Some(0.1),
+                inflammation_warn_frac: Some(0.1),
+            },
+            tree_of_life: TreeOfLifeView {
+                blood: 0.5, oxygen: 0.5, wave: 0.5, h2o: 0.5, time: 0.5,
+                decay: 0.2, lifeforce: 0.8,
+                brain: 0.5, smart: 0.5, evolve: 0.5, power: 0.3, tech,
+                fear: 0.2, pain: 0.2, nano,
+            },
+            micro_society: MicroSocietyView { predicates: Vec::new() },
+            evolve_index: None,
+            epoch_index: None,
+        }
+    }
+
+    const NANO_SOURCES: &[Cow<'static, str>] =
+        &[Cow::Borrowed("TreeOfLifeView.nano"), Cow::Borrowed("TreeOfLifeView.tech")];
+
+    /// The module example: nano activity above the tech budget.
+    struct NanoOutpacingTech;
+
+    impl GuardRule for NanoOutpacingTech {
+        fn evaluate(&self, input: &MorphixGuardInput, _: &MorphixGuardConfig) -> Option<MorphixDiagnostic> {
+            let t = &input.tree_of_life;
+            (t.nano > t.tech).then(|| MorphixDiagnostic {
+                label: MorphixLabel::Custom(Cow::Borrowed("NanoOutpacingTech")),
+                provenance: LabelProvenance::fixed(
+                    GuardDimension::D3,
+                    "Nano activity above the tech budget.",
+                    NANO_SOURCES,
+                    &[],
+                )
+                .with_evidence([Some(Evidence::new("nano", t.nano, "tech", t.tech)), None, None]),
+                severity: (t.nano - t.tech).clamp(0.0, 1.0),
+                margin: (t.nano - t.tech).clamp(0.0, 1.0),
+            })
+        }
+    }
+
+    /// A second opinion on D1Fair, to exercise merging.
+    struct TechVouchesForFairness(f32);
+
+    impl GuardRule for TechVouchesForFairness {
+        fn evaluate(&self, input: &MorphixGuardInput, _: &MorphixGuardConfig) -> Option<MorphixDiagnostic> {
+            Some(MorphixDiagnostic {
+                label: MorphixLabel::D1Fair,
+                provenance: LabelProvenance::fixed(
+                    GuardDimension::D1,
+                    "Tech budget within site norms.",
+                    &[Cow::Borrowed("TreeOfLifeView.tech"), Cow::Borrowed("RoH.value")],
+                    &[],
+                )
+                .with_evidence([
+                    Some(Evidence::new("tech", input.tree_of_life.tech, "site", 0.9)),
+                    None,
+                    None,
+                ]),
+                severity: 0.0,
+                margin: self.0,
+            })
+        }
+    }
+
+    fn labels(view: &MorphixGuardView) -> Vec<MorphixLabel> {
+        view.diagnostics.iter().map(|d| d.label.clone()).collect()
+    }
+
+    #[test]
+    fn test_custom_rules_run_after_builtins_in_registration_order() {
+        let cfg = MorphixGuardConfig::default();
+        let builtin = MorphixGuard::evaluate(&input(0.9, 0.4), &cfg);
+        let ruleset = MorphixGuardRuleset::new().with_rule(NanoOutpacingTech);
+        let view = ruleset.evaluate(&input(0.9, 0.4), &cfg);
+
+        let mut expected = labels(&builtin);
+        expected.push(MorphixLabel::Custom(Cow::Borrowed("NanoOutpacingTech")));
+        assert_eq!(labels(&view), expected);
+        let custom = view.diagnostics.last().unwrap();
+        assert!((custom.severity - 0.5).abs() < 1e-6);
+        assert!(custom.provenance.to_string().contains("nano=0.90 vs tech=0.40"));
+        assert!(custom.label.is_risk());
+
+        // Quiet rules leave the built-in view untouched.
+        assert_eq!(labels(&ruleset.evaluate(&input(0.3, 0.4), &cfg)), labels(&builtin));
+        assert_eq!(labels(&MorphixGuardRuleset::new().evaluate(&input(0.9, 0.4), &cfg)), labels(&builtin));
+    }
+
+    #[test]
+    fn test_duplicate_labels_merge_provenance() {
+        let cfg = MorphixGuardConfig::default();
+        let ruleset = MorphixGuardRuleset::new()
+            .with_rule(TechVouchesForFairness(0.1))
+            .with_rule(TechVouchesForFairness(0.05));
+        let view = ruleset.evaluate(&input(0.3, 0.4), &cfg);
+        let fair: Vec<_> = view.diagnostics.iter().filter(|d| d.label == MorphixLabel::D1Fair).collect();
+        assert_eq!(fair.len(), 1);
+
+        let p = &fair[0].provenance;
+        assert_eq!(
+            p.sources.as_ref(),
+            ["TreeOfLifeView.lifeforce", "TreeOfLifeView.decay", "RoH.value", "TreeOfLifeView.tech"]
+        );
+        assert!(p.explanation.ends_with("threshold.; Tech budget within site norms."));
+        assert_eq!(p.evidence().count(), 3);
+        assert!(p.to_string().contains("tech=0.40 vs site=0.90"));
+        assert_eq!(fair[0].margin, 0.05);
+    }
+}
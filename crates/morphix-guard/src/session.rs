@@ -0,0 +1,232 @@
+//! Hysteresis across epochs.
+//!
+//! `MorphixGuard::evaluate` is stateless, so inputs hovering around a
+//! threshold flip labels every epoch. A session remembers the previous view:
+//! risk labels are entered at the configured thresholds but only left once
+//! the inputs clear the (stricter) exit thresholds. Like the rest of the
+//! guard, a session does no IO.
+
+use crate::{
+    MorphixDiagnostic, MorphixGuard, MorphixGuardConfig, MorphixGuardInput, MorphixGuardView,
+    MorphixLabel, PartialThresholds,
+};
+
+/// Default width of the band between entry and exit thresholds.
+pub const DEFAULT_HYSTERESIS: f32 = 0.05;
+
+/// Stateful wrapper around `MorphixGuard::evaluate` that damps label
+/// flapping.
+#[derive(Debug, Clone)]
+pub struct MorphixGuardSession {
+    enter: MorphixGuardConfig,
+    exit: MorphixGuardConfig,
+    previous: Option<MorphixGuardView>,
+}
+
+impl MorphixGuardSession {
+    /// Risk labels are held until every threshold is cleared by
+    /// `DEFAULT_HYSTERESIS`.
+    pub fn new(cfg: MorphixGuardConfig) -> Self {
+        Self {
+            exit: relaxed(&cfg, DEFAULT_HYSTERESIS),
+            enter: cfg,
+            previous: None,
+        }
+    }
+
+    /// Widen or narrow the band; 0 disables hysteresis.
+    pub fn with_hysteresis(mut self, band: f32) -> Self {
+        self.exit = relaxed(&self.enter, band);
+        self
+    }
+
+    /// Thresholds a held risk label is re-checked against, e.g.
+    /// `decay_boundary_thresh` 0.65 against an entry threshold of 0.70.
+    pub fn with_exit_config(mut self, exit: MorphixGuardConfig) -> Self {
+        self.exit = exit;
+        self
+    }
+
+    /// Diagnostics for `input`. Last epoch's risk labels are kept while they
+    /// still hold under the exit thresholds, and fair labels that were absent
+    /// last epoch only appear once they hold under them too.
+    pub fn evaluate(&mut self, input: &MorphixGuardInput) -> &MorphixGuardView {
+        let mut view = MorphixGuard::evaluate(input, &self.enter);
+        if let Some(previous) = &self.previous {
+            let held = MorphixGuard::evaluate(input, &self.exit);
+            let was = |label: &MorphixLabel| previous.diagnostics.iter().any(|d| d.label == *label);
+            view.diagnostics.retain(|d| {
+                d.label.is_risk()
+                    || was(&d.label)
+                    || held.diagnostics.iter().any(|h| h.label == d.label)
+            });
+            let sticky: Vec<MorphixDiagnostic> = held
+                .diagnostics
+                .into_iter()
+                .filter(|d| d.label.is_risk() && was(&d.label))
+                .collect();
+            view.diagnostics.retain(|d| {
+                !sticky
+                    .iter()
+                    .any(|s| s.label == d.label || exclusive(&s.label, &d.label))
+            });
+            view.diagnostics.extend(sticky);
+            view.diagnostics.sort_by_key(|d| rank(&d.label));
+        }
+        self.previous.insert(view)
+    }
+
+    /// The view returned by the last `evaluate`.
+    pub fn previous(&self) -> Option<&MorphixGuardView> {
+        self.previous.as_ref()
+    }
+
+    /// Forget the previous view; the next epoch is evaluated statelessly.
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+/// `cfg` with every threshold, overrides included, moved `band` towards
+/// keeping risk labels.
+fn relaxed(cfg: &MorphixGuardConfig, band: f32) -> MorphixGuardConfig {
+    let lower = |t: f32| (t - band).clamp(0.0, 1.0);
+    let raise = |t: f32| (t + band).clamp(0.0, 1.0);
+    let overrides = cfg
+        .overrides
+        .iter()
+        .map(|(state, o)| {
+            let relaxed = PartialThresholds {
+                decay_boundary_thresh: o.decay_boundary_thresh.map(lower),
+                lifeforce_fair_floor: o.lifeforce_fair_floor.map(raise),
+                power_unfair_thresh: o.power_unfair_thresh.map(lower),
+                fear_overload_thresh: o.fear_overload_thresh.map(lower),
+                pain_overload_thresh: o.pain_overload_thresh.map(lower),
+                // A modelled limit rather than a noisy boundary; not widened.
+                roh_ceiling: o.roh_ceiling,
+            };
+            (*state, relaxed)
+        })
+        .collect();
+    MorphixGuardConfig {
+        decay_boundary_thresh: lower(cfg.decay_boundary_thresh),
+        lifeforce_fair_floor: raise(cfg.lifeforce_fair_floor),
+        power_unfair_thresh: lower(cfg.power_unfair_thresh),
+        fear_overload_thresh: lower(cfg.fear_overload_thresh),
+        pain_overload_thresh: lower(cfg.pain_overload_thresh),
+        overrides,
+        ..cfg.clone()
+    }
+}
+
+/// Whether both labels come from the same one-of rule (the 1D or 3D view).
+fn exclusive(a: &MorphixLabel, b: &MorphixLabel) -> bool {
+    let group = |l: &MorphixLabel| match rank(l) {
+        0..=1 => Some(1),
+        2..=4 => Some(3),
+        _ => None,
+    };
+    group(a).is_some() && group(a) == group(b)
+}
+
+/// Position in `MorphixGuard::evaluate`'s output order.
+fn rank(label: &MorphixLabel) -> u8 {
+    match label {
+        MorphixLabel::D1Fair => 0,
+        MorphixLabel::D1UnfairDrainRisk => 1,
+        MorphixLabel::D3Fair => 2,
+        MorphixLabel::D3UnfairDrainRisk => 3,
+        MorphixLabel::D3OverloadRisk => 4,
+        MorphixLabel::D5CalmStable => 5,
+        MorphixLabel::D5BoundarySkimming => 6,
+        MorphixLabel::D5UnfairDrainConfirmed => 7,
+        MorphixLabel::D5OverloadedRecoveryWindow => 8,
+        MorphixLabel::DataQuality => 9,
+        MorphixLabel::Custom(_) => 10,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BiophysicalEnvelopeSnapshot, CapabilityState, MicroSocietyView, RoH, TreeOfLifeView};
+
+    fn input(decay: f32) -> MorphixGuardInput {
+        MorphixGuardInput {
+            capability_state: CapabilityState::ControlledHuman,
+            roh: RoH::new(0.1),
+            envelope: BiophysicalEnvelopeSnapshot {
+                eeg_alpha_frac: Some(0.5),
+                eeg_gamma_frac: Some(0.5),
+                eda_tonic_frac: Some(0.5),
+                bpm_frac: Some(0.5),
+                cognitive_load_warn_frac: Some(0.1),
+                sleep_arousal_warn_frac: Some(0.1),
+                inflammation_warn_frac: Some(0.1),
+            },
+            tree_of_life: TreeOfLifeView {
+                blood: 0.5, oxygen: 0.5, wave: 0.5, h2o: 0.5, time: 0.5,
+                decay, lifeforce: 0.8,
+                brain: 0.5, smart: 0.5, evolve: 0.5, power: 0.3, tech: 0.5,
+                fear: 0.2, pain: 0.2, nano: 0.5,
+            },
+            micro_society: MicroSocietyView { predicates: Vec::new() },
+            evolve_index: None,
+            epoch_index: None,
+        }
+    }
+
+    fn d1(view: &MorphixGuardView) -> MorphixLabel {
+        view.diagnostics[0].label.clone()
+    }
+
+    fn transitions(labels: &[MorphixLabel]) -> usize {
+        labels.windows(2).filter(|w| w[0] != w[1]).count()
+    }
+
+    const OSCILLATING: [f32; 8] = [0.69, 0.71, 0.68, 0.72, 0.66, 0.71, 0.67, 0.70];
+
+    #[test]
+    fn test_oscillating_decay_transitions_at_most_once() {
+        let cfg = MorphixGuardConfig::default();
+        let stateless: Vec<_> = OSCILLATING
+            .iter()
+            .map(|&d| d1(&MorphixGuard::evaluate(&input(d), &cfg)))
+            .collect();
+        assert_eq!(transitions(&stateless), 7);
+
+        let mut session = MorphixGuardSession::new(cfg);
+        let damped: Vec<_> = OSCILLATING
+            .iter()
+            .map(|&d| d1(session.evaluate(&input(d))))
+            .collect();
+        assert!(transitions(&damped) <= 1, "{damped:?}");
+        assert_eq!(damped.last(), Some(&MorphixLabel::D1UnfairDrainRisk));
+
+        // D3Fair flaps with decay too; it is held off until decay clears the exit threshold.
+        let previous = session.previous().unwrap();
+        let labels: Vec<_> = previous.diagnostics.iter().map(|d| d.label.clone()).collect();
+        assert_eq!(labels, [MorphixLabel::D1UnfairDrainRisk]);
+    }
+
+    #[test]
+    fn test_risk_clears_below_the_exit_threshold() {
+        let exit = MorphixGuardConfig {
+            decay_boundary_thresh: 0.65,
+            ..MorphixGuardConfig::default()
+        };
+        let mut session = MorphixGuardSession::new(MorphixGuardConfig::default()).with_exit_config(exit);
+        assert_eq!(d1(session.evaluate(&input(0.71))), MorphixLabel::D1UnfairDrainRisk);
+        let held = session.evaluate(&input(0.66));
+        assert_eq!(d1(held), MorphixLabel::D1UnfairDrainRisk);
+        assert!(held.diagnostics[0].provenance.to_string().contains("decay=0.66 vs boundary=0.65"));
+        assert_eq!(d1(session.evaluate(&input(0.64))), MorphixLabel::D1Fair);
+
+        session.reset();
+        assert_eq!(d1(session.evaluate(&input(0.68))), MorphixLabel::D1Fair);
+
+        let mut plain = MorphixGuardSession::new(MorphixGuardConfig::default()).with_hysteresis(0.0);
+        let labels: Vec<_> = OSCILLATING.iter().map(|&d| d1(plain.evaluate(&input(d)))).collect();
+        assert_eq!(transitions(&labels), 7);
+    }
+}
@@ -0,0 +1,288 @@
+//! History-aware evaluation over a window of epochs.
+//!
+//! A single snapshot cannot tell a transient DECAY spike from a sustained
+//! drain; the window view fits a least-squares slope per field and only
+//! labels trends that hold across the whole window.
+
+use std::borrow::Cow;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use core_contract::config_version::ConfigVersion;
+
+use crate::{
+    CapabilityState, GuardDimension, LabelProvenance, MorphixGuard, MorphixGuardConfig,
+    MorphixGuardInput, D1_SHARDS, SRC_DECAY, SRC_LIFEFORCE, SRC_ROH,
+};
+
+/// Trend-level labels; as advisory as the per-epoch `MorphixLabel`s. [file:10]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MorphixTrendLabel {
+    /// Lifeforce falling while decay rises across the window.
+    D1SustainedDrain,
+    /// RoH rising across the window.
+    D1RisingRoH,
+    /// Lifeforce rising and decay falling, with RoH not rising.
+    D3RecoveryTrajectory,
+}
+
+/// Least-squares fit of one field against epoch index.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FieldTrend {
+    /// Change per epoch.
+    pub slope: f32,
+    pub first: f32,
+    pub last: f32,
+}
+
+/// A trend label with its provenance and the epochs it covers.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MorphixTrendDiagnostic {
+    pub label: MorphixTrendLabel,
+    pub provenance: LabelProvenance,
+    /// First and last `epoch_index` in the window, when the inputs carry
+    /// them.
+    pub epoch_range: Option<(u64, u64)>,
+}
+
+/// Trends over a window of `MorphixGuardInput`s.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MorphixGuardTrendView {
+    /// Capability state of the last input.
+    pub capability_state: Option<CapabilityState>,
+    pub samples: usize,
+    /// Lowest and highest `epoch_index` in the window, if every input has
+    /// one.
+    pub epoch_range: Option<(u64, u64)>,
+    pub lifeforce: FieldTrend,
+    pub decay: FieldTrend,
+    pub roh: FieldTrend,
+    pub diagnostics: Vec<MorphixTrendDiagnostic>,
+    pub config_version: Option<ConfigVersion>,
+}
+
+impl MorphixGuard {
+    /// Trend diagnostics over `inputs`, oldest first.
+    ///
+    /// Slopes are fitted against `epoch_index`, so gaps between epochs are
+    /// weighted correctly; if any input lacks an index, positions in the
+    /// slice are used instead. A field trends once its slope exceeds
+    /// `cfg.trend_slope_thresh` per epoch. Windows shorter than two inputs
+    /// have no trends. Like `evaluate`, this only reads its inputs.
+    pub fn evaluate_window(
+        inputs: &[MorphixGuardInput],
+        cfg: &MorphixGuardConfig,
+    ) -> MorphixGuardTrendView {
+        let indices: Option<Vec<u64>> = inputs.iter().map(|i| i.epoch_index).collect();
+        let epoch_range = indices
+            .as_ref()
+            .and_then(|ix| Some((*ix.iter().min()?, *ix.iter().max()?)));
+        let xs: Vec<f64> = match &indices {
+            Some(ix) => ix.iter().map(|&i| i as f64).collect(),
+            None => (0..inputs.len()).map(|i| i as f64).collect(),
+        };
+        let fit = |field: fn(&MorphixGuardInput) -> f32| {
+            let ys: Vec<f32> = inputs.iter().map(field).collect();
+            FieldTrend {
+                slope: slope(&xs, &ys),
+                first: ys.first().copied().unwrap_or_default(),
+                last: ys.last().copied().unwrap_or_default(),
+            }
+        };
+        let lifeforce = fit(|i| i.tree_of_life.lifeforce);
+        let decay = fit(|i| i.tree_of_life.decay);
+        let roh = fit(|i| i.roh.value());
+
+        let t = cfg.trend_slope_thresh;
+        let rising = |trend: &FieldTrend| trend.slope > t;
+        let falling = |trend: &FieldTrend| trend.slope < -t;
+        let epochs = match epoch_range {
+            Some((from, to)) => format!("epochs {from}–{to}"),
+            None => format!("{} unindexed epochs", inputs.len()),
+        };
+        let mut diagnostics = Vec::new();
+        let mut push = |label, dimension, explanation: String, sources: crate::Names| {
+            diagnostics.push(MorphixTrendDiagnostic {
+                label,
+                provenance: LabelProvenance {
+                    dimension,
+                    explanation: Cow::Owned(explanation),
+                    sources: Cow::Borrowed(sources),
+                    shard_refs: Cow::Borrowed(D1_SHARDS),
+                    evidence: Default::default(),
+                    thresholds: Default::default(),
+                    determinacy: Default::default(),
+                },
+                epoch_range,
+            });
+        };
+
+        if falling(&lifeforce) && rising(&decay) {
+            push(
+                MorphixTrendLabel::D1SustainedDrain,
+                GuardDimension::D1,
+                format!(
+                    "Sustained drain over {epochs}: lifeforce {:+.3}/epoch, decay {:+.3}/epoch.",
+                    lifeforce.slope, decay.slope
+                ),
+                DRAIN_SOURCES,
+            );
+        }
+        if rising(&roh) {
+            push(
+                MorphixTrendLabel::D1RisingRoH,
+                GuardDimension::D1,
+                format!("RoH rising over {epochs}: {:+.3}/epoch.", roh.slope),
+                ROH_SOURCES,
+            );
+        }
+        if rising(&lifeforce) && falling(&decay) && roh.slope <= 0.0 {
+            push(
+                MorphixTrendLabel::D3RecoveryTrajectory,
+                GuardDimension::D3,
+                format!(
+                    "Recovery trajectory over {epochs}: lifeforce {:+.3}/epoch, decay {:+.3}/epoch, RoH not rising.",
+                    lifeforce.slope, decay.slope
+                ),
+                RECOVERY_SOURCES,
+            );
+        }
+
+        MorphixGuardTrendView {
+            capability_state: inputs.last().map(|i| i.capability_state),
+            samples: inputs.len(),
+            epoch_range,
+            lifeforce,
+            decay,
+            roh,
+            diagnostics,
+            config_version: cfg.config_version.clone(),
+        }
+    }
+}
+
+const DRAIN_SOURCES: crate::Names = &[SRC_LIFEFORCE, SRC_DECAY];
+const ROH_SOURCES: crate::Names = &[SRC_ROH];
+const RECOVERY_SOURCES: crate::Names = &[SRC_LIFEFORCE, SRC_DECAY, SRC_ROH];
+
+/// Least-squares slope of `ys` against `xs`; 0 with fewer than two points or
+/// no spread in `xs`. Values are taken relative to the first, so a constant
+/// series has a slope of exactly 0.
+fn slope(xs: &[f64], ys: &[f32]) -> f32 {
+    let (Some(&y0), true) = (ys.first(), ys.len() >= 2) else {
+        return 0.0;
+    };
+    let n = xs.len() as f64;
+    let mean_x = xs.iter().sum::<f64>() / n;
+    let (mut num, mut den) = (0.0, 0.0);
+    for (&x, &y) in xs.iter().zip(ys) {
+        let dx = x - mean_x;
+        num += dx * f64::from(y - y0);
+        den += dx * dx;
+    }
+    if den > 0.0 {
+        (num / den) as f32
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BiophysicalEnvelopeSnapshot, MicroSocietyView, RoH, TreeOfLifeView};
+    use proptest::prelude::*;
+
+    fn input(epoch: Option<u64>, lifeforce: f32, decay: f32, roh: f32) -> MorphixGuardInput {
+        MorphixGuardInput {
+            capability_state: CapabilityState::ControlledHuman,
+            roh: RoH::new(roh),
+            envelope: BiophysicalEnvelopeSnapshot {
+                eeg_alpha_frac: Some(0.5),
+                eeg_gamma_frac: Some(0.5),
+                eda_tonic_frac: Some(0.5),
+                bpm_frac: Some(0.5),
+                cognitive_load_warn_frac: Some(0.1),
+                sleep_arousal_warn_frac: Some(0.1),
+                inflammation_warn_frac: Some(0.1),
+            },
+            tree_of_life: TreeOfLifeView {
+                blood: 0.5, oxygen: 0.5, wave: 0.5, h2o: 0.5, time: 0.5,
+                decay, lifeforce,
+                brain: 0.5, smart: 0.5, evolve: 0.5, power: 0.5, tech: 0.5,
+                fear: 0.2, pain: 0.2, nano: 0.5,
+            },
+            micro_society: MicroSocietyView { predicates: Vec::new() },
+            evolve_index: None,
+            epoch_index: epoch,
+        }
+    }
+
+    fn labels(view: &MorphixGuardTrendView) -> Vec<MorphixTrendLabel> {
+        view.diagnostics.iter().map(|d| d.label).collect()
+    }
+
+    #[test]
+    fn test_sustained_drain_is_labelled_but_a_spike_is_not() {
+        let cfg = MorphixGuardConfig::default();
+        // Non-contiguous epochs: 0.1 lifeforce lost per 2 epochs.
+        let drain: Vec<_> = [(3, 0.8, 0.2), (5, 0.7, 0.3), (9, 0.5, 0.5), (11, 0.4, 0.6)]
+            .into_iter()
+            .map(|(e, l, d)| input(Some(e), l, d, 0.1))
+            .collect();
+        let view = MorphixGuard::evaluate_window(&drain, &cfg);
+        assert_eq!(labels(&view), [MorphixTrendLabel::D1SustainedDrain]);
+        assert!((view.lifeforce.slope + 0.05).abs() < 1e-4);
+        assert_eq!(view.epoch_range, Some((3, 11)));
+        assert_eq!(view.diagnostics[0].epoch_range, Some((3, 11)));
+        assert!(view.diagnostics[0].provenance.explanation.contains("epochs 3–11"));
+
+        let spike: Vec<_> = [0.2, 0.2, 0.9, 0.2, 0.2]
+            .into_iter()
+            .enumerate()
+            .map(|(e, d)| input(Some(e as u64), 0.8, d, 0.1))
+            .collect();
+        assert!(MorphixGuard::evaluate_window(&spike, &cfg).diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_two_epoch_recovery_and_rising_roh() {
+        let cfg = MorphixGuardConfig::default();
+        let recovery = [input(None, 0.4, 0.6, 0.2), input(None, 0.6, 0.4, 0.2)];
+        let view = MorphixGuard::evaluate_window(&recovery, &cfg);
+        assert_eq!(labels(&view), [MorphixTrendLabel::D3RecoveryTrajectory]);
+        assert_eq!(view.epoch_range, None);
+        assert!(view.diagnostics[0].provenance.explanation.contains("2 unindexed epochs"));
+
+        let climbing = [input(Some(1), 0.6, 0.4, 0.1), input(Some(2), 0.6, 0.4, 0.2)];
+        let view = MorphixGuard::evaluate_window(&climbing, &cfg);
+        assert_eq!(labels(&view), [MorphixTrendLabel::D1RisingRoH]);
+        assert_eq!(view.roh.first, 0.1);
+
+        let single = MorphixGuard::evaluate_window(&recovery[..1], &cfg);
+        assert_eq!((single.samples, single.lifeforce.slope), (1, 0.0));
+        assert!(MorphixGuard::evaluate_window(&[], &cfg).capability_state.is_none());
+    }
+
+    proptest! {
+        #[test]
+        fn test_constant_series_never_trends(
+            lifeforce in 0.0f32..=1.0,
+            decay in 0.0f32..=1.0,
+            roh in 0.0f32..=1.0,
+            epochs in prop::collection::btree_set(0u64..1_000, 2..12),
+            thresh in 0.0f32..0.1,
+        ) {
+            let cfg = MorphixGuardConfig { trend_slope_thresh: thresh, ..MorphixGuardConfig::default() };
+            let inputs: Vec<_> = epochs.iter().map(|&e| input(Some(e), lifeforce, decay, roh)).collect();
+            let view = MorphixGuard::evaluate_window(&inputs, &cfg);
+            prop_assert!(view.diagnostics.is_empty());
+            prop_assert_eq!(view.lifeforce.slope, 0.0);
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::crypto::hash_canonical;
+
+pub use core_contract::config_version::ConfigVersion;
+
+// Config holds ledger and Tree-of-Life economics parameters.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Config {
+    pub token_mint_rate: u64, // CHURCH tokens minted per good deed
+    pub eco_grant_multiplier: u32, // Multiplier applied to Tree-of-Life eco-grants
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            token_mint_rate: 1,
+            eco_grant_multiplier: 1,
+        }
+    }
+}
+
+// Serialization format of a configuration bundle on disk.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BundleFormat {
+    Toml,
+    Json,
+}
+
+// ConfigBundle is the exact bytes of a loaded configuration plus where they came from.
+#[derive(Clone, Debug)]
+pub struct ConfigBundle {
+    pub bytes: Vec<u8>,
+    pub format: BundleFormat,
+    pub source_path: Option<String>,
+}
+
+impl ConfigBundle {
+    // Reads a bundle from disk, inferring the format from the extension.
+    pub fn read(path: &Path) -> io::Result<Self> {
+        let format = match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => BundleFormat::Json,
+            _ => BundleFormat::Toml,
+        };
+        Ok(ConfigBundle {
+            bytes: fs::read(path)?,
+            format,
+            source_path: Some(path.display().to_string()),
+        })
+    }
+
+    // Computes the version of this bundle; the hash is taken over a canonical
+    // JSON rendering with sorted keys, so field reordering does not change it.
+    pub fn version(&self, loaded_at: SystemTime) -> Result<ConfigVersion, String> {
+        Ok(ConfigVersion::new(
+            canonical_hash(self)?,
+            loaded_at,
+            self.source_path.clone(),
+        ))
+    }
+}
+
+// Returns the SHA-256 hex digest of the canonicalized bundle.
+pub fn canonical_hash(bundle: &ConfigBundle) -> Result<String, String> {
+    let text = std::str::from_utf8(&bundle.bytes).map_err(|e| format!("Config is not UTF-8: {e}"))?;
+    let value: serde_json::Value = match bundle.format {
+        BundleFormat::Toml => {
+            let parsed: toml::Value = toml::from_str(text).map_err(|e| format!("Invalid TOML: {e}"))?;
+            serde_json::to_value(parsed).map_err(|e| format!("Config conversion failed: {e}"))?
+        }
+        BundleFormat::Json => serde_json::from_str(text).map_err(|e| format!("Invalid JSON: {e}"))?,
+    };
+    // Versions taken before the switch to morphix_canonical hashed plain
+    // serde_json output, which differs for floats with an integral value
+    // ("1.0" vs "1"); such bundles get a new version and archive entry.
+    hash_canonical(&value).map_err(|e| format!("Config serialization failed: {e}"))
+}
+
+// Writes the exact bundle bytes under its hash so decisions can be traced back later.
+pub fn archive(bundle: &ConfigBundle, dir: &Path) -> Result<PathBuf, String> {
+    let hash = canonical_hash(bundle)?;
+    let ext = match bundle.format {
+        BundleFormat::Toml => "toml",
+        BundleFormat::Json => "json",
+    };
+    fs::create_dir_all(dir).map_err(|e| format!("Cannot create archive dir: {e}"))?;
+    let path = dir.join(format!("{hash}.{ext}"));
+    if !path.exists() {
+        fs::write(&path, &bundle.bytes).map_err(|e| format!("Cannot archive config: {e}"))?;
+    }
+    Ok(path)
+}
+
+// Ledger context entry recording the config version in force for a deed.
+pub fn context_entry(version: &ConfigVersion) -> (String, serde_json::Value) {
+    (
+        "config_version".to_string(),
+        serde_json::json!({
+            "hash": version.hash,
+            "source_path": version.source_path,
+        }),
+    )
+}
+
+// Replay guard: decisions made under different configs are only comparable
+// when the caller explicitly opts in (the `--cross-config` flag).
+pub fn ensure_comparable(
+    recorded: &ConfigVersion,
+    replay: &ConfigVersion,
+    cross_config: bool,
+) -> Result<(), String> {
+    recorded.ensure_comparable(replay, cross_config)
+}
+
+// Unit tests for configuration versioning.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn toml_bundle(text: &str) -> ConfigBundle {
+        ConfigBundle {
+            bytes: text.as_bytes().to_vec(),
+            format: BundleFormat::Toml,
+            source_path: None,
+        }
+    }
+
+    #[test]
+    fn test_hash_stable_across_field_reordering() {
+        let a = toml_bundle("token_mint_rate = 2\neco_grant_multiplier = 3\n[guard]\ndecay = 0.7\nfear = 0.6\n");
+        let b = toml_bundle("eco_grant_multiplier = 3\ntoken_mint_rate = 2\n[guard]\nfear = 0.6\ndecay = 0.7\n");
+        assert_eq!(canonical_hash(&a).unwrap(), canonical_hash(&b).unwrap());
+
+        let c = toml_bundle("token_mint_rate = 3\neco_grant_multiplier = 3\n[guard]\ndecay = 0.7\nfear = 0.6\n");
+        assert_ne!(canonical_hash(&a).unwrap(), canonical_hash(&c).unwrap());
+    }
+
+    #[test]
+    fn test_replay_guard_requires_cross_config() {
+        let now = SystemTime::now();
+        let a = toml_bundle("token_mint_rate = 1\n").version(now).unwrap();
+        let b = toml_bundle("token_mint_rate = 2\n").version(now).unwrap();
+        assert!(ensure_comparable(&a, &a.clone(), false).is_ok());
+        assert!(ensure_comparable(&a, &b, false).is_err());
+        assert!(ensure_comparable(&a, &b, true).is_ok());
+    }
+
+    #[test]
+    fn test_context_entry_carries_hash() {
+        let version = toml_bundle("token_mint_rate = 1\n").version(SystemTime::now()).unwrap();
+        let (key, value) = context_entry(&version);
+        assert_eq!(key, "config_version");
+        assert_eq!(value["hash"], serde_json::json!(version.hash));
+    }
+}
@@ -0,0 +1,434 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use serde::{Serialize, Deserialize};
+use tokio::sync::RwLock;
+use tracing::info;
+use uuid::Uuid;
+
+use core_contract::health::{ComponentHealth, HealthCheckable, HealthStatus};
+
+use crate::config::Config;
+use crate::utils::crypto::{hash_canonical, hash_json};
+
+pub mod privacy;
+
+// DeedEvent represents a single morally relevant action in the neuromorphic microspace.
+// It is designed as an immutable, hash-linked unit for tamper-evident auditing.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DeedEvent {
+    pub event_id: String, // UUID as string for global uniqueness
+    pub timestamp: u64, // Unix epoch in seconds
+    pub prev_hash: String, // SHA-256 hash of previous event's self_hash
+    pub self_hash: String, // SHA-256 hash of this event's canonical JSON
+    pub actor_id: String, // Unique ID of the agent performing the deed
+    pub target_ids: Vec<String>, // IDs of agents affected by the deed
+    pub deed_type: String, // High-level classification (e.g., "ecological_sustainability")
+    pub tags: Vec<String>, // Keywords for categorization (e.g., "math_science_education")
+    pub context_json: HashMap<String, serde_json::Value>, // Optional evidence or parameters
+    pub ethics_flags: Vec<String>, // Violations of ALN ethics or RoH breaches
+    pub life_harm_flag: bool, // True if the deed harmed a living creature or lifeform
+}
+
+impl DeedEvent {
+    // Creates a new DeedEvent with automatic hashing and timestamping.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        prev_hash: String,
+        actor_id: String,
+        target_ids: Vec<String>,
+        deed_type: String,
+        tags: Vec<String>,
+        context_json: HashMap<String, serde_json::Value>,
+        ethics_flags: Vec<String>,
+        life_harm_flag: bool,
+    ) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs();
+
+        let mut event = DeedEvent {
+            event_id: Uuid::new_v4().to_string(),
+            timestamp,
+            prev_hash,
+            self_hash: String::new(), // Placeholder, will be computed
+            actor_id,
+            target_ids,
+            deed_type,
+            tags,
+            context_json,
+            ethics_flags,
+            life_harm_flag,
+        };
+
+        // Compute self_hash based on canonical JSON (excluding self_hash field)
+        event.self_hash = event.canonical_hash();
+
+        event
+    }
+
+    // Replaces the generated id and timestamp (e.g. for events imported from a
+    // legacy system) and recomputes self_hash.
+    pub fn with_identity(self, event_id: String, timestamp: u64) -> Self {
+        let mut event = DeedEvent {
+            event_id,
+            timestamp,
+            self_hash: String::new(),
+            ..self
+        };
+        event.self_hash = event.canonical_hash();
+        event
+    }
+
+    // Hash over the canonical JSON of this event with self_hash empty. Key
+    // order in context_json does not affect it.
+    fn canonical_hash(&self) -> String {
+        let unhashed = DeedEvent {
+            self_hash: String::new(),
+            ..self.clone()
+        };
+        hash_canonical(&unhashed).expect("Serialization failed")
+    }
+
+    // Pre-canonicalization hash: plain serde_json output, whose key order
+    // follows context_json's HashMap iteration order.
+    fn legacy_hash(&self) -> String {
+        let unhashed = DeedEvent {
+            self_hash: String::new(),
+            ..self.clone()
+        };
+        let serialized = serde_json::to_string(&unhashed).expect("Serialization failed");
+        hash_json(&serialized)
+    }
+
+    // Validates the event's integrity against its self_hash and prev_hash.
+    pub fn validate(&self, expected_prev_hash: &str) -> bool {
+        self.validate_with(expected_prev_hash, HashCompat::CanonicalOnly)
+    }
+
+    // Like validate, but events stamped before a migration cutoff may still
+    // carry a legacy self_hash.
+    pub fn validate_with(&self, expected_prev_hash: &str, compat: HashCompat) -> bool {
+        let hash_ok = self.canonical_hash() == self.self_hash
+            || (compat.accepts_legacy(self.timestamp) && self.legacy_hash() == self.self_hash);
+        if !hash_ok {
+            info!("Self-hash mismatch for event ID: {}", self.event_id);
+            return false;
+        }
+        if self.prev_hash != expected_prev_hash {
+            info!("Prev-hash mismatch for event ID: {}", self.event_id);
+            return false;
+        }
+        true
+    }
+}
+
+// Which self_hash forms verification accepts. Events hashed before the
+// switch to canonical JSON were hashed over plain serde_json output; their
+// hashes only reproduce if context_json iterates in the original order, so
+// ledgers holding such events should be verified with AcceptLegacyBefore
+// set to the migration time and re-imported if that still fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashCompat {
+    CanonicalOnly,
+    // Unix seconds; events with an earlier timestamp may match either form.
+    AcceptLegacyBefore(u64),
+}
+
+impl HashCompat {
+    fn accepts_legacy(self, timestamp: u64) -> bool {
+        match self {
+            HashCompat::CanonicalOnly => false,
+            HashCompat::AcceptLegacyBefore(cutoff) => timestamp < cutoff,
+        }
+    }
+}
+
+// Ledger manages the chain of DeedEvents, ensuring append-only immutability.
+#[derive(Clone)]
+pub struct Ledger {
+    events: Arc<RwLock<Vec<DeedEvent>>>,
+    config: Config,
+}
+
+impl Ledger {
+    pub fn new(config: Config) -> Self {
+        Ledger {
+            events: Arc::new(RwLock::new(Vec::new())),
+            config,
+        }
+    }
+
+    // Appends a new DeedEvent to the ledger after validation.
+    pub async fn append(&self, event: DeedEvent) -> Result<(), String> {
+        let mut events = self.events.write().await;
+        let expected_prev_hash = if events.is_empty() {
+            "genesis".to_string() // Initial hash for the chain
+        } else {
+            events.last().unwrap().self_hash.clone()
+        };
+
+        if !event.validate(&expected_prev_hash) {
+            return Err("Event validation failed".to_string());
+        }
+
+        events.push(event);
+        info!("Appended DeedEvent ID: {} with type: {}", events.last().unwrap().event_id, events.last().unwrap().deed_type);
+        Ok(())
+    }
+
+    // Hash the next appended event must link to.
+    pub async fn head_hash(&self) -> String {
+        let events = self.events.read().await;
+        events
+            .last()
+            .map(|e| e.self_hash.clone())
+            .unwrap_or_else(|| "genesis".to_string())
+    }
+
+    // Re-validates every event's self_hash and link; returns the chain length.
+    pub async fn verify(&self) -> Result<usize, String> {
+        self.verify_chain(HashCompat::CanonicalOnly).await
+    }
+
+    // verify with an explicit policy for pre-migration (legacy) hashes.
+    pub async fn verify_chain(&self, compat: HashCompat) -> Result<usize, String> {
+        let events = self.events.read().await;
+        let mut expected_prev = "genesis";
+        for event in events.iter() {
+            if !event.validate_with(expected_prev, compat) {
+                return Err(format!("Chain broken at event {}", event.event_id));
+            }
+            expected_prev = &event.self_hash;
+        }
+        Ok(events.len())
+    }
+
+    // Computes metrics over the ledger for CHURCH token minting.
+    pub async fn compute_metrics(&self) -> Metrics {
+        let events = self.events.read().await;
+        let mut good_deeds = 0;
+        let mut harm_flags = 0;
+        let mut per_actor = BTreeMap::new();
+        let mut per_deed_type = BTreeMap::new();
+
+        for event in events.iter() {
+            if event.life_harm_flag {
+                harm_flags += 1;
+            } else {
+                good_deeds += 1;
+            }
+            *per_actor.entry(event.actor_id.clone()).or_insert(0) += 1;
+            *per_deed_type.entry(event.deed_type.clone()).or_insert(0) += 1;
+        }
+
+        Metrics {
+            total_events: events.len() as u64,
+            good_deeds,
+            harm_flags,
+            balance: Balance { church_tokens: good_deeds * self.config.token_mint_rate },
+            per_actor,
+            per_deed_type,
+        }
+    }
+}
+
+// Readiness probe: the chain must be lockable and hash-linked end to end.
+// A busy lock is reported as Degraded; a broken link makes the store Unhealthy.
+impl HealthCheckable for Ledger {
+    fn health(&self) -> ComponentHealth {
+        let events = match self.events.try_read() {
+            Ok(events) => events,
+            Err(_) => {
+                return ComponentHealth::new("ledger", HealthStatus::Degraded, "ledger lock busy; writes are queueing")
+            }
+        };
+
+        let mut expected_prev = "genesis";
+        for event in events.iter() {
+            if event.prev_hash != expected_prev {
+                return ComponentHealth::new(
+                    "ledger",
+                    HealthStatus::Unhealthy,
+                    format!("chain broken at event {}", event.event_id),
+                );
+            }
+            expected_prev = &event.self_hash;
+        }
+        ComponentHealth::new("ledger", HealthStatus::Healthy, format!("{} events linked", events.len()))
+    }
+}
+
+// Metrics for ledger analysis, supporting eco_grants and debt_ceiling adjustments.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Metrics {
+    pub total_events: u64,
+    pub good_deeds: u64,
+    pub harm_flags: u64,
+    pub balance: Balance,
+    #[serde(default)]
+    pub per_actor: BTreeMap<String, u64>, // Deed count per actor_id
+    #[serde(default)]
+    pub per_deed_type: BTreeMap<String, u64>, // Deed count per deed_type
+}
+
+// Balance represents accumulated CHURCH tokens from good deeds.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Balance {
+    pub church_tokens: u64,
+}
+
+// Unit tests for the ledger module.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[tokio::test]
+    async fn test_ledger_append_and_validate() {
+        let config = Config::default();
+        let ledger = Ledger::new(config);
+
+        let event1 = DeedEvent::new(
+            "genesis".to_string(),
+            "actor1".to_string(),
+            vec!["target1".to_string()],
+            "ecological_sustainability".to_string(),
+            vec!["math_science_education".to_string()],
+            HashMap::new(),
+            vec![],
+            false,
+        );
+
+        assert!(ledger.append(event1.clone()).await.is_ok());
+
+        let event2 = DeedEvent::new(
+            event1.self_hash.clone(),
+            "actor2".to_string(),
+            vec!["target2".to_string()],
+            "homelessness_relief".to_string(),
+            vec!["civic_duty".to_string()],
+            HashMap::new(),
+            vec![],
+            false,
+        );
+
+        assert!(ledger.append(event2).await.is_ok());
+        assert_eq!(ledger.verify().await, Ok(2));
+
+        let metrics = ledger.compute_metrics().await;
+        assert_eq!(metrics.good_deeds, 2);
+        assert_eq!(metrics.harm_flags, 0);
+    }
+
+    #[tokio::test]
+    async fn test_with_identity_rehashes_and_links() {
+        let ledger = Ledger::new(Config::default());
+        let event = DeedEvent::new(
+            ledger.head_hash().await,
+            "actor1".to_string(),
+            vec![],
+            "ecological_sustainability".to_string(),
+            vec![],
+            HashMap::new(),
+            vec![],
+            false,
+        );
+        let original_hash = event.self_hash.clone();
+        let imported = event.with_identity("legacy-1".to_string(), 1_600_000_000);
+
+        assert_ne!(imported.self_hash, original_hash);
+        assert!(imported.validate("genesis"));
+        ledger.append(imported.clone()).await.unwrap();
+        assert_eq!(ledger.head_hash().await, imported.self_hash);
+    }
+
+    fn context(pairs: &[(&str, serde_json::Value)]) -> HashMap<String, serde_json::Value> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.clone())).collect()
+    }
+
+    #[test]
+    fn test_self_hash_ignores_context_key_order() {
+        let pairs = [
+            ("river", serde_json::json!(1.5)),
+            ("canopy", serde_json::json!({"b": 2, "a": 1})),
+            ("soil", serde_json::json!("loam")),
+            ("pollinator", serde_json::json!([3, 2, 1])),
+        ];
+        let mut reversed = pairs.clone();
+        reversed.reverse();
+
+        let event = DeedEvent::new(
+            "genesis".to_string(),
+            "actor1".to_string(),
+            vec![],
+            "ecological_sustainability".to_string(),
+            vec![],
+            context(&pairs),
+            vec![],
+            false,
+        )
+        .with_identity("evt-1".to_string(), 1_700_000_000);
+        for order in [&pairs, &reversed] {
+            for _ in 0..16 {
+                let rebuilt = DeedEvent {
+                    context_json: context(order),
+                    ..event.clone()
+                };
+                assert_eq!(rebuilt.canonical_hash(), event.self_hash);
+                assert!(rebuilt.validate("genesis"));
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_chain_accepts_legacy_hashes_only_before_cutoff() {
+        let ledger = Ledger::new(Config::default());
+        let mut legacy = DeedEvent::new(
+            "genesis".to_string(),
+            "actor1".to_string(),
+            vec![],
+            "ecological_sustainability".to_string(),
+            vec![],
+            context(&[("a", serde_json::json!(1.0)), ("b", serde_json::json!(2))]),
+            vec![],
+            false,
+        )
+        .with_identity("legacy-1".to_string(), 1_600_000_000);
+        legacy.self_hash = legacy.legacy_hash();
+        assert_ne!(legacy.self_hash, legacy.canonical_hash());
+        ledger.events.write().await.push(legacy.clone());
+
+        assert!(ledger.verify().await.is_err());
+        assert!(ledger.verify_chain(HashCompat::AcceptLegacyBefore(1_600_000_000)).await.is_err());
+        assert_eq!(ledger.verify_chain(HashCompat::AcceptLegacyBefore(1_700_000_000)).await, Ok(1));
+
+        // New events must still be canonical.
+        assert!(ledger.append(DeedEvent { prev_hash: legacy.self_hash.clone(), ..legacy }).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_ledger_health_paths() {
+        let ledger = Ledger::new(Config::default());
+        assert_eq!(ledger.health().status, HealthStatus::Healthy);
+
+        {
+            let _writer = ledger.events.write().await;
+            assert_eq!(ledger.health().status, HealthStatus::Degraded);
+        }
+
+        let orphan = DeedEvent::new(
+            "not-genesis".to_string(),
+            "actor1".to_string(),
+            vec![],
+            "ecological_sustainability".to_string(),
+            vec![],
+            HashMap::new(),
+            vec![],
+            false,
+        );
+        ledger.events.write().await.push(orphan);
+        assert_eq!(ledger.health().status, HealthStatus::Unhealthy);
+    }
+}
@@ -0,0 +1,294 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::{Balance, Metrics};
+
+// Differentially private export of ledger metrics.
+//
+// Sensitivity analysis: adding or removing one DeedEvent changes exactly one
+// per-actor count by 1, one per-deed-type count by 1, and either good_deeds or
+// harm_flags by 1. The released vector therefore has L1 sensitivity 3, and
+// every released count gets Laplace noise with scale 3 / epsilon, which gives
+// epsilon-DP for the whole export. total_events, church_tokens, and the
+// consistency rescaling below are post-processing and cost no extra budget.
+//
+// The key sets (actor ids, deed types) are released as-is and must be treated
+// as public, e.g. the list of registered actors; only the counts are protected.
+pub const L1_SENSITIVITY: f64 = 3.0;
+
+// Noise mechanism recorded in every DpReport.
+pub const MECHANISM: &str = "laplace";
+
+// DpSeed drives the noise of one export. `seed` must come from a CSPRNG
+// (DpSeed::generate): anyone who can guess it can regenerate the noise and
+// subtract it. The salt blinds the published commitment, so a small or
+// guessable seed cannot be recovered from it either; keep the DpSeed private
+// and disclose both fields only to the auditor.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DpSeed {
+    pub seed: u64,
+    pub salt: [u8; 16],
+}
+
+impl DpSeed {
+    // Draws seed and salt from the operating system's CSPRNG.
+    pub fn generate() -> Result<Self, String> {
+        let mut bytes = [0u8; 24];
+        getrandom::getrandom(&mut bytes).map_err(|e| format!("Cannot draw DP seed: {e}"))?;
+        let (seed, salt) = bytes.split_at(8);
+        Ok(DpSeed {
+            seed: u64::from_le_bytes(seed.try_into().expect("8 bytes")),
+            salt: salt.try_into().expect("16 bytes"),
+        })
+    }
+
+    // SHA-256(salt || seed as little-endian bytes), hex-encoded.
+    pub fn commitment(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.salt);
+        hasher.update(self.seed.to_le_bytes());
+        hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect()
+    }
+}
+
+// DpReport tells consumers that noise was applied and with what budget.
+// The seed and salt are withheld; the commitment lets an auditor who is later
+// given the DpSeed confirm the export was produced with it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DpReport {
+    pub epsilon: f64,
+    pub mechanism: String,
+    pub seed_commitment: String,
+}
+
+impl DpReport {
+    // True when `seed` is the one this export committed to.
+    pub fn verify_seed(&self, seed: &DpSeed) -> bool {
+        self.seed_commitment == seed.commitment()
+    }
+}
+
+// DpMetrics are noised Metrics. Post-processing guarantees all counts are
+// non-negative, total_events == good_deeds + harm_flags, and both per-actor and
+// per-deed-type counts sum exactly to total_events (tolerance 0).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DpMetrics {
+    pub total_events: u64,
+    pub good_deeds: u64,
+    pub harm_flags: u64,
+    pub balance: Balance,
+    pub per_actor: BTreeMap<String, u64>,
+    pub per_deed_type: BTreeMap<String, u64>,
+    pub report: DpReport,
+}
+
+// Applies calibrated Laplace noise to the metrics. The same DpSeed always
+// yields the same output. Fails unless epsilon is positive and finite.
+pub fn dp_metrics(metrics: &Metrics, epsilon: f64, seed: &DpSeed) -> Result<DpMetrics, String> {
+    if !(epsilon.is_finite() && epsilon > 0.0) {
+        return Err(format!("epsilon must be positive and finite, got {epsilon}"));
+    }
+    let scale = L1_SENSITIVITY / epsilon;
+    let mut rng = SplitMix64(seed.seed);
+
+    let good_deeds = noisy_count(metrics.good_deeds, scale, &mut rng);
+    let harm_flags = noisy_count(metrics.harm_flags, scale, &mut rng);
+    let total_events = good_deeds + harm_flags;
+
+    // BTreeMap iteration order is fixed, so noise draws are deterministic per key.
+    let per_actor = metrics
+        .per_actor
+        .iter()
+        .map(|(k, v)| (k.clone(), noisy_value(*v, scale, &mut rng)))
+        .collect();
+    let per_deed_type = metrics
+        .per_deed_type
+        .iter()
+        .map(|(k, v)| (k.clone(), noisy_value(*v, scale, &mut rng)))
+        .collect();
+
+    // CHURCH minting is linear in good deeds at the public mint rate, recovered
+    // from the exact metrics; with no good deeds the rate is unknown and no
+    // CHURCH is reported.
+    let mint_rate = metrics
+        .balance
+        .church_tokens
+        .checked_div(metrics.good_deeds)
+        .unwrap_or(0);
+
+    Ok(DpMetrics {
+        total_events,
+        good_deeds,
+        harm_flags,
+        balance: Balance { church_tokens: good_deeds * mint_rate },
+        per_actor: apportion(per_actor, total_events),
+        per_deed_type: apportion(per_deed_type, total_events),
+        report: DpReport {
+            epsilon,
+            mechanism: MECHANISM.to_string(),
+            seed_commitment: seed.commitment(),
+        },
+    })
+}
+
+fn noisy_value(count: u64, scale: f64, rng: &mut SplitMix64) -> f64 {
+    (count as f64 + laplace(scale, rng)).max(0.0)
+}
+
+fn noisy_count(count: u64, scale: f64, rng: &mut SplitMix64) -> u64 {
+    noisy_value(count, scale, rng).round() as u64
+}
+
+// Rescales non-negative noisy values to integers summing exactly to `total`
+// using largest-remainder rounding. If every value clamped to zero the total is
+// spread evenly in key order; with no keys at all nothing can be attributed.
+fn apportion(values: BTreeMap<String, f64>, total: u64) -> BTreeMap<String, u64> {
+    if values.is_empty() {
+        return BTreeMap::new();
+    }
+    let sum: f64 = values.values().sum();
+    let n = values.len() as f64;
+    let shares: Vec<(String, f64)> = values
+        .into_iter()
+        .map(|(k, v)| {
+            let share = if sum > 0.0 { v / sum } else { 1.0 / n };
+            (k, share * total as f64)
+        })
+        .collect();
+
+    let mut out: BTreeMap<String, u64> = shares.iter().map(|(k, v)| (k.clone(), v.floor() as u64)).collect();
+    let assigned: u64 = out.values().sum();
+    let mut remainders: Vec<(&String, f64)> = shares.iter().map(|(k, v)| (k, v - v.floor())).collect();
+    remainders.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal).then(a.0.cmp(b.0)));
+    for (key, _) in remainders.iter().cycle().take(total.saturating_sub(assigned) as usize) {
+        *out.get_mut(*key).expect("key from shares") += 1;
+    }
+    out
+}
+
+// Laplace(0, scale) sample by inverse CDF.
+fn laplace(scale: f64, rng: &mut SplitMix64) -> f64 {
+    // u in (-0.5, 0.5); the open interval keeps ln() finite.
+    let u = rng.next_f64_open() - 0.5;
+    -scale * u.signum() * (1.0 - 2.0 * u.abs()).ln()
+}
+
+// SplitMix64: small, dependency-free, and stable across platforms, which keeps
+// exports reproducible for a given seed.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in the open interval (0, 1).
+    fn next_f64_open(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    }
+}
+
+// Property tests for the DP export.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics(good: u64, harm: u64) -> Metrics {
+        let mut per_actor = BTreeMap::new();
+        per_actor.insert("actor1".to_string(), good / 2 + harm);
+        per_actor.insert("actor2".to_string(), good - good / 2);
+        per_actor.insert("actor3".to_string(), 0);
+        let mut per_deed_type = BTreeMap::new();
+        per_deed_type.insert("ecological_sustainability".to_string(), good);
+        per_deed_type.insert("harm".to_string(), harm);
+        Metrics {
+            total_events: good + harm,
+            good_deeds: good,
+            harm_flags: harm,
+            balance: Balance { church_tokens: good * 10 },
+            per_actor,
+            per_deed_type,
+        }
+    }
+
+    fn seed(seed: u64) -> DpSeed {
+        DpSeed { seed, salt: [7; 16] }
+    }
+
+    #[test]
+    fn test_noise_scales_inversely_with_epsilon() {
+        let m = metrics(100_000, 0);
+        let mean_abs_error = |epsilon: f64| {
+            let runs = 2_000;
+            (0..runs)
+                .map(|s| (dp_metrics(&m, epsilon, &seed(s)).unwrap().good_deeds as f64 - 100_000.0).abs())
+                .sum::<f64>()
+                / runs as f64
+        };
+        // E|Laplace(b)| = b = 3 / epsilon.
+        let loose = mean_abs_error(0.1);
+        let tight = mean_abs_error(1.0);
+        assert!((loose - 30.0).abs() < 4.0, "epsilon 0.1 error {loose}");
+        assert!((tight - 3.0).abs() < 0.5, "epsilon 1.0 error {tight}");
+    }
+
+    #[test]
+    fn test_deterministic_for_fixed_seed() {
+        let m = metrics(40, 5);
+        let dp = |s: u64| dp_metrics(&m, 0.5, &seed(s)).unwrap();
+        assert_eq!(dp(7), dp(7));
+        assert_ne!(dp(7).report.seed_commitment, dp(8).report.seed_commitment);
+        assert!((0..20).any(|s| dp(s).good_deeds != dp(7).good_deeds));
+    }
+
+    #[test]
+    fn test_commitment_is_salted_and_verifiable() {
+        let m = metrics(40, 5);
+        let report = dp_metrics(&m, 0.5, &seed(7)).unwrap().report;
+        assert!(report.verify_seed(&seed(7)));
+        assert!(!report.verify_seed(&seed(8)));
+        assert!(!report.verify_seed(&DpSeed { seed: 7, salt: [8; 16] }));
+        // An unsalted hash of the seed no longer matches.
+        let unsalted: String = Sha256::digest(7u64.to_le_bytes()).iter().map(|b| format!("{:02x}", b)).collect();
+        assert_ne!(report.seed_commitment, unsalted);
+
+        let a = DpSeed::generate().unwrap();
+        let b = DpSeed::generate().unwrap();
+        assert_ne!(a, b);
+        assert_ne!(a.commitment(), b.commitment());
+    }
+
+    #[test]
+    fn test_invalid_epsilon_is_an_error() {
+        let m = metrics(40, 5);
+        for epsilon in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let err = dp_metrics(&m, epsilon, &seed(7)).unwrap_err();
+            assert!(err.starts_with("epsilon must be positive and finite"), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_post_conditions_always_hold() {
+        for (good, harm) in [(0, 0), (1, 0), (3, 2), (40, 5), (1_000, 30)] {
+            let m = metrics(good, harm);
+            for epsilon in [0.01, 0.1, 1.0, 10.0] {
+                for s in 0..200 {
+                    let dp = dp_metrics(&m, epsilon, &seed(s)).unwrap();
+                    assert_eq!(dp.total_events, dp.good_deeds + dp.harm_flags);
+                    assert_eq!(dp.per_actor.values().sum::<u64>(), dp.total_events);
+                    assert_eq!(dp.per_deed_type.values().sum::<u64>(), dp.total_events);
+                    if good > 0 {
+                        assert_eq!(dp.balance.church_tokens, dp.good_deeds * 10);
+                    }
+                    assert_eq!(dp.report.mechanism, MECHANISM);
+                }
+            }
+        }
+    }
+}
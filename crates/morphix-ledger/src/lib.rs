@@ -0,0 +1,10 @@
+//! Hash-linked deed ledger, Tree-of-Life evaluation, and the configuration
+//! bundles they are parameterized by.
+
+pub mod config;
+pub mod ledger;
+pub mod tree_of_life;
+mod utils;
+
+pub use ledger::{privacy, Balance, DeedEvent, HashCompat, Ledger, Metrics};
+pub use tree_of_life::{TreeBranch, TreeOfLife, TreeTrait};
@@ -1,12 +1,11 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use serde::{Serialize, Deserialize};
 use tokio::sync::RwLock;
 use tracing::info;
-use uuid::Uuid;
 
 use crate::config::Config;
 use crate::ledger::{DeedEvent, Ledger};
-use crate::utils::crypto::hash_json;
 
 // TreeBranch defines the hierarchical structure of Tree-of-Life traits, grouped into branches for moral and biophysical evaluation.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
@@ -88,9 +87,29 @@ impl TreeOfLife {
         }
     }
 
+    // Ledger whose deeds this tree evaluates.
+    pub fn ledger(&self) -> &Ledger {
+        &self.ledger
+    }
+
     // Evaluates a deed against Tree-of-Life traits, updating the trait map and computing balances.
     pub async fn evaluate_deed(&self, event: &DeedEvent) -> Result<i64, String> {
         let mut traits = self.traits.write().await;
+        let total_score = Self::score_into(&mut traits, event);
+        info!("Evaluated DeedEvent ID: {} with total moral score: {}", event.event_id, total_score);
+        Ok(total_score)
+    }
+
+    // Backlog mode: evaluates historical deeds in order under a single lock and
+    // logs one summary line instead of one per deed. Returns the summed score.
+    pub async fn evaluate_backlog(&self, events: &[DeedEvent]) -> Result<i64, String> {
+        let mut traits = self.traits.write().await;
+        let total_score = events.iter().map(|event| Self::score_into(&mut traits, event)).sum();
+        info!("Evaluated backlog of {} DeedEvents with total moral score: {}", events.len(), total_score);
+        Ok(total_score)
+    }
+
+    fn score_into(traits: &mut HashMap<String, TreeBranch>, event: &DeedEvent) -> i64 {
         let mut total_score = 0;
 
         // Example evaluation: Map deed_type to relevant traits and compute scores
@@ -100,15 +119,13 @@ impl TreeOfLife {
             _ => vec![TreeTrait::Knowledge], // Default to learning/knowledge for unknown deeds
         };
 
-        for trait in relevant_traits {
-            let score = trait.moral_score(event);
+        for tree_trait in relevant_traits {
+            let score = tree_trait.moral_score(event);
             total_score += score;
-            let branch = trait.branch();
+            let branch = tree_trait.branch();
             traits.insert(event.event_id.clone(), branch);
         }
-
-        info!("Evaluated DeedEvent ID: {} with total moral score: {}", event.event_id, total_score);
-        Ok(total_score)
+        total_score
     }
 
     // Computes eco-grants based on accumulated trait scores for CHURCH token minting.
@@ -159,4 +176,36 @@ mod tests {
         let grant = tree.compute_eco_grant().await;
         assert!(grant > 0);
     }
+
+    #[tokio::test]
+    async fn test_backlog_matches_per_deed_evaluation() {
+        let config = Config::default();
+        let deed = |deed_type: &str, harm: bool| {
+            DeedEvent::new(
+                "genesis".to_string(),
+                "actor1".to_string(),
+                vec![],
+                deed_type.to_string(),
+                vec![],
+                HashMap::new(),
+                vec![],
+                harm,
+            )
+        };
+        let events = vec![
+            deed("ecological_sustainability", false),
+            deed("homelessness_relief", true),
+            deed("tutoring", false),
+        ];
+
+        let one_by_one = TreeOfLife::new(Ledger::new(config.clone()), config.clone());
+        let mut expected = 0;
+        for event in &events {
+            expected += one_by_one.evaluate_deed(event).await.unwrap();
+        }
+
+        let backlog = TreeOfLife::new(Ledger::new(config.clone()), config);
+        assert_eq!(backlog.evaluate_backlog(&events).await.unwrap(), expected);
+        assert_eq!(backlog.compute_eco_grant().await, one_by_one.compute_eco_grant().await);
+    }
 }
@@ -0,0 +1,20 @@
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+// Returns the SHA-256 hex digest of a serialized JSON document.
+pub fn hash_json(json: &str) -> String {
+    hash_bytes(json.as_bytes())
+}
+
+// Returns the SHA-256 hex digest of the canonical JSON form of `value`.
+pub fn hash_canonical<T: Serialize + ?Sized>(value: &T) -> Result<String, String> {
+    let bytes = morphix_canonical::canonical_json(value).map_err(|e| e.to_string())?;
+    Ok(hash_bytes(&bytes))
+}
+
+fn hash_bytes(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
@@ -0,0 +1,242 @@
+//! Ledger-backed audit sink for orchestrator distillations.
+//!
+//! `LedgerAuditSink` files every `DistillationRecord` as a `DeedEvent` of
+//! type `neuromorph_distillation`, linked after the ledger head. Refusal
+//! codes become ethics flags so ledger metrics can count them. Appends that
+//! lose a race with another writer fail validation; like any other append
+//! failure they are kept in `failures()` and never change the distillation
+//! result.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use morphix_ledger::config::context_entry;
+use morphix_ledger::{DeedEvent, Ledger};
+use orchestration::audit::{
+    AuditFuture, DistillationAuditSink, DistillationOutcome, DistillationRecord,
+    DISTILLATION_DEED_TYPE,
+};
+
+pub struct LedgerAuditSink {
+    ledger: Ledger,
+    actor_id: String,
+    failures: Mutex<Vec<String>>,
+}
+
+impl LedgerAuditSink {
+    /// Records appended by this sink carry `actor_id`.
+    pub fn new(ledger: Ledger, actor_id: impl Into<String>) -> Self {
+        Self {
+            ledger,
+            actor_id: actor_id.into(),
+            failures: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn ledger(&self) -> &Ledger {
+        &self.ledger
+    }
+
+    /// Append failures so far, one line per failed record.
+    pub fn failures(&self) -> Vec<String> {
+        self.failures.lock().map(|f| f.clone()).unwrap_or_default()
+    }
+}
+
+/// Ledger event for `record`, linked after `prev_hash`.
+pub fn to_deed_event(
+    record: &DistillationRecord,
+    prev_hash: String,
+    actor_id: String,
+) -> DeedEvent {
+    let mut context = HashMap::new();
+    context.insert(
+        "artifact_id".to_string(),
+        serde_json::json!(record.artifact_id),
+    );
+    context.insert(
+        "corridor_id".to_string(),
+        serde_json::json!(record.corridor_id),
+    );
+    context.insert(
+        "role".to_string(),
+        serde_json::json!(format!("{:?}", record.role)),
+    );
+    context.insert("purpose".to_string(), serde_json::json!(record.purpose));
+    if let Some(version) = &record.config_version {
+        let (key, value) = context_entry(version);
+        context.insert(key, value);
+    }
+    context.insert(
+        "data_labels".to_string(),
+        serde_json::to_value(&record.data_labels).unwrap_or_default(),
+    );
+
+    let mut ethics_flags = Vec::new();
+    match &record.outcome {
+        DistillationOutcome::Distilled {
+            access_class,
+            knowledge_factor,
+            hex_stamp,
+            restrictions,
+        } => {
+            context.insert(
+                "access_class".to_string(),
+                serde_json::json!(format!("{access_class:?}")),
+            );
+            context.insert(
+                "knowledge_factor".to_string(),
+                serde_json::json!(knowledge_factor),
+            );
+            context.insert("hex_stamp".to_string(), serde_json::json!(hex_stamp));
+            let codes: Vec<&str> = restrictions.iter().map(|r| r.code()).collect();
+            context.insert("restrictions".to_string(), serde_json::json!(codes));
+        }
+        DistillationOutcome::Refused { code, reason } => {
+            context.insert("refusal".to_string(), serde_json::json!(reason));
+            ethics_flags.push(code.to_string());
+        }
+    }
+
+    DeedEvent::new(
+        prev_hash,
+        actor_id,
+        vec![record.artifact_id.clone()],
+        DISTILLATION_DEED_TYPE.to_string(),
+        vec!["distillation".to_string()],
+        context,
+        ethics_flags,
+        false,
+    )
+}
+
+impl DistillationAuditSink for LedgerAuditSink {
+    fn record<'a>(&'a self, record: &'a DistillationRecord) -> AuditFuture<'a> {
+        Box::pin(async move {
+            let prev_hash = self.ledger.head_hash().await;
+            let event = to_deed_event(record, prev_hash, self.actor_id.clone());
+            self.ledger.append(event).await
+        })
+    }
+
+    fn on_record_failed(&self, record: &DistillationRecord, error: &str) {
+        if let Ok(mut failures) = self.failures.lock() {
+            failures.push(format!("{}: {error}", record.artifact_id));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core_contract::eco::{CorridorId, EcoImpactMetrics, NeuromorphArtifact};
+    use core_contract::eco_source::{EcoDataSource, EcoSourceError, SyncEcoAdapter};
+    use core_contract::{DefaultSovereignNeuromorphContract, RoleTier};
+    use morphix_ledger::config::{Config, ConfigVersion};
+    use orchestration::NeuromorphOrchestrator;
+    use std::time::UNIX_EPOCH;
+
+    struct DeclaredEco;
+
+    impl EcoDataSource for DeclaredEco {
+        fn calculate(
+            &self,
+            artifact: &NeuromorphArtifact,
+        ) -> Result<EcoImpactMetrics, EcoSourceError> {
+            Ok(artifact.eco_impact.clone())
+        }
+
+        fn provenance_label(&self) -> &'static str {
+            "declared"
+        }
+    }
+
+    fn artifact(id: &str) -> NeuromorphArtifact {
+        NeuromorphArtifact {
+            id: id.to_string(),
+            corridor_id: CorridorId("phoenix-canal".to_string()),
+            eco_impact: EcoImpactMetrics {
+                climate_score: 0.9,
+                biodiversity_score: 0.9,
+                biosphere_score: 0.9,
+                corridor_score: 0.9,
+            },
+            summary: "Canal shade survey".to_string(),
+            data_labels: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_two_distillations_append_two_linked_events() {
+        let ledger = Ledger::new(Config::default());
+        let orchestrator = NeuromorphOrchestrator::new(
+            DefaultSovereignNeuromorphContract::new(true, true, true),
+            SyncEcoAdapter(DeclaredEco),
+        )
+        .with_audit_sink(LedgerAuditSink::new(ledger.clone(), "orchestrator"));
+
+        orchestrator
+            .distill_neuromorph_content_async(
+                RoleTier::Learner,
+                artifact("a-1"),
+                "education",
+                false,
+                false,
+                true,
+                true,
+            )
+            .await
+            .unwrap();
+        orchestrator
+            .distill_neuromorph_content_async(
+                RoleTier::Learner,
+                artifact("a-2"),
+                "education",
+                false,
+                false,
+                false,
+                true,
+            )
+            .await
+            .unwrap_err();
+
+        assert_eq!(ledger.verify().await, Ok(2));
+        let metrics = ledger.compute_metrics().await;
+        assert_eq!(metrics.per_deed_type[DISTILLATION_DEED_TYPE], 2);
+    }
+
+    #[test]
+    fn test_refusal_reason_lands_in_ethics_flags() {
+        let record = DistillationRecord {
+            artifact_id: "a-3".to_string(),
+            corridor_id: "phoenix-canal".to_string(),
+            role: RoleTier::Teacher,
+            purpose: "commercial".to_string(),
+            data_labels: Default::default(),
+            outcome: DistillationOutcome::Refused {
+                code: "PurposeProhibited",
+                reason: "PurposeProhibited: ...".to_string(),
+            },
+            config_version: None,
+        };
+        let event = to_deed_event(&record, "genesis".to_string(), "orchestrator".to_string());
+        assert_eq!(event.deed_type, DISTILLATION_DEED_TYPE);
+        assert_eq!(event.ethics_flags, ["PurposeProhibited"]);
+        assert_eq!(event.context_json["artifact_id"], "a-3");
+        assert_eq!(event.context_json["corridor_id"], "phoenix-canal");
+        assert!(event.validate("genesis"));
+        assert!(!event.context_json.contains_key("config_version"));
+
+        let version = ConfigVersion::new("c0ffee", UNIX_EPOCH, Some("morphix.toml".to_string()));
+        let record = DistillationRecord {
+            config_version: Some(version),
+            ..record
+        };
+        let event = to_deed_event(&record, "genesis".to_string(), "orchestrator".to_string());
+        assert_eq!(event.context_json["config_version"]["hash"], "c0ffee");
+        assert_eq!(
+            event.context_json["config_version"]["source_path"],
+            "morphix.toml"
+        );
+    }
+}
@@ -0,0 +1,611 @@
+//! Imports an externally produced deed history into a `Ledger` and rebuilds
+//! derived Tree-of-Life (and optionally guard) state from it.
+//!
+//! Legacy rows are converted to `DeedEvent`s with deterministic UUIDv5 ids,
+//! sorted by timestamp, chained onto the ledger head and evaluated through
+//! `TreeOfLife` in backlog mode. Rows that cannot be converted are skipped
+//! and reported, never guessed at.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use uuid::Uuid;
+
+use morphix_ledger::{DeedEvent, Ledger, TreeOfLife};
+
+/// Namespace for backfilled event ids; fixed so re-importing the same
+/// history yields the same ids.
+const BACKFILL_NAMESPACE: Uuid = Uuid::from_u128(0x6d6f_7270_6869_7842_6163_6b66_696c_6c00);
+
+/// Context key recording which legacy source an event came from.
+pub const CONTEXT_BACKFILL_SOURCE_KEY: &str = "backfill_source";
+
+/// Which legacy column feeds which `DeedEvent` field. Columns not named here
+/// are copied verbatim into `context_json`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnMapping {
+    /// Legacy primary key; seeds the UUIDv5 id when present.
+    pub legacy_id: Option<String>,
+    /// Unix seconds or `YYYY-MM-DDTHH:MM:SSZ`.
+    pub timestamp: String,
+    pub actor: String,
+    pub deed_type: String,
+    pub targets: Option<String>,
+    pub tags: Option<String>,
+    pub life_harm: Option<String>,
+    pub ethics_flags: Option<String>,
+    /// Separator inside list-valued columns.
+    pub list_separator: char,
+}
+
+impl Default for ColumnMapping {
+    fn default() -> Self {
+        ColumnMapping {
+            legacy_id: Some("legacy_id".to_string()),
+            timestamp: "timestamp".to_string(),
+            actor: "actor_id".to_string(),
+            deed_type: "deed_type".to_string(),
+            targets: Some("target_ids".to_string()),
+            tags: Some("tags".to_string()),
+            life_harm: Some("life_harm".to_string()),
+            ethics_flags: Some("ethics_flags".to_string()),
+            list_separator: ';',
+        }
+    }
+}
+
+/// Legacy export to import.
+#[derive(Clone, Debug)]
+pub enum BackfillSource {
+    /// CSV with a header row.
+    Csv {
+        text: String,
+        mapping: ColumnMapping,
+    },
+    /// JSON array of flat objects; list-valued fields may be arrays.
+    Json {
+        text: String,
+        mapping: ColumnMapping,
+    },
+}
+
+impl BackfillSource {
+    /// Reads a source from disk; `.json` files are JSON, anything else CSV.
+    pub fn read(path: &Path, mapping: ColumnMapping) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read {}: {e}", path.display()))?;
+        Ok(match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => BackfillSource::Json { text, mapping },
+            _ => BackfillSource::Csv { text, mapping },
+        })
+    }
+
+    fn mapping(&self) -> &ColumnMapping {
+        match self {
+            BackfillSource::Csv { mapping, .. } | BackfillSource::Json { mapping, .. } => mapping,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BackfillOptions {
+    /// Names the legacy system; part of every generated id and recorded in
+    /// each event's context.
+    pub source_name: String,
+    /// Convert, sort and chain, but leave the ledger and tree untouched.
+    pub dry_run: bool,
+    /// Summarize the imported history into a guard session baseline.
+    pub guard_baseline: bool,
+}
+
+impl Default for BackfillOptions {
+    fn default() -> Self {
+        BackfillOptions {
+            source_name: "legacy".to_string(),
+            dry_run: false,
+            guard_baseline: false,
+        }
+    }
+}
+
+/// Rows are numbered from 1 in source order, excluding any header.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConvertedDeed {
+    pub row: usize,
+    pub event_id: String,
+    pub timestamp: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SkippedRow {
+    pub row: usize,
+    pub reason: String,
+}
+
+/// A row that appeared after a later-timestamped row and was moved ahead of
+/// it when the import was sorted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderingRepair {
+    pub row: usize,
+    pub timestamp: u64,
+    pub preceding_timestamp: u64,
+}
+
+/// Starting point for guard sessions on a site with imported history.
+#[cfg(feature = "guard")]
+#[derive(Clone, Debug)]
+pub struct GuardBaseline {
+    pub deeds: u64,
+    pub harm_ratio: f32,
+    pub ethics_flags_per_deed: f32,
+    /// `CalmStable` when the history has no harm or ethics flags;
+    /// `Recovery` when the later half harms less than the earlier half.
+    pub micro_society: morphix_guard::MicroSocietyView,
+}
+
+#[derive(Clone, Debug)]
+pub struct BackfillReport {
+    /// Converted deeds in the order they were (or would be) appended.
+    pub converted: Vec<ConvertedDeed>,
+    pub skipped: Vec<SkippedRow>,
+    pub repairs: Vec<OrderingRepair>,
+    /// Events actually appended; 0 on a dry run.
+    pub appended: usize,
+    /// Summed Tree-of-Life score of the appended events.
+    pub moral_score: i64,
+    pub dry_run: bool,
+    #[cfg(feature = "guard")]
+    pub guard_baseline: Option<GuardBaseline>,
+}
+
+/// Imports `source` into `ledger` and evaluates it through `tree`.
+///
+/// Fails only when the source as a whole is unusable (unparseable JSON or
+/// CSV header) or the ledger rejects an append; row-level problems are
+/// reported in `BackfillReport::skipped`.
+pub async fn run(
+    source: BackfillSource,
+    ledger: &Ledger,
+    tree: &TreeOfLife,
+    opts: BackfillOptions,
+) -> Result<BackfillReport, String> {
+    let mapping = source.mapping().clone();
+    let rows = match &source {
+        BackfillSource::Csv { text, .. } => csv_rows(text)?,
+        BackfillSource::Json { text, .. } => json_rows(text, mapping.list_separator)?,
+    };
+
+    let mut skipped = Vec::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut pending: Vec<(usize, DeedEvent)> = Vec::new();
+    for (index, row) in rows.into_iter().enumerate() {
+        let row_number = index + 1;
+        let record = match row {
+            Ok(record) => record,
+            Err(reason) => {
+                skipped.push(SkippedRow {
+                    row: row_number,
+                    reason,
+                });
+                continue;
+            }
+        };
+        match convert(&record, &mapping, &opts.source_name) {
+            Ok(event) => {
+                if let Some(first) = seen.get(&event.event_id) {
+                    skipped.push(SkippedRow {
+                        row: row_number,
+                        reason: format!("duplicate of row {first}"),
+                    });
+                    continue;
+                }
+                seen.insert(event.event_id.clone(), row_number);
+                pending.push((row_number, event));
+            }
+            Err(reason) => skipped.push(SkippedRow {
+                row: row_number,
+                reason,
+            }),
+        }
+    }
+
+    let mut repairs = Vec::new();
+    let mut latest = 0;
+    for (row, event) in &pending {
+        if event.timestamp < latest {
+            repairs.push(OrderingRepair {
+                row: *row,
+                timestamp: event.timestamp,
+                preceding_timestamp: latest,
+            });
+        }
+        latest = latest.max(event.timestamp);
+    }
+    // Stable sort: equal timestamps keep source order.
+    pending.sort_by_key(|(_, event)| event.timestamp);
+
+    let mut prev_hash = ledger.head_hash().await;
+    let mut converted = Vec::with_capacity(pending.len());
+    let mut events = Vec::with_capacity(pending.len());
+    for (row, event) in pending {
+        let (event_id, timestamp) = (event.event_id.clone(), event.timestamp);
+        let event = DeedEvent { prev_hash, ..event }.with_identity(event_id, timestamp);
+        prev_hash = event.self_hash.clone();
+        converted.push(ConvertedDeed {
+            row,
+            event_id: event.event_id.clone(),
+            timestamp: event.timestamp,
+        });
+        events.push(event);
+    }
+
+    let mut appended = 0;
+    let mut moral_score = 0;
+    if !opts.dry_run {
+        for event in &events {
+            ledger.append(event.clone()).await?;
+            appended += 1;
+        }
+        moral_score = tree.evaluate_backlog(&events).await?;
+    }
+
+    Ok(BackfillReport {
+        converted,
+        skipped,
+        repairs,
+        appended,
+        moral_score,
+        dry_run: opts.dry_run,
+        #[cfg(feature = "guard")]
+        guard_baseline: opts.guard_baseline.then(|| guard_baseline(&events)),
+    })
+}
+
+type Record = BTreeMap<String, String>;
+
+fn csv_rows(text: &str) -> Result<Vec<Result<Record, String>>, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(text.as_bytes());
+    let headers = reader
+        .headers()
+        .map_err(|e| format!("Cannot read CSV header: {e}"))?
+        .clone();
+    Ok(reader
+        .records()
+        .map(|record| {
+            let record = record.map_err(|e| format!("malformed row: {e}"))?;
+            Ok(headers
+                .iter()
+                .zip(record.iter())
+                .map(|(h, v)| (h.to_string(), v.to_string()))
+                .collect())
+        })
+        .collect())
+}
+
+fn json_rows(text: &str, separator: char) -> Result<Vec<Result<Record, String>>, String> {
+    let values: Vec<serde_json::Value> =
+        serde_json::from_str(text).map_err(|e| format!("Cannot parse JSON source: {e}"))?;
+    Ok(values
+        .into_iter()
+        .map(|value| {
+            let object = match value {
+                serde_json::Value::Object(object) => object,
+                other => return Err(format!("malformed row: expected an object, got {other}")),
+            };
+            Ok(object
+                .into_iter()
+                .map(|(key, value)| (key, flatten(value, separator)))
+                .collect())
+        })
+        .collect())
+}
+
+fn flatten(value: serde_json::Value, separator: char) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(s) => s,
+        serde_json::Value::Array(items) => items
+            .into_iter()
+            .map(|item| flatten(item, separator))
+            .collect::<Vec<_>>()
+            .join(&separator.to_string()),
+        other => other.to_string(),
+    }
+}
+
+fn convert(
+    record: &Record,
+    mapping: &ColumnMapping,
+    source_name: &str,
+) -> Result<DeedEvent, String> {
+    let required = |column: &str| -> Result<&str, String> {
+        match record.get(column).map(String::as_str) {
+            Some(value) if !value.is_empty() => Ok(value),
+            Some(_) => Err(format!("empty '{column}'")),
+            None => Err(format!("missing column '{column}'")),
+        }
+    };
+    let optional = |column: &Option<String>| -> &str {
+        column
+            .as_deref()
+            .and_then(|c| record.get(c))
+            .map(String::as_str)
+            .unwrap_or("")
+    };
+    let list = |column: &Option<String>| -> Vec<String> {
+        optional(column)
+            .split(mapping.list_separator)
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(str::to_string)
+            .collect()
+    };
+
+    let raw_timestamp = required(&mapping.timestamp)?;
+    let timestamp = parse_timestamp(raw_timestamp)
+        .ok_or_else(|| format!("unparseable timestamp '{raw_timestamp}'"))?;
+    let actor = required(&mapping.actor)?;
+    let deed_type = required(&mapping.deed_type)?;
+    let life_harm = match optional(&mapping.life_harm).to_ascii_lowercase().as_str() {
+        "" | "false" | "no" | "0" => false,
+        "true" | "yes" | "1" => true,
+        other => return Err(format!("unparseable life_harm '{other}'")),
+    };
+
+    let mapped: Vec<&str> = [
+        mapping.legacy_id.as_deref(),
+        Some(mapping.timestamp.as_str()),
+        Some(mapping.actor.as_str()),
+        Some(mapping.deed_type.as_str()),
+        mapping.targets.as_deref(),
+        mapping.tags.as_deref(),
+        mapping.life_harm.as_deref(),
+        mapping.ethics_flags.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    let mut context: HashMap<String, serde_json::Value> = record
+        .iter()
+        .filter(|(key, value)| !mapped.contains(&key.as_str()) && !value.is_empty())
+        .map(|(key, value)| (key.clone(), serde_json::json!(value)))
+        .collect();
+    context.insert(
+        CONTEXT_BACKFILL_SOURCE_KEY.to_string(),
+        serde_json::json!(source_name),
+    );
+
+    // Legacy key when mapped, otherwise the identifying fields themselves.
+    let id_seed = match optional(&mapping.legacy_id) {
+        "" => format!(
+            "{source_name}\x1f{timestamp}\x1f{actor}\x1f{deed_type}\x1f{}",
+            optional(&mapping.targets)
+        ),
+        legacy_id => format!("{source_name}\x1f{legacy_id}"),
+    };
+    let event_id = Uuid::new_v5(&BACKFILL_NAMESPACE, id_seed.as_bytes()).to_string();
+
+    Ok(DeedEvent::new(
+        String::new(),
+        actor.to_string(),
+        list(&mapping.targets),
+        deed_type.to_string(),
+        list(&mapping.tags),
+        context,
+        list(&mapping.ethics_flags),
+        life_harm,
+    )
+    .with_identity(event_id, timestamp))
+}
+
+/// Unix seconds, or an ISO-8601 UTC instant `YYYY-MM-DDTHH:MM:SSZ` (a space
+/// may replace the `T`, and the `Z` may be omitted).
+fn parse_timestamp(raw: &str) -> Option<u64> {
+    if let Ok(secs) = raw.parse::<u64>() {
+        return Some(secs);
+    }
+    let raw = raw.strip_suffix('Z').unwrap_or(raw);
+    let (date, time) = raw.split_once(['T', ' '])?;
+    let mut date = date.splitn(3, '-').map(|p| p.parse::<i64>().ok());
+    let (year, month, day) = (date.next()??, date.next()??, date.next()??);
+    let mut time = time.splitn(3, ':').map(|p| p.parse::<i64>().ok());
+    let (hour, minute, second) = (time.next()??, time.next()??, time.next()??);
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || !(0..24).contains(&hour)
+        || !(0..60).contains(&minute)
+        || !(0..61).contains(&second)
+    {
+        return None;
+    }
+    // Days-from-civil (Howard Hinnant).
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+    u64::try_from(days * 86_400 + hour * 3_600 + minute * 60 + second).ok()
+}
+
+#[cfg(feature = "guard")]
+fn guard_baseline(events: &[DeedEvent]) -> GuardBaseline {
+    use morphix_guard::{MicroSocietyPredicate, MicroSocietyView};
+
+    let harm_ratio = |slice: &[DeedEvent]| {
+        if slice.is_empty() {
+            0.0
+        } else {
+            slice.iter().filter(|e| e.life_harm_flag).count() as f32 / slice.len() as f32
+        }
+    };
+    let deeds = events.len() as u64;
+    let ethics_flags: usize = events.iter().map(|e| e.ethics_flags.len()).sum();
+    let ethics_flags_per_deed = if events.is_empty() {
+        0.0
+    } else {
+        ethics_flags as f32 / events.len() as f32
+    };
+
+    let mut predicates = Vec::new();
+    let overall = harm_ratio(events);
+    if overall == 0.0 && ethics_flags == 0 {
+        predicates.push(MicroSocietyPredicate::CalmStable);
+    } else {
+        let (earlier, later) = events.split_at(events.len() / 2);
+        if harm_ratio(later) < harm_ratio(earlier) {
+            predicates.push(MicroSocietyPredicate::Recovery);
+        }
+    }
+
+    GuardBaseline {
+        deeds,
+        harm_ratio: overall,
+        ethics_flags_per_deed,
+        micro_society: MicroSocietyView { predicates },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use morphix_ledger::config::Config;
+
+    const LEGACY_CSV: &str = include_str!("../tests/fixtures/legacy_deeds.csv");
+
+    fn fresh() -> (Ledger, TreeOfLife) {
+        let config = Config::default();
+        let ledger = Ledger::new(config.clone());
+        let tree = TreeOfLife::new(ledger.clone(), config);
+        (ledger, tree)
+    }
+
+    fn csv_source() -> BackfillSource {
+        BackfillSource::Csv {
+            text: LEGACY_CSV.to_string(),
+            mapping: ColumnMapping::default(),
+        }
+    }
+
+    #[test]
+    fn test_parse_timestamp_formats() {
+        assert_eq!(parse_timestamp("1600000000"), Some(1_600_000_000));
+        assert_eq!(parse_timestamp("2020-09-13T12:26:40Z"), Some(1_600_000_000));
+        assert_eq!(parse_timestamp("2020-09-13 12:26:40"), Some(1_600_000_000));
+        assert_eq!(parse_timestamp("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(parse_timestamp("2020-13-01T00:00:00Z"), None);
+        assert_eq!(parse_timestamp("yesterday"), None);
+    }
+
+    #[tokio::test]
+    async fn test_backfill_fixture_repairs_order_and_skips_bad_rows() {
+        let (ledger, tree) = fresh();
+        let opts = BackfillOptions {
+            guard_baseline: true,
+            ..BackfillOptions::default()
+        };
+        let report = run(csv_source(), &ledger, &tree, opts).await.unwrap();
+
+        // Sorted by timestamp: L-001, L-004, L-003, L-002.
+        let rows: Vec<usize> = report.converted.iter().map(|c| c.row).collect();
+        assert_eq!(rows, vec![1, 4, 3, 2]);
+        assert_eq!(
+            report.repairs,
+            vec![
+                OrderingRepair {
+                    row: 3,
+                    timestamp: 1_600_001_800,
+                    preceding_timestamp: 1_600_003_600
+                },
+                OrderingRepair {
+                    row: 4,
+                    timestamp: 1_600_000_900,
+                    preceding_timestamp: 1_600_003_600
+                },
+            ]
+        );
+        let skipped: Vec<usize> = report.skipped.iter().map(|s| s.row).collect();
+        assert_eq!(skipped, vec![5, 6, 7]);
+        assert!(report.skipped[0].reason.starts_with("malformed row"));
+        assert!(report.skipped[1].reason.contains("timestamp"));
+        assert_eq!(report.skipped[2].reason, "duplicate of row 2");
+
+        assert_eq!(report.appended, 4);
+        assert_eq!(ledger.verify().await, Ok(4));
+
+        // Hand-computed: three good deeds at mint rate 1, one harm deed.
+        let metrics = ledger.compute_metrics().await;
+        assert_eq!(metrics.total_events, 4);
+        assert_eq!(metrics.good_deeds, 3);
+        assert_eq!(metrics.harm_flags, 1);
+        assert_eq!(metrics.balance.church_tokens, 3);
+        assert_eq!(metrics.per_actor["alice"], 2);
+        assert_eq!(metrics.per_deed_type["ecological_sustainability"], 2);
+
+        // L-001 +30, L-004 3 × (-10 - 2×5) = -60, L-003 +10, L-002 +30.
+        assert_eq!(report.moral_score, 10);
+
+        let baseline = report.guard_baseline.unwrap();
+        assert_eq!(baseline.deeds, 4);
+        assert_eq!(baseline.harm_ratio, 0.25);
+        assert_eq!(baseline.ethics_flags_per_deed, 0.5);
+        assert_eq!(
+            baseline.micro_society.predicates,
+            vec![morphix_guard::MicroSocietyPredicate::Recovery]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_backfill_is_deterministic_and_dry_run_leaves_ledger_alone() {
+        let (ledger, tree) = fresh();
+        let dry = BackfillOptions {
+            dry_run: true,
+            ..BackfillOptions::default()
+        };
+        let preview = run(csv_source(), &ledger, &tree, dry).await.unwrap();
+        assert_eq!(preview.appended, 0);
+        assert_eq!(ledger.verify().await, Ok(0));
+        assert!(preview.guard_baseline.is_none());
+
+        let real = run(csv_source(), &ledger, &tree, BackfillOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(preview.converted, real.converted);
+    }
+
+    #[tokio::test]
+    async fn test_backfill_json_source_with_custom_mapping() {
+        let (ledger, tree) = fresh();
+        let source = BackfillSource::Json {
+            text: r#"[
+                {"when": "2020-09-13T12:26:40Z", "who": "alice", "what": "tutoring", "flags": ["late"]},
+                {"when": 1600000100, "who": "bob", "what": "tutoring", "harmed": true},
+                "not an object"
+            ]"#
+            .to_string(),
+            mapping: ColumnMapping {
+                legacy_id: None,
+                timestamp: "when".to_string(),
+                actor: "who".to_string(),
+                deed_type: "what".to_string(),
+                targets: None,
+                tags: None,
+                life_harm: Some("harmed".to_string()),
+                ethics_flags: Some("flags".to_string()),
+                list_separator: ';',
+            },
+        };
+        let report = run(source, &ledger, &tree, BackfillOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(report.appended, 2);
+        assert_eq!(report.skipped.len(), 1);
+        assert_eq!(ledger.verify().await, Ok(2));
+        let metrics = ledger.compute_metrics().await;
+        assert_eq!(metrics.harm_flags, 1);
+    }
+}
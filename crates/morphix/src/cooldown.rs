@@ -0,0 +1,449 @@
+//! Advisory cooldown schedules derived from guard labels, RoH history and
+//! gate verdicts.
+//!
+//! `plan` never actuates anything: it turns "this subject/site needs rest"
+//! signals into a minimum cooldown, a list of activity classes to suspend
+//! and re-evaluation checkpoints, recording which rule produced each part.
+//! A `SessionBudget` only tightens itself from a plan when the operator has
+//! opted in.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use morphix_guard::{MorphixGuardView, MorphixLabel, RoH};
+
+#[cfg(feature = "biorail")]
+use microsociety::biorail_terrasafe::{GateReport, GateVerdict};
+
+/// Activity classes a plan can suspend, in suspension priority order:
+/// biophysical-signal distillations go first.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ActivityClass {
+    BiophysicalDistillation,
+    DisciplineSignalDistillation,
+    SiteChange,
+    Distillation,
+}
+
+/// Cooldown recommended when a guard view carries `label`.
+#[derive(Clone, Debug)]
+pub struct LabelRule {
+    pub label: MorphixLabel,
+    pub cooldown: Duration,
+    pub suspend: Vec<ActivityClass>,
+}
+
+/// Cooldown recommended when the gate returned `verdict`.
+#[cfg(feature = "biorail")]
+#[derive(Clone, Debug)]
+pub struct VerdictRule {
+    pub verdict: GateVerdict,
+    pub cooldown: Duration,
+    pub suspend: Vec<ActivityClass>,
+}
+
+/// Rule tables. Defaults:
+///
+/// | trigger                      | cooldown | suspends                                  |
+/// |------------------------------|----------|-------------------------------------------|
+/// | D3OverloadRisk               | 30 min   | biophysical                               |
+/// | D5OverloadedRecoveryWindow   | 2 h      | biophysical, discipline-signal            |
+/// | RoH rising to >= 0.25        | 30 min   | biophysical                               |
+/// | gate Block                   | 1 h      | site changes                              |
+/// | gate ForceRepair             | 4 h      | biophysical, discipline-signal, site changes |
+///
+/// Checkpoints fall every 30 minutes up to and including the end of the
+/// cooldown.
+#[derive(Clone, Debug)]
+pub struct CooldownConfig {
+    pub labels: Vec<LabelRule>,
+    #[cfg(feature = "biorail")]
+    pub verdicts: Vec<VerdictRule>,
+    /// RoH level at or above which a rising history triggers `roh_cooldown`.
+    pub roh_alert: f32,
+    pub roh_cooldown: Duration,
+    pub roh_suspend: Vec<ActivityClass>,
+    pub checkpoint_every: Duration,
+}
+
+const MINUTE: Duration = Duration::from_secs(60);
+
+impl Default for CooldownConfig {
+    fn default() -> Self {
+        use ActivityClass::*;
+        CooldownConfig {
+            labels: vec![
+                LabelRule {
+                    label: MorphixLabel::D3OverloadRisk,
+                    cooldown: 30 * MINUTE,
+                    suspend: vec![BiophysicalDistillation],
+                },
+                LabelRule {
+                    label: MorphixLabel::D5OverloadedRecoveryWindow,
+                    cooldown: 120 * MINUTE,
+                    suspend: vec![BiophysicalDistillation, DisciplineSignalDistillation],
+                },
+            ],
+            #[cfg(feature = "biorail")]
+            verdicts: vec![
+                VerdictRule {
+                    verdict: GateVerdict::Block,
+                    cooldown: 60 * MINUTE,
+                    suspend: vec![SiteChange],
+                },
+                VerdictRule {
+                    verdict: GateVerdict::ForceRepair,
+                    cooldown: 240 * MINUTE,
+                    suspend: vec![
+                        BiophysicalDistillation,
+                        DisciplineSignalDistillation,
+                        SiteChange,
+                    ],
+                },
+            ],
+            roh_alert: 0.25,
+            roh_cooldown: 30 * MINUTE,
+            roh_suspend: vec![BiophysicalDistillation],
+            checkpoint_every: 30 * MINUTE,
+        }
+    }
+}
+
+/// Latest outputs the plan is derived from.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CooldownInputs<'a> {
+    pub guard_view: Option<&'a MorphixGuardView>,
+    /// Recent RoH values, oldest first.
+    pub roh_history: &'a [RoH],
+    #[cfg(feature = "biorail")]
+    pub gate: Option<&'a GateReport>,
+}
+
+/// One rule that contributed to a plan.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AppliedRule {
+    /// Stable rule id, e.g. `label.D3OverloadRisk` or `gate.ForceRepair`.
+    pub rule: String,
+    pub cooldown_secs: u64,
+    pub suspends: Vec<ActivityClass>,
+    /// Why the rule fired (label explanation and evidence, RoH trend, gate biosignatures).
+    pub detail: String,
+}
+
+/// Purely advisory rest schedule. An all-clear input yields
+/// `CooldownPlan::default()`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct CooldownPlan {
+    /// Maximum over all applied rules.
+    pub min_cooldown_secs: u64,
+    /// Union of suspended classes, biophysical distillations first.
+    pub suspend: Vec<ActivityClass>,
+    /// Offsets from now, in seconds, at which to re-evaluate.
+    pub checkpoints_secs: Vec<u64>,
+    pub applied: Vec<AppliedRule>,
+}
+
+impl CooldownPlan {
+    pub fn is_all_clear(&self) -> bool {
+        self.applied.is_empty()
+    }
+
+    pub fn min_cooldown(&self) -> Duration {
+        Duration::from_secs(self.min_cooldown_secs)
+    }
+}
+
+/// Builds a plan with the default rule tables.
+pub fn plan(inputs: CooldownInputs<'_>) -> CooldownPlan {
+    plan_with(inputs, &CooldownConfig::default())
+}
+
+/// Builds a plan with caller-supplied rule tables.
+pub fn plan_with(inputs: CooldownInputs<'_>, config: &CooldownConfig) -> CooldownPlan {
+    let mut applied = Vec::new();
+
+    if let Some(view) = inputs.guard_view {
+        for diagnostic in &view.diagnostics {
+            for rule in config.labels.iter().filter(|r| r.label == diagnostic.label) {
+                applied.push(AppliedRule {
+                    rule: format!("label.{:?}", rule.label),
+                    cooldown_secs: rule.cooldown.as_secs(),
+                    suspends: rule.suspend.clone(),
+                    detail: diagnostic.provenance.to_string(),
+                });
+            }
+        }
+    }
+
+    if let (Some(first), Some(last)) = (inputs.roh_history.first(), inputs.roh_history.last()) {
+        let rising = inputs
+            .roh_history
+            .windows(2)
+            .all(|w| w[1].value() >= w[0].value())
+            && last.value() > first.value();
+        if rising && last.value() >= config.roh_alert {
+            applied.push(AppliedRule {
+                rule: "roh.rising".to_string(),
+                cooldown_secs: config.roh_cooldown.as_secs(),
+                suspends: config.roh_suspend.clone(),
+                detail: format!(
+                    "RoH rose from {:.2} to {:.2} over {} samples (alert at {:.2})",
+                    first.value(),
+                    last.value(),
+                    inputs.roh_history.len(),
+                    config.roh_alert
+                ),
+            });
+        }
+    }
+
+    #[cfg(feature = "biorail")]
+    if let Some(gate) = inputs.gate {
+        for rule in config.verdicts.iter().filter(|r| r.verdict == gate.verdict) {
+            applied.push(AppliedRule {
+                rule: format!("gate.{:?}", rule.verdict),
+                cooldown_secs: rule.cooldown.as_secs(),
+                suspends: rule.suspend.clone(),
+                detail: format!(
+                    "biosignature {:.3} -> {:.3}, corridor [{:.3}, {:.3}]",
+                    gate.current_b.value(),
+                    gate.predicted_b.value(),
+                    gate.tuned_corridor.0.value(),
+                    gate.tuned_corridor.1.value()
+                ),
+            });
+        }
+    }
+
+    let min_cooldown_secs = applied.iter().map(|r| r.cooldown_secs).max().unwrap_or(0);
+    let mut suspend: Vec<ActivityClass> = applied
+        .iter()
+        .flat_map(|r| r.suspends.iter().copied())
+        .collect();
+    suspend.sort();
+    suspend.dedup();
+
+    let step = config.checkpoint_every.as_secs().max(1);
+    let mut checkpoints_secs: Vec<u64> = (1..)
+        .map(|i| i * step)
+        .take_while(|offset| *offset < min_cooldown_secs)
+        .collect();
+    if min_cooldown_secs > 0 {
+        checkpoints_secs.push(min_cooldown_secs);
+    }
+
+    CooldownPlan {
+        min_cooldown_secs,
+        suspend,
+        checkpoints_secs,
+        applied,
+    }
+}
+
+/// Limits for one working session. `apply` tightens them from a plan only
+/// when `auto_tighten` is set; it never loosens them.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct SessionBudget {
+    /// Operator opt-in for plan-driven tightening.
+    pub auto_tighten: bool,
+    /// Classes that may not run this session.
+    pub suspended: Vec<ActivityClass>,
+    /// Earliest offset, in seconds, at which the session may resume.
+    pub resume_after_secs: u64,
+    /// Next offset, in seconds, at which the budget should be re-evaluated.
+    pub next_review_secs: Option<u64>,
+}
+
+impl SessionBudget {
+    /// Returns whether the budget changed.
+    pub fn apply(&mut self, plan: &CooldownPlan) -> bool {
+        if !self.auto_tighten || plan.is_all_clear() {
+            return false;
+        }
+        let before = self.clone();
+        self.suspended.extend(plan.suspend.iter().copied());
+        self.suspended.sort();
+        self.suspended.dedup();
+        self.resume_after_secs = self.resume_after_secs.max(plan.min_cooldown_secs);
+        if let Some(first) = plan.checkpoints_secs.first() {
+            self.next_review_secs = Some(self.next_review_secs.map_or(*first, |n| n.min(*first)));
+        }
+        *self != before
+    }
+
+    pub fn allows(&self, activity: ActivityClass) -> bool {
+        !self.suspended.contains(&activity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use morphix_guard::{CapabilityState, GuardDimension, LabelProvenance, MorphixDiagnostic};
+    use std::borrow::Cow;
+
+    fn view(labels: &[MorphixLabel]) -> MorphixGuardView {
+        MorphixGuardView {
+            capability_state: CapabilityState::ControlledHuman,
+            roh_value: 0.1,
+            evolve_index: None,
+            epoch_index: None,
+            diagnostics: labels
+                .iter()
+                .map(|label| MorphixDiagnostic {
+                    label: label.clone(),
+                    provenance: LabelProvenance {
+                        dimension: GuardDimension::D3,
+                        explanation: Cow::Borrowed("test"),
+                        sources: Cow::Borrowed(&[]),
+                        shard_refs: Cow::Borrowed(&[]),
+                        evidence: Default::default(),
+                        thresholds: Default::default(),
+                        determinacy: Default::default(),
+                    },
+                    severity: 0.5,
+                    margin: 0.5,
+                })
+                .collect(),
+            config_version: None,
+        }
+    }
+
+    fn roh(values: &[f32]) -> Vec<RoH> {
+        values.iter().map(|value| RoH::new(*value)).collect()
+    }
+
+    #[test]
+    fn test_labels_map_to_documented_durations() {
+        let overload = view(&[MorphixLabel::D3OverloadRisk]);
+        let p = plan(CooldownInputs {
+            guard_view: Some(&overload),
+            ..Default::default()
+        });
+        assert_eq!(p.min_cooldown(), 30 * MINUTE);
+        assert_eq!(p.suspend, vec![ActivityClass::BiophysicalDistillation]);
+        assert_eq!(p.checkpoints_secs, vec![1_800]);
+        assert_eq!(p.applied[0].rule, "label.D3OverloadRisk");
+
+        let recovery = view(&[MorphixLabel::D5OverloadedRecoveryWindow]);
+        let p = plan(CooldownInputs {
+            guard_view: Some(&recovery),
+            ..Default::default()
+        });
+        assert_eq!(p.min_cooldown(), 120 * MINUTE);
+        assert_eq!(p.checkpoints_secs, vec![1_800, 3_600, 5_400, 7_200]);
+
+        let p = plan(CooldownInputs {
+            roh_history: &roh(&[0.10, 0.18, 0.26]),
+            ..Default::default()
+        });
+        assert_eq!(p.min_cooldown(), 30 * MINUTE);
+        assert_eq!(p.applied[0].rule, "roh.rising");
+
+        // High but falling RoH is recovering, not a trigger.
+        let p = plan(CooldownInputs {
+            roh_history: &roh(&[0.29, 0.27]),
+            ..Default::default()
+        });
+        assert!(p.is_all_clear());
+    }
+
+    #[cfg(feature = "biorail")]
+    #[test]
+    fn test_verdicts_map_to_documented_durations() {
+        let report = |verdict| GateReport {
+            verdict,
+            ..GateReport::default()
+        };
+        let force_repair = report(GateVerdict::ForceRepair);
+        let p = plan(CooldownInputs {
+            gate: Some(&force_repair),
+            ..Default::default()
+        });
+        assert_eq!(p.min_cooldown(), 240 * MINUTE);
+        assert_eq!(
+            p.suspend,
+            vec![
+                ActivityClass::BiophysicalDistillation,
+                ActivityClass::DisciplineSignalDistillation,
+                ActivityClass::SiteChange,
+            ]
+        );
+
+        let block = report(GateVerdict::Block);
+        let p = plan(CooldownInputs {
+            gate: Some(&block),
+            ..Default::default()
+        });
+        assert_eq!(p.min_cooldown(), 60 * MINUTE);
+
+        let allow = report(GateVerdict::Allow);
+        assert!(plan(CooldownInputs {
+            gate: Some(&allow),
+            ..Default::default()
+        })
+        .is_all_clear());
+    }
+
+    #[test]
+    fn test_overlapping_rules_take_the_maximum() {
+        let both = view(&[
+            MorphixLabel::D3OverloadRisk,
+            MorphixLabel::D5OverloadedRecoveryWindow,
+        ]);
+        let p = plan(CooldownInputs {
+            guard_view: Some(&both),
+            roh_history: &roh(&[0.20, 0.25]),
+            ..Default::default()
+        });
+        assert_eq!(p.applied.len(), 3);
+        assert_eq!(p.min_cooldown(), 120 * MINUTE);
+        assert_eq!(
+            p.suspend,
+            vec![
+                ActivityClass::BiophysicalDistillation,
+                ActivityClass::DisciplineSignalDistillation,
+            ]
+        );
+        assert_eq!(p.checkpoints_secs.last(), Some(&7_200));
+    }
+
+    #[test]
+    fn test_all_clear_yields_empty_plan() {
+        let calm = view(&[MorphixLabel::D1Fair, MorphixLabel::D5CalmStable]);
+        let p = plan(CooldownInputs {
+            guard_view: Some(&calm),
+            roh_history: &roh(&[0.05, 0.05]),
+            ..Default::default()
+        });
+        assert_eq!(p, CooldownPlan::default());
+        assert_eq!(plan(CooldownInputs::default()), CooldownPlan::default());
+    }
+
+    #[test]
+    fn test_budget_tightens_only_when_opted_in() {
+        let overload = view(&[MorphixLabel::D3OverloadRisk]);
+        let p = plan(CooldownInputs {
+            guard_view: Some(&overload),
+            ..Default::default()
+        });
+
+        let mut untouched = SessionBudget::default();
+        assert!(!untouched.apply(&p));
+        assert!(untouched.allows(ActivityClass::BiophysicalDistillation));
+
+        let mut budget = SessionBudget {
+            auto_tighten: true,
+            resume_after_secs: 7_200,
+            ..SessionBudget::default()
+        };
+        assert!(budget.apply(&p));
+        assert!(!budget.allows(ActivityClass::BiophysicalDistillation));
+        assert!(budget.allows(ActivityClass::Distillation));
+        // Never loosened by a shorter plan.
+        assert_eq!(budget.resume_after_secs, 7_200);
+        assert_eq!(budget.next_review_secs, Some(1_800));
+        assert!(!budget.apply(&p));
+    }
+}
@@ -0,0 +1,255 @@
+//! Ledger-backed audit trail for BioRail gate refusals.
+//!
+//! `LedgerGateAuditSink` files every `Block` and `ForceRepair` report it is
+//! given as a `DeedEvent` of type `biorail_gate_refusal`, linked after the
+//! ledger head. The violated constraints go into `context_json` and their
+//! names become ethics flags, so ledger metrics count breaches per
+//! envelope. A report from `gate_with_ethics` also records the ethics
+//! decision, and an approval the rail refused is flagged
+//! `ethics_contradiction`. The gate's verdict always stands: failed appends
+//! are kept in `failures()`.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use microsociety::audit::GateAuditSink;
+use microsociety::biorail_terrasafe::GateReport;
+use morphix_ledger::config::context_entry;
+use morphix_ledger::{DeedEvent, Ledger};
+
+use crate::governance_audit::block_on;
+
+/// Deed type of the events this sink appends.
+pub const GATE_REFUSAL_DEED_TYPE: &str = "biorail_gate_refusal";
+
+/// Ethics flag on events where the ethics layer approved a deed the rail
+/// refused.
+pub const ETHICS_CONTRADICTION_FLAG: &str = "ethics_contradiction";
+
+pub struct LedgerGateAuditSink {
+    ledger: Ledger,
+    actor_id: String,
+    failures: Mutex<Vec<String>>,
+}
+
+impl LedgerGateAuditSink {
+    /// Events appended by this sink carry `actor_id`.
+    pub fn new(ledger: Ledger, actor_id: impl Into<String>) -> Self {
+        Self {
+            ledger,
+            actor_id: actor_id.into(),
+            failures: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn ledger(&self) -> &Ledger {
+        &self.ledger
+    }
+
+    /// Append failures so far, one line per lost refusal.
+    pub fn failures(&self) -> Vec<String> {
+        self.failures.lock().map(|f| f.clone()).unwrap_or_default()
+    }
+}
+
+/// Ledger event for a refusal of site `site_id`, linked after `prev_hash`.
+pub fn to_deed_event(
+    site_id: usize,
+    report: &GateReport,
+    prev_hash: String,
+    actor_id: String,
+) -> DeedEvent {
+    let violations: Vec<serde_json::Value> = report
+        .violated_constraints
+        .iter()
+        .map(|v| {
+            serde_json::json!({
+                "constraint": v.constraint.name(),
+                "limit": v.limit,
+                "predicted": v.predicted,
+            })
+        })
+        .collect();
+    let mut context = HashMap::from([
+        ("site_id".to_string(), serde_json::json!(site_id)),
+        (
+            "verdict".to_string(),
+            serde_json::json!(format!("{:?}", report.verdict)),
+        ),
+        (
+            "violated_constraints".to_string(),
+            serde_json::json!(violations),
+        ),
+        (
+            "current_b".to_string(),
+            serde_json::json!(report.current_b.value()),
+        ),
+        (
+            "predicted_b".to_string(),
+            serde_json::json!(report.predicted_b.value()),
+        ),
+    ]);
+    if let Some(version) = &report.config_version {
+        let (key, value) = context_entry(version);
+        context.insert(key, value);
+    }
+    if let Some(zone) = report.zone {
+        context.insert("zone".to_string(), serde_json::json!(format!("{zone:?}")));
+    }
+    let mut ethics_flags: Vec<String> = report
+        .violated_constraints
+        .iter()
+        .map(|v| v.constraint.name().to_string())
+        .collect();
+    if let Some(ethics) = &report.ethics {
+        context.insert(
+            "ethics".to_string(),
+            serde_json::json!({
+                "decision": format!("{:?}", ethics.decision),
+                "biorail_verdict": format!("{:?}", ethics.biorail_verdict),
+                "outcome": format!("{:?}", ethics.outcome),
+            }),
+        );
+        if ethics.is_contradiction() {
+            ethics_flags.push(ETHICS_CONTRADICTION_FLAG.to_string());
+        }
+    }
+
+    DeedEvent::new(
+        prev_hash,
+        actor_id,
+        vec![site_id.to_string()],
+        GATE_REFUSAL_DEED_TYPE.to_string(),
+        vec!["biorail".to_string()],
+        context,
+        ethics_flags,
+        false,
+    )
+}
+
+impl GateAuditSink for LedgerGateAuditSink {
+    fn record_refusal(&self, site_id: usize, report: &GateReport) {
+        let appended = block_on(async {
+            let prev_hash = self.ledger.head_hash().await;
+            let event = to_deed_event(site_id, report, prev_hash, self.actor_id.clone());
+            self.ledger.append(event).await
+        });
+        if let Err(error) = appended {
+            if let Ok(mut failures) = self.failures.lock() {
+                failures.push(format!("site {site_id}: {error}"));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use microsociety::audit::AuditedGate;
+    use microsociety::biorail_terrasafe::*;
+    use microsociety::test_util::{cfg, site};
+    use morphix_ledger::config::{Config, ConfigVersion};
+    use std::time::UNIX_EPOCH;
+
+    #[test]
+    fn test_two_refusals_append_two_linked_events() {
+        let ledger = Ledger::new(Config::default());
+        let gate = AuditedGate::new(LedgerGateAuditSink::new(ledger.clone(), "biorail-gate"));
+        let roh_breach = ProposedChange {
+            delta_roh: 0.25,
+            ..ProposedChange::default()
+        };
+        let power_grab = ProposedChange {
+            delta_power: 5.0,
+            ..ProposedChange::default()
+        };
+
+        gate.gate_report(&site(), &cfg(), &ProposedChange::default());
+        let first = gate.gate_report(&site(), &cfg(), &roh_breach);
+        let second = gate.gate_report(&site(), &cfg(), &power_grab);
+        assert_eq!(first.verdict, GateVerdict::ForceRepair);
+        assert_eq!(second.verdict, GateVerdict::Block);
+        assert!(gate.sink().failures().is_empty());
+
+        assert_eq!(block_on(ledger.verify()), Ok(2));
+        let metrics = block_on(ledger.compute_metrics());
+        assert_eq!(metrics.per_deed_type[GATE_REFUSAL_DEED_TYPE], 2);
+    }
+
+    #[test]
+    fn test_event_names_breached_envelopes() {
+        let report = BioRailTerrasafeGuard::gate_report(
+            &site(),
+            &cfg(),
+            &ProposedChange {
+                delta_roh: 0.25,
+                ..ProposedChange::default()
+            },
+        );
+        let event = to_deed_event(3, &report, "genesis".to_string(), "gate".to_string());
+        assert_eq!(event.deed_type, GATE_REFUSAL_DEED_TYPE);
+        assert_eq!(event.target_ids, ["3"]);
+        assert_eq!(event.ethics_flags, ["roh_ceiling"]);
+        assert_eq!(event.context_json["verdict"], "ForceRepair");
+        let violation = &event.context_json["violated_constraints"][0];
+        assert_eq!(violation["constraint"], "roh_ceiling");
+        assert_eq!(violation["limit"], 0.3);
+        assert!(!event.context_json.contains_key("zone"));
+        assert!(!event.context_json.contains_key("config_version"));
+
+        let version = ConfigVersion::new("c0ffee", UNIX_EPOCH, Some("biorail.toml".to_string()));
+        let cfg = BioRailConfig {
+            config_version: Some(version),
+            ..cfg()
+        };
+        let report = BioRailTerrasafeGuard::gate_report(
+            &site(),
+            &cfg,
+            &ProposedChange {
+                delta_roh: 0.25,
+                ..ProposedChange::default()
+            },
+        );
+        let event = to_deed_event(3, &report, "genesis".to_string(), "gate".to_string());
+        assert_eq!(event.context_json["config_version"]["hash"], "c0ffee");
+        assert_eq!(
+            event.context_json["config_version"]["source_path"],
+            "biorail.toml"
+        );
+    }
+
+    #[test]
+    fn test_contradiction_is_flagged_for_audit() {
+        use microsociety::ethics::EthicsDecision;
+
+        let roh_breach = ProposedChange {
+            delta_roh: 0.25,
+            ..ProposedChange::default()
+        };
+        let report = BioRailTerrasafeGuard::gate_with_ethics(
+            &site(),
+            &cfg(),
+            &roh_breach,
+            &EthicsDecision::Approve,
+        );
+        let event = to_deed_event(3, &report, "genesis".to_string(), "gate".to_string());
+        assert_eq!(
+            event.ethics_flags,
+            ["roh_ceiling", ETHICS_CONTRADICTION_FLAG]
+        );
+        assert_eq!(event.context_json["ethics"]["outcome"], "Contradiction");
+
+        let denied = BioRailTerrasafeGuard::gate_with_ethics(
+            &site(),
+            &cfg(),
+            &ProposedChange::default(),
+            &EthicsDecision::Deny {
+                reason: "no mandate".to_string(),
+            },
+        );
+        let event = to_deed_event(3, &denied, "genesis".to_string(), "gate".to_string());
+        assert!(event.ethics_flags.is_empty());
+        assert_eq!(event.context_json["verdict"], "Block");
+        assert_eq!(event.context_json["ethics"]["outcome"], "EthicsOverride");
+    }
+}
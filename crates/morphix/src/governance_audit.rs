@@ -0,0 +1,305 @@
+//! Ledger-backed audit trail for FPIC decisions.
+//!
+//! `LedgerBackedGovernanceAudit` wraps any `CommunityGovernanceBackend` and
+//! files every vote the inner backend accepts as a `DeedEvent` of type
+//! `fpic_decision`, linked after the ledger head. The inner backend's
+//! result always wins: a failed ledger append is reported as a
+//! `LostAuditEvent`, never as a failed vote.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+use governance_local::{
+    CommunityGovernanceBackend, CommunityId, CommunityVoteResult, FpicStatus, GovernanceProposal,
+    ProposalState, ProposalTransition,
+};
+use morphix_ledger::config::{context_entry, ConfigVersion};
+use morphix_ledger::{DeedEvent, Ledger};
+
+/// Deed type of the events this adapter appends.
+pub const FPIC_DECISION_DEED_TYPE: &str = "fpic_decision";
+
+/// A vote the inner backend recorded but the ledger did not.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LostAuditEvent {
+    pub proposal_id: String,
+    pub community_id: CommunityId,
+    pub error: String,
+}
+
+pub struct LedgerBackedGovernanceAudit<B> {
+    inner: B,
+    ledger: Ledger,
+    actor_id: String,
+    config_version: Option<ConfigVersion>,
+    lost: Mutex<Vec<LostAuditEvent>>,
+}
+
+impl<B: CommunityGovernanceBackend> LedgerBackedGovernanceAudit<B> {
+    /// Events appended by this adapter carry `actor_id`.
+    pub fn new(inner: B, ledger: Ledger, actor_id: impl Into<String>) -> Self {
+        Self {
+            inner,
+            ledger,
+            actor_id: actor_id.into(),
+            config_version: None,
+            lost: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Stamp every appended event with the config bundle in force.
+    pub fn with_config_version(mut self, version: ConfigVersion) -> Self {
+        self.config_version = Some(version);
+        self
+    }
+
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    pub fn ledger(&self) -> &Ledger {
+        &self.ledger
+    }
+
+    /// Audit events lost so far by `record_fpic_result`.
+    pub fn lost_events(&self) -> Vec<LostAuditEvent> {
+        self.lost.lock().map(|l| l.clone()).unwrap_or_default()
+    }
+
+    /// Record `result` on the inner backend, then append its deed. `Ok`
+    /// carries a warning if the inner backend accepted the vote but the
+    /// ledger append failed.
+    pub fn record_fpic_result_audited(
+        &self,
+        result: CommunityVoteResult,
+    ) -> Result<Option<LostAuditEvent>, String> {
+        let proposal_id = result.proposal_id.clone();
+        let community_id = result.community_id.clone();
+        let context = self.event_context(&result);
+        self.inner.record_fpic_result(result)?;
+
+        let appended = block_on(async {
+            let prev_hash = self.ledger.head_hash().await;
+            let event = DeedEvent::new(
+                prev_hash,
+                self.actor_id.clone(),
+                vec![community_id.0.clone()],
+                FPIC_DECISION_DEED_TYPE.to_string(),
+                vec!["fpic".to_string()],
+                context,
+                Vec::new(),
+                false,
+            );
+            self.ledger.append(event).await
+        });
+        Ok(appended.err().map(|error| LostAuditEvent {
+            proposal_id,
+            community_id,
+            error,
+        }))
+    }
+
+    /// `decision_context` plus the config version, when one is set.
+    fn event_context(&self, result: &CommunityVoteResult) -> HashMap<String, serde_json::Value> {
+        let mut context = decision_context(result);
+        if let Some(version) = &self.config_version {
+            let (key, value) = context_entry(version);
+            context.insert(key, value);
+        }
+        context
+    }
+}
+
+/// `context_json` for a vote: the proposal, the decision name and the full
+/// status as recorded.
+fn decision_context(result: &CommunityVoteResult) -> HashMap<String, serde_json::Value> {
+    let decision = match result.fpic_status {
+        FpicStatus::Pending => "Pending",
+        FpicStatus::Granted { .. } => "Granted",
+        FpicStatus::Withheld { .. } => "Withheld",
+    };
+    HashMap::from([
+        (
+            "proposal_id".to_string(),
+            serde_json::json!(result.proposal_id),
+        ),
+        ("decision".to_string(), serde_json::json!(decision)),
+        (
+            "fpic_status".to_string(),
+            serde_json::to_value(&result.fpic_status).unwrap_or_default(),
+        ),
+    ])
+}
+
+impl<B: CommunityGovernanceBackend> CommunityGovernanceBackend for LedgerBackedGovernanceAudit<B> {
+    fn get_fpic_status(
+        &self,
+        proposal_id: &str,
+        community: &CommunityId,
+    ) -> Result<FpicStatus, String> {
+        self.inner.get_fpic_status(proposal_id, community)
+    }
+
+    /// Lost audit events are kept in `lost_events()`.
+    fn record_fpic_result(&self, result: CommunityVoteResult) -> Result<(), String> {
+        if let Some(lost) = self.record_fpic_result_audited(result)? {
+            if let Ok(mut events) = self.lost.lock() {
+                events.push(lost);
+            }
+        }
+        Ok(())
+    }
+
+    fn get_proposal_state(&self, proposal_id: &str) -> Result<ProposalState, String> {
+        self.inner.get_proposal_state(proposal_id)
+    }
+
+    fn advance_proposal(
+        &self,
+        proposal_id: &str,
+        transition: ProposalTransition,
+    ) -> Result<ProposalState, String> {
+        self.inner.advance_proposal(proposal_id, transition)
+    }
+
+    fn list_proposals_for_corridor(
+        &self,
+        corridor: &str,
+    ) -> Result<Vec<GovernanceProposal>, String> {
+        self.inner.list_proposals_for_corridor(corridor)
+    }
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// The governance trait is sync; drive ledger futures on the calling thread.
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = std::pin::pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use morphix_ledger::config::Config;
+    use std::time::UNIX_EPOCH;
+
+    struct AcceptAll;
+
+    impl CommunityGovernanceBackend for AcceptAll {
+        fn get_fpic_status(&self, _: &str, _: &CommunityId) -> Result<FpicStatus, String> {
+            Ok(FpicStatus::Pending)
+        }
+
+        fn record_fpic_result(&self, _: CommunityVoteResult) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    fn vote(community: &str, fpic_status: FpicStatus) -> CommunityVoteResult {
+        CommunityVoteResult {
+            proposal_id: "snc-policy-2026-02".to_string(),
+            community_id: CommunityId(community.to_string()),
+            fpic_status,
+            signatures: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_two_decisions_append_two_linked_events() {
+        let ledger = Ledger::new(Config::default());
+        let audited = LedgerBackedGovernanceAudit::new(AcceptAll, ledger.clone(), "fpic-desk");
+        audited
+            .record_fpic_result(vote("water-shed", FpicStatus::Pending))
+            .unwrap();
+        let warning = audited
+            .record_fpic_result_audited(vote(
+                "south-phoenix-air",
+                FpicStatus::Withheld {
+                    timestamp: UNIX_EPOCH,
+                    reason: "heat season".to_string(),
+                },
+            ))
+            .unwrap();
+        assert_eq!(warning, None);
+        assert!(audited.lost_events().is_empty());
+
+        assert_eq!(block_on(ledger.verify()), Ok(2));
+        let metrics = block_on(ledger.compute_metrics());
+        assert_eq!(metrics.per_deed_type[FPIC_DECISION_DEED_TYPE], 2);
+    }
+
+    #[test]
+    fn test_decision_context_names_community_and_status() {
+        let result = vote(
+            "water-shed",
+            FpicStatus::Granted {
+                timestamp: UNIX_EPOCH,
+                signed_by: vec!["did:example:elder".to_string()],
+                valid_until: None,
+            },
+        );
+        let context = decision_context(&result);
+        assert_eq!(context["decision"], "Granted");
+        assert_eq!(context["proposal_id"], "snc-policy-2026-02");
+        assert_eq!(
+            context["fpic_status"]["Granted"]["signed_by"][0],
+            "did:example:elder"
+        );
+    }
+
+    #[test]
+    fn test_events_carry_the_config_version() {
+        let version = ConfigVersion::new("c0ffee", UNIX_EPOCH, Some("morphix.toml".to_string()));
+        let ledger = Ledger::new(Config::default());
+        let audited = LedgerBackedGovernanceAudit::new(AcceptAll, ledger.clone(), "fpic-desk");
+        let result = vote("water-shed", FpicStatus::Pending);
+        assert!(!audited
+            .event_context(&result)
+            .contains_key("config_version"));
+
+        let audited = audited.with_config_version(version);
+        let context = audited.event_context(&result);
+        assert_eq!(context["config_version"]["hash"], "c0ffee");
+        assert_eq!(context["config_version"]["source_path"], "morphix.toml");
+    }
+
+    #[test]
+    fn test_inner_result_wins_over_ledger_failures() {
+        struct Refuses;
+
+        impl CommunityGovernanceBackend for Refuses {
+            fn get_fpic_status(&self, _: &str, _: &CommunityId) -> Result<FpicStatus, String> {
+                Ok(FpicStatus::Pending)
+            }
+
+            fn record_fpic_result(&self, _: CommunityVoteResult) -> Result<(), String> {
+                Err("ledger node offline".to_string())
+            }
+        }
+
+        let ledger = Ledger::new(Config::default());
+        let audited = LedgerBackedGovernanceAudit::new(Refuses, ledger.clone(), "fpic-desk");
+        assert_eq!(
+            audited.record_fpic_result(vote("water-shed", FpicStatus::Pending)),
+            Err("ledger node offline".to_string())
+        );
+        assert_eq!(block_on(ledger.verify()), Ok(0));
+    }
+}
@@ -0,0 +1,279 @@
+//! Morphix facade: the stable, blessed public surface of the workspace.
+//!
+//! Depend on this crate instead of the individual `crates/*` members. Every
+//! path re-exported here is pinned by `tests/public_api.txt`; the member
+//! crates remain free to reorganise behind it.
+//!
+//! Feature flags:
+//! - `ledger` (default): hash-linked deed ledger, Tree-of-Life, config
+//!   bundles, the distillation audit sink and differentially private
+//!   metrics (pulls tokio, uuid, tracing).
+//! - `guard` (default): MORPHIX fairness–safety labelling observer, advisory
+//!   cooldowns and the invariant watchdog.
+//! - `biorail` (default): BioRail scalar gate and Terrasafe guard, with
+//!   `GateCase` JSON files for replaying gate decisions (pulls serde_json).
+//! - `telemetry` (default): opt-in local usage aggregates keyed by
+//!   enumerated counters only.
+//! - `governance-file` (default): `JsonFileGovernanceBackend`, an FPIC vote
+//!   store on an append-only JSONL file.
+//! - `governance-signatures` (default): Ed25519 delegate signature checks
+//!   on FPIC votes.
+//! - `governance-events` (default): FPIC change notifications and
+//!   `ConsentWatcher` (pulls tokio).
+//! - `governance-async` (default): `validate_policy_change_async` for
+//!   simulators behind a network (pulls tokio).
+//! - `guard-parallel`: `MorphixGuard::evaluate_many` and `evaluate_stream`
+//!   across rayon's pool (pulls rayon).
+//! - `serde`: serialization derives on the re-exported contract types.
+//!
+//! With `--no-default-features` only the contract, orchestrator and
+//! governance traits remain.
+
+/// SNC contract types shared by every Morphix crate.
+pub mod contract {
+    pub use core_contract::care::{CareAttestable, CareAttestation, CommunityId};
+    pub use core_contract::composite::CompositeContract;
+    pub use core_contract::labels::DataLabels;
+    pub use core_contract::roh::{CapabilityState, RoH, RoHFloat};
+    pub use core_contract::sovereign_runtime::{SovereignContext, SovereignRuntimeGuard};
+    pub use core_contract::{
+        hex_stamp, AccessClass, DefaultSovereignNeuromorphContract, DistilledKnowledge, RoleTier,
+        SncPredicate, SovereignNeuromorphContract, SCHEMA_VERSION,
+    };
+}
+
+/// EcoImpact metrics, data sources and trend tracking.
+pub mod eco {
+    pub use core_contract::eco::{
+        ArtifactViolation, CorridorId, EcoImpactMetrics, EcoImpactReport, MetricExplanation,
+        NeuromorphArtifact, ProvenanceInfo, DEFAULT_MAX_SUMMARY_LEN,
+    };
+    pub use core_contract::eco_cache::{CacheKeying, CachedEcoSource, CACHED_SUFFIX};
+    pub use core_contract::eco_rate_limit::{
+        is_transient, RateLimitConfig, RateLimitStats, RateLimitedEcoSource,
+    };
+    pub use core_contract::eco_source::{
+        AsyncEcoDataSource, CompositeEcoSource, EcoDataSource, EcoSourceError, FallbackEcoSource,
+        LegFailure, SyncEcoAdapter, COMPOSITE_SEPARATOR, FALLBACK_SEPARATOR,
+    };
+    pub use core_contract::eco_trend::{
+        EcoDimension, EcoDriftAlert, EcoTrendConfig, EcoTrendObserver, EcoTrendSample,
+        EcoTrendStore, EcoTrendTracker,
+    };
+}
+
+/// FPIC consent tokens and revocation simulation.
+pub mod fpic {
+    pub use core_contract::fpic::{
+        simulate_revocation, simulate_revocation_with_sessions, ConsentLifetime, DelegationLink,
+        DelegationTree, FpicStatus, FpicToken, RevocationGroup, RevocationImpact,
+    };
+}
+
+/// Readiness probes for eco, governance, simulator and ledger backends.
+pub mod health {
+    pub use core_contract::health::{
+        report, ComponentHealth, EcoSourceProbe, HealthCheckable, HealthReport, HealthStatus,
+    };
+    pub use governance_local::GovernanceProbe;
+    pub use governance_sim::SimulatorProbe;
+}
+
+/// Distillation orchestrator and graduated access.
+pub mod orchestration {
+    pub use orchestration::audit::{
+        AuditFuture, DistillationAuditSink, DistillationOutcome, DistillationRecord,
+        DISTILLATION_DEED_TYPE,
+    };
+    #[cfg(feature = "governance-events")]
+    pub use orchestration::consent_watch::ConsentWatcher;
+    #[cfg(feature = "governance-async")]
+    pub use orchestration::governance::validate_policy_change_async;
+    pub use orchestration::governance::{
+        corridor_has_pending_governance, validate_policy_change,
+        validate_policy_change_with_ensemble, ConsensusRule, PolicyChangeRequest,
+        PolicyGateThresholds,
+    };
+    pub use orchestration::graduated::{
+        apply_restrictions, AccessOutcome, GraduatedBand, RedactedArtifact, Restriction,
+    };
+    pub use orchestration::knowledge::{
+        KnowledgeFactorBreakdown, KnowledgeFactorConfig, KnowledgeOverrides, KnowledgeWeights,
+    };
+    pub use orchestration::policy::{
+        AccessClassPolicy, SimulationCheck, SimulationGate, ThresholdAccessPolicy,
+    };
+    pub use orchestration::quota::{DistillationQuota, InMemoryQuota, QuotaRemaining};
+    pub use orchestration::trace::{AccessBranch, DistillationTrace};
+    pub use orchestration::{
+        distill_neuromorph_content_from_components, DistillFlags, DistillRequest,
+        NeuromorphOrchestrator, OrchestrationError, OPEN_MIN_ECO_IMPACT, OPEN_MIN_KNOWLEDGE_FACTOR,
+        PURPOSE_PROHIBITED, STALE_SUFFIX,
+    };
+}
+
+/// Community governance and policy-simulation backends.
+pub mod governance {
+    #[cfg(feature = "governance-file")]
+    pub use governance_local::JsonFileGovernanceBackend;
+    #[cfg(feature = "governance-signatures")]
+    pub use governance_local::{
+        payload_hash, verify_vote_signatures, vote_signing_payload, DelegateFailure,
+        Ed25519Verifier, SignatureCheckedBackend, SignatureError, SignatureFailure,
+        SignatureVerifier,
+    };
+    pub use governance_local::{
+        sync_token_from_governance, verify_quorum, CommunityGovernanceBackend, CommunityVoteResult,
+        DelegateSignature, FpicStatus, GovernanceProposal, LifecycleError, ProposalLifecycle,
+        ProposalOutcome, ProposalState, ProposalTransition, QuorumError, QuorumPolicy,
+    };
+    #[cfg(feature = "governance-events")]
+    pub use governance_local::{GovernanceEvent, GovernanceEventStream, DEFAULT_EVENT_CAPACITY};
+    pub use governance_sim::{
+        eco_to_global_indicator, rank_policies, rank_policies_with_epsilon, sensitivity_sweep,
+        AsyncPolicySimulationBackend, CachedSimulationBackend, Domination, FieldChange,
+        LinearCoefficients, LinearPolicySimulator, OutcomeAxis, OutcomeGradient, OutcomeStats,
+        PolicyDiff, PolicyDirection, PolicyRanking, PolicySimulationBackend, RankedPolicy,
+        SeededSimulator, SensitivitySweep, SimulationEnsemble, SimulationHorizon,
+        SimulationOutcome, SnapshotKey, SnapshotParam, SncPolicySnapshot, SyncSimulationAdapter,
+        DEFAULT_PERCENTILES, PARETO_EPSILON,
+    };
+}
+
+/// MORPHIX fairness–safety labelling observer.
+#[cfg(feature = "guard")]
+pub mod guard {
+    pub use morphix_guard::jsonl;
+    pub use morphix_guard::{
+        default_roh_ceiling, BiophysicalEnvelopeSnapshot, CapabilityBreakdown, CapabilityChange,
+        CapabilityState, ConfigViolation, Determinacy, EnvelopeAxes, EnvelopeAxis, Evidence,
+        FairnessIndexWeights, FieldTrend, GuardConfigError, GuardDimension, GuardRule,
+        GuardViewDiff, GuardViewStream, LabelProvenance, LabelStats, MicroSocietyPredicate,
+        MicroSocietyView, MorphixDiagnostic, MorphixGuard, MorphixGuardConfig, MorphixGuardInput,
+        MorphixGuardReport, MorphixGuardRuleset, MorphixGuardSession, MorphixGuardTrendView,
+        MorphixGuardView, MorphixLabel, MorphixTrendDiagnostic, MorphixTrendLabel,
+        PartialThresholds, RoH, SeverityDelta, ThresholdSource, Thresholds, TreeOfLifeView,
+        DEFAULT_HYSTERESIS, DEFAULT_STREAM_CHUNK, MAX_DIAGNOSTICS, MAX_EVIDENCE,
+    };
+}
+
+/// BioRail scalar gate and Terrasafe guard.
+#[cfg(feature = "biorail")]
+pub mod biorail {
+    pub use microsociety::audit::{is_refusal, AuditedGate, GateAuditSink};
+    pub use microsociety::biorail_terrasafe::{
+        BioEnvelope, BioLoadView, BioRailConfig, BioRailTerrasafeGuard, BioRailZone,
+        BioloadCeilings, BiosignatureWeights, ConstraintSlack, ConstraintSlackReport,
+        ConstraintViolation, DiagnosticFlags, FiveDIdentity, GateConstraint, GateReport,
+        GateVerdict, JusticeCorridorConfig, JusticeMetric, JusticeMetrics, JusticeTuningRecord,
+        PowerChurchMode, PowerChurchState, ProposedChange, RailScalar, RepairPlan, RepairTarget,
+        ScaledChange, SequenceReport, SiteView, StressedMetric, StressedMetrics, TrajectoryReport,
+        TrajectoryStep, WeightViolation, ZoneCorridors, ZoneTag, DOWNSCALE_TOLERANCE,
+    };
+    pub use microsociety::case::GateCase;
+    pub use microsociety::deeds::{
+        DeedEffect, DeedEffectProjector, DeedProjection, EffectTable, ProjectionError,
+        ProposedDeed, TableProjector,
+    };
+    pub use microsociety::ethics::{EthicsDecision, EthicsOutcome, EthicsRecord};
+    pub use microsociety::grid::{GridAggregator, GridAllocation, GridCeilings};
+    pub use microsociety::power_window::{PowerChurchAverage, PowerChurchWindow};
+    pub use microsociety::repair::{RepairConfig, RepairController, RepairStatus};
+}
+
+/// Hash-linked deed ledger, Tree-of-Life and differentially private metrics.
+#[cfg(feature = "ledger")]
+pub mod ledger {
+    pub use morphix_ledger::privacy::{dp_metrics, DpMetrics, DpReport, DpSeed};
+    pub use morphix_ledger::{
+        Balance, DeedEvent, HashCompat, Ledger, Metrics, TreeBranch, TreeOfLife, TreeTrait,
+    };
+}
+
+/// Canonical JSON used for every persisted hash.
+#[cfg(any(feature = "ledger", feature = "telemetry"))]
+pub mod canonical {
+    pub use morphix_canonical::{canonical_json, CanonError};
+}
+
+/// Advisory cooldown schedules from guard labels, RoH and gate verdicts.
+#[cfg(feature = "guard")]
+pub mod cooldown;
+
+/// Advisory checks of declared invariants against observed state.
+#[cfg(feature = "guard")]
+pub mod watchdog;
+
+/// Ledger sink recording every distillation as a deed.
+#[cfg(feature = "ledger")]
+pub mod audit;
+
+/// Governance backend adapter recording every FPIC decision as a deed.
+#[cfg(feature = "ledger")]
+pub mod governance_audit;
+
+/// Ledger sink recording every BioRail gate refusal as a deed.
+#[cfg(all(feature = "ledger", feature = "biorail"))]
+pub mod gate_audit;
+
+/// Legacy deed-history import.
+#[cfg(feature = "ledger")]
+pub mod backfill;
+
+/// Versioned configuration bundles.
+pub mod config {
+    pub use core_contract::config_version::ConfigVersion;
+    #[cfg(feature = "ledger")]
+    pub use morphix_ledger::config::{
+        archive, canonical_hash, context_entry, ensure_comparable, BundleFormat, Config,
+        ConfigBundle,
+    };
+}
+
+/// Per-corridor activity reports over governance, ledger, eco and guard data.
+#[cfg(all(feature = "ledger", feature = "guard"))]
+pub mod reports;
+
+/// Opt-in local usage aggregates.
+#[cfg(feature = "telemetry")]
+pub mod telemetry;
+
+/// The types almost every integration needs.
+pub mod prelude {
+    pub use crate::contract::{
+        AccessClass, DefaultSovereignNeuromorphContract, DistilledKnowledge, RoleTier,
+        SovereignNeuromorphContract,
+    };
+    pub use crate::eco::{CorridorId, EcoDataSource, EcoImpactMetrics, NeuromorphArtifact};
+    pub use crate::governance::{CommunityGovernanceBackend, PolicySimulationBackend};
+    pub use crate::health::HealthCheckable;
+    pub use crate::orchestration::NeuromorphOrchestrator;
+
+    #[cfg(feature = "biorail")]
+    pub use crate::biorail::{BioRailTerrasafeGuard, GateVerdict};
+    #[cfg(feature = "guard")]
+    pub use crate::guard::{MorphixGuard, MorphixGuardConfig, MorphixLabel};
+    #[cfg(feature = "ledger")]
+    pub use crate::ledger::{DeedEvent, Ledger, TreeOfLife};
+}
+
+// Old top-level module paths from before the loose `src/` modules became
+// workspace crates. Kept for one release so downstream imports keep compiling.
+
+#[cfg(feature = "guard")]
+#[deprecated(note = "use `morphix::guard`")]
+pub mod morphix_guard {
+    pub use crate::guard::*;
+}
+
+#[cfg(feature = "ledger")]
+#[deprecated(note = "use `morphix::ledger`")]
+pub mod tree_of_life {
+    pub use crate::ledger::{TreeBranch, TreeOfLife, TreeTrait};
+}
+
+#[cfg(feature = "biorail")]
+#[deprecated(note = "use `morphix::biorail`")]
+pub mod biorail_terrasafe {
+    pub use crate::biorail::*;
+}
@@ -0,0 +1,466 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use core_contract::eco::CorridorId;
+use core_contract::eco_trend::{EcoDimension, EcoTrendStore, EcoTrendTracker};
+use governance_local::{CommunityVoteResult, FpicStatus, GovernanceProposal};
+
+use morphix_guard::MorphixGuardView;
+use morphix_ledger::DeedEvent;
+
+// Ledger context keys used to attribute deeds to a corridor and to read back
+// distillation outcomes. Deeds may alternatively carry a "corridor:<id>" tag.
+pub const CONTEXT_CORRIDOR_KEY: &str = "corridor_id";
+pub const CONTEXT_ACCESS_CLASS_KEY: &str = "access_class";
+pub const DISTILLATION_DEED_TYPE: &str = "neuromorph_distillation";
+
+// Half-open reporting period [start, end) in Unix epoch seconds, matching DeedEvent timestamps.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimeRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl TimeRange {
+    pub fn contains(&self, ts: u64) -> bool {
+        ts >= self.start && ts < self.end
+    }
+}
+
+// Guard view captured during a session tagged with a corridor.
+#[derive(Clone, Debug)]
+pub struct TaggedGuardView {
+    pub corridor: CorridorId,
+    pub view: MorphixGuardView,
+}
+
+// Persisted stores the report is computed from; everything is borrowed so the
+// report can be produced offline from whatever has been loaded.
+pub struct ReportSources<'a> {
+    pub proposals: &'a [GovernanceProposal],
+    pub votes: &'a [CommunityVoteResult],
+    pub deeds: &'a [DeedEvent],
+    pub eco_trend: Option<&'a EcoTrendTracker>,
+    pub guard_views: &'a [TaggedGuardView],
+    // CHURCH tokens minted per good deed (Config::token_mint_rate).
+    pub token_mint_rate: u64,
+}
+
+// Eco trend tracker kept as one JSON file, so `ReportSources::eco_trend` and
+// the orchestrator see the same series across runs.
+#[derive(Clone, Debug)]
+pub struct JsonFileEcoTrendStore {
+    path: PathBuf,
+}
+
+impl JsonFileEcoTrendStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        JsonFileEcoTrendStore { path: path.into() }
+    }
+}
+
+impl EcoTrendStore for JsonFileEcoTrendStore {
+    fn load(&self) -> Result<Option<EcoTrendTracker>, String> {
+        match fs::read(&self.path) {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .map(Some)
+                .map_err(|e| format!("{}: {e}", self.path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(format!("{}: {e}", self.path.display())),
+        }
+    }
+
+    fn save(&self, tracker: &EcoTrendTracker) -> Result<(), String> {
+        let json = serde_json::to_vec(tracker).map_err(|e| e.to_string())?;
+        if let Some(dir) = self.path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+        }
+        fs::write(&self.path, json).map_err(|e| format!("{}: {e}", self.path.display()))
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct GovernanceActivity {
+    pub proposals: Vec<String>,
+    pub fpic_granted: u64,
+    pub fpic_withheld: u64,
+    pub fpic_pending: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct DistillationActivity {
+    pub total: u64,
+    pub by_access_class: BTreeMap<String, u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct EcoTrendSummary {
+    pub samples: u64,
+    pub rolling_mean: BTreeMap<String, f32>,
+    pub slope_per_day: BTreeMap<String, f32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct GuardActivity {
+    pub epochs: u64,
+    pub epochs_with_risk: u64,
+    pub label_counts: BTreeMap<String, u64>,
+    pub max_roh: f32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CorridorReport {
+    pub corridor: String,
+    pub period: TimeRange,
+    pub governance: GovernanceActivity,
+    pub distillations: DistillationActivity,
+    pub eco_trend: Option<EcoTrendSummary>,
+    pub church_minted: u64,
+    pub harm_flags: u64,
+    pub guard: GuardActivity,
+}
+
+// Aggregates governance, ledger, eco trend and guard data for one corridor over a period.
+pub fn corridor_report(
+    corridor: &CorridorId,
+    sources: ReportSources<'_>,
+    period: TimeRange,
+) -> CorridorReport {
+    // Governance: proposals touching the corridor created within the period.
+    let mut governance = GovernanceActivity::default();
+    for proposal in sources.proposals {
+        let created = unix_secs(proposal.created_at);
+        if !period.contains(created) || !proposal.affected_corridors.contains(&corridor.0) {
+            continue;
+        }
+        governance.proposals.push(proposal.id.clone());
+        for vote in sources
+            .votes
+            .iter()
+            .filter(|v| v.proposal_id == proposal.id)
+        {
+            match vote.fpic_status {
+                FpicStatus::Granted { .. } => governance.fpic_granted += 1,
+                FpicStatus::Withheld { .. } => governance.fpic_withheld += 1,
+                FpicStatus::Pending => governance.fpic_pending += 1,
+            }
+        }
+    }
+
+    // Ledger: corridor-tagged deeds within the period.
+    let mut distillations = DistillationActivity::default();
+    let mut good_deeds = 0u64;
+    let mut harm_flags = 0u64;
+    for deed in sources
+        .deeds
+        .iter()
+        .filter(|d| period.contains(d.timestamp) && deed_in_corridor(d, corridor))
+    {
+        if deed.life_harm_flag {
+            harm_flags += 1;
+        } else {
+            good_deeds += 1;
+        }
+        if deed.deed_type == DISTILLATION_DEED_TYPE {
+            distillations.total += 1;
+            let class = deed
+                .context_json
+                .get(CONTEXT_ACCESS_CLASS_KEY)
+                .and_then(|v| v.as_str())
+                .unwrap_or("refused")
+                .to_string();
+            *distillations.by_access_class.entry(class).or_insert(0) += 1;
+        }
+    }
+
+    // Eco trend summary over the samples taken within the period.
+    let eco_trend = sources.eco_trend.and_then(|tracker| {
+        let tracker = &tracker.samples_where(|at| period.contains(unix_secs(at)));
+        let samples = tracker.series(corridor).len() as u64;
+        if samples == 0 {
+            return None;
+        }
+        let mut rolling_mean = BTreeMap::new();
+        let mut slope_per_day = BTreeMap::new();
+        for dimension in EcoDimension::ALL {
+            let name = format!("{:?}", dimension);
+            if let Some(mean) = tracker.rolling_mean(corridor, dimension) {
+                rolling_mean.insert(name.clone(), mean);
+            }
+            if let Some(slope) = tracker.slope(corridor, dimension) {
+                slope_per_day.insert(name, slope);
+            }
+        }
+        Some(EcoTrendSummary {
+            samples,
+            rolling_mean,
+            slope_per_day,
+        })
+    });
+
+    // Guard: label statistics for sessions tagged with the corridor.
+    let mut guard = GuardActivity::default();
+    for tagged in sources
+        .guard_views
+        .iter()
+        .filter(|g| &g.corridor == corridor)
+    {
+        guard.epochs += 1;
+        guard.max_roh = guard.max_roh.max(tagged.view.roh_value);
+        let mut risky = false;
+        for diag in &tagged.view.diagnostics {
+            risky |= diag.label.is_risk();
+            *guard
+                .label_counts
+                .entry(format!("{:?}", diag.label))
+                .or_insert(0) += 1;
+        }
+        if risky {
+            guard.epochs_with_risk += 1;
+        }
+    }
+
+    CorridorReport {
+        corridor: corridor.0.clone(),
+        period,
+        governance,
+        distillations,
+        eco_trend,
+        church_minted: good_deeds * sources.token_mint_rate,
+        harm_flags,
+        guard,
+    }
+}
+
+fn unix_secs(at: SystemTime) -> u64 {
+    at.duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn deed_in_corridor(deed: &DeedEvent, corridor: &CorridorId) -> bool {
+    let tagged = format!("corridor:{}", corridor.0);
+    deed.context_json
+        .get(CONTEXT_CORRIDOR_KEY)
+        .and_then(|v| v.as_str())
+        .map(|c| c == corridor.0)
+        .unwrap_or(false)
+        || deed.tags.contains(&tagged)
+}
+
+impl CorridorReport {
+    // Renders the report as Markdown for stewards.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "# Corridor report: {}", self.corridor);
+        let _ = writeln!(out);
+        let _ = writeln!(out, "Period: {} – {}", self.period.start, self.period.end);
+        let _ = writeln!(out);
+
+        let _ = writeln!(out, "## Governance");
+        let _ = writeln!(out, "- Proposals: {}", self.governance.proposals.len());
+        for id in &self.governance.proposals {
+            let _ = writeln!(out, "  - {}", id);
+        }
+        let _ = writeln!(
+            out,
+            "- FPIC granted / withheld / pending: {} / {} / {}",
+            self.governance.fpic_granted,
+            self.governance.fpic_withheld,
+            self.governance.fpic_pending
+        );
+        let _ = writeln!(out);
+
+        let _ = writeln!(out, "## Distillations");
+        let _ = writeln!(out, "- Total: {}", self.distillations.total);
+        for (class, count) in &self.distillations.by_access_class {
+            let _ = writeln!(out, "  - {}: {}", class, count);
+        }
+        let _ = writeln!(out);
+
+        let _ = writeln!(out, "## EcoImpact trend");
+        match &self.eco_trend {
+            Some(trend) => {
+                let _ = writeln!(out, "| Dimension | Rolling mean | Slope/day |");
+                let _ = writeln!(out, "|---|---|---|");
+                for (dim, mean) in &trend.rolling_mean {
+                    let slope = trend.slope_per_day.get(dim).copied().unwrap_or(0.0);
+                    let _ = writeln!(out, "| {} | {:.3} | {:+.4} |", dim, mean, slope);
+                }
+            }
+            None => {
+                let _ = writeln!(out, "No trend samples recorded.");
+            }
+        }
+        let _ = writeln!(out);
+
+        let _ = writeln!(out, "## Ledger");
+        let _ = writeln!(out, "- CHURCH minted: {}", self.church_minted);
+        let _ = writeln!(out, "- Harm flags: {}", self.harm_flags);
+        let _ = writeln!(out);
+
+        let _ = writeln!(out, "## Guard");
+        let _ = writeln!(
+            out,
+            "- Epochs: {} ({} with risk labels), max RoH {:.2}",
+            self.guard.epochs, self.guard.epochs_with_risk, self.guard.max_roh
+        );
+        for (label, count) in &self.guard.label_counts {
+            let _ = writeln!(out, "  - {}: {}", label, count);
+        }
+        out
+    }
+}
+
+// Unit tests for corridor report aggregation.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn deed(
+        prev: &str,
+        corridor: &str,
+        deed_type: &str,
+        class: Option<&str>,
+        harm: bool,
+    ) -> DeedEvent {
+        let mut context = HashMap::new();
+        context.insert(
+            CONTEXT_CORRIDOR_KEY.to_string(),
+            serde_json::json!(corridor),
+        );
+        if let Some(class) = class {
+            context.insert(
+                CONTEXT_ACCESS_CLASS_KEY.to_string(),
+                serde_json::json!(class),
+            );
+        }
+        DeedEvent::new(
+            prev.to_string(),
+            "actor1".to_string(),
+            vec![],
+            deed_type.to_string(),
+            vec![],
+            context,
+            vec![],
+            harm,
+        )
+    }
+
+    #[test]
+    fn test_corridor_report_counts_only_matching_corridor() {
+        let corridor = CorridorId("protected-desert-phoenix".to_string());
+        let d1 = deed(
+            "genesis",
+            &corridor.0,
+            DISTILLATION_DEED_TYPE,
+            Some("KnowledgeGated"),
+            false,
+        );
+        let d2 = deed(
+            &d1.self_hash,
+            &corridor.0,
+            DISTILLATION_DEED_TYPE,
+            Some("Open"),
+            false,
+        );
+        let d3 = deed(
+            &d2.self_hash,
+            &corridor.0,
+            "ecological_sustainability",
+            None,
+            true,
+        );
+        let d4 = deed(
+            &d3.self_hash,
+            "urban-phoenix",
+            DISTILLATION_DEED_TYPE,
+            Some("Open"),
+            false,
+        );
+        let deeds = vec![d1, d2, d3, d4];
+
+        let report = corridor_report(
+            &corridor,
+            ReportSources {
+                proposals: &[],
+                votes: &[],
+                deeds: &deeds,
+                eco_trend: None,
+                guard_views: &[],
+                token_mint_rate: 10,
+            },
+            TimeRange {
+                start: 0,
+                end: u64::MAX,
+            },
+        );
+
+        assert_eq!(report.distillations.total, 2);
+        assert_eq!(report.distillations.by_access_class.get("Open"), Some(&1));
+        assert_eq!(report.church_minted, 20);
+        assert_eq!(report.harm_flags, 1);
+
+        let md = report.to_markdown();
+        assert!(md.starts_with("# Corridor report: protected-desert-phoenix"));
+        assert!(md.contains("- CHURCH minted: 20"));
+        assert!(md.contains("No trend samples recorded."));
+    }
+
+    #[test]
+    fn test_eco_trend_is_read_back_and_limited_to_the_period() {
+        use core_contract::eco::EcoImpactMetrics;
+        use core_contract::eco_trend::EcoTrendConfig;
+        use std::time::Duration;
+
+        let corridor = CorridorId("protected-desert-phoenix".to_string());
+        let mut tracker = EcoTrendTracker::new(EcoTrendConfig::default());
+        for day in 0..6u64 {
+            let score = 0.9 - 0.1 * day as f32;
+            let metrics = EcoImpactMetrics {
+                climate_score: score,
+                biodiversity_score: score,
+                biosphere_score: score,
+                corridor_score: score,
+            };
+            tracker.record(
+                &corridor,
+                metrics,
+                UNIX_EPOCH + Duration::from_secs(86_400 * day),
+            );
+        }
+        let path =
+            std::env::temp_dir().join(format!("morphix-eco-trend-{}.json", std::process::id()));
+        let store = JsonFileEcoTrendStore::new(&path);
+        assert!(store.load().unwrap().is_none());
+        store.save(&tracker).unwrap();
+        let loaded = store.load().unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let report = corridor_report(
+            &corridor,
+            ReportSources {
+                proposals: &[],
+                votes: &[],
+                deeds: &[],
+                eco_trend: loaded.as_ref(),
+                guard_views: &[],
+                token_mint_rate: 1,
+            },
+            TimeRange {
+                start: 86_400,
+                end: 3 * 86_400,
+            },
+        );
+        let trend = report.eco_trend.unwrap();
+        assert_eq!(trend.samples, 2);
+        assert!((trend.rolling_mean["Climate"] - 0.75).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,631 @@
+//! Opt-in, sovereignty-preserving usage telemetry.
+//!
+//! `LocalAggregator` only ever counts the keys enumerated below. Keys are
+//! fieldless enums, so no artifact, corridor, actor or envelope value can be
+//! attached to them; refusal messages are classified into a `Counter` and
+//! then dropped. Aggregates stay on local disk as one JSON file per UTC day,
+//! each carrying a SHA-256 hash of its own contents.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use core_contract::eco::{EcoImpactMetrics, EcoImpactReport, NeuromorphArtifact, ProvenanceInfo};
+use core_contract::eco_source::{EcoDataSource, EcoSourceError};
+use orchestration::OrchestrationError;
+
+const FILE_PREFIX: &str = "telemetry-";
+
+/// Upper bucket bounds for latency histograms, in milliseconds. A final
+/// overflow bucket catches everything slower.
+pub const LATENCY_BOUNDS_MS: [u64; 8] = [1, 5, 10, 50, 100, 500, 1_000, 5_000];
+
+/// Pre-approved counters. Adding a key is a reviewed code change.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Counter {
+    Distilled,
+    RefusedInvalidArtifact,
+    RefusedConsentRequired,
+    RefusedAbortControlMissing,
+    RefusedCoerciveDiscipline,
+    RefusedDowngradeForbidden,
+    RefusedFpicInvalid,
+    RefusedCareMisaligned,
+    RefusedPurposeProhibited,
+    RefusedChatIneligible,
+    RefusedEcoSourceError,
+    RefusedQuotaExceeded,
+    RefusedOther,
+}
+
+impl Counter {
+    pub const ALL: [Counter; 13] = [
+        Counter::Distilled,
+        Counter::RefusedInvalidArtifact,
+        Counter::RefusedConsentRequired,
+        Counter::RefusedAbortControlMissing,
+        Counter::RefusedCoerciveDiscipline,
+        Counter::RefusedDowngradeForbidden,
+        Counter::RefusedFpicInvalid,
+        Counter::RefusedCareMisaligned,
+        Counter::RefusedPurposeProhibited,
+        Counter::RefusedChatIneligible,
+        Counter::RefusedEcoSourceError,
+        Counter::RefusedQuotaExceeded,
+        Counter::RefusedOther,
+    ];
+
+    /// Maps an orchestrator refusal onto its refusal code. Payloads (purpose,
+    /// artifact id, upstream error text) are never stored.
+    pub fn for_refusal(error: &OrchestrationError) -> Counter {
+        match error {
+            OrchestrationError::InvalidArtifact(_) => Counter::RefusedInvalidArtifact,
+            OrchestrationError::MissingConsent => Counter::RefusedConsentRequired,
+            OrchestrationError::NoAbortControl => Counter::RefusedAbortControlMissing,
+            OrchestrationError::CoerciveDiscipline => Counter::RefusedCoerciveDiscipline,
+            OrchestrationError::DowngradeForbidden => Counter::RefusedDowngradeForbidden,
+            OrchestrationError::ContractMemberRefused { predicate, .. } => {
+                Counter::for_refusal(&(*predicate).into())
+            }
+            OrchestrationError::FpicExpired | OrchestrationError::FpicWithdrawn(_) => {
+                Counter::RefusedFpicInvalid
+            }
+            OrchestrationError::CareMisaligned => Counter::RefusedCareMisaligned,
+            OrchestrationError::PurposeProhibited { .. } => Counter::RefusedPurposeProhibited,
+            OrchestrationError::ChatMissingDualLinkage
+            | OrchestrationError::ChatUncertaintyNotExposed => Counter::RefusedChatIneligible,
+            OrchestrationError::EcoSourceFailure(_) => Counter::RefusedEcoSourceError,
+            OrchestrationError::QuotaExceeded { .. } => Counter::RefusedQuotaExceeded,
+            OrchestrationError::QuotaActorMissing => Counter::RefusedOther,
+            OrchestrationError::SimulationFailure(_)
+            | OrchestrationError::InvalidKnowledgeFactor(_) => Counter::RefusedOther,
+        }
+    }
+}
+
+/// Pre-approved histograms.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Histogram {
+    EcoSourceLatency,
+}
+
+impl Histogram {
+    pub const ALL: [Histogram; 1] = [Histogram::EcoSourceLatency];
+}
+
+/// Whether telemetry is collected and where daily aggregates are written.
+/// Disabled unless a deployment turns it on explicitly.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TelemetryConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_dir")]
+    pub dir: PathBuf,
+}
+
+fn default_dir() -> PathBuf {
+    PathBuf::from("telemetry")
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        TelemetryConfig {
+            enabled: false,
+            dir: default_dir(),
+        }
+    }
+}
+
+/// Bucketed latency observations; `counts` has one more entry than
+/// `bounds_ms` for the overflow bucket.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct HistogramCounts {
+    pub bounds_ms: Vec<u64>,
+    pub counts: Vec<u64>,
+    pub total: u64,
+    pub sum_ms: u64,
+}
+
+impl Default for HistogramCounts {
+    fn default() -> Self {
+        HistogramCounts {
+            bounds_ms: LATENCY_BOUNDS_MS.to_vec(),
+            counts: vec![0; LATENCY_BOUNDS_MS.len() + 1],
+            total: 0,
+            sum_ms: 0,
+        }
+    }
+}
+
+impl HistogramCounts {
+    fn observe(&mut self, elapsed: Duration) {
+        let ms = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
+        let bucket = self
+            .bounds_ms
+            .iter()
+            .position(|bound| ms <= *bound)
+            .unwrap_or(self.bounds_ms.len());
+        self.counts[bucket] += 1;
+        self.total += 1;
+        self.sum_ms = self.sum_ms.saturating_add(ms);
+    }
+
+    fn merge(&mut self, other: &HistogramCounts) {
+        for (mine, theirs) in self.counts.iter_mut().zip(&other.counts) {
+            *mine += theirs;
+        }
+        self.total += other.total;
+        self.sum_ms = self.sum_ms.saturating_add(other.sum_ms);
+    }
+}
+
+fn hex_digest(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// One UTC day of aggregated telemetry as written to disk.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DailyAggregate {
+    pub day: String,
+    pub counters: BTreeMap<Counter, u64>,
+    pub histograms: BTreeMap<Histogram, HistogramCounts>,
+    /// SHA-256 hex of this aggregate's canonical JSON with an empty hash.
+    pub content_hash: String,
+}
+
+impl DailyAggregate {
+    fn empty(day: String) -> Self {
+        DailyAggregate {
+            day,
+            counters: BTreeMap::new(),
+            histograms: BTreeMap::new(),
+            content_hash: String::new(),
+        }
+    }
+
+    fn compute_hash(&self) -> String {
+        let mut unhashed = self.clone();
+        unhashed.content_hash.clear();
+        hex_digest(&morphix_canonical::canonical_json(&unhashed).unwrap_or_default())
+    }
+
+    /// Hash as written before aggregates were canonicalized (plain
+    /// serde_json, counters in enum order).
+    fn legacy_hash(&self) -> String {
+        let mut unhashed = self.clone();
+        unhashed.content_hash.clear();
+        hex_digest(&serde_json::to_vec(&unhashed).unwrap_or_default())
+    }
+
+    /// True when the stored hash matches the contents. Files written before
+    /// the canonical hash are still accepted; the next flush for that day
+    /// rewrites them canonically.
+    pub fn verify(&self) -> bool {
+        self.content_hash == self.compute_hash() || self.content_hash == self.legacy_hash()
+    }
+
+    fn merge(&mut self, other: &DailyAggregate) {
+        for (key, count) in &other.counters {
+            *self.counters.entry(*key).or_insert(0) += count;
+        }
+        for (key, counts) in &other.histograms {
+            self.histograms.entry(*key).or_default().merge(counts);
+        }
+    }
+}
+
+#[derive(Default)]
+struct Pending {
+    counters: BTreeMap<Counter, u64>,
+    histograms: BTreeMap<Histogram, HistogramCounts>,
+}
+
+/// In-memory accumulator for the enumerated counters and histograms.
+/// Every recording call is a no-op while telemetry is disabled.
+pub struct LocalAggregator {
+    config: TelemetryConfig,
+    pending: Mutex<Pending>,
+}
+
+impl LocalAggregator {
+    pub fn new(config: TelemetryConfig) -> Self {
+        LocalAggregator {
+            config,
+            pending: Mutex::new(Pending::default()),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    pub fn config(&self) -> &TelemetryConfig {
+        &self.config
+    }
+
+    pub fn increment(&self, counter: Counter) {
+        if !self.config.enabled {
+            return;
+        }
+        if let Ok(mut pending) = self.pending.lock() {
+            *pending.counters.entry(counter).or_insert(0) += 1;
+        }
+    }
+
+    /// Counts a refusal by code; its payload is discarded.
+    pub fn record_refusal(&self, error: &OrchestrationError) {
+        self.increment(Counter::for_refusal(error));
+    }
+
+    pub fn observe(&self, histogram: Histogram, elapsed: Duration) {
+        if !self.config.enabled {
+            return;
+        }
+        if let Ok(mut pending) = self.pending.lock() {
+            pending
+                .histograms
+                .entry(histogram)
+                .or_default()
+                .observe(elapsed);
+        }
+    }
+
+    /// Returns what has been recorded since the last flush, tagged with the
+    /// UTC day of `now`.
+    pub fn snapshot(&self, now: SystemTime) -> DailyAggregate {
+        let mut aggregate = DailyAggregate::empty(utc_day(now));
+        if let Ok(pending) = self.pending.lock() {
+            aggregate.counters = pending.counters.clone();
+            aggregate.histograms = pending.histograms.clone();
+        }
+        aggregate.content_hash = aggregate.compute_hash();
+        aggregate
+    }
+
+    /// Merges pending observations into the day file for `now` and clears
+    /// them. Returns the written path, or `None` when disabled.
+    pub fn flush(&self, now: SystemTime) -> io::Result<Option<PathBuf>> {
+        if !self.config.enabled {
+            return Ok(None);
+        }
+        let mut pending = self
+            .pending
+            .lock()
+            .map_err(|_| io::Error::other("telemetry aggregator poisoned"))?;
+
+        let day = utc_day(now);
+        let path = self.config.dir.join(format!("{FILE_PREFIX}{day}.json"));
+        let mut aggregate = match fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).map_err(io::Error::other)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => DailyAggregate::empty(day),
+            Err(e) => return Err(e),
+        };
+        aggregate.merge(&DailyAggregate {
+            counters: std::mem::take(&mut pending.counters),
+            histograms: std::mem::take(&mut pending.histograms),
+            ..DailyAggregate::empty(String::new())
+        });
+        aggregate.content_hash = aggregate.compute_hash();
+
+        fs::create_dir_all(&self.config.dir)?;
+        let json = serde_json::to_vec_pretty(&aggregate).map_err(io::Error::other)?;
+        fs::write(&path, json)?;
+        Ok(Some(path))
+    }
+}
+
+/// Reads every daily aggregate in `dir`, oldest first.
+pub fn load_aggregates(dir: &Path) -> io::Result<Vec<DailyAggregate>> {
+    let mut aggregates = Vec::new();
+    for path in aggregate_files(dir)? {
+        let bytes = fs::read(&path)?;
+        let aggregate: DailyAggregate = serde_json::from_slice(&bytes)
+            .map_err(|e| io::Error::other(format!("{}: {e}", path.display())))?;
+        aggregates.push(aggregate);
+    }
+    aggregates.sort_by(|a, b| a.day.cmp(&b.day));
+    Ok(aggregates)
+}
+
+/// Deletes every daily aggregate in `dir` and returns how many were removed.
+/// Other files in the directory are left alone.
+pub fn purge(dir: &Path) -> io::Result<usize> {
+    let files = aggregate_files(dir)?;
+    for path in &files {
+        fs::remove_file(path)?;
+    }
+    Ok(files.len())
+}
+
+fn aggregate_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        if name.starts_with(FILE_PREFIX) && name.ends_with(".json") {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+/// `YYYY-MM-DD` for the UTC day containing `at`.
+fn utc_day(at: SystemTime) -> String {
+    let days = at
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() / 86_400)
+        .unwrap_or(0) as i64;
+    // Civil-from-days (Howard Hinnant), valid for the proleptic Gregorian calendar.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Eco source wrapper that records call latency into an aggregator.
+pub struct TimedEcoSource<'a, E: EcoDataSource> {
+    pub inner: E,
+    pub telemetry: &'a LocalAggregator,
+}
+
+impl<E: EcoDataSource> EcoDataSource for TimedEcoSource<'_, E> {
+    fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+        let started = Instant::now();
+        let result = self.inner.calculate(artifact);
+        self.telemetry
+            .observe(Histogram::EcoSourceLatency, started.elapsed());
+        result
+    }
+
+    /// Forwards to the inner batch path; the whole batch is one observation.
+    fn calculate_batch(
+        &self,
+        artifacts: &[&NeuromorphArtifact],
+    ) -> Vec<Result<EcoImpactMetrics, EcoSourceError>> {
+        let started = Instant::now();
+        let results = self.inner.calculate_batch(artifacts);
+        self.telemetry
+            .observe(Histogram::EcoSourceLatency, started.elapsed());
+        results
+    }
+
+    fn provenance_label(&self) -> &'static str {
+        self.inner.provenance_label()
+    }
+
+    fn provenance_detail(&self) -> ProvenanceInfo {
+        self.inner.provenance_detail()
+    }
+
+    fn calculate_with_provenance(
+        &self,
+        artifact: &NeuromorphArtifact,
+    ) -> Result<(EcoImpactMetrics, ProvenanceInfo), EcoSourceError> {
+        let started = Instant::now();
+        let result = self.inner.calculate_with_provenance(artifact);
+        self.telemetry
+            .observe(Histogram::EcoSourceLatency, started.elapsed());
+        result
+    }
+
+    fn calculate_with_report(
+        &self,
+        artifact: &NeuromorphArtifact,
+    ) -> Result<EcoImpactReport, EcoSourceError> {
+        let started = Instant::now();
+        let result = self.inner.calculate_with_report(artifact);
+        self.telemetry
+            .observe(Histogram::EcoSourceLatency, started.elapsed());
+        result
+    }
+
+    fn calculate_batch_with_provenance(
+        &self,
+        artifacts: &[&NeuromorphArtifact],
+    ) -> Vec<Result<(EcoImpactMetrics, ProvenanceInfo), EcoSourceError>> {
+        let started = Instant::now();
+        let results = self.inner.calculate_batch_with_provenance(artifacts);
+        self.telemetry
+            .observe(Histogram::EcoSourceLatency, started.elapsed());
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core_contract::eco::ArtifactViolation;
+    use core_contract::SncPredicate;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("morphix-telemetry-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn enabled(dir: &Path) -> LocalAggregator {
+        LocalAggregator::new(TelemetryConfig {
+            enabled: true,
+            dir: dir.to_path_buf(),
+        })
+    }
+
+    // `key as usize` only compiles for fieldless enums, so a variant that
+    // carried a String (or any other payload) would break this test at
+    // compile time. ALL must also list every variant exactly once.
+    #[test]
+    fn test_keys_are_fieldless_and_enumerated() {
+        for (index, key) in Counter::ALL.iter().enumerate() {
+            assert_eq!(*key as usize, index);
+        }
+        for (index, key) in Histogram::ALL.iter().enumerate() {
+            assert_eq!(*key as usize, index);
+        }
+        assert_eq!(std::mem::size_of::<Counter>(), 1);
+        assert_eq!(std::mem::size_of::<Histogram>(), 0);
+    }
+
+    #[test]
+    fn test_refusal_messages_map_to_codes() {
+        let purpose = OrchestrationError::PurposeProhibited {
+            purpose: "x".to_string(),
+            artifact_id: "a-1".to_string(),
+        };
+        assert_eq!(
+            Counter::for_refusal(&purpose),
+            Counter::RefusedPurposeProhibited
+        );
+        assert_eq!(
+            Counter::for_refusal(&OrchestrationError::InvalidArtifact(vec![
+                ArtifactViolation::EmptyId
+            ])),
+            Counter::RefusedInvalidArtifact
+        );
+        assert_eq!(
+            Counter::for_refusal(&OrchestrationError::MissingConsent),
+            Counter::RefusedConsentRequired
+        );
+        assert_eq!(
+            Counter::for_refusal(&OrchestrationError::ContractMemberRefused {
+                member: "community".to_string(),
+                predicate: SncPredicate::ExplicitConsent,
+            }),
+            Counter::RefusedConsentRequired
+        );
+        assert_eq!(
+            Counter::for_refusal(&OrchestrationError::FpicExpired),
+            Counter::RefusedFpicInvalid
+        );
+        assert_eq!(
+            Counter::for_refusal(&OrchestrationError::CareMisaligned),
+            Counter::RefusedCareMisaligned
+        );
+        assert_eq!(
+            Counter::for_refusal(&OrchestrationError::ChatUncertaintyNotExposed),
+            Counter::RefusedChatIneligible
+        );
+        assert_eq!(
+            Counter::for_refusal(&OrchestrationError::EcoSourceFailure(
+                EcoSourceError::UpstreamTimeout
+            )),
+            Counter::RefusedEcoSourceError
+        );
+        assert_eq!(
+            Counter::for_refusal(&OrchestrationError::QuotaExceeded {
+                retry_after: Duration::from_secs(30)
+            }),
+            Counter::RefusedQuotaExceeded
+        );
+        assert_eq!(
+            Counter::for_refusal(&OrchestrationError::InvalidKnowledgeFactor(
+                "nan".to_string()
+            )),
+            Counter::RefusedOther
+        );
+    }
+
+    #[test]
+    fn test_disabled_aggregator_records_nothing() {
+        let dir = scratch_dir("disabled");
+        let telemetry = LocalAggregator::new(TelemetryConfig {
+            enabled: false,
+            dir: dir.clone(),
+        });
+        telemetry.increment(Counter::Distilled);
+        telemetry.observe(Histogram::EcoSourceLatency, Duration::from_millis(3));
+
+        let snapshot = telemetry.snapshot(UNIX_EPOCH);
+        assert!(snapshot.counters.is_empty());
+        assert!(snapshot.histograms.is_empty());
+        assert_eq!(telemetry.flush(UNIX_EPOCH).unwrap(), None);
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn test_aggregation_math_and_daily_merge() {
+        let dir = scratch_dir("math");
+        let telemetry = enabled(&dir);
+        let day = UNIX_EPOCH + Duration::from_secs(19_000 * 86_400 + 3_600);
+
+        telemetry.increment(Counter::Distilled);
+        telemetry.increment(Counter::Distilled);
+        telemetry.record_refusal(&OrchestrationError::ChatMissingDualLinkage);
+        for ms in [0, 1, 7, 450, 9_000] {
+            telemetry.observe(Histogram::EcoSourceLatency, Duration::from_millis(ms));
+        }
+
+        let first = telemetry.flush(day).unwrap().unwrap();
+        assert!(first.ends_with("telemetry-2022-01-08.json"));
+
+        telemetry.increment(Counter::Distilled);
+        telemetry.observe(Histogram::EcoSourceLatency, Duration::from_millis(2));
+        let second = telemetry
+            .flush(day + Duration::from_secs(60))
+            .unwrap()
+            .unwrap();
+        assert_eq!(first, second);
+
+        let aggregates = load_aggregates(&dir).unwrap();
+        assert_eq!(aggregates.len(), 1);
+        let aggregate = &aggregates[0];
+        assert!(aggregate.verify());
+        assert_eq!(aggregate.counters[&Counter::Distilled], 3);
+        assert_eq!(aggregate.counters[&Counter::RefusedChatIneligible], 1);
+
+        let latency = &aggregate.histograms[&Histogram::EcoSourceLatency];
+        // Buckets: <=1, <=5, <=10, <=50, <=100, <=500, <=1000, <=5000, overflow.
+        assert_eq!(latency.counts, vec![2, 1, 1, 0, 0, 1, 0, 0, 1]);
+        assert_eq!(latency.total, 6);
+        assert_eq!(latency.sum_ms, 9_460);
+
+        // Pending state was cleared by the flushes.
+        assert!(telemetry.snapshot(day).counters.is_empty());
+
+        let mut tampered = aggregate.clone();
+        tampered.counters.insert(Counter::Distilled, 99);
+        assert!(!tampered.verify());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_purge_removes_only_aggregates() {
+        let dir = scratch_dir("purge");
+        let telemetry = enabled(&dir);
+        telemetry.increment(Counter::Distilled);
+        telemetry.flush(UNIX_EPOCH).unwrap();
+        telemetry.increment(Counter::Distilled);
+        telemetry
+            .flush(UNIX_EPOCH + Duration::from_secs(86_400))
+            .unwrap();
+        fs::write(dir.join("notes.txt"), "keep me").unwrap();
+
+        assert_eq!(purge(&dir).unwrap(), 2);
+        assert!(load_aggregates(&dir).unwrap().is_empty());
+        assert!(dir.join("notes.txt").exists());
+        assert_eq!(purge(&dir).unwrap(), 0);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,583 @@
+//! Advisory watchdog for invariants that are declared in doc comments but
+//! not enforced on observed state.
+//!
+//! `INVARIANTS` is the single table of what is declared. Both the stream
+//! checks (`InvariantWatchdog::check_*`, `scan_guard_stream`) and the static
+//! config checks (`verify_*_config`) report against its ids and share its
+//! bounds (`ROH_CEILING`); reporting an undeclared id is a debug assertion.
+//!
+//! The watchdog never actuates: every method takes shared references to the
+//! observed state and returns `InvariantBreach` records. Callers write them
+//! to the session log (`Display` is one line per breach) and, with the
+//! `ledger` feature, to the ledger via `InvariantBreach::to_deed_event`.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use morphix_guard::{CapabilityState, MorphixGuardInput, RoH};
+
+use crate::cooldown::CooldownConfig;
+
+#[cfg(feature = "biorail")]
+use microsociety::biorail_terrasafe::{JusticeCorridorConfig, SiteView};
+
+/// RoH never decreases between consecutive observations.
+pub const ROH_MONOTONE: &str = "roh.monotone";
+/// RoH stays at or below `ROH_CEILING` while in ControlledHuman.
+pub const ROH_CEILING_CONTROLLED_HUMAN: &str = "roh.ceiling.controlled_human";
+/// Capability never moves down the lattice.
+pub const CAPABILITY_NO_DOWNGRADE: &str = "capability.no_downgrade";
+/// Observed POWER ≤ k·CHURCH at every site.
+pub const POWER_CHURCH_BOUND: &str = "power_church.bound";
+/// Site RoH envelope stays at or below `ROH_CEILING`.
+pub const SITE_ROH_CEILING: &str = "site.roh_ceiling";
+/// Site roles are diagnostic-only.
+pub const ROLE_DIAGNOSTIC_ONLY: &str = "site.role_diagnostic_only";
+/// Justice corridors only shrink ceilings.
+pub const JUSTICE_TIGHTENING_ONLY: &str = "justice.tightening_only";
+
+/// RoH hard ceiling shared by the guard and the BioRail envelope.
+pub const ROH_CEILING: f32 = RoH::ceiling_for(CapabilityState::ControlledHuman);
+
+/// Where an invariant can be checked.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CheckedOn {
+    /// Observed state streams only.
+    Stream,
+    /// Configuration only.
+    Config,
+    /// Both observed state and configuration.
+    StreamAndConfig,
+}
+
+/// One declared invariant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvariantSpec {
+    pub id: &'static str,
+    pub statement: &'static str,
+    /// Doc comment the invariant is declared in.
+    pub declared_in: &'static str,
+    pub checked_on: CheckedOn,
+}
+
+/// Every invariant the watchdog and the static verifier know about.
+pub const INVARIANTS: &[InvariantSpec] = &[
+    InvariantSpec {
+        id: ROH_MONOTONE,
+        statement: "RoH_after >= RoH_before",
+        declared_in: "morphix_guard::RoH",
+        checked_on: CheckedOn::Stream,
+    },
+    InvariantSpec {
+        id: ROH_CEILING_CONTROLLED_HUMAN,
+        statement: "RoH <= 0.30 in CapControlledHuman",
+        declared_in: "morphix_guard::RoH",
+        checked_on: CheckedOn::StreamAndConfig,
+    },
+    InvariantSpec {
+        id: CAPABILITY_NO_DOWNGRADE,
+        statement:
+            "capability never moves down ModelOnly < LabBench < ControlledHuman < GeneralUse",
+        declared_in: "core_contract::SovereignNeuromorphContract::forbids_downgrade_or_rollback",
+        checked_on: CheckedOn::Stream,
+    },
+    InvariantSpec {
+        id: POWER_CHURCH_BOUND,
+        statement: "POWER <= k * CHURCH",
+        declared_in: "biorail_terrasafe::PowerChurchState::k_ratio",
+        checked_on: CheckedOn::Stream,
+    },
+    InvariantSpec {
+        id: SITE_ROH_CEILING,
+        statement: "BioEnvelope.roh <= 0.30",
+        declared_in: "biorail_terrasafe::BioEnvelope::roh",
+        checked_on: CheckedOn::Stream,
+    },
+    InvariantSpec {
+        id: ROLE_DIAGNOSTIC_ONLY,
+        statement: "DiagnosticFlags.role_diagnostic_only is true",
+        declared_in: "biorail_terrasafe::DiagnosticFlags::role_diagnostic_only",
+        checked_on: CheckedOn::Stream,
+    },
+    InvariantSpec {
+        id: JUSTICE_TIGHTENING_ONLY,
+        statement: "0 < tightening_factor <= 1",
+        declared_in: "biorail_terrasafe::JusticeCorridorConfig::tightening_factor",
+        checked_on: CheckedOn::StreamAndConfig,
+    },
+];
+
+/// Looks an invariant up by id.
+pub fn invariant(id: &str) -> Option<&'static InvariantSpec> {
+    INVARIANTS.iter().find(|spec| spec.id == id)
+}
+
+/// One observed (or configured) violation of a declared invariant.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct InvariantBreach {
+    pub invariant_id: String,
+    pub observed: String,
+    pub expected: String,
+    /// Epoch of the observation; `None` for static config findings.
+    pub epoch: Option<u64>,
+}
+
+impl fmt::Display for InvariantBreach {
+    /// Session-log line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.epoch {
+            Some(epoch) => write!(
+                f,
+                "invariant {} breached at epoch {epoch}",
+                self.invariant_id
+            )?,
+            None => write!(f, "invariant {} breached by config", self.invariant_id)?,
+        }
+        write!(
+            f,
+            ": observed {}, expected {}",
+            self.observed, self.expected
+        )
+    }
+}
+
+#[cfg(feature = "ledger")]
+impl InvariantBreach {
+    /// Ledger record of this breach, linked after `prev_hash`. The invariant
+    /// id doubles as the ethics flag so ledger metrics can count breaches.
+    pub fn to_deed_event(&self, prev_hash: String, actor_id: String) -> morphix_ledger::DeedEvent {
+        let mut context = std::collections::HashMap::new();
+        context.insert("observed".to_string(), serde_json::json!(self.observed));
+        context.insert("expected".to_string(), serde_json::json!(self.expected));
+        context.insert("epoch".to_string(), serde_json::json!(self.epoch));
+        morphix_ledger::DeedEvent::new(
+            prev_hash,
+            actor_id,
+            vec![],
+            "invariant_breach".to_string(),
+            vec!["watchdog".to_string()],
+            context,
+            vec![self.invariant_id.clone()],
+            false,
+        )
+    }
+}
+
+/// Position on the capability lattice.
+fn capability_rank(state: CapabilityState) -> u8 {
+    match state {
+        CapabilityState::ModelOnly => 0,
+        CapabilityState::LabBench => 1,
+        CapabilityState::ControlledHuman => 2,
+        CapabilityState::GeneralUse => 3,
+    }
+}
+
+/// A capability transition as seen by reversal evaluation.
+#[derive(Clone, Copy, Debug)]
+pub struct ReversalObservation {
+    pub cap_before: CapabilityState,
+    pub cap_after: CapabilityState,
+    pub roh_before: RoH,
+    pub roh_after: RoH,
+}
+
+/// Checks observed state against `INVARIANTS`.
+#[derive(Clone, Copy, Debug)]
+pub struct InvariantWatchdog {
+    /// Slack for floating-point comparisons.
+    pub tolerance: f64,
+}
+
+impl Default for InvariantWatchdog {
+    fn default() -> Self {
+        Self { tolerance: 1e-9 }
+    }
+}
+
+impl InvariantWatchdog {
+    fn breach(
+        id: &'static str,
+        observed: String,
+        expected: String,
+        epoch: Option<u64>,
+    ) -> InvariantBreach {
+        debug_assert!(invariant(id).is_some(), "{id} missing from INVARIANTS");
+        InvariantBreach {
+            invariant_id: id.to_string(),
+            observed,
+            expected,
+            epoch,
+        }
+    }
+
+    fn check_transition(
+        &self,
+        before: (CapabilityState, RoH),
+        after: (CapabilityState, RoH),
+        epoch: Option<u64>,
+        out: &mut Vec<InvariantBreach>,
+    ) {
+        if (after.1.value() as f64) < before.1.value() as f64 - self.tolerance {
+            out.push(Self::breach(
+                ROH_MONOTONE,
+                format!("RoH {:.3}", after.1.value()),
+                format!("RoH >= {:.3}", before.1.value()),
+                epoch,
+            ));
+        }
+        if capability_rank(after.0) < capability_rank(before.0) {
+            out.push(Self::breach(
+                CAPABILITY_NO_DOWNGRADE,
+                format!("{:?}", after.0),
+                format!("{:?} or above", before.0),
+                epoch,
+            ));
+        }
+    }
+
+    fn check_controlled_human_ceiling(
+        &self,
+        state: CapabilityState,
+        roh: RoH,
+        epoch: Option<u64>,
+        out: &mut Vec<InvariantBreach>,
+    ) {
+        if state == CapabilityState::ControlledHuman
+            && roh.value() as f64 > ROH_CEILING as f64 + self.tolerance
+        {
+            out.push(Self::breach(
+                ROH_CEILING_CONTROLLED_HUMAN,
+                format!("RoH {:.3}", roh.value()),
+                format!("RoH <= {ROH_CEILING:.2}"),
+                epoch,
+            ));
+        }
+    }
+
+    /// Checks one guard input against the previous one from the same stream.
+    /// The epoch is the input's `epoch_index`, or `fallback_epoch` without one.
+    pub fn check_guard(
+        &self,
+        previous: Option<&MorphixGuardInput>,
+        current: &MorphixGuardInput,
+        fallback_epoch: u64,
+    ) -> Vec<InvariantBreach> {
+        let epoch = Some(current.epoch_index.unwrap_or(fallback_epoch));
+        let mut out = Vec::new();
+        if let Some(previous) = previous {
+            self.check_transition(
+                (previous.capability_state, previous.roh),
+                (current.capability_state, current.roh),
+                epoch,
+                &mut out,
+            );
+        }
+        self.check_controlled_human_ceiling(current.capability_state, current.roh, epoch, &mut out);
+        out
+    }
+
+    /// Checks a whole guard stream, oldest first; inputs without an
+    /// `epoch_index` use their position.
+    pub fn scan_guard_stream(&self, stream: &[MorphixGuardInput]) -> Vec<InvariantBreach> {
+        stream
+            .iter()
+            .enumerate()
+            .flat_map(|(i, current)| {
+                let previous = i.checked_sub(1).map(|p| &stream[p]);
+                self.check_guard(previous, current, i as u64)
+            })
+            .collect()
+    }
+
+    /// Checks a capability transition that went through reversal evaluation.
+    pub fn check_reversal(
+        &self,
+        reversal: &ReversalObservation,
+        epoch: u64,
+    ) -> Vec<InvariantBreach> {
+        let mut out = Vec::new();
+        self.check_transition(
+            (reversal.cap_before, reversal.roh_before),
+            (reversal.cap_after, reversal.roh_after),
+            Some(epoch),
+            &mut out,
+        );
+        self.check_controlled_human_ceiling(
+            reversal.cap_after,
+            reversal.roh_after,
+            Some(epoch),
+            &mut out,
+        );
+        out
+    }
+
+    /// Checks one observed site view.
+    #[cfg(feature = "biorail")]
+    pub fn check_site(&self, site: &SiteView, epoch: u64) -> Vec<InvariantBreach> {
+        let epoch = Some(epoch);
+        let mut out = Vec::new();
+
+        let pc = &site.power_church;
+        let allowed = pc.k_ratio.max(0.0) * pc.church.max(0.0);
+        if pc.power > allowed + self.tolerance {
+            out.push(Self::breach(
+                POWER_CHURCH_BOUND,
+                format!("site {} POWER {:.3}", site.id, pc.power),
+                format!(
+                    "POWER <= {:.3} (k {:.3} * CHURCH {:.3})",
+                    allowed, pc.k_ratio, pc.church
+                ),
+                epoch,
+            ));
+        }
+        if site.bio_env.roh > ROH_CEILING as f64 + self.tolerance {
+            out.push(Self::breach(
+                SITE_ROH_CEILING,
+                format!("site {} RoH {:.3}", site.id, site.bio_env.roh),
+                format!("RoH <= {ROH_CEILING:.2}"),
+                epoch,
+            ));
+        }
+        if !site.diag.role_diagnostic_only {
+            out.push(Self::breach(
+                ROLE_DIAGNOSTIC_ONLY,
+                format!("site {} role_diagnostic_only false", site.id),
+                "role_diagnostic_only true".to_string(),
+                epoch,
+            ));
+        }
+        out.extend(self.justice_breach(&site.justice_cfg, epoch));
+        out
+    }
+
+    #[cfg(feature = "biorail")]
+    fn justice_breach(
+        &self,
+        cfg: &JusticeCorridorConfig,
+        epoch: Option<u64>,
+    ) -> Option<InvariantBreach> {
+        let factor = cfg.tightening_factor;
+        (!(factor > 0.0 && factor <= 1.0 + self.tolerance)).then(|| {
+            Self::breach(
+                JUSTICE_TIGHTENING_ONLY,
+                format!("tightening_factor {factor:.3}"),
+                "0 < tightening_factor <= 1".to_string(),
+                epoch,
+            )
+        })
+    }
+
+    /// Static check: the cooldown RoH alert must fire at or below the ceiling,
+    /// otherwise the advisory only appears after the invariant is breached.
+    pub fn verify_cooldown_config(&self, config: &CooldownConfig) -> Vec<InvariantBreach> {
+        let mut out = Vec::new();
+        if config.roh_alert as f64 > ROH_CEILING as f64 + self.tolerance {
+            out.push(Self::breach(
+                ROH_CEILING_CONTROLLED_HUMAN,
+                format!("cooldown roh_alert {:.3}", config.roh_alert),
+                format!("roh_alert <= {ROH_CEILING:.2}"),
+                None,
+            ));
+        }
+        out
+    }
+
+    /// Static check of a justice corridor configuration.
+    #[cfg(feature = "biorail")]
+    pub fn verify_justice_config(&self, config: &JusticeCorridorConfig) -> Vec<InvariantBreach> {
+        self.justice_breach(config, None).into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use morphix_guard::{BiophysicalEnvelopeSnapshot, MicroSocietyView, TreeOfLifeView};
+
+    fn guard_input(epoch: u64, roh: f32) -> MorphixGuardInput {
+        MorphixGuardInput {
+            capability_state: CapabilityState::ControlledHuman,
+            roh: RoH::new(roh),
+            envelope: BiophysicalEnvelopeSnapshot {
+                eeg_alpha_frac: Some(0.5),
+                eeg_gamma_frac: Some(0.5),
+                eda_tonic_frac: Some(0.5),
+                bpm_frac: Some(0.5),
+                cognitive_load_warn_frac: Some(0.0),
+                sleep_arousal_warn_frac: Some(0.0),
+                inflammation_warn_frac: Some(0.0),
+            },
+            tree_of_life: TreeOfLifeView {
+                blood: 0.5,
+                oxygen: 0.5,
+                wave: 0.5,
+                h2o: 0.5,
+                time: 0.5,
+                decay: 0.2,
+                lifeforce: 0.8,
+                brain: 0.5,
+                smart: 0.5,
+                evolve: 0.5,
+                power: 0.3,
+                tech: 0.5,
+                fear: 0.1,
+                pain: 0.1,
+                nano: 0.5,
+            },
+            micro_society: MicroSocietyView { predicates: vec![] },
+            evolve_index: None,
+            epoch_index: Some(epoch),
+        }
+    }
+
+    #[test]
+    fn test_roh_drops_yield_one_breach_per_violation_epoch() {
+        // RoH drops at epochs 12 and 15; the repeat at 13 is not a drop.
+        let roh = [0.10, 0.12, 0.08, 0.08, 0.20, 0.15, 0.18];
+        let stream: Vec<_> = roh
+            .iter()
+            .enumerate()
+            .map(|(i, r)| guard_input(10 + i as u64, *r))
+            .collect();
+        let before = format!("{stream:?}");
+
+        let breaches = InvariantWatchdog::default().scan_guard_stream(&stream);
+
+        assert_eq!(format!("{stream:?}"), before);
+        assert!(breaches.iter().all(|b| b.invariant_id == ROH_MONOTONE));
+        let epochs: Vec<_> = breaches.iter().map(|b| b.epoch).collect();
+        assert_eq!(epochs, vec![Some(12), Some(15)]);
+        assert_eq!(
+            breaches[0].to_string(),
+            "invariant roh.monotone breached at epoch 12: observed RoH 0.080, expected RoH >= 0.120"
+        );
+    }
+
+    #[test]
+    fn test_ceiling_downgrade_and_reversal_checks() {
+        let watchdog = InvariantWatchdog::default();
+        let mut over = guard_input(1, 0.35);
+        let breaches = watchdog.check_guard(Some(&guard_input(0, 0.2)), &over, 0);
+        assert_eq!(breaches.len(), 1);
+        assert_eq!(breaches[0].invariant_id, ROH_CEILING_CONTROLLED_HUMAN);
+
+        over.capability_state = CapabilityState::GeneralUse;
+        assert!(watchdog.check_guard(None, &over, 0).is_empty());
+
+        let reversal = ReversalObservation {
+            cap_before: CapabilityState::ControlledHuman,
+            cap_after: CapabilityState::LabBench,
+            roh_before: RoH::new(0.2),
+            roh_after: RoH::new(0.2),
+        };
+        let breaches = watchdog.check_reversal(&reversal, 7);
+        assert_eq!(breaches.len(), 1);
+        assert_eq!(breaches[0].invariant_id, CAPABILITY_NO_DOWNGRADE);
+        assert_eq!(breaches[0].epoch, Some(7));
+    }
+
+    #[test]
+    fn test_every_check_is_declared_in_the_table() {
+        let mut ids: Vec<_> = INVARIANTS.iter().map(|spec| spec.id).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), INVARIANTS.len());
+        assert!(invariant(ROH_MONOTONE).is_some());
+
+        let defaults =
+            InvariantWatchdog::default().verify_cooldown_config(&CooldownConfig::default());
+        assert!(defaults.is_empty());
+        let late_alert = CooldownConfig {
+            roh_alert: 0.4,
+            ..CooldownConfig::default()
+        };
+        let findings = InvariantWatchdog::default().verify_cooldown_config(&late_alert);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].epoch, None);
+        let spec = invariant(&findings[0].invariant_id).unwrap();
+        assert_ne!(spec.checked_on, CheckedOn::Stream);
+    }
+
+    #[cfg(feature = "biorail")]
+    #[test]
+    fn test_site_view_power_church_and_roh() {
+        use microsociety::biorail_terrasafe::{
+            BioEnvelope, BioLoadView, DiagnosticFlags, FiveDIdentity, JusticeMetrics,
+            PowerChurchState, RailScalar,
+        };
+
+        let site = SiteView {
+            id: 4,
+            bio_env: BioEnvelope {
+                roh: 0.1,
+                decay: 0.2,
+                lifeforce: 0.7,
+                lifeforce_min: 0.2,
+                lifeforce_max: 1.0,
+            },
+            identity_5d: FiveDIdentity {
+                biostate_load: 0.3,
+                neurostate_fear: 0.2,
+                lifeforce: 0.7,
+                context_load: 0.3,
+                sovereignty_trust: 0.9,
+                sovereignty_consent: true,
+            },
+            bioload_view: BioLoadView {
+                body: RailScalar::new_clamped(0.3),
+                room: RailScalar::new_clamped(0.3),
+                grid: RailScalar::new_clamped(0.3),
+                body_max: RailScalar::new_clamped(0.8),
+                room_max: RailScalar::new_clamped(0.8),
+                grid_max: RailScalar::new_clamped(0.8),
+            },
+            power_church: PowerChurchState {
+                power: 5.0,
+                church: 4.0,
+                k_ratio: 1.0,
+            },
+            justice_metrics: JusticeMetrics {
+                hpcc: 0.1,
+                erg: 0.1,
+                tecr: 0.1,
+            },
+            justice_cfg: JusticeCorridorConfig {
+                hpcc_max: 0.5,
+                erg_max: 0.5,
+                tecr_max: 0.5,
+                tightening_factor: 0.8,
+            },
+            diag: DiagnosticFlags {
+                beast_tag: false,
+                plague_tag: false,
+                unfair_drain: false,
+                role_diagnostic_only: true,
+            },
+        };
+
+        let breaches = InvariantWatchdog::default().check_site(&site, 3);
+        let ids: Vec<_> = breaches.iter().map(|b| b.invariant_id.as_str()).collect();
+        assert_eq!(ids, vec![POWER_CHURCH_BOUND]);
+
+        let loosening = JusticeCorridorConfig {
+            tightening_factor: 1.5,
+            ..site.justice_cfg.clone()
+        };
+        let findings = InvariantWatchdog::default().verify_justice_config(&loosening);
+        assert_eq!(findings[0].invariant_id, JUSTICE_TIGHTENING_ONLY);
+    }
+
+    #[cfg(feature = "ledger")]
+    #[test]
+    fn test_breach_becomes_a_ledger_event() {
+        let breach = InvariantWatchdog::default()
+            .scan_guard_stream(&[guard_input(0, 0.2), guard_input(1, 0.1)]);
+        let event = breach[0].to_deed_event("genesis".to_string(), "watchdog".to_string());
+        assert_eq!(event.deed_type, "invariant_breach");
+        assert_eq!(event.ethics_flags, vec![ROH_MONOTONE.to_string()]);
+        assert_eq!(event.context_json["epoch"], serde_json::json!(1));
+        assert!(event.validate("genesis"));
+    }
+}
@@ -0,0 +1,427 @@
+//! Pins the facade's public surface. Every path below must resolve, and the
+//! list must match `public_api.txt`; removing or renaming a re-export fails
+//! this test. Additions are deliberate: append them to both lists.
+
+#![cfg(all(
+    feature = "ledger",
+    feature = "guard",
+    feature = "biorail",
+    feature = "telemetry",
+    feature = "governance-file",
+    feature = "governance-signatures",
+    feature = "governance-events",
+    feature = "governance-async"
+))]
+
+macro_rules! blessed {
+    ($($root:ident $(::$seg:ident)+),* $(,)?) => {{
+        $( {
+            #[allow(unused_imports, deprecated)]
+            use $root $(::$seg)+;
+        } )*
+        vec![$(concat!(stringify!($root) $(, "::", stringify!($seg))+)),*]
+    }};
+}
+
+#[test]
+fn test_public_api_matches_checked_in_list() {
+    let resolved: Vec<&str> = blessed![
+        morphix::audit::LedgerAuditSink,
+        morphix::audit::to_deed_event,
+        morphix::backfill::BackfillOptions,
+        morphix::backfill::BackfillReport,
+        morphix::backfill::BackfillSource,
+        morphix::backfill::CONTEXT_BACKFILL_SOURCE_KEY,
+        morphix::backfill::ColumnMapping,
+        morphix::backfill::ConvertedDeed,
+        morphix::backfill::GuardBaseline,
+        morphix::backfill::OrderingRepair,
+        morphix::backfill::SkippedRow,
+        morphix::backfill::run,
+        morphix::biorail::AuditedGate,
+        morphix::biorail::BioEnvelope,
+        morphix::biorail::BioLoadView,
+        morphix::biorail::BioRailConfig,
+        morphix::biorail::BioRailTerrasafeGuard,
+        morphix::biorail::BioRailZone,
+        morphix::biorail::BioloadCeilings,
+        morphix::biorail::BiosignatureWeights,
+        morphix::biorail::ConstraintSlack,
+        morphix::biorail::ConstraintSlackReport,
+        morphix::biorail::ConstraintViolation,
+        morphix::biorail::DOWNSCALE_TOLERANCE,
+        morphix::biorail::DeedEffect,
+        morphix::biorail::DeedEffectProjector,
+        morphix::biorail::DeedProjection,
+        morphix::biorail::DiagnosticFlags,
+        morphix::biorail::EffectTable,
+        morphix::biorail::EthicsDecision,
+        morphix::biorail::EthicsOutcome,
+        morphix::biorail::EthicsRecord,
+        morphix::biorail::FiveDIdentity,
+        morphix::biorail::GateAuditSink,
+        morphix::biorail::GateCase,
+        morphix::biorail::GateConstraint,
+        morphix::biorail::GateReport,
+        morphix::biorail::GateVerdict,
+        morphix::biorail::GridAggregator,
+        morphix::biorail::GridAllocation,
+        morphix::biorail::GridCeilings,
+        morphix::biorail::JusticeCorridorConfig,
+        morphix::biorail::JusticeMetric,
+        morphix::biorail::JusticeMetrics,
+        morphix::biorail::JusticeTuningRecord,
+        morphix::biorail::PowerChurchAverage,
+        morphix::biorail::PowerChurchMode,
+        morphix::biorail::PowerChurchState,
+        morphix::biorail::PowerChurchWindow,
+        morphix::biorail::ProjectionError,
+        morphix::biorail::ProposedChange,
+        morphix::biorail::ProposedDeed,
+        morphix::biorail::RailScalar,
+        morphix::biorail::RepairConfig,
+        morphix::biorail::RepairController,
+        morphix::biorail::RepairPlan,
+        morphix::biorail::RepairStatus,
+        morphix::biorail::RepairTarget,
+        morphix::biorail::ScaledChange,
+        morphix::biorail::SequenceReport,
+        morphix::biorail::SiteView,
+        morphix::biorail::StressedMetric,
+        morphix::biorail::StressedMetrics,
+        morphix::biorail::TableProjector,
+        morphix::biorail::TrajectoryReport,
+        morphix::biorail::TrajectoryStep,
+        morphix::biorail::WeightViolation,
+        morphix::biorail::ZoneCorridors,
+        morphix::biorail::ZoneTag,
+        morphix::biorail::is_refusal,
+        morphix::canonical::CanonError,
+        morphix::canonical::canonical_json,
+        morphix::config::BundleFormat,
+        morphix::config::Config,
+        morphix::config::ConfigBundle,
+        morphix::config::ConfigVersion,
+        morphix::config::archive,
+        morphix::config::canonical_hash,
+        morphix::config::context_entry,
+        morphix::config::ensure_comparable,
+        morphix::contract::AccessClass,
+        morphix::contract::CapabilityState,
+        morphix::contract::CareAttestable,
+        morphix::contract::CareAttestation,
+        morphix::contract::CommunityId,
+        morphix::contract::CompositeContract,
+        morphix::contract::DataLabels,
+        morphix::contract::DefaultSovereignNeuromorphContract,
+        morphix::contract::DistilledKnowledge,
+        morphix::contract::RoH,
+        morphix::contract::RoHFloat,
+        morphix::contract::RoleTier,
+        morphix::contract::SCHEMA_VERSION,
+        morphix::contract::SncPredicate,
+        morphix::contract::SovereignContext,
+        morphix::contract::SovereignNeuromorphContract,
+        morphix::contract::SovereignRuntimeGuard,
+        morphix::contract::hex_stamp,
+        morphix::cooldown::ActivityClass,
+        morphix::cooldown::AppliedRule,
+        morphix::cooldown::CooldownConfig,
+        morphix::cooldown::CooldownInputs,
+        morphix::cooldown::CooldownPlan,
+        morphix::cooldown::LabelRule,
+        morphix::cooldown::SessionBudget,
+        morphix::cooldown::VerdictRule,
+        morphix::cooldown::plan,
+        morphix::cooldown::plan_with,
+        morphix::eco::ArtifactViolation,
+        morphix::eco::AsyncEcoDataSource,
+        morphix::eco::CACHED_SUFFIX,
+        morphix::eco::COMPOSITE_SEPARATOR,
+        morphix::eco::CacheKeying,
+        morphix::eco::CachedEcoSource,
+        morphix::eco::CompositeEcoSource,
+        morphix::eco::CorridorId,
+        morphix::eco::DEFAULT_MAX_SUMMARY_LEN,
+        morphix::eco::EcoDataSource,
+        morphix::eco::EcoDimension,
+        morphix::eco::EcoDriftAlert,
+        morphix::eco::EcoImpactMetrics,
+        morphix::eco::EcoImpactReport,
+        morphix::eco::EcoSourceError,
+        morphix::eco::EcoTrendConfig,
+        morphix::eco::EcoTrendObserver,
+        morphix::eco::EcoTrendSample,
+        morphix::eco::EcoTrendStore,
+        morphix::eco::EcoTrendTracker,
+        morphix::eco::FALLBACK_SEPARATOR,
+        morphix::eco::FallbackEcoSource,
+        morphix::eco::LegFailure,
+        morphix::eco::MetricExplanation,
+        morphix::eco::NeuromorphArtifact,
+        morphix::eco::ProvenanceInfo,
+        morphix::eco::RateLimitConfig,
+        morphix::eco::RateLimitStats,
+        morphix::eco::RateLimitedEcoSource,
+        morphix::eco::SyncEcoAdapter,
+        morphix::eco::is_transient,
+        morphix::fpic::ConsentLifetime,
+        morphix::fpic::DelegationLink,
+        morphix::fpic::DelegationTree,
+        morphix::fpic::FpicStatus,
+        morphix::fpic::FpicToken,
+        morphix::fpic::RevocationGroup,
+        morphix::fpic::RevocationImpact,
+        morphix::fpic::simulate_revocation,
+        morphix::fpic::simulate_revocation_with_sessions,
+        morphix::gate_audit::ETHICS_CONTRADICTION_FLAG,
+        morphix::gate_audit::GATE_REFUSAL_DEED_TYPE,
+        morphix::gate_audit::LedgerGateAuditSink,
+        morphix::gate_audit::to_deed_event,
+        morphix::governance::AsyncPolicySimulationBackend,
+        morphix::governance::CachedSimulationBackend,
+        morphix::governance::CommunityGovernanceBackend,
+        morphix::governance::CommunityVoteResult,
+        morphix::governance::DEFAULT_EVENT_CAPACITY,
+        morphix::governance::DEFAULT_PERCENTILES,
+        morphix::governance::DelegateFailure,
+        morphix::governance::DelegateSignature,
+        morphix::governance::Domination,
+        morphix::governance::Ed25519Verifier,
+        morphix::governance::FieldChange,
+        morphix::governance::FpicStatus,
+        morphix::governance::GovernanceEvent,
+        morphix::governance::GovernanceEventStream,
+        morphix::governance::GovernanceProposal,
+        morphix::governance::JsonFileGovernanceBackend,
+        morphix::governance::LifecycleError,
+        morphix::governance::LinearCoefficients,
+        morphix::governance::LinearPolicySimulator,
+        morphix::governance::OutcomeAxis,
+        morphix::governance::OutcomeGradient,
+        morphix::governance::OutcomeStats,
+        morphix::governance::PARETO_EPSILON,
+        morphix::governance::PolicyDiff,
+        morphix::governance::PolicyDirection,
+        morphix::governance::PolicyRanking,
+        morphix::governance::PolicySimulationBackend,
+        morphix::governance::ProposalLifecycle,
+        morphix::governance::ProposalOutcome,
+        morphix::governance::ProposalState,
+        morphix::governance::ProposalTransition,
+        morphix::governance::QuorumError,
+        morphix::governance::QuorumPolicy,
+        morphix::governance::RankedPolicy,
+        morphix::governance::SeededSimulator,
+        morphix::governance::SensitivitySweep,
+        morphix::governance::SignatureCheckedBackend,
+        morphix::governance::SignatureError,
+        morphix::governance::SignatureFailure,
+        morphix::governance::SignatureVerifier,
+        morphix::governance::SimulationEnsemble,
+        morphix::governance::SimulationHorizon,
+        morphix::governance::SimulationOutcome,
+        morphix::governance::SnapshotKey,
+        morphix::governance::SnapshotParam,
+        morphix::governance::SncPolicySnapshot,
+        morphix::governance::SyncSimulationAdapter,
+        morphix::governance::eco_to_global_indicator,
+        morphix::governance::payload_hash,
+        morphix::governance::rank_policies,
+        morphix::governance::rank_policies_with_epsilon,
+        morphix::governance::sensitivity_sweep,
+        morphix::governance::sync_token_from_governance,
+        morphix::governance::verify_quorum,
+        morphix::governance::verify_vote_signatures,
+        morphix::governance::vote_signing_payload,
+        morphix::governance_audit::FPIC_DECISION_DEED_TYPE,
+        morphix::governance_audit::LedgerBackedGovernanceAudit,
+        morphix::governance_audit::LostAuditEvent,
+        morphix::guard::BiophysicalEnvelopeSnapshot,
+        morphix::guard::CapabilityBreakdown,
+        morphix::guard::CapabilityChange,
+        morphix::guard::CapabilityState,
+        morphix::guard::ConfigViolation,
+        morphix::guard::DEFAULT_HYSTERESIS,
+        morphix::guard::DEFAULT_STREAM_CHUNK,
+        morphix::guard::Determinacy,
+        morphix::guard::EnvelopeAxes,
+        morphix::guard::EnvelopeAxis,
+        morphix::guard::Evidence,
+        morphix::guard::FairnessIndexWeights,
+        morphix::guard::FieldTrend,
+        morphix::guard::GuardConfigError,
+        morphix::guard::GuardDimension,
+        morphix::guard::GuardRule,
+        morphix::guard::GuardViewDiff,
+        morphix::guard::GuardViewStream,
+        morphix::guard::LabelProvenance,
+        morphix::guard::LabelStats,
+        morphix::guard::MAX_DIAGNOSTICS,
+        morphix::guard::MAX_EVIDENCE,
+        morphix::guard::MicroSocietyPredicate,
+        morphix::guard::MicroSocietyView,
+        morphix::guard::MorphixDiagnostic,
+        morphix::guard::MorphixGuard,
+        morphix::guard::MorphixGuardConfig,
+        morphix::guard::MorphixGuardInput,
+        morphix::guard::MorphixGuardReport,
+        morphix::guard::MorphixGuardRuleset,
+        morphix::guard::MorphixGuardSession,
+        morphix::guard::MorphixGuardTrendView,
+        morphix::guard::MorphixGuardView,
+        morphix::guard::MorphixLabel,
+        morphix::guard::MorphixTrendDiagnostic,
+        morphix::guard::MorphixTrendLabel,
+        morphix::guard::PartialThresholds,
+        morphix::guard::RoH,
+        morphix::guard::SeverityDelta,
+        morphix::guard::ThresholdSource,
+        morphix::guard::Thresholds,
+        morphix::guard::TreeOfLifeView,
+        morphix::guard::default_roh_ceiling,
+        morphix::guard::jsonl::EVOLVE_LINE_VERSION,
+        morphix::guard::jsonl::EvolveReader,
+        morphix::guard::jsonl::ParseError,
+        morphix::guard::jsonl::SkippedLine,
+        morphix::guard::jsonl::from_evolve_line,
+        morphix::guard::jsonl::to_evolve_line,
+        morphix::health::ComponentHealth,
+        morphix::health::EcoSourceProbe,
+        morphix::health::GovernanceProbe,
+        morphix::health::HealthCheckable,
+        morphix::health::HealthReport,
+        morphix::health::HealthStatus,
+        morphix::health::SimulatorProbe,
+        morphix::health::report,
+        morphix::ledger::Balance,
+        morphix::ledger::DeedEvent,
+        morphix::ledger::DpMetrics,
+        morphix::ledger::DpReport,
+        morphix::ledger::DpSeed,
+        morphix::ledger::HashCompat,
+        morphix::ledger::Ledger,
+        morphix::ledger::Metrics,
+        morphix::ledger::TreeBranch,
+        morphix::ledger::TreeOfLife,
+        morphix::ledger::TreeTrait,
+        morphix::ledger::dp_metrics,
+        morphix::orchestration::AccessBranch,
+        morphix::orchestration::AccessClassPolicy,
+        morphix::orchestration::AccessOutcome,
+        morphix::orchestration::AuditFuture,
+        morphix::orchestration::ConsensusRule,
+        morphix::orchestration::ConsentWatcher,
+        morphix::orchestration::DISTILLATION_DEED_TYPE,
+        morphix::orchestration::DistillFlags,
+        morphix::orchestration::DistillRequest,
+        morphix::orchestration::DistillationAuditSink,
+        morphix::orchestration::DistillationOutcome,
+        morphix::orchestration::DistillationQuota,
+        morphix::orchestration::DistillationRecord,
+        morphix::orchestration::DistillationTrace,
+        morphix::orchestration::GraduatedBand,
+        morphix::orchestration::InMemoryQuota,
+        morphix::orchestration::KnowledgeFactorBreakdown,
+        morphix::orchestration::KnowledgeFactorConfig,
+        morphix::orchestration::KnowledgeOverrides,
+        morphix::orchestration::KnowledgeWeights,
+        morphix::orchestration::NeuromorphOrchestrator,
+        morphix::orchestration::OPEN_MIN_ECO_IMPACT,
+        morphix::orchestration::OPEN_MIN_KNOWLEDGE_FACTOR,
+        morphix::orchestration::OrchestrationError,
+        morphix::orchestration::PURPOSE_PROHIBITED,
+        morphix::orchestration::PolicyChangeRequest,
+        morphix::orchestration::PolicyGateThresholds,
+        morphix::orchestration::QuotaRemaining,
+        morphix::orchestration::RedactedArtifact,
+        morphix::orchestration::Restriction,
+        morphix::orchestration::STALE_SUFFIX,
+        morphix::orchestration::SimulationCheck,
+        morphix::orchestration::SimulationGate,
+        morphix::orchestration::ThresholdAccessPolicy,
+        morphix::orchestration::apply_restrictions,
+        morphix::orchestration::corridor_has_pending_governance,
+        morphix::orchestration::distill_neuromorph_content_from_components,
+        morphix::orchestration::validate_policy_change,
+        morphix::orchestration::validate_policy_change_async,
+        morphix::orchestration::validate_policy_change_with_ensemble,
+        morphix::prelude::AccessClass,
+        morphix::prelude::BioRailTerrasafeGuard,
+        morphix::prelude::CommunityGovernanceBackend,
+        morphix::prelude::CorridorId,
+        morphix::prelude::DeedEvent,
+        morphix::prelude::DefaultSovereignNeuromorphContract,
+        morphix::prelude::DistilledKnowledge,
+        morphix::prelude::EcoDataSource,
+        morphix::prelude::EcoImpactMetrics,
+        morphix::prelude::GateVerdict,
+        morphix::prelude::HealthCheckable,
+        morphix::prelude::Ledger,
+        morphix::prelude::MorphixGuard,
+        morphix::prelude::MorphixGuardConfig,
+        morphix::prelude::MorphixLabel,
+        morphix::prelude::NeuromorphArtifact,
+        morphix::prelude::NeuromorphOrchestrator,
+        morphix::prelude::PolicySimulationBackend,
+        morphix::prelude::RoleTier,
+        morphix::prelude::SovereignNeuromorphContract,
+        morphix::prelude::TreeOfLife,
+        morphix::reports::CONTEXT_ACCESS_CLASS_KEY,
+        morphix::reports::CONTEXT_CORRIDOR_KEY,
+        morphix::reports::CorridorReport,
+        morphix::reports::DISTILLATION_DEED_TYPE,
+        morphix::reports::DistillationActivity,
+        morphix::reports::EcoTrendSummary,
+        morphix::reports::GovernanceActivity,
+        morphix::reports::GuardActivity,
+        morphix::reports::JsonFileEcoTrendStore,
+        morphix::reports::ReportSources,
+        morphix::reports::TaggedGuardView,
+        morphix::reports::TimeRange,
+        morphix::reports::corridor_report,
+        morphix::telemetry::Counter,
+        morphix::telemetry::DailyAggregate,
+        morphix::telemetry::Histogram,
+        morphix::telemetry::HistogramCounts,
+        morphix::telemetry::LATENCY_BOUNDS_MS,
+        morphix::telemetry::LocalAggregator,
+        morphix::telemetry::TelemetryConfig,
+        morphix::telemetry::TimedEcoSource,
+        morphix::telemetry::load_aggregates,
+        morphix::telemetry::purge,
+        morphix::watchdog::CAPABILITY_NO_DOWNGRADE,
+        morphix::watchdog::CheckedOn,
+        morphix::watchdog::INVARIANTS,
+        morphix::watchdog::InvariantBreach,
+        morphix::watchdog::InvariantSpec,
+        morphix::watchdog::InvariantWatchdog,
+        morphix::watchdog::JUSTICE_TIGHTENING_ONLY,
+        morphix::watchdog::POWER_CHURCH_BOUND,
+        morphix::watchdog::ROH_CEILING,
+        morphix::watchdog::ROH_CEILING_CONTROLLED_HUMAN,
+        morphix::watchdog::ROH_MONOTONE,
+        morphix::watchdog::ROLE_DIAGNOSTIC_ONLY,
+        morphix::watchdog::ReversalObservation,
+        morphix::watchdog::SITE_ROH_CEILING,
+        morphix::watchdog::invariant,
+    ];
+
+    let pinned: Vec<&str> = include_str!("public_api.txt").lines().collect();
+    assert_eq!(
+        resolved, pinned,
+        "facade surface changed; update tests/public_api.txt deliberately"
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_paths_still_resolve() {
+    use morphix::biorail_terrasafe::GateVerdict;
+    use morphix::morphix_guard::MorphixGuardConfig;
+    use morphix::tree_of_life::TreeOfLife;
+
+    let _ = GateVerdict::Allow;
+    let _ = MorphixGuardConfig::default();
+    let _: Option<TreeOfLife> = None;
+}
@@ -0,0 +1,123 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+use core_contract::config_version::ConfigVersion;
+use core_contract::labels::DataLabels;
+use core_contract::{AccessClass, DistilledKnowledge, RoleTier};
+
+use crate::graduated::Restriction;
+use crate::OrchestrationError;
+
+/// Deed type under which sinks should file distillation records.
+pub const DISTILLATION_DEED_TYPE: &str = "neuromorph_distillation";
+
+/// Future returned by `DistillationAuditSink::record`.
+pub type AuditFuture<'a> = Pin<Box<dyn Future<Output = Result<(), String>> + Send + 'a>>;
+
+/// What became of one distillation request.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DistillationOutcome {
+    Distilled {
+        access_class: AccessClass,
+        knowledge_factor: f32,
+        hex_stamp: String,
+        /// Restrictions of a graduated grant; empty for plain grants.
+        restrictions: Vec<Restriction>,
+    },
+    Refused {
+        /// `OrchestrationError::code`.
+        code: &'static str,
+        reason: String,
+    },
+}
+
+/// One distillation, successful or refused, as handed to the audit sink.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DistillationRecord {
+    pub artifact_id: String,
+    pub corridor_id: String,
+    pub role: RoleTier,
+    pub purpose: String,
+    pub data_labels: DataLabels,
+    pub outcome: DistillationOutcome,
+    /// Version of the config bundle the orchestrator was built from.
+    pub config_version: Option<ConfigVersion>,
+}
+
+impl DistillationRecord {
+    pub(crate) fn new(
+        artifact: &core_contract::eco::NeuromorphArtifact,
+        role: RoleTier,
+        purpose: &str,
+        result: Result<&DistilledKnowledge, &OrchestrationError>,
+        restrictions: &[Restriction],
+        config_version: Option<&ConfigVersion>,
+    ) -> Self {
+        let outcome = match result {
+            Ok(dk) => DistillationOutcome::Distilled {
+                access_class: dk.access_class,
+                knowledge_factor: dk.knowledge_factor,
+                hex_stamp: dk.hex_stamp.clone(),
+                restrictions: restrictions.to_vec(),
+            },
+            Err(e) => DistillationOutcome::Refused {
+                code: e.code(),
+                reason: e.to_string(),
+            },
+        };
+        Self {
+            artifact_id: artifact.id.clone(),
+            corridor_id: artifact.corridor_id.0.clone(),
+            role,
+            purpose: purpose.to_string(),
+            data_labels: artifact.data_labels.clone(),
+            outcome,
+            config_version: config_version.cloned(),
+        }
+    }
+}
+
+/// Receives a record of every distillation, e.g. to append it to the deed
+/// ledger. Recording never changes the distillation result: a failed
+/// `record` is passed to `on_record_failed` and otherwise ignored.
+pub trait DistillationAuditSink: Send + Sync {
+    fn record<'a>(&'a self, record: &'a DistillationRecord) -> AuditFuture<'a>;
+
+    /// Called when `record` fails.
+    fn on_record_failed(&self, _record: &DistillationRecord, _error: &str) {}
+}
+
+/// Records `record` on `sink`, reporting rather than propagating failure.
+pub(crate) async fn record_async(sink: &dyn DistillationAuditSink, record: DistillationRecord) {
+    if let Err(e) = sink.record(&record).await {
+        sink.on_record_failed(&record, &e);
+    }
+}
+
+/// Sync distillation paths drive the sink's future on the calling thread.
+pub(crate) fn record_blocking(sink: &dyn DistillationAuditSink, record: DistillationRecord) {
+    block_on(record_async(sink, record));
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = std::pin::pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
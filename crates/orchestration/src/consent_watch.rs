@@ -0,0 +1,114 @@
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use governance_local::{CommunityId, GovernanceEvent};
+use tokio::sync::broadcast::{error::RecvError, Receiver};
+
+/// Raises a shared "consent dirty" flag when any of a fixed set of
+/// communities changes FPIC status, so long-running services know to
+/// re-validate instead of polling. Clones share the flag.
+#[derive(Clone, Debug)]
+pub struct ConsentWatcher {
+    communities: Arc<HashSet<CommunityId>>,
+    dirty: Arc<AtomicBool>,
+}
+
+impl ConsentWatcher {
+    pub fn new(affected: impl IntoIterator<Item = CommunityId>) -> Self {
+        Self {
+            communities: Arc::new(affected.into_iter().collect()),
+            dirty: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// The flag itself, for callers that check it without the watcher.
+    pub fn dirty_flag(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.dirty)
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.dirty.load(Ordering::Acquire)
+    }
+
+    /// Lower the flag after re-validating; returns whether it was raised.
+    pub fn clear(&self) -> bool {
+        self.dirty.swap(false, Ordering::AcqRel)
+    }
+
+    /// Raise the flag if `event` concerns a watched community; returns
+    /// whether it did.
+    pub fn observe(&self, event: &GovernanceEvent) -> bool {
+        let affected = self.communities.contains(&event.community_id);
+        if affected {
+            self.dirty.store(true, Ordering::Release);
+        }
+        affected
+    }
+
+    /// Observe `events` until the stream closes. Falling behind raises the
+    /// flag, since a missed event may have been a withdrawal.
+    pub async fn watch(self, mut events: Receiver<GovernanceEvent>) {
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    self.observe(&event);
+                }
+                Err(RecvError::Lagged(_)) => self.dirty.store(true, Ordering::Release),
+                Err(RecvError::Closed) => return,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use governance_local::{FpicStatus, GovernanceEventStream};
+    use std::time::SystemTime;
+
+    fn event(community: &str) -> GovernanceEvent {
+        GovernanceEvent {
+            proposal_id: "p-1".into(),
+            community_id: CommunityId(community.into()),
+            new_status: FpicStatus::Withheld {
+                timestamp: SystemTime::UNIX_EPOCH,
+                reason: "withdrawn".into(),
+            },
+            at: SystemTime::UNIX_EPOCH,
+        }
+    }
+
+    #[tokio::test]
+    async fn only_watched_communities_raise_the_flag() {
+        let watcher = ConsentWatcher::new([CommunityId("water-shed".into())]);
+        let stream = GovernanceEventStream::default();
+        let events = stream.subscribe();
+        stream.publish(event("south-phoenix-air"));
+        drop(stream);
+        watcher.clone().watch(events).await;
+        assert!(!watcher.is_dirty());
+
+        let stream = GovernanceEventStream::default();
+        let events = stream.subscribe();
+        stream.publish(event("south-phoenix-air"));
+        stream.publish(event("water-shed"));
+        drop(stream);
+        watcher.clone().watch(events).await;
+        assert!(watcher.dirty_flag().load(Ordering::Acquire));
+        assert!(watcher.clear());
+        assert!(!watcher.is_dirty());
+    }
+
+    #[tokio::test]
+    async fn lagging_raises_the_flag() {
+        let watcher = ConsentWatcher::new([CommunityId("water-shed".into())]);
+        let stream = GovernanceEventStream::new(1);
+        let events = stream.subscribe();
+        stream.publish(event("south-phoenix-air"));
+        stream.publish(event("south-phoenix-air"));
+        drop(stream);
+        watcher.clone().watch(events).await;
+        assert!(watcher.is_dirty());
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt;
+use std::time::Duration;
+
+use core_contract::eco::ArtifactViolation;
+use core_contract::eco_source::EcoSourceError;
+use core_contract::fpic::FpicStatus;
+use core_contract::SncPredicate;
+
+use crate::PURPOSE_PROHIBITED;
+
+/// Why a distillation was refused. `Display` keeps the wording the
+/// orchestrator has always logged.
+#[derive(Clone, Debug, PartialEq)]
+pub enum OrchestrationError {
+    /// The artifact failed `NeuromorphArtifact::validate`; checked before
+    /// anything else.
+    InvalidArtifact(Vec<ArtifactViolation>),
+    /// SNC: the subject has not given explicit consent.
+    MissingConsent,
+    /// SNC: no sovereign abort control is available.
+    NoAbortControl,
+    /// SNC: discipline is not personalized and non-coercive.
+    CoerciveDiscipline,
+    /// SNC: the contract permits downgrades or rollbacks.
+    DowngradeForbidden,
+    /// SNC: `member` of a composite contract fails `predicate`. Carries the
+    /// code of the corresponding single-contract refusal.
+    ContractMemberRefused { member: String, predicate: SncPredicate },
+    /// FPIC: the token's consent lifetime has lapsed.
+    FpicExpired,
+    /// FPIC: consent was denied or vetoed.
+    FpicWithdrawn(FpicStatus),
+    /// CARE: the attestation is missing one or more principles.
+    CareMisaligned,
+    /// The requested purpose conflicts with the artifact's data labels.
+    PurposeProhibited { purpose: String, artifact_id: String },
+    /// CHAT: no dual empirical + formal linkage.
+    ChatMissingDualLinkage,
+    /// CHAT: uncertainty was not exposed.
+    ChatUncertaintyNotExposed,
+    /// The eco source could not score the artifact. Never `StaleData`,
+    /// which is scored in a degraded mode instead.
+    EcoSourceFailure(EcoSourceError),
+    /// The policy simulator failed; Open is never granted without it.
+    SimulationFailure(String),
+    /// The actor's distillation quota is spent; retry after `retry_after`.
+    QuotaExceeded { retry_after: Duration },
+    /// A quota is attached but the call names no actor to charge.
+    QuotaActorMissing,
+    /// F_K could not be computed (non-finite component or invalid config).
+    InvalidKnowledgeFactor(String),
+}
+
+impl OrchestrationError {
+    /// Stable machine-readable refusal code; never empty.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidArtifact(_) => "InvalidArtifact",
+            Self::MissingConsent => "MissingConsent",
+            Self::NoAbortControl => "NoAbortControl",
+            Self::CoerciveDiscipline => "CoerciveDiscipline",
+            Self::DowngradeForbidden => "DowngradeForbidden",
+            Self::ContractMemberRefused { predicate, .. } => Self::from(*predicate).code(),
+            Self::FpicExpired => "FpicExpired",
+            Self::FpicWithdrawn(_) => "FpicWithdrawn",
+            Self::CareMisaligned => "CareMisaligned",
+            Self::PurposeProhibited { .. } => PURPOSE_PROHIBITED,
+            Self::ChatMissingDualLinkage => "ChatMissingDualLinkage",
+            Self::ChatUncertaintyNotExposed => "ChatUncertaintyNotExposed",
+            Self::EcoSourceFailure(_) => "EcoSourceFailure",
+            Self::SimulationFailure(_) => "SimulationFailure",
+            Self::QuotaExceeded { .. } => "QuotaExceeded",
+            Self::QuotaActorMissing => "QuotaActorMissing",
+            Self::InvalidKnowledgeFactor(_) => "InvalidKnowledgeFactor",
+        }
+    }
+}
+
+impl fmt::Display for OrchestrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidArtifact(violations) => {
+                f.write_str("Invalid artifact:")?;
+                for (i, v) in violations.iter().enumerate() {
+                    write!(f, "{} {v}", if i == 0 { "" } else { ";" })?;
+                }
+                f.write_str(".")
+            }
+            Self::MissingConsent => f.write_str("SNC violation: explicit consent required."),
+            Self::NoAbortControl => f.write_str("SNC violation: sovereign abort control is mandatory."),
+            Self::CoerciveDiscipline => {
+                f.write_str("SNC violation: discipline must be personalized and non-coercive.")
+            }
+            Self::DowngradeForbidden => f.write_str("SNC violation: downgrades/rollbacks are forbidden."),
+            Self::ContractMemberRefused { member, predicate } => {
+                let refusal = Self::from(*predicate).to_string();
+                let refusal = refusal.strip_suffix('.').unwrap_or(&refusal);
+                write!(f, "{refusal} (contract member '{member}').")
+            }
+            Self::FpicExpired => f.write_str("FPIC stale: consent lifetime has lapsed."),
+            Self::FpicWithdrawn(status) => write!(f, "FPIC veto: consent is {status:?}."),
+            Self::CareMisaligned => f.write_str("CARE alignment missing or incomplete."),
+            Self::PurposeProhibited { purpose, artifact_id } => write!(
+                f,
+                "{PURPOSE_PROHIBITED}: purpose '{purpose}' conflicts with the data labels of artifact {artifact_id}."
+            ),
+            Self::ChatMissingDualLinkage => {
+                f.write_str("CHAT-ineligible: missing dual empirical + formal linkage.")
+            }
+            Self::ChatUncertaintyNotExposed => f.write_str("CHAT-ineligible: uncertainty must be exposed."),
+            Self::EcoSourceFailure(e) => write!(f, "EcoImpact error: {e}"),
+            Self::SimulationFailure(e) => write!(f, "Policy simulation error: {e}"),
+            Self::QuotaExceeded { retry_after } => write!(
+                f,
+                "Distillation quota exceeded: retry after {}s.",
+                retry_after.as_secs_f32().ceil()
+            ),
+            Self::QuotaActorMissing => {
+                f.write_str("Distillation quota is metered per actor: an actor id is required.")
+            }
+            Self::InvalidKnowledgeFactor(e) => f.write_str(e),
+        }
+    }
+}
+
+impl From<SncPredicate> for OrchestrationError {
+    /// The refusal for a single contract failing `predicate`.
+    fn from(predicate: SncPredicate) -> Self {
+        match predicate {
+            SncPredicate::ExplicitConsent => Self::MissingConsent,
+            SncPredicate::SovereignAbortControl => Self::NoAbortControl,
+            SncPredicate::NonCoerciveDiscipline => Self::CoerciveDiscipline,
+            SncPredicate::ForbidsDowngradeOrRollback => Self::DowngradeForbidden,
+        }
+    }
+}
+
+impl std::error::Error for OrchestrationError {}
@@ -1,46 +1,1009 @@
-use crate::NeuromorphOrchestrator;
-use governance_local::{CommunityGovernanceBackend, CommunityId, FpicStatus};
-use governance_sim::{PolicySimulationBackend, SncPolicySnapshot};
+use std::collections::HashMap;
+use std::time::SystemTime;
+
+use core_contract::eco::CorridorId;
+use governance_local::{
+    utc_date, verify_quorum, CommunityGovernanceBackend, CommunityId, FpicStatus, ProposalState,
+    QuorumPolicy,
+};
+#[cfg(feature = "async")]
+use governance_sim::AsyncPolicySimulationBackend;
+use governance_sim::{
+    PolicySimulationBackend, SimulationHorizon, SimulationOutcome, SncPolicySnapshot,
+};
+
+/// Simulation bounds a policy change must meet, negotiated per corridor.
+/// Fields missing from a serialized config take their default.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct PolicyGateThresholds {
+    /// Simulated neurorights risk above this blocks the change.
+    pub max_neurorights_risk: f32,
+    /// Simulated environmental justice below this blocks the change.
+    pub min_environmental_justice: f32,
+    /// Simulated trust index below this blocks the change.
+    pub min_trust_index: f32,
+}
+
+impl Default for PolicyGateThresholds {
+    /// 0.3 risk ceiling and 0.6 justice floor; the trust floor is 0.0 so
+    /// existing policies keep passing until a corridor negotiates one.
+    fn default() -> Self {
+        Self {
+            max_neurorights_risk: 0.3,
+            min_environmental_justice: 0.6,
+            min_trust_index: 0.0,
+        }
+    }
+}
+
+impl PolicyGateThresholds {
+    /// Check one simulated outcome, or an ensemble's means, against every
+    /// bound.
+    pub fn check(&self, outcome: &SimulationOutcome) -> Result<(), String> {
+        if outcome.expected_neurorights_risk > self.max_neurorights_risk {
+            return Err("Policy blocked: neurorights risk too high in simulation.".into());
+        }
+        if outcome.environmental_justice_score < self.min_environmental_justice {
+            return Err("Policy blocked: environmental justice score too low.".into());
+        }
+        if outcome.trust_index < self.min_trust_index {
+            return Err("Policy blocked: trust index too low in simulation.".into());
+        }
+        Ok(())
+    }
+}
+
+/// Whether any proposal touching `corridor_id` is still Draft or
+/// OpenForFpic. A proposal whose state the backend cannot report counts as
+/// pending.
+pub fn corridor_has_pending_governance<G>(
+    governance: &G,
+    corridor_id: &CorridorId,
+) -> Result<bool, String>
+where
+    G: CommunityGovernanceBackend + ?Sized,
+{
+    let proposals = governance.list_proposals_for_corridor(&corridor_id.0)?;
+    Ok(proposals.iter().any(|proposal| {
+        !matches!(
+            governance.get_proposal_state(&proposal.id),
+            Ok(ProposalState::Closed { .. } | ProposalState::Withdrawn)
+        )
+    }))
+}
+
+/// A proposed SNC / CHAT policy change and how it is judged; shared by
+/// the sync, async and ensemble gates. `new` fills in the defaults:
+/// default thresholds, a point-in-time simulation, no quorums, unanimous
+/// consent, judged now.
+#[derive(Clone, Debug)]
+pub struct PolicyChangeRequest<'a> {
+    pub proposal_id: &'a str,
+    pub affected_communities: &'a [CommunityId],
+    pub snapshot: &'a SncPolicySnapshot,
+    pub thresholds: PolicyGateThresholds,
+    /// Simulate over this horizon instead of at a point in time.
+    pub horizon: Option<SimulationHorizon>,
+    /// A community listed here only counts as Granted once its
+    /// `QuorumPolicy` is met; communities without an entry accept any
+    /// Granted status.
+    pub quorums: HashMap<CommunityId, QuorumPolicy>,
+    pub rule: ConsensusRule,
+    /// A grant whose `valid_until` is at or before `now` counts as Pending.
+    pub now: SystemTime,
+}
+
+impl<'a> PolicyChangeRequest<'a> {
+    pub fn new(
+        proposal_id: &'a str,
+        affected_communities: &'a [CommunityId],
+        snapshot: &'a SncPolicySnapshot,
+    ) -> Self {
+        Self {
+            proposal_id,
+            affected_communities,
+            snapshot,
+            thresholds: PolicyGateThresholds::default(),
+            horizon: None,
+            quorums: HashMap::new(),
+            rule: ConsensusRule::Unanimous,
+            now: SystemTime::now(),
+        }
+    }
+}
 
 /// Guard a proposed SNC / CHAT policy change behind FPIC + global simulation.[web:145][web:146]
+///
+/// The affected communities must consent under `request.rule`, then the
+/// simulated outcome must meet `request.thresholds`. With a horizon, the
+/// simulator is asked for outcomes over it through `evaluate_policy_over`.
+/// A simulator that does not support horizons fails that call, and the
+/// change is blocked with its error: the gate fails closed rather than
+/// judging a long-run policy on a point-in-time outcome.
 pub fn validate_policy_change<G, S>(
     governance: &G,
     simulator: &S,
-    proposal_id: &str,
-    affected_communities: &[CommunityId],
-    snapshot: &SncPolicySnapshot,
+    request: &PolicyChangeRequest<'_>,
 ) -> Result<(), String>
 where
     G: CommunityGovernanceBackend,
     S: PolicySimulationBackend,
 {
-    // 1. FPIC: every affected community must have Granted status.[web:145][web:143]
-    for community in affected_communities {
-        match governance.get_fpic_status(proposal_id, community)? {
-            FpicStatus::Granted { .. } => {}
-            FpicStatus::Pending => {
-                return Err(format!(
-                    "Policy blocked: FPIC still pending for community {:?}.",
-                    community.0
+    check_consent(governance, request)?;
+
+    // 2. Osireon‑style simulation: reject clearly unsafe futures.[web:136][web:149][web:146]
+    let outcome = match request.horizon {
+        Some(horizon) => simulator.evaluate_policy_over(request.snapshot, horizon)?,
+        None => simulator.evaluate_policy(request.snapshot)?,
+    };
+    request.thresholds.check(&outcome)
+}
+
+/// How the affected communities' FPIC statuses combine into consent. A
+/// community "grants" when its status is Granted, unexpired and meets its
+/// quorum, as set on `PolicyChangeRequest::quorums`.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConsensusRule {
+    /// Every affected community must grant.
+    #[default]
+    Unanimous,
+    /// Granting communities must hold at least `threshold` (0.0..=1.0) of
+    /// the affected communities' total weight. Communities absent from
+    /// `weights` weigh 1.0.
+    WeightedThreshold {
+        weights: HashMap<CommunityId, f32>,
+        threshold: f32,
+    },
+    /// At least `threshold` of the affected communities, equally weighted,
+    /// must grant, and Withheld from any `veto` community blocks outright.
+    VetoClasses {
+        veto: Vec<CommunityId>,
+        threshold: f32,
+    },
+}
+
+impl ConsensusRule {
+    /// Reject thresholds outside 0.0..=1.0 and weights that are negative or
+    /// non-finite. NaN would otherwise compare false against every bound
+    /// and let the weighted check pass.
+    pub fn validate(&self) -> Result<(), String> {
+        let threshold = match self {
+            Self::Unanimous => return Ok(()),
+            Self::WeightedThreshold { weights, threshold } => {
+                for (community, weight) in weights {
+                    if !weight.is_finite() || *weight < 0.0 {
+                        return Err(format!(
+                            "Policy blocked: consensus weight {weight} for community {:?} must be finite and non-negative.",
+                            community.0
+                        ));
+                    }
+                }
+                threshold
+            }
+            Self::VetoClasses { threshold, .. } => threshold,
+        };
+        if !(0.0..=1.0).contains(threshold) {
+            return Err(format!(
+                "Policy blocked: consensus threshold {threshold} must lie in 0.0..=1.0."
+            ));
+        }
+        Ok(())
+    }
+
+    fn weight(&self, community: &CommunityId) -> f32 {
+        match self {
+            Self::WeightedThreshold { weights, .. } => {
+                weights.get(community).copied().unwrap_or(1.0)
+            }
+            _ => 1.0,
+        }
+    }
+}
+
+/// Why one community's FPIC does not count as granted.
+struct NotGranted {
+    /// The withholding reason, if the community withheld.
+    withheld: Option<String>,
+    message: String,
+}
+
+fn community_consent<G: CommunityGovernanceBackend>(
+    governance: &G,
+    proposal_id: &str,
+    community: &CommunityId,
+    quorums: &HashMap<CommunityId, QuorumPolicy>,
+    now: SystemTime,
+) -> Result<Option<NotGranted>, String> {
+    let not_granted = |message| {
+        Ok(Some(NotGranted {
+            withheld: None,
+            message,
+        }))
+    };
+    match governance.get_fpic_status(proposal_id, community)? {
+        status @ FpicStatus::Granted { .. } => {
+            if let Some(until) = status.expired_at(now) {
+                return not_granted(format!(
+                    "Policy blocked: FPIC for community {:?} expired on {}; re-consent is pending.",
+                    community.0,
+                    utc_date(until)
                 ));
             }
-            FpicStatus::Withheld { reason, .. } => {
+            if let Some(policy) = quorums.get(community) {
+                if let Err(e) = verify_quorum(&status, policy) {
+                    return not_granted(format!(
+                        "Policy blocked: FPIC quorum not met for community {:?}: {e}.",
+                        community.0
+                    ));
+                }
+            }
+            Ok(None)
+        }
+        FpicStatus::Pending => not_granted(format!(
+            "Policy blocked: FPIC still pending for community {:?}.",
+            community.0
+        )),
+        FpicStatus::Withheld { reason, .. } => Ok(Some(NotGranted {
+            message: format!(
+                "Policy blocked: FPIC withheld by community {:?}: {}",
+                community.0, reason
+            ),
+            withheld: Some(reason),
+        })),
+    }
+}
+
+/// `validate_policy_change` for async simulators. The simulation does not
+/// depend on consent, so it runs alongside the FPIC lookups; errors are
+/// reported exactly as the sync path reports them, FPIC first.
+#[cfg(feature = "async")]
+pub async fn validate_policy_change_async<G, S>(
+    governance: &G,
+    simulator: &S,
+    request: &PolicyChangeRequest<'_>,
+) -> Result<(), String>
+where
+    G: CommunityGovernanceBackend,
+    S: AsyncPolicySimulationBackend,
+{
+    // The simulator future is polled first so a remote request is in
+    // flight while the (sync) FPIC lookups run.
+    let simulation = async {
+        match request.horizon {
+            Some(horizon) => simulator.evaluate_policy_over(request.snapshot, horizon).await,
+            None => simulator.evaluate_policy(request.snapshot).await,
+        }
+    };
+    let (outcome, consent) = tokio::join!(simulation, async { check_consent(governance, request) });
+    consent?;
+    request.thresholds.check(&outcome?)
+}
+
+/// `validate_policy_change` judged on `n_runs` simulator runs: the means
+/// must meet `thresholds`, and the 95th-percentile neurorights risk must
+/// also stay within its bound. Ensembles are point-in-time, so a request
+/// with a horizon is blocked rather than judged without it.
+pub fn validate_policy_change_with_ensemble<G, S>(
+    governance: &G,
+    simulator: &S,
+    request: &PolicyChangeRequest<'_>,
+    n_runs: usize,
+) -> Result<(), String>
+where
+    G: CommunityGovernanceBackend,
+    S: PolicySimulationBackend,
+{
+    check_consent(governance, request)?;
+    if let Some(horizon) = request.horizon {
+        return Err(format!(
+            "ensemble simulation does not support a {}-year horizon",
+            horizon.years
+        ));
+    }
+
+    let thresholds = &request.thresholds;
+    let ensemble =
+        simulator.evaluate_policy_ensemble_with_percentiles(request.snapshot, n_runs, &[95.0])?;
+    let risk = &ensemble.expected_neurorights_risk;
+    thresholds.check(&SimulationOutcome {
+        expected_neurorights_risk: risk.mean,
+        environmental_justice_score: ensemble.environmental_justice_score.mean,
+        trust_index: ensemble.trust_index.mean,
+    })?;
+    let p95 = risk.percentile(95.0).unwrap_or(risk.mean);
+    if p95 > thresholds.max_neurorights_risk {
+        return Err(format!(
+            "Policy blocked: 95th-percentile neurorights risk {p95:.3} exceeds {} across {} simulation runs.",
+            thresholds.max_neurorights_risk, ensemble.runs
+        ));
+    }
+    Ok(())
+}
+
+fn check_consent<G: CommunityGovernanceBackend>(
+    governance: &G,
+    request: &PolicyChangeRequest<'_>,
+) -> Result<(), String> {
+    let PolicyChangeRequest {
+        proposal_id,
+        affected_communities,
+        ref quorums,
+        ref rule,
+        now,
+        ..
+    } = *request;
+    // 1. FPIC: affected communities must consent under `rule`.[web:145][web:143]
+    rule.validate()?;
+    let mut total_weight = 0.0;
+    let mut granted_weight = 0.0;
+    let mut not_granted = Vec::new();
+    for community in affected_communities {
+        let weight = rule.weight(community);
+        total_weight += weight;
+        let Some(refusal) = community_consent(governance, proposal_id, community, quorums, now)?
+        else {
+            granted_weight += weight;
+            continue;
+        };
+        match (rule, &refusal.withheld) {
+            (ConsensusRule::Unanimous, _) => return Err(refusal.message),
+            (ConsensusRule::VetoClasses { veto, .. }, Some(reason)) if veto.contains(community) => {
                 return Err(format!(
-                    "Policy blocked: FPIC withheld by community {:?}: {}",
+                    "Policy blocked: veto-class community {:?} withheld FPIC: {}",
                     community.0, reason
                 ));
             }
+            _ => not_granted.push(community.0.as_str()),
+        }
+    }
+    if let ConsensusRule::WeightedThreshold { threshold, .. }
+    | ConsensusRule::VetoClasses { threshold, .. } = rule
+    {
+        // All-zero weights would make any threshold vacuously met.
+        if !affected_communities.is_empty() && total_weight == 0.0 {
+            return Err("Policy blocked: the affected communities carry no consensus weight.".into());
+        }
+        if granted_weight < threshold * total_weight {
+            return Err(format!(
+                "Policy blocked: granted FPIC weight {granted_weight} of {total_weight} is below the {threshold} threshold (not granted: {}).",
+                not_granted.join(", ")
+            ));
         }
     }
+    Ok(())
+}
 
-    // 2. Osireon‑style simulation: reject clearly unsafe futures.[web:136][web:149][web:146]
-    let outcome = simulator.evaluate_policy(snapshot)?;
-    if outcome.expected_neurorights_risk > 0.3 {
-        return Err("Policy blocked: neurorights risk too high in simulation.".into());
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    use governance_local::{
+        CommunityVoteResult, GovernanceProposal, MockCall, MockGovernanceBackend,
+    };
+    use governance_sim::{LinearPolicySimulator, SeededSimulator, SimulationOutcome};
+
+    /// Every community granted until `valid_until`, signed by `did:a`
+    /// twice and `did:b`.
+    fn granted_backend(valid_until: Option<SystemTime>) -> MockGovernanceBackend {
+        MockGovernanceBackend::new().with_default_status(FpicStatus::Granted {
+            timestamp: SystemTime::UNIX_EPOCH,
+            signed_by: vec!["did:a".into(), "did:a".into(), "did:b".into()],
+            valid_until,
+        })
     }
-    if outcome.environmental_justice_score < 0.6 {
-        return Err("Policy blocked: environmental justice score too low.".into());
+
+    struct SafeSimulator;
+
+    impl PolicySimulationBackend for SafeSimulator {
+        fn evaluate_policy(&self, _: &SncPolicySnapshot) -> Result<SimulationOutcome, String> {
+            Ok(SimulationOutcome {
+                expected_neurorights_risk: 0.1,
+                environmental_justice_score: 0.9,
+                trust_index: 0.9,
+            })
+        }
     }
 
-    Ok(())
+    fn snapshot() -> SncPolicySnapshot {
+        SncPolicySnapshot {
+            min_knowledge_factor_open: 0.8,
+            chat_issuance_slope: 1.0,
+            eco_weight: 0.4,
+        }
+    }
+
+    #[test]
+    fn granted_status_must_meet_the_community_quorum() {
+        let community = CommunityId("indigenous-phoenix-water-shed".into());
+        let snapshot = snapshot();
+        let validate = |required| {
+            let quorums = HashMap::from([(
+                community.clone(),
+                QuorumPolicy {
+                    required,
+                    recognized_delegates: vec![],
+                },
+            )]);
+            let request = PolicyChangeRequest {
+                quorums,
+                ..PolicyChangeRequest::new("p-1", std::slice::from_ref(&community), &snapshot)
+            };
+            validate_policy_change(&granted_backend(None), &SafeSimulator, &request)
+        };
+
+        assert_eq!(validate(2), Ok(()));
+        let err = validate(3).unwrap_err();
+        assert!(err.contains("quorum not met"), "{err}");
+        assert!(err.contains("2 of 3"), "{err}");
+        assert_eq!(
+            validate_policy_change(
+                &granted_backend(None),
+                &SafeSimulator,
+                &PolicyChangeRequest::new("p-1", std::slice::from_ref(&community), &snapshot),
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn expired_grant_blocks_like_pending() {
+        let community = CommunityId("indigenous-phoenix-water-shed".into());
+        // 2023-06-01T00:00:00Z
+        let until = SystemTime::UNIX_EPOCH + Duration::from_secs(1_685_577_600);
+        let snapshot = snapshot();
+        let validate = |now| {
+            let request = PolicyChangeRequest {
+                now,
+                ..PolicyChangeRequest::new("p-1", std::slice::from_ref(&community), &snapshot)
+            };
+            validate_policy_change(&granted_backend(Some(until)), &SafeSimulator, &request)
+        };
+
+        assert_eq!(validate(until - Duration::from_secs(1)), Ok(()));
+        let err = validate(until).unwrap_err();
+        assert!(err.contains("indigenous-phoenix-water-shed"), "{err}");
+        assert!(err.contains("expired on 2023-06-01"), "{err}");
+    }
+
+    /// Proposals with the state each reports, or `None` for untracked.
+    struct ProposalsBackend(Vec<(GovernanceProposal, Option<ProposalState>)>);
+
+    impl CommunityGovernanceBackend for ProposalsBackend {
+        fn get_fpic_status(&self, _: &str, _: &CommunityId) -> Result<FpicStatus, String> {
+            Ok(FpicStatus::Pending)
+        }
+
+        fn record_fpic_result(&self, _: CommunityVoteResult) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn get_proposal_state(&self, proposal_id: &str) -> Result<ProposalState, String> {
+            self.0
+                .iter()
+                .find(|(p, _)| p.id == proposal_id)
+                .and_then(|(_, state)| *state)
+                .ok_or_else(|| "untracked".to_string())
+        }
+
+        fn list_proposals_for_corridor(
+            &self,
+            corridor: &str,
+        ) -> Result<Vec<GovernanceProposal>, String> {
+            Ok(self
+                .0
+                .iter()
+                .filter(|(p, _)| p.affected_corridors.iter().any(|c| c == corridor))
+                .map(|(p, _)| p.clone())
+                .collect())
+        }
+    }
+
+    fn proposal(id: &str, corridors: &[&str]) -> GovernanceProposal {
+        GovernanceProposal {
+            id: id.into(),
+            title: String::new(),
+            description: String::new(),
+            affected_corridors: corridors.iter().map(|c| c.to_string()).collect(),
+            created_at: SystemTime::UNIX_EPOCH,
+            policy_diff: None,
+        }
+    }
+
+    #[test]
+    fn only_unsettled_proposals_mark_a_corridor_pending() {
+        let enacted = ProposalState::Closed {
+            outcome: governance_local::ProposalOutcome::Enacted,
+        };
+        let backend = ProposalsBackend(vec![
+            (
+                proposal("p-1", &["phoenix-canal", "south-phoenix-air"]),
+                Some(ProposalState::OpenForFpic),
+            ),
+            (
+                proposal("p-2", &["phoenix-canal", "salt-river"]),
+                Some(enacted),
+            ),
+            (
+                proposal("p-3", &["salt-river"]),
+                Some(ProposalState::Withdrawn),
+            ),
+            (proposal("p-4", &["gila-river"]), None),
+        ]);
+        let pending = |corridor: &str| {
+            corridor_has_pending_governance(&backend, &CorridorId(corridor.into())).unwrap()
+        };
+
+        assert!(pending("phoenix-canal"));
+        assert!(pending("south-phoenix-air"));
+        assert!(!pending("salt-river"));
+        assert!(pending("gila-river"));
+        assert!(!pending("verde-river"));
+
+        // Backends that do not store proposals cannot answer.
+        struct VotesOnly;
+
+        impl CommunityGovernanceBackend for VotesOnly {
+            fn get_fpic_status(&self, _: &str, _: &CommunityId) -> Result<FpicStatus, String> {
+                Ok(FpicStatus::Pending)
+            }
+
+            fn record_fpic_result(&self, _: CommunityVoteResult) -> Result<(), String> {
+                Ok(())
+            }
+        }
+        assert!(corridor_has_pending_governance(&VotesOnly, &CorridorId("x".into())).is_err());
+    }
+
+    fn granted() -> FpicStatus {
+        FpicStatus::Granted {
+            timestamp: SystemTime::UNIX_EPOCH,
+            signed_by: vec!["did:a".into()],
+            valid_until: None,
+        }
+    }
+
+    fn withheld() -> FpicStatus {
+        FpicStatus::Withheld {
+            timestamp: SystemTime::UNIX_EPOCH,
+            reason: "sacred site".into(),
+        }
+    }
+
+    fn communities() -> [CommunityId; 3] {
+        ["water-shed", "south-phoenix-air", "salt-river"].map(|c| CommunityId(c.into()))
+    }
+
+    fn validate_with(statuses: [FpicStatus; 3], rule: &ConsensusRule) -> Result<(), String> {
+        let affected = communities();
+        let backend = affected
+            .iter()
+            .cloned()
+            .zip(statuses)
+            .fold(MockGovernanceBackend::new(), |mock, (community, status)| {
+                mock.with_statuses("p-1", community, [status])
+            });
+        let snapshot = snapshot();
+        let request = PolicyChangeRequest {
+            rule: rule.clone(),
+            ..PolicyChangeRequest::new("p-1", &affected, &snapshot)
+        };
+        validate_policy_change(&backend, &SafeSimulator, &request)
+    }
+
+    #[test]
+    fn weighted_threshold_passes_when_exactly_met() {
+        let [a, b, c] = communities();
+        let rule = |threshold| ConsensusRule::WeightedThreshold {
+            weights: HashMap::from([(a.clone(), 0.5), (b.clone(), 0.25), (c.clone(), 0.25)]),
+            threshold,
+        };
+        let statuses = || [granted(), granted(), FpicStatus::Pending];
+
+        assert_eq!(validate_with(statuses(), &rule(0.75)), Ok(()));
+        let err = validate_with(statuses(), &rule(0.8)).unwrap_err();
+        assert!(err.contains("below the 0.8 threshold"), "{err}");
+        assert!(err.contains("not granted: salt-river"), "{err}");
+        assert_eq!(
+            validate_with(statuses(), &ConsensusRule::Unanimous),
+            Err("Policy blocked: FPIC still pending for community \"salt-river\".".into())
+        );
+    }
+
+    #[test]
+    fn malformed_weighted_rules_block() {
+        let [a, b, c] = communities();
+        let rule = |weight, threshold| ConsensusRule::WeightedThreshold {
+            weights: HashMap::from([(a.clone(), weight), (b.clone(), 1.0), (c.clone(), 1.0)]),
+            threshold,
+        };
+        let statuses = || [granted(), FpicStatus::Pending, FpicStatus::Pending];
+
+        // A NaN weight would make every comparison false and pass the gate.
+        let err = validate_with(statuses(), &rule(f32::NAN, 0.9)).unwrap_err();
+        assert!(err.contains("must be finite and non-negative"), "{err}");
+        assert!(validate_with(statuses(), &rule(f32::INFINITY, 0.9)).is_err());
+        assert!(validate_with(statuses(), &rule(-5.0, 0.9)).is_err());
+        for threshold in [f32::NAN, -0.1, 1.5] {
+            let err = validate_with(statuses(), &rule(1.0, threshold)).unwrap_err();
+            assert!(err.contains("must lie in 0.0..=1.0"), "{err}");
+        }
+        let veto = ConsensusRule::VetoClasses { veto: vec![], threshold: f32::NAN };
+        assert!(validate_with([granted(), granted(), granted()], &veto).is_err());
+
+        let weightless = ConsensusRule::WeightedThreshold {
+            weights: communities().into_iter().map(|c| (c, 0.0)).collect(),
+            threshold: 0.5,
+        };
+        assert!(weightless.validate().is_ok());
+        let err = validate_with(statuses(), &weightless).unwrap_err();
+        assert!(err.contains("no consensus weight"), "{err}");
+        assert_eq!(ConsensusRule::Unanimous.validate(), Ok(()));
+    }
+
+    #[test]
+    fn veto_class_withholding_overrides_the_threshold() {
+        let [a, ..] = communities();
+        let rule = ConsensusRule::VetoClasses {
+            veto: vec![a],
+            threshold: 0.5,
+        };
+
+        let err = validate_with([withheld(), granted(), granted()], &rule).unwrap_err();
+        assert_eq!(
+            err,
+            "Policy blocked: veto-class community \"water-shed\" withheld FPIC: sacred site"
+        );
+        // Pending is not a veto, and other communities cannot veto.
+        assert_eq!(
+            validate_with([FpicStatus::Pending, granted(), granted()], &rule),
+            Ok(())
+        );
+        assert_eq!(
+            validate_with([granted(), withheld(), granted()], &rule),
+            Ok(())
+        );
+        assert!(validate_with([granted(), withheld(), withheld()], &rule).is_err());
+    }
+
+    #[test]
+    fn consent_withdrawn_between_validations_blocks_the_second() {
+        let [community, ..] = communities();
+        let governance = MockGovernanceBackend::new().with_statuses(
+            "p-1",
+            community.clone(),
+            [granted(), withheld()],
+        );
+        let snapshot = snapshot();
+        let request = PolicyChangeRequest::new("p-1", std::slice::from_ref(&community), &snapshot);
+        let validate = || validate_policy_change(&governance, &SafeSimulator, &request);
+
+        assert_eq!(validate(), Ok(()));
+        assert_eq!(
+            validate(),
+            Err("Policy blocked: FPIC withheld by community \"water-shed\": sacred site".into())
+        );
+        assert_eq!(
+            governance.calls(),
+            vec![
+                MockCall::GetFpicStatus {
+                    proposal_id: "p-1".into(),
+                    community: community.clone(),
+                };
+                2
+            ]
+        );
+    }
+
+    #[test]
+    fn ensemble_tail_blocks_what_the_mean_allows() {
+        let [community, ..] = communities();
+        let governance = MockGovernanceBackend::new().with_default_status(granted());
+        // Risk uniform in [0.15, 0.35]: mean 0.25, 95th percentile ~0.34.
+        let simulator = || {
+            SeededSimulator::new(
+                42,
+                SimulationOutcome {
+                    expected_neurorights_risk: 0.25,
+                    environmental_justice_score: 0.9,
+                    trust_index: 0.9,
+                },
+                0.1,
+            )
+        };
+        let mean = simulator()
+            .evaluate_policy_ensemble(&snapshot(), 500)
+            .unwrap()
+            .expected_neurorights_risk
+            .mean;
+        assert!(mean < 0.3);
+
+        let snapshot = snapshot();
+        let request = PolicyChangeRequest::new("p-1", std::slice::from_ref(&community), &snapshot);
+        let err = validate_policy_change_with_ensemble(&governance, &simulator(), &request, 500)
+            .unwrap_err();
+        assert!(err.starts_with("Policy blocked: 95th-percentile neurorights risk 0.3"));
+        assert!(err.ends_with("exceeds 0.3 across 500 simulation runs."));
+
+        assert_eq!(
+            validate_policy_change_with_ensemble(&governance, &SafeSimulator, &request, 20),
+            Ok(())
+        );
+        // Ensembles are point-in-time: a horizon blocks instead of being dropped.
+        let over_horizon = PolicyChangeRequest {
+            horizon: Some(SimulationHorizon {
+                years: 5.0,
+                discount_rate: 0.03,
+            }),
+            ..request.clone()
+        };
+        assert_eq!(
+            validate_policy_change_with_ensemble(&governance, &SafeSimulator, &over_horizon, 20),
+            Err("ensemble simulation does not support a 5-year horizon".into())
+        );
+    }
+
+    struct FixedSimulator(SimulationOutcome);
+
+    impl PolicySimulationBackend for FixedSimulator {
+        fn evaluate_policy(&self, _: &SncPolicySnapshot) -> Result<SimulationOutcome, String> {
+            Ok(self.0.clone())
+        }
+    }
+
+    #[test]
+    fn each_threshold_trips_the_gate_on_its_own() {
+        let community = CommunityId("frontline-south-phoenix-air".into());
+        let negotiated = PolicyGateThresholds {
+            max_neurorights_risk: 0.4,
+            min_environmental_justice: 0.5,
+            min_trust_index: 0.7,
+        };
+        let within = SimulationOutcome {
+            expected_neurorights_risk: 0.4,
+            environmental_justice_score: 0.5,
+            trust_index: 0.7,
+        };
+        let snapshot = snapshot();
+        let validate = |outcome: SimulationOutcome, thresholds: &PolicyGateThresholds| {
+            let request = PolicyChangeRequest {
+                thresholds: thresholds.clone(),
+                ..PolicyChangeRequest::new("p-1", std::slice::from_ref(&community), &snapshot)
+            };
+            validate_policy_change(&granted_backend(None), &FixedSimulator(outcome), &request)
+        };
+
+        assert_eq!(validate(within.clone(), &negotiated), Ok(()));
+        // The default 0.3 ceiling would have blocked the same outcome.
+        assert!(validate(within.clone(), &PolicyGateThresholds::default()).is_err());
+        assert_eq!(
+            validate(
+                SimulationOutcome {
+                    expected_neurorights_risk: 0.41,
+                    ..within.clone()
+                },
+                &negotiated
+            ),
+            Err("Policy blocked: neurorights risk too high in simulation.".into())
+        );
+        assert_eq!(
+            validate(
+                SimulationOutcome {
+                    environmental_justice_score: 0.49,
+                    ..within.clone()
+                },
+                &negotiated
+            ),
+            Err("Policy blocked: environmental justice score too low.".into())
+        );
+        assert_eq!(
+            validate(
+                SimulationOutcome {
+                    trust_index: 0.69,
+                    ..within.clone()
+                },
+                &negotiated
+            ),
+            Err("Policy blocked: trust index too low in simulation.".into())
+        );
+        // Trust is unbounded by default.
+        assert_eq!(
+            validate(
+                SimulationOutcome {
+                    expected_neurorights_risk: 0.1,
+                    environmental_justice_score: 0.9,
+                    trust_index: 0.0,
+                },
+                &PolicyGateThresholds::default()
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn linear_reference_simulator_runs_end_to_end() {
+        let community = CommunityId("indigenous-phoenix-water-shed".into());
+        let validate = |min_knowledge_factor_open, chat_issuance_slope| {
+            let snapshot = SncPolicySnapshot {
+                min_knowledge_factor_open,
+                chat_issuance_slope,
+                eco_weight: 0.4,
+            };
+            validate_policy_change(
+                &granted_backend(None),
+                &LinearPolicySimulator::default(),
+                &PolicyChangeRequest::new("p-1", std::slice::from_ref(&community), &snapshot),
+            )
+        };
+
+        assert_eq!(validate(0.8, 1.0), Ok(()));
+        assert_eq!(
+            validate(0.0, 2.0),
+            Err("Policy blocked: neurorights risk too high in simulation.".into())
+        );
+    }
+
+    #[test]
+    fn horizons_discount_justice_and_fail_closed_when_unsupported() {
+        let community = CommunityId("indigenous-phoenix-water-shed".into());
+        let horizon = |years| {
+            Some(SimulationHorizon {
+                years,
+                discount_rate: 0.03,
+            })
+        };
+        let snapshot = snapshot();
+        let request = |horizon| PolicyChangeRequest {
+            horizon,
+            ..PolicyChangeRequest::new("p-1", std::slice::from_ref(&community), &snapshot)
+        };
+        let linear = |horizon| {
+            validate_policy_change(
+                &granted_backend(None),
+                &LinearPolicySimulator::default(),
+                &request(horizon),
+            )
+        };
+
+        // Justice 0.65 today: 0.631 after a year, 0.360 after twenty.
+        assert_eq!(linear(horizon(1.0)), Ok(()));
+        assert_eq!(
+            linear(horizon(20.0)),
+            Err("Policy blocked: environmental justice score too low.".into())
+        );
+
+        let point_in_time =
+            validate_policy_change(&granted_backend(None), &SafeSimulator, &request(horizon(1.0)));
+        assert_eq!(
+            point_in_time,
+            Err("simulator does not support a 1-year horizon".into())
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn thresholds_load_from_partial_json() {
+        let thresholds: PolicyGateThresholds =
+            serde_json::from_str(r#"{"min_trust_index": 0.5}"#).unwrap();
+        assert_eq!(
+            thresholds,
+            PolicyGateThresholds {
+                min_trust_index: 0.5,
+                ..PolicyGateThresholds::default()
+            }
+        );
+    }
+
+    /// Answers `outcome` after `latency`, like a remote simulator.
+    #[cfg(feature = "async")]
+    struct SlowSimulator {
+        latency: Duration,
+        outcome: Result<SimulationOutcome, String>,
+    }
+
+    #[cfg(feature = "async")]
+    impl governance_sim::AsyncPolicySimulationBackend for SlowSimulator {
+        async fn evaluate_policy(
+            &self,
+            _: &SncPolicySnapshot,
+        ) -> Result<SimulationOutcome, String> {
+            tokio::time::sleep(self.latency).await;
+            self.outcome.clone()
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_validation_matches_the_sync_path() {
+        struct FixedOutcome(Result<SimulationOutcome, String>);
+
+        impl PolicySimulationBackend for FixedOutcome {
+            fn evaluate_policy(&self, _: &SncPolicySnapshot) -> Result<SimulationOutcome, String> {
+                self.0.clone()
+            }
+        }
+
+        let [community, ..] = communities();
+        let risky = SimulationOutcome {
+            expected_neurorights_risk: 0.5,
+            environmental_justice_score: 0.9,
+            trust_index: 0.9,
+        };
+        let safe = SimulationOutcome {
+            expected_neurorights_risk: 0.1,
+            environmental_justice_score: 0.9,
+            trust_index: 0.9,
+        };
+        let cases = [
+            (granted(), Ok(safe.clone())),
+            (granted(), Ok(risky.clone())),
+            (granted(), Err("osireon node offline".to_string())),
+            // FPIC is reported before any simulator result.
+            (FpicStatus::Pending, Ok(risky)),
+            (withheld(), Err("osireon node offline".to_string())),
+        ];
+        let snapshot = snapshot();
+        let request = PolicyChangeRequest::new("p-1", std::slice::from_ref(&community), &snapshot);
+        for (status, outcome) in cases {
+            let governance = MockGovernanceBackend::new().with_default_status(status);
+            let sync = validate_policy_change(&governance, &FixedOutcome(outcome.clone()), &request);
+            let simulator = SlowSimulator {
+                latency: Duration::from_millis(5),
+                outcome,
+            };
+            let async_result = validate_policy_change_async(&governance, &simulator, &request).await;
+            assert_eq!(async_result, sync);
+        }
+
+        let adapted = governance_sim::SyncSimulationAdapter(SafeSimulator);
+        let governance = MockGovernanceBackend::new().with_default_status(granted());
+        let result = validate_policy_change_async(&governance, &adapted, &request).await;
+        assert_eq!(result, Ok(()));
+
+        // Quorums, rule, clock and horizon reach the async path as they do
+        // the sync one.
+        let expired = PolicyChangeRequest {
+            now: SystemTime::UNIX_EPOCH + Duration::from_secs(1_685_577_600),
+            ..request.clone()
+        };
+        let lapsed = granted_backend(Some(expired.now));
+        assert_eq!(
+            validate_policy_change_async(&lapsed, &adapted, &expired).await,
+            validate_policy_change(&lapsed, &SafeSimulator, &expired)
+        );
+        assert!(validate_policy_change(&lapsed, &SafeSimulator, &expired).is_err());
+        let quorum = PolicyChangeRequest {
+            quorums: HashMap::from([(
+                community.clone(),
+                QuorumPolicy {
+                    required: 3,
+                    recognized_delegates: vec![],
+                },
+            )]),
+            ..request.clone()
+        };
+        let err = validate_policy_change_async(&granted_backend(None), &adapted, &quorum)
+            .await
+            .unwrap_err();
+        assert!(err.contains("quorum not met"), "{err}");
+        let over_horizon = PolicyChangeRequest {
+            horizon: Some(SimulationHorizon {
+                years: 1.0,
+                discount_rate: 0.03,
+            }),
+            ..request.clone()
+        };
+        let linear = governance_sim::SyncSimulationAdapter(LinearPolicySimulator::default());
+        assert_eq!(
+            validate_policy_change_async(&governance, &linear, &over_horizon).await,
+            validate_policy_change(&governance, &LinearPolicySimulator::default(), &over_horizon)
+        );
+        assert_eq!(
+            validate_policy_change_async(&governance, &adapted, &over_horizon).await,
+            Err("simulator does not support a 1-year horizon".into())
+        );
+    }
 }
@@ -0,0 +1,197 @@
+use std::time::{Duration, SystemTime};
+
+use core_contract::eco::{CorridorId, EcoImpactMetrics, NeuromorphArtifact};
+use core_contract::labels::DataLabels;
+use core_contract::DistilledKnowledge;
+
+use crate::OrchestrationError;
+
+/// Machine-readable restriction attached to a restricted grant.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Restriction {
+    /// Only the summary may be released; refined eco metrics are withheld.
+    SummaryOnly,
+    /// Content may not be released before this instant.
+    EmbargoUntil(SystemTime),
+    /// Raw biophysical signals must never accompany the release.
+    NoRawBiophysical,
+}
+
+impl Restriction {
+    /// Stable code for ledger records and logs.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Restriction::SummaryOnly => "summary_only",
+            Restriction::EmbargoUntil(_) => "embargo_until",
+            Restriction::NoRawBiophysical => "no_raw_biophysical",
+        }
+    }
+}
+
+/// Graduated result of a distillation: the knowledge-access analogue of the
+/// biorail Allow / Downscale / Block verdicts.
+#[derive(Clone, Debug)]
+pub enum AccessOutcome {
+    Grant(DistilledKnowledge),
+    GrantRestricted {
+        dk: DistilledKnowledge,
+        restrictions: Vec<Restriction>,
+    },
+    Refuse(OrchestrationError),
+}
+
+/// Intermediate band between KnowledgeGated and Open.
+///
+/// Scores at or above `fk_min` / `eco_min` but below the Open thresholds
+/// earn a restricted grant instead of a plain KnowledgeGated result.
+#[derive(Clone, Debug)]
+pub struct GraduatedBand {
+    pub fk_min: f32,
+    pub eco_min: f32,
+    pub fk_open: f32,
+    pub eco_open: f32,
+    /// Embargo applied to restricted grants; zero disables the embargo.
+    pub embargo: Duration,
+}
+
+impl Default for GraduatedBand {
+    fn default() -> Self {
+        Self {
+            fk_min: 0.3,
+            eco_min: 0.6,
+            fk_open: 0.75,
+            eco_open: 0.8,
+            embargo: Duration::from_secs(7 * 86_400),
+        }
+    }
+}
+
+impl GraduatedBand {
+    /// True when the scores land inside the band (inclusive lower bounds,
+    /// exclusive Open bounds).
+    pub fn contains(&self, fk: f32, eco_impact: f32) -> bool {
+        let above_floor = fk >= self.fk_min && eco_impact >= self.eco_min;
+        let below_open = fk < self.fk_open || eco_impact < self.eco_open;
+        above_floor && below_open
+    }
+
+    /// Restriction set for a score inside the band, or `None` outside it.
+    pub fn restrictions_for(
+        &self,
+        fk: f32,
+        eco_impact: f32,
+        has_biophysical_signal: bool,
+        now: SystemTime,
+    ) -> Option<Vec<Restriction>> {
+        if !self.contains(fk, eco_impact) {
+            return None;
+        }
+        let mut restrictions = vec![Restriction::SummaryOnly];
+        if !self.embargo.is_zero() {
+            restrictions.push(Restriction::EmbargoUntil(now + self.embargo));
+        }
+        if has_biophysical_signal {
+            restrictions.push(Restriction::NoRawBiophysical);
+        }
+        Some(restrictions)
+    }
+}
+
+/// Artifact view released under a restricted grant.
+#[derive(Clone, Debug)]
+pub struct RedactedArtifact {
+    pub id: String,
+    pub corridor_id: CorridorId,
+    /// `None` while an embargo is in force.
+    pub summary: Option<String>,
+    /// `None` under SummaryOnly.
+    pub eco_impact: Option<EcoImpactMetrics>,
+    pub raw_biophysical_withheld: bool,
+    pub embargoed_until: Option<SystemTime>,
+    /// Always the artifact's labels, whatever else is withheld.
+    pub data_labels: DataLabels,
+}
+
+/// Enforce a restriction set on an artifact as of `now`.
+pub fn apply_restrictions(
+    artifact: &NeuromorphArtifact,
+    restrictions: &[Restriction],
+    now: SystemTime,
+) -> RedactedArtifact {
+    let mut redacted = RedactedArtifact {
+        id: artifact.id.clone(),
+        corridor_id: artifact.corridor_id.clone(),
+        summary: Some(artifact.summary.clone()),
+        eco_impact: Some(artifact.eco_impact.clone()),
+        raw_biophysical_withheld: false,
+        embargoed_until: None,
+        data_labels: artifact.data_labels.clone(),
+    };
+
+    for restriction in restrictions {
+        match restriction {
+            Restriction::SummaryOnly => redacted.eco_impact = None,
+            Restriction::EmbargoUntil(until) => {
+                if now < *until {
+                    redacted.summary = None;
+                    redacted.eco_impact = None;
+                    redacted.embargoed_until = Some(*until);
+                }
+            }
+            Restriction::NoRawBiophysical => redacted.raw_biophysical_withheld = true,
+        }
+    }
+    redacted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::UNIX_EPOCH;
+
+    fn artifact() -> NeuromorphArtifact {
+        NeuromorphArtifact {
+            id: "artifact-001".into(),
+            corridor_id: CorridorId("urban-phoenix".into()),
+            eco_impact: EcoImpactMetrics {
+                climate_score: 0.9,
+                biodiversity_score: 0.9,
+                biosphere_score: 0.9,
+                corridor_score: 0.9,
+            },
+            summary: "summary".into(),
+            data_labels: DataLabels::default(),
+        }
+    }
+
+    #[test]
+    fn band_boundaries() {
+        let band = GraduatedBand::default();
+        assert!(band.contains(0.3, 0.6));
+        assert!(band.contains(0.74, 0.9));
+        assert!(!band.contains(0.75, 0.8));
+        assert!(!band.contains(0.29, 0.7));
+        assert!(!band.contains(0.6, 0.59));
+    }
+
+    #[test]
+    fn embargo_is_honored_until_it_lapses() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_000);
+        let band = GraduatedBand {
+            embargo: Duration::from_secs(100),
+            ..GraduatedBand::default()
+        };
+        let restrictions = band.restrictions_for(0.6, 0.7, true, now).unwrap();
+        assert!(restrictions.contains(&Restriction::EmbargoUntil(now + Duration::from_secs(100))));
+
+        let during = apply_restrictions(&artifact(), &restrictions, now + Duration::from_secs(99));
+        assert!(during.summary.is_none());
+        assert!(during.raw_biophysical_withheld);
+
+        let after = apply_restrictions(&artifact(), &restrictions, now + Duration::from_secs(100));
+        assert_eq!(after.summary.as_deref(), Some("summary"));
+        assert!(after.eco_impact.is_none());
+        assert!(after.embargoed_until.is_none());
+    }
+}
@@ -0,0 +1,232 @@
+/// Weighted components of the knowledge factor
+/// F_K = V^wV · R^wR · E^wE · N^wN.[file:69]
+///
+/// Validation, reuse and novelty come from the config (or per-call
+/// overrides); the eco term E is always the refined scalar from the
+/// orchestrator's EcoDataSource. Weights are exponents: 1.0 keeps a
+/// component as-is, 0.0 removes it from the product.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KnowledgeFactorConfig {
+    pub validation: f32,
+    pub reuse: f32,
+    pub novelty: f32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub weights: KnowledgeWeights,
+}
+
+impl Default for KnowledgeFactorConfig {
+    /// The component values the orchestrator has always used.
+    fn default() -> Self {
+        Self {
+            validation: 0.9,
+            reuse: 0.6,
+            novelty: 0.7,
+            weights: KnowledgeWeights::default(),
+        }
+    }
+}
+
+/// Per-component exponents; must be finite and non-negative.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KnowledgeWeights {
+    pub validation: f32,
+    pub reuse: f32,
+    pub eco_impact: f32,
+    pub novelty: f32,
+}
+
+impl Default for KnowledgeWeights {
+    fn default() -> Self {
+        Self {
+            validation: 1.0,
+            reuse: 1.0,
+            eco_impact: 1.0,
+            novelty: 1.0,
+        }
+    }
+}
+
+/// Caller-supplied component values for a single distillation. Values
+/// outside [0, 1] are clamped and the breakdown records that they were.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KnowledgeOverrides {
+    pub validation: Option<f32>,
+    pub reuse: Option<f32>,
+    pub novelty: Option<f32>,
+}
+
+/// The components F_K was computed from, for explaining access classes.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KnowledgeFactorBreakdown {
+    pub validation: f32,
+    pub reuse: f32,
+    pub eco_impact: f32,
+    pub novelty: f32,
+    pub knowledge_factor: f32,
+    /// True when an override or the eco scalar had to be clamped into [0, 1].
+    pub clamped: bool,
+}
+
+impl KnowledgeFactorConfig {
+    /// Components must lie in [0, 1]; weights must be finite and >= 0.
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, value) in [
+            ("validation", self.validation),
+            ("reuse", self.reuse),
+            ("novelty", self.novelty),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(format!(
+                    "Knowledge factor component {name} must be in [0, 1], got {value}."
+                ));
+            }
+        }
+        let w = &self.weights;
+        for (name, weight) in [
+            ("validation", w.validation),
+            ("reuse", w.reuse),
+            ("eco_impact", w.eco_impact),
+            ("novelty", w.novelty),
+        ] {
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(format!(
+                    "Knowledge factor weight {name} must be finite and non-negative, got {weight}."
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Computes F_K from the refined eco scalar and optional overrides.
+    pub fn compute(
+        &self,
+        eco_impact: f32,
+        overrides: Option<&KnowledgeOverrides>,
+    ) -> Result<KnowledgeFactorBreakdown, String> {
+        let overrides = overrides.cloned().unwrap_or_default();
+        let mut clamped = false;
+        let mut component = |name: &str, value: f32| -> Result<f32, String> {
+            if !value.is_finite() {
+                return Err(format!("Knowledge factor component {name} must be finite."));
+            }
+            let bounded = value.clamp(0.0, 1.0);
+            clamped |= bounded != value;
+            Ok(bounded)
+        };
+
+        let validation = component("validation", overrides.validation.unwrap_or(self.validation))?;
+        let reuse = component("reuse", overrides.reuse.unwrap_or(self.reuse))?;
+        let eco_impact = component("eco_impact", eco_impact)?;
+        let novelty = component("novelty", overrides.novelty.unwrap_or(self.novelty))?;
+
+        let w = &self.weights;
+        let knowledge_factor = (validation.powf(w.validation)
+            * reuse.powf(w.reuse)
+            * eco_impact.powf(w.eco_impact)
+            * novelty.powf(w.novelty))
+        .clamp(0.0, 1.0);
+
+        Ok(KnowledgeFactorBreakdown {
+            validation,
+            reuse,
+            eco_impact,
+            novelty,
+            knowledge_factor,
+            clamped,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_config_reproduces_the_fixed_product() {
+        let breakdown = KnowledgeFactorConfig::default().compute(0.5, None).unwrap();
+        assert!((breakdown.knowledge_factor - 0.9 * 0.6 * 0.5 * 0.7).abs() < 1e-6);
+        assert!(!breakdown.clamped);
+    }
+
+    #[test]
+    fn zero_component_zeroes_the_product_unless_weighted_out() {
+        let config = KnowledgeFactorConfig::default();
+        let zero_reuse = KnowledgeOverrides {
+            reuse: Some(0.0),
+            ..KnowledgeOverrides::default()
+        };
+        let breakdown = config.compute(1.0, Some(&zero_reuse)).unwrap();
+        assert_eq!(breakdown.reuse, 0.0);
+        assert_eq!(breakdown.knowledge_factor, 0.0);
+        assert!(!breakdown.clamped);
+
+        let ignore_reuse = KnowledgeFactorConfig {
+            weights: KnowledgeWeights {
+                reuse: 0.0,
+                ..KnowledgeWeights::default()
+            },
+            ..config
+        };
+        let breakdown = ignore_reuse.compute(1.0, Some(&zero_reuse)).unwrap();
+        assert!((breakdown.knowledge_factor - 0.9 * 0.7).abs() < 1e-6);
+
+        let zero_eco = KnowledgeFactorConfig::default().compute(0.0, None).unwrap();
+        assert_eq!(zero_eco.knowledge_factor, 0.0);
+    }
+
+    #[test]
+    fn out_of_range_overrides_are_clamped_and_flagged() {
+        let overrides = KnowledgeOverrides {
+            validation: Some(1.4),
+            novelty: Some(-0.2),
+            ..KnowledgeOverrides::default()
+        };
+        let breakdown = KnowledgeFactorConfig::default()
+            .compute(1.0, Some(&overrides))
+            .unwrap();
+        assert_eq!(breakdown.validation, 1.0);
+        assert_eq!(breakdown.novelty, 0.0);
+        assert_eq!(breakdown.knowledge_factor, 0.0);
+        assert!(breakdown.clamped);
+
+        let eco_over = KnowledgeFactorConfig::default().compute(1.2, None).unwrap();
+        assert_eq!(eco_over.eco_impact, 1.0);
+        assert!(eco_over.clamped);
+
+        let nan = KnowledgeOverrides {
+            reuse: Some(f32::NAN),
+            ..KnowledgeOverrides::default()
+        };
+        assert!(KnowledgeFactorConfig::default().compute(1.0, Some(&nan)).is_err());
+    }
+
+    #[test]
+    fn validate_rejects_components_and_weights_out_of_range() {
+        assert!(KnowledgeFactorConfig::default().validate().is_ok());
+        let boundary = KnowledgeFactorConfig {
+            validation: 0.0,
+            reuse: 1.0,
+            ..KnowledgeFactorConfig::default()
+        };
+        assert!(boundary.validate().is_ok());
+
+        let high = KnowledgeFactorConfig {
+            novelty: 1.01,
+            ..KnowledgeFactorConfig::default()
+        };
+        assert!(high.validate().unwrap_err().contains("novelty"));
+
+        let negative_weight = KnowledgeFactorConfig {
+            weights: KnowledgeWeights {
+                eco_impact: -1.0,
+                ..KnowledgeWeights::default()
+            },
+            ..KnowledgeFactorConfig::default()
+        };
+        assert!(negative_weight.validate().unwrap_err().contains("eco_impact"));
+    }
+}
@@ -1,97 +1,1756 @@
-use core_contract::eco::{EcoImpactMetrics, NeuromorphArtifact};
-use core_contract::eco_source::EcoDataSource;
-use core_contract::{SovereignNeuromorphContract, DistilledKnowledge, AccessClass, RoleTier};
+use std::sync::Mutex;
+use std::time::SystemTime;
 
-/// Orchestrator now requires an EcoDataSource and uses its output
-/// as the EcoImpact term in the knowledge-factor F_K.[file:69][file:55]
+use core_contract::config_version::ConfigVersion;
+use core_contract::eco::{
+    EcoImpactMetrics, EcoImpactReport, NeuromorphArtifact, ProvenanceInfo, DEFAULT_MAX_SUMMARY_LEN,
+};
+use core_contract::eco_source::{AsyncEcoDataSource, EcoDataSource, EcoSourceError};
+use core_contract::eco_trend::{EcoTrendConfig, EcoTrendObserver, EcoTrendStore, EcoTrendTracker};
+use core_contract::fpic::FpicStatus;
+use core_contract::sovereign_runtime::SovereignContext;
+use core_contract::{SovereignNeuromorphContract, DistilledKnowledge, AccessClass, RoleTier, SncPredicate};
+use governance_sim::SncPolicySnapshot;
+
+pub mod audit;
+#[cfg(feature = "events")]
+pub mod consent_watch;
+mod error;
+pub mod governance;
+pub mod graduated;
+pub mod knowledge;
+pub mod policy;
+pub mod quota;
+pub mod trace;
+
+pub use error::OrchestrationError;
+
+use audit::{DistillationAuditSink, DistillationRecord};
+use graduated::{AccessOutcome, GraduatedBand, Restriction};
+use knowledge::{KnowledgeFactorBreakdown, KnowledgeFactorConfig, KnowledgeOverrides};
+use policy::{AccessClassPolicy, SimulationCheck, SimulationGate, ThresholdAccessPolicy};
+use quota::{DistillationQuota, QuotaRemaining};
+use trace::DistillationTrace;
+
+/// Appended to the eco provenance of a decision scored from stale eco data.
+pub const STALE_SUFFIX: &str = "[stale]";
+
+/// Stale eco data is scored in a degraded mode: its metrics are used under
+/// `provenance` with `STALE_SUFFIX` on its label, alongside a warning for
+/// the trace. Every other eco failure is a refusal.
+fn degrade_stale(
+    error: EcoSourceError,
+    mut provenance: ProvenanceInfo,
+) -> Result<(EcoImpactMetrics, ProvenanceInfo, String), OrchestrationError> {
+    match error {
+        EcoSourceError::StaleData { ref metrics, .. } => {
+            provenance.label.push_str(STALE_SUFFIX);
+            Ok((metrics.clone(), provenance, format!("{error}; scored in degraded mode")))
+        }
+        other => Err(OrchestrationError::EcoSourceFailure(other)),
+    }
+}
+
+/// Refusal code prefixed to `OrchestrationError::PurposeProhibited`
+/// messages.
+pub const PURPOSE_PROHIBITED: &str = "PurposeProhibited";
+
+/// Minimum F_K for `AccessClass::Open` under the default policy.
+pub const OPEN_MIN_KNOWLEDGE_FACTOR: f32 = 0.75;
+/// Minimum refined eco scalar for `AccessClass::Open` under the default
+/// policy.
+pub const OPEN_MIN_ECO_IMPACT: f32 = 0.8;
+
+/// Per-artifact signals for `NeuromorphOrchestrator::distill_batch`, matching
+/// the boolean arguments of `distill_neuromorph_content`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DistillFlags {
+    pub has_biophysical_signal: bool,
+    pub uses_discipline_signals: bool,
+    pub dual_empirical_formal_present: bool,
+    pub uncertainty_exposed: bool,
+}
+
+/// One call to `NeuromorphOrchestrator::distill`. `new` covers the plain
+/// case; the optional fields add per-call F_K overrides, the actor charged
+/// against the quota, an FPIC/CARE context and a graduated band.
+pub struct DistillRequest<'a> {
+    pub role: RoleTier,
+    pub artifact: NeuromorphArtifact,
+    pub purpose: &'a str,
+    pub flags: DistillFlags,
+    pub overrides: Option<&'a KnowledgeOverrides>,
+    /// Charged against the quota set with `with_quota`.
+    pub actor_id: Option<&'a str>,
+    /// Checked after the SNC predicates, before the purpose labels.
+    pub sovereign: Option<SovereignContext<'a>>,
+    /// KnowledgeGated results inside the band get restrictions dated from
+    /// the given instant.
+    pub band: Option<(&'a GraduatedBand, SystemTime)>,
+}
+
+impl<'a> DistillRequest<'a> {
+    pub fn new(role: RoleTier, artifact: NeuromorphArtifact, purpose: &'a str, flags: DistillFlags) -> Self {
+        Self {
+            role,
+            artifact,
+            purpose,
+            flags,
+            overrides: None,
+            actor_id: None,
+            sovereign: None,
+            band: None,
+        }
+    }
+}
+
+/// Trend tracker, its observer and, when persisted, its store.
+struct EcoTrend {
+    tracker: Mutex<EcoTrendTracker>,
+    observer: Box<dyn EcoTrendObserver>,
+    store: Option<Box<dyn EcoTrendStore>>,
+}
+
+/// Orchestrator now requires an EcoDataSource (or AsyncEcoDataSource) and
+/// uses its output as the EcoImpact term in the knowledge-factor F_K.[file:69][file:55]
 pub struct NeuromorphOrchestrator<C, E>
 where
     C: SovereignNeuromorphContract,
-    E: EcoDataSource,
 {
     contract: C,
     eco_source: E,
+    /// Optional per-corridor trend history fed by every refined EcoImpact.
+    eco_trend: Option<EcoTrend>,
+    /// Version of the configuration bundle this orchestrator was built from.
+    config_version: Option<ConfigVersion>,
+    /// Component values and weights for F_K.
+    knowledge_factor: KnowledgeFactorConfig,
+    /// Decides the access class from F_K, EcoImpact, role and signals.
+    access_policy: Box<dyn AccessClassPolicy>,
+    /// Optional record of every distillation, e.g. the deed ledger.
+    audit_sink: Option<Box<dyn DistillationAuditSink>>,
+    /// Optional simulator consulted before granting Open.
+    simulation_gate: Option<SimulationGate>,
+    /// Longest artifact summary accepted, in characters.
+    max_summary_len: usize,
+    /// Optional per-actor rate limit for `distill`.
+    quota: Option<Box<dyn DistillationQuota>>,
 }
 
 impl<C, E> NeuromorphOrchestrator<C, E>
 where
     C: SovereignNeuromorphContract,
-    E: EcoDataSource,
 {
     pub fn new(contract: C, eco_source: E) -> Self {
-        Self { contract, eco_source }
+        Self {
+            contract,
+            eco_source,
+            eco_trend: None,
+            config_version: None,
+            knowledge_factor: KnowledgeFactorConfig::default(),
+            access_policy: Box::new(ThresholdAccessPolicy::default()),
+            audit_sink: None,
+            simulation_gate: None,
+            max_summary_len: DEFAULT_MAX_SUMMARY_LEN,
+            quota: None,
+        }
+    }
+
+    /// Meter `distill` calls on `quota`, keyed by `DistillRequest::actor_id`.
+    /// A call is charged once it passes the contract and eligibility checks,
+    /// before the eco source is consulted. Once a quota is attached, calls
+    /// that name no actor — including `distill_batch`, the async path and
+    /// the positional helpers — are refused with `QuotaActorMissing`.
+    pub fn with_quota(mut self, quota: impl DistillationQuota + 'static) -> Self {
+        self.quota = Some(Box::new(quota));
+        self
+    }
+
+    /// Refuse artifacts whose summary exceeds `len` characters; defaults to
+    /// `DEFAULT_MAX_SUMMARY_LEN`.
+    pub fn with_max_summary_len(mut self, len: usize) -> Self {
+        self.max_summary_len = len;
+        self
+    }
+
+    /// Consult `gate`'s simulator whenever the access class would be Open:
+    /// a simulated neurorights risk above the gate's bound downgrades the
+    /// result to KnowledgeGated, and a simulator error refuses it.
+    pub fn with_simulation_gate(mut self, gate: SimulationGate) -> Self {
+        self.simulation_gate = Some(gate);
+        self
+    }
+
+    /// Record every distillation, successful or refused, on `sink`.
+    pub fn with_audit_sink(mut self, sink: impl DistillationAuditSink + 'static) -> Self {
+        self.audit_sink = Some(Box::new(sink));
+        self
+    }
+
+    /// Replace the default `ThresholdAccessPolicy`.
+    pub fn with_access_policy(mut self, policy: impl AccessClassPolicy + 'static) -> Self {
+        self.access_policy = Box::new(policy);
+        self
+    }
+
+    /// Replace the default F_K components and weights; rejects configs
+    /// whose components leave [0, 1] or whose weights are negative.
+    pub fn with_knowledge_factor(
+        mut self,
+        config: KnowledgeFactorConfig,
+    ) -> Result<Self, OrchestrationError> {
+        config
+            .validate()
+            .map_err(OrchestrationError::InvalidKnowledgeFactor)?;
+        self.knowledge_factor = config;
+        Ok(self)
+    }
+
+    /// Stamp every distillation with the version of the loaded config bundle.
+    pub fn with_config_version(mut self, version: ConfigVersion) -> Self {
+        self.config_version = Some(version);
+        self
+    }
+
+    /// Record refined EcoImpact per corridor and report drift to `observer`.
+    pub fn with_eco_trend(
+        mut self,
+        tracker: EcoTrendTracker,
+        observer: Box<dyn EcoTrendObserver>,
+    ) -> Self {
+        self.eco_trend = Some(EcoTrend {
+            tracker: Mutex::new(tracker),
+            observer,
+            store: None,
+        });
+        self
+    }
+
+    /// Like `with_eco_trend`, but the tracker is loaded from `store` (or
+    /// started empty) under `config` and saved back after every sample.
+    /// Save failures go to `EcoTrendObserver::on_store_failed`.
+    pub fn with_stored_eco_trend(
+        mut self,
+        config: EcoTrendConfig,
+        store: impl EcoTrendStore + 'static,
+        observer: Box<dyn EcoTrendObserver>,
+    ) -> Result<Self, String> {
+        let tracker = match store.load()? {
+            Some(tracker) => tracker.with_config(config),
+            None => EcoTrendTracker::new(config),
+        };
+        self.eco_trend = Some(EcoTrend {
+            tracker: Mutex::new(tracker),
+            observer,
+            store: Some(Box::new(store)),
+        });
+        Ok(self)
+    }
+
+    /// Snapshot of the trend tracker, if one is attached.
+    pub fn eco_trend(&self) -> Option<EcoTrendTracker> {
+        self.eco_trend
+            .as_ref()
+            .and_then(|trend| trend.tracker.lock().ok().map(|t| t.clone()))
+    }
+
+    /// 0. Malformed artifacts never reach the contract checks or F_K math.
+    fn check_artifact(&self, artifact: &NeuromorphArtifact) -> Result<(), OrchestrationError> {
+        artifact
+            .validate(self.max_summary_len)
+            .map_err(OrchestrationError::InvalidArtifact)
     }
 
+    /// 1. Sovereignty + neurorights checks (unchanged).
+    fn check_contract(&self) -> Result<(), OrchestrationError> {
+        for predicate in SncPredicate::ALL {
+            if !predicate.holds(&self.contract) {
+                return Err(match self.contract.failing_member(predicate) {
+                    Some(member) => OrchestrationError::ContractMemberRefused {
+                        member: member.to_string(),
+                        predicate,
+                    },
+                    None => predicate.into(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn audit(
+        &self,
+        role: RoleTier,
+        artifact: &NeuromorphArtifact,
+        purpose: &str,
+        result: Result<&DistilledKnowledge, &OrchestrationError>,
+        restrictions: &[Restriction],
+    ) {
+        if let Some(sink) = &self.audit_sink {
+            let record = DistillationRecord::new(
+                artifact,
+                role,
+                purpose,
+                result,
+                restrictions,
+                self.config_version.as_ref(),
+            );
+            audit::record_blocking(sink.as_ref(), record);
+        }
+    }
+
+    /// Charge `actor_id` against the attached quota. Without a quota nothing
+    /// is metered; with one, a call that names no actor is refused.
+    fn charge_quota(&self, actor_id: Option<&str>) -> Result<Option<QuotaRemaining>, OrchestrationError> {
+        let Some(quota) = &self.quota else {
+            return Ok(None);
+        };
+        let actor_id = actor_id.ok_or(OrchestrationError::QuotaActorMissing)?;
+        quota
+            .acquire(actor_id, SystemTime::now())
+            .map(Some)
+            .map_err(|retry_after| OrchestrationError::QuotaExceeded { retry_after })
+    }
+
+    /// FPIC freshness and veto, then CARE alignment. A stale token reports
+    /// `FpicExpired` even if it was also vetoed.
+    fn check_sovereign_context(ctx: &SovereignContext<'_>) -> Result<(), OrchestrationError> {
+        if !ctx.fpic.is_fresh(ctx.now) {
+            return Err(OrchestrationError::FpicExpired);
+        }
+        match ctx.fpic.status(ctx.now) {
+            FpicStatus::Granted => {}
+            status => return Err(OrchestrationError::FpicWithdrawn(status)),
+        }
+        if !ctx.care.is_fully_care_aligned() {
+            return Err(OrchestrationError::CareMisaligned);
+        }
+        Ok(())
+    }
+
+    /// Per-artifact checks that run before the eco source is consulted.
+    fn check_eligibility(
+        artifact: &NeuromorphArtifact,
+        purpose: &str,
+        flags: &DistillFlags,
+    ) -> Result<(), OrchestrationError> {
+        // 2. Data-sovereignty labels: the requested purpose must be permitted
+        //    before the artifact is scored at all.
+        if !artifact.data_labels.permits(purpose) {
+            return Err(OrchestrationError::PurposeProhibited {
+                purpose: purpose.to_string(),
+                artifact_id: artifact.id.clone(),
+            });
+        }
+
+        // 3. CHAT eligibility: dual empirical + formal, uncertainty required.[file:55]
+        if !flags.dual_empirical_formal_present {
+            return Err(OrchestrationError::ChatMissingDualLinkage);
+        }
+        if !flags.uncertainty_exposed {
+            return Err(OrchestrationError::ChatUncertaintyNotExposed);
+        }
+        Ok(())
+    }
+
+    /// Steps 5–7: record the trend sample, compute F_K and the access class.
+    fn score(
+        &self,
+        role: RoleTier,
+        artifact: &NeuromorphArtifact,
+        flags: &DistillFlags,
+        eco_refined: EcoImpactMetrics,
+        overrides: Option<&KnowledgeOverrides>,
+        eco_provenance: &ProvenanceInfo,
+    ) -> Result<(DistilledKnowledge, DistillationTrace), OrchestrationError> {
+        if let Some(trend) = &self.eco_trend {
+            if let Ok(mut tracker) = trend.tracker.lock() {
+                let alerts =
+                    tracker.record(&artifact.corridor_id, eco_refined.clone(), SystemTime::now());
+                if let Some(Err(err)) = trend.store.as_ref().map(|store| store.save(&tracker)) {
+                    trend.observer.on_store_failed(&err);
+                }
+                for alert in &alerts {
+                    trend.observer.on_drift_alert(alert);
+                }
+            }
+        }
+
+        // 5. Knowledge-factor components: V, R, E, N.[file:69]
+        let breakdown = self
+            .knowledge_factor
+            .compute(eco_refined.scalar(), overrides)
+            .map_err(OrchestrationError::InvalidKnowledgeFactor)?;
+
+        // 6. Access class: ecological risk + neuromorphic sensitivity.[file:69]
+        let (fk, eco_impact) = (breakdown.knowledge_factor, breakdown.eco_impact);
+        let branch = self.access_policy.branch(role, fk, eco_impact, flags);
+        let mut access_class = self.access_policy.classify(role, fk, eco_impact, flags);
+        let simulation = match (&self.simulation_gate, access_class) {
+            (Some(gate), AccessClass::Open) => {
+                let check = self.simulate(gate)?;
+                if check.downgraded {
+                    access_class = AccessClass::KnowledgeGated;
+                }
+                Some(check)
+            }
+            _ => None,
+        };
+
+        // 7. Delegate to existing DistilledKnowledge constructor.
+        let mut dk = crate::distill_neuromorph_content_from_components(
+            &self.contract,
+            role,
+            flags.has_biophysical_signal,
+            flags.uses_discipline_signals,
+            fk,
+            access_class,
+            eco_provenance,
+        )?;
+        dk.config_version = self.config_version.clone();
+        dk.data_labels = artifact.data_labels.clone();
+
+        let trace = DistillationTrace {
+            role,
+            has_biophysical_signal: flags.has_biophysical_signal,
+            uses_discipline_signals: flags.uses_discipline_signals,
+            components: breakdown,
+            branch,
+            access_class,
+            eco_provenance: eco_provenance.label.clone(),
+            simulation,
+            quota: None,
+            eco_report: None,
+            eco_warning: None,
+            restrictions: None,
+        };
+        Ok((dk, trace))
+    }
+
+    /// Evaluate the current Open thresholds on the gate's simulator.
+    fn simulate(&self, gate: &SimulationGate) -> Result<SimulationCheck, OrchestrationError> {
+        let snapshot = SncPolicySnapshot {
+            min_knowledge_factor_open: self.access_policy.min_knowledge_factor_open(),
+            chat_issuance_slope: gate.chat_issuance_slope,
+            eco_weight: self.knowledge_factor.weights.eco_impact,
+        };
+        let outcome = gate
+            .backend
+            .evaluate_policy(&snapshot)
+            .map_err(OrchestrationError::SimulationFailure)?;
+        let risk = outcome.expected_neurorights_risk;
+        if !risk.is_finite() {
+            return Err(OrchestrationError::SimulationFailure(format!(
+                "non-finite neurorights risk {risk}"
+            )));
+        }
+        Ok(SimulationCheck {
+            expected_neurorights_risk: risk,
+            max_neurorights_risk: gate.max_neurorights_risk,
+            downgraded: risk > gate.max_neurorights_risk,
+        })
+    }
+}
+
+impl<C, E> NeuromorphOrchestrator<C, E>
+where
+    C: SovereignNeuromorphContract,
+    E: EcoDataSource,
+{
+    /// Distill `request.artifact` for `request.role`. Every check runs once,
+    /// in order: artifact shape, SNC predicates, the sovereign context when
+    /// one is given, purpose labels and CHAT eligibility, then the quota;
+    /// only then is the eco source consulted. The outcome is recorded on
+    /// the audit sink.
+    pub fn distill(
+        &self,
+        request: DistillRequest<'_>,
+    ) -> Result<(DistilledKnowledge, DistillationTrace), OrchestrationError> {
+        let result = self.distill_unaudited(&request);
+        let restrictions = match &result {
+            Ok((_, trace)) => trace.restrictions.as_deref().unwrap_or_default(),
+            Err(_) => &[],
+        };
+        self.audit(
+            request.role,
+            &request.artifact,
+            request.purpose,
+            result.as_ref().map(|(dk, _)| dk),
+            restrictions,
+        );
+        result
+    }
+
+    /// `distill` as a graduated outcome: a KnowledgeGated result inside the
+    /// request's band becomes a restricted grant instead of a plain gate.
+    /// Without a band this is `Grant` or `Refuse`.
+    pub fn distill_graduated(&self, request: DistillRequest<'_>) -> AccessOutcome {
+        match self.distill(request) {
+            Ok((dk, trace)) => match trace.restrictions {
+                Some(restrictions) => AccessOutcome::GrantRestricted { dk, restrictions },
+                None => AccessOutcome::Grant(dk),
+            },
+            Err(e) => AccessOutcome::Refuse(e),
+        }
+    }
+
+    /// Distill `artifact` for `role`, serving the declared `purpose`; the
+    /// purpose is checked against the artifact's data labels before scoring.
+    #[allow(clippy::too_many_arguments)]
     pub fn distill_neuromorph_content(
         &self,
         role: RoleTier,
         artifact: NeuromorphArtifact,
+        purpose: &str,
+        has_biophysical_signal: bool,
+        uses_discipline_signals: bool,
+        dual_empirical_formal_present: bool,
+        uncertainty_exposed: bool,
+    ) -> Result<DistilledKnowledge, OrchestrationError> {
+        let flags = DistillFlags {
+            has_biophysical_signal,
+            uses_discipline_signals,
+            dual_empirical_formal_present,
+            uncertainty_exposed,
+        };
+        self.distill(DistillRequest::new(role, artifact, purpose, flags))
+            .map(|(dk, _)| dk)
+    }
+
+    /// Like `distill_neuromorph_content`, with per-call F_K component
+    /// overrides, also returning the V, R, E, N breakdown behind the result.
+    #[allow(clippy::too_many_arguments)]
+    pub fn distill_with_breakdown(
+        &self,
+        role: RoleTier,
+        artifact: NeuromorphArtifact,
+        purpose: &str,
+        has_biophysical_signal: bool,
+        uses_discipline_signals: bool,
+        dual_empirical_formal_present: bool,
+        uncertainty_exposed: bool,
+        overrides: Option<&KnowledgeOverrides>,
+    ) -> Result<(DistilledKnowledge, KnowledgeFactorBreakdown), OrchestrationError> {
+        self.distill_with_trace(
+            role,
+            artifact,
+            purpose,
+            has_biophysical_signal,
+            uses_discipline_signals,
+            dual_empirical_formal_present,
+            uncertainty_exposed,
+            overrides,
+        )
+        .map(|(dk, trace)| (dk, trace.components))
+    }
+
+    /// Like `distill_with_breakdown`, returning the full decision trace:
+    /// components, signals, the access-class branch that fired and the eco
+    /// provenance.
+    #[allow(clippy::too_many_arguments)]
+    pub fn distill_with_trace(
+        &self,
+        role: RoleTier,
+        artifact: NeuromorphArtifact,
+        purpose: &str,
         has_biophysical_signal: bool,
         uses_discipline_signals: bool,
         dual_empirical_formal_present: bool,
         uncertainty_exposed: bool,
-    ) -> Result<DistilledKnowledge, String> {
-        // 1. Sovereignty + neurorights checks (unchanged).
-        if !self.contract.has_explicit_consent() {
-            return Err("SNC violation: explicit consent required.".into());
+        overrides: Option<&KnowledgeOverrides>,
+    ) -> Result<(DistilledKnowledge, DistillationTrace), OrchestrationError> {
+        let flags = DistillFlags {
+            has_biophysical_signal,
+            uses_discipline_signals,
+            dual_empirical_formal_present,
+            uncertainty_exposed,
+        };
+        self.distill(DistillRequest {
+            overrides,
+            ..DistillRequest::new(role, artifact, purpose, flags)
+        })
+    }
+
+    /// Distill a batch of artifacts for `role` and `purpose`. Each artifact
+    /// is validated, the SNC contract is checked once and eligible artifacts are scored through a
+    /// single `EcoDataSource::calculate_batch_with_provenance` call. Results come back in
+    /// input order; a refusal only affects its own entry. A batch names no
+    /// actor, so with a quota attached every entry refuses with
+    /// `QuotaActorMissing`.
+    pub fn distill_batch(
+        &self,
+        role: RoleTier,
+        purpose: &str,
+        batch: &[(NeuromorphArtifact, DistillFlags)],
+    ) -> Vec<Result<DistilledKnowledge, OrchestrationError>> {
+        let contract = self.check_contract();
+        let mut results: Vec<Option<Result<DistilledKnowledge, OrchestrationError>>> =
+            vec![None; batch.len()];
+        let mut eligible = Vec::with_capacity(batch.len());
+        for (i, (artifact, flags)) in batch.iter().enumerate() {
+            let checked = self
+                .check_artifact(artifact)
+                .and_then(|()| contract.clone())
+                .and_then(|()| Self::check_eligibility(artifact, purpose, flags))
+                .and_then(|()| self.charge_quota(None).map(|_| ()));
+            match checked {
+                Ok(()) => eligible.push(i),
+                Err(e) => results[i] = Some(Err(e)),
+            }
         }
-        if !self.contract.has_sovereign_abort_control() {
-            return Err("SNC violation: sovereign abort control is mandatory.".into());
+
+        let artifacts: Vec<&NeuromorphArtifact> = eligible.iter().map(|&i| &batch[i].0).collect();
+        let mut refined = if artifacts.is_empty() {
+            Vec::new()
+        } else {
+            self.eco_source.calculate_batch_with_provenance(&artifacts)
+        }
+        .into_iter();
+        for &i in &eligible {
+            let (artifact, flags) = &batch[i];
+            let outcome = match refined.next() {
+                Some(eco) => eco
+                    .or_else(|e| {
+                        degrade_stale(e, self.eco_source.provenance_detail())
+                            .map(|(eco, provenance, _)| (eco, provenance))
+                    })
+                    .and_then(|(eco, provenance)| {
+                        self.score(role, artifact, flags, eco, None, &provenance)
+                    })
+                    .map(|(dk, _)| dk),
+                None => Err(OrchestrationError::EcoSourceFailure(EcoSourceError::Internal(format!(
+                    "batch returned no result for artifact {}",
+                    artifact.id
+                )))),
+            };
+            results[i] = Some(outcome);
+        }
+
+        let results: Vec<_> = results
+            .into_iter()
+            .map(|r| r.expect("every batch entry is either refused or scored"))
+            .collect();
+        for ((artifact, _), result) in batch.iter().zip(&results) {
+            self.audit(role, artifact, purpose, result.as_ref(), &[]);
+        }
+        results
+    }
+
+    /// The single checked path behind `distill`; the caller audits.
+    fn distill_unaudited(
+        &self,
+        request: &DistillRequest<'_>,
+    ) -> Result<(DistilledKnowledge, DistillationTrace), OrchestrationError> {
+        let DistillRequest { role, ref artifact, purpose, ref flags, overrides, actor_id, .. } = *request;
+        self.check_artifact(artifact)?;
+        self.check_contract()?;
+        if let Some(ctx) = &request.sovereign {
+            Self::check_sovereign_context(ctx)?;
         }
-        if !self.contract.is_discipline_personalized_and_non_coercive() {
-            return Err(
-                "SNC violation: discipline must be personalized and non-coercive.".into(),
+        Self::check_eligibility(artifact, purpose, flags)?;
+        let quota = self.charge_quota(actor_id)?;
+
+        // 4. EcoImpact: refine the artifact’s eco_impact via pluggable source.
+        let (report, eco_warning) = match self.eco_source.calculate_with_report(artifact) {
+            Ok(report) => (report, None),
+            Err(e) => {
+                let (metrics, provenance, warning) = degrade_stale(e, self.eco_source.provenance_detail())?;
+                (EcoImpactReport::generic(&metrics, provenance), Some(warning))
+            }
+        };
+
+        let (dk, mut trace) =
+            self.score(role, artifact, flags, report.metrics(), overrides, &report.provenance_info())?;
+        trace.quota = quota;
+        trace.eco_report = Some(report);
+        trace.eco_warning = eco_warning;
+        trace.restrictions = match request.band {
+            Some((band, now)) if matches!(dk.access_class, AccessClass::KnowledgeGated) => band
+                .restrictions_for(
+                    trace.components.knowledge_factor,
+                    trace.components.eco_impact,
+                    flags.has_biophysical_signal,
+                    now,
+                ),
+            _ => None,
+        };
+        Ok((dk, trace))
+    }
+}
+
+impl<C, E> NeuromorphOrchestrator<C, E>
+where
+    C: SovereignNeuromorphContract,
+    E: AsyncEcoDataSource,
+{
+    /// `distill_neuromorph_content` for async eco sources: the same checks,
+    /// scoring and trend recording, awaiting the eco lookup.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn distill_neuromorph_content_async(
+        &self,
+        role: RoleTier,
+        artifact: NeuromorphArtifact,
+        purpose: &str,
+        has_biophysical_signal: bool,
+        uses_discipline_signals: bool,
+        dual_empirical_formal_present: bool,
+        uncertainty_exposed: bool,
+    ) -> Result<DistilledKnowledge, OrchestrationError> {
+        let flags = DistillFlags {
+            has_biophysical_signal,
+            uses_discipline_signals,
+            dual_empirical_formal_present,
+            uncertainty_exposed,
+        };
+        let result = self.distill_async_unaudited(role, &artifact, purpose, &flags).await;
+        if let Some(sink) = &self.audit_sink {
+            let record = DistillationRecord::new(
+                &artifact,
+                role,
+                purpose,
+                result.as_ref(),
+                &[],
+                self.config_version.as_ref(),
             );
+            audit::record_async(sink.as_ref(), record).await;
         }
-        if !self.contract.forbids_downgrade_or_rollback() {
-            return Err("SNC violation: downgrades/rollbacks are forbidden.".into());
+        result
+    }
+
+    async fn distill_async_unaudited(
+        &self,
+        role: RoleTier,
+        artifact: &NeuromorphArtifact,
+        purpose: &str,
+        flags: &DistillFlags,
+    ) -> Result<DistilledKnowledge, OrchestrationError> {
+        self.check_artifact(artifact)?;
+        self.check_contract()?;
+        Self::check_eligibility(artifact, purpose, flags)?;
+        self.charge_quota(None)?;
+
+        let provenance = self.eco_source.provenance_detail();
+        let (eco_refined, provenance) = match self.eco_source.calculate(artifact).await {
+            Ok(eco) => (eco, provenance),
+            Err(e) => degrade_stale(e, provenance).map(|(eco, provenance, _)| (eco, provenance))?,
+        };
+
+        self.score(role, artifact, flags, eco_refined, None, &provenance)
+            .map(|(dk, _)| dk)
+    }
+}
+
+/// Build `DistilledKnowledge` from already-computed components. The eco
+/// dataset version and fetch time, when known, are folded into the hex stamp
+/// after the provenance label.
+pub fn distill_neuromorph_content_from_components<C: SovereignNeuromorphContract>(
+    contract: &C,
+    role: RoleTier,
+    has_biophysical_signal: bool,
+    uses_discipline_signals: bool,
+    knowledge_factor: f32,
+    access_class: AccessClass,
+    eco_provenance: &ProvenanceInfo,
+) -> Result<DistilledKnowledge, OrchestrationError> {
+    if !knowledge_factor.is_finite() {
+        return Err(OrchestrationError::InvalidKnowledgeFactor(
+            "Knowledge factor must be finite.".into(),
+        ));
+    }
+    let knowledge_factor = knowledge_factor.clamp(0.0, 1.0);
+
+    let neurorights_compliant = contract.has_explicit_consent()
+        && contract.has_sovereign_abort_control()
+        && contract.is_discipline_personalized_and_non_coercive()
+        && contract.forbids_downgrade_or_rollback();
+
+    let mut stamp_input = format!(
+        "{:?}|{:.6}|{:?}|{}|{}|{}",
+        role,
+        knowledge_factor,
+        access_class,
+        has_biophysical_signal,
+        uses_discipline_signals,
+        eco_provenance.label
+    );
+    // Only appended when present, so stamps without either are unchanged.
+    if eco_provenance.dataset_version.is_some() || eco_provenance.fetched_at.is_some() {
+        stamp_input.push_str(&format!(
+            "|{}|{}",
+            eco_provenance.dataset_version.as_deref().unwrap_or_default(),
+            eco_provenance.fetched_at.map(|t| t.to_string()).unwrap_or_default()
+        ));
+    }
+
+    Ok(DistilledKnowledge {
+        role,
+        knowledge_factor,
+        access_class,
+        hex_stamp: core_contract::hex_stamp(stamp_input.as_bytes()),
+        neurorights_compliant,
+        has_biophysical_signal,
+        uses_discipline_signals,
+        eco_provenance: eco_provenance.label.clone(),
+        eco_dataset_version: eco_provenance.dataset_version.clone(),
+        eco_fetched_at: eco_provenance.fetched_at,
+        data_labels: Default::default(),
+        config_version: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trace::AccessBranch;
+    use core_contract::care::CommunityId;
+    use core_contract::eco::{ArtifactViolation, CorridorId};
+    use core_contract::eco_mock::MockEcoSource;
+    use core_contract::labels::DataLabels;
+    use core_contract::DefaultSovereignNeuromorphContract;
+
+    /// Scores every artifact as declared.
+    fn fixed_eco() -> MockEcoSource {
+        MockEcoSource::new().with_label("fixed")
+    }
+
+    /// Fails every lookup for `labelled_artifact`'s corridor with `error`.
+    fn erring_eco(label: &'static str, error: EcoSourceError) -> MockEcoSource {
+        MockEcoSource::new().with_label(label).with_error(labelled_artifact().corridor_id.0, error)
+    }
+
+    fn orchestrator() -> NeuromorphOrchestrator<DefaultSovereignNeuromorphContract, MockEcoSource> {
+        NeuromorphOrchestrator::new(DefaultSovereignNeuromorphContract::new(true, true, true), fixed_eco())
+    }
+
+    fn labelled_artifact() -> NeuromorphArtifact {
+        NeuromorphArtifact {
+            id: "artifact-tk".into(),
+            corridor_id: CorridorId("indigenous-phoenix-water-shed".into()),
+            eco_impact: EcoImpactMetrics {
+                climate_score: 0.95,
+                biodiversity_score: 0.95,
+                biosphere_score: 0.95,
+                corridor_score: 0.95,
+            },
+            summary: "Seasonal water knowledge".into(),
+            data_labels: DataLabels {
+                notices: vec!["TK Attribution".into(), "TK Non-Commercial".into()],
+                allowed_purposes: vec!["education".into(), "research".into()],
+                prohibited_purposes: vec!["commercial".into()],
+                steward_community: Some(CommunityId("indigenous-phoenix-water-shed".into())),
+            },
         }
+    }
+
+    #[test]
+    fn invalid_artifact_is_refused_before_contract_checks() {
+        let mut artifact = labelled_artifact();
+        artifact.corridor_id.0.clear();
+        artifact.eco_impact.climate_score = f32::NAN;
+        let no_consent = NeuromorphOrchestrator::new(
+            DefaultSovereignNeuromorphContract::new(false, true, true),
+            fixed_eco(),
+        );
+        let err = no_consent
+            .distill_neuromorph_content(RoleTier::Researcher, artifact, "education", false, false, true, true)
+            .unwrap_err();
+        let OrchestrationError::InvalidArtifact(violations) = &err else {
+            panic!("expected InvalidArtifact, got {err:?}");
+        };
+        assert_eq!(violations.len(), 2);
+        assert_eq!(err.code(), "InvalidArtifact");
+        assert!(err.to_string().starts_with("Invalid artifact: corridor id is empty; climate_score"));
+    }
+
+    #[test]
+    fn summary_cap_is_configurable_and_batch_refuses_per_entry() {
+        let capped = orchestrator().with_max_summary_len(8);
+        let flags = DistillFlags {
+            dual_empirical_formal_present: true,
+            uncertainty_exposed: true,
+            ..DistillFlags::default()
+        };
+        let mut short = labelled_artifact();
+        short.summary = "Seasonal".into();
+        let results = capped.distill_batch(
+            RoleTier::Researcher,
+            "education",
+            &[(labelled_artifact(), flags), (short, flags)],
+        );
+        assert_eq!(
+            results[0].as_ref().unwrap_err(),
+            &OrchestrationError::InvalidArtifact(vec![ArtifactViolation::SummaryTooLong {
+                len: 24,
+                max: 8
+            }])
+        );
+        assert!(results[1].is_ok());
+    }
+
+    #[test]
+    fn quota_meters_actors_and_reports_remaining_budget() {
+        let metered = orchestrator().with_quota(quota::InMemoryQuota::new(
+            std::time::Duration::from_secs(60),
+            1,
+            10,
+        ));
+        let flags = DistillFlags {
+            dual_empirical_formal_present: true,
+            uncertainty_exposed: true,
+            ..DistillFlags::default()
+        };
+        let distill = |actor, purpose| {
+            metered.distill(DistillRequest {
+                actor_id: Some(actor),
+                ..DistillRequest::new(RoleTier::Learner, labelled_artifact(), purpose, flags)
+            })
+        };
+
+        // Refused before the eco source: not charged.
+        assert!(matches!(
+            distill("learner-1", "commercial"),
+            Err(OrchestrationError::PurposeProhibited { .. })
+        ));
+        let (_, trace) = distill("learner-1", "education").unwrap();
+        assert_eq!(trace.quota, Some(quota::QuotaRemaining { window: 0, daily: 9 }));
+
+        let err = distill("learner-1", "education").unwrap_err();
+        let OrchestrationError::QuotaExceeded { retry_after } = err else {
+            panic!("expected QuotaExceeded, got {err:?}");
+        };
+        assert!(!retry_after.is_zero() && retry_after <= std::time::Duration::from_secs(60));
+        assert_eq!(err.code(), "QuotaExceeded");
+
+        assert!(distill("learner-2", "education").is_ok());
+
+        // Metered orchestrators refuse calls that name no actor, on every path.
+        let anonymous = metered.distill(DistillRequest::new(RoleTier::Learner, labelled_artifact(), "education", flags));
+        assert_eq!(anonymous.unwrap_err(), OrchestrationError::QuotaActorMissing);
+        assert_eq!(
+            metered
+                .distill_neuromorph_content(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true)
+                .unwrap_err()
+                .code(),
+            "QuotaActorMissing"
+        );
+        let batch = metered.distill_batch(RoleTier::Learner, "education", &[(labelled_artifact(), flags)]);
+        assert_eq!(batch, [Err(OrchestrationError::QuotaActorMissing)]);
+    }
 
-        // 2. CHAT eligibility: dual empirical + formal, uncertainty required.[file:55]
-        if !dual_empirical_formal_present {
-            return Err("CHAT-ineligible: missing dual empirical + formal linkage.".into());
+    /// Community contract that tolerates rollbacks.
+    struct PermitsRollback;
+
+    impl SovereignNeuromorphContract for PermitsRollback {
+        fn has_explicit_consent(&self) -> bool {
+            true
         }
-        if !uncertainty_exposed {
-            return Err("CHAT-ineligible: uncertainty must be exposed.".into());
+
+        fn has_sovereign_abort_control(&self) -> bool {
+            true
         }
 
-        // 3. EcoImpact: refine the artifact’s eco_impact via pluggable source.
-        let eco_refined: EcoImpactMetrics = self
-            .eco_source
-            .calculate(&artifact)
-            .map_err(|e| format!("EcoImpact error: {e}"))?;
+        fn is_discipline_personalized_and_non_coercive(&self) -> bool {
+            true
+        }
 
-        // 4. Knowledge-factor components: V, R, E, N.[file:69]
-        let validation = 0.9_f32;
-        let reuse = 0.6_f32;
-        let eco_impact = eco_refined.scalar().clamp(0.0, 1.0);
-        let novelty = 0.7_f32;
+        fn forbids_downgrade_or_rollback(&self) -> bool {
+            false
+        }
+    }
 
-        let fk = (validation * reuse * eco_impact * novelty).clamp(0.0, 1.0);
+    #[test]
+    fn composite_refusal_names_the_failing_member() {
+        let composite = core_contract::composite::CompositeContract::new()
+            .with_member("institutional", DefaultSovereignNeuromorphContract::new(true, true, true))
+            .with_member("community", PermitsRollback);
+        let err = NeuromorphOrchestrator::new(composite, fixed_eco())
+            .distill_neuromorph_content(RoleTier::Researcher, labelled_artifact(), "education", false, false, true, true)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            OrchestrationError::ContractMemberRefused {
+                member: "community".into(),
+                predicate: SncPredicate::ForbidsDowngradeOrRollback,
+            }
+        );
+        assert_eq!(err.code(), "DowngradeForbidden");
+        assert_eq!(
+            err.to_string(),
+            "SNC violation: downgrades/rollbacks are forbidden (contract member 'community')."
+        );
+    }
 
-        // 5. Access class: ecological risk + neuromorphic sensitivity.[file:69]
-        let access_class = if has_biophysical_signal || uses_discipline_signals {
-            match role {
-                RoleTier::Teacher | RoleTier::Mentor | RoleTier::Researcher => {
-                    AccessClass::HighAutonomy
-                }
-                RoleTier::Learner => AccessClass::KnowledgeGated,
+    #[test]
+    fn prohibited_purpose_is_refused_before_scoring() {
+        let err = orchestrator()
+            .distill_neuromorph_content(RoleTier::Researcher, labelled_artifact(), "commercial", false, false, true, true)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            OrchestrationError::PurposeProhibited {
+                purpose: "commercial".into(),
+                artifact_id: "artifact-tk".into(),
             }
-        } else if fk >= 0.75 && eco_impact >= 0.8 {
-            AccessClass::Open
-        } else {
-            AccessClass::KnowledgeGated
+        );
+        assert!(err.to_string().starts_with(PURPOSE_PROHIBITED));
+
+        // Purposes missing from a non-empty allow-list are refused as well.
+        let err = orchestrator()
+            .distill_neuromorph_content(RoleTier::Researcher, labelled_artifact(), "advertising", false, false, true, true)
+            .unwrap_err();
+        assert!(matches!(err, OrchestrationError::PurposeProhibited { .. }));
+    }
+
+    #[test]
+    fn allowed_purpose_passes_and_labels_propagate() {
+        let artifact = labelled_artifact();
+        let dk = orchestrator()
+            .distill_neuromorph_content(RoleTier::Learner, artifact.clone(), "education", false, false, true, true)
+            .unwrap();
+        assert_eq!(dk.data_labels, artifact.data_labels);
+
+        let now = SystemTime::now();
+        let band = GraduatedBand {
+            fk_min: 0.0,
+            ..GraduatedBand::default()
+        };
+        let flags = DistillFlags {
+            has_biophysical_signal: true,
+            dual_empirical_formal_present: true,
+            uncertainty_exposed: true,
+            ..DistillFlags::default()
+        };
+        let request = DistillRequest {
+            band: Some((&band, now)),
+            ..DistillRequest::new(RoleTier::Learner, artifact.clone(), "research", flags)
         };
+        match orchestrator().distill_graduated(request) {
+            AccessOutcome::GrantRestricted { dk, restrictions } => {
+                assert_eq!(dk.data_labels, artifact.data_labels);
+                let redacted = graduated::apply_restrictions(&artifact, &restrictions, now);
+                assert!(redacted.summary.is_none());
+                assert_eq!(redacted.data_labels, artifact.data_labels);
+            }
+            other => panic!("expected a restricted grant, got {other:?}"),
+        }
+    }
 
-        // 6. Delegate to existing DistilledKnowledge constructor.
-        crate::distill_neuromorph_content_from_components(
-            &self.contract,
-            role,
-            has_biophysical_signal,
-            uses_discipline_signals,
-            fk,
-            access_class,
-            self.eco_source.provenance_label(),
+    #[test]
+    fn refusals_surface_as_distinct_variants() {
+        let refuse = |consent, abort, non_coercive, dual, uncertainty| {
+            NeuromorphOrchestrator::new(DefaultSovereignNeuromorphContract::new(consent, abort, non_coercive), fixed_eco())
+                .distill_neuromorph_content(RoleTier::Learner, labelled_artifact(), "education", false, false, dual, uncertainty)
+                .unwrap_err()
+        };
+        assert_eq!(refuse(false, true, true, true, true), OrchestrationError::MissingConsent);
+        assert_eq!(refuse(true, false, true, true, true), OrchestrationError::NoAbortControl);
+        assert_eq!(refuse(true, true, false, true, true), OrchestrationError::CoerciveDiscipline);
+        assert_eq!(refuse(true, true, true, false, true), OrchestrationError::ChatMissingDualLinkage);
+        assert_eq!(refuse(true, true, true, true, false), OrchestrationError::ChatUncertaintyNotExposed);
+
+        let failing = erring_eco("failing", EcoSourceError::UpstreamTimeout);
+        let err = NeuromorphOrchestrator::new(DefaultSovereignNeuromorphContract::new(true, true, true), failing)
+            .distill_neuromorph_content(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true)
+            .unwrap_err();
+        assert_eq!(err, OrchestrationError::EcoSourceFailure(EcoSourceError::UpstreamTimeout));
+        assert_eq!(err.to_string(), "EcoImpact error: upstream timeout");
+    }
+
+    /// Batching source that looks each corridor up once per batch.
+    #[derive(Default)]
+    struct CorridorBatchEco {
+        lookups: std::cell::Cell<usize>,
+    }
+
+    impl EcoDataSource for CorridorBatchEco {
+        fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+            self.lookups.set(self.lookups.get() + 1);
+            Ok(artifact.eco_impact.clone())
+        }
+
+        fn calculate_batch(
+            &self,
+            artifacts: &[&NeuromorphArtifact],
+        ) -> Vec<Result<EcoImpactMetrics, EcoSourceError>> {
+            let mut corridors = std::collections::HashMap::new();
+            artifacts
+                .iter()
+                .map(|a| {
+                    let metrics = corridors
+                        .entry(a.corridor_id.clone())
+                        .or_insert_with(|| self.calculate(a));
+                    metrics.clone()
+                })
+                .collect()
+        }
+
+        fn provenance_label(&self) -> &'static str {
+            "corridor-batch"
+        }
+    }
+
+    #[test]
+    fn batch_shares_corridor_lookups_and_keeps_input_order() {
+        let eligible = DistillFlags {
+            dual_empirical_formal_present: true,
+            uncertainty_exposed: true,
+            ..DistillFlags::default()
+        };
+        let mut second = labelled_artifact();
+        second.id = "artifact-tk-2".into();
+        let mut refused = labelled_artifact();
+        refused.id = "artifact-no-chat".into();
+        let batch = vec![
+            (labelled_artifact(), eligible),
+            (refused, DistillFlags::default()),
+            (second, eligible),
+        ];
+
+        let orchestrator = NeuromorphOrchestrator::new(DefaultSovereignNeuromorphContract::new(true, true, true), CorridorBatchEco::default());
+        let results = orchestrator.distill_batch(RoleTier::Learner, "education", &batch);
+        assert_eq!(results.len(), 3);
+        assert_eq!(orchestrator.eco_source.lookups.get(), 1);
+
+        let single = orchestrator
+            .distill_neuromorph_content(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true)
+            .unwrap();
+        assert_eq!(results[0].as_ref().unwrap().hex_stamp, single.hex_stamp);
+        assert_eq!(results[1].as_ref().unwrap_err(), &OrchestrationError::ChatMissingDualLinkage);
+        assert_eq!(results[2].as_ref().unwrap().hex_stamp, single.hex_stamp);
+
+        // A contract failure refuses every entry without touching the eco source.
+        let unconsented = NeuromorphOrchestrator::new(DefaultSovereignNeuromorphContract::new(false, true, true), CorridorBatchEco::default());
+        let results = unconsented.distill_batch(RoleTier::Learner, "education", &batch);
+        assert!(results.iter().all(|r| r.as_ref().err() == Some(&OrchestrationError::MissingConsent)));
+        assert_eq!(unconsented.eco_source.lookups.get(), 0);
+    }
+
+    fn assert_send<T: Send>(value: T) -> T {
+        value
+    }
+
+    #[tokio::test]
+    async fn async_distillation_matches_the_sync_path() {
+        use core_contract::eco_source::SyncEcoAdapter;
+
+        let contract = || DefaultSovereignNeuromorphContract::new(true, true, true);
+        let sync = orchestrator()
+            .distill_neuromorph_content(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true)
+            .unwrap();
+
+        let adapted = NeuromorphOrchestrator::new(contract(), SyncEcoAdapter(fixed_eco()));
+        let dk = assert_send(adapted.distill_neuromorph_content_async(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true))
+            .await
+            .unwrap();
+        assert_eq!(dk.hex_stamp, sync.hex_stamp);
+
+        // Answers after a delay, like an HTTP call.
+        let latency = std::time::Duration::from_millis(5);
+        let remote = NeuromorphOrchestrator::new(contract(), fixed_eco().with_latency(latency));
+        let dk = remote
+            .distill_neuromorph_content_async(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true)
+            .await
+            .unwrap();
+        assert_eq!(dk.hex_stamp, sync.hex_stamp);
+
+        let rejected = EcoSourceError::UpstreamRejected { status: 503 };
+        let err = NeuromorphOrchestrator::new(contract(), erring_eco("fixed", rejected).with_latency(latency))
+            .distill_neuromorph_content_async(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            OrchestrationError::EcoSourceFailure(EcoSourceError::UpstreamRejected { status: 503 })
+        );
+
+        // Contract and label checks still run before the source is awaited.
+        let err = remote
+            .distill_neuromorph_content_async(RoleTier::Learner, labelled_artifact(), "commercial", false, false, true, true)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, OrchestrationError::PurposeProhibited { .. }));
+        assert_eq!(remote.eco_source.call_count(), 1);
+        // The async path names no actor, so a metered orchestrator refuses it.
+        let metered = remote.with_quota(quota::InMemoryQuota::new(std::time::Duration::from_secs(60), 5, 10));
+        let err = metered
+            .distill_neuromorph_content_async(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true)
+            .await
+            .unwrap_err();
+        assert_eq!(err, OrchestrationError::QuotaActorMissing);
+        assert_eq!(metered.eco_source.call_count(), 1);
+    }
+
+    #[test]
+    fn breakdown_explains_the_access_class() {
+        let config = knowledge::KnowledgeFactorConfig {
+            validation: 1.0,
+            reuse: 1.0,
+            novelty: 1.0,
+            ..Default::default()
+        };
+        let orchestrator = orchestrator().with_knowledge_factor(config).unwrap();
+
+        let (dk, breakdown) = orchestrator
+            .distill_with_breakdown(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true, None)
+            .unwrap();
+        assert_eq!(dk.access_class, AccessClass::Open);
+        assert_eq!(dk.knowledge_factor, breakdown.knowledge_factor);
+        assert_eq!(breakdown.eco_impact, labelled_artifact().eco_impact.scalar());
+        assert_eq!(breakdown.knowledge_factor, breakdown.eco_impact);
+
+        // A zero override drops F_K to 0 and the artifact back to KnowledgeGated.
+        let overrides = KnowledgeOverrides {
+            novelty: Some(0.0),
+            ..Default::default()
+        };
+        let (dk, breakdown) = orchestrator
+            .distill_with_breakdown(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true, Some(&overrides))
+            .unwrap();
+        assert_eq!(breakdown.knowledge_factor, 0.0);
+        assert_eq!(dk.access_class, AccessClass::KnowledgeGated);
+
+        let invalid = knowledge::KnowledgeFactorConfig {
+            reuse: 1.5,
+            ..Default::default()
+        };
+        assert!(NeuromorphOrchestrator::new(DefaultSovereignNeuromorphContract::new(true, true, true), fixed_eco())
+            .with_knowledge_factor(invalid)
+            .is_err_and(|e| matches!(e, OrchestrationError::InvalidKnowledgeFactor(_))));
+    }
+
+    #[test]
+    fn trace_matches_each_access_class_branch() {
+        let full = knowledge::KnowledgeFactorConfig {
+            validation: 1.0,
+            reuse: 1.0,
+            novelty: 1.0,
+            ..Default::default()
+        };
+        let traced = |config: knowledge::KnowledgeFactorConfig, role, artifact, biophysical| {
+            let (dk, trace) = orchestrator()
+                .with_knowledge_factor(config)
+                .unwrap()
+                .distill_with_trace(role, artifact, "education", biophysical, false, true, true, None)
+                .unwrap();
+            assert_eq!(trace.access_class, dk.access_class);
+            assert_eq!(trace.components.knowledge_factor, dk.knowledge_factor);
+            assert_eq!(trace.eco_provenance, "fixed");
+            trace
+        };
+
+        let trace = traced(full.clone(), RoleTier::Learner, labelled_artifact(), false);
+        assert_eq!(trace.branch, AccessBranch::OpenThresholdsMet);
+        assert_eq!(trace.access_class, AccessClass::Open);
+
+        // The default components cap F_K below the Open floor; EcoImpact clears it.
+        let trace = traced(Default::default(), RoleTier::Learner, labelled_artifact(), false);
+        assert_eq!(
+            trace.branch,
+            AccessBranch::BelowOpenThresholds {
+                knowledge_factor_short: true,
+                eco_impact_short: false,
+            }
+        );
+        assert_eq!(trace.access_class, AccessClass::KnowledgeGated);
+
+        let mut degraded = labelled_artifact();
+        degraded.eco_impact.corridor_score = 0.0;
+        let trace = traced(full.clone(), RoleTier::Learner, degraded, false);
+        assert_eq!(
+            trace.branch,
+            AccessBranch::BelowOpenThresholds {
+                knowledge_factor_short: true,
+                eco_impact_short: true,
+            }
+        );
+        assert!(trace.components.eco_impact < OPEN_MIN_ECO_IMPACT);
+
+        // A biophysical signal overrides the thresholds; the role tier decides.
+        let trace = traced(full.clone(), RoleTier::Learner, labelled_artifact(), true);
+        assert_eq!(trace.branch, AccessBranch::SensitiveSignal { role: RoleTier::Learner });
+        assert_eq!(trace.access_class, AccessClass::KnowledgeGated);
+        assert!(trace.has_biophysical_signal);
+
+        let trace = traced(full, RoleTier::Researcher, labelled_artifact(), true);
+        assert_eq!(trace.branch, AccessBranch::SensitiveSignal { role: RoleTier::Researcher });
+        assert_eq!(trace.access_class, AccessClass::HighAutonomy);
+    }
+
+    #[test]
+    fn trace_embeds_the_eco_report() {
+        use core_contract::eco::{EcoImpactReport, MetricExplanation};
+
+        /// Explains its metrics and scores biodiversity lower than `calculate`.
+        struct ExplainedEco;
+
+        impl EcoDataSource for ExplainedEco {
+            fn calculate(&self, artifact: &NeuromorphArtifact) -> Result<EcoImpactMetrics, EcoSourceError> {
+                Ok(artifact.eco_impact.clone())
+            }
+
+            fn provenance_label(&self) -> &'static str {
+                "explained"
+            }
+
+            fn calculate_with_report(
+                &self,
+                artifact: &NeuromorphArtifact,
+            ) -> Result<EcoImpactReport, EcoSourceError> {
+                let mut report = EcoImpactReport::generic(&artifact.eco_impact, "explained");
+                report.biodiversity = MetricExplanation {
+                    value: 0.5,
+                    explanation: "two red-listed taxa recorded".into(),
+                    sources: vec!["gbif:dataset/d-1".into()],
+                };
+                Ok(report)
+            }
+        }
+
+        let (dk, trace) = NeuromorphOrchestrator::new(
+            DefaultSovereignNeuromorphContract::new(true, true, true),
+            ExplainedEco,
         )
+        .distill_with_trace(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true, None)
+        .unwrap();
+        let report = trace.eco_report.as_ref().unwrap();
+        assert_eq!(report.biodiversity.explanation, "two red-listed taxa recorded");
+        assert_eq!(dk.eco_provenance, "explained");
+        // Scoring uses the reported values.
+        assert_eq!(trace.components.eco_impact, report.metrics().scalar());
+
+        // Sources without reasons still get a generic report.
+        let (_, trace) = orchestrator()
+            .distill_with_trace(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true, None)
+            .unwrap();
+        assert_eq!(
+            trace.eco_report.unwrap().climate.explanation,
+            "reported by fixed without an explanation"
+        );
+    }
+
+    #[test]
+    fn stale_eco_data_degrades_while_unknown_corridors_refuse() {
+        let stale = EcoSourceError::StaleData {
+            age: std::time::Duration::from_secs(86_400),
+            metrics: labelled_artifact().eco_impact,
+        };
+        let contract = || DefaultSovereignNeuromorphContract::new(true, true, true);
+        let (dk, trace) = NeuromorphOrchestrator::new(contract(), erring_eco("erring", stale.clone()))
+            .distill_with_trace(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true, None)
+            .unwrap();
+        assert_eq!(dk.eco_provenance, "erring[stale]");
+        assert_eq!(
+            trace.eco_warning.as_deref(),
+            Some("eco data is stale (86400 s old); scored in degraded mode")
+        );
+        assert_eq!(trace.components.eco_impact, labelled_artifact().eco_impact.scalar());
+        let fresh = orchestrator()
+            .distill_with_trace(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true, None)
+            .unwrap();
+        assert_eq!(fresh.1.eco_warning, None);
+
+        let batch = NeuromorphOrchestrator::new(contract(), erring_eco("erring", stale))
+            .distill_batch(RoleTier::Learner, "education", &[(labelled_artifact(), DistillFlags {
+                dual_empirical_formal_present: true,
+                uncertainty_exposed: true,
+                ..DistillFlags::default()
+            })]);
+        assert_eq!(batch[0].as_ref().unwrap().eco_provenance, "erring[stale]");
+
+        let unknown = EcoSourceError::UnknownCorridor(labelled_artifact().corridor_id);
+        let err = NeuromorphOrchestrator::new(contract(), fixed_eco().with_unknown_corridors_refused())
+            .distill_neuromorph_content(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true)
+            .unwrap_err();
+        assert_eq!(err, OrchestrationError::EcoSourceFailure(unknown));
+        assert_eq!(err.code(), "EcoSourceFailure");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn trace_round_trips_through_json() {
+        let (_, trace) = orchestrator()
+            .distill_with_trace(RoleTier::Mentor, labelled_artifact(), "research", false, true, true, true, None)
+            .unwrap();
+        let json = serde_json::to_value(&trace).unwrap();
+        assert_eq!(json["branch"]["SensitiveSignal"]["role"], "Mentor");
+        assert_eq!(json["access_class"], "HighAutonomy");
+        assert_eq!(json["eco_provenance"], "fixed");
+        assert_eq!(json["eco_report"]["provenance"], "fixed");
+        assert!(json["components"]["knowledge_factor"].is_number());
+
+        let back: DistillationTrace = serde_json::from_value(json).unwrap();
+        assert_eq!(back, trace);
+    }
+
+    #[test]
+    fn sovereign_context_refuses_before_the_eco_source() {
+        use core_contract::care::CareAttestation;
+        use core_contract::fpic::FpicToken;
+        use std::time::{Duration, UNIX_EPOCH};
+
+        let granted_at = UNIX_EPOCH + Duration::from_secs(1_000);
+        let max_age = Duration::from_secs(60);
+        let token = FpicToken::new(granted_at, max_age);
+        let care = CareAttestation {
+            collective_benefit: true,
+            authority_to_control: true,
+            responsibility: true,
+            ethics: true,
+            proof_ref: None,
+        };
+        let flags = DistillFlags {
+            dual_empirical_formal_present: true,
+            uncertainty_exposed: true,
+            ..DistillFlags::default()
+        };
+        let orchestrator = NeuromorphOrchestrator::new(DefaultSovereignNeuromorphContract::new(true, true, true), CorridorBatchEco::default());
+        let distill = |fpic: &FpicToken, care: &CareAttestation, now| {
+            let ctx = SovereignContext { fpic, care, now };
+            orchestrator.distill(DistillRequest {
+                sovereign: Some(ctx),
+                ..DistillRequest::new(RoleTier::Learner, labelled_artifact(), "education", flags)
+            })
+        };
+
+        // `granted_at + max_age == now` is still fresh.
+        assert!(distill(&token, &care, granted_at + max_age).is_ok());
+        assert_eq!(orchestrator.eco_source.lookups.get(), 1);
+
+        assert_eq!(
+            distill(&token, &care, granted_at + max_age + Duration::from_nanos(1)).unwrap_err(),
+            OrchestrationError::FpicExpired
+        );
+        let mut vetoed = token.clone();
+        vetoed.veto();
+        assert_eq!(
+            distill(&vetoed, &care, granted_at).unwrap_err(),
+            OrchestrationError::FpicWithdrawn(FpicStatus::Revoked)
+        );
+        let partial = CareAttestation { ethics: false, ..care.clone() };
+        assert_eq!(distill(&token, &partial, granted_at).unwrap_err(), OrchestrationError::CareMisaligned);
+        assert_eq!(orchestrator.eco_source.lookups.get(), 1);
+    }
+
+    /// Corridor policy that never releases content as Open.
+    struct NeverOpen;
+
+    impl AccessClassPolicy for NeverOpen {
+        fn classify(&self, role: RoleTier, fk: f32, eco: f32, flags: &DistillFlags) -> AccessClass {
+            match ThresholdAccessPolicy::default().classify(role, fk, eco, flags) {
+                AccessClass::Open => AccessClass::KnowledgeGated,
+                other => other,
+            }
+        }
+    }
+
+    #[test]
+    fn custom_access_policy_replaces_the_thresholds() {
+        let config = knowledge::KnowledgeFactorConfig {
+            validation: 1.0,
+            reuse: 1.0,
+            novelty: 1.0,
+            ..Default::default()
+        };
+        let distill = |orchestrator: NeuromorphOrchestrator<_, _>| {
+            orchestrator
+                .with_knowledge_factor(config.clone())
+                .unwrap()
+                .distill_with_trace(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true, None)
+                .unwrap()
+        };
+
+        let (dk, _) = distill(orchestrator());
+        assert_eq!(dk.access_class, AccessClass::Open);
+
+        let (dk, trace) = distill(orchestrator().with_access_policy(NeverOpen));
+        assert_eq!(dk.access_class, AccessClass::KnowledgeGated);
+        assert_eq!(trace.branch, AccessBranch::Custom);
+        assert_eq!(trace.components.knowledge_factor, dk.knowledge_factor);
+    }
+
+    /// Sink that keeps every record and can be told to fail.
+    #[derive(Clone, Default)]
+    struct MemorySink {
+        fail: bool,
+        records: std::sync::Arc<Mutex<Vec<DistillationRecord>>>,
+        failures: std::sync::Arc<Mutex<Vec<String>>>,
+    }
+
+    impl DistillationAuditSink for MemorySink {
+        fn record<'a>(&'a self, record: &'a DistillationRecord) -> audit::AuditFuture<'a> {
+            Box::pin(async move {
+                if self.fail {
+                    return Err("ledger unavailable".to_string());
+                }
+                self.records.lock().unwrap().push(record.clone());
+                Ok(())
+            })
+        }
+
+        fn on_record_failed(&self, record: &DistillationRecord, error: &str) {
+            self.failures.lock().unwrap().push(format!("{}: {error}", record.artifact_id));
+        }
+    }
+
+    #[test]
+    fn audit_sink_sees_grants_and_refusals_without_changing_them() {
+        let sink = MemorySink::default();
+        let audited = orchestrator().with_audit_sink(sink.clone());
+        audited
+            .distill_neuromorph_content(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true)
+            .unwrap();
+        audited
+            .distill_neuromorph_content(RoleTier::Learner, labelled_artifact(), "commercial", false, false, true, true)
+            .unwrap_err();
+
+        let records = sink.records.lock().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].artifact_id, "artifact-tk");
+        assert_eq!(records[0].corridor_id, "indigenous-phoenix-water-shed");
+        assert_eq!(records[0].data_labels, labelled_artifact().data_labels);
+        assert!(matches!(records[0].outcome, audit::DistillationOutcome::Distilled { .. }));
+        assert!(matches!(
+            records[1].outcome,
+            audit::DistillationOutcome::Refused { code: PURPOSE_PROHIBITED, .. }
+        ));
+
+        // A failing sink is reported but the grant still stands.
+        let failing = MemorySink { fail: true, ..MemorySink::default() };
+        let dk = orchestrator()
+            .with_audit_sink(failing.clone())
+            .distill_neuromorph_content(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true);
+        assert!(dk.is_ok());
+        assert_eq!(*failing.failures.lock().unwrap(), ["artifact-tk: ledger unavailable"]);
+    }
+
+    /// Store that keeps the tracker in memory and can be told to fail.
+    #[derive(Clone, Default)]
+    struct MemoryTrendStore {
+        fail: bool,
+        saved: std::sync::Arc<Mutex<Option<EcoTrendTracker>>>,
+        failures: std::sync::Arc<Mutex<Vec<String>>>,
+    }
+
+    impl EcoTrendStore for MemoryTrendStore {
+        fn load(&self) -> Result<Option<EcoTrendTracker>, String> {
+            Ok(self.saved.lock().unwrap().clone())
+        }
+
+        fn save(&self, tracker: &EcoTrendTracker) -> Result<(), String> {
+            if self.fail {
+                return Err("disk full".to_string());
+            }
+            *self.saved.lock().unwrap() = Some(tracker.clone());
+            Ok(())
+        }
+    }
+
+    impl EcoTrendObserver for MemoryTrendStore {
+        fn on_drift_alert(&self, _: &core_contract::eco_trend::EcoDriftAlert) {}
+
+        fn on_store_failed(&self, error: &str) {
+            self.failures.lock().unwrap().push(error.to_string());
+        }
+    }
+
+    #[test]
+    fn stored_eco_trend_survives_a_new_orchestrator() {
+        let store = MemoryTrendStore::default();
+        let corridor = labelled_artifact().corridor_id;
+        for expected in 1..=2 {
+            let orchestrator = orchestrator()
+                .with_stored_eco_trend(EcoTrendConfig::default(), store.clone(), Box::new(store.clone()))
+                .unwrap();
+            orchestrator
+                .distill_neuromorph_content(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true)
+                .unwrap();
+            assert_eq!(orchestrator.eco_trend().unwrap().series(&corridor).len(), expected);
+        }
+        assert_eq!(store.saved.lock().unwrap().as_ref().unwrap().series(&corridor).len(), 2);
+
+        // A failing store is reported but the sample is still tracked.
+        let failing = MemoryTrendStore { fail: true, ..MemoryTrendStore::default() };
+        let orchestrator = orchestrator()
+            .with_stored_eco_trend(EcoTrendConfig::default(), failing.clone(), Box::new(failing.clone()))
+            .unwrap();
+        orchestrator
+            .distill_neuromorph_content(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true)
+            .unwrap();
+        assert_eq!(orchestrator.eco_trend().unwrap().series(&corridor).len(), 1);
+        assert_eq!(*failing.failures.lock().unwrap(), ["disk full"]);
+    }
+
+    #[test]
+    fn audit_records_carry_the_config_version() {
+        let sink = MemorySink::default();
+        let version = ConfigVersion::new("c0ffee", SystemTime::UNIX_EPOCH, None);
+        orchestrator()
+            .with_audit_sink(sink.clone())
+            .with_config_version(version.clone())
+            .distill_neuromorph_content(RoleTier::Learner, labelled_artifact(), "commercial", false, false, true, true)
+            .unwrap_err();
+        orchestrator()
+            .with_audit_sink(sink.clone())
+            .distill_neuromorph_content(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true)
+            .unwrap();
+
+        let records = sink.records.lock().unwrap();
+        assert_eq!(records[0].config_version, Some(version));
+        assert_eq!(records[1].config_version, None);
+    }
+
+    #[test]
+    fn one_request_carries_context_actor_and_band() {
+        use core_contract::care::CareAttestation;
+        use core_contract::fpic::FpicToken;
+
+        let sink = MemorySink::default();
+        let orchestrator = orchestrator()
+            .with_audit_sink(sink.clone())
+            .with_quota(quota::InMemoryQuota::new(std::time::Duration::from_secs(60), 5, 10));
+        let now = SystemTime::now();
+        let token = FpicToken::new(now, std::time::Duration::from_secs(60));
+        let mut vetoed = token.clone();
+        vetoed.veto();
+        let care = CareAttestation {
+            collective_benefit: true,
+            authority_to_control: true,
+            responsibility: true,
+            ethics: true,
+            proof_ref: None,
+        };
+        let band = GraduatedBand { fk_min: 0.0, ..GraduatedBand::default() };
+        let flags = DistillFlags {
+            has_biophysical_signal: true,
+            dual_empirical_formal_present: true,
+            uncertainty_exposed: true,
+            ..DistillFlags::default()
+        };
+        let request = |fpic| DistillRequest {
+            actor_id: Some("learner-1"),
+            sovereign: Some(SovereignContext { fpic, care: &care, now }),
+            band: Some((&band, now)),
+            ..DistillRequest::new(RoleTier::Learner, labelled_artifact(), "research", flags)
+        };
+
+        // Refused on the sovereign context: not charged, audited once.
+        assert!(matches!(
+            orchestrator.distill_graduated(request(&vetoed)),
+            AccessOutcome::Refuse(OrchestrationError::FpicWithdrawn(_))
+        ));
+        let (_, trace) = orchestrator.distill(request(&token)).unwrap();
+        assert_eq!(trace.quota, Some(quota::QuotaRemaining { window: 4, daily: 9 }));
+        let restrictions = trace.restrictions.expect("inside the band");
+
+        let records = sink.records.lock().unwrap();
+        assert_eq!(records.len(), 2);
+        assert!(matches!(
+            &records[1].outcome,
+            audit::DistillationOutcome::Distilled { restrictions: recorded, .. } if *recorded == restrictions
+        ));
+    }
+
+    #[test]
+    fn fallback_provenance_reaches_distilled_knowledge() {
+        use core_contract::eco_source::FallbackEcoSource;
+
+        let failing = erring_eco("failing", EcoSourceError::UpstreamTimeout);
+        let chain = FallbackEcoSource::new().with_source(failing, 1).with_source(fixed_eco(), 0);
+        let (dk, trace) = NeuromorphOrchestrator::new(DefaultSovereignNeuromorphContract::new(true, true, true), chain)
+            .distill_with_trace(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true, None)
+            .unwrap();
+        assert_eq!(dk.eco_provenance, "failing→fixed");
+        assert_eq!(trace.eco_provenance, dk.eco_provenance);
+    }
+
+    #[test]
+    fn dataset_version_and_fetch_time_reach_distilled_knowledge() {
+        let detail = ProvenanceInfo::new("fixed").with_dataset_version("v3").with_fetched_at(1_762_041_600);
+        let versioned = fixed_eco().with_provenance_detail(detail);
+        let contract = DefaultSovereignNeuromorphContract::new(true, true, true);
+        let (dk, trace) = NeuromorphOrchestrator::new(contract.clone(), versioned)
+            .distill_with_trace(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true, None)
+            .unwrap();
+        assert_eq!(dk.eco_provenance, "fixed");
+        assert_eq!(dk.eco_dataset_version.as_deref(), Some("v3"));
+        assert_eq!(dk.eco_fetched_at, Some(1_762_041_600));
+        assert_eq!(trace.eco_report.unwrap().dataset_version.as_deref(), Some("v3"));
+
+        // The stamp binds the version; without one it is as before.
+        let plain = NeuromorphOrchestrator::new(contract, fixed_eco())
+            .distill_neuromorph_content(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true)
+            .unwrap();
+        assert_ne!(plain.hex_stamp, dk.hex_stamp);
+        assert_eq!((plain.eco_dataset_version, plain.eco_fetched_at), (None, None));
+        let stamp = format!("{:?}|{:.6}|{:?}|false|false|fixed", plain.role, plain.knowledge_factor, plain.access_class);
+        assert_eq!(plain.hex_stamp, core_contract::hex_stamp(stamp.as_bytes()));
+    }
+
+    /// Simulator returning a fixed risk, or an error.
+    struct ScriptedSimulator {
+        risk: Result<f32, String>,
+        seen: std::sync::Arc<Mutex<Vec<f32>>>,
+    }
+
+    impl governance_sim::PolicySimulationBackend for ScriptedSimulator {
+        fn evaluate_policy(&self, policy: &SncPolicySnapshot) -> Result<governance_sim::SimulationOutcome, String> {
+            self.seen.lock().unwrap().push(policy.min_knowledge_factor_open);
+            self.risk.clone().map(|risk| governance_sim::SimulationOutcome {
+                expected_neurorights_risk: risk,
+                environmental_justice_score: 1.0,
+                trust_index: 1.0,
+            })
+        }
+    }
+
+    #[test]
+    fn simulation_gate_downgrades_risky_open_grants_and_fails_closed() {
+        let full = knowledge::KnowledgeFactorConfig {
+            validation: 1.0,
+            reuse: 1.0,
+            novelty: 1.0,
+            ..Default::default()
+        };
+        let seen = std::sync::Arc::new(Mutex::new(Vec::new()));
+        let gated = |risk: Result<f32, String>| {
+            let gate = SimulationGate::new(ScriptedSimulator { risk, seen: seen.clone() }).with_max_neurorights_risk(0.3);
+            orchestrator()
+                .with_knowledge_factor(full.clone())
+                .unwrap()
+                .with_simulation_gate(gate)
+                .distill_with_trace(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true, None)
+        };
+
+        let (dk, trace) = gated(Ok(0.1)).unwrap();
+        assert_eq!(dk.access_class, AccessClass::Open);
+        assert_eq!(trace.simulation.as_ref().map(|s| s.downgraded), Some(false));
+        assert_eq!(*seen.lock().unwrap(), [OPEN_MIN_KNOWLEDGE_FACTOR]);
+
+        let (dk, trace) = gated(Ok(0.31)).unwrap();
+        assert_eq!(dk.access_class, AccessClass::KnowledgeGated);
+        assert_eq!(trace.access_class, AccessClass::KnowledgeGated);
+        assert_eq!(trace.branch, AccessBranch::OpenThresholdsMet);
+        assert_eq!(
+            trace.simulation,
+            Some(SimulationCheck {
+                expected_neurorights_risk: 0.31,
+                max_neurorights_risk: 0.3,
+                downgraded: true,
+            })
+        );
+
+        // A failing simulator never lets Open through.
+        let err = gated(Err("osireon unreachable".into())).unwrap_err();
+        assert_eq!(err, OrchestrationError::SimulationFailure("osireon unreachable".into()));
+        assert!(gated(Ok(f32::NAN)).is_err());
+
+        // Results that would not be Open never reach the simulator.
+        seen.lock().unwrap().clear();
+        let gate = SimulationGate::new(ScriptedSimulator { risk: Err("unused".into()), seen: seen.clone() });
+        let dk = orchestrator()
+            .with_simulation_gate(gate)
+            .distill_neuromorph_content(RoleTier::Learner, labelled_artifact(), "education", false, false, true, true)
+            .unwrap();
+        assert_eq!(dk.access_class, AccessClass::KnowledgeGated);
+        assert!(seen.lock().unwrap().is_empty());
     }
 }
@@ -0,0 +1,205 @@
+use core_contract::{AccessClass, RoleTier};
+use governance_sim::{PolicySimulationBackend, SncPolicySnapshot};
+
+use crate::governance::PolicyGateThresholds;
+use crate::trace::AccessBranch;
+use crate::{DistillFlags, OPEN_MIN_ECO_IMPACT, OPEN_MIN_KNOWLEDGE_FACTOR};
+
+/// Maps a scored artifact onto an access class. `knowledge_factor` and
+/// `eco_impact` are finite and in [0, 1].
+pub trait AccessClassPolicy: Send + Sync {
+    fn classify(
+        &self,
+        role: RoleTier,
+        knowledge_factor: f32,
+        eco_impact: f32,
+        flags: &DistillFlags,
+    ) -> AccessClass;
+
+    /// Branch recorded in the `DistillationTrace`.
+    fn branch(
+        &self,
+        _role: RoleTier,
+        _knowledge_factor: f32,
+        _eco_impact: f32,
+        _flags: &DistillFlags,
+    ) -> AccessBranch {
+        AccessBranch::Custom
+    }
+
+    /// F_K floor for Open, reported to policy simulators.
+    fn min_knowledge_factor_open(&self) -> f32 {
+        OPEN_MIN_KNOWLEDGE_FACTOR
+    }
+}
+
+/// Simulator consulted before any Open grant; see
+/// `NeuromorphOrchestrator::with_simulation_gate`.
+pub struct SimulationGate {
+    pub backend: Box<dyn PolicySimulationBackend + Send + Sync>,
+    /// Open is downgraded to KnowledgeGated above this simulated risk.
+    pub max_neurorights_risk: f32,
+    /// Reported in the snapshot; the orchestrator has no CHAT issuance
+    /// curve of its own.
+    pub chat_issuance_slope: f32,
+}
+
+impl SimulationGate {
+    /// Same risk bound as the default `PolicyGateThresholds`.
+    pub fn new(backend: impl PolicySimulationBackend + Send + Sync + 'static) -> Self {
+        Self {
+            backend: Box::new(backend),
+            max_neurorights_risk: PolicyGateThresholds::default().max_neurorights_risk,
+            chat_issuance_slope: 1.0,
+        }
+    }
+
+    pub fn with_max_neurorights_risk(mut self, bound: f32) -> Self {
+        self.max_neurorights_risk = bound;
+        self
+    }
+}
+
+/// Outcome of a simulation check, recorded in the decision trace.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimulationCheck {
+    pub expected_neurorights_risk: f32,
+    pub max_neurorights_risk: f32,
+    /// True when Open was downgraded to KnowledgeGated.
+    pub downgraded: bool,
+}
+
+/// The built-in policy: sensitive signals gate Learners and give other
+/// roles HighAutonomy; otherwise Open needs both thresholds.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThresholdAccessPolicy {
+    pub min_knowledge_factor_open: f32,
+    pub min_eco_impact_open: f32,
+}
+
+impl Default for ThresholdAccessPolicy {
+    fn default() -> Self {
+        Self {
+            min_knowledge_factor_open: OPEN_MIN_KNOWLEDGE_FACTOR,
+            min_eco_impact_open: OPEN_MIN_ECO_IMPACT,
+        }
+    }
+}
+
+impl From<&SncPolicySnapshot> for ThresholdAccessPolicy {
+    /// Takes the F_K floor from the snapshot; the eco floor stays at
+    /// `OPEN_MIN_ECO_IMPACT` since snapshots do not carry one.
+    fn from(snapshot: &SncPolicySnapshot) -> Self {
+        Self {
+            min_knowledge_factor_open: snapshot.min_knowledge_factor_open,
+            ..Self::default()
+        }
+    }
+}
+
+impl AccessClassPolicy for ThresholdAccessPolicy {
+    fn min_knowledge_factor_open(&self) -> f32 {
+        self.min_knowledge_factor_open
+    }
+
+    fn classify(
+        &self,
+        role: RoleTier,
+        knowledge_factor: f32,
+        eco_impact: f32,
+        flags: &DistillFlags,
+    ) -> AccessClass {
+        match self.branch(role, knowledge_factor, eco_impact, flags) {
+            AccessBranch::SensitiveSignal {
+                role: RoleTier::Learner,
+            } => AccessClass::KnowledgeGated,
+            AccessBranch::SensitiveSignal { .. } => AccessClass::HighAutonomy,
+            AccessBranch::OpenThresholdsMet => AccessClass::Open,
+            AccessBranch::BelowOpenThresholds { .. } | AccessBranch::Custom => {
+                AccessClass::KnowledgeGated
+            }
+        }
+    }
+
+    fn branch(
+        &self,
+        role: RoleTier,
+        knowledge_factor: f32,
+        eco_impact: f32,
+        flags: &DistillFlags,
+    ) -> AccessBranch {
+        if flags.has_biophysical_signal || flags.uses_discipline_signals {
+            return AccessBranch::SensitiveSignal { role };
+        }
+        let knowledge_factor_short = knowledge_factor < self.min_knowledge_factor_open;
+        let eco_impact_short = eco_impact < self.min_eco_impact_open;
+        if knowledge_factor_short || eco_impact_short {
+            AccessBranch::BelowOpenThresholds {
+                knowledge_factor_short,
+                eco_impact_short,
+            }
+        } else {
+            AccessBranch::OpenThresholdsMet
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLEAN: DistillFlags = DistillFlags {
+        has_biophysical_signal: false,
+        uses_discipline_signals: false,
+        dual_empirical_formal_present: true,
+        uncertainty_exposed: true,
+    };
+
+    #[test]
+    fn default_policy_keeps_the_built_in_thresholds() {
+        let policy = ThresholdAccessPolicy::default();
+        let classify = |fk, eco| policy.classify(RoleTier::Learner, fk, eco, &CLEAN);
+        assert_eq!(
+            classify(OPEN_MIN_KNOWLEDGE_FACTOR, OPEN_MIN_ECO_IMPACT),
+            AccessClass::Open
+        );
+        assert_eq!(classify(0.74, 1.0), AccessClass::KnowledgeGated);
+        assert_eq!(classify(1.0, 0.79), AccessClass::KnowledgeGated);
+
+        let signalled = DistillFlags {
+            uses_discipline_signals: true,
+            ..CLEAN
+        };
+        assert_eq!(
+            policy.classify(RoleTier::Mentor, 1.0, 1.0, &signalled),
+            AccessClass::HighAutonomy
+        );
+        assert_eq!(
+            policy.classify(RoleTier::Learner, 1.0, 1.0, &signalled),
+            AccessClass::KnowledgeGated
+        );
+    }
+
+    #[test]
+    fn snapshot_drives_the_knowledge_factor_floor() {
+        let snapshot = SncPolicySnapshot {
+            min_knowledge_factor_open: 0.9,
+            chat_issuance_slope: 0.1,
+            eco_weight: 0.5,
+        };
+        let policy = ThresholdAccessPolicy::from(&snapshot);
+        assert_eq!(policy.min_eco_impact_open, OPEN_MIN_ECO_IMPACT);
+        assert_eq!(
+            policy.branch(RoleTier::Learner, 0.85, 0.95, &CLEAN),
+            AccessBranch::BelowOpenThresholds {
+                knowledge_factor_short: true,
+                eco_impact_short: false,
+            }
+        );
+        assert_eq!(
+            policy.classify(RoleTier::Learner, 0.9, 0.95, &CLEAN),
+            AccessClass::Open
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Budget an actor has left after a distillation was admitted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuotaRemaining {
+    /// Distillations left in the current sliding window.
+    pub window: u32,
+    /// Distillations left today (UTC).
+    pub daily: u32,
+}
+
+/// Meters distillations per actor; see `NeuromorphOrchestrator::with_quota`.
+pub trait DistillationQuota: Send + Sync {
+    /// Admit one distillation for `actor_id` at `now` and return the budget
+    /// left, or refuse with how long to wait before the next one fits.
+    fn acquire(&self, actor_id: &str, now: SystemTime) -> Result<QuotaRemaining, Duration>;
+}
+
+#[derive(Default)]
+struct ActorUsage {
+    /// Admissions inside the sliding window, oldest first.
+    recent: VecDeque<SystemTime>,
+    /// UTC day number of `daily_count`.
+    day: u64,
+    daily_count: u32,
+}
+
+/// In-process `DistillationQuota`: at most `max_per_window` distillations in
+/// any trailing `window`, and at most `daily_cap` per UTC day. Refused
+/// attempts do not count against either limit.
+pub struct InMemoryQuota {
+    window: Duration,
+    max_per_window: u32,
+    daily_cap: u32,
+    actors: Mutex<HashMap<String, ActorUsage>>,
+}
+
+impl InMemoryQuota {
+    pub fn new(window: Duration, max_per_window: u32, daily_cap: u32) -> Self {
+        Self {
+            window,
+            max_per_window,
+            daily_cap,
+            actors: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+fn since_epoch(t: SystemTime) -> Duration {
+    t.duration_since(UNIX_EPOCH).unwrap_or_default()
+}
+
+impl DistillationQuota for InMemoryQuota {
+    fn acquire(&self, actor_id: &str, now: SystemTime) -> Result<QuotaRemaining, Duration> {
+        let mut actors = self.actors.lock().unwrap_or_else(|e| e.into_inner());
+        let usage = actors.entry(actor_id.to_string()).or_default();
+
+        while let Some(&oldest) = usage.recent.front() {
+            match now.duration_since(oldest) {
+                Ok(age) if age >= self.window => {
+                    usage.recent.pop_front();
+                }
+                _ => break,
+            }
+        }
+        let elapsed = since_epoch(now);
+        let day = elapsed.as_secs() / DAY.as_secs();
+        if usage.day != day {
+            usage.day = day;
+            usage.daily_count = 0;
+        }
+
+        let window_full = usage.recent.len() as u32 >= self.max_per_window;
+        let day_full = usage.daily_count >= self.daily_cap;
+        if window_full || day_full {
+            let mut retry_after = Duration::ZERO;
+            if window_full {
+                retry_after = match usage.recent.front() {
+                    Some(&oldest) => (oldest + self.window)
+                        .duration_since(now)
+                        .unwrap_or_default(),
+                    // A zero-sized window never admits anything.
+                    None => self.window,
+                };
+            }
+            if day_full {
+                let midnight = Duration::from_secs((day + 1) * DAY.as_secs());
+                retry_after = retry_after.max(midnight - elapsed);
+            }
+            return Err(retry_after);
+        }
+
+        usage.recent.push_back(now);
+        usage.daily_count += 1;
+        Ok(QuotaRemaining {
+            window: self.max_per_window - usage.recent.len() as u32,
+            daily: self.daily_cap - usage.daily_count,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINUTE: Duration = Duration::from_secs(60);
+
+    fn at(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn sliding_window_reopens_as_admissions_age_out() {
+        let quota = InMemoryQuota::new(MINUTE, 2, 100);
+        assert_eq!(quota.acquire("learner", at(0)).unwrap().window, 1);
+        assert_eq!(quota.acquire("learner", at(30)).unwrap().window, 0);
+        assert_eq!(
+            quota.acquire("learner", at(45)),
+            Err(Duration::from_secs(15))
+        );
+
+        // The first admission leaves the window at t = 60.
+        let remaining = quota.acquire("learner", at(60)).unwrap();
+        assert_eq!(
+            remaining,
+            QuotaRemaining {
+                window: 0,
+                daily: 97
+            }
+        );
+        assert_eq!(
+            quota.acquire("learner", at(61)),
+            Err(Duration::from_secs(29))
+        );
+
+        // Other actors are metered separately.
+        assert!(quota.acquire("teacher", at(61)).is_ok());
+    }
+
+    #[test]
+    fn daily_cap_rolls_over_at_utc_midnight() {
+        let quota = InMemoryQuota::new(MINUTE, 10, 2);
+        let evening = 3 * 86_400 - 120;
+        quota.acquire("learner", at(evening)).unwrap();
+        assert_eq!(quota.acquire("learner", at(evening + 1)).unwrap().daily, 0);
+        assert_eq!(
+            quota.acquire("learner", at(evening + 2)),
+            Err(Duration::from_secs(118))
+        );
+
+        let remaining = quota.acquire("learner", at(3 * 86_400)).unwrap();
+        assert_eq!(remaining.daily, 1);
+    }
+
+    #[test]
+    fn retry_after_covers_both_limits() {
+        let quota = InMemoryQuota::new(10 * MINUTE, 1, 1);
+        let late = 86_400 - 60;
+        quota.acquire("learner", at(late)).unwrap();
+        // Midnight is a minute away but the window needs ten.
+        assert_eq!(quota.acquire("learner", at(late)), Err(10 * MINUTE));
+    }
+}
@@ -0,0 +1,57 @@
+use core_contract::eco::EcoImpactReport;
+use core_contract::{AccessClass, RoleTier};
+
+use crate::graduated::Restriction;
+use crate::knowledge::KnowledgeFactorBreakdown;
+use crate::policy::SimulationCheck;
+use crate::quota::QuotaRemaining;
+
+/// Which branch of the access-class decision fired.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AccessBranch {
+    /// Biophysical or discipline signals present; the role tier decides.
+    SensitiveSignal { role: RoleTier },
+    /// No sensitive signals and both F_K and EcoImpact reach the Open floor.
+    OpenThresholdsMet,
+    /// No sensitive signals, but F_K and/or EcoImpact fall short of Open.
+    BelowOpenThresholds {
+        knowledge_factor_short: bool,
+        eco_impact_short: bool,
+    },
+    /// A custom `AccessClassPolicy` that does not report its branches.
+    Custom,
+}
+
+/// Why a distillation landed in its access class: the F_K components, the
+/// signals, the branch that fired and where the eco figures came from.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistillationTrace {
+    pub role: RoleTier,
+    pub has_biophysical_signal: bool,
+    pub uses_discipline_signals: bool,
+    /// V, R, E, N and the final F_K.
+    pub components: KnowledgeFactorBreakdown,
+    pub branch: AccessBranch,
+    pub access_class: AccessClass,
+    pub eco_provenance: String,
+    /// Present when a simulation gate was consulted before granting Open.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub simulation: Option<SimulationCheck>,
+    /// Budget left for the actor of a metered `distill` call.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub quota: Option<QuotaRemaining>,
+    /// Why each eco metric came out as it did, from
+    /// `EcoDataSource::calculate_with_report`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub eco_report: Option<EcoImpactReport>,
+    /// Set when the eco source only had stale data and the decision was
+    /// scored from it in a degraded mode.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub eco_warning: Option<String>,
+    /// Restrictions on a KnowledgeGated result that landed inside the
+    /// request's graduated band.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub restrictions: Option<Vec<Restriction>>,
+}
@@ -0,0 +1,305 @@
+//! Property tests for the access-class guarantees of the orchestrator.
+//!
+//! CI pins `PROPTEST_RNG_SEED` so runs are reproducible; the nightly job
+//! leaves it unset for a fresh seed. Failing cases are shrunk to a minimal
+//! counterexample before being reported.
+
+use core_contract::eco::{CorridorId, EcoImpactMetrics, NeuromorphArtifact, DEFAULT_MAX_SUMMARY_LEN};
+use core_contract::eco_mock::MockEcoSource;
+use core_contract::labels::DataLabels;
+use core_contract::{AccessClass, DistilledKnowledge, RoleTier, SovereignNeuromorphContract};
+use orchestration::knowledge::{
+    KnowledgeFactorBreakdown, KnowledgeFactorConfig, KnowledgeOverrides, KnowledgeWeights,
+};
+use orchestration::{
+    DistillFlags, NeuromorphOrchestrator, OrchestrationError, OPEN_MIN_ECO_IMPACT,
+    OPEN_MIN_KNOWLEDGE_FACTOR,
+};
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+
+#[derive(Clone, Debug)]
+struct Contract {
+    consent: bool,
+    abort: bool,
+    non_coercive: bool,
+    forbids_downgrade: bool,
+}
+
+impl SovereignNeuromorphContract for Contract {
+    fn has_explicit_consent(&self) -> bool {
+        self.consent
+    }
+
+    fn has_sovereign_abort_control(&self) -> bool {
+        self.abort
+    }
+
+    fn is_discipline_personalized_and_non_coercive(&self) -> bool {
+        self.non_coercive
+    }
+
+    fn forbids_downgrade_or_rollback(&self) -> bool {
+        self.forbids_downgrade
+    }
+}
+
+/// Reports the artifact's declared metrics unchanged, so generated values
+/// (NaN included) reach scoring as-is.
+fn declared_eco() -> MockEcoSource {
+    MockEcoSource::new().with_label("declared")
+}
+
+#[derive(Clone, Debug)]
+struct Case {
+    contract: Contract,
+    role: RoleTier,
+    flags: DistillFlags,
+    eco: EcoImpactMetrics,
+    purpose: &'static str,
+    commercial_prohibited: bool,
+    config: KnowledgeFactorConfig,
+    overrides: Option<KnowledgeOverrides>,
+}
+
+impl Case {
+    fn artifact(&self) -> NeuromorphArtifact {
+        NeuromorphArtifact {
+            id: "prop-artifact".into(),
+            corridor_id: CorridorId("prop-corridor".into()),
+            eco_impact: self.eco.clone(),
+            summary: "generated".into(),
+            data_labels: DataLabels {
+                prohibited_purposes: if self.commercial_prohibited {
+                    vec!["commercial".into()]
+                } else {
+                    vec![]
+                },
+                ..DataLabels::default()
+            },
+        }
+    }
+}
+
+/// Unit-interval values biased towards the Open thresholds.
+fn unit() -> impl Strategy<Value = f32> {
+    prop_oneof![
+        3 => 0.0f32..=1.0,
+        4 => 0.9f32..=1.0,
+        1 => Just(0.0f32),
+        1 => Just(1.0f32),
+        1 => Just(OPEN_MIN_ECO_IMPACT),
+        1 => Just(OPEN_MIN_KNOWLEDGE_FACTOR),
+    ]
+}
+
+/// Mostly unit values, sometimes ones the orchestrator must cope with:
+/// out of range, NaN and infinities. As eco metrics these are refused as
+/// invalid artifacts.
+fn score() -> impl Strategy<Value = f32> {
+    prop_oneof![
+        40 => unit(),
+        1 => Just(f32::NAN),
+        1 => Just(-0.1f32),
+        1 => Just(1.5f32),
+        1 => Just(f32::INFINITY),
+    ]
+}
+
+fn weight() -> impl Strategy<Value = f32> {
+    prop_oneof![
+        16 => 0.0f32..=2.0,
+        4 => Just(1.0f32),
+        2 => Just(0.0f32),
+        1 => Just(-1.0f32),
+        1 => Just(f32::NAN),
+    ]
+}
+
+/// Mostly true, so generated cases reach scoring instead of being refused.
+fn likely() -> impl Strategy<Value = bool> {
+    proptest::bool::weighted(0.85)
+}
+
+/// Mostly false, so the Open branch stays reachable.
+fn unlikely() -> impl Strategy<Value = bool> {
+    proptest::bool::weighted(0.25)
+}
+
+fn role() -> impl Strategy<Value = RoleTier> {
+    prop_oneof![
+        Just(RoleTier::Learner),
+        Just(RoleTier::Teacher),
+        Just(RoleTier::Mentor),
+        Just(RoleTier::Researcher),
+    ]
+}
+
+prop_compose! {
+    fn contract()(consent in likely(), abort in likely(), non_coercive in likely(), forbids_downgrade in likely()) -> Contract {
+        Contract { consent, abort, non_coercive, forbids_downgrade }
+    }
+}
+
+prop_compose! {
+    fn flags()(has_biophysical_signal in unlikely(), uses_discipline_signals in unlikely(), dual_empirical_formal_present in likely(), uncertainty_exposed in likely()) -> DistillFlags {
+        DistillFlags { has_biophysical_signal, uses_discipline_signals, dual_empirical_formal_present, uncertainty_exposed }
+    }
+}
+
+prop_compose! {
+    fn independent_eco()(climate_score in score(), biodiversity_score in score(), biosphere_score in score(), corridor_score in score()) -> EcoImpactMetrics {
+        EcoImpactMetrics { climate_score, biodiversity_score, biosphere_score, corridor_score }
+    }
+}
+
+/// Uniform high metrics put the eco scalar around the Open floor.
+fn eco() -> impl Strategy<Value = EcoImpactMetrics> {
+    prop_oneof![
+        independent_eco(),
+        (0.9f32..=1.0).prop_map(|x| EcoImpactMetrics {
+            climate_score: x,
+            biodiversity_score: x,
+            biosphere_score: x,
+            corridor_score: x,
+        }),
+    ]
+}
+
+prop_compose! {
+    fn config()(validation in score(), reuse in score(), novelty in score(), weights in (weight(), weight(), weight(), weight())) -> KnowledgeFactorConfig {
+        KnowledgeFactorConfig {
+            validation,
+            reuse,
+            novelty,
+            weights: KnowledgeWeights { validation: weights.0, reuse: weights.1, eco_impact: weights.2, novelty: weights.3 },
+        }
+    }
+}
+
+/// The default components cap F_K below the Open threshold; these reach it.
+fn high_config() -> impl Strategy<Value = KnowledgeFactorConfig> {
+    (0.95f32..=1.0, 0.95f32..=1.0, 0.95f32..=1.0).prop_map(|(validation, reuse, novelty)| {
+        KnowledgeFactorConfig {
+            validation,
+            reuse,
+            novelty,
+            weights: KnowledgeWeights::default(),
+        }
+    })
+}
+
+prop_compose! {
+    fn overrides()(validation in proptest::option::of(score()), reuse in proptest::option::of(score()), novelty in proptest::option::of(score())) -> KnowledgeOverrides {
+        KnowledgeOverrides { validation, reuse, novelty }
+    }
+}
+
+prop_compose! {
+    fn case()(
+        contract in contract(),
+        role in role(),
+        flags in flags(),
+        eco in eco(),
+        purpose in prop_oneof![Just("education"), Just("commercial")],
+        commercial_prohibited in any::<bool>(),
+        config in prop_oneof![
+            1 => Just(KnowledgeFactorConfig::default()),
+            2 => high_config(),
+            2 => config(),
+        ],
+        overrides in proptest::option::of(overrides()),
+    ) -> Case {
+        Case { contract, role, flags, eco, purpose, commercial_prohibited, config, overrides }
+    }
+}
+
+fn orchestrator(case: &Case) -> Option<NeuromorphOrchestrator<Contract, MockEcoSource>> {
+    NeuromorphOrchestrator::new(case.contract.clone(), declared_eco())
+        .with_knowledge_factor(case.config.clone())
+        .ok()
+}
+
+/// The invariants every distillation result must satisfy.
+fn check(
+    case: &Case,
+    result: &Result<DistilledKnowledge, OrchestrationError>,
+    breakdown: Option<&KnowledgeFactorBreakdown>,
+) -> Result<(), TestCaseError> {
+    let c = &case.contract;
+    let dk = match result {
+        Err(e) => {
+            prop_assert!(!e.code().is_empty());
+            prop_assert!(e.code().chars().all(|ch| ch.is_ascii_alphanumeric()));
+            return Ok(());
+        }
+        Ok(dk) => dk,
+    };
+
+    prop_assert!(c.consent && c.abort, "Ok without consent or abort control");
+    prop_assert!(c.non_coercive && c.forbids_downgrade);
+    prop_assert!(case.flags.dual_empirical_formal_present && case.flags.uncertainty_exposed);
+    prop_assert!(dk.knowledge_factor.is_finite());
+    prop_assert!((0.0..=1.0).contains(&dk.knowledge_factor));
+
+    if dk.access_class == AccessClass::Open {
+        prop_assert!(!(case.flags.has_biophysical_signal || case.flags.uses_discipline_signals));
+        prop_assert!(dk.knowledge_factor >= OPEN_MIN_KNOWLEDGE_FACTOR);
+        if let Some(breakdown) = breakdown {
+            prop_assert!(breakdown.eco_impact >= OPEN_MIN_ECO_IMPACT);
+        }
+        prop_assert!(case.eco.scalar() >= OPEN_MIN_ECO_IMPACT);
+    }
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig { cases: 512, ..ProptestConfig::default() })]
+
+    #[test]
+    fn single_distillation_upholds_access_invariants(case in case()) {
+        let Some(orchestrator) = orchestrator(&case) else {
+            prop_assert!(case.config.validate().is_err());
+            return Ok(());
+        };
+        let f = case.flags;
+        let scored = orchestrator.distill_with_breakdown(
+            case.role,
+            case.artifact(),
+            case.purpose,
+            f.has_biophysical_signal,
+            f.uses_discipline_signals,
+            f.dual_empirical_formal_present,
+            f.uncertainty_exposed,
+            case.overrides.as_ref(),
+        );
+        let breakdown = scored.as_ref().ok().map(|(_, b)| b.clone());
+        let result = scored.map(|(dk, _)| dk);
+        check(&case, &result, breakdown.as_ref())?;
+
+        if case.artifact().validate(DEFAULT_MAX_SUMMARY_LEN).is_err() {
+            prop_assert!(matches!(result, Err(OrchestrationError::InvalidArtifact(_))));
+        } else if !(case.contract.consent && case.contract.abort) {
+            prop_assert!(matches!(
+                result,
+                Err(OrchestrationError::MissingConsent) | Err(OrchestrationError::NoAbortControl)
+            ));
+        }
+    }
+
+    #[test]
+    fn batch_distillation_upholds_access_invariants(cases in proptest::collection::vec(case(), 1..6)) {
+        // One contract and config per batch; artifacts and flags vary.
+        let head = &cases[0];
+        let Some(orchestrator) = orchestrator(head) else {
+            return Ok(());
+        };
+        let batch: Vec<_> = cases.iter().map(|c| (c.artifact(), c.flags)).collect();
+        let results = orchestrator.distill_batch(head.role, head.purpose, &batch);
+        prop_assert_eq!(results.len(), cases.len());
+        for (case, result) in cases.iter().zip(&results) {
+            let case = Case { contract: head.contract.clone(), role: head.role, purpose: head.purpose, ..case.clone() };
+            check(&case, result, None)?;
+        }
+    }
+}
@@ -0,0 +1,12 @@
+//! Capability lattice and decision codes shared by the policy kernel.
+
+/// The capability lattice is the one core-contract defines, so the RoH
+/// ceiling for each tier comes from the same place everywhere.
+pub use core_contract::roh::CapabilityState;
+
+/// Why the kernel denied a capability transition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecisionReason {
+    DeniedIllegalDowngradeByNonRegulator,
+    DeniedRoHViolation,
+}
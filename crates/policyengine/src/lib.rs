@@ -0,0 +1,6 @@
+//! NewRow-Print policy engine kernel: capability transitions and the
+//! reversal conditions that keep neuromorph evolution one-way.
+
+pub mod aln_core;
+pub mod reversalconditions;
+pub mod roh_model;
@@ -1,25 +1,13 @@
-use crate::aln_core::{
-    CapabilityState,
-    CapabilityTransitionRequest,
-    Decision,
-    DecisionReason,
-    PolicyStack,
-};
-use crate::aln_roles::{RoleSet};
+use crate::aln_core::{CapabilityState, DecisionReason};
 use crate::roh_model::RoHScore;
-use crate::envelope::EnvelopeContextView;
 
 /// Minimal context, kept pure and immutable.
 #[derive(Debug, Clone)]
-pub struct ReversalContext<'a> {
-    pub base: &'a CapabilityTransitionRequest,
+pub struct ReversalContext {
     pub cap_before: CapabilityState,
     pub cap_after: CapabilityState,
     pub roh_before: RoHScore,
     pub roh_after: RoHScore,
-    pub roles: &'a RoleSet,
-    pub policy_stack: &'a PolicyStack,
-    pub envelope_ctx: &'a EnvelopeContextView,
     /// Diagnostic-only: true if this evaluation was triggered from a pure observer path.
     pub diag_event: bool,
 }
@@ -37,14 +25,13 @@ pub fn evaluate_reversal(ctx: &ReversalContext) -> ReversalDecision {
         return ReversalDecision::Denied(DecisionReason::DeniedIllegalDowngradeByNonRegulator);
     }
 
-    // 2. RoH invariants for CapControlledHuman (monotone + ceiling 0.30 already enforced upstream).
-    if matches!(ctx.cap_before, CapabilityState::CapControlledHuman) {
-        if ctx.roh_after.value > ctx.roh_before.value {
-            return ReversalDecision::Denied(DecisionReason::DeniedRoHViolation);
-        }
-        if ctx.roh_after.value > 0.30 {
-            return ReversalDecision::Denied(DecisionReason::DeniedRoHViolation);
-        }
+    // 2. RoH invariants: under the ceiling of the current tier (0.30 in
+    //    CapControlledHuman), and monotone in CapControlledHuman. NaN fails both.
+    let rising = !ctx.roh_after.is_monotone_after(ctx.roh_before);
+    if ctx.roh_after.exceeds_ceiling(ctx.cap_before)
+        || (ctx.cap_before == CapabilityState::ControlledHuman && rising)
+    {
+        return ReversalDecision::Denied(DecisionReason::DeniedRoHViolation);
     }
 
     // 3. If this is *not* a neuromorph evolution downgrade, we do not interfere.
@@ -61,10 +48,75 @@ fn is_neuromorph_downgrade(from: CapabilityState, to: CapabilityState) -> bool {
     use CapabilityState::*;
     matches!(
         (from, to),
-        (CapControlledHuman, CapLabBench)
-            | (CapControlledHuman, CapModelOnly)
-            | (CapGeneralUse, CapControlledHuman)
-            | (CapGeneralUse, CapLabBench)
-            | (CapGeneralUse, CapModelOnly)
+        (ControlledHuman, LabBench)
+            | (ControlledHuman, ModelOnly)
+            | (GeneralUse, ControlledHuman)
+            | (GeneralUse, LabBench)
+            | (GeneralUse, ModelOnly)
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctx(cap_before: CapabilityState, cap_after: CapabilityState, roh_before: f32, roh_after: f32) -> ReversalContext {
+        ReversalContext {
+            cap_before,
+            cap_after,
+            roh_before: RoHScore::new(roh_before),
+            roh_after: RoHScore::new(roh_after),
+            diag_event: false,
+        }
+    }
+
+    #[test]
+    fn test_upgrade_within_the_ceiling_is_allowed() {
+        let upgrade = ctx(CapabilityState::LabBench, CapabilityState::ControlledHuman, 0.2, 0.2);
+        assert_eq!(evaluate_reversal(&upgrade), ReversalDecision::Allowed);
+    }
+
+    #[test]
+    fn test_every_downgrade_is_denied() {
+        use CapabilityState::*;
+        for (from, to) in [(ControlledHuman, LabBench), (ControlledHuman, ModelOnly), (GeneralUse, ControlledHuman)] {
+            assert_eq!(
+                evaluate_reversal(&ctx(from, to, 0.1, 0.1)),
+                ReversalDecision::Denied(DecisionReason::DeniedIllegalDowngradeByNonRegulator),
+                "{from:?} -> {to:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_diagnostic_events_never_change_capability() {
+        let observed = ReversalContext { diag_event: true, ..ctx(CapabilityState::LabBench, CapabilityState::LabBench, 0.1, 0.1) };
+        assert_eq!(
+            evaluate_reversal(&observed),
+            ReversalDecision::Denied(DecisionReason::DeniedIllegalDowngradeByNonRegulator)
+        );
+    }
+
+    #[test]
+    fn test_controlled_human_roh_must_not_rise_or_pass_the_ceiling() {
+        let denied = ReversalDecision::Denied(DecisionReason::DeniedRoHViolation);
+        let human = CapabilityState::ControlledHuman;
+        assert_eq!(evaluate_reversal(&ctx(human, human, 0.1, 0.2)), denied);
+        assert_eq!(evaluate_reversal(&ctx(human, human, 0.4, 0.35)), denied);
+        assert_eq!(evaluate_reversal(&ctx(human, human, 0.3, 0.3)), ReversalDecision::Allowed);
+        // Outside ControlledHuman the RoH invariants are not this module's.
+        let bench = CapabilityState::LabBench;
+        assert_eq!(evaluate_reversal(&ctx(bench, bench, 0.4, 0.5)), ReversalDecision::Allowed);
+    }
+
+    #[test]
+    fn test_nan_roh_is_denied_in_every_tier() {
+        for state in [CapabilityState::LabBench, CapabilityState::ControlledHuman] {
+            assert_eq!(
+                evaluate_reversal(&ctx(state, state, 0.1, f32::NAN)),
+                ReversalDecision::Denied(DecisionReason::DeniedRoHViolation),
+                "{state:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,5 @@
+//! Scores under .rohmodel.aln.
+
+/// roh_model's score: the shared `core_contract::roh::RoH` at the guard's
+/// `f32` precision, so a score and a guard input compare without rounding.
+pub type RoHScore = core_contract::roh::RoH<f32>;
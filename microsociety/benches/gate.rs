@@ -0,0 +1,99 @@
+//! Hot-path benchmarks for the BioRail/Terrasafe gate, single and batched.
+//!
+//! Run with `cargo bench -p microsociety`. Zero-allocation behaviour is
+//! asserted separately in `tests/zero_alloc.rs`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use microsociety::biorail_terrasafe::*;
+
+fn site() -> SiteView {
+    SiteView {
+        id: 7,
+        bio_env: BioEnvelope { roh: 0.12, decay: 0.4, lifeforce: 0.6, lifeforce_min: 0.2, lifeforce_max: 1.0 },
+        identity_5d: FiveDIdentity {
+            biostate_load: 0.3,
+            neurostate_fear: 0.2,
+            lifeforce: 0.6,
+            context_load: 0.25,
+            sovereignty_trust: 0.7,
+            sovereignty_consent: true,
+        },
+        bioload_view: BioLoadView {
+            body: RailScalar::new_clamped(0.3),
+            room: RailScalar::new_clamped(0.4),
+            grid: RailScalar::new_clamped(0.5),
+            body_max: RailScalar::new_clamped(0.8),
+            room_max: RailScalar::new_clamped(0.8),
+            grid_max: RailScalar::new_clamped(0.9),
+        },
+        power_church: PowerChurchState { power: 2.0, church: 4.0, k_ratio: 1.0 },
+        justice_metrics: JusticeMetrics { hpcc: 0.2, erg: 0.9, tecr: 0.1 },
+        justice_cfg: JusticeCorridorConfig { hpcc_max: 0.5, erg_max: 0.5, tecr_max: 0.5, tightening_factor: 0.8 },
+        diag: DiagnosticFlags { beast_tag: false, plague_tag: false, unfair_drain: false, role_diagnostic_only: true },
+    }
+}
+
+fn change() -> ProposedChange {
+    ProposedChange {
+        delta_biostate_load: 0.05,
+        delta_neurostate_fear: 0.02,
+        delta_lifeforce: -0.05,
+        delta_context_load: 0.03,
+        delta_sovereignty_trust: 0.0,
+        delta_roh: 0.03,
+        delta_decay: 0.05,
+        delta_lifeforce_env: -0.05,
+        delta_bioload_body: 0.05,
+        delta_bioload_room: 0.02,
+        delta_bioload_grid: 0.01,
+        delta_power: 0.5,
+    }
+}
+
+fn bench_gate(c: &mut Criterion) {
+    let site = site();
+    let cfg = BioRailConfig { corridor_min: RailScalar::new_clamped(0.0), corridor_max: RailScalar::new_clamped(0.6), power_church_mode: PowerChurchMode::Instantaneous, warning_margin: 0.0, capability_state: CapabilityState::ControlledHuman, config_version: None };
+    let proposed = change();
+
+    c.bench_function("gate", |b| {
+        b.iter(|| BioRailTerrasafeGuard::gate(black_box(&site), black_box(&cfg), black_box(&proposed)))
+    });
+
+    let mut report = GateReport::default();
+    c.bench_function("gate_into", |b| {
+        b.iter(|| {
+            BioRailTerrasafeGuard::gate_into(black_box(&site), black_box(&cfg), black_box(&proposed), &mut report);
+            report.verdict
+        })
+    });
+}
+
+/// 1,000 candidate deeds against one site, as the deed engine does per tick.
+fn bench_batch(c: &mut Criterion) {
+    let site = site();
+    let cfg = BioRailConfig { corridor_min: RailScalar::new_clamped(0.0), corridor_max: RailScalar::new_clamped(0.6), power_church_mode: PowerChurchMode::Instantaneous, warning_margin: 0.0, capability_state: CapabilityState::ControlledHuman, config_version: None };
+    let proposals: Vec<ProposedChange> = (0..1_000)
+        .map(|i| {
+            let scale = (i % 50) as f64 / 25.0;
+            let base = change();
+            ProposedChange { delta_roh: base.delta_roh * scale, delta_power: base.delta_power * scale, ..base }
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("proposals_1000");
+    group.bench_function("gate_report_each", |b| {
+        b.iter(|| {
+            proposals
+                .iter()
+                .map(|p| BioRailTerrasafeGuard::gate_report(black_box(&site), black_box(&cfg), p))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("gate_batch", |b| {
+        b.iter(|| BioRailTerrasafeGuard::gate_batch(black_box(&site), black_box(&cfg), black_box(&proposals)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_gate, bench_batch);
+criterion_main!(benches);
@@ -0,0 +1,62 @@
+//! Refusal hooks around the gate.
+//!
+//! `BioRailTerrasafeGuard` stays pure: it never records anything. Callers
+//! that want an audit trail gate through `AuditedGate`, which hands every
+//! `Block` and `ForceRepair` report to a `GateAuditSink` along with the site
+//! id. `Allow` and `Downscale` reports are not passed on.
+
+use crate::biorail_terrasafe::{BioRailConfig, BioRailTerrasafeGuard, GateReport, GateVerdict, ProposedChange, SiteView};
+
+/// Receives the gate's refusals. Implementations must not fail the gate:
+/// a sink that cannot record a refusal keeps the error itself.
+pub trait GateAuditSink {
+    /// Called once per `Block` or `ForceRepair` report for `site_id`.
+    fn record_refusal(&self, site_id: usize, report: &GateReport);
+}
+
+impl<S: GateAuditSink + ?Sized> GateAuditSink for &S {
+    fn record_refusal(&self, site_id: usize, report: &GateReport) {
+        (**self).record_refusal(site_id, report)
+    }
+}
+
+/// Whether `verdict` is one a `GateAuditSink` is told about.
+pub fn is_refusal(verdict: GateVerdict) -> bool {
+    matches!(verdict, GateVerdict::Block | GateVerdict::ForceRepair)
+}
+
+/// The gate with a `GateAuditSink` attached.
+#[derive(Debug, Clone)]
+pub struct AuditedGate<S> {
+    sink: S,
+}
+
+impl<S: GateAuditSink> AuditedGate<S> {
+    pub fn new(sink: S) -> Self {
+        Self { sink }
+    }
+
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    /// `BioRailTerrasafeGuard::gate_report`, then the sink if the verdict is
+    /// a refusal. The report is returned unchanged either way.
+    pub fn gate_report(&self,
+                       site: &SiteView,
+                       base_cfg: &BioRailConfig,
+                       proposed: &ProposedChange) -> GateReport
+    {
+        let report = BioRailTerrasafeGuard::gate_report(site, base_cfg, proposed);
+        self.observe(site.id, &report);
+        report
+    }
+
+    /// Passes an existing report for `site_id` to the sink if it is a
+    /// refusal. Callers that gate through `gate_into` use this directly.
+    pub fn observe(&self, site_id: usize, report: &GateReport) {
+        if is_refusal(report.verdict) {
+            self.sink.record_refusal(site_id, report);
+        }
+    }
+}
@@ -3,7 +3,8 @@
 //! Nonfictional enforcement spine for the Jetson-Line / MicroSociety stack:
 //! - Monotone scalar rail b_i ∈ [0,1] as a projection of 5D identity
 //!   (BioState, NeuroState, Lifeforce, Context, Sovereignty).
-//! - Hard biophysical ceilings: RoH ≤ 0.3, DECAY ≤ 1.0, Lifeforce floors,
+//! - Hard biophysical ceilings: RoH ≤ the tier's ceiling (0.3 in
+//!   ControlledHuman), DECAY ≤ 1.0, Lifeforce floors,
 //!   computebioload ceilings at body/room/grid, POWER ≤ k·CHURCH.
 //! - Justice metrics (HPCC, ERG, TECR) act only as corridor tuners
 //!   (tighten limits, force repair modes), never as direct actuators.
@@ -14,6 +15,23 @@
 //! Site, World, Deed, and justice/diagnostic types are already defined. [file:4][file:2]
 
 use std::cmp::Ordering;
+use std::fmt;
+use std::ops::Deref;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use core_contract::config_version::ConfigVersion;
+pub use core_contract::roh::CapabilityState;
+use core_contract::roh::RoH;
+
+use crate::ethics::{EthicsDecision, EthicsRecord};
+
+/// RoH at which the biosignature's RoH component saturates: the
+/// `ControlledHuman` ceiling, fixed so b (and the projection vectors shared
+/// with microsociety-line) do not move with the tier. The gate's RoH
+/// ceiling comes from `BioRailConfig::capability_state`. [file:2]
+const ROH_SCALE: f64 = RoH::<f64>::ceiling_for(CapabilityState::ControlledHuman);
 
 /// Bounded scalar in [0,1] used for rails and normalized views. [file:4]
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -21,7 +39,7 @@ pub struct RailScalar(f64);
 
 impl RailScalar {
     pub fn new_clamped(x: f64) -> Self {
-        let v = if x.is_nan() { 0.0 } else if x < 0.0 { 0.0 } else if x > 1.0 { 1.0 } else { x };
+        let v = if x.is_nan() { 0.0 } else { x.clamp(0.0, 1.0) };
         RailScalar(v)
     }
 
@@ -37,13 +55,31 @@ impl RailScalar {
         RailScalar::new_clamped(self.0.min(other.0))
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn cmp(&self, other: &RailScalar) -> Ordering {
         self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal)
     }
 }
 
+/// Serialized as a bare number. Deserializing goes through `new_clamped`,
+/// so an out-of-range or hand-edited value can never bypass the clamp.
+#[cfg(feature = "serde")]
+impl Serialize for RailScalar {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for RailScalar {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        f64::deserialize(deserializer).map(RailScalar::new_clamped)
+    }
+}
+
 /// Core biophysical envelopes needed for the rail. [file:4][file:2]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BioEnvelope {
     pub roh: f64,          // Risk-of-Harm slice, must be ≤ 0.3. [file:2][file:4]
     pub decay: f64,        // DECAY, normalized, must be ≤ 1.0. [file:2]
@@ -54,16 +90,29 @@ pub struct BioEnvelope {
 
 /// 5D identity components already present in your stack. [file:4][file:3]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FiveDIdentity {
     pub biostate_load: f64,     // fatigue, inflammation, metabolic overhead (normalized). [file:4]
     pub neurostate_fear: f64,   // FEAR envelope slice (normalized). [file:3]
     pub lifeforce: f64,         // same as in BioEnvelope, kept for convenience.
     pub context_load: f64,      // territorial eco-impact, XR intensity, exposure (normalized). [file:4][file:2]
     pub sovereignty_trust: f64, // trust/consent in [0,1]. [file:4][file:2]
+    /// Whether the person behind the site consents to actuation at all.
+    /// `false` blocks every change (`GateConstraint::ConsentWithdrawn`),
+    /// however favourable the other metrics; no `ProposedChange` moves it.
+    /// Missing in serialized identities means given. [file:4]
+    #[cfg_attr(feature = "serde", serde(default = "consent_given"))]
+    pub sovereignty_consent: bool,
+}
+
+#[cfg(feature = "serde")]
+fn consent_given() -> bool {
+    true
 }
 
 /// Territorial bioload views from computebioload. [file:4][file:2]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BioLoadView {
     pub body: RailScalar,
     pub room: RailScalar,
@@ -75,6 +124,7 @@ pub struct BioLoadView {
 
 /// POWER/CHURCH slice per site. [file:3][file:2]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PowerChurchState {
     pub power: f64,
     pub church: f64,
@@ -84,6 +134,7 @@ pub struct PowerChurchState {
 
 /// Justice metrics snapshot (diagnostic only). [file:3][file:2]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct JusticeMetrics {
     pub hpcc: f64, // Habit-Pollution Coupling Coefficient. [file:3]
     pub erg: f64,  // Exposure-Responsibility Gap. [file:3]
@@ -92,6 +143,7 @@ pub struct JusticeMetrics {
 
 /// Justice corridor configuration; only tightens/relaxes scalar ceilings. [file:3][file:2]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct JusticeCorridorConfig {
     pub hpcc_max: f64,
     pub erg_max: f64,
@@ -100,8 +152,122 @@ pub struct JusticeCorridorConfig {
     pub tightening_factor: f64, // e.g., 0.8
 }
 
+/// One of the justice metrics that tune corridors. [file:3]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JusticeMetric {
+    Hpcc,
+    Erg,
+    Tecr,
+}
+
+impl JusticeMetric {
+    pub fn name(self) -> &'static str {
+        match self {
+            JusticeMetric::Hpcc => "HPCC",
+            JusticeMetric::Erg => "ERG",
+            JusticeMetric::Tecr => "TECR",
+        }
+    }
+}
+
+/// A justice metric above its corridor maximum.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StressedMetric {
+    pub metric: JusticeMetric,
+    pub value: f64,
+    pub max: f64,
+}
+
+/// The stressed metrics of one evaluation, in HPCC, ERG, TECR order.
+///
+/// Derefs to a slice. Stored inline rather than in a `Vec` so reports from
+/// `gate_into` stay allocation-free for tightened sites too.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StressedMetrics {
+    len: usize,
+    slots: [StressedMetric; 3],
+}
+
+impl StressedMetrics {
+    fn push(&mut self, metric: StressedMetric) {
+        self.slots[self.len] = metric;
+        self.len += 1;
+    }
+}
+
+impl Default for StressedMetrics {
+    fn default() -> Self {
+        let empty = StressedMetric { metric: JusticeMetric::Hpcc, value: 0.0, max: 0.0 };
+        StressedMetrics { len: 0, slots: [empty; 3] }
+    }
+}
+
+impl Deref for StressedMetrics {
+    type Target = [StressedMetric];
+
+    fn deref(&self) -> &[StressedMetric] {
+        &self.slots[..self.len]
+    }
+}
+
+/// Serialized as a list of the stressed metrics.
+#[cfg(feature = "serde")]
+impl Serialize for StressedMetrics {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for StressedMetrics {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let metrics = Vec::<StressedMetric>::deserialize(deserializer)?;
+        if metrics.len() > 3 {
+            return Err(serde::de::Error::invalid_length(metrics.len(), &"at most 3 stressed metrics"));
+        }
+        let mut out = StressedMetrics::default();
+        for m in metrics {
+            out.push(m);
+        }
+        Ok(out)
+    }
+}
+
+/// Body / room / grid bioload ceilings. [file:4]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BioloadCeilings {
+    pub body: RailScalar,
+    pub room: RailScalar,
+    pub grid: RailScalar,
+}
+
+/// Limits before and after justice tuning, and why they moved; see
+/// `BioRailTerrasafeGuard::preview_justice_tuning`. [file:3]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JusticeTuningRecord {
+    pub stressed_metrics: StressedMetrics,
+    /// Clamped `tightening_factor`; 1.0 when nothing is stressed.
+    pub factor_applied: f64,
+    pub corridor_before: (RailScalar, RailScalar),
+    pub corridor_after: (RailScalar, RailScalar),
+    pub ceilings_before: BioloadCeilings,
+    pub ceilings_after: BioloadCeilings,
+}
+
+impl JusticeTuningRecord {
+    /// Whether any metric was stressed, so the limits were tightened.
+    pub fn tightened(&self) -> bool {
+        !self.stressed_metrics.is_empty()
+    }
+}
+
 /// Flags for diagnostics like BEAST/PLAGUE, strictly non-actuating. [file:6][file:2]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DiagnosticFlags {
     pub beast_tag: bool,
     pub plague_tag: bool,
@@ -111,6 +277,7 @@ pub struct DiagnosticFlags {
 
 /// Site-local view needed for the BioRail computation and gating. [file:4][file:3]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SiteView {
     pub id: usize,
     pub bio_env: BioEnvelope,
@@ -124,14 +291,212 @@ pub struct SiteView {
 
 /// Corridor configuration for the biosignature rail. [file:4][file:3]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BioRailConfig {
     /// Allowed corridor for b_i at this site/zone. [file:4][file:3]
     pub corridor_min: RailScalar,
     pub corridor_max: RailScalar,
+    /// How POWER ≤ k·CHURCH is judged. [file:3]
+    pub power_church_mode: PowerChurchMode,
+    /// Fraction of a limit within which a constraint that still holds is
+    /// reported in `GateReport::warnings`, e.g. 0.1 warns from 0.27 on the
+    /// 0.3 RoH cap. 0 (or a negative or non-finite value) disables warnings.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub warning_margin: f64,
+    /// Capability tier the site runs in; selects the RoH ceiling, 0.3 in
+    /// `ControlledHuman` and none below 1.0 elsewhere. Configs written
+    /// before the field existed load as `ControlledHuman`. [file:2]
+    #[cfg_attr(feature = "serde", serde(default = "controlled_human"))]
+    pub capability_state: CapabilityState,
+    /// Version of the bundle this config was loaded from; copied onto every
+    /// `GateReport` so a decision can be traced to its thresholds.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub config_version: Option<ConfigVersion>,
+}
+
+#[cfg(feature = "serde")]
+fn controlled_human() -> CapabilityState {
+    CapabilityState::ControlledHuman
+}
+
+impl BioRailConfig {
+    /// RoH ceiling in force for `capability_state`.
+    pub fn roh_ceiling(&self) -> f64 {
+        RoH::<f64>::ceiling_for(self.capability_state)
+    }
+}
+
+/// Which POWER and CHURCH readings the POWER ≤ k·CHURCH check uses.
+///
+/// `gate`, `gate_report` and the other stateless entry points have no
+/// sample history, so they refuse a `Windowed` config with
+/// `GateConstraint::PowerChurchWindow` rather than quietly judging the
+/// instantaneous readings; `Windowed` is honoured by
+/// `PowerChurchWindow::gate_report`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PowerChurchMode {
+    /// The readings in `SiteView::power_church`.
+    #[default]
+    Instantaneous,
+    /// Time-weighted averages over the last `window` time units, so brief
+    /// spikes neither hide a sustained imbalance nor block on their own.
+    Windowed { window: f64 },
+}
+
+/// Substrate / anatomical context of a site; same tags as the
+/// microsociety-line zone table. [file:4]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ZoneTag {
+    NeuralBand,
+    VascularConduit,
+    HydrogelPatch,
+    XrField,
+    JetsonLineSite,
+}
+
+/// Corridor for b in one zone. [file:3]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BioRailZone {
+    pub zone: ZoneTag,
+    pub corridor: BioRailConfig,
+}
+
+/// Per-zone corridors for `BioRailTerrasafeGuard::gate_in_zone`.
+///
+/// A zone with no entry gets `fallback`, and reports gated that way carry
+/// `zone: None` so the substitution is visible. Keep the fallback at least
+/// as tight as the tightest zone if unknown substrates should be treated
+/// conservatively. If a zone is listed twice, the first entry wins.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ZoneCorridors {
+    pub zones: Vec<BioRailZone>,
+    pub fallback: BioRailConfig,
+}
+
+impl ZoneCorridors {
+    pub fn new(fallback: BioRailConfig) -> Self {
+        ZoneCorridors { zones: Vec::new(), fallback }
+    }
+
+    pub fn with_zone(mut self, zone: ZoneTag, corridor: BioRailConfig) -> Self {
+        self.zones.push(BioRailZone { zone, corridor });
+        self
+    }
+
+    /// Corridor applied to `zone`, and the zone it was resolved from
+    /// (`None` for the fallback).
+    pub fn resolve(&self, zone: ZoneTag) -> (&BioRailConfig, Option<ZoneTag>) {
+        match self.zones.iter().find(|z| z.zone == zone) {
+            Some(z) => (&z.corridor, Some(z.zone)),
+            None => (&self.fallback, None),
+        }
+    }
+}
+
+/// Weights projecting 5D identity and envelopes onto b_i. [file:4][file:3]
+///
+/// b_i = clamp(Σ risk weight · risk component − sovereign_relief · trust).
+/// With every weight non-negative, raising a risk component can never lower
+/// b_i; `validate` checks that and keeps the risk sum within the rail.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct BiosignatureWeights {
+    pub biostate_load: f64,
+    pub neurostate_fear: f64,
+    pub context_load: f64,
+    /// Applied to RoH normalized by its 0.3 ceiling. [file:2]
+    pub roh: f64,
+    pub decay: f64,
+    /// Applied to how far lifeforce sits below the top of its band.
+    pub lifeforce_risk: f64,
+    /// Subtracted per unit of sovereignty trust. [file:4]
+    pub sovereign_relief: f64,
+}
+
+impl BiosignatureWeights {
+    /// The projection `compute_biosignature` uses.
+    pub const DEFAULT: BiosignatureWeights = BiosignatureWeights {
+        biostate_load: 0.18,
+        neurostate_fear: 0.18,
+        context_load: 0.18,
+        roh: 0.18,
+        decay: 0.18,
+        lifeforce_risk: 0.10,
+        sovereign_relief: 0.4,
+    };
+
+    fn fields(&self) -> [(&'static str, f64); 7] {
+        [
+            ("biostate_load", self.biostate_load),
+            ("neurostate_fear", self.neurostate_fear),
+            ("context_load", self.context_load),
+            ("roh", self.roh),
+            ("decay", self.decay),
+            ("lifeforce_risk", self.lifeforce_risk),
+            ("sovereign_relief", self.sovereign_relief),
+        ]
+    }
+
+    /// Checks every weight is a finite, non-negative number (the monotonicity
+    /// condition), the risk weights sum to at most 1.0, and the relief is at
+    /// most 1.0. Reports all violations at once.
+    pub fn validate(&self) -> Result<(), Vec<WeightViolation>> {
+        let mut violations = Vec::new();
+        for (field, value) in self.fields() {
+            if !value.is_finite() {
+                violations.push(WeightViolation::NotFinite { field });
+            } else if value < 0.0 {
+                violations.push(WeightViolation::Negative { field, value });
+            }
+        }
+        let risk_sum: f64 = self.fields()[..6].iter().map(|(_, w)| w).sum();
+        if risk_sum > 1.0 + 1e-9 {
+            violations.push(WeightViolation::RiskSumAboveOne { sum: risk_sum });
+        }
+        if self.sovereign_relief > 1.0 + 1e-9 {
+            violations.push(WeightViolation::ReliefAboveOne { relief: self.sovereign_relief });
+        }
+        if violations.is_empty() { Ok(()) } else { Err(violations) }
+    }
+}
+
+impl Default for BiosignatureWeights {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Why a `BiosignatureWeights` set is unusable.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WeightViolation {
+    NotFinite { field: &'static str },
+    /// A negative weight would let more risk lower b_i.
+    Negative { field: &'static str, value: f64 },
+    /// Risk weights summing above 1.0 saturate the rail before every
+    /// component is at its maximum.
+    RiskSumAboveOne { sum: f64 },
+    ReliefAboveOne { relief: f64 },
+}
+
+impl fmt::Display for WeightViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFinite { field } => write!(f, "{field} is not a finite number"),
+            Self::Negative { field, value } => write!(f, "{field} = {value} is negative"),
+            Self::RiskSumAboveOne { sum } => write!(f, "risk weights sum to {sum}, above 1.0"),
+            Self::ReliefAboveOne { relief } => write!(f, "sovereign_relief = {relief} is above 1.0"),
+        }
+    }
 }
 
 /// Verdict from BioRail/Terrasafe gating. [file:4][file:3]
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GateVerdict {
     Allow,
     Downscale,
@@ -140,7 +505,10 @@ pub enum GateVerdict {
 }
 
 /// Proposed change summary used for prediction; this is computed upstream from deeds. [file:4][file:3]
-#[derive(Debug, Clone)]
+///
+/// `Default` is the empty change.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProposedChange {
     /// Predicted post-change 5D identity deltas (additive). [file:4]
     pub delta_biostate_load: f64,
@@ -171,11 +539,55 @@ impl BioRailTerrasafeGuard {
     ///   must not decrease b_i (monotonic safety). [file:4][file:3]
     /// - Increasing sovereignty_trust may reduce b_i within bounds, reflecting improved stewardship. [file:4]
     pub fn compute_biosignature(site: &SiteView) -> RailScalar {
-        let id = &site.identity_5d;
-        let env = &site.bio_env;
+        Self::biosignature_of(&site.bio_env, &site.identity_5d, &BiosignatureWeights::DEFAULT)
+    }
+
+    /// `compute_biosignature` under site-specific weights. Weights are not
+    /// re-checked here; run `BiosignatureWeights::validate` when loading them.
+    ///
+    /// With the `strict-invariants` feature, every call also checks that
+    /// worsening any one risk component (or dropping sovereignty_trust)
+    /// cannot lower the result, and panics with the offending site if it does.
+    pub fn compute_biosignature_weighted(site: &SiteView, weights: &BiosignatureWeights) -> RailScalar {
+        let b = Self::biosignature_of(&site.bio_env, &site.identity_5d, weights);
+        #[cfg(feature = "strict-invariants")]
+        Self::assert_monotone(site, weights, b);
+        b
+    }
+
+    /// Re-scores `site` with each risk component pushed to its worst value
+    /// and with sovereignty_trust removed; none may score below `b`.
+    #[cfg(feature = "strict-invariants")]
+    fn assert_monotone(site: &SiteView, w: &BiosignatureWeights, b: RailScalar) {
+        let (env, id) = (&site.bio_env, &site.identity_5d);
+        let worse_env = [
+            ("roh", BioEnvelope { roh: env.roh.max(ROH_SCALE), ..env.clone() }),
+            ("decay", BioEnvelope { decay: env.decay.max(1.0), ..env.clone() }),
+            ("lifeforce", BioEnvelope { lifeforce: env.lifeforce.min(env.lifeforce_min), ..env.clone() }),
+        ];
+        let worse_id = [
+            ("biostate_load", FiveDIdentity { biostate_load: id.biostate_load.max(1.0), ..id.clone() }),
+            ("neurostate_fear", FiveDIdentity { neurostate_fear: id.neurostate_fear.max(1.0), ..id.clone() }),
+            ("context_load", FiveDIdentity { context_load: id.context_load.max(1.0), ..id.clone() }),
+            ("sovereignty_trust", FiveDIdentity { sovereignty_trust: id.sovereignty_trust.min(0.0), ..id.clone() }),
+        ];
+        let scored = worse_env.iter().map(|(name, e)| (*name, Self::biosignature_of(e, id, w)))
+            .chain(worse_id.iter().map(|(name, i)| (*name, Self::biosignature_of(env, i, w))));
+        for (name, worse) in scored {
+            assert!(
+                worse.value() >= b.value(),
+                "biosignature not monotone in {name}: {} -> {} under {w:?} for {site:?}",
+                b.value(),
+                worse.value(),
+            );
+        }
+    }
 
+    /// Biosignature from borrowed envelope and identity slices, so predicted
+    /// states can be scored without assembling a full `SiteView`.
+    fn biosignature_of(env: &BioEnvelope, id: &FiveDIdentity, w: &BiosignatureWeights) -> RailScalar {
         // Normalize components into [0,1] contributions.
-        let roh_norm = (env.roh / 0.3).clamp(0.0, 1.0);        // RoH ≤ 0.3 corridor. [file:2]
+        let roh_norm = (env.roh / ROH_SCALE).clamp(0.0, 1.0);        // RoH ≤ 0.3 corridor. [file:2]
         let decay_norm = env.decay.clamp(0.0, 1.0);            // DECAY ≤ 1.0. [file:2]
         let lf_band_width = (env.lifeforce_max - env.lifeforce_min).max(1e-9);
         let lf_pos = ((env.lifeforce - env.lifeforce_min) / lf_band_width).clamp(0.0, 1.0);
@@ -188,36 +600,52 @@ impl BioRailTerrasafeGuard {
 
         // Risk-weighted aggregation; all risk components push b upward. [file:4][file:3]
         let risk_sum =
-            0.18 * bio_load +
-            0.18 * fear +
-            0.18 * ctx +
-            0.18 * roh_norm +
-            0.18 * decay_norm +
-            0.10 * lf_risk;
+            w.biostate_load * bio_load +
+            w.neurostate_fear * fear +
+            w.context_load * ctx +
+            w.roh * roh_norm +
+            w.decay * decay_norm +
+            w.lifeforce_risk * lf_risk;
 
         // Sovereignty/trust can only reduce risk, never create it. [file:4]
-        let sovereign_relief = 0.4 * sovereign;
+        let sovereign_relief = w.sovereign_relief * sovereign;
 
         let raw = (risk_sum - sovereign_relief).clamp(0.0, 1.0);
         RailScalar::new_clamped(raw)
     }
 
-    /// Apply justice metric tightening to the effective corridors and ceilings. [file:3][file:2]
-    fn apply_justice_tuning(site: &SiteView,
-                            rail_cfg: &BioRailConfig,
-                            bioload: &BioLoadView) -> (BioRailConfig, BioLoadView) {
+    /// Limits `gate` would apply to `site` before any change is proposed:
+    /// the baseline corridor and bioload ceilings, and what justice tuning
+    /// makes of them. Pure; for dashboards showing tightened limits ahead of
+    /// deeds. When no metric is stressed, the "after" limits equal the
+    /// "before" ones and `factor_applied` is 1.0. [file:3]
+    pub fn preview_justice_tuning(site: &SiteView, rail_cfg: &BioRailConfig) -> JusticeTuningRecord {
         let jm = &site.justice_metrics;
         let cfg = &site.justice_cfg;
+        let bioload = &site.bioload_view;
+        let corridor = (rail_cfg.corridor_min, rail_cfg.corridor_max);
+        let ceilings = BioloadCeilings { body: bioload.body_max, room: bioload.room_max, grid: bioload.grid_max };
 
         // If any justice metric exceeds its corridor, tighten corridors multiplicatively;
         // never loosen beyond the baseline. [file:3]
-        let stressed =
-            (jm.hpcc > cfg.hpcc_max) ||
-            (jm.erg > cfg.erg_max) ||
-            (jm.tecr > cfg.tecr_max);
+        let mut stressed = StressedMetrics::default();
+        for (metric, value, max) in [(JusticeMetric::Hpcc, jm.hpcc, cfg.hpcc_max),
+                                     (JusticeMetric::Erg, jm.erg, cfg.erg_max),
+                                     (JusticeMetric::Tecr, jm.tecr, cfg.tecr_max)] {
+            if value > max {
+                stressed.push(StressedMetric { metric, value, max });
+            }
+        }
 
-        if !stressed {
-            return (rail_cfg.clone(), bioload.clone());
+        if stressed.is_empty() {
+            return JusticeTuningRecord {
+                stressed_metrics: stressed,
+                factor_applied: 1.0,
+                corridor_before: corridor,
+                corridor_after: corridor,
+                ceilings_before: ceilings,
+                ceilings_after: ceilings,
+            };
         }
 
         let factor = cfg.tightening_factor.clamp(0.0, 1.0);
@@ -225,89 +653,116 @@ impl BioRailTerrasafeGuard {
         // Narrow the b_i corridor symmetrically around its midpoint. [file:3]
         let mid = 0.5 * (rail_cfg.corridor_min.value() + rail_cfg.corridor_max.value());
         let half_width = 0.5 * (rail_cfg.corridor_max.value() - rail_cfg.corridor_min.value()) * factor;
-        let tuned_min = RailScalar::new_clamped(mid - half_width);
-        let tuned_max = RailScalar::new_clamped(mid + half_width);
 
         // Shrink bioload ceilings; actual views remain unchanged. [file:3]
-        let tuned_bioload = BioLoadView {
-            body: bioload.body,
-            room: bioload.room,
-            grid: bioload.grid,
-            body_max: RailScalar::new_clamped(bioload.body_max.value() * factor),
-            room_max: RailScalar::new_clamped(bioload.room_max.value() * factor),
-            grid_max: RailScalar::new_clamped(bioload.grid_max.value() * factor),
-        };
-
-        let tuned_cfg = BioRailConfig {
-            corridor_min: tuned_min,
-            corridor_max: tuned_max,
-        };
+        JusticeTuningRecord {
+            stressed_metrics: stressed,
+            factor_applied: factor,
+            corridor_before: corridor,
+            corridor_after: (RailScalar::new_clamped(mid - half_width), RailScalar::new_clamped(mid + half_width)),
+            ceilings_before: ceilings,
+            ceilings_after: BioloadCeilings {
+                body: RailScalar::new_clamped(bioload.body_max.value() * factor),
+                room: RailScalar::new_clamped(bioload.room_max.value() * factor),
+                grid: RailScalar::new_clamped(bioload.grid_max.value() * factor),
+            },
+        }
+    }
 
-        (tuned_cfg, tuned_bioload)
+    /// Apply justice metric tightening to the effective corridors and ceilings. [file:3][file:2]
+    fn apply_justice_tuning(site: &SiteView, rail_cfg: &BioRailConfig) -> TunedLimits {
+        let record = Self::preview_justice_tuning(site, rail_cfg);
+        TunedLimits {
+            corridor_min: record.corridor_after.0,
+            corridor_max: record.corridor_after.1,
+            body_max: record.ceilings_after.body,
+            room_max: record.ceilings_after.room,
+            grid_max: record.ceilings_after.grid,
+            record: record.tightened().then_some(record),
+        }
     }
 
     /// Check RoH, DECAY, Lifeforce invariants on the predicted envelopes. [file:2]
-    fn check_envelopes(pred_env: &BioEnvelope) -> bool {
-        if pred_env.roh > 0.3 + 1e-9 { // RoH ≤ 0.3 hard ceiling. [file:2]
-            return false;
-        }
-        if pred_env.decay > 1.0 + 1e-9 { // DECAY ≤ 1.0. [file:2]
-            return false;
-        }
-        if pred_env.lifeforce < pred_env.lifeforce_min - 1e-9 {
-            return false;
-        }
-        if pred_env.lifeforce > pred_env.lifeforce_max + 1e-9 {
-            return false;
-        }
-        true
+    fn check_envelopes(pred_env: &BioEnvelope, roh_ceiling: f64, out: &mut Violations) -> bool {
+        // RoH ≤ the tier's hard ceiling, 0.3 in ControlledHuman. [file:2]
+        let roh = out.check_ceiling(pred_env.roh > roh_ceiling + 1e-9, GateConstraint::RohCeiling, roh_ceiling, pred_env.roh);
+        // DECAY ≤ 1.0. [file:2]
+        let decay = out.check_ceiling(pred_env.decay > 1.0 + 1e-9, GateConstraint::DecayCeiling, 1.0, pred_env.decay);
+        let floor = out.check_floor(pred_env.lifeforce < pred_env.lifeforce_min - 1e-9,
+                                    GateConstraint::LifeforceFloor, pred_env.lifeforce_min, pred_env.lifeforce);
+        let ceiling = out.check_ceiling(pred_env.lifeforce > pred_env.lifeforce_max + 1e-9,
+                                        GateConstraint::LifeforceCeiling, pred_env.lifeforce_max, pred_env.lifeforce);
+        roh && decay && floor && ceiling
     }
 
     /// Check BioLoad Terrasafe ceilings for predicted values. [file:4][file:2]
-    fn check_bioload(pred: &BioLoadView) -> bool {
-        pred.body.value() <= pred.body_max.value() + 1e-9 &&
-        pred.room.value() <= pred.room_max.value() + 1e-9 &&
-        pred.grid.value() <= pred.grid_max.value() + 1e-9
+    fn check_bioload(pred: &BioLoadView, out: &mut Violations) -> bool {
+        let territories = [
+            (GateConstraint::BodyBioload, pred.body, pred.body_max),
+            (GateConstraint::RoomBioload, pred.room, pred.room_max),
+            (GateConstraint::GridBioload, pred.grid, pred.grid_max),
+        ];
+        let mut ok = true;
+        for (constraint, load, max) in territories {
+            ok &= out.check_ceiling(load.value() > max.value() + 1e-9, constraint, max.value(), load.value());
+        }
+        ok
     }
 
     /// Check POWER ≤ k·CHURCH constraint after the proposed change. [file:3][file:2]
-    fn check_power_church(pred_pc: &PowerChurchState) -> bool {
+    fn check_power_church(pred_pc: &PowerChurchState, out: &mut Violations) -> bool {
         let k = pred_pc.k_ratio.max(0.0);
         let allowed_power = k * pred_pc.church.max(0.0);
-        pred_pc.power <= allowed_power + 1e-9
+        // A NaN allowance (k = inf, CHURCH = 0) admits no POWER.
+        let over = allowed_power.is_nan() || pred_pc.power > allowed_power + 1e-9;
+        out.check_ceiling(over, GateConstraint::PowerChurch, allowed_power, pred_pc.power)
     }
 
     /// Compute predicted post-change state slices needed for gating. [file:4][file:3]
+    ///
+    /// Reads the site by reference and builds only the slices that change;
+    /// nothing here touches the heap.
     fn predict_post_state(site: &SiteView,
                           change: &ProposedChange,
-                          tuned_bioload_max: &BioLoadView)
-                          -> (BioEnvelope, BioLoadView, PowerChurchState, FiveDIdentity)
+                          tuned: &TunedLimits) -> PredictedState
     {
-        let mut env = site.bio_env.clone();
-        env.roh = (env.roh + change.delta_roh).max(0.0);
-        env.decay = (env.decay + change.delta_decay).max(0.0);
-        env.lifeforce = env.lifeforce + change.delta_lifeforce_env;
+        let cur_env = &site.bio_env;
+        let env = BioEnvelope {
+            roh: (cur_env.roh + change.delta_roh).max(0.0),
+            decay: (cur_env.decay + change.delta_decay).max(0.0),
+            lifeforce: cur_env.lifeforce + change.delta_lifeforce_env,
+            lifeforce_min: cur_env.lifeforce_min,
+            lifeforce_max: cur_env.lifeforce_max,
+        };
 
-        let mut id = site.identity_5d.clone();
-        id.biostate_load = (id.biostate_load + change.delta_biostate_load).clamp(0.0, 1.0);
-        id.neurostate_fear = (id.neurostate_fear + change.delta_neurostate_fear).clamp(0.0, 1.0);
-        id.lifeforce = id.lifeforce + change.delta_lifeforce;
-        id.context_load = (id.context_load + change.delta_context_load).clamp(0.0, 1.0);
-        id.sovereignty_trust = (id.sovereignty_trust + change.delta_sovereignty_trust).clamp(0.0, 1.0);
+        let cur_id = &site.identity_5d;
+        let identity = FiveDIdentity {
+            biostate_load: (cur_id.biostate_load + change.delta_biostate_load).clamp(0.0, 1.0),
+            neurostate_fear: (cur_id.neurostate_fear + change.delta_neurostate_fear).clamp(0.0, 1.0),
+            lifeforce: cur_id.lifeforce + change.delta_lifeforce,
+            context_load: (cur_id.context_load + change.delta_context_load).clamp(0.0, 1.0),
+            sovereignty_trust: (cur_id.sovereignty_trust + change.delta_sovereignty_trust).clamp(0.0, 1.0),
+            sovereignty_consent: cur_id.sovereignty_consent,
+        };
 
-        let mut bl = site.bioload_view.clone();
-        bl.body = RailScalar::new_clamped(bl.body.value() + change.delta_bioload_body);
-        bl.room = RailScalar::new_clamped(bl.room.value() + change.delta_bioload_room);
-        bl.grid = RailScalar::new_clamped(bl.grid.value() + change.delta_bioload_grid);
-        // ceilings come from tuned_bioload_max. [file:4][file:3]
-        bl.body_max = tuned_bioload_max.body_max;
-        bl.room_max = tuned_bioload_max.room_max;
-        bl.grid_max = tuned_bioload_max.grid_max;
+        let cur_bl = &site.bioload_view;
+        // ceilings come from the tuned limits. [file:4][file:3]
+        let bioload = BioLoadView {
+            body: RailScalar::new_clamped(cur_bl.body.value() + change.delta_bioload_body),
+            room: RailScalar::new_clamped(cur_bl.room.value() + change.delta_bioload_room),
+            grid: RailScalar::new_clamped(cur_bl.grid.value() + change.delta_bioload_grid),
+            body_max: tuned.body_max,
+            room_max: tuned.room_max,
+            grid_max: tuned.grid_max,
+        };
 
-        let mut pc = site.power_church.clone();
-        pc.power = (pc.power + change.delta_power).max(0.0);
+        let power_church = PowerChurchState {
+            power: (site.power_church.power + change.delta_power).max(0.0),
+            church: site.power_church.church,
+            k_ratio: site.power_church.k_ratio,
+        };
 
-        (env, bl, pc, id)
+        PredictedState { env, identity, bioload, power_church }
     }
 
     /// Main guard entrypoint.
@@ -315,64 +770,187 @@ impl BioRailTerrasafeGuard {
     /// - Computes current and predicted biosignature b_i.
     /// - Applies justice tuning to corridors and bioload ceilings.
     /// - Enforces:
-    ///   * RoH ≤ 0.3, DECAY ≤ 1.0, Lifeforce within [min,max]. [file:2]
+    ///   * RoH ≤ the tier's ceiling (0.3 in ControlledHuman), DECAY ≤ 1.0,
+    ///     Lifeforce within [min,max]. [file:2]
     ///   * bioload_body/room/grid ≤ max ceilings. [file:4][file:2]
     ///   * POWER ≤ k·CHURCH. [file:3][file:2]
     ///   * b_i_after within tuned corridor.
     /// - Returns GateVerdict used by higher-level deed engine to block/downscale/repair. [file:4][file:3]
+    ///
+    /// `gate_report` / `gate_into` give the reasons behind the verdict.
     pub fn gate(site: &SiteView,
                 base_cfg: &BioRailConfig,
                 proposed: &ProposedChange) -> GateVerdict
     {
-        // Hard requirement: diagnostics are non-actuating; we ignore them in gating
-        // except as evidence later in logs. [file:6][file:2]
-        debug_assert!(site.diag.role_diagnostic_only);
+        Self::evaluate(site, base_cfg, proposed, &mut Violations::none()).verdict
+    }
 
-        // Justice metrics only tune corridors; get tuned cfg and bioload ceilings. [file:3]
-        let (tuned_cfg, tuned_bioload_max) =
-            Self::apply_justice_tuning(site, base_cfg, &site.bioload_view);
+    /// Same decision as `gate`, with the biosignatures, tuned corridor and
+    /// every violated constraint.
+    pub fn gate_report(site: &SiteView,
+                       base_cfg: &BioRailConfig,
+                       proposed: &ProposedChange) -> GateReport
+    {
+        let mut report = GateReport::default();
+        Self::gate_into(site, base_cfg, proposed, &mut report);
+        report
+    }
 
-        // Compute current biosignature (for monotonicity checks if needed). [file:4]
-        let current_b = Self::compute_biosignature(site);
+    /// Same decision as `gate`, written into a caller-owned report so the
+    /// 100 Hz per-site loop can reuse one buffer and never allocate.
+    /// `violated_constraints` is cleared and refilled; a report from
+    /// `GateReport::default()` already has room for every constraint.
+    pub fn gate_into(site: &SiteView,
+                     base_cfg: &BioRailConfig,
+                     proposed: &ProposedChange,
+                     report: &mut GateReport)
+    {
+        let mut violations = std::mem::take(&mut report.violated_constraints);
+        let mut warnings = std::mem::take(&mut report.warnings);
+        violations.clear();
+        warnings.clear();
+        // Reused as long as the config does not change, so the loop stays
+        // allocation-free.
+        let mut config_version = report.config_version.take();
+        if config_version != base_cfg.config_version {
+            config_version = base_cfg.config_version.clone();
+        }
+        let mut out = Violations::collect(&mut violations, &mut warnings, base_cfg.warning_margin);
+        let outcome = Self::evaluate(site, base_cfg, proposed, &mut out);
+        *report = GateReport { violated_constraints: violations, warnings, config_version, ..outcome };
+    }
 
-        // Predict post-change slices. [file:4]
-        let (pred_env, pred_bioload, pred_pc, pred_id) =
-            Self::predict_post_state(site, proposed, &tuned_bioload_max);
+    /// `gate_report` with the corridor `zones` resolves for `zone`; the
+    /// report's `zone` names the entry used, or is `None` for the fallback.
+    pub fn gate_in_zone(site: &SiteView,
+                        zone: ZoneTag,
+                        zones: &ZoneCorridors,
+                        proposed: &ProposedChange) -> GateReport
+    {
+        let (base_cfg, resolved) = zones.resolve(zone);
+        let mut report = Self::gate_report(site, base_cfg, proposed);
+        report.zone = resolved;
+        report
+    }
 
-        // Enforce envelope invariants first. [file:2]
-        if !Self::check_envelopes(&pred_env) {
-            return GateVerdict::ForceRepair;
-        }
+    /// `gate_report` with the ethics layer's decision on the same deed.
+    ///
+    /// An ethics denial always blocks; an ethics approval never loosens a
+    /// biorail refusal. `violated_constraints` stays the rail's own, and
+    /// `ethics` records the decision, the rail's verdict and whether the two
+    /// contradicted (approved but refused).
+    pub fn gate_with_ethics(site: &SiteView,
+                            base_cfg: &BioRailConfig,
+                            proposed: &ProposedChange,
+                            ethics: &EthicsDecision) -> GateReport
+    {
+        let mut report = Self::gate_report(site, base_cfg, proposed);
+        let (record, verdict) = EthicsRecord::combine(ethics.clone(), report.verdict);
+        report.verdict = verdict;
+        report.ethics = Some(record);
+        report
+    }
 
-        // Enforce BioLoad Terrasafe ceilings. [file:4]
-        if !Self::check_bioload(&pred_bioload) {
-            return GateVerdict::ForceRepair;
-        }
+    /// `gate_report` for each proposal against one site, in input order.
+    ///
+    /// Justice tuning and the current biosignature depend only on the site,
+    /// so they are computed once; each report is identical to the one
+    /// `gate_report` would return for that proposal.
+    pub fn gate_batch(site: &SiteView,
+                      base_cfg: &BioRailConfig,
+                      proposals: &[ProposedChange]) -> Vec<GateReport>
+    {
+        let prepared = Self::prepare(site, base_cfg);
+        proposals
+            .iter()
+            .map(|proposed| {
+                let (mut violations, mut warnings) = (Vec::new(), Vec::new());
+                let mut out = Violations::collect(&mut violations, &mut warnings, base_cfg.warning_margin);
+                let outcome = Self::evaluate_prepared(site, &prepared, proposed, &mut out);
+                GateReport {
+                    violated_constraints: violations,
+                    warnings,
+                    config_version: base_cfg.config_version.clone(),
+                    ..outcome
+                }
+            })
+            .collect()
+    }
+
+    /// Shared body of `gate` and `gate_into`; violations go to `out`.
+    /// The returned report's `violated_constraints` is empty.
+    fn evaluate(site: &SiteView,
+                base_cfg: &BioRailConfig,
+                proposed: &ProposedChange,
+                out: &mut Violations) -> GateReport
+    {
+        let prepared = Self::prepare(site, base_cfg);
+        Self::evaluate_prepared(site, &prepared, proposed, out)
+    }
+
+    /// The per-site half of a gate evaluation.
+    fn prepare(site: &SiteView, base_cfg: &BioRailConfig) -> Prepared {
+        // Hard requirement: diagnostics are non-actuating; we ignore them in gating
+        // except as evidence later in logs. [file:6][file:2]
+        debug_assert!(site.diag.role_diagnostic_only);
 
-        // Enforce POWER ≤ k·CHURCH caps. [file:3][file:2]
-        if !Self::check_power_church(&pred_pc) {
-            return GateVerdict::Block;
+        Prepared {
+            // Justice metrics only tune corridors; get tuned corridor and bioload ceilings. [file:3]
+            tuned: Self::apply_justice_tuning(site, base_cfg),
+            // Compute current biosignature (for monotonicity checks if needed). [file:4]
+            current_b: Self::compute_biosignature(site),
+            power_church_mode: base_cfg.power_church_mode,
+            roh_ceiling: base_cfg.roh_ceiling(),
         }
+    }
+
+    /// The per-proposal half of a gate evaluation.
+    fn evaluate_prepared(site: &SiteView,
+                         prepared: &Prepared,
+                         proposed: &ProposedChange,
+                         out: &mut Violations) -> GateReport
+    {
+        let Prepared { tuned, current_b, .. } = prepared;
+        let current_b = *current_b;
+
+        // Predict post-change slices. [file:4]
+        let pred = Self::predict_post_state(site, proposed, tuned);
 
         // Compute predicted biosignature under new 5D identity. [file:4]
-        let pred_site_view = SiteView {
-            id: site.id,
-            bio_env: pred_env.clone(),
-            identity_5d: pred_id,
-            bioload_view: pred_bioload.clone(),
-            power_church: pred_pc.clone(),
-            justice_metrics: site.justice_metrics.clone(),
-            justice_cfg: site.justice_cfg.clone(),
-            diag: site.diag.clone(),
+        let pred_b = Self::biosignature_of(&pred.env, &pred.identity, &BiosignatureWeights::DEFAULT);
+
+        // Every check runs so the report lists all violations; the verdict
+        // still follows the order below.
+        let consent_ok = out.check_consent(pred.identity.sovereignty_consent);
+        let envelopes_ok = Self::check_envelopes(&pred.env, prepared.roh_ceiling, out);
+        let bioload_ok = Self::check_bioload(&pred.bioload, out);
+        let power_ok = Self::check_power_church(&pred.power_church, out);
+        let window_ok = out.check_window(prepared.power_church_mode);
+        let (lo, hi, b) = (tuned.corridor_min.value(), tuned.corridor_max.value(), pred_b.value());
+        // Inside the corridor only the nearer bound can warn.
+        let corridor_ok = if b < lo || b - lo < hi - b {
+            out.check_floor(b < lo, GateConstraint::CorridorExit, lo, b)
+        } else {
+            out.check_ceiling(b > hi, GateConstraint::CorridorExit, hi, b)
         };
-        let pred_b = Self::compute_biosignature(&pred_site_view);
-
-        // Corridor check on b_i. [file:4][file:3]
-        if pred_b.value() < tuned_cfg.corridor_min.value() ||
-           pred_b.value() > tuned_cfg.corridor_max.value()
-        {
-            // If we are leaving the corridor, classify between Downscale vs ForceRepair
-            // depending on whether risk is increasing compared to current b. [file:4]
+
+        let verdict = if !consent_ok {
+            // Withdrawn consent refuses actuation outright. [file:4][file:2]
+            GateVerdict::Block
+        } else if !envelopes_ok {
+            // Enforce envelope invariants first. [file:2]
+            GateVerdict::ForceRepair
+        } else if !bioload_ok {
+            // Enforce BioLoad Terrasafe ceilings. [file:4]
+            GateVerdict::ForceRepair
+        } else if !power_ok || !window_ok {
+            // Enforce POWER ≤ k·CHURCH caps; fail closed when they cannot be
+            // judged as configured. [file:3][file:2]
+            GateVerdict::Block
+        } else if !corridor_ok {
+            // Corridor check on b_i. If we are leaving the corridor, classify between
+            // Downscale vs ForceRepair depending on whether risk is increasing compared
+            // to current b. [file:4][file:3]
             if pred_b.value() > current_b.value() {
                 GateVerdict::ForceRepair
             } else {
@@ -380,6 +958,751 @@ impl BioRailTerrasafeGuard {
             }
         } else {
             GateVerdict::Allow
+        };
+
+        GateReport {
+            verdict,
+            violated_constraints: Vec::new(),
+            warnings: Vec::new(),
+            current_b,
+            predicted_b: pred_b,
+            tuned_corridor: (tuned.corridor_min, tuned.corridor_max),
+            justice_tightening_applied: tuned.record.is_some(),
+            justice_tuning: tuned.record,
+            zone: None,
+            ethics: None,
+            config_version: None,
+        }
+    }
+
+    /// Largest factor α ∈ (0, 1] such that `proposed` with every delta
+    /// scaled by α passes `gate` with `Allow`, found by bisection to within
+    /// `DOWNSCALE_TOLERANCE`. α = 1 when the change already passes.
+    ///
+    /// Every α returned has been checked against `gate` itself. Returns
+    /// `None` when even an α of `DOWNSCALE_TOLERANCE` is not allowed, i.e.
+    /// the site already violates a constraint that shrinking cannot fix.
+    pub fn synthesize_downscale(site: &SiteView,
+                                base_cfg: &BioRailConfig,
+                                proposed: &ProposedChange) -> Option<ScaledChange>
+    {
+        let allowed = |alpha: f64| Self::gate(site, base_cfg, &proposed.scaled(alpha)) == GateVerdict::Allow;
+
+        let alpha = if allowed(1.0) {
+            1.0
+        } else if !allowed(DOWNSCALE_TOLERANCE) {
+            return None;
+        } else {
+            // Invariant: `lo` is allowed, `hi` is not.
+            let (mut lo, mut hi) = (DOWNSCALE_TOLERANCE, 1.0);
+            while hi - lo > DOWNSCALE_TOLERANCE {
+                let mid = 0.5 * (lo + hi);
+                if allowed(mid) {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            lo
+        };
+
+        let change = proposed.scaled(alpha);
+        let tuned = Self::apply_justice_tuning(site, base_cfg);
+        let pred = Self::predict_post_state(site, &change, &tuned);
+        Some(ScaledChange {
+            alpha,
+            predicted_b: Self::biosignature_of(&pred.env, &pred.identity, &BiosignatureWeights::DEFAULT),
+            predicted_env: pred.env,
+            predicted_bioload: pred.bioload,
+            predicted_power_church: pred.power_church,
+            change,
+        })
+    }
+
+    /// Advisory plan for bringing `site` back inside every constraint,
+    /// judged on its current state against the justice-tuned limits. The
+    /// guard never applies it; the deed engine may turn it into deeds.
+    ///
+    /// Envelope, bioload and POWER targets move exactly the violated
+    /// quantity. A b above the corridor is brought down by shedding the
+    /// largest weighted identity risks first, then by sovereignty relief.
+    /// No change is proposed for a b below the corridor floor, since that
+    /// would mean adding risk; such plans have `resolves == false`.
+    pub fn repair_plan(site: &SiteView, base_cfg: &BioRailConfig) -> RepairPlan {
+        let report = Self::gate_report(site, base_cfg, &ProposedChange::default());
+        let mut targets: Vec<RepairTarget> = report.violated_constraints.iter().map(|v| {
+            // A NaN POWER allowance admits no POWER at all.
+            let delta = if v.limit.is_nan() { -v.predicted } else { v.limit - v.predicted };
+            RepairTarget { constraint: v.constraint, current: v.predicted, limit: v.limit, delta }
+        }).collect();
+
+        let mut change = ProposedChange::default();
+        let mut b_too_high = false;
+        for t in &targets {
+            match t.constraint {
+                // Only the person can give consent back; no change does.
+                GateConstraint::ConsentWithdrawn => {}
+                // Needs the windowed gate, not a different change.
+                GateConstraint::PowerChurchWindow => {}
+                GateConstraint::RohCeiling => change.delta_roh = t.delta,
+                GateConstraint::DecayCeiling => change.delta_decay = t.delta,
+                GateConstraint::LifeforceFloor | GateConstraint::LifeforceCeiling => change.delta_lifeforce_env = t.delta,
+                GateConstraint::BodyBioload => change.delta_bioload_body = t.delta,
+                GateConstraint::RoomBioload => change.delta_bioload_room = t.delta,
+                GateConstraint::GridBioload => change.delta_bioload_grid = t.delta,
+                GateConstraint::PowerChurch => change.delta_power = t.delta,
+                GateConstraint::CorridorExit => b_too_high = t.delta < 0.0,
+            }
+        }
+        if b_too_high {
+            Self::shed_identity_risk(site, base_cfg, &mut change);
+        }
+
+        // Most severe first; ties keep `GateConstraint::ALL` order.
+        targets.sort_by(|a, b| b.severity().total_cmp(&a.severity()));
+        let resolves = Self::gate(site, base_cfg, &change) == GateVerdict::Allow;
+        RepairPlan { targets, change, resolves }
+    }
+
+    /// Adds identity deltas to `change` lowering the predicted b below the
+    /// tuned corridor ceiling, largest weighted contribution first.
+    fn shed_identity_risk(site: &SiteView, base_cfg: &BioRailConfig, change: &mut ProposedChange) {
+        let report = Self::gate_report(site, base_cfg, change);
+        // Aim just inside the ceiling: the corridor check has no tolerance.
+        let mut excess = report.predicted_b.value() - report.tuned_corridor.1.value() + 1e-6;
+        if excess <= 0.0 {
+            return;
+        }
+
+        let w = &BiosignatureWeights::DEFAULT;
+        let id = &site.identity_5d;
+        let mut risks = [
+            (w.biostate_load, id.biostate_load.clamp(0.0, 1.0), &mut change.delta_biostate_load, id.biostate_load),
+            (w.neurostate_fear, id.neurostate_fear.clamp(0.0, 1.0), &mut change.delta_neurostate_fear, id.neurostate_fear),
+            (w.context_load, id.context_load.clamp(0.0, 1.0), &mut change.delta_context_load, id.context_load),
+        ];
+        risks.sort_by(|a, b| (b.0 * b.1).total_cmp(&(a.0 * a.1)));
+        for (weight, level, delta, raw) in risks {
+            if excess <= 0.0 || weight <= 0.0 {
+                continue;
+            }
+            let shed = (excess / weight).min(level);
+            *delta = (level - shed) - raw;
+            excess -= weight * shed;
+        }
+
+        let trust = id.sovereignty_trust.clamp(0.0, 1.0);
+        if excess > 0.0 && w.sovereign_relief > 0.0 {
+            let raise = (excess / w.sovereign_relief).min(1.0 - trust);
+            change.delta_sovereignty_trust = (trust + raise) - id.sovereignty_trust;
+        }
+    }
+
+    /// Applies `proposed` to `site` up to `steps` times in a row, gating
+    /// each application against the state the previous ones left, so a
+    /// change that is safe once but not when repeated shows where it fails.
+    ///
+    /// Justice tuning is fixed by the site's justice metrics, which no
+    /// change moves, so every step is judged against the same tuned limits;
+    /// bioload ceilings stay the site's own. Simulation stops early once an
+    /// application leaves the state unchanged (every delta saturated at a
+    /// clamp), since every later step would repeat it.
+    pub fn simulate_trajectory(site: &SiteView,
+                               base_cfg: &BioRailConfig,
+                               proposed: &ProposedChange,
+                               steps: usize) -> TrajectoryReport
+    {
+        let tuned = Self::apply_justice_tuning(site, base_cfg);
+        let mut cur = site.clone();
+        let mut report = TrajectoryReport {
+            horizon: steps,
+            steps: Vec::new(),
+            first_violation: None,
+            violated_constraints: Vec::new(),
+            settled_at: None,
+        };
+
+        for step in 1..=steps {
+            let gated = Self::gate_report(&cur, base_cfg, proposed);
+            if report.first_violation.is_none() && gated.verdict != GateVerdict::Allow {
+                report.first_violation = Some(step);
+                report.violated_constraints = gated.violated_constraints;
+            }
+            let next = Self::advance(&cur, proposed, &tuned);
+            report.steps.push(TrajectoryStep {
+                step,
+                verdict: gated.verdict,
+                b: gated.predicted_b,
+                roh: next.bio_env.roh,
+                decay: next.bio_env.decay,
+                bioload: next.bioload_view.clone(),
+            });
+            if Self::same_state(&cur, &next) {
+                report.settled_at = Some(step);
+                break;
+            }
+            cur = next;
+        }
+        report
+    }
+
+    /// Headroom left under every constraint `gate` enforces, for planners
+    /// that want more than the verdict.
+    ///
+    /// Evaluated with the same justice-tuned limits and checks as `gate`, so
+    /// the entries with negative slack are exactly `gate_report`'s
+    /// violations, in the same order and with the same limit and predicted
+    /// value.
+    pub fn explain(site: &SiteView,
+                   base_cfg: &BioRailConfig,
+                   proposed: &ProposedChange) -> ConstraintSlackReport
+    {
+        let mut constraints = Vec::with_capacity(GateConstraint::ALL.len());
+        let outcome = Self::evaluate(site, base_cfg, proposed, &mut Violations::slack(&mut constraints));
+        ConstraintSlackReport {
+            verdict: outcome.verdict,
+            predicted_b: outcome.predicted_b,
+            tuned_corridor: outcome.tuned_corridor,
+            constraints,
+        }
+    }
+
+    /// Gates a pipeline of changes in order, each against the state the
+    /// previous ones are predicted to leave, and stops at the first one that
+    /// is not `Allow`. Every step must pass for the pipeline to proceed;
+    /// `ProposedChange::compose` passing is not enough.
+    ///
+    /// As in `simulate_trajectory`, justice tuning is fixed for the whole
+    /// sequence and bioload ceilings stay the site's own.
+    pub fn gate_sequence(site: &SiteView,
+                         base_cfg: &BioRailConfig,
+                         changes: &[ProposedChange]) -> SequenceReport
+    {
+        let tuned = Self::apply_justice_tuning(site, base_cfg);
+        let mut cur = site.clone();
+        let mut reports = Vec::with_capacity(changes.len());
+        for (i, change) in changes.iter().enumerate() {
+            let report = Self::gate_report(&cur, base_cfg, change);
+            let allowed = report.verdict == GateVerdict::Allow;
+            reports.push(report);
+            if !allowed {
+                return SequenceReport { reports, failed_at: Some(i) };
+            }
+            cur = Self::advance(&cur, change, &tuned);
+        }
+        SequenceReport { reports, failed_at: None }
+    }
+
+    /// `site` after `change`, keeping the site's own bioload ceilings.
+    fn advance(site: &SiteView, change: &ProposedChange, tuned: &TunedLimits) -> SiteView {
+        let pred = Self::predict_post_state(site, change, tuned);
+        SiteView {
+            bio_env: pred.env,
+            identity_5d: pred.identity,
+            bioload_view: BioLoadView {
+                body: pred.bioload.body,
+                room: pred.bioload.room,
+                grid: pred.bioload.grid,
+                ..site.bioload_view.clone()
+            },
+            power_church: pred.power_church,
+            ..site.clone()
+        }
+    }
+
+    /// Whether `a` and `b` agree on every quantity a change can move.
+    fn same_state(a: &SiteView, b: &SiteView) -> bool {
+        let (ea, eb) = (&a.bio_env, &b.bio_env);
+        let (ia, ib) = (&a.identity_5d, &b.identity_5d);
+        let (la, lb) = (&a.bioload_view, &b.bioload_view);
+        ea.roh == eb.roh
+            && ea.decay == eb.decay
+            && ea.lifeforce == eb.lifeforce
+            && ia.biostate_load == ib.biostate_load
+            && ia.neurostate_fear == ib.neurostate_fear
+            && ia.lifeforce == ib.lifeforce
+            && ia.context_load == ib.context_load
+            && ia.sovereignty_trust == ib.sovereignty_trust
+            && la.body == lb.body
+            && la.room == lb.room
+            && la.grid == lb.grid
+            && a.power_church.power == b.power_church.power
+    }
+}
+
+/// Smallest step `synthesize_downscale` resolves α to, and the smallest α
+/// it will propose.
+pub const DOWNSCALE_TOLERANCE: f64 = 1e-3;
+
+impl ProposedChange {
+    /// Every delta multiplied by `alpha`.
+    pub fn scaled(&self, alpha: f64) -> ProposedChange {
+        ProposedChange {
+            delta_biostate_load: self.delta_biostate_load * alpha,
+            delta_neurostate_fear: self.delta_neurostate_fear * alpha,
+            delta_lifeforce: self.delta_lifeforce * alpha,
+            delta_context_load: self.delta_context_load * alpha,
+            delta_sovereignty_trust: self.delta_sovereignty_trust * alpha,
+            delta_roh: self.delta_roh * alpha,
+            delta_decay: self.delta_decay * alpha,
+            delta_lifeforce_env: self.delta_lifeforce_env * alpha,
+            delta_bioload_body: self.delta_bioload_body * alpha,
+            delta_bioload_room: self.delta_bioload_room * alpha,
+            delta_bioload_grid: self.delta_bioload_grid * alpha,
+            delta_power: self.delta_power * alpha,
+        }
+    }
+
+    /// One change with every delta summed over `changes`; the empty slice
+    /// composes to the empty change.
+    ///
+    /// Deltas are additive, so composing is order-independent. Applying the
+    /// composition gives the same state as applying `changes` one after
+    /// another only while no clamp saturates in between (e.g. fear pushed
+    /// below 0 by one step and raised by the next), and a passing
+    /// composition says nothing about the intermediate states; use
+    /// `BioRailTerrasafeGuard::gate_sequence` to gate a pipeline.
+    pub fn compose(changes: &[ProposedChange]) -> ProposedChange {
+        changes.iter().fold(ProposedChange::default(), |acc, c| ProposedChange {
+            delta_biostate_load: acc.delta_biostate_load + c.delta_biostate_load,
+            delta_neurostate_fear: acc.delta_neurostate_fear + c.delta_neurostate_fear,
+            delta_lifeforce: acc.delta_lifeforce + c.delta_lifeforce,
+            delta_context_load: acc.delta_context_load + c.delta_context_load,
+            delta_sovereignty_trust: acc.delta_sovereignty_trust + c.delta_sovereignty_trust,
+            delta_roh: acc.delta_roh + c.delta_roh,
+            delta_decay: acc.delta_decay + c.delta_decay,
+            delta_lifeforce_env: acc.delta_lifeforce_env + c.delta_lifeforce_env,
+            delta_bioload_body: acc.delta_bioload_body + c.delta_bioload_body,
+            delta_bioload_room: acc.delta_bioload_room + c.delta_bioload_room,
+            delta_bioload_grid: acc.delta_bioload_grid + c.delta_bioload_grid,
+            delta_power: acc.delta_power + c.delta_power,
+        })
+    }
+}
+
+/// A downscaled change from `synthesize_downscale` and the state it is
+/// predicted to produce.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScaledChange {
+    /// Factor applied to every delta of the original change.
+    pub alpha: f64,
+    pub change: ProposedChange,
+    pub predicted_b: RailScalar,
+    pub predicted_env: BioEnvelope,
+    /// Predicted loads against the justice-tuned ceilings.
+    pub predicted_bioload: BioLoadView,
+    pub predicted_power_church: PowerChurchState,
+}
+
+/// One constraint in a `RepairPlan`: how far the site's current value must
+/// move to satisfy it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RepairTarget {
+    pub constraint: GateConstraint,
+    pub current: f64,
+    pub limit: f64,
+    /// Smallest signed change that satisfies the constraint: negative to
+    /// reduce, positive to raise.
+    pub delta: f64,
+}
+
+impl RepairTarget {
+    /// Size of the violation relative to its limit; plans list the largest
+    /// first.
+    pub fn severity(&self) -> f64 {
+        self.delta.abs() / self.limit.abs().max(1e-9)
+    }
+}
+
+impl fmt::Display for RepairTarget {
+    /// e.g. "reduce RoH by ≥0.05 (0.35 → 0.30)", or "restore consent".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let quantity = match self.constraint {
+            GateConstraint::ConsentWithdrawn => return f.write_str("restore consent"),
+            GateConstraint::PowerChurchWindow => return f.write_str("gate through PowerChurchWindow"),
+            GateConstraint::RohCeiling => "RoH",
+            GateConstraint::DecayCeiling => "DECAY",
+            GateConstraint::LifeforceFloor | GateConstraint::LifeforceCeiling => "lifeforce",
+            GateConstraint::BodyBioload => "body bioload",
+            GateConstraint::RoomBioload => "room bioload",
+            GateConstraint::GridBioload => "grid bioload",
+            GateConstraint::PowerChurch => "POWER",
+            GateConstraint::CorridorExit => "b",
+        };
+        let verb = if self.delta < 0.0 { "reduce" } else { "raise" };
+        write!(f, "{verb} {quantity} by ≥{:.2} ({:.2} → {:.2})", self.delta.abs(), self.current, self.current + self.delta)
+    }
+}
+
+/// Advisory repair guidance from `BioRailTerrasafeGuard::repair_plan`.
+/// Carries no actuation of its own.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RepairPlan {
+    /// Every violated constraint, most severe first; empty for a site
+    /// already inside all of them.
+    pub targets: Vec<RepairTarget>,
+    /// One change meeting every target.
+    pub change: ProposedChange,
+    /// Whether `gate` allows `change`.
+    pub resolves: bool,
+}
+
+/// The state after one application in `simulate_trajectory`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TrajectoryStep {
+    /// 1-based: the state after applying the change `step` times.
+    pub step: usize,
+    /// `gate` verdict for this application.
+    pub verdict: GateVerdict,
+    pub b: RailScalar,
+    pub roh: f64,
+    pub decay: f64,
+    /// Loads after this application, against the site's own ceilings.
+    pub bioload: BioLoadView,
+}
+
+/// Outcome of `BioRailTerrasafeGuard::simulate_trajectory`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TrajectoryReport {
+    /// Steps requested.
+    pub horizon: usize,
+    /// One entry per step simulated; shorter than `horizon` only when the
+    /// state settled.
+    pub steps: Vec<TrajectoryStep>,
+    /// First step whose verdict is not `Allow`.
+    pub first_violation: Option<usize>,
+    /// The constraints violated at `first_violation`; empty if none.
+    pub violated_constraints: Vec<ConstraintViolation>,
+    /// Step after which the state stopped changing, if it did.
+    pub settled_at: Option<usize>,
+}
+
+impl TrajectoryReport {
+    /// Applications that pass the gate before the first one that does not;
+    /// `horizon` if none fails. The deed engine can require a minimum.
+    pub fn safe_horizon(&self) -> usize {
+        self.first_violation.map_or(self.horizon, |step| step - 1)
+    }
+}
+
+/// One constraint's headroom from `BioRailTerrasafeGuard::explain`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConstraintSlack {
+    pub constraint: GateConstraint,
+    pub limit: f64,
+    pub predicted: f64,
+    /// How far `predicted` may still move toward `limit`; negative when the
+    /// constraint is violated. For the corridor, measured to the nearer
+    /// bound, which is `limit`.
+    pub slack: f64,
+}
+
+/// Every enforced constraint with its slack, in `GateConstraint::ALL` order.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConstraintSlackReport {
+    pub verdict: GateVerdict,
+    pub predicted_b: RailScalar,
+    pub tuned_corridor: (RailScalar, RailScalar),
+    pub constraints: Vec<ConstraintSlack>,
+}
+
+impl ConstraintSlackReport {
+    /// Slack of `constraint`.
+    pub fn slack(&self, constraint: GateConstraint) -> Option<f64> {
+        self.constraints.iter().find(|c| c.constraint == constraint).map(|c| c.slack)
+    }
+
+    /// The constraint with the least slack.
+    pub fn tightest(&self) -> Option<&ConstraintSlack> {
+        self.constraints.iter().min_by(|a, b| a.slack.total_cmp(&b.slack))
+    }
+
+    /// Entries with negative slack: the violated constraints.
+    pub fn violated(&self) -> impl Iterator<Item = &ConstraintSlack> {
+        self.constraints.iter().filter(|c| c.slack < 0.0)
+    }
+}
+
+/// Outcome of `BioRailTerrasafeGuard::gate_sequence`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SequenceReport {
+    /// One report per step gated, in order; the last is the failing one
+    /// when `failed_at` is set.
+    pub reports: Vec<GateReport>,
+    /// Index of the first step that is not `Allow`.
+    pub failed_at: Option<usize>,
+}
+
+impl SequenceReport {
+    /// `Allow` if every step passed, else the failing step's verdict.
+    pub fn verdict(&self) -> GateVerdict {
+        self.failure().map_or(GateVerdict::Allow, |(_, report)| report.verdict)
+    }
+
+    /// The first failing step and its report.
+    pub fn failure(&self) -> Option<(usize, &GateReport)> {
+        let i = self.failed_at?;
+        Some((i, &self.reports[i]))
+    }
+}
+
+/// Where constraint checks record what they found; `None` when only the
+/// verdict is wanted.
+struct Violations<'a> {
+    violations: Option<&'a mut Vec<ConstraintViolation>>,
+    /// `None` also when the margin disables warnings.
+    warnings: Option<&'a mut Vec<ConstraintViolation>>,
+    margin: f64,
+    /// Every check, held or not, for `explain`.
+    slack: Option<&'a mut Vec<ConstraintSlack>>,
+}
+
+impl<'a> Violations<'a> {
+    /// Records nothing; for callers that only need the verdict.
+    fn none() -> Self {
+        Violations { violations: None, warnings: None, margin: 0.0, slack: None }
+    }
+
+    /// Records the slack of every check and nothing else.
+    fn slack(slack: &'a mut Vec<ConstraintSlack>) -> Self {
+        Violations { violations: None, warnings: None, margin: 0.0, slack: Some(slack) }
+    }
+
+    fn collect(violations: &'a mut Vec<ConstraintViolation>,
+               warnings: &'a mut Vec<ConstraintViolation>,
+               margin: f64) -> Self
+    {
+        let warn = margin.is_finite() && margin > 0.0;
+        Violations { violations: Some(violations), warnings: warn.then_some(warnings), margin, slack: None }
+    }
+
+    /// Records the violation if `violated`; returns whether the constraint held.
+    fn check(&mut self, violated: bool, constraint: GateConstraint, limit: f64, predicted: f64) -> bool {
+        if let (true, Some(out)) = (violated, self.violations.as_mut()) {
+            out.push(ConstraintViolation { constraint, limit, predicted });
+        }
+        !violated
+    }
+
+    /// `check` for an upper limit; a held constraint warns when `predicted`
+    /// is within the margin below `limit`.
+    fn check_ceiling(&mut self, violated: bool, constraint: GateConstraint, limit: f64, predicted: f64) -> bool {
+        let held = self.check(violated, constraint, limit, predicted);
+        self.warn(held && predicted >= limit - self.margin * limit.abs(), constraint, limit, predicted);
+        self.record_slack(held, constraint, limit, predicted, limit - predicted);
+        held
+    }
+
+    /// `check` for a lower limit; a held constraint warns when `predicted`
+    /// is within the margin above `limit`.
+    fn check_floor(&mut self, violated: bool, constraint: GateConstraint, limit: f64, predicted: f64) -> bool {
+        let held = self.check(violated, constraint, limit, predicted);
+        self.warn(held && predicted <= limit + self.margin * limit.abs(), constraint, limit, predicted);
+        self.record_slack(held, constraint, limit, predicted, predicted - limit);
+        held
+    }
+
+    /// `check` for `sovereignty_consent`. Consent has no continuous headroom:
+    /// its slack is ∞ while given, since no change can withdraw it, and −1
+    /// once withdrawn.
+    fn check_consent(&mut self, given: bool) -> bool {
+        let predicted = if given { 1.0 } else { 0.0 };
+        let held = self.check(!given, GateConstraint::ConsentWithdrawn, 1.0, predicted);
+        self.record_slack(held, GateConstraint::ConsentWithdrawn, 1.0, predicted, if given { f64::INFINITY } else { -1.0 });
+        held
+    }
+
+    /// `check` that POWER ≤ k·CHURCH was judged as configured: a stateless
+    /// evaluation has no history for `Windowed`. Like consent it has no
+    /// continuous headroom: slack ∞ when judged, −1 when not.
+    fn check_window(&mut self, mode: PowerChurchMode) -> bool {
+        let judged = mode == PowerChurchMode::Instantaneous;
+        let predicted = if judged { 1.0 } else { 0.0 };
+        let held = self.check(!judged, GateConstraint::PowerChurchWindow, 1.0, predicted);
+        self.record_slack(held, GateConstraint::PowerChurchWindow, 1.0, predicted, if judged { f64::INFINITY } else { -1.0 });
+        held
+    }
+
+    /// Signed slack whose sign agrees with the check: `headroom` rounded up
+    /// to 0 inside the 1e-9 tolerance, and −∞ for a violation with no
+    /// finite headroom (a NaN POWER allowance).
+    fn record_slack(&mut self, held: bool, constraint: GateConstraint, limit: f64, predicted: f64, headroom: f64) {
+        let Some(out) = self.slack.as_mut() else { return };
+        let slack = if held {
+            headroom.max(0.0)
+        } else if headroom < 0.0 {
+            headroom
+        } else {
+            f64::NEG_INFINITY
+        };
+        out.push(ConstraintSlack { constraint, limit, predicted, slack });
+    }
+
+    fn warn(&mut self, near: bool, constraint: GateConstraint, limit: f64, predicted: f64) {
+        if let (true, Some(out)) = (near, self.warnings.as_mut()) {
+            out.push(ConstraintViolation { constraint, limit, predicted });
+        }
+    }
+}
+
+/// Site-level inputs shared by every proposal gated against one site.
+struct Prepared {
+    tuned: TunedLimits,
+    current_b: RailScalar,
+    power_church_mode: PowerChurchMode,
+    roh_ceiling: f64,
+}
+
+/// Corridor and bioload ceilings after justice tuning.
+struct TunedLimits {
+    corridor_min: RailScalar,
+    corridor_max: RailScalar,
+    body_max: RailScalar,
+    room_max: RailScalar,
+    grid_max: RailScalar,
+    /// Present exactly when tightening fired.
+    record: Option<JusticeTuningRecord>,
+}
+
+/// Post-change slices produced by `predict_post_state`.
+struct PredictedState {
+    env: BioEnvelope,
+    identity: FiveDIdentity,
+    bioload: BioLoadView,
+    power_church: PowerChurchState,
+}
+
+/// A constraint `gate` enforces on the predicted state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GateConstraint {
+    /// `sovereignty_consent` is true; limit 1, predicted 1 or 0. [file:4]
+    ConsentWithdrawn,
+    /// RoH ≤ `BioRailConfig::roh_ceiling`, 0.3 in `ControlledHuman`. [file:2]
+    RohCeiling,
+    /// DECAY ≤ 1.0. [file:2]
+    DecayCeiling,
+    /// Lifeforce ≥ `lifeforce_min`.
+    LifeforceFloor,
+    /// Lifeforce ≤ `lifeforce_max`.
+    LifeforceCeiling,
+    /// Bioload ≤ the tuned body/room/grid ceiling. [file:4]
+    BodyBioload,
+    RoomBioload,
+    GridBioload,
+    /// POWER ≤ k·CHURCH. [file:3]
+    PowerChurch,
+    /// A `Windowed` POWER ≤ k·CHURCH config reached a stateless gate, which
+    /// has no sample history; limit 1, predicted 0. Gate through
+    /// `PowerChurchWindow` instead.
+    PowerChurchWindow,
+    /// Predicted b_i outside the tuned corridor; the limit is the bound crossed.
+    CorridorExit,
+}
+
+impl GateConstraint {
+    pub const ALL: [GateConstraint; 11] = [
+        GateConstraint::ConsentWithdrawn,
+        GateConstraint::RohCeiling,
+        GateConstraint::DecayCeiling,
+        GateConstraint::LifeforceFloor,
+        GateConstraint::LifeforceCeiling,
+        GateConstraint::BodyBioload,
+        GateConstraint::RoomBioload,
+        GateConstraint::GridBioload,
+        GateConstraint::PowerChurch,
+        GateConstraint::PowerChurchWindow,
+        GateConstraint::CorridorExit,
+    ];
+
+    /// Stable snake_case name, e.g. for audit records.
+    pub fn name(self) -> &'static str {
+        match self {
+            GateConstraint::ConsentWithdrawn => "consent_withdrawn",
+            GateConstraint::RohCeiling => "roh_ceiling",
+            GateConstraint::DecayCeiling => "decay_ceiling",
+            GateConstraint::LifeforceFloor => "lifeforce_floor",
+            GateConstraint::LifeforceCeiling => "lifeforce_ceiling",
+            GateConstraint::BodyBioload => "body_bioload",
+            GateConstraint::RoomBioload => "room_bioload",
+            GateConstraint::GridBioload => "grid_bioload",
+            GateConstraint::PowerChurch => "power_church",
+            GateConstraint::PowerChurchWindow => "power_church_window",
+            GateConstraint::CorridorExit => "corridor_exit",
+        }
+    }
+}
+
+/// One violated constraint: the limit in force and the predicted value
+/// that crossed it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConstraintViolation {
+    pub constraint: GateConstraint,
+    pub limit: f64,
+    pub predicted: f64,
+}
+
+/// Outcome of one gate evaluation; reusable across calls via `gate_into`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GateReport {
+    pub verdict: GateVerdict,
+    /// Every constraint the predicted state violates, in `GateConstraint::ALL`
+    /// order; empty exactly when the verdict is `Allow`, or when an ethics
+    /// denial alone blocked (see `ethics`).
+    pub violated_constraints: Vec<ConstraintViolation>,
+    /// Constraints that hold but whose predicted value is within
+    /// `BioRailConfig::warning_margin` of the limit, in `GateConstraint::ALL`
+    /// order. Non-blocking: they never change the verdict.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub warnings: Vec<ConstraintViolation>,
+    /// Biosignature before the proposed change.
+    pub current_b: RailScalar,
+    /// Biosignature predicted after the proposed change.
+    pub predicted_b: RailScalar,
+    /// Effective (min, max) corridor after justice tuning.
+    pub tuned_corridor: (RailScalar, RailScalar),
+    pub justice_tightening_applied: bool,
+    /// What justice tuning changed; present exactly when
+    /// `justice_tightening_applied`.
+    pub justice_tuning: Option<JusticeTuningRecord>,
+    /// Zone whose corridor was applied by `gate_in_zone`; `None` for the
+    /// fallback corridor and for the flat `gate_*` calls.
+    pub zone: Option<ZoneTag>,
+    /// The ethics decision `gate_with_ethics` applied; `None` from every
+    /// other gate call.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ethics: Option<EthicsRecord>,
+    /// `BioRailConfig::config_version` of the config the gate ran under.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub config_version: Option<ConfigVersion>,
+}
+
+impl Default for GateReport {
+    /// Placeholder buffer for `gate_into`; the conservative verdict is
+    /// overwritten on the first evaluation.
+    fn default() -> Self {
+        Self {
+            verdict: GateVerdict::Block,
+            violated_constraints: Vec::with_capacity(GateConstraint::ALL.len()),
+            warnings: Vec::with_capacity(GateConstraint::ALL.len()),
+            current_b: RailScalar::new_clamped(0.0),
+            predicted_b: RailScalar::new_clamped(0.0),
+            tuned_corridor: (RailScalar::new_clamped(0.0), RailScalar::new_clamped(0.0)),
+            justice_tightening_applied: false,
+            justice_tuning: None,
+            zone: None,
+            ethics: None,
+            config_version: None,
         }
     }
 }
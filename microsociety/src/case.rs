@@ -0,0 +1,65 @@
+//! Gate decisions captured as JSON files, so a surprising verdict from the
+//! field can be replayed exactly in a test.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use core_contract::config_version::ConfigVersion;
+use serde::{Deserialize, Serialize};
+
+use crate::biorail_terrasafe::{BioRailConfig, BioRailTerrasafeGuard, GateReport, ProposedChange, SiteView};
+
+/// The inputs of one `gate_report` call and the report it produced.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GateCase {
+    pub site: SiteView,
+    pub config: BioRailConfig,
+    pub proposed: ProposedChange,
+    pub report: GateReport,
+}
+
+impl GateCase {
+    /// Runs `gate_report` and keeps its inputs alongside the result.
+    pub fn capture(site: &SiteView, config: &BioRailConfig, proposed: &ProposedChange) -> Self {
+        GateCase {
+            site: site.clone(),
+            config: config.clone(),
+            proposed: proposed.clone(),
+            report: BioRailTerrasafeGuard::gate_report(site, config, proposed),
+        }
+    }
+
+    /// Re-runs the gate on the stored inputs. Equal to `report` unless the
+    /// gate's behaviour changed since the case was captured.
+    pub fn replay(&self) -> GateReport {
+        BioRailTerrasafeGuard::gate_report(&self.site, &self.config, &self.proposed)
+    }
+
+    /// Re-runs the gate on the stored site and proposal under `config`.
+    /// Refused when `config` is not the version the case was recorded under
+    /// (including one side being unversioned) unless `cross_config` is set.
+    pub fn replay_under(&self, config: &BioRailConfig, cross_config: bool) -> Result<GateReport, String> {
+        let hash = |v: &Option<ConfigVersion>| v.as_ref().map_or("no config version".to_string(), |v| v.hash.clone());
+        let (recorded, replay) = (hash(&self.report.config_version), hash(&config.config_version));
+        if recorded != replay && !cross_config {
+            return Err(format!(
+                "Config mismatch: recorded under {recorded} but replaying under {replay}; pass --cross-config to compare anyway."
+            ));
+        }
+        Ok(BioRailTerrasafeGuard::gate_report(&self.site, config, &self.proposed))
+    }
+
+    /// Writes the case to `path` as pretty-printed JSON.
+    pub fn save_json(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::from)?;
+        fs::write(path, json)
+    }
+
+    /// Reads a case written by `save_json`. Malformed files fail with
+    /// `io::ErrorKind::InvalidData`, truncated ones with `UnexpectedEof`.
+    pub fn load_json(path: impl AsRef<Path>) -> io::Result<Self> {
+        let json = fs::read_to_string(path)?;
+        serde_json::from_str(&json).map_err(io::Error::from)
+    }
+}
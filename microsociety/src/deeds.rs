@@ -0,0 +1,182 @@
+//! Deed effects projected onto the deltas the gate judges.
+//!
+//! The deed engine describes a deed by what it does (stimulation, material
+//! deployment, data collection, XR exposure), not by envelope, bioload and
+//! identity deltas. A `DeedEffectProjector` turns the declared effects into
+//! one `ProposedChange` for `BioRailTerrasafeGuard`. Projection fails
+//! closed: an effect the projector cannot price rejects the whole deed
+//! rather than being gated as if it did nothing. [file:4][file:3]
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::biorail_terrasafe::ProposedChange;
+
+/// One declared effect of a deed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DeedEffect {
+    /// Effect category, e.g. "stimulation"; looked up in the projector's table.
+    pub category: String,
+    /// Non-negative intensity multiplying the category's per-unit deltas.
+    pub magnitude: f64,
+    /// Required when the effect would lower RoH, so a deed cannot buy gate
+    /// headroom by claiming to be restorative.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub justification: Option<String>,
+}
+
+/// A deed as the engine proposes it, before projection.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ProposedDeed {
+    pub deed_id: String,
+    pub effects: Vec<DeedEffect>,
+}
+
+/// A deed and the change it projects to, kept together for auditing.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DeedProjection {
+    pub deed: ProposedDeed,
+    /// Per-effect changes, in `deed.effects` order.
+    pub effects: Vec<ProposedChange>,
+    /// `ProposedChange::compose` of `effects`; what goes to the gate.
+    pub change: ProposedChange,
+}
+
+/// Why a deed could not be projected.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProjectionError {
+    /// No table entry for the category.
+    UnknownEffect { category: String },
+    /// Negative or non-finite magnitude.
+    InvalidMagnitude { category: String, magnitude: f64 },
+    /// The effect lowers RoH but carries no justification.
+    UnjustifiedRohReduction { category: String, delta_roh: f64 },
+}
+
+impl fmt::Display for ProjectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownEffect { category } => write!(f, "unknown deed effect {category:?}"),
+            Self::InvalidMagnitude { category, magnitude } => {
+                write!(f, "effect {category:?} has invalid magnitude {magnitude}")
+            }
+            Self::UnjustifiedRohReduction { category, delta_roh } => {
+                write!(f, "effect {category:?} lowers RoH by {} without justification", -delta_roh)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProjectionError {}
+
+/// Turns a deed's declared effects into the change the gate judges.
+pub trait DeedEffectProjector {
+    /// Fails on the first effect that cannot be projected.
+    fn project(&self, deed: &ProposedDeed) -> Result<DeedProjection, ProjectionError>;
+}
+
+/// Per-unit deltas for each effect category.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EffectTable {
+    entries: BTreeMap<String, ProposedChange>,
+}
+
+impl EffectTable {
+    /// A table with no categories; every effect is unknown.
+    pub fn empty() -> Self {
+        Self { entries: BTreeMap::new() }
+    }
+
+    /// Sets the deltas of one unit of `category`, replacing any earlier entry.
+    pub fn with_effect(mut self, category: impl Into<String>, per_unit: ProposedChange) -> Self {
+        self.entries.insert(category.into(), per_unit);
+        self
+    }
+
+    pub fn get(&self, category: &str) -> Option<&ProposedChange> {
+        self.entries.get(category)
+    }
+
+    pub fn categories(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+}
+
+impl Default for EffectTable {
+    /// Conservative per-unit deltas for the four categories the deed engine
+    /// emits today. None lowers RoH. [file:4][file:3]
+    fn default() -> Self {
+        Self::empty()
+            .with_effect("stimulation", ProposedChange {
+                delta_neurostate_fear: 0.05,
+                delta_biostate_load: 0.02,
+                delta_roh: 0.01,
+                delta_bioload_body: 0.05,
+                ..ProposedChange::default()
+            })
+            .with_effect("material_deployment", ProposedChange {
+                delta_context_load: 0.05,
+                delta_decay: 0.02,
+                delta_bioload_room: 0.05,
+                delta_bioload_grid: 0.02,
+                ..ProposedChange::default()
+            })
+            .with_effect("data_collection", ProposedChange {
+                delta_context_load: 0.02,
+                delta_sovereignty_trust: -0.02,
+                delta_power: 0.1,
+                ..ProposedChange::default()
+            })
+            .with_effect("xr_exposure", ProposedChange {
+                delta_neurostate_fear: 0.03,
+                delta_context_load: 0.05,
+                delta_roh: 0.005,
+                delta_bioload_room: 0.03,
+                ..ProposedChange::default()
+            })
+    }
+}
+
+/// `DeedEffectProjector` scaling table entries by each effect's magnitude.
+#[derive(Debug, Clone, Default)]
+pub struct TableProjector {
+    table: EffectTable,
+}
+
+impl TableProjector {
+    pub fn new(table: EffectTable) -> Self {
+        Self { table }
+    }
+
+    pub fn table(&self) -> &EffectTable {
+        &self.table
+    }
+
+    fn project_effect(&self, effect: &DeedEffect) -> Result<ProposedChange, ProjectionError> {
+        let category = || effect.category.clone();
+        let per_unit = self.table.get(&effect.category).ok_or_else(|| ProjectionError::UnknownEffect { category: category() })?;
+        if !(effect.magnitude.is_finite() && effect.magnitude >= 0.0) {
+            return Err(ProjectionError::InvalidMagnitude { category: category(), magnitude: effect.magnitude });
+        }
+        let change = per_unit.scaled(effect.magnitude);
+        let justified = effect.justification.as_deref().is_some_and(|j| !j.trim().is_empty());
+        if change.delta_roh < 0.0 && !justified {
+            return Err(ProjectionError::UnjustifiedRohReduction { category: category(), delta_roh: change.delta_roh });
+        }
+        Ok(change)
+    }
+}
+
+impl DeedEffectProjector for TableProjector {
+    fn project(&self, deed: &ProposedDeed) -> Result<DeedProjection, ProjectionError> {
+        let effects = deed.effects.iter().map(|e| self.project_effect(e)).collect::<Result<Vec<_>, _>>()?;
+        Ok(DeedProjection { change: ProposedChange::compose(&effects), effects, deed: deed.clone() })
+    }
+}
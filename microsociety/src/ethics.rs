@@ -0,0 +1,71 @@
+//! Ethics-layer decisions folded into the biorail gate.
+//!
+//! The ethics layer and the biorail gate judge the same deed independently.
+//! `BioRailTerrasafeGuard::gate_with_ethics` combines them with one fixed
+//! precedence: an ethics denial always blocks, and an ethics approval never
+//! loosens a biorail refusal. The case where the two disagree in the
+//! approving direction is recorded as a contradiction so audit can find
+//! deeds the ethics layer cleared but the rail would not carry. [file:2][file:4]
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::audit::is_refusal;
+use crate::biorail_terrasafe::GateVerdict;
+
+/// The ethics layer's ruling on a deed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EthicsDecision {
+    Approve,
+    Deny { reason: String },
+}
+
+impl EthicsDecision {
+    pub fn is_denial(&self) -> bool {
+        matches!(self, Self::Deny { .. })
+    }
+}
+
+/// How the ethics decision and the biorail verdict combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EthicsOutcome {
+    /// Both layers refuse, or ethics approves and the rail does not refuse;
+    /// the biorail verdict stands, except that a denial turns `ForceRepair`
+    /// into `Block`.
+    Agreed,
+    /// Ethics denied a deed the rail would have let through; the verdict
+    /// is `Block`.
+    EthicsOverride,
+    /// Ethics approved a deed the rail refuses; the biorail verdict stands.
+    Contradiction,
+}
+
+/// The ethics input to a gate decision, kept on the report.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EthicsRecord {
+    pub decision: EthicsDecision,
+    /// Verdict of the rail alone, before precedence was applied.
+    pub biorail_verdict: GateVerdict,
+    pub outcome: EthicsOutcome,
+}
+
+impl EthicsRecord {
+    /// Applies the precedence rule; returns the record and the combined verdict.
+    pub fn combine(decision: EthicsDecision, biorail_verdict: GateVerdict) -> (Self, GateVerdict) {
+        let refused = is_refusal(biorail_verdict);
+        let (outcome, verdict) = match (decision.is_denial(), refused) {
+            (true, true) => (EthicsOutcome::Agreed, GateVerdict::Block),
+            (true, false) => (EthicsOutcome::EthicsOverride, GateVerdict::Block),
+            (false, true) => (EthicsOutcome::Contradiction, biorail_verdict),
+            (false, false) => (EthicsOutcome::Agreed, biorail_verdict),
+        };
+        (Self { decision, biorail_verdict, outcome }, verdict)
+    }
+
+    pub fn is_contradiction(&self) -> bool {
+        self.outcome == EthicsOutcome::Contradiction
+    }
+}
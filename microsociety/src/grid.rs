@@ -0,0 +1,122 @@
+//! Shared grid budget across the sites of one territory.
+//!
+//! `BioLoadView::grid_max` is a per-site ceiling, but the grid behind it is
+//! one physical budget: ten sites each well under their own ceiling can
+//! still overdraw it together. `GridAggregator` splits the territory budget
+//! into per-site grid ceilings, never above a site's own `grid_max`, and
+//! `GridCeilings::gate_report` gates a site against its share. [file:4][file:2]
+//!
+//! Aggregation is a pure function of the snapshot: sites are taken in id
+//! order, so the same sites in any order give bit-identical ceilings.
+
+use std::collections::BTreeMap;
+
+use crate::biorail_terrasafe::{
+    BioLoadView, BioRailConfig, BioRailTerrasafeGuard, GateReport, ProposedChange, RailScalar, SiteView,
+};
+
+/// How the territory grid budget is divided when the sites' own ceilings
+/// add up to more than it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GridAllocation {
+    /// Each site gets budget · grid_max / Σ grid_max.
+    Proportional,
+    /// Sites are served their full `grid_max` in descending priority, ties
+    /// by ascending site id, until the budget runs out. Sites missing from
+    /// the map have priority 0.
+    Priority(BTreeMap<usize, u32>),
+}
+
+/// Splits a territory's grid budget into per-site ceilings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridAggregator {
+    /// Total grid load the territory can carry, in the units of
+    /// `BioLoadView::grid` summed over sites. Non-finite or negative
+    /// budgets are treated as 0.
+    pub budget: f64,
+    pub allocation: GridAllocation,
+}
+
+/// Per-site grid ceilings for one snapshot; see `GridAggregator::aggregate`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridCeilings {
+    /// Σ grid over the snapshot.
+    pub aggregate_load: f64,
+    pub budget: f64,
+    ceilings: BTreeMap<usize, RailScalar>,
+}
+
+impl GridAggregator {
+    pub fn new(budget: f64, allocation: GridAllocation) -> Self {
+        GridAggregator { budget, allocation }
+    }
+
+    /// Effective grid ceilings for every site in `sites`, one territory's
+    /// snapshot. If a site id appears twice, the first view is used.
+    pub fn aggregate(&self, sites: &[SiteView]) -> GridCeilings {
+        let mut views: BTreeMap<usize, &BioLoadView> = BTreeMap::new();
+        for site in sites {
+            views.entry(site.id).or_insert(&site.bioload_view);
+        }
+        let budget = if self.budget.is_finite() { self.budget.max(0.0) } else { 0.0 };
+        let aggregate_load = views.values().map(|v| v.grid.value()).sum();
+        let entitled: f64 = views.values().map(|v| v.grid_max.value()).sum();
+
+        let ceilings = if entitled <= budget {
+            views.iter().map(|(&id, v)| (id, v.grid_max)).collect()
+        } else {
+            match &self.allocation {
+                GridAllocation::Proportional => views
+                    .iter()
+                    .map(|(&id, v)| (id, RailScalar::new_clamped(budget * v.grid_max.value() / entitled)))
+                    .collect(),
+                GridAllocation::Priority(priorities) => {
+                    let mut order: Vec<_> = views.iter().collect();
+                    // Stable sort keeps ascending ids within a priority.
+                    order.sort_by_key(|(id, _)| std::cmp::Reverse(priorities.get(id).copied().unwrap_or(0)));
+                    let mut remaining = budget;
+                    order
+                        .into_iter()
+                        .map(|(&id, v)| {
+                            let share = v.grid_max.value().min(remaining);
+                            remaining -= share;
+                            (id, RailScalar::new_clamped(share))
+                        })
+                        .collect()
+                }
+            }
+        };
+        GridCeilings { aggregate_load, budget, ceilings }
+    }
+}
+
+impl GridCeilings {
+    /// Whether the snapshot's sites together exceed the budget right now.
+    pub fn oversubscribed(&self) -> bool {
+        self.aggregate_load > self.budget + 1e-9
+    }
+
+    /// Effective grid ceiling of `site_id`, or `None` if it was not in the
+    /// snapshot.
+    pub fn ceiling(&self, site_id: usize) -> Option<RailScalar> {
+        self.ceilings.get(&site_id).copied()
+    }
+
+    /// `BioRailTerrasafeGuard::gate_report` with the site's `grid_max`
+    /// replaced by its effective ceiling. The budget was split over the
+    /// snapshot only, so a site that was not in it has no share: it is
+    /// gated against a grid ceiling of 0 and any grid load violates
+    /// `GridBioload`.
+    pub fn gate_report(&self,
+                       site: &SiteView,
+                       base_cfg: &BioRailConfig,
+                       proposed: &ProposedChange) -> GateReport
+    {
+        let grid_max = self.ceiling(site.id).unwrap_or(RailScalar::new_clamped(0.0));
+        let shared = SiteView {
+            bioload_view: BioLoadView { grid_max, ..site.bioload_view.clone() },
+            ..site.clone()
+        };
+        BioRailTerrasafeGuard::gate_report(&shared, base_cfg, proposed)
+    }
+}
@@ -0,0 +1,13 @@
+//! MicroSociety enforcement spine: the BioRail scalar gate and Terrasafe guard.
+
+pub mod audit;
+pub mod biorail_terrasafe;
+#[cfg(feature = "case-files")]
+pub mod case;
+pub mod deeds;
+pub mod ethics;
+pub mod grid;
+pub mod power_window;
+pub mod repair;
+#[cfg(feature = "test-util")]
+pub mod test_util;
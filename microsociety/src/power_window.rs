@@ -0,0 +1,119 @@
+//! Rolling POWER and CHURCH averages for windowed POWER ≤ k·CHURCH checks.
+//!
+//! Readings are recorded per site as they arrive and treated as holding
+//! until the next one, so averages are weighted by how long each value was
+//! in effect rather than by how often it was sampled. Time is whatever
+//! monotone unit the caller uses consistently, e.g. seconds since start.
+
+use std::collections::{BTreeMap, VecDeque};
+
+use crate::biorail_terrasafe::{
+    BioRailConfig, BioRailTerrasafeGuard, GateReport, PowerChurchMode, PowerChurchState, ProposedChange, SiteView,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Sample {
+    at: f64,
+    power: f64,
+    church: f64,
+}
+
+/// Time-weighted POWER and CHURCH over a window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PowerChurchAverage {
+    pub power: f64,
+    pub church: f64,
+}
+
+/// Per-site sample history behind `PowerChurchMode::Windowed`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PowerChurchWindow {
+    retention: f64,
+    sites: BTreeMap<usize, VecDeque<Sample>>,
+}
+
+impl PowerChurchWindow {
+    /// Keeps enough history to average over windows up to `retention` long;
+    /// use at least the longest window configured.
+    pub fn new(retention: f64) -> Self {
+        Self { retention: retention.max(0.0), sites: BTreeMap::new() }
+    }
+
+    /// Records the readings of `site_id` at time `at`. Samples may arrive out
+    /// of order; a sample at the same time as an existing one replaces it.
+    /// Samples with a non-finite field are ignored.
+    pub fn record_sample(&mut self, site_id: usize, power: f64, church: f64, at: f64) {
+        if !(at.is_finite() && power.is_finite() && church.is_finite()) {
+            return;
+        }
+        let samples = self.sites.entry(site_id).or_default();
+        let sample = Sample { at, power, church };
+        let i = samples.partition_point(|s| s.at < at);
+        match samples.get_mut(i) {
+            Some(s) if s.at == at => *s = sample,
+            _ => samples.insert(i, sample),
+        }
+
+        // Drop samples that ended before the retention horizon; the last one
+        // starting before it still covers the start of the longest window.
+        let horizon = samples.back().map_or(at, |s| s.at) - self.retention;
+        while samples.len() > 1 && samples[1].at <= horizon {
+            samples.pop_front();
+        }
+    }
+
+    /// Averages of `site_id` over `[at - window, at]`, or `None` if it has no
+    /// sample at or before `at`. Time before the first sample is not counted;
+    /// a window of 0 gives the readings in effect at `at`.
+    pub fn average(&self, site_id: usize, window: f64, at: f64) -> Option<PowerChurchAverage> {
+        let samples = self.sites.get(&site_id)?;
+        let end = samples.partition_point(|s| s.at <= at);
+        let last = samples.get(end.checked_sub(1)?)?;
+        let start = at - window.max(0.0);
+
+        let (mut power, mut church, mut covered) = (0.0, 0.0, 0.0);
+        for (i, s) in samples.range(..end).enumerate() {
+            let until = samples.get(i + 1).map_or(at, |next| next.at.min(at));
+            let span = until - s.at.max(start);
+            if span > 0.0 {
+                power += s.power * span;
+                church += s.church * span;
+                covered += span;
+            }
+        }
+        if covered > 0.0 {
+            Some(PowerChurchAverage { power: power / covered, church: church / covered })
+        } else {
+            Some(PowerChurchAverage { power: last.power, church: last.church })
+        }
+    }
+
+    /// `BioRailTerrasafeGuard::gate_report` with POWER ≤ k·CHURCH judged as
+    /// `base_cfg.power_church_mode` asks.
+    ///
+    /// In `Windowed` mode the site's POWER and CHURCH are replaced by their
+    /// averages up to `at`, and the proposed `delta_power` is added to the
+    /// average POWER. A site with no samples yet is judged on its
+    /// instantaneous readings.
+    pub fn gate_report(&self,
+                       site: &SiteView,
+                       base_cfg: &BioRailConfig,
+                       proposed: &ProposedChange,
+                       at: f64) -> GateReport
+    {
+        let PowerChurchMode::Windowed { window } = base_cfg.power_church_mode else {
+            return BioRailTerrasafeGuard::gate_report(site, base_cfg, proposed);
+        };
+        // The readings below are the ones to judge, so the stateless gate
+        // sees an instantaneous config.
+        let instant_cfg = BioRailConfig { power_church_mode: PowerChurchMode::Instantaneous, ..base_cfg.clone() };
+        let Some(avg) = self.average(site.id, window, at) else {
+            return BioRailTerrasafeGuard::gate_report(site, &instant_cfg, proposed);
+        };
+        let windowed = SiteView {
+            power_church: PowerChurchState { power: avg.power, church: avg.church, k_ratio: site.power_church.k_ratio },
+            ..site.clone()
+        };
+        BioRailTerrasafeGuard::gate_report(&windowed, &instant_cfg, proposed)
+    }
+}
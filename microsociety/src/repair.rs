@@ -0,0 +1,132 @@
+//! ForceRepair recovery tracking.
+//!
+//! `BioRailTerrasafeGuard::gate` is stateless: a site that trips
+//! `ForceRepair` is judged afresh on the next call. `RepairController`
+//! remembers the trip per site id and holds the site in repair until it has
+//! passed the gate enough times in a row. While a site is in repair no
+//! verdict is more permissive than `Downscale`, and any non-`Allow` verdict
+//! restarts the count.
+
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::biorail_terrasafe::{
+    BioRailConfig, BioRailTerrasafeGuard, GateReport, GateVerdict, ProposedChange, SiteView,
+};
+
+/// When a site in repair may leave it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct RepairConfig {
+    /// Consecutive `Allow` verdicts needed to clear; 0 behaves as 1.
+    /// Default 5.
+    pub required_passes: u32,
+    /// If set, an `Allow` verdict whose predicted b is below this clears
+    /// repair at once, without waiting for `required_passes`. Default unset.
+    pub recovery_b: Option<f64>,
+}
+
+impl Default for RepairConfig {
+    fn default() -> Self {
+        Self { required_passes: 5, recovery_b: None }
+    }
+}
+
+/// Repair progress of one site.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RepairStatus {
+    /// `ForceRepair` verdicts since the site entered repair, including the
+    /// one that put it there; above 1 means it relapsed.
+    pub trips: u32,
+    /// Evaluations observed since the site entered repair, the tripping one
+    /// excluded.
+    pub evaluations: u32,
+    /// `Allow` verdicts in a row since the last non-`Allow` one.
+    pub consecutive_passes: u32,
+}
+
+/// Per-site ForceRepair state on top of the stateless gate.
+///
+/// Serializable with the `serde` feature so it can be checkpointed with the
+/// rest of the world state.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RepairController {
+    cfg: RepairConfig,
+    sites: BTreeMap<usize, RepairStatus>,
+}
+
+impl RepairController {
+    pub fn new(cfg: RepairConfig) -> Self {
+        Self { cfg, sites: BTreeMap::new() }
+    }
+
+    pub fn config(&self) -> &RepairConfig {
+        &self.cfg
+    }
+
+    /// `BioRailTerrasafeGuard::gate_report` for `site`, passed through `observe`.
+    pub fn gate(&mut self, site: &SiteView, base_cfg: &BioRailConfig, proposed: &ProposedChange) -> GateVerdict {
+        let report = BioRailTerrasafeGuard::gate_report(site, base_cfg, proposed);
+        self.observe(site.id, &report)
+    }
+
+    /// Records a report for site `site_id` and returns the verdict to act
+    /// on. Callers that already gate through `gate_into` use this directly.
+    ///
+    /// The report is never modified, so its violations still describe the
+    /// raw gate decision. The evaluation that completes recovery is still
+    /// held to `Downscale`; the site is unrestricted from the next one.
+    pub fn observe(&mut self, site_id: usize, report: &GateReport) -> GateVerdict {
+        let raw = report.verdict;
+        let Some(status) = self.sites.get_mut(&site_id) else {
+            if raw == GateVerdict::ForceRepair {
+                self.sites.insert(site_id, RepairStatus { trips: 1, evaluations: 0, consecutive_passes: 0 });
+            }
+            return raw;
+        };
+
+        status.evaluations = status.evaluations.saturating_add(1);
+        match raw {
+            GateVerdict::Allow => {
+                status.consecutive_passes = status.consecutive_passes.saturating_add(1);
+                let passed = status.consecutive_passes >= self.cfg.required_passes.max(1);
+                let settled = self.cfg.recovery_b.is_some_and(|b| report.predicted_b.value() < b);
+                if passed || settled {
+                    self.sites.remove(&site_id);
+                }
+                GateVerdict::Downscale
+            }
+            GateVerdict::Downscale | GateVerdict::Block => {
+                status.consecutive_passes = 0;
+                raw
+            }
+            GateVerdict::ForceRepair => {
+                status.trips = status.trips.saturating_add(1);
+                status.consecutive_passes = 0;
+                raw
+            }
+        }
+    }
+
+    /// Repair progress of `site_id`, or `None` if it is not in repair.
+    pub fn repair_status(&self, site_id: usize) -> Option<&RepairStatus> {
+        self.sites.get(&site_id)
+    }
+
+    /// Site ids currently in repair, ascending.
+    pub fn in_repair(&self) -> impl Iterator<Item = usize> + '_ {
+        self.sites.keys().copied()
+    }
+
+    /// Takes `site_id` out of repair regardless of progress, e.g. after a
+    /// manual inspection. Returns its status if it was in repair.
+    pub fn clear(&mut self, site_id: usize) -> Option<RepairStatus> {
+        self.sites.remove(&site_id)
+    }
+}
+
@@ -0,0 +1,44 @@
+//! Site and config factories for tests, here and in downstream crates.
+
+use crate::biorail_terrasafe::*;
+
+/// A calm site well inside every corridor: RoH 0.1, bioloads 0.3 of 0.8,
+/// justice metrics 0.1 of 0.5, consent given. Tests vary one field from here.
+pub fn site() -> SiteView {
+    SiteView {
+        id: 3,
+        bio_env: BioEnvelope { roh: 0.1, decay: 0.3, lifeforce: 0.6, lifeforce_min: 0.2, lifeforce_max: 1.0 },
+        identity_5d: FiveDIdentity {
+            biostate_load: 0.3,
+            neurostate_fear: 0.2,
+            lifeforce: 0.6,
+            context_load: 0.2,
+            sovereignty_trust: 0.6,
+            sovereignty_consent: true,
+        },
+        bioload_view: BioLoadView {
+            body: RailScalar::new_clamped(0.3),
+            room: RailScalar::new_clamped(0.3),
+            grid: RailScalar::new_clamped(0.3),
+            body_max: RailScalar::new_clamped(0.8),
+            room_max: RailScalar::new_clamped(0.8),
+            grid_max: RailScalar::new_clamped(0.8),
+        },
+        power_church: PowerChurchState { power: 1.0, church: 2.0, k_ratio: 1.0 },
+        justice_metrics: JusticeMetrics { hpcc: 0.1, erg: 0.1, tecr: 0.1 },
+        justice_cfg: JusticeCorridorConfig { hpcc_max: 0.5, erg_max: 0.5, tecr_max: 0.5, tightening_factor: 0.8 },
+        diag: DiagnosticFlags { beast_tag: false, plague_tag: false, unfair_drain: false, role_diagnostic_only: true },
+    }
+}
+
+/// Corridor [0, 0.5], instantaneous power/church check, no warning margin.
+pub fn cfg() -> BioRailConfig {
+    BioRailConfig {
+        corridor_min: RailScalar::new_clamped(0.0),
+        corridor_max: RailScalar::new_clamped(0.5),
+        power_church_mode: PowerChurchMode::Instantaneous,
+        warning_margin: 0.0,
+        capability_state: CapabilityState::ControlledHuman,
+        config_version: None,
+    }
+}
@@ -0,0 +1,68 @@
+//! `AuditedGate` reports refusals to its sink and nothing else.
+
+mod common;
+
+use std::cell::RefCell;
+
+use common::cfg;
+use microsociety::audit::*;
+use microsociety::biorail_terrasafe::*;
+
+fn site(id: usize) -> SiteView {
+    SiteView { id, ..common::site() }
+}
+
+/// Pushes RoH past its 0.3 ceiling.
+fn roh_breach() -> ProposedChange {
+    ProposedChange { delta_roh: 0.25, ..ProposedChange::default() }
+}
+
+/// Exceeds POWER ≤ k·CHURCH without touching an envelope.
+fn power_grab() -> ProposedChange {
+    ProposedChange { delta_power: 5.0, ..ProposedChange::default() }
+}
+
+#[derive(Default)]
+struct Recorded(RefCell<Vec<(usize, GateVerdict, Vec<GateConstraint>)>>);
+
+impl GateAuditSink for Recorded {
+    fn record_refusal(&self, site_id: usize, report: &GateReport) {
+        let constraints = report.violated_constraints.iter().map(|v| v.constraint).collect();
+        self.0.borrow_mut().push((site_id, report.verdict, constraints));
+    }
+}
+
+#[test]
+fn only_block_and_force_repair_reach_the_sink() {
+    let gate = AuditedGate::new(Recorded::default());
+    let s = site(7);
+    assert_eq!(gate.gate_report(&s, &cfg(), &ProposedChange::default()).verdict, GateVerdict::Allow);
+    assert_eq!(gate.gate_report(&s, &cfg(), &roh_breach()).verdict, GateVerdict::ForceRepair);
+    assert_eq!(gate.gate_report(&s, &cfg(), &power_grab()).verdict, GateVerdict::Block);
+
+    assert_eq!(
+        *gate.sink().0.borrow(),
+        [
+            (7, GateVerdict::ForceRepair, vec![GateConstraint::RohCeiling]),
+            (7, GateVerdict::Block, vec![GateConstraint::PowerChurch]),
+        ]
+    );
+}
+
+#[test]
+fn audited_report_matches_the_pure_gate() {
+    let recorded = Recorded::default();
+    let gate = AuditedGate::new(&recorded);
+    for proposed in [ProposedChange::default(), roh_breach(), power_grab()] {
+        assert_eq!(gate.gate_report(&site(2), &cfg(), &proposed), BioRailTerrasafeGuard::gate_report(&site(2), &cfg(), &proposed));
+    }
+    assert_eq!(recorded.0.borrow().len(), 2);
+}
+
+#[test]
+fn downscale_is_not_a_refusal() {
+    assert!(!is_refusal(GateVerdict::Allow));
+    assert!(!is_refusal(GateVerdict::Downscale));
+    assert!(is_refusal(GateVerdict::Block));
+    assert!(is_refusal(GateVerdict::ForceRepair));
+}
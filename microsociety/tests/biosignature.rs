@@ -0,0 +1,139 @@
+//! `compute_biosignature_weighted` stays monotone for every valid weight set.
+
+mod common;
+
+use microsociety::biorail_terrasafe::*;
+use proptest::prelude::*;
+
+fn site(u: &[f64]) -> SiteView {
+    let lf_min = 0.4 * u[0];
+    SiteView {
+        id: 0,
+        bio_env: BioEnvelope { roh: 0.3 * u[1], decay: u[2], lifeforce: lf_min + (1.0 - lf_min) * u[3], lifeforce_min: lf_min, lifeforce_max: 1.0 },
+        identity_5d: FiveDIdentity {
+            biostate_load: u[4],
+            neurostate_fear: u[5],
+            lifeforce: u[3],
+            context_load: u[6],
+            sovereignty_trust: u[7],
+            sovereignty_consent: true,
+        },
+        ..common::site()
+    }
+}
+
+/// Non-negative risk weights scaled so they sum to at most 1.0.
+fn weights() -> impl Strategy<Value = BiosignatureWeights> {
+    (prop::array::uniform6(0.0f64..1.0), 0.0f64..=1.0, 0.0f64..=1.0).prop_map(|(raw, total, relief)| {
+        let sum: f64 = raw.iter().sum();
+        let scale = if sum > 0.0 { total / sum } else { 0.0 };
+        BiosignatureWeights {
+            biostate_load: raw[0] * scale,
+            neurostate_fear: raw[1] * scale,
+            context_load: raw[2] * scale,
+            roh: raw[3] * scale,
+            decay: raw[4] * scale,
+            lifeforce_risk: raw[5] * scale,
+            sovereign_relief: relief,
+        }
+    })
+}
+
+/// Raises risk input `which` of `site` by `bump`; lifeforce risk rises as lifeforce falls.
+fn riskier(site: &SiteView, which: usize, bump: f64) -> SiteView {
+    let mut s = site.clone();
+    match which {
+        0 => s.identity_5d.biostate_load += bump,
+        1 => s.identity_5d.neurostate_fear += bump,
+        2 => s.identity_5d.context_load += bump,
+        3 => s.bio_env.roh += bump,
+        4 => s.bio_env.decay += bump,
+        _ => s.bio_env.lifeforce -= bump,
+    }
+    s
+}
+
+#[test]
+fn default_weights_match_compute_biosignature() {
+    let u = [0.5, 0.3, 0.4, 0.6, 0.3, 0.2, 0.2, 0.6];
+    let s = site(&u);
+    assert_eq!(BiosignatureWeights::default(), BiosignatureWeights::DEFAULT);
+    assert_eq!(BiosignatureWeights::DEFAULT.validate(), Ok(()));
+    assert_eq!(
+        BioRailTerrasafeGuard::compute_biosignature_weighted(&s, &BiosignatureWeights::DEFAULT).value().to_bits(),
+        BioRailTerrasafeGuard::compute_biosignature(&s).value().to_bits()
+    );
+
+    let fear_only = BiosignatureWeights { neurostate_fear: 1.0, ..BiosignatureWeights { sovereign_relief: 0.0, ..zero() } };
+    assert_eq!(BioRailTerrasafeGuard::compute_biosignature_weighted(&s, &fear_only).value(), 0.2);
+}
+
+fn zero() -> BiosignatureWeights {
+    BiosignatureWeights {
+        biostate_load: 0.0,
+        neurostate_fear: 0.0,
+        context_load: 0.0,
+        roh: 0.0,
+        decay: 0.0,
+        lifeforce_risk: 0.0,
+        sovereign_relief: 0.0,
+    }
+}
+
+#[test]
+fn invalid_weights_report_every_violation() {
+    let bad = BiosignatureWeights { roh: -0.1, decay: f64::NAN, biostate_load: 0.9, context_load: 0.5, sovereign_relief: 1.5, ..zero() };
+    let violations = bad.validate().unwrap_err();
+    assert_eq!(
+        violations,
+        [
+            WeightViolation::Negative { field: "roh", value: -0.1 },
+            WeightViolation::NotFinite { field: "decay" },
+            WeightViolation::ReliefAboveOne { relief: 1.5 },
+        ]
+    );
+
+    let saturating = BiosignatureWeights { biostate_load: 0.6, neurostate_fear: 0.6, ..zero() };
+    assert!(matches!(saturating.validate().unwrap_err()[..], [WeightViolation::RiskSumAboveOne { .. }]));
+    assert_eq!(WeightViolation::Negative { field: "roh", value: -0.1 }.to_string(), "roh = -0.1 is negative");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn weights_load_from_partial_json() {
+    let w: BiosignatureWeights = serde_json::from_str(r#"{"roh":0.3,"decay":0.06}"#).unwrap();
+    assert_eq!((w.roh, w.decay, w.neurostate_fear), (0.3, 0.06, 0.18));
+    assert!(w.validate().is_ok());
+    assert!(serde_json::from_str::<BiosignatureWeights>(r#"{"power":0.1}"#).is_err());
+}
+
+proptest! {
+    #[test]
+    fn more_risk_never_lowers_b(
+        u in prop::collection::vec(0.0f64..1.0, 8),
+        w in weights(),
+        which in 0usize..6,
+        bump in 0.0f64..0.5,
+    ) {
+        prop_assert!(w.validate().is_ok());
+        let s = site(&u);
+        let before = BioRailTerrasafeGuard::compute_biosignature_weighted(&s, &w).value();
+        let after = BioRailTerrasafeGuard::compute_biosignature_weighted(&riskier(&s, which, bump), &w).value();
+        prop_assert!(after >= before, "input {}: {} -> {}", which, before, after);
+    }
+
+    #[test]
+    fn more_trust_never_raises_b(
+        u in prop::collection::vec(0.0f64..1.0, 8),
+        w in weights(),
+        bump in 0.0f64..0.5,
+    ) {
+        let s = site(&u);
+        let mut trusted = s.clone();
+        trusted.identity_5d.sovereignty_trust += bump;
+        prop_assert!(
+            BioRailTerrasafeGuard::compute_biosignature_weighted(&trusted, &w).value()
+                <= BioRailTerrasafeGuard::compute_biosignature_weighted(&s, &w).value()
+        );
+    }
+}
@@ -0,0 +1,110 @@
+//! Gate inputs and reports round-trip through JSON, and saved cases replay.
+#![cfg(feature = "case-files")]
+
+mod common;
+
+use microsociety::biorail_terrasafe::*;
+use core_contract::config_version::ConfigVersion;
+use microsociety::case::GateCase;
+
+fn site(erg: f64) -> SiteView {
+    let mut site = common::site();
+    site.id = 8;
+    site.bioload_view.room = RailScalar::new_clamped(0.7);
+    site.justice_metrics = JusticeMetrics { hpcc: 0.7, erg, tecr: 0.1 };
+    site
+}
+
+fn roh_breach() -> ProposedChange {
+    ProposedChange { delta_roh: 0.25, ..ProposedChange::default() }
+}
+
+fn cfg() -> BioRailConfig {
+    BioRailConfig { power_church_mode: PowerChurchMode::Windowed { window: 30.0 }, ..common::cfg() }
+}
+
+#[test]
+fn rail_scalar_is_clamped_on_deserialize() {
+    assert_eq!(serde_json::to_string(&RailScalar::new_clamped(0.25)).unwrap(), "0.25");
+    assert_eq!(serde_json::from_str::<RailScalar>("1.7").unwrap().value(), 1.0);
+    assert_eq!(serde_json::from_str::<RailScalar>("-3").unwrap().value(), 0.0);
+    assert_eq!(serde_json::from_str::<RailScalar>("0.4").unwrap().value(), 0.4);
+    assert!(serde_json::from_str::<RailScalar>("\"high\"").is_err());
+
+    // Inside a larger structure too.
+    let json = serde_json::to_string(&site(0.1)).unwrap().replace(r#""grid_max":0.8"#, r#""grid_max":42.0"#);
+    let back: SiteView = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.bioload_view.grid_max.value(), 1.0);
+}
+
+#[test]
+fn report_round_trips_with_violations_and_tuning_record() {
+    let report = BioRailTerrasafeGuard::gate_report(&site(0.6), &cfg(), &roh_breach());
+    assert_eq!(report.verdict, GateVerdict::ForceRepair);
+    assert_eq!(report.justice_tuning.unwrap().stressed_metrics.len(), 2);
+
+    let json = serde_json::to_string(&report).unwrap();
+    assert!(json.contains(r#""verdict":"ForceRepair""#));
+    assert!(json.contains(r#""stressed_metrics":[{"metric":"Hpcc","value":0.7,"max":0.5},{"metric":"Erg""#));
+    let back: GateReport = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, report);
+
+    let four = json.replacen(r#""stressed_metrics":["#, r#""stressed_metrics":[{"metric":"Tecr","value":1,"max":0},{"metric":"Tecr","value":1,"max":0},"#, 1);
+    assert!(serde_json::from_str::<GateReport>(&four).is_err());
+}
+
+#[test]
+fn config_without_capability_state_loads_as_controlled_human() {
+    let mut json: serde_json::Value = serde_json::to_value(cfg()).unwrap();
+    json.as_object_mut().unwrap().remove("capability_state");
+    let loaded: BioRailConfig = serde_json::from_value(json).unwrap();
+    assert_eq!(loaded.capability_state, CapabilityState::ControlledHuman);
+    assert_eq!(loaded.roh_ceiling(), 0.3);
+}
+
+#[test]
+fn saved_case_replays_to_the_same_report() {
+    let path = std::env::temp_dir().join(format!("microsociety-case-{}.json", std::process::id()));
+    let case = GateCase::capture(&site(0.6), &cfg(), &roh_breach());
+    case.save_json(&path).unwrap();
+
+    let loaded = GateCase::load_json(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.report, case.report);
+    assert_eq!(loaded.replay(), case.report);
+    assert_eq!(loaded.site.justice_metrics.hpcc, 0.7);
+    assert_eq!(loaded.config.power_church_mode, PowerChurchMode::Windowed { window: 30.0 });
+    assert_eq!(loaded.proposed.delta_roh, 0.25);
+
+    let bad = std::env::temp_dir().join(format!("microsociety-case-bad-{}.json", std::process::id()));
+    std::fs::write(&bad, "{\"site\":").unwrap();
+    let err = GateCase::load_json(&bad).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    std::fs::write(&bad, "{}").unwrap();
+    assert_eq!(GateCase::load_json(&bad).unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+    std::fs::remove_file(&bad).unwrap();
+    assert_eq!(GateCase::load_json(&bad).unwrap_err().kind(), std::io::ErrorKind::NotFound);
+}
+
+fn versioned(hash: &str) -> BioRailConfig {
+    let version = ConfigVersion::new(hash, std::time::UNIX_EPOCH, Some("biorail.toml".to_string()));
+    BioRailConfig { config_version: Some(version), ..cfg() }
+}
+
+#[test]
+fn report_carries_the_config_version() {
+    let report = BioRailTerrasafeGuard::gate_report(&site(0.6), &versioned("a1"), &roh_breach());
+    assert_eq!(report.config_version.unwrap().hash, "a1");
+    assert_eq!(BioRailTerrasafeGuard::gate_report(&site(0.6), &cfg(), &roh_breach()).config_version, None);
+}
+
+#[test]
+fn replay_under_another_config_needs_cross_config() {
+    let case = GateCase::capture(&site(0.6), &versioned("a1"), &roh_breach());
+    assert_eq!(case.replay_under(&versioned("a1"), false).unwrap(), case.report);
+
+    let err = case.replay_under(&versioned("b2"), false).unwrap_err();
+    assert!(err.contains("recorded under a1 but replaying under b2"), "{err}");
+    assert!(case.replay_under(&cfg(), false).unwrap_err().contains("--cross-config"));
+    assert_eq!(case.replay_under(&versioned("b2"), true).unwrap().config_version.unwrap().hash, "b2");
+}
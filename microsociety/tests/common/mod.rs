@@ -0,0 +1,4 @@
+//! Site and config factories shared by the integration tests; they live in
+//! `microsociety::test_util` so downstream crates can use them too.
+
+pub use microsociety::test_util::*;
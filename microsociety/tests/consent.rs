@@ -0,0 +1,70 @@
+//! Withdrawn consent blocks every change, whatever the other metrics say.
+
+mod common;
+
+use common::cfg;
+use microsociety::biorail_terrasafe::*;
+
+fn site(sovereignty_consent: bool) -> SiteView {
+    let mut site = common::site();
+    site.identity_5d.sovereignty_consent = sovereignty_consent;
+    site
+}
+
+#[test]
+fn healthy_site_without_consent_still_blocks() {
+    let healthy = site(false);
+    assert_eq!(BioRailTerrasafeGuard::gate(&site(true), &cfg(), &ProposedChange::default()), GateVerdict::Allow);
+    assert_eq!(BioRailTerrasafeGuard::gate(&healthy, &cfg(), &ProposedChange::default()), GateVerdict::Block);
+
+    let report = BioRailTerrasafeGuard::gate_report(&healthy, &cfg(), &ProposedChange::default());
+    assert_eq!(report.verdict, GateVerdict::Block);
+    assert_eq!(
+        report.violated_constraints,
+        [ConstraintViolation { constraint: GateConstraint::ConsentWithdrawn, limit: 1.0, predicted: 0.0 }]
+    );
+}
+
+#[test]
+fn withdrawn_consent_outranks_force_repair() {
+    let breach = ProposedChange { delta_roh: 0.25, ..ProposedChange::default() };
+    assert_eq!(BioRailTerrasafeGuard::gate(&site(true), &cfg(), &breach), GateVerdict::ForceRepair);
+
+    let report = BioRailTerrasafeGuard::gate_report(&site(false), &cfg(), &breach);
+    assert_eq!(report.verdict, GateVerdict::Block);
+    let constraints: Vec<_> = report.violated_constraints.iter().map(|v| v.constraint).collect();
+    assert_eq!(constraints, [GateConstraint::ConsentWithdrawn, GateConstraint::RohCeiling]);
+}
+
+#[test]
+fn no_change_can_restore_consent() {
+    assert!(BioRailTerrasafeGuard::synthesize_downscale(&site(false), &cfg(), &ProposedChange::default()).is_none());
+
+    let plan = BioRailTerrasafeGuard::repair_plan(&site(false), &cfg());
+    assert!(!plan.resolves);
+    assert_eq!(plan.targets.len(), 1);
+    assert_eq!(plan.targets[0].to_string(), "restore consent");
+
+    let relief = ProposedChange { delta_sovereignty_trust: 0.4, delta_neurostate_fear: -0.2, ..ProposedChange::default() };
+    let sequence = BioRailTerrasafeGuard::gate_sequence(&site(false), &cfg(), &[relief]);
+    assert_eq!(sequence.failed_at, Some(0));
+}
+
+#[test]
+fn consent_slack_is_infinite_when_given_and_negative_when_withdrawn() {
+    let given = BioRailTerrasafeGuard::explain(&site(true), &cfg(), &ProposedChange::default());
+    assert_eq!(given.slack(GateConstraint::ConsentWithdrawn), Some(f64::INFINITY));
+    assert_ne!(given.tightest().unwrap().constraint, GateConstraint::ConsentWithdrawn);
+
+    let withdrawn = BioRailTerrasafeGuard::explain(&site(false), &cfg(), &ProposedChange::default());
+    assert_eq!(withdrawn.slack(GateConstraint::ConsentWithdrawn), Some(-1.0));
+    assert_eq!(withdrawn.violated().count(), 1);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn identities_without_a_consent_field_deserialize_as_consenting() {
+    let json = r#"{"biostate_load":0.3,"neurostate_fear":0.2,"lifeforce":0.6,"context_load":0.2,"sovereignty_trust":0.6}"#;
+    let identity: FiveDIdentity = serde_json::from_str(json).unwrap();
+    assert!(identity.sovereignty_consent);
+}
@@ -0,0 +1,128 @@
+//! `TableProjector` prices each declared effect and fails closed on the rest.
+
+use microsociety::biorail_terrasafe::*;
+use microsociety::deeds::*;
+
+fn effect(category: &str, magnitude: f64) -> DeedEffect {
+    DeedEffect { category: category.to_string(), magnitude, justification: None }
+}
+
+fn deed(effects: Vec<DeedEffect>) -> ProposedDeed {
+    ProposedDeed { deed_id: "deed-7".to_string(), effects }
+}
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-12
+}
+
+/// The change `TableProjector::default()` gives one effect.
+fn project_one(category: &str, magnitude: f64) -> ProposedChange {
+    TableProjector::default().project(&deed(vec![effect(category, magnitude)])).unwrap().change
+}
+
+#[test]
+fn each_default_category_projects_its_scaled_deltas() {
+    let stimulation = project_one("stimulation", 2.0);
+    assert!(close(stimulation.delta_neurostate_fear, 0.1) && close(stimulation.delta_bioload_body, 0.1));
+    assert!(close(stimulation.delta_roh, 0.02));
+
+    let deployment = project_one("material_deployment", 1.0);
+    assert!(close(deployment.delta_bioload_room, 0.05) && close(deployment.delta_decay, 0.02));
+
+    let collection = project_one("data_collection", 0.5);
+    assert!(close(collection.delta_sovereignty_trust, -0.01) && close(collection.delta_power, 0.05));
+
+    let xr = project_one("xr_exposure", 4.0);
+    assert!(close(xr.delta_context_load, 0.2) && close(xr.delta_roh, 0.02));
+
+    assert_eq!(
+        TableProjector::default().table().categories().collect::<Vec<_>>(),
+        ["data_collection", "material_deployment", "stimulation", "xr_exposure"]
+    );
+}
+
+#[test]
+fn effects_compose_and_the_deed_is_kept_for_audit() {
+    let proposed = deed(vec![effect("stimulation", 1.0), effect("xr_exposure", 1.0), effect("data_collection", 0.0)]);
+    let projection = TableProjector::default().project(&proposed).unwrap();
+    assert_eq!(projection.deed, proposed);
+    assert_eq!(projection.effects.len(), 3);
+    assert!(close(projection.change.delta_neurostate_fear, 0.08));
+    assert!(close(projection.change.delta_roh, 0.015));
+    assert_eq!(projection.effects[2].delta_power, 0.0);
+}
+
+#[test]
+fn unknown_effect_fails_closed() {
+    let proposed = deed(vec![effect("stimulation", 1.0), effect("soil_injection", 1.0)]);
+    let err = TableProjector::default().project(&proposed).unwrap_err();
+    assert_eq!(err, ProjectionError::UnknownEffect { category: "soil_injection".to_string() });
+    assert_eq!(err.to_string(), "unknown deed effect \"soil_injection\"");
+    assert!(TableProjector::new(EffectTable::empty()).project(&deed(vec![effect("stimulation", 1.0)])).is_err());
+}
+
+#[test]
+fn negative_or_non_finite_magnitudes_are_rejected() {
+    for magnitude in [-1.0, f64::NAN, f64::INFINITY] {
+        let err = TableProjector::default().project(&deed(vec![effect("stimulation", magnitude)])).unwrap_err();
+        assert!(matches!(err, ProjectionError::InvalidMagnitude { .. }), "{magnitude}: {err:?}");
+    }
+}
+
+#[test]
+fn roh_reduction_needs_a_justification() {
+    let table = EffectTable::default().with_effect("rest", ProposedChange { delta_roh: -0.02, delta_neurostate_fear: -0.05, ..ProposedChange::default() });
+    let projector = TableProjector::new(table);
+
+    let err = projector.project(&deed(vec![effect("rest", 1.0)])).unwrap_err();
+    assert_eq!(err, ProjectionError::UnjustifiedRohReduction { category: "rest".to_string(), delta_roh: -0.02 });
+    let blank = DeedEffect { justification: Some("  ".to_string()), ..effect("rest", 1.0) };
+    assert!(projector.project(&deed(vec![blank])).is_err());
+
+    let justified = DeedEffect { justification: Some("clinician-prescribed recovery block".to_string()), ..effect("rest", 1.0) };
+    let projection = projector.project(&deed(vec![justified])).unwrap();
+    assert!(close(projection.change.delta_roh, -0.02));
+}
+
+#[test]
+fn projected_deed_goes_through_the_gate() {
+    let site = SiteView {
+        id: 1,
+        bio_env: BioEnvelope { roh: 0.1, decay: 0.3, lifeforce: 0.6, lifeforce_min: 0.2, lifeforce_max: 1.0 },
+        identity_5d: FiveDIdentity {
+            biostate_load: 0.3,
+            neurostate_fear: 0.2,
+            lifeforce: 0.6,
+            context_load: 0.2,
+            sovereignty_trust: 0.6,
+            sovereignty_consent: true,
+        },
+        bioload_view: BioLoadView {
+            body: RailScalar::new_clamped(0.3),
+            room: RailScalar::new_clamped(0.3),
+            grid: RailScalar::new_clamped(0.3),
+            body_max: RailScalar::new_clamped(0.8),
+            room_max: RailScalar::new_clamped(0.8),
+            grid_max: RailScalar::new_clamped(0.8),
+        },
+        power_church: PowerChurchState { power: 1.0, church: 2.0, k_ratio: 1.0 },
+        justice_metrics: JusticeMetrics { hpcc: 0.1, erg: 0.1, tecr: 0.1 },
+        justice_cfg: JusticeCorridorConfig { hpcc_max: 0.5, erg_max: 0.5, tecr_max: 0.5, tightening_factor: 0.8 },
+        diag: DiagnosticFlags { beast_tag: false, plague_tag: false, unfair_drain: false, role_diagnostic_only: true },
+    };
+    let cfg = BioRailConfig {
+        corridor_min: RailScalar::new_clamped(0.0),
+        corridor_max: RailScalar::new_clamped(0.5),
+        power_church_mode: PowerChurchMode::Instantaneous,
+        warning_margin: 0.0,
+        capability_state: CapabilityState::ControlledHuman,
+        config_version: None,
+    };
+    let projector = TableProjector::default();
+
+    let gentle = projector.project(&deed(vec![effect("stimulation", 1.0)])).unwrap();
+    assert_eq!(BioRailTerrasafeGuard::gate(&site, &cfg, &gentle.change), GateVerdict::Allow);
+    // Twenty units of stimulation add 1.0 body bioload against a 0.8 ceiling.
+    let intense = projector.project(&deed(vec![effect("stimulation", 20.0)])).unwrap();
+    assert_eq!(BioRailTerrasafeGuard::gate(&site, &cfg, &intense.change), GateVerdict::ForceRepair);
+}
@@ -0,0 +1,353 @@
+//! Differential corpus: the allocation-free gate must return exactly the
+//! verdicts of the original cloning implementation, kept here verbatim.
+
+use microsociety::biorail_terrasafe::*;
+
+#[allow(clippy::all)]
+mod legacy {
+    use microsociety::biorail_terrasafe::*;
+
+    /// Main guard that couples the scalar BioRail with BioLoad Terrasafe and POWER/CHURCH caps. [file:4][file:3][file:2]
+    pub struct LegacyGuard;
+
+    impl LegacyGuard {
+        /// Compute monotone biosignature rail b_i from 5D identity and envelopes. [file:4][file:3]
+        ///
+        /// Design:
+        /// - Increasing risk components (bioload-like, FEAR-like, RoH, DECAY, context load)
+        ///   must not decrease b_i (monotonic safety). [file:4][file:3]
+        /// - Increasing sovereignty_trust may reduce b_i within bounds, reflecting improved stewardship. [file:4]
+        pub fn compute_biosignature(site: &SiteView) -> RailScalar {
+            let id = &site.identity_5d;
+            let env = &site.bio_env;
+
+            // Normalize components into [0,1] contributions.
+            let roh_norm = (env.roh / 0.3).clamp(0.0, 1.0);        // RoH ≤ 0.3 corridor. [file:2]
+            let decay_norm = env.decay.clamp(0.0, 1.0);            // DECAY ≤ 1.0. [file:2]
+            let lf_band_width = (env.lifeforce_max - env.lifeforce_min).max(1e-9);
+            let lf_pos = ((env.lifeforce - env.lifeforce_min) / lf_band_width).clamp(0.0, 1.0);
+            let lf_risk = 1.0 - lf_pos; // more risk toward lower lifeforce. [file:2]
+
+            let bio_load = id.biostate_load.clamp(0.0, 1.0);
+            let fear = id.neurostate_fear.clamp(0.0, 1.0);         // FEAR slice. [file:3]
+            let ctx = id.context_load.clamp(0.0, 1.0);
+            let sovereign = id.sovereignty_trust.clamp(0.0, 1.0);
+
+            // Risk-weighted aggregation; all risk components push b upward. [file:4][file:3]
+            let risk_sum =
+                0.18 * bio_load +
+                0.18 * fear +
+                0.18 * ctx +
+                0.18 * roh_norm +
+                0.18 * decay_norm +
+                0.10 * lf_risk;
+
+            // Sovereignty/trust can only reduce risk, never create it. [file:4]
+            let sovereign_relief = 0.4 * sovereign;
+
+            let raw = (risk_sum - sovereign_relief).clamp(0.0, 1.0);
+            RailScalar::new_clamped(raw)
+        }
+
+        /// Apply justice metric tightening to the effective corridors and ceilings. [file:3][file:2]
+        fn apply_justice_tuning(site: &SiteView,
+                                rail_cfg: &BioRailConfig,
+                                bioload: &BioLoadView) -> (BioRailConfig, BioLoadView) {
+            let jm = &site.justice_metrics;
+            let cfg = &site.justice_cfg;
+
+            // If any justice metric exceeds its corridor, tighten corridors multiplicatively;
+            // never loosen beyond the baseline. [file:3]
+            let stressed =
+                (jm.hpcc > cfg.hpcc_max) ||
+                (jm.erg > cfg.erg_max) ||
+                (jm.tecr > cfg.tecr_max);
+
+            if !stressed {
+                return (rail_cfg.clone(), bioload.clone());
+            }
+
+            let factor = cfg.tightening_factor.clamp(0.0, 1.0);
+
+            // Narrow the b_i corridor symmetrically around its midpoint. [file:3]
+            let mid = 0.5 * (rail_cfg.corridor_min.value() + rail_cfg.corridor_max.value());
+            let half_width = 0.5 * (rail_cfg.corridor_max.value() - rail_cfg.corridor_min.value()) * factor;
+            let tuned_min = RailScalar::new_clamped(mid - half_width);
+            let tuned_max = RailScalar::new_clamped(mid + half_width);
+
+            // Shrink bioload ceilings; actual views remain unchanged. [file:3]
+            let tuned_bioload = BioLoadView {
+                body: bioload.body,
+                room: bioload.room,
+                grid: bioload.grid,
+                body_max: RailScalar::new_clamped(bioload.body_max.value() * factor),
+                room_max: RailScalar::new_clamped(bioload.room_max.value() * factor),
+                grid_max: RailScalar::new_clamped(bioload.grid_max.value() * factor),
+            };
+
+            let tuned_cfg = BioRailConfig {
+                corridor_min: tuned_min,
+                corridor_max: tuned_max,
+                power_church_mode: PowerChurchMode::Instantaneous,
+                warning_margin: 0.0,
+                capability_state: CapabilityState::ControlledHuman,
+                config_version: None,
+            };
+
+            (tuned_cfg, tuned_bioload)
+        }
+
+        /// Check RoH, DECAY, Lifeforce invariants on the predicted envelopes. [file:2]
+        fn check_envelopes(pred_env: &BioEnvelope) -> bool {
+            if pred_env.roh > 0.3 + 1e-9 { // RoH ≤ 0.3 hard ceiling. [file:2]
+                return false;
+            }
+            if pred_env.decay > 1.0 + 1e-9 { // DECAY ≤ 1.0. [file:2]
+                return false;
+            }
+            if pred_env.lifeforce < pred_env.lifeforce_min - 1e-9 {
+                return false;
+            }
+            if pred_env.lifeforce > pred_env.lifeforce_max + 1e-9 {
+                return false;
+            }
+            true
+        }
+
+        /// Check BioLoad Terrasafe ceilings for predicted values. [file:4][file:2]
+        fn check_bioload(pred: &BioLoadView) -> bool {
+            pred.body.value() <= pred.body_max.value() + 1e-9 &&
+            pred.room.value() <= pred.room_max.value() + 1e-9 &&
+            pred.grid.value() <= pred.grid_max.value() + 1e-9
+        }
+
+        /// Check POWER ≤ k·CHURCH constraint after the proposed change. [file:3][file:2]
+        fn check_power_church(pred_pc: &PowerChurchState) -> bool {
+            let k = pred_pc.k_ratio.max(0.0);
+            let allowed_power = k * pred_pc.church.max(0.0);
+            pred_pc.power <= allowed_power + 1e-9
+        }
+
+        /// Compute predicted post-change state slices needed for gating. [file:4][file:3]
+        fn predict_post_state(site: &SiteView,
+                              change: &ProposedChange,
+                              tuned_bioload_max: &BioLoadView)
+                              -> (BioEnvelope, BioLoadView, PowerChurchState, FiveDIdentity)
+        {
+            let mut env = site.bio_env.clone();
+            env.roh = (env.roh + change.delta_roh).max(0.0);
+            env.decay = (env.decay + change.delta_decay).max(0.0);
+            env.lifeforce = env.lifeforce + change.delta_lifeforce_env;
+
+            let mut id = site.identity_5d.clone();
+            id.biostate_load = (id.biostate_load + change.delta_biostate_load).clamp(0.0, 1.0);
+            id.neurostate_fear = (id.neurostate_fear + change.delta_neurostate_fear).clamp(0.0, 1.0);
+            id.lifeforce = id.lifeforce + change.delta_lifeforce;
+            id.context_load = (id.context_load + change.delta_context_load).clamp(0.0, 1.0);
+            id.sovereignty_trust = (id.sovereignty_trust + change.delta_sovereignty_trust).clamp(0.0, 1.0);
+
+            let mut bl = site.bioload_view.clone();
+            bl.body = RailScalar::new_clamped(bl.body.value() + change.delta_bioload_body);
+            bl.room = RailScalar::new_clamped(bl.room.value() + change.delta_bioload_room);
+            bl.grid = RailScalar::new_clamped(bl.grid.value() + change.delta_bioload_grid);
+            // ceilings come from tuned_bioload_max. [file:4][file:3]
+            bl.body_max = tuned_bioload_max.body_max;
+            bl.room_max = tuned_bioload_max.room_max;
+            bl.grid_max = tuned_bioload_max.grid_max;
+
+            let mut pc = site.power_church.clone();
+            pc.power = (pc.power + change.delta_power).max(0.0);
+
+            (env, bl, pc, id)
+        }
+
+        /// Main guard entrypoint.
+        ///
+        /// - Computes current and predicted biosignature b_i.
+        /// - Applies justice tuning to corridors and bioload ceilings.
+        /// - Enforces:
+        ///   * RoH ≤ 0.3, DECAY ≤ 1.0, Lifeforce within [min,max]. [file:2]
+        ///   * bioload_body/room/grid ≤ max ceilings. [file:4][file:2]
+        ///   * POWER ≤ k·CHURCH. [file:3][file:2]
+        ///   * b_i_after within tuned corridor.
+        /// - Returns GateVerdict used by higher-level deed engine to block/downscale/repair. [file:4][file:3]
+        pub fn gate(site: &SiteView,
+                    base_cfg: &BioRailConfig,
+                    proposed: &ProposedChange) -> GateVerdict
+        {
+            // Hard requirement: diagnostics are non-actuating; we ignore them in gating
+            // except as evidence later in logs. [file:6][file:2]
+            debug_assert!(site.diag.role_diagnostic_only);
+
+            // Justice metrics only tune corridors; get tuned cfg and bioload ceilings. [file:3]
+            let (tuned_cfg, tuned_bioload_max) =
+                Self::apply_justice_tuning(site, base_cfg, &site.bioload_view);
+
+            // Compute current biosignature (for monotonicity checks if needed). [file:4]
+            let current_b = Self::compute_biosignature(site);
+
+            // Predict post-change slices. [file:4]
+            let (pred_env, pred_bioload, pred_pc, pred_id) =
+                Self::predict_post_state(site, proposed, &tuned_bioload_max);
+
+            // Enforce envelope invariants first. [file:2]
+            if !Self::check_envelopes(&pred_env) {
+                return GateVerdict::ForceRepair;
+            }
+
+            // Enforce BioLoad Terrasafe ceilings. [file:4]
+            if !Self::check_bioload(&pred_bioload) {
+                return GateVerdict::ForceRepair;
+            }
+
+            // Enforce POWER ≤ k·CHURCH caps. [file:3][file:2]
+            if !Self::check_power_church(&pred_pc) {
+                return GateVerdict::Block;
+            }
+
+            // Compute predicted biosignature under new 5D identity. [file:4]
+            let pred_site_view = SiteView {
+                id: site.id,
+                bio_env: pred_env.clone(),
+                identity_5d: pred_id,
+                bioload_view: pred_bioload.clone(),
+                power_church: pred_pc.clone(),
+                justice_metrics: site.justice_metrics.clone(),
+                justice_cfg: site.justice_cfg.clone(),
+                diag: site.diag.clone(),
+            };
+            let pred_b = Self::compute_biosignature(&pred_site_view);
+
+            // Corridor check on b_i. [file:4][file:3]
+            if pred_b.value() < tuned_cfg.corridor_min.value() ||
+               pred_b.value() > tuned_cfg.corridor_max.value()
+            {
+                // If we are leaving the corridor, classify between Downscale vs ForceRepair
+                // depending on whether risk is increasing compared to current b. [file:4]
+                if pred_b.value() > current_b.value() {
+                    GateVerdict::ForceRepair
+                } else {
+                    GateVerdict::Downscale
+                }
+            } else {
+                GateVerdict::Allow
+            }
+        }
+    }
+}
+
+/// Deterministic xorshift generator so the corpus is reproducible.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn range(&mut self, lo: f64, hi: f64) -> f64 {
+        lo + (hi - lo) * self.next()
+    }
+}
+
+fn random_case(rng: &mut Rng) -> (SiteView, BioRailConfig, ProposedChange) {
+    let lf_min = rng.range(0.0, 0.4);
+    let site = SiteView {
+        id: 0,
+        bio_env: BioEnvelope {
+            roh: rng.range(0.0, 0.3),
+            decay: rng.range(0.0, 1.0),
+            lifeforce: rng.range(lf_min, 1.0),
+            lifeforce_min: lf_min,
+            lifeforce_max: 1.0,
+        },
+        identity_5d: FiveDIdentity {
+            biostate_load: rng.next(),
+            neurostate_fear: rng.next(),
+            lifeforce: rng.next(),
+            context_load: rng.next(),
+            sovereignty_trust: rng.next(),
+            sovereignty_consent: true,
+        },
+        bioload_view: BioLoadView {
+            body: RailScalar::new_clamped(rng.range(0.0, 0.7)),
+            room: RailScalar::new_clamped(rng.range(0.0, 0.7)),
+            grid: RailScalar::new_clamped(rng.range(0.0, 0.7)),
+            body_max: RailScalar::new_clamped(rng.range(0.5, 1.0)),
+            room_max: RailScalar::new_clamped(rng.range(0.5, 1.0)),
+            grid_max: RailScalar::new_clamped(rng.range(0.5, 1.0)),
+        },
+        power_church: PowerChurchState { power: rng.range(0.0, 4.0), church: rng.range(0.0, 4.0), k_ratio: rng.range(0.5, 2.0) },
+        justice_metrics: JusticeMetrics { hpcc: rng.next(), erg: rng.next(), tecr: rng.next() },
+        justice_cfg: JusticeCorridorConfig {
+            hpcc_max: rng.range(0.5, 1.0),
+            erg_max: rng.range(0.5, 1.0),
+            tecr_max: rng.range(0.5, 1.0),
+            tightening_factor: rng.range(0.5, 1.0),
+        },
+        diag: DiagnosticFlags { beast_tag: false, plague_tag: false, unfair_drain: false, role_diagnostic_only: true },
+    };
+    let lo = rng.range(0.0, 0.4);
+    let cfg = BioRailConfig { corridor_min: RailScalar::new_clamped(lo), corridor_max: RailScalar::new_clamped(lo + rng.range(0.1, 0.6)), power_church_mode: PowerChurchMode::Instantaneous, warning_margin: 0.0, capability_state: CapabilityState::ControlledHuman, config_version: None };
+    let change = ProposedChange {
+        delta_biostate_load: rng.range(-0.2, 0.2),
+        delta_neurostate_fear: rng.range(-0.2, 0.2),
+        delta_lifeforce: rng.range(-0.2, 0.2),
+        delta_context_load: rng.range(-0.2, 0.2),
+        delta_sovereignty_trust: rng.range(-0.2, 0.2),
+        delta_roh: rng.range(-0.05, 0.1),
+        delta_decay: rng.range(-0.1, 0.2),
+        delta_lifeforce_env: rng.range(-0.2, 0.2),
+        delta_bioload_body: rng.range(-0.2, 0.3),
+        delta_bioload_room: rng.range(-0.2, 0.3),
+        delta_bioload_grid: rng.range(-0.2, 0.3),
+        delta_power: rng.range(-1.0, 2.0),
+    };
+    (site, cfg, change)
+}
+
+#[test]
+fn gate_matches_legacy_over_corpus() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    let mut seen = [0usize; 4];
+    let mut report = GateReport::default();
+
+    for _ in 0..5_000 {
+        let (site, cfg, change) = random_case(&mut rng);
+        let expected = legacy::LegacyGuard::gate(&site, &cfg, &change);
+
+        assert_eq!(BioRailTerrasafeGuard::gate(&site, &cfg, &change), expected);
+        BioRailTerrasafeGuard::gate_into(&site, &cfg, &change, &mut report);
+        assert_eq!(report.verdict, expected);
+
+        seen[expected as usize] += 1;
+    }
+
+    // The corpus must exercise every verdict to be meaningful.
+    assert!(seen.iter().all(|&n| n > 0), "verdict coverage {:?}", seen);
+}
+
+#[test]
+fn gate_batch_matches_individual_gates() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..200 {
+        let (site, cfg, _) = random_case(&mut rng);
+        let proposals: Vec<ProposedChange> = (0..25).map(|_| random_case(&mut rng).2).collect();
+        let batch = BioRailTerrasafeGuard::gate_batch(&site, &cfg, &proposals);
+        assert_eq!(batch.len(), proposals.len());
+
+        for (report, proposed) in batch.iter().zip(&proposals) {
+            let single = BioRailTerrasafeGuard::gate_report(&site, &cfg, proposed);
+            assert_eq!(report.verdict, BioRailTerrasafeGuard::gate(&site, &cfg, proposed));
+            assert_eq!(report.current_b.value().to_bits(), single.current_b.value().to_bits());
+            assert_eq!(report.predicted_b.value().to_bits(), single.predicted_b.value().to_bits());
+            assert_eq!(report, &single);
+        }
+    }
+
+    let (site, cfg, _) = random_case(&mut rng);
+    assert!(BioRailTerrasafeGuard::gate_batch(&site, &cfg, &[]).is_empty());
+}
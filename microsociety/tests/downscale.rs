@@ -0,0 +1,139 @@
+//! `synthesize_downscale` never proposes a change the gate would reject.
+
+mod common;
+
+use common::cfg;
+use microsociety::biorail_terrasafe::*;
+use proptest::prelude::*;
+
+fn site(roh: f64) -> SiteView {
+    let mut site = common::site();
+    site.id = 4;
+    site.bio_env.roh = roh;
+    site
+}
+
+fn change(delta_roh: f64, delta_bioload_body: f64) -> ProposedChange {
+    ProposedChange {
+        delta_biostate_load: 0.0,
+        delta_neurostate_fear: 0.0,
+        delta_lifeforce: 0.0,
+        delta_context_load: 0.0,
+        delta_sovereignty_trust: 0.0,
+        delta_roh,
+        delta_decay: 0.0,
+        delta_lifeforce_env: 0.0,
+        delta_bioload_body,
+        delta_bioload_room: 0.0,
+        delta_bioload_grid: 0.0,
+        delta_power: 0.0,
+    }
+}
+
+#[test]
+fn allowed_change_is_kept_whole() {
+    let scaled = BioRailTerrasafeGuard::synthesize_downscale(&site(0.1), &cfg(), &change(0.1, 0.1)).unwrap();
+    assert_eq!(scaled.alpha, 1.0);
+    assert!((scaled.predicted_env.roh - 0.2).abs() < 1e-9);
+}
+
+#[test]
+fn change_is_shrunk_to_the_tightest_ceiling() {
+    // RoH alone would allow α = 0.5; the body bioload (0.3 + 0.8α ≤ 0.8) caps it at 0.625 → 0.5 wins.
+    let proposed = change(0.4, 0.8);
+    assert_eq!(BioRailTerrasafeGuard::gate(&site(0.1), &cfg(), &proposed), GateVerdict::ForceRepair);
+    let scaled = BioRailTerrasafeGuard::synthesize_downscale(&site(0.1), &cfg(), &proposed).unwrap();
+    assert!(scaled.alpha <= 0.5 && scaled.alpha > 0.5 - 2.0 * DOWNSCALE_TOLERANCE, "{}", scaled.alpha);
+    assert!(scaled.predicted_env.roh <= 0.3);
+    assert_eq!(BioRailTerrasafeGuard::gate(&site(0.1), &cfg(), &scaled.change), GateVerdict::Allow);
+    assert!((scaled.change.delta_bioload_body - 0.8 * scaled.alpha).abs() < 1e-12);
+    assert!((scaled.predicted_bioload.body.value() - (0.3 + 0.8 * scaled.alpha)).abs() < 1e-9);
+}
+
+#[test]
+fn pre_existing_violation_cannot_be_downscaled() {
+    assert!(BioRailTerrasafeGuard::synthesize_downscale(&site(0.35), &cfg(), &change(0.0, 0.1)).is_none());
+    assert!(BioRailTerrasafeGuard::synthesize_downscale(&site(0.35), &cfg(), &change(-0.01, 0.0)).is_none());
+}
+
+/// Draws in [0, 1) mapped onto the ranges the differential corpus uses.
+fn random_case(u: &[f64]) -> (SiteView, BioRailConfig, ProposedChange) {
+    let r = |i: usize, lo: f64, hi: f64| lo + (hi - lo) * u[i];
+    let lf_min = r(0, 0.0, 0.4);
+    let site = SiteView {
+        id: 0,
+        bio_env: BioEnvelope {
+            roh: r(1, 0.0, 0.3),
+            decay: r(2, 0.0, 1.0),
+            lifeforce: r(3, lf_min, 1.0),
+            lifeforce_min: lf_min,
+            lifeforce_max: 1.0,
+        },
+        identity_5d: FiveDIdentity {
+            biostate_load: u[4],
+            neurostate_fear: u[5],
+            lifeforce: u[6],
+            context_load: u[7],
+            sovereignty_trust: u[8],
+            sovereignty_consent: true,
+        },
+        bioload_view: BioLoadView {
+            body: RailScalar::new_clamped(r(9, 0.0, 0.7)),
+            room: RailScalar::new_clamped(r(10, 0.0, 0.7)),
+            grid: RailScalar::new_clamped(r(11, 0.0, 0.7)),
+            body_max: RailScalar::new_clamped(r(12, 0.5, 1.0)),
+            room_max: RailScalar::new_clamped(r(13, 0.5, 1.0)),
+            grid_max: RailScalar::new_clamped(r(14, 0.5, 1.0)),
+        },
+        power_church: PowerChurchState { power: r(15, 0.0, 4.0), church: r(16, 0.0, 4.0), k_ratio: r(17, 0.5, 2.0) },
+        justice_metrics: JusticeMetrics { hpcc: u[18], erg: u[19], tecr: u[20] },
+        justice_cfg: JusticeCorridorConfig {
+            hpcc_max: r(21, 0.5, 1.0),
+            erg_max: r(22, 0.5, 1.0),
+            tecr_max: r(23, 0.5, 1.0),
+            tightening_factor: r(24, 0.5, 1.0),
+        },
+        diag: DiagnosticFlags { beast_tag: false, plague_tag: false, unfair_drain: false, role_diagnostic_only: true },
+    };
+    let lo = r(25, 0.0, 0.4);
+    let cfg = BioRailConfig { corridor_min: RailScalar::new_clamped(lo), corridor_max: RailScalar::new_clamped(lo + r(26, 0.1, 0.6)), power_church_mode: PowerChurchMode::Instantaneous, warning_margin: 0.0, capability_state: CapabilityState::ControlledHuman, config_version: None };
+    let change = ProposedChange {
+        delta_biostate_load: r(27, -0.2, 0.2),
+        delta_neurostate_fear: r(28, -0.2, 0.2),
+        delta_lifeforce: r(29, -0.2, 0.2),
+        delta_context_load: r(30, -0.2, 0.2),
+        delta_sovereignty_trust: r(31, -0.2, 0.2),
+        delta_roh: r(32, -0.05, 0.1),
+        delta_decay: r(33, -0.1, 0.2),
+        delta_lifeforce_env: r(34, -0.2, 0.2),
+        delta_bioload_body: r(35, -0.2, 0.3),
+        delta_bioload_room: r(36, -0.2, 0.3),
+        delta_bioload_grid: r(37, -0.2, 0.3),
+        delta_power: r(38, -1.0, 2.0),
+    };
+    (site, cfg, change)
+}
+
+proptest! {
+    #[test]
+    fn synthesized_changes_always_pass_the_gate(u in prop::collection::vec(0.0f64..1.0, 39)) {
+        let (site, cfg, proposed) = random_case(&u);
+        match BioRailTerrasafeGuard::synthesize_downscale(&site, &cfg, &proposed) {
+            Some(scaled) => {
+                prop_assert!(scaled.alpha >= DOWNSCALE_TOLERANCE && scaled.alpha <= 1.0);
+                prop_assert_eq!(BioRailTerrasafeGuard::gate(&site, &cfg, &scaled.change), GateVerdict::Allow);
+                prop_assert_eq!(BioRailTerrasafeGuard::gate(&site, &cfg, &proposed.scaled(scaled.alpha)), GateVerdict::Allow);
+                if BioRailTerrasafeGuard::gate(&site, &cfg, &proposed) == GateVerdict::Allow {
+                    prop_assert_eq!(scaled.alpha, 1.0);
+                }
+            }
+            None => {
+                prop_assert_ne!(BioRailTerrasafeGuard::gate(&site, &cfg, &proposed), GateVerdict::Allow);
+                prop_assert_ne!(
+                    BioRailTerrasafeGuard::gate(&site, &cfg, &proposed.scaled(DOWNSCALE_TOLERANCE)),
+                    GateVerdict::Allow
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! `gate_with_ethics`: an ethics denial always blocks, an ethics approval
+//! never loosens a biorail refusal, and approve-but-refused is flagged.
+
+mod common;
+
+use common::cfg;
+use microsociety::audit::*;
+use microsociety::biorail_terrasafe::*;
+use microsociety::ethics::*;
+
+fn site(id: usize) -> SiteView {
+    SiteView { id, ..common::site() }
+}
+
+/// Pushes RoH past its 0.3 ceiling.
+fn roh_breach() -> ProposedChange {
+    ProposedChange { delta_roh: 0.25, ..ProposedChange::default() }
+}
+
+fn deny() -> EthicsDecision {
+    EthicsDecision::Deny { reason: "no community mandate".to_string() }
+}
+
+#[test]
+fn approve_and_allow_passes() {
+    let report = BioRailTerrasafeGuard::gate_with_ethics(&site(1), &cfg(), &ProposedChange::default(), &EthicsDecision::Approve);
+    assert_eq!(report.verdict, GateVerdict::Allow);
+    assert_eq!(
+        report.ethics,
+        Some(EthicsRecord { decision: EthicsDecision::Approve, biorail_verdict: GateVerdict::Allow, outcome: EthicsOutcome::Agreed })
+    );
+}
+
+#[test]
+fn approval_never_loosens_a_biorail_refusal() {
+    let report = BioRailTerrasafeGuard::gate_with_ethics(&site(1), &cfg(), &roh_breach(), &EthicsDecision::Approve);
+    assert_eq!(report.verdict, GateVerdict::ForceRepair);
+    let record = report.ethics.as_ref().unwrap();
+    assert_eq!(record.outcome, EthicsOutcome::Contradiction);
+    assert!(record.is_contradiction());
+    assert_eq!(report.violated_constraints.len(), 1);
+    assert!(is_refusal(report.verdict));
+}
+
+#[test]
+fn denial_blocks_what_the_rail_allows() {
+    let report = BioRailTerrasafeGuard::gate_with_ethics(&site(1), &cfg(), &ProposedChange::default(), &deny());
+    assert_eq!(report.verdict, GateVerdict::Block);
+    assert!(report.violated_constraints.is_empty(), "the rail itself found nothing");
+    let record = report.ethics.unwrap();
+    assert_eq!(record.outcome, EthicsOutcome::EthicsOverride);
+    assert_eq!(record.biorail_verdict, GateVerdict::Allow);
+    assert!(!record.is_contradiction());
+}
+
+#[test]
+fn denial_and_refusal_agree_on_block() {
+    let report = BioRailTerrasafeGuard::gate_with_ethics(&site(1), &cfg(), &roh_breach(), &deny());
+    assert_eq!(report.verdict, GateVerdict::Block);
+    let record = report.ethics.unwrap();
+    assert_eq!(record.outcome, EthicsOutcome::Agreed);
+    assert_eq!(record.biorail_verdict, GateVerdict::ForceRepair);
+    assert_eq!(record.decision, deny());
+}
+
+#[test]
+fn rail_fields_match_the_plain_report() {
+    for proposed in [ProposedChange::default(), roh_breach()] {
+        for ethics in [EthicsDecision::Approve, deny()] {
+            let plain = BioRailTerrasafeGuard::gate_report(&site(3), &cfg(), &proposed);
+            let with = BioRailTerrasafeGuard::gate_with_ethics(&site(3), &cfg(), &proposed, &ethics);
+            assert_eq!(with.ethics.as_ref().unwrap().biorail_verdict, plain.verdict);
+            assert_eq!(GateReport { verdict: plain.verdict, ethics: None, ..with }, plain);
+        }
+    }
+}
+
+#[test]
+fn downscale_with_denial_is_an_override() {
+    let (record, verdict) = EthicsRecord::combine(deny(), GateVerdict::Downscale);
+    assert_eq!(verdict, GateVerdict::Block);
+    assert_eq!(record.outcome, EthicsOutcome::EthicsOverride);
+    let (record, verdict) = EthicsRecord::combine(EthicsDecision::Approve, GateVerdict::Downscale);
+    assert_eq!(verdict, GateVerdict::Downscale);
+    assert_eq!(record.outcome, EthicsOutcome::Agreed);
+}
@@ -0,0 +1,105 @@
+//! `explain` reports headroom that agrees with the gate's own violations.
+
+mod common;
+
+use microsociety::biorail_terrasafe::*;
+
+fn site(erg: f64) -> SiteView {
+    let mut site = common::site();
+    site.justice_metrics.erg = erg;
+    site
+}
+
+fn cfg(max: f64) -> BioRailConfig {
+    BioRailConfig { corridor_min: RailScalar::new_clamped(0.05), corridor_max: RailScalar::new_clamped(max), ..common::cfg() }
+}
+
+/// Negative slack is exactly the gate's violation list, one entry per constraint.
+fn assert_consistent(site: &SiteView, cfg: &BioRailConfig, proposed: &ProposedChange) {
+    let explained = BioRailTerrasafeGuard::explain(site, cfg, proposed);
+    let report = BioRailTerrasafeGuard::gate_report(site, cfg, proposed);
+    assert_eq!(explained.verdict, report.verdict);
+    assert_eq!(explained.predicted_b, report.predicted_b);
+    assert_eq!(explained.tuned_corridor, report.tuned_corridor);
+
+    let order: Vec<_> = explained.constraints.iter().map(|c| c.constraint).collect();
+    assert_eq!(order, GateConstraint::ALL);
+    let negative: Vec<_> = explained
+        .violated()
+        .map(|c| ConstraintViolation { constraint: c.constraint, limit: c.limit, predicted: c.predicted })
+        .collect();
+    // Compared via Debug so a NaN limit matches itself.
+    assert_eq!(format!("{negative:?}"), format!("{:?}", report.violated_constraints), "{proposed:?}");
+    assert!(explained.constraints.iter().all(|c| !c.slack.is_nan()));
+}
+
+#[test]
+fn slack_names_headroom_under_each_limit() {
+    let change = ProposedChange { delta_roh: 0.14, delta_bioload_grid: 0.48, ..ProposedChange::default() };
+    let explained = BioRailTerrasafeGuard::explain(&site(0.1), &cfg(0.5), &change);
+    assert_eq!(explained.verdict, GateVerdict::Allow);
+    assert!((explained.slack(GateConstraint::RohCeiling).unwrap() - 0.06).abs() < 1e-9);
+    assert!((explained.slack(GateConstraint::GridBioload).unwrap() - 0.02).abs() < 1e-6);
+    assert!((explained.slack(GateConstraint::PowerChurch).unwrap() - 1.0).abs() < 1e-9);
+    assert!((explained.slack(GateConstraint::LifeforceFloor).unwrap() - 0.4).abs() < 1e-9);
+    assert_eq!(explained.tightest().unwrap().constraint, GateConstraint::GridBioload);
+    assert_eq!(explained.violated().count(), 0);
+}
+
+#[test]
+fn corridor_slack_is_measured_to_the_nearer_bound() {
+    let explained = BioRailTerrasafeGuard::explain(&site(0.1), &cfg(0.5), &ProposedChange::default());
+    let corridor = explained.constraints.last().unwrap();
+    let b = explained.predicted_b.value();
+    let (lo, hi) = (explained.tuned_corridor.0.value(), explained.tuned_corridor.1.value());
+    assert_eq!(corridor.slack, (b - lo).min(hi - b));
+}
+
+#[test]
+fn violated_constraints_have_negative_slack() {
+    let change = ProposedChange { delta_roh: 0.25, delta_power: 3.0, ..ProposedChange::default() };
+    let explained = BioRailTerrasafeGuard::explain(&site(0.1), &cfg(0.5), &change);
+    let violated: Vec<_> = explained.violated().map(|c| c.constraint).collect();
+    assert_eq!(violated, [GateConstraint::RohCeiling, GateConstraint::PowerChurch]);
+    assert!((explained.slack(GateConstraint::RohCeiling).unwrap() + 0.05).abs() < 1e-9);
+    assert!((explained.slack(GateConstraint::PowerChurch).unwrap() + 2.0).abs() < 1e-9);
+}
+
+#[test]
+fn nan_power_allowance_is_a_violation_with_infinite_deficit() {
+    let mut s = site(0.1);
+    s.power_church = PowerChurchState { power: 0.0, church: 0.0, k_ratio: f64::INFINITY };
+    let explained = BioRailTerrasafeGuard::explain(&s, &cfg(0.5), &ProposedChange::default());
+    assert_eq!(explained.slack(GateConstraint::PowerChurch), Some(f64::NEG_INFINITY));
+    assert_consistent(&s, &cfg(0.5), &ProposedChange::default());
+}
+
+#[test]
+fn negative_slack_matches_gate_violations_over_a_grid() {
+    let mut cases = 0;
+    for erg in [0.1, 0.9] {
+        for max in [0.3, 0.6] {
+            for delta_roh in [-0.05, 0.1, 0.2 + 1e-10, 0.25] {
+                for delta_bioload_body in [0.0, 0.3, 0.5, 0.6] {
+                    for delta_power in [0.0, 1.0, 1.0 + 1e-10, 2.0] {
+                        for delta_neurostate_fear in [-0.2, 0.4] {
+                            for delta_lifeforce_env in [-0.5, 0.0, 0.45] {
+                                let change = ProposedChange {
+                                    delta_roh,
+                                    delta_bioload_body,
+                                    delta_power,
+                                    delta_neurostate_fear,
+                                    delta_lifeforce_env,
+                                    ..ProposedChange::default()
+                                };
+                                assert_consistent(&site(erg), &cfg(max), &change);
+                                cases += 1;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    assert_eq!(cases, 2 * 2 * 4 * 4 * 4 * 2 * 3);
+}
@@ -0,0 +1,118 @@
+//! `gate_report` names every constraint behind a verdict.
+
+mod common;
+
+use microsociety::biorail_terrasafe::*;
+
+fn site(erg: f64) -> SiteView {
+    let mut site = common::site();
+    site.justice_metrics.erg = erg;
+    site
+}
+
+fn corridor(min: f64, max: f64) -> BioRailConfig {
+    BioRailConfig { corridor_min: RailScalar::new_clamped(min), corridor_max: RailScalar::new_clamped(max), power_church_mode: PowerChurchMode::Instantaneous, warning_margin: 0.0, capability_state: CapabilityState::ControlledHuman, config_version: None }
+}
+
+/// The single violation in `report`, checked against the verdict `gate` gives.
+fn only_violation(site: &SiteView, cfg: &BioRailConfig, change: &ProposedChange) -> (GateVerdict, ConstraintViolation) {
+    let report = BioRailTerrasafeGuard::gate_report(site, cfg, change);
+    assert_eq!(report.verdict, BioRailTerrasafeGuard::gate(site, cfg, change));
+    assert_eq!(report.violated_constraints.len(), 1, "{:?}", report.violated_constraints);
+    (report.verdict, report.violated_constraints[0])
+}
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn allowed_change_has_no_violations() {
+    let report = BioRailTerrasafeGuard::gate_report(&site(0.1), &corridor(0.0, 0.5), &ProposedChange::default());
+    assert_eq!(report.verdict, GateVerdict::Allow);
+    assert!(report.violated_constraints.is_empty());
+    assert!(!report.justice_tightening_applied);
+    assert!(report.predicted_b.value() > 0.0);
+}
+
+#[test]
+fn roh_ceiling_follows_the_capability_tier() {
+    let breach = ProposedChange { delta_roh: 0.25, ..ProposedChange::default() };
+    let (verdict, v) = only_violation(&site(0.1), &corridor(0.0, 0.5), &breach);
+    assert_eq!((verdict, v.constraint, v.limit), (GateVerdict::ForceRepair, GateConstraint::RohCeiling, 0.3));
+
+    // RoH 0.35 is within every other tier's ceiling.
+    let bench = BioRailConfig { capability_state: CapabilityState::LabBench, ..corridor(0.0, 0.5) };
+    assert_eq!(bench.roh_ceiling(), 1.0);
+    let report = BioRailTerrasafeGuard::gate_report(&site(0.1), &bench, &breach);
+    assert!(report.violated_constraints.iter().all(|v| v.constraint != GateConstraint::RohCeiling));
+}
+
+#[test]
+fn envelope_violations_name_the_envelope() {
+    let cfg = corridor(0.0, 0.5);
+    let (verdict, v) = only_violation(&site(0.1), &cfg, &ProposedChange { delta_roh: 0.25, ..ProposedChange::default() });
+    assert_eq!((verdict, v.constraint), (GateVerdict::ForceRepair, GateConstraint::RohCeiling));
+    assert!(close(v.limit, 0.3) && close(v.predicted, 0.35));
+
+    let (verdict, v) = only_violation(&site(0.1), &cfg, &ProposedChange { delta_decay: 0.8, ..ProposedChange::default() });
+    assert_eq!((verdict, v.constraint), (GateVerdict::ForceRepair, GateConstraint::DecayCeiling));
+    assert!(close(v.limit, 1.0) && close(v.predicted, 1.1));
+
+    let drain = ProposedChange { delta_lifeforce_env: -0.5, ..ProposedChange::default() };
+    let (verdict, v) = only_violation(&site(0.1), &cfg, &drain);
+    assert_eq!((verdict, v.constraint), (GateVerdict::ForceRepair, GateConstraint::LifeforceFloor));
+    assert!(close(v.limit, 0.2) && close(v.predicted, 0.1));
+}
+
+#[test]
+fn bioload_violation_names_the_territory() {
+    let cfg = corridor(0.0, 0.5);
+    let (verdict, v) = only_violation(&site(0.1), &cfg, &ProposedChange { delta_bioload_room: 0.6, ..ProposedChange::default() });
+    assert_eq!((verdict, v.constraint), (GateVerdict::ForceRepair, GateConstraint::RoomBioload));
+    assert!(close(v.limit, 0.8) && close(v.predicted, 0.9));
+
+    // Justice stress shrinks the ceiling: 0.8 · 0.8 = 0.64.
+    let stressed = site(0.9);
+    let body = ProposedChange { delta_bioload_body: 0.4, ..ProposedChange::default() };
+    assert_eq!(BioRailTerrasafeGuard::gate(&site(0.1), &cfg, &body), GateVerdict::Allow);
+    let report = BioRailTerrasafeGuard::gate_report(&stressed, &cfg, &body);
+    assert!(report.justice_tightening_applied);
+    assert_eq!(report.violated_constraints[0].constraint, GateConstraint::BodyBioload);
+    assert!(close(report.violated_constraints[0].limit, 0.64));
+}
+
+#[test]
+fn power_violation_blocks() {
+    let (verdict, v) = only_violation(&site(0.1), &corridor(0.0, 0.5), &ProposedChange { delta_power: 1.5, ..ProposedChange::default() });
+    assert_eq!((verdict, v.constraint), (GateVerdict::Block, GateConstraint::PowerChurch));
+    assert!(close(v.limit, 2.0) && close(v.predicted, 2.5));
+}
+
+#[test]
+fn corridor_exit_reports_the_bound_crossed() {
+    let riskier = ProposedChange { delta_context_load: 0.1, ..ProposedChange::default() };
+    let (verdict, v) = only_violation(&site(0.1), &corridor(0.0, 0.05), &riskier);
+    assert_eq!((verdict, v.constraint), (GateVerdict::ForceRepair, GateConstraint::CorridorExit));
+    assert!(close(v.limit, 0.05) && v.predicted > v.limit);
+
+    let safer = ProposedChange { delta_sovereignty_trust: 0.1, ..ProposedChange::default() };
+    let (verdict, v) = only_violation(&site(0.1), &corridor(0.1, 0.5), &safer);
+    assert_eq!((verdict, v.constraint), (GateVerdict::Downscale, GateConstraint::CorridorExit));
+    assert!(close(v.limit, 0.1) && v.predicted < v.limit);
+}
+
+#[test]
+fn every_violation_is_listed_and_the_buffer_is_refilled() {
+    let cfg = corridor(0.0, 0.5);
+    let everything = ProposedChange { delta_roh: 0.5, delta_bioload_grid: 0.6, delta_power: 2.0, ..ProposedChange::default() };
+    let mut report = GateReport::default();
+    BioRailTerrasafeGuard::gate_into(&site(0.1), &cfg, &everything, &mut report);
+    let constraints: Vec<_> = report.violated_constraints.iter().map(|v| v.constraint).collect();
+    assert_eq!(constraints, [GateConstraint::RohCeiling, GateConstraint::GridBioload, GateConstraint::PowerChurch]);
+    assert_eq!(report.verdict, GateVerdict::ForceRepair);
+
+    BioRailTerrasafeGuard::gate_into(&site(0.1), &cfg, &ProposedChange::default(), &mut report);
+    assert!(report.violated_constraints.is_empty());
+    assert_eq!(report.verdict, GateVerdict::Allow);
+}
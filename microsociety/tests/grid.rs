@@ -0,0 +1,101 @@
+//! Sites sharing one grid are gated against their share of its budget.
+
+mod common;
+
+use std::collections::BTreeMap;
+
+use common::cfg;
+use microsociety::biorail_terrasafe::*;
+use microsociety::grid::*;
+
+fn site(id: usize, grid: f64, grid_max: f64) -> SiteView {
+    let mut site = SiteView { id, ..common::site() };
+    site.bioload_view.grid = RailScalar::new_clamped(grid);
+    site.bioload_view.grid_max = RailScalar::new_clamped(grid_max);
+    site
+}
+
+/// Ten sites at 60% of a 0.5 grid ceiling on a grid that carries 2.0 in total.
+fn territory() -> Vec<SiteView> {
+    (0..10).map(|id| site(id, 0.3, 0.5)).collect()
+}
+
+#[test]
+fn oversubscribed_grid_fails_sites_that_pass_alone() {
+    let sites = territory();
+    for s in &sites {
+        assert_eq!(BioRailTerrasafeGuard::gate(s, &cfg(), &ProposedChange::default()), GateVerdict::Allow);
+    }
+
+    let ceilings = GridAggregator::new(2.0, GridAllocation::Proportional).aggregate(&sites);
+    assert!(ceilings.oversubscribed());
+    assert!((ceilings.aggregate_load - 3.0).abs() < 1e-9);
+    for s in &sites {
+        assert!((ceilings.ceiling(s.id).unwrap().value() - 0.2).abs() < 1e-9);
+        let report = ceilings.gate_report(s, &cfg(), &ProposedChange::default());
+        assert_ne!(report.verdict, GateVerdict::Allow);
+        let v = report.violated_constraints[0];
+        assert_eq!(v.constraint, GateConstraint::GridBioload);
+        assert!((v.limit - 0.2).abs() < 1e-9 && v.predicted == 0.3);
+    }
+}
+
+#[test]
+fn ample_budget_keeps_each_sites_own_ceiling() {
+    let sites = territory();
+    let ceilings = GridAggregator::new(5.0, GridAllocation::Proportional).aggregate(&sites);
+    assert!(!ceilings.oversubscribed());
+    for s in &sites {
+        assert_eq!(ceilings.ceiling(s.id), Some(s.bioload_view.grid_max));
+        assert_eq!(ceilings.gate_report(s, &cfg(), &ProposedChange::default()), BioRailTerrasafeGuard::gate_report(s, &cfg(), &ProposedChange::default()));
+    }
+}
+
+#[test]
+fn site_outside_the_snapshot_has_no_grid_share() {
+    let ceilings = GridAggregator::new(5.0, GridAllocation::Proportional).aggregate(&territory());
+    let outsider = site(99, 0.3, 0.5);
+    assert_eq!(ceilings.ceiling(99), None);
+    assert_eq!(BioRailTerrasafeGuard::gate(&outsider, &cfg(), &ProposedChange::default()), GateVerdict::Allow);
+
+    let report = ceilings.gate_report(&outsider, &cfg(), &ProposedChange::default());
+    assert_ne!(report.verdict, GateVerdict::Allow);
+    let v = report.violated_constraints[0];
+    assert_eq!(v.constraint, GateConstraint::GridBioload);
+    assert_eq!((v.limit, v.predicted), (0.0, 0.3));
+}
+
+#[test]
+fn priority_serves_high_priority_sites_in_full() {
+    let sites = territory();
+    let priorities = BTreeMap::from([(7, 2), (3, 1), (5, 1)]);
+    let ceilings = GridAggregator::new(2.2, GridAllocation::Priority(priorities)).aggregate(&sites);
+
+    // 7, then 3 and 5 by id, then 0 and 1 at priority 0; 2 gets the remaining 0.2.
+    for id in [7, 3, 5, 0] {
+        assert_eq!(ceilings.ceiling(id), Some(RailScalar::new_clamped(0.5)), "site {id}");
+    }
+    assert!((ceilings.ceiling(1).unwrap().value() - 0.2).abs() < 1e-9);
+    for id in [2, 4, 6, 8, 9] {
+        assert_eq!(ceilings.ceiling(id), Some(RailScalar::new_clamped(0.0)), "site {id}");
+    }
+    assert_eq!(ceilings.gate_report(&sites[7], &cfg(), &ProposedChange::default()).verdict, GateVerdict::Allow);
+    assert_ne!(ceilings.gate_report(&sites[2], &cfg(), &ProposedChange::default()).verdict, GateVerdict::Allow);
+}
+
+#[test]
+fn aggregation_ignores_snapshot_order() {
+    let mut sites: Vec<_> = (0..10).map(|id| site(id, 0.05 + 0.07 * id as f64, 0.2 + 0.061 * id as f64)).collect();
+    let agg = GridAggregator::new(2.3, GridAllocation::Proportional);
+    let forward = agg.aggregate(&sites);
+    sites.reverse();
+    sites.swap(2, 7);
+    let shuffled = agg.aggregate(&sites);
+    assert_eq!(forward.aggregate_load.to_bits(), shuffled.aggregate_load.to_bits());
+    for id in 0..10 {
+        assert_eq!(forward.ceiling(id).unwrap().value().to_bits(), shuffled.ceiling(id).unwrap().value().to_bits());
+    }
+
+    let broken = GridAggregator::new(f64::NAN, GridAllocation::Proportional).aggregate(&sites);
+    assert_eq!(broken.ceiling(4), Some(RailScalar::new_clamped(0.0)));
+}
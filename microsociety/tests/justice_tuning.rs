@@ -0,0 +1,79 @@
+//! Justice tuning leaves a record of what it tightened and why.
+
+mod common;
+
+use common::cfg;
+use microsociety::biorail_terrasafe::*;
+
+fn site(hpcc: f64, erg: f64, tecr: f64) -> SiteView {
+    let mut site = common::site();
+    site.id = 6;
+    site.bioload_view.room = RailScalar::new_clamped(0.7);
+    site.bioload_view.grid_max = RailScalar::new_clamped(0.5);
+    site.justice_metrics = JusticeMetrics { hpcc, erg, tecr };
+    site
+}
+
+fn close(a: RailScalar, b: f64) -> bool {
+    (a.value() - b).abs() < 1e-9
+}
+
+#[test]
+fn calm_site_previews_baseline_limits() {
+    let record = BioRailTerrasafeGuard::preview_justice_tuning(&site(0.1, 0.1, 0.1), &cfg());
+    assert!(!record.tightened());
+    assert!(record.stressed_metrics.is_empty());
+    assert_eq!(record.factor_applied, 1.0);
+    assert_eq!(record.corridor_after, record.corridor_before);
+    assert_eq!(record.ceilings_after, record.ceilings_before);
+
+    let report = BioRailTerrasafeGuard::gate_report(&site(0.1, 0.1, 0.1), &cfg(), &ProposedChange::default());
+    assert_eq!((report.verdict, report.justice_tuning), (GateVerdict::Allow, None));
+}
+
+#[test]
+fn every_stressed_metric_is_recorded() {
+    let s = site(0.7, 0.2, 0.9);
+    let record = BioRailTerrasafeGuard::preview_justice_tuning(&s, &cfg());
+    assert_eq!(
+        *record.stressed_metrics,
+        [
+            StressedMetric { metric: JusticeMetric::Hpcc, value: 0.7, max: 0.5 },
+            StressedMetric { metric: JusticeMetric::Tecr, value: 0.9, max: 0.5 },
+        ]
+    );
+    assert_eq!(record.stressed_metrics[1].metric.name(), "TECR");
+    assert_eq!(record.factor_applied, 0.8);
+
+    // Tightening is applied once, however many metrics are stressed.
+    let all = BioRailTerrasafeGuard::preview_justice_tuning(&site(0.7, 0.6, 0.9), &cfg());
+    assert_eq!(all.stressed_metrics.len(), 3);
+    assert_eq!((all.corridor_after, all.ceilings_after), (record.corridor_after, record.ceilings_after));
+
+    assert_eq!(record.corridor_before, (cfg().corridor_min, cfg().corridor_max));
+    assert!(close(record.corridor_after.0, 0.05) && close(record.corridor_after.1, 0.45));
+    assert!(close(record.ceilings_after.body, 0.64));
+    assert!(close(record.ceilings_after.room, 0.64));
+    assert!(close(record.ceilings_after.grid, 0.4));
+}
+
+#[test]
+fn block_from_tightened_ceiling_carries_the_record() {
+    // Room bioload 0.7 is within the 0.8 baseline but not the tightened 0.64.
+    let calm = BioRailTerrasafeGuard::gate_report(&site(0.1, 0.1, 0.1), &cfg(), &ProposedChange::default());
+    assert_eq!(calm.verdict, GateVerdict::Allow);
+
+    let s = site(0.6, 0.6, 0.1);
+    let report = BioRailTerrasafeGuard::gate_report(&s, &cfg(), &ProposedChange::default());
+    assert_ne!(report.verdict, GateVerdict::Allow);
+    assert!(report.justice_tightening_applied);
+    let v = report.violated_constraints[0];
+    assert_eq!(v.constraint, GateConstraint::RoomBioload);
+
+    let record = report.justice_tuning.expect("tightening fired");
+    assert_eq!(record, BioRailTerrasafeGuard::preview_justice_tuning(&s, &cfg()));
+    assert_eq!(record.stressed_metrics.iter().map(|m| m.metric).collect::<Vec<_>>(), [JusticeMetric::Hpcc, JusticeMetric::Erg]);
+    assert_eq!(v.limit, record.ceilings_after.room.value());
+    assert!(v.limit < record.ceilings_before.room.value());
+    assert_eq!(report.tuned_corridor, record.corridor_after);
+}
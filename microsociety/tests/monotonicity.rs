@@ -0,0 +1,123 @@
+//! The biosignature rail b never falls when a single risk component rises,
+//! and never rises with sovereignty_trust. Inputs deliberately stray outside
+//! [0, 1] so the clamping in `compute_biosignature` is exercised too.
+
+mod common;
+
+use microsociety::biorail_terrasafe::*;
+use proptest::prelude::*;
+
+/// The six risk components; lifeforce is risk through its fall.
+#[derive(Debug, Clone, Copy)]
+enum Risk {
+    BiostateLoad,
+    NeurostateFear,
+    ContextLoad,
+    Roh,
+    Decay,
+    LifeforceDrop,
+}
+
+const RISKS: [Risk; 6] =
+    [Risk::BiostateLoad, Risk::NeurostateFear, Risk::ContextLoad, Risk::Roh, Risk::Decay, Risk::LifeforceDrop];
+
+fn site() -> impl Strategy<Value = SiteView> {
+    (prop::array::uniform8(-0.5f64..1.5), 0.0f64..0.6, 0.4f64..1.2).prop_map(|(u, lf_min, lf_width)| site_at(u, lf_min, lf_width))
+}
+
+fn site_at(u: [f64; 8], lf_min: f64, lf_width: f64) -> SiteView {
+    SiteView {
+        id: 0,
+        bio_env: BioEnvelope { roh: 0.4 * u[0], decay: u[1], lifeforce: u[2], lifeforce_min: lf_min, lifeforce_max: lf_min + lf_width },
+        identity_5d: FiveDIdentity {
+            biostate_load: u[3],
+            neurostate_fear: u[4],
+            lifeforce: u[2],
+            context_load: u[5],
+            sovereignty_trust: u[6],
+            sovereignty_consent: true,
+        },
+        bioload_view: BioLoadView { body: RailScalar::new_clamped(u[7]), ..common::site().bioload_view },
+        ..common::site()
+    }
+}
+
+/// Valid weight sets: non-negative, risk weights summing to at most 1.0.
+fn weights() -> impl Strategy<Value = BiosignatureWeights> {
+    prop_oneof![
+        Just(BiosignatureWeights::DEFAULT),
+        (prop::array::uniform6(0.0f64..1.0), 0.0f64..=1.0, 0.0f64..=1.0).prop_map(|(raw, total, relief)| {
+            let sum: f64 = raw.iter().sum();
+            let scale = if sum > 0.0 { total / sum } else { 0.0 };
+            BiosignatureWeights {
+                biostate_load: raw[0] * scale,
+                neurostate_fear: raw[1] * scale,
+                context_load: raw[2] * scale,
+                roh: raw[3] * scale,
+                decay: raw[4] * scale,
+                lifeforce_risk: raw[5] * scale,
+                sovereign_relief: relief,
+            }
+        }),
+    ]
+}
+
+fn worsen(site: &SiteView, risk: Risk, bump: f64) -> SiteView {
+    let mut s = site.clone();
+    match risk {
+        Risk::BiostateLoad => s.identity_5d.biostate_load += bump,
+        Risk::NeurostateFear => s.identity_5d.neurostate_fear += bump,
+        Risk::ContextLoad => s.identity_5d.context_load += bump,
+        Risk::Roh => s.bio_env.roh += bump,
+        Risk::Decay => s.bio_env.decay += bump,
+        Risk::LifeforceDrop => {
+            s.bio_env.lifeforce -= bump;
+            s.identity_5d.lifeforce -= bump;
+        }
+    }
+    s
+}
+
+fn b(site: &SiteView, w: &BiosignatureWeights) -> f64 {
+    BioRailTerrasafeGuard::compute_biosignature_weighted(site, w).value()
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(1024))]
+
+    #[test]
+    fn raising_one_risk_never_lowers_b(
+        site in site(),
+        w in weights(),
+        risk in (0..RISKS.len()).prop_map(|i| RISKS[i]),
+        bump in 0.0f64..1.0,
+    ) {
+        let worse = worsen(&site, risk, bump);
+        let (before, after) = (b(&site, &w), b(&worse, &w));
+        prop_assert!(after >= before, "{:?} +{}: b {} -> {}\nweights {:?}\nsite {:?}", risk, bump, before, after, w, site);
+    }
+
+    #[test]
+    fn raising_trust_never_raises_b(site in site(), w in weights(), bump in 0.0f64..1.0) {
+        let mut trusted = site.clone();
+        trusted.identity_5d.sovereignty_trust += bump;
+        let (before, after) = (b(&site, &w), b(&trusted, &w));
+        prop_assert!(after <= before, "trust +{}: b {} -> {}\nweights {:?}\nsite {:?}", bump, before, after, w, site);
+    }
+
+    #[test]
+    fn default_rail_is_monotone_too(site in site(), risk in (0..RISKS.len()).prop_map(|i| RISKS[i]), bump in 0.0f64..1.0) {
+        let before = BioRailTerrasafeGuard::compute_biosignature(&site).value();
+        let after = BioRailTerrasafeGuard::compute_biosignature(&worsen(&site, risk, bump)).value();
+        prop_assert!(after >= before, "{:?} +{}: b {} -> {}\nsite {:?}", risk, bump, before, after, site);
+    }
+}
+
+#[cfg(feature = "strict-invariants")]
+#[test]
+#[should_panic(expected = "biosignature not monotone in decay")]
+fn strict_invariants_catch_a_negative_weight() {
+    let s = site_at([0.5, 0.2, 0.8, 0.5, 0.5, 0.5, 0.0, 0.3], 0.2, 0.8);
+    let w = BiosignatureWeights { decay: -0.2, ..BiosignatureWeights::DEFAULT };
+    b(&s, &w);
+}
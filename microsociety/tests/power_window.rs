@@ -0,0 +1,126 @@
+//! POWER ≤ k·CHURCH on instantaneous readings versus windowed averages.
+
+mod common;
+
+use microsociety::biorail_terrasafe::*;
+use microsociety::power_window::*;
+
+fn site(power: f64) -> SiteView {
+    let mut site = common::site();
+    site.id = 2;
+    site.power_church.power = power;
+    site
+}
+
+fn cfg(power_church_mode: PowerChurchMode) -> BioRailConfig {
+    BioRailConfig { power_church_mode, ..common::cfg() }
+}
+
+const WINDOWED: PowerChurchMode = PowerChurchMode::Windowed { window: 10.0 };
+
+/// Records `power` at each `(at, power)` with CHURCH fixed at 2.0.
+fn series(points: &[(f64, f64)]) -> PowerChurchWindow {
+    let mut window = PowerChurchWindow::new(60.0);
+    for &(at, power) in points {
+        window.record_sample(2, power, 2.0, at);
+    }
+    window
+}
+
+#[test]
+fn sustained_abuse_hidden_by_a_quiet_tick_fails_windowed() {
+    // POWER 4.0 for nine ticks, then a quiet 1.0 just as the gate runs.
+    let window = series(&[(0.0, 4.0), (9.0, 1.0)]);
+    let now = site(1.0);
+
+    let instant = window.gate_report(&now, &cfg(PowerChurchMode::Instantaneous), &ProposedChange::default(), 10.0);
+    assert_eq!(instant.verdict, GateVerdict::Allow);
+    assert_eq!(instant, BioRailTerrasafeGuard::gate_report(&now, &cfg(PowerChurchMode::Instantaneous), &ProposedChange::default()));
+
+    let windowed = window.gate_report(&now, &cfg(WINDOWED), &ProposedChange::default(), 10.0);
+    assert_eq!(windowed.verdict, GateVerdict::Block);
+    let v = windowed.violated_constraints[0];
+    assert_eq!(v.constraint, GateConstraint::PowerChurch);
+    assert!((v.predicted - 3.7).abs() < 1e-9 && v.limit == 2.0);
+}
+
+#[test]
+fn harmless_transient_fails_instantaneous_only() {
+    // A half-tick spike to 5.0 after a calm window.
+    let window = series(&[(0.0, 1.0), (9.5, 5.0)]);
+    let now = site(5.0);
+
+    let instant = window.gate_report(&now, &cfg(PowerChurchMode::Instantaneous), &ProposedChange::default(), 10.0);
+    assert_eq!(instant.verdict, GateVerdict::Block);
+    assert_eq!(instant.violated_constraints[0].constraint, GateConstraint::PowerChurch);
+
+    let windowed = window.gate_report(&now, &cfg(WINDOWED), &ProposedChange::default(), 10.0);
+    assert_eq!(windowed.verdict, GateVerdict::Allow);
+
+    // The proposed delta is judged on top of the average.
+    let push = ProposedChange { delta_power: 1.0, ..ProposedChange::default() };
+    assert_eq!(window.gate_report(&now, &cfg(WINDOWED), &push, 10.0).verdict, GateVerdict::Block);
+}
+
+#[test]
+fn averages_weight_readings_by_how_long_they_held() {
+    let window = series(&[(0.0, 1.0), (2.0, 3.0), (8.0, 0.0), (8.0, 2.0)]);
+    // 1.0 for 2, 3.0 for 6, 2.0 (replacing 0.0) for 2.
+    let avg = window.average(2, 10.0, 10.0).unwrap();
+    assert!((avg.power - 2.4).abs() < 1e-9 && avg.church == 2.0);
+    // Only the last 4 time units: 3.0 for 2, 2.0 for 2.
+    assert!((window.average(2, 4.0, 10.0).unwrap().power - 2.5).abs() < 1e-9);
+    // Time before the first sample is not counted.
+    assert!((window.average(2, 100.0, 4.0).unwrap().power - 2.0).abs() < 1e-9);
+    assert_eq!(window.average(2, 0.0, 5.0).unwrap().power, 3.0);
+    assert_eq!(window.average(2, 10.0, -1.0), None);
+    assert_eq!(window.average(7, 10.0, 10.0), None);
+
+    // Out-of-order and non-finite samples.
+    let mut shuffled = series(&[(8.0, 2.0), (0.0, 1.0), (2.0, 3.0)]);
+    shuffled.record_sample(2, f64::NAN, 2.0, 9.0);
+    assert_eq!(shuffled.average(2, 10.0, 10.0), window.average(2, 10.0, 10.0));
+}
+
+#[test]
+fn pruning_keeps_windows_within_retention_exact() {
+    let mut short = PowerChurchWindow::new(10.0);
+    let mut long = PowerChurchWindow::new(1e9);
+    for t in 0..200 {
+        let power = if t % 7 == 0 { 6.0 } else { 1.0 };
+        short.record_sample(2, power, 2.0, t as f64 * 0.5);
+        long.record_sample(2, power, 2.0, t as f64 * 0.5);
+    }
+    let (a, b) = (short.average(2, 10.0, 99.5).unwrap(), long.average(2, 10.0, 99.5).unwrap());
+    assert!((a.power - b.power).abs() < 1e-9);
+}
+
+#[test]
+fn site_without_samples_is_judged_instantaneously() {
+    let window = PowerChurchWindow::new(10.0);
+    let report = window.gate_report(&site(5.0), &cfg(WINDOWED), &ProposedChange::default(), 10.0);
+    let instant = BioRailTerrasafeGuard::gate_report(&site(5.0), &cfg(PowerChurchMode::Instantaneous), &ProposedChange::default());
+    assert_eq!(report, instant);
+    assert_eq!(report.verdict, GateVerdict::Block);
+}
+
+#[test]
+fn stateless_gate_refuses_a_windowed_config() {
+    // Calm readings would pass; without history the stateless gate cannot
+    // judge a windowed config and must not fall back to the instantaneous one.
+    let calm = site(1.0);
+    assert_eq!(BioRailTerrasafeGuard::gate(&calm, &cfg(PowerChurchMode::Instantaneous), &ProposedChange::default()), GateVerdict::Allow);
+    assert_eq!(BioRailTerrasafeGuard::gate(&calm, &cfg(WINDOWED), &ProposedChange::default()), GateVerdict::Block);
+
+    let report = BioRailTerrasafeGuard::gate_report(&calm, &cfg(WINDOWED), &ProposedChange::default());
+    assert_eq!(
+        report.violated_constraints,
+        [ConstraintViolation { constraint: GateConstraint::PowerChurchWindow, limit: 1.0, predicted: 0.0 }]
+    );
+    let plan = BioRailTerrasafeGuard::repair_plan(&calm, &cfg(WINDOWED));
+    assert!(!plan.resolves);
+    assert_eq!(plan.targets[0].to_string(), "gate through PowerChurchWindow");
+
+    let windowed = PowerChurchWindow::new(10.0).gate_report(&calm, &cfg(WINDOWED), &ProposedChange::default(), 10.0);
+    assert_eq!(windowed.verdict, GateVerdict::Allow);
+}
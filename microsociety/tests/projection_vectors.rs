@@ -0,0 +1,56 @@
+//! `compute_biosignature` reproduces the projection vectors shared with
+//! microsociety-line's `Identity5D::project`.
+
+mod common;
+
+use microsociety::biorail_terrasafe::*;
+
+const VECTORS: &str = include_str!("fixtures/projection_vectors.csv");
+
+/// Rows of `VECTORS` as numbers, header and comments skipped.
+fn vectors() -> Vec<[f64; 8]> {
+    VECTORS
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .skip(1)
+        .map(|line| {
+            let fields: Vec<f64> = line.split(',').map(|f| f.parse().unwrap()).collect();
+            fields.try_into().unwrap()
+        })
+        .collect()
+}
+
+fn site(row: &[f64; 8]) -> SiteView {
+    let [biostate_load, neurostate_fear, context_load, roh, decay, lifeforce, sovereignty_trust, _] = *row;
+    SiteView {
+        id: 0,
+        bio_env: BioEnvelope { roh, decay, lifeforce, lifeforce_min: 0.0, lifeforce_max: 1.0 },
+        identity_5d: FiveDIdentity {
+            biostate_load,
+            neurostate_fear,
+            lifeforce,
+            context_load,
+            sovereignty_trust,
+            sovereignty_consent: true,
+        },
+        ..common::site()
+    }
+}
+
+#[test]
+fn biosignature_matches_shared_vectors() {
+    let rows = vectors();
+    assert!(rows.len() >= 10);
+    for row in &rows {
+        let b = BioRailTerrasafeGuard::compute_biosignature(&site(row)).value();
+        assert!((b - row[7]).abs() < 1e-12, "{row:?}: got {b}");
+    }
+}
+
+#[test]
+fn shared_vectors_use_the_default_weights() {
+    for row in &vectors() {
+        let weighted = BioRailTerrasafeGuard::compute_biosignature_weighted(&site(row), &BiosignatureWeights::DEFAULT);
+        assert_eq!(weighted, BioRailTerrasafeGuard::compute_biosignature(&site(row)));
+    }
+}
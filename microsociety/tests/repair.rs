@@ -0,0 +1,119 @@
+//! `RepairController` holds a site that tripped `ForceRepair` until it recovers.
+
+mod common;
+
+use common::cfg;
+use microsociety::biorail_terrasafe::*;
+use microsociety::repair::*;
+
+fn site(id: usize) -> SiteView {
+    SiteView { id, ..common::site() }
+}
+
+/// Pushes RoH past its 0.3 ceiling.
+fn roh_breach() -> ProposedChange {
+    ProposedChange { delta_roh: 0.25, ..ProposedChange::default() }
+}
+
+/// Exceeds POWER ≤ k·CHURCH without touching an envelope.
+fn power_grab() -> ProposedChange {
+    ProposedChange { delta_power: 5.0, ..ProposedChange::default() }
+}
+
+#[test]
+fn trip_repair_recover_cycle() {
+    let mut repair = RepairController::new(RepairConfig { required_passes: 3, recovery_b: None });
+    let s = site(4);
+    assert_eq!(repair.gate(&s, &cfg(), &ProposedChange::default()), GateVerdict::Allow);
+    assert_eq!(repair.repair_status(4), None);
+
+    assert_eq!(repair.gate(&s, &cfg(), &roh_breach()), GateVerdict::ForceRepair);
+    assert_eq!(repair.repair_status(4), Some(&RepairStatus { trips: 1, evaluations: 0, consecutive_passes: 0 }));
+
+    // Passing proposals are held to Downscale until the third pass in a row.
+    for pass in 1..=2 {
+        assert_eq!(repair.gate(&s, &cfg(), &ProposedChange::default()), GateVerdict::Downscale);
+        assert_eq!(repair.repair_status(4).unwrap().consecutive_passes, pass);
+    }
+    assert_eq!(repair.gate(&s, &cfg(), &power_grab()), GateVerdict::Block);
+    assert_eq!(repair.repair_status(4).unwrap().consecutive_passes, 0);
+    for _ in 0..3 {
+        assert_eq!(repair.gate(&s, &cfg(), &ProposedChange::default()), GateVerdict::Downscale);
+    }
+    assert_eq!(repair.repair_status(4), None);
+    assert_eq!(repair.gate(&s, &cfg(), &ProposedChange::default()), GateVerdict::Allow);
+}
+
+#[test]
+fn relapse_during_recovery_restarts_the_count() {
+    let mut repair = RepairController::new(RepairConfig { required_passes: 2, recovery_b: None });
+    let s = site(1);
+    repair.gate(&s, &cfg(), &roh_breach());
+    repair.gate(&s, &cfg(), &ProposedChange::default());
+    assert_eq!(repair.gate(&s, &cfg(), &roh_breach()), GateVerdict::ForceRepair);
+    assert_eq!(repair.repair_status(1), Some(&RepairStatus { trips: 2, evaluations: 2, consecutive_passes: 0 }));
+
+    assert_eq!(repair.gate(&s, &cfg(), &ProposedChange::default()), GateVerdict::Downscale);
+    assert!(repair.repair_status(1).is_some());
+    assert_eq!(repair.gate(&s, &cfg(), &ProposedChange::default()), GateVerdict::Downscale);
+    assert_eq!(repair.repair_status(1), None);
+}
+
+#[test]
+fn sites_are_tracked_independently() {
+    let mut repair = RepairController::default();
+    repair.gate(&site(7), &cfg(), &roh_breach());
+    repair.gate(&site(2), &cfg(), &roh_breach());
+    assert_eq!(repair.gate(&site(3), &cfg(), &ProposedChange::default()), GateVerdict::Allow);
+    assert_eq!(repair.in_repair().collect::<Vec<_>>(), [2, 7]);
+
+    assert_eq!(repair.clear(7).map(|s| s.trips), Some(1));
+    assert_eq!(repair.gate(&site(7), &cfg(), &ProposedChange::default()), GateVerdict::Allow);
+    assert_eq!(repair.gate(&site(2), &cfg(), &ProposedChange::default()), GateVerdict::Downscale);
+}
+
+#[test]
+fn low_predicted_b_clears_early() {
+    let s = site(0);
+    let b = BioRailTerrasafeGuard::gate_report(&s, &cfg(), &ProposedChange::default()).predicted_b.value();
+    let mut repair = RepairController::new(RepairConfig { required_passes: 10, recovery_b: Some(b + 0.01) });
+    repair.gate(&s, &cfg(), &roh_breach());
+    assert_eq!(repair.gate(&s, &cfg(), &ProposedChange::default()), GateVerdict::Downscale);
+    assert_eq!(repair.repair_status(0), None);
+
+    // Only a passing evaluation settles the site, however low its b.
+    let mut strict = RepairController::new(RepairConfig { required_passes: 10, recovery_b: Some(1.0) });
+    strict.gate(&s, &cfg(), &roh_breach());
+    strict.gate(&s, &cfg(), &power_grab());
+    assert!(strict.repair_status(0).is_some());
+}
+
+#[test]
+fn observe_matches_gate_for_reused_reports() {
+    let s = site(5);
+    let mut via_gate = RepairController::default();
+    let mut via_observe = RepairController::default();
+    let mut report = GateReport::default();
+    for change in [ProposedChange::default(), roh_breach(), ProposedChange::default(), power_grab(), ProposedChange::default()] {
+        BioRailTerrasafeGuard::gate_into(&s, &cfg(), &change, &mut report);
+        assert_eq!(via_observe.observe(s.id, &report), via_gate.gate(&s, &cfg(), &change));
+    }
+    assert_eq!(via_observe, via_gate);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn state_checkpoints_through_json() {
+    let mut repair = RepairController::new(RepairConfig { required_passes: 2, recovery_b: Some(0.05) });
+    repair.gate(&site(9), &cfg(), &roh_breach());
+    repair.gate(&site(9), &cfg(), &power_grab());
+
+    let json = serde_json::to_string(&repair).unwrap();
+    let mut restored: RepairController = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, repair);
+    assert_eq!(restored.repair_status(9), Some(&RepairStatus { trips: 1, evaluations: 1, consecutive_passes: 0 }));
+    assert_eq!(restored.gate(&site(9), &cfg(), &ProposedChange::default()), GateVerdict::Downscale);
+
+    let cfg: RepairConfig = serde_json::from_str(r#"{"recovery_b":0.1}"#).unwrap();
+    assert_eq!(cfg, RepairConfig { required_passes: 5, recovery_b: Some(0.1) });
+}
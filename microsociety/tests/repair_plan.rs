@@ -0,0 +1,146 @@
+//! `repair_plan` turns a site's current violations into a change the gate allows.
+
+mod common;
+
+use microsociety::biorail_terrasafe::*;
+use proptest::prelude::*;
+
+fn site() -> SiteView {
+    let mut site = common::site();
+    site.id = 12;
+    site
+}
+
+fn corridor(min: f64, max: f64) -> BioRailConfig {
+    BioRailConfig {
+        corridor_min: RailScalar::new_clamped(min),
+        corridor_max: RailScalar::new_clamped(max),
+        power_church_mode: PowerChurchMode::Instantaneous,
+        warning_margin: 0.0,
+        capability_state: CapabilityState::ControlledHuman,
+        config_version: None,
+    }
+}
+
+#[test]
+fn healthy_site_needs_no_repair() {
+    let plan = BioRailTerrasafeGuard::repair_plan(&site(), &corridor(0.0, 0.5));
+    assert!(plan.targets.is_empty() && plan.resolves);
+    assert_eq!(plan.change.delta_roh, 0.0);
+}
+
+#[test]
+fn plan_covers_every_violation_most_severe_first() {
+    let mut s = site();
+    s.bio_env.roh = 0.35;
+    s.bio_env.lifeforce = 0.08;
+    s.bioload_view.room = RailScalar::new_clamped(1.0);
+    s.power_church.power = 2.6;
+    let cfg = corridor(0.0, 0.5);
+    assert_eq!(BioRailTerrasafeGuard::gate(&s, &cfg, &ProposedChange::default()), GateVerdict::ForceRepair);
+
+    let plan = BioRailTerrasafeGuard::repair_plan(&s, &cfg);
+    let order: Vec<_> = plan.targets.iter().map(|t| t.constraint).collect();
+    // Lifeforce 0.12 under a 0.2 floor (60%), POWER 0.6 over 2.0 (30%),
+    // room 0.2 over 0.8 (25%), RoH 0.05 over 0.3 (17%).
+    assert_eq!(
+        order,
+        [GateConstraint::LifeforceFloor, GateConstraint::PowerChurch, GateConstraint::RoomBioload, GateConstraint::RohCeiling]
+    );
+    assert_eq!(plan.targets[0].to_string(), "raise lifeforce by ≥0.12 (0.08 → 0.20)");
+    assert_eq!(plan.targets[2].to_string(), "reduce room bioload by ≥0.20 (1.00 → 0.80)");
+    assert_eq!(plan.targets[3].to_string(), "reduce RoH by ≥0.05 (0.35 → 0.30)");
+
+    assert!(plan.resolves);
+    assert_eq!(BioRailTerrasafeGuard::gate(&s, &cfg, &plan.change), GateVerdict::Allow);
+    assert!((plan.change.delta_roh + 0.05).abs() < 1e-9);
+    assert_eq!(plan.change.delta_neurostate_fear, 0.0);
+}
+
+#[test]
+fn b_above_a_tightened_corridor_sheds_identity_risk() {
+    let mut s = site();
+    s.identity_5d.neurostate_fear = 0.9;
+    s.identity_5d.sovereignty_trust = 0.1;
+    s.justice_metrics.erg = 0.9;
+    let cfg = corridor(0.0, 0.4);
+    let report = BioRailTerrasafeGuard::gate_report(&s, &cfg, &ProposedChange::default());
+    assert!(report.justice_tightening_applied);
+    assert!(report.predicted_b.value() > report.tuned_corridor.1.value());
+
+    let plan = BioRailTerrasafeGuard::repair_plan(&s, &cfg);
+    assert_eq!(plan.targets.len(), 1);
+    assert_eq!(plan.targets[0].constraint, GateConstraint::CorridorExit);
+    assert!(plan.targets[0].delta < 0.0);
+    // FEAR is the largest weighted risk, so it goes first.
+    assert!(plan.change.delta_neurostate_fear < 0.0);
+    assert!(plan.resolves);
+    assert_eq!(BioRailTerrasafeGuard::gate(&s, &cfg, &plan.change), GateVerdict::Allow);
+}
+
+#[test]
+fn b_below_the_corridor_floor_is_not_resolved() {
+    let plan = BioRailTerrasafeGuard::repair_plan(&site(), &corridor(0.4, 0.8));
+    assert_eq!(plan.targets[0].constraint, GateConstraint::CorridorExit);
+    assert!(plan.targets[0].delta > 0.0);
+    assert!(!plan.resolves);
+    assert_eq!(plan.change.delta_neurostate_fear, 0.0);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn plan_serializes_for_the_deed_engine() {
+    let mut s = site();
+    s.bio_env.decay = 1.2;
+    let plan = BioRailTerrasafeGuard::repair_plan(&s, &corridor(0.0, 0.5));
+    let json = serde_json::to_string(&plan).unwrap();
+    assert!(json.starts_with(r#"{"targets":[{"constraint":"DecayCeiling","current":1.2,"limit":1.0,"#));
+    let back: RepairPlan = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.targets, plan.targets);
+    assert_eq!(back.resolves, plan.resolves);
+}
+
+/// Sites drawn past their ceilings and floors.
+fn broken_site(u: &[f64]) -> (SiteView, BioRailConfig) {
+    let r = |i: usize, lo: f64, hi: f64| lo + (hi - lo) * u[i];
+    let mut s = site();
+    s.bio_env = BioEnvelope { roh: r(0, 0.0, 0.45), decay: r(1, 0.0, 1.4), lifeforce: r(2, 0.0, 1.2), lifeforce_min: 0.2, lifeforce_max: 1.0 };
+    s.identity_5d = FiveDIdentity {
+        biostate_load: u[3],
+        neurostate_fear: u[4],
+        lifeforce: u[2],
+        context_load: u[5],
+        sovereignty_trust: u[6],
+        sovereignty_consent: true,
+    };
+    s.bioload_view.body = RailScalar::new_clamped(u[7]);
+    s.bioload_view.room = RailScalar::new_clamped(u[8]);
+    s.bioload_view.grid = RailScalar::new_clamped(u[9]);
+    s.power_church.power = r(10, 0.0, 5.0);
+    s.justice_metrics.hpcc = u[11];
+    (s, corridor(0.0, r(12, 0.3, 0.8)))
+}
+
+proptest! {
+    #[test]
+    fn applying_the_plan_clears_every_envelope_bioload_and_power_violation(u in prop::collection::vec(0.0f64..1.0, 13)) {
+        let (s, cfg) = broken_site(&u);
+        let plan = BioRailTerrasafeGuard::repair_plan(&s, &cfg);
+        let after = BioRailTerrasafeGuard::gate_report(&s, &cfg, &plan.change);
+        prop_assert!(after.violated_constraints.iter().all(|v| v.constraint == GateConstraint::CorridorExit), "{:?}", after.violated_constraints);
+        prop_assert_eq!(plan.resolves, after.verdict == GateVerdict::Allow);
+        // A b left above the ceiling means there was no identity risk left
+        // to shed and no relief left to add.
+        if !plan.resolves && after.predicted_b.value() > after.tuned_corridor.1.value() {
+            let id = &s.identity_5d;
+            let c = &plan.change;
+            let shed = [
+                id.biostate_load + c.delta_biostate_load,
+                id.neurostate_fear + c.delta_neurostate_fear,
+                id.context_load + c.delta_context_load,
+            ];
+            prop_assert!(shed.iter().all(|x| *x <= 1e-9), "{:?}", shed);
+            prop_assert!(id.sovereignty_trust + c.delta_sovereignty_trust >= 1.0 - 1e-9);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! `gate_sequence` gates each step of a pipeline against the state the
+//! earlier steps leave.
+
+mod common;
+
+use common::{cfg, site};
+use microsociety::biorail_terrasafe::*;
+
+#[test]
+fn composition_passes_but_the_intermediate_step_is_rejected() {
+    // Step 1 takes RoH to 0.35, over the cap; step 2 brings it back to 0.15.
+    let pipeline = [
+        ProposedChange { delta_roh: 0.25, ..ProposedChange::default() },
+        ProposedChange { delta_roh: -0.2, ..ProposedChange::default() },
+    ];
+    let composed = ProposedChange::compose(&pipeline);
+    assert!((composed.delta_roh - 0.05).abs() < 1e-12);
+    assert_eq!(BioRailTerrasafeGuard::gate(&site(), &cfg(), &composed), GateVerdict::Allow);
+
+    let sequence = BioRailTerrasafeGuard::gate_sequence(&site(), &cfg(), &pipeline);
+    assert_eq!(sequence.failed_at, Some(0));
+    assert_eq!(sequence.verdict(), GateVerdict::ForceRepair);
+    assert_eq!(sequence.reports.len(), 1);
+    let (step, report) = sequence.failure().unwrap();
+    assert_eq!(step, 0);
+    assert_eq!(report.violated_constraints[0].constraint, GateConstraint::RohCeiling);
+}
+
+#[test]
+fn later_steps_see_the_state_earlier_steps_leave() {
+    // Each step alone is fine against the baseline; the third overdraws the body ceiling.
+    let step = ProposedChange { delta_bioload_body: 0.2, ..ProposedChange::default() };
+    assert_eq!(BioRailTerrasafeGuard::gate(&site(), &cfg(), &step), GateVerdict::Allow);
+
+    let sequence = BioRailTerrasafeGuard::gate_sequence(&site(), &cfg(), &[step.clone(), step.clone(), step.clone()]);
+    assert_eq!(sequence.failed_at, Some(2));
+    assert_eq!(sequence.reports.len(), 3);
+    assert_eq!(sequence.reports[0].verdict, GateVerdict::Allow);
+    assert_eq!(sequence.reports[1].verdict, GateVerdict::Allow);
+    let (_, report) = sequence.failure().unwrap();
+    assert_eq!(report.violated_constraints[0].constraint, GateConstraint::BodyBioload);
+    assert!((report.violated_constraints[0].predicted - 0.9).abs() < 1e-9);
+}
+
+#[test]
+fn passing_pipeline_reports_every_step() {
+    let pipeline = [
+        ProposedChange { delta_bioload_body: 0.1, ..ProposedChange::default() },
+        ProposedChange { delta_neurostate_fear: -0.1, ..ProposedChange::default() },
+        ProposedChange::default(),
+    ];
+    let sequence = BioRailTerrasafeGuard::gate_sequence(&site(), &cfg(), &pipeline);
+    assert_eq!(sequence.failed_at, None);
+    assert_eq!(sequence.verdict(), GateVerdict::Allow);
+    assert_eq!(sequence.reports.len(), 3);
+    assert_eq!(sequence.reports[0], BioRailTerrasafeGuard::gate_report(&site(), &cfg(), &pipeline[0]));
+    // The second step starts from the first step's predicted b.
+    assert_eq!(sequence.reports[1].current_b, sequence.reports[0].predicted_b);
+}
+
+#[test]
+fn empty_pipeline_is_allowed() {
+    let sequence = BioRailTerrasafeGuard::gate_sequence(&site(), &cfg(), &[]);
+    assert!(sequence.reports.is_empty());
+    assert_eq!(sequence.verdict(), GateVerdict::Allow);
+    assert_eq!(ProposedChange::compose(&[]).delta_power, 0.0);
+}
+
+#[test]
+fn compose_sums_every_delta() {
+    let a = ProposedChange { delta_biostate_load: 0.1, delta_power: 1.0, delta_bioload_grid: -0.2, ..ProposedChange::default() };
+    let b = ProposedChange { delta_biostate_load: 0.2, delta_decay: 0.05, delta_bioload_grid: 0.1, ..ProposedChange::default() };
+    let sum = ProposedChange::compose(&[a.clone(), b.clone()]);
+    assert!((sum.delta_biostate_load - 0.3).abs() < 1e-12);
+    assert_eq!(sum.delta_power, 1.0);
+    assert_eq!(sum.delta_decay, 0.05);
+    assert!((sum.delta_bioload_grid + 0.1).abs() < 1e-12);
+    let reversed = ProposedChange::compose(&[b, a]);
+    assert_eq!(reversed.delta_biostate_load, sum.delta_biostate_load);
+}
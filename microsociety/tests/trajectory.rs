@@ -0,0 +1,69 @@
+//! `simulate_trajectory` finds where a repeated change leaves the rail.
+
+mod common;
+
+use common::{cfg, site};
+use microsociety::biorail_terrasafe::*;
+
+#[test]
+fn roh_creep_is_allowed_once_but_breaches_at_step_three() {
+    // RoH 0.1 → 0.18 → 0.26 → 0.34 against the 0.3 cap.
+    let creep = ProposedChange { delta_roh: 0.08, ..ProposedChange::default() };
+    assert_eq!(BioRailTerrasafeGuard::gate(&site(), &cfg(), &creep), GateVerdict::Allow);
+
+    let trajectory = BioRailTerrasafeGuard::simulate_trajectory(&site(), &cfg(), &creep, 5);
+    assert_eq!(trajectory.first_violation, Some(3));
+    assert_eq!(trajectory.safe_horizon(), 2);
+    assert_eq!(trajectory.violated_constraints.iter().map(|v| v.constraint).collect::<Vec<_>>(), [GateConstraint::RohCeiling]);
+    assert_eq!(trajectory.steps.len(), 5);
+    let verdicts: Vec<_> = trajectory.steps.iter().map(|s| s.verdict).collect();
+    assert_eq!(verdicts[..3], [GateVerdict::Allow, GateVerdict::Allow, GateVerdict::ForceRepair]);
+    for (step, expected) in trajectory.steps.iter().zip([0.18, 0.26, 0.34]) {
+        assert!((step.roh - expected).abs() < 1e-9, "{step:?}");
+    }
+}
+
+#[test]
+fn first_step_matches_gate_report() {
+    let change = ProposedChange { delta_bioload_body: 0.1, delta_neurostate_fear: 0.05, ..ProposedChange::default() };
+    let report = BioRailTerrasafeGuard::gate_report(&site(), &cfg(), &change);
+    let trajectory = BioRailTerrasafeGuard::simulate_trajectory(&site(), &cfg(), &change, 1);
+    let first = &trajectory.steps[0];
+    assert_eq!(first.verdict, report.verdict);
+    assert_eq!(first.b, report.predicted_b);
+    assert!((first.bioload.body.value() - 0.4).abs() < 1e-9);
+    assert_eq!(first.bioload.body_max, site().bioload_view.body_max);
+}
+
+#[test]
+fn saturated_change_settles_instead_of_running_every_step() {
+    // Fear clamps at 0 after two steps; nothing else moves.
+    let calming = ProposedChange { delta_neurostate_fear: -0.15, ..ProposedChange::default() };
+    let trajectory = BioRailTerrasafeGuard::simulate_trajectory(&site(), &cfg(), &calming, usize::MAX);
+    assert_eq!(trajectory.settled_at, Some(3));
+    assert_eq!(trajectory.steps.len(), 3);
+    assert_eq!(trajectory.first_violation, None);
+    assert_eq!(trajectory.safe_horizon(), usize::MAX);
+}
+
+#[test]
+fn lifeforce_leaving_its_band_is_reported_and_the_run_stays_bounded() {
+    let drain = ProposedChange { delta_lifeforce_env: -0.15, ..ProposedChange::default() };
+    let trajectory = BioRailTerrasafeGuard::simulate_trajectory(&site(), &cfg(), &drain, 50);
+    // 0.6 → 0.45 → 0.30 → 0.15 crosses the 0.2 floor on step 3.
+    assert_eq!(trajectory.first_violation, Some(3));
+    assert_eq!(trajectory.violated_constraints[0].constraint, GateConstraint::LifeforceFloor);
+    assert_eq!(trajectory.steps.len(), 50);
+    assert_eq!(trajectory.settled_at, None);
+}
+
+#[test]
+fn zero_steps_and_no_change() {
+    let none = BioRailTerrasafeGuard::simulate_trajectory(&site(), &cfg(), &ProposedChange::default(), 0);
+    assert!(none.steps.is_empty());
+    assert_eq!(none.safe_horizon(), 0);
+
+    let idle = BioRailTerrasafeGuard::simulate_trajectory(&site(), &cfg(), &ProposedChange::default(), 10);
+    assert_eq!(idle.settled_at, Some(1));
+    assert_eq!(idle.safe_horizon(), 10);
+}
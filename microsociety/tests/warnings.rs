@@ -0,0 +1,83 @@
+//! `warning_margin` reports constraints close to their limit without changing the verdict.
+
+mod common;
+
+use microsociety::biorail_terrasafe::*;
+
+fn site(erg: f64) -> SiteView {
+    let mut site = common::site();
+    site.justice_metrics.erg = erg;
+    site
+}
+
+fn cfg(warning_margin: f64) -> BioRailConfig {
+    BioRailConfig { corridor_max: RailScalar::new_clamped(1.0), warning_margin, ..common::cfg() }
+}
+
+/// RoH to 0.28 of 0.3, body bioload to 0.75 of 0.8, POWER to 1.9 of 2.0.
+fn close_to_three_ceilings() -> ProposedChange {
+    ProposedChange { delta_roh: 0.18, delta_bioload_body: 0.45, delta_power: 0.9, ..ProposedChange::default() }
+}
+
+#[test]
+fn allowed_change_near_three_ceilings_warns_three_times() {
+    let report = BioRailTerrasafeGuard::gate_report(&site(0.1), &cfg(0.1), &close_to_three_ceilings());
+    assert_eq!(report.verdict, GateVerdict::Allow);
+    assert!(report.violated_constraints.is_empty());
+    let warned: Vec<_> = report.warnings.iter().map(|w| w.constraint).collect();
+    assert_eq!(warned, [GateConstraint::RohCeiling, GateConstraint::BodyBioload, GateConstraint::PowerChurch]);
+
+    let roh = report.warnings[0];
+    assert_eq!(roh.limit, 0.3);
+    assert!((roh.predicted - 0.28).abs() < 1e-12);
+    assert_eq!(report.warnings[1].limit, RailScalar::new_clamped(0.8).value());
+    assert_eq!(report.warnings[2].limit, 2.0);
+}
+
+#[test]
+fn change_far_from_every_limit_has_no_warnings() {
+    let report = BioRailTerrasafeGuard::gate_report(&site(0.1), &cfg(0.1), &ProposedChange::default());
+    assert_eq!(report.verdict, GateVerdict::Allow);
+    assert!(report.warnings.is_empty(), "{:?}", report.warnings);
+}
+
+#[test]
+fn zero_or_invalid_margin_disables_warnings() {
+    for margin in [0.0, -0.1, f64::NAN, f64::INFINITY] {
+        let report = BioRailTerrasafeGuard::gate_report(&site(0.1), &cfg(margin), &close_to_three_ceilings());
+        assert!(report.warnings.is_empty(), "{margin}: {:?}", report.warnings);
+    }
+}
+
+#[test]
+fn warnings_never_change_the_verdict() {
+    let far = ProposedChange { delta_roh: 0.25, ..close_to_three_ceilings() };
+    for proposed in [ProposedChange::default(), close_to_three_ceilings(), far] {
+        assert_eq!(
+            BioRailTerrasafeGuard::gate_report(&site(0.1), &cfg(0.1), &proposed).verdict,
+            BioRailTerrasafeGuard::gate(&site(0.1), &cfg(0.0), &proposed)
+        );
+    }
+}
+
+#[test]
+fn violated_constraints_do_not_also_warn() {
+    let over = ProposedChange { delta_roh: 0.25, ..close_to_three_ceilings() };
+    let report = BioRailTerrasafeGuard::gate_report(&site(0.1), &cfg(0.1), &over);
+    assert_eq!(report.verdict, GateVerdict::ForceRepair);
+    assert_eq!(report.violated_constraints.iter().map(|v| v.constraint).collect::<Vec<_>>(), [GateConstraint::RohCeiling]);
+    assert!(report.warnings.iter().all(|w| w.constraint != GateConstraint::RohCeiling));
+    assert_eq!(report.warnings.len(), 2);
+}
+
+#[test]
+fn corridor_warns_near_the_nearer_bound() {
+    // b ≈ 0.05 sits just above a 0.04 floor and far below the 0.5 ceiling.
+    let narrow = BioRailConfig { corridor_min: RailScalar::new_clamped(0.04), corridor_max: RailScalar::new_clamped(0.5), ..cfg(0.5) };
+    let report = BioRailTerrasafeGuard::gate_report(&site(0.1), &narrow, &ProposedChange::default());
+    assert_eq!(report.verdict, GateVerdict::Allow);
+    let corridor: Vec<_> = report.warnings.iter().filter(|w| w.constraint == GateConstraint::CorridorExit).collect();
+    assert_eq!(corridor.len(), 1);
+    assert_eq!(corridor[0].limit, 0.04);
+    assert_eq!(corridor[0].predicted, report.predicted_b.value());
+}
@@ -0,0 +1,88 @@
+//! The gate hot path must not touch the heap: `gate_into` is called at
+//! 100 Hz across hundreds of sites.
+
+mod common;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use microsociety::biorail_terrasafe::*;
+
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATED: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATED.try_with(|n| n.set(n.get() + layout.size()));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocated_during(f: impl FnOnce()) -> usize {
+    let before = ALLOCATED.with(Cell::get);
+    f();
+    ALLOCATED.with(Cell::get) - before
+}
+
+fn site(erg: f64) -> SiteView {
+    let mut site = common::site();
+    site.id = 1;
+    site.justice_metrics.erg = erg;
+    site
+}
+
+fn change(delta_roh: f64) -> ProposedChange {
+    ProposedChange {
+        delta_biostate_load: 0.05,
+        delta_neurostate_fear: 0.0,
+        delta_lifeforce: 0.0,
+        delta_context_load: 0.05,
+        delta_sovereignty_trust: 0.0,
+        delta_roh,
+        delta_decay: 0.0,
+        delta_lifeforce_env: 0.0,
+        delta_bioload_body: 0.05,
+        delta_bioload_room: 0.0,
+        delta_bioload_grid: 0.0,
+        delta_power: 0.5,
+    }
+}
+
+#[test]
+fn gate_into_allocates_zero_bytes() {
+    let cfg = BioRailConfig { corridor_min: RailScalar::new_clamped(0.0), corridor_max: RailScalar::new_clamped(0.5), power_church_mode: PowerChurchMode::Instantaneous, warning_margin: 0.1, capability_state: CapabilityState::ControlledHuman, config_version: None };
+    // Untuned and justice-tightened sites; allowed and force-repaired changes;
+    // warnings on.
+    let sites = [site(0.1), site(0.9)];
+    let changes = [change(0.0), change(0.5)];
+    let mut report = GateReport::default();
+
+    let bytes = allocated_during(|| {
+        for site in &sites {
+            for proposed in &changes {
+                BioRailTerrasafeGuard::gate_into(site, &cfg, proposed, &mut report);
+                let _ = BioRailTerrasafeGuard::gate(site, &cfg, proposed);
+            }
+        }
+    });
+
+    assert_eq!(bytes, 0);
+}
+
+#[test]
+fn counting_allocator_observes_heap_use() {
+    let bytes = allocated_during(|| {
+        std::hint::black_box(vec![0u8; 64]);
+    });
+    assert!(bytes >= 64);
+}
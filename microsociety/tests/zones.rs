@@ -0,0 +1,63 @@
+//! `gate_in_zone` picks the b corridor from the site's zone.
+
+mod common;
+
+use microsociety::biorail_terrasafe::*;
+
+fn site() -> SiteView {
+    let mut site = common::site();
+    site.id = 11;
+    site.identity_5d.sovereignty_trust = 0.0;
+    site
+}
+
+fn corridor(min: f64, max: f64) -> BioRailConfig {
+    BioRailConfig { corridor_min: RailScalar::new_clamped(min), corridor_max: RailScalar::new_clamped(max), power_church_mode: PowerChurchMode::Instantaneous, warning_margin: 0.0, capability_state: CapabilityState::ControlledHuman, config_version: None }
+}
+
+fn zones() -> ZoneCorridors {
+    ZoneCorridors::new(corridor(0.0, 0.15))
+        .with_zone(ZoneTag::NeuralBand, corridor(0.0, 0.2))
+        .with_zone(ZoneTag::XrField, corridor(0.0, 0.6))
+        .with_zone(ZoneTag::NeuralBand, corridor(0.0, 1.0))
+}
+
+#[test]
+fn same_b_passes_in_xr_field_and_fails_in_neural_band() {
+    let change = ProposedChange { delta_neurostate_fear: 0.1, ..ProposedChange::default() };
+    let xr = BioRailTerrasafeGuard::gate_in_zone(&site(), ZoneTag::XrField, &zones(), &change);
+    let neural = BioRailTerrasafeGuard::gate_in_zone(&site(), ZoneTag::NeuralBand, &zones(), &change);
+
+    assert_eq!(xr.predicted_b, neural.predicted_b);
+    assert!((0.2..0.6).contains(&xr.predicted_b.value()), "{:?}", xr.predicted_b);
+    assert_eq!((xr.verdict, xr.zone), (GateVerdict::Allow, Some(ZoneTag::XrField)));
+    assert_eq!(neural.zone, Some(ZoneTag::NeuralBand));
+    assert_ne!(neural.verdict, GateVerdict::Allow);
+    assert_eq!(neural.violated_constraints.len(), 1);
+    assert_eq!(neural.violated_constraints[0].constraint, GateConstraint::CorridorExit);
+    assert_eq!(neural.tuned_corridor.1, RailScalar::new_clamped(0.2));
+}
+
+#[test]
+fn missing_zone_falls_back_and_says_so() {
+    let z = zones();
+    let (cfg, resolved) = z.resolve(ZoneTag::HydrogelPatch);
+    assert_eq!((cfg.corridor_max, resolved), (RailScalar::new_clamped(0.15), None));
+
+    let report = BioRailTerrasafeGuard::gate_in_zone(&site(), ZoneTag::HydrogelPatch, &z, &ProposedChange::default());
+    assert_eq!(report.zone, None);
+    assert_eq!(report.tuned_corridor.1, RailScalar::new_clamped(0.15));
+    assert_eq!(report.verdict, BioRailTerrasafeGuard::gate(&site(), &z.fallback, &ProposedChange::default()));
+}
+
+#[test]
+fn zoned_report_matches_flat_gate_with_the_resolved_corridor() {
+    let z = zones();
+    for zone in [ZoneTag::NeuralBand, ZoneTag::VascularConduit, ZoneTag::XrField, ZoneTag::JetsonLineSite] {
+        let (cfg, resolved) = z.resolve(zone);
+        let zoned = BioRailTerrasafeGuard::gate_in_zone(&site(), zone, &z, &ProposedChange::default());
+        let flat = BioRailTerrasafeGuard::gate_report(&site(), cfg, &ProposedChange::default());
+        assert_eq!(flat.zone, None);
+        assert_eq!(zoned, GateReport { zone: resolved, ..flat });
+    }
+}